@@ -0,0 +1,99 @@
+//! Contains the batcher CLI.
+
+use crate::{
+    flags::{BatcherArgs, SignerArgs},
+    metrics::VersionInfo,
+};
+use alloy_provider::RootProvider;
+use alloy_rpc_client::RpcClient;
+use anyhow::Result;
+use clap::Parser;
+use kona_batcher_service::{AlloyL1Client, AlloyL2Source, BatcherService, Metrics};
+use kona_cli::{LogArgs, LogConfig, MetricsArgs, cli_styles};
+use op_alloy_network::Optimism;
+use std::pin::pin;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+/// CLI for the Rust implementation of the OP Batcher.
+#[derive(Parser, Debug)]
+#[command(name = "kona-batcher", about = "Rust implementation of the OP Batcher", styles = cli_styles())]
+pub struct Cli {
+    /// Global args
+    #[command(flatten)]
+    pub global: LogArgs,
+
+    /// Prometheus metrics args
+    #[command(flatten)]
+    pub metrics: MetricsArgs,
+
+    /// Batcher args
+    #[command(flatten)]
+    pub batcher: BatcherArgs,
+
+    /// Signer args
+    #[command(flatten)]
+    pub signer: SignerArgs,
+}
+
+impl Cli {
+    /// Runs the CLI.
+    pub fn run(self) -> Result<()> {
+        self.metrics.init_metrics()?;
+        // Register build metrics
+        VersionInfo::from_build().register_version_metrics();
+        if self.metrics.enabled {
+            Metrics::init();
+        }
+
+        self.init_logs(&self.global)?;
+
+        Self::run_until_ctrl_c(async move {
+            let config = self.batcher.config()?;
+            let signer = self.signer.signer()?;
+            let l1 = AlloyL1Client::new(RootProvider::new_http(self.batcher.l1_eth_rpc.clone()));
+            let l2 = AlloyL2Source::new(
+                RpcClient::new_http(self.batcher.rollup_rpc.clone()),
+                RootProvider::<Optimism>::new_http(self.batcher.l2_eth_rpc.clone()),
+            );
+
+            let cancel = CancellationToken::new();
+            let mut service = pin!(BatcherService::new(config, l1, l2, signer).run(cancel.clone()));
+
+            tokio::select! {
+                res = &mut service => return Ok(res?),
+                _ = tokio::signal::ctrl_c() => {
+                    info!(target: "batcher", "Ctrl+C received, initiating service shutdown...");
+                }
+            }
+
+            cancel.cancel();
+            service.await?;
+            info!(target: "batcher", "Batcher service shut down gracefully.");
+            Ok(())
+        })
+    }
+
+    /// Run until ctrl-c is pressed.
+    pub fn run_until_ctrl_c<F>(fut: F) -> Result<()>
+    where
+        F: std::future::Future<Output = Result<()>>,
+    {
+        let rt = Self::tokio_runtime().map_err(|e| anyhow::anyhow!(e))?;
+        rt.block_on(fut)
+    }
+
+    /// Creates a new default tokio multi-thread [`Runtime`](tokio::runtime::Runtime) with all
+    /// features enabled
+    pub fn tokio_runtime() -> Result<tokio::runtime::Runtime, std::io::Error> {
+        tokio::runtime::Builder::new_multi_thread().enable_all().build()
+    }
+
+    /// Initializes the telemetry stack.
+    pub fn init_logs(&self, args: &LogArgs) -> anyhow::Result<()> {
+        let filter = tracing_subscriber::EnvFilter::from_default_env();
+
+        LogConfig::new(args.clone()).init_tracing_subscriber(Some(filter))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+use anyhow::{Context as _, Result, bail};
+use clap::{Args, builder::RangedU64ValueParser};
+use kona_batcher_core::ChannelConfig;
+use kona_batcher_service::{BatcherConfig, TxManagerConfig};
+use kona_comp::{
+    BatchTypeArg, CompressionAlgo, CompressorArg, DEFAULT_APPROX_COMPR_RATIO, DataAvailabilityArg,
+    load_rollup_config, parse_compression_algo,
+};
+use std::{
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+use url::Url;
+
+/// Batcher configuration arguments.
+#[derive(Args, Debug)]
+pub struct BatcherArgs {
+    /// HTTP provider URL for L1.
+    #[arg(long = "l1-eth-rpc", env = "KONA_BATCHER_L1_ETH_RPC")]
+    pub l1_eth_rpc: Url,
+
+    /// HTTP provider URL for the L2 execution client.
+    #[arg(long = "l2-eth-rpc", env = "KONA_BATCHER_L2_ETH_RPC")]
+    pub l2_eth_rpc: Url,
+
+    /// HTTP provider URL for the rollup node.
+    #[arg(long = "rollup-rpc", env = "KONA_BATCHER_ROLLUP_RPC")]
+    pub rollup_rpc: Url,
+
+    /// The L2 chain ID, used to load the rollup config from the superchain registry.
+    #[arg(long = "l2-chain-id", env = "KONA_BATCHER_L2_CHAIN_ID", default_value_t = 10)]
+    pub l2_chain_id: u64,
+
+    /// Path to a custom rollup config file, overriding the superchain registry.
+    #[arg(long = "rollup-config", env = "KONA_BATCHER_ROLLUP_CONFIG")]
+    pub rollup_config: Option<PathBuf>,
+
+    /// How frames are posted to L1.
+    #[arg(
+        long = "data-availability-type",
+        env = "KONA_BATCHER_DATA_AVAILABILITY_TYPE",
+        value_enum,
+        default_value_t = DataAvailabilityArg::Calldata
+    )]
+    pub da_type: DataAvailabilityArg,
+
+    /// The number of frames per transaction. For blobs, this is the number of blobs per
+    /// transaction.
+    #[arg(long = "target-num-frames", env = "KONA_BATCHER_TARGET_NUM_FRAMES", default_value_t = 1)]
+    pub target_num_frames: usize,
+
+    /// The maximum size of a frame, in bytes. Defaults to the largest frame of the data
+    /// availability type.
+    #[arg(long = "max-frame-size", env = "KONA_BATCHER_MAX_FRAME_SIZE")]
+    pub max_frame_size: Option<usize>,
+
+    /// The number of L1 blocks a channel may stay open. Zero disables the limit.
+    #[arg(
+        long = "max-channel-duration",
+        env = "KONA_BATCHER_MAX_CHANNEL_DURATION",
+        default_value_t = 0
+    )]
+    pub max_channel_duration: u64,
+
+    /// The number of L1 blocks before the channel timeout at which a channel is resubmitted.
+    #[arg(
+        long = "sub-safety-margin",
+        env = "KONA_BATCHER_SUB_SAFETY_MARGIN",
+        default_value_t = 10
+    )]
+    pub sub_safety_margin: u64,
+
+    /// The type of batches written to channels.
+    #[arg(
+        long = "batch-type",
+        env = "KONA_BATCHER_BATCH_TYPE",
+        value_enum,
+        default_value_t = BatchTypeArg::Single
+    )]
+    pub batch_type: BatchTypeArg,
+
+    /// The compressor deciding when a channel is full.
+    #[arg(
+        long = "compressor",
+        env = "KONA_BATCHER_COMPRESSOR",
+        value_enum,
+        default_value_t = CompressorArg::Shadow
+    )]
+    pub compressor: CompressorArg,
+
+    /// The compression algorithm: zlib, brotli-9, brotli-10 or brotli-11.
+    #[arg(
+        long = "compression-algo",
+        env = "KONA_BATCHER_COMPRESSION_ALGO",
+        default_value = "zlib",
+        value_parser = parse_compression_algo
+    )]
+    pub compression_algo: CompressionAlgo,
+
+    /// The compression ratio assumed by the ratio compressor.
+    #[arg(
+        long = "approx-compr-ratio",
+        env = "KONA_BATCHER_APPROX_COMPR_RATIO",
+        default_value_t = DEFAULT_APPROX_COMPR_RATIO
+    )]
+    pub approx_compr_ratio: f64,
+
+    /// The number of L1 blocks on top of the inclusion block after which a transaction is
+    /// confirmed.
+    #[arg(
+        long = "num-confirmations",
+        env = "KONA_BATCHER_NUM_CONFIRMATIONS",
+        default_value_t = 10
+    )]
+    pub num_confirmations: u64,
+
+    /// The number of L1 blocks after which a transaction that was not included is resubmitted
+    /// with bumped fees.
+    #[arg(
+        long = "resubmission-timeout",
+        env = "KONA_BATCHER_RESUBMISSION_TIMEOUT",
+        default_value_t = 4
+    )]
+    pub resubmission_timeout: u64,
+
+    /// The maximum number of transactions in flight. Must be at least one.
+    #[arg(
+        long = "max-pending-tx",
+        env = "KONA_BATCHER_MAX_PENDING_TX",
+        default_value_t = 1,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    pub max_pending_txs: usize,
+
+    /// The interval at which L1 and the rollup node are polled, in seconds.
+    #[arg(long = "poll-interval", env = "KONA_BATCHER_POLL_INTERVAL", default_value_t = 6)]
+    pub poll_interval: u64,
+
+    /// Wait for `admin_startBatcher` before submitting.
+    #[arg(long = "stopped", env = "KONA_BATCHER_STOPPED", default_value_t = false)]
+    pub stopped: bool,
+
+    /// IP address for the admin RPC server to listen on.
+    #[arg(long = "rpc.addr", env = "KONA_BATCHER_RPC_ADDR", default_value = "127.0.0.1")]
+    pub rpc_address: IpAddr,
+
+    /// Port for the admin RPC server to listen on.
+    #[arg(long = "rpc.port", env = "KONA_BATCHER_RPC_PORT", default_value_t = 8548)]
+    pub rpc_port: u16,
+}
+
+impl BatcherArgs {
+    /// Builds the [`BatcherConfig`] from the arguments.
+    pub fn config(&self) -> Result<BatcherConfig> {
+        let rollup_config =
+            Arc::new(load_rollup_config(self.rollup_config.as_deref(), self.l2_chain_id)?);
+
+        let mut channel = match self.da_type {
+            DataAvailabilityArg::Calldata => {
+                if self.target_num_frames != 1 {
+                    bail!("Calldata transactions carry a single frame");
+                }
+                ChannelConfig::calldata()
+            }
+            DataAvailabilityArg::Blobs => ChannelConfig::blobs(self.target_num_frames),
+        }
+        .with_max_channel_duration(self.max_channel_duration)
+        .with_sub_safety_margin(self.sub_safety_margin)
+        .with_batch_type(self.batch_type.into());
+        if let Some(max_frame_size) = self.max_frame_size {
+            channel = channel.with_max_frame_size(max_frame_size);
+        }
+        channel.validate().context("Invalid channel config")?;
+
+        Ok(BatcherConfig {
+            rollup_config,
+            channel,
+            compressor_kind: self.compressor.into(),
+            compression_algo: self.compression_algo,
+            approx_compr_ratio: self.approx_compr_ratio,
+            tx_manager: TxManagerConfig {
+                num_confirmations: self.num_confirmations,
+                resubmission_timeout: self.resubmission_timeout,
+                max_pending_txs: self.max_pending_txs,
+            },
+            poll_interval: Duration::from_secs(self.poll_interval),
+            start_stopped: self.stopped,
+            rpc_addr: SocketAddr::new(self.rpc_address, self.rpc_port),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestCli {
+        #[command(flatten)]
+        batcher: BatcherArgs,
+    }
+
+    fn parse(args: &[&str]) -> Result<TestCli, clap::Error> {
+        let required = [
+            "test",
+            "--l1-eth-rpc",
+            "http://localhost:8545",
+            "--l2-eth-rpc",
+            "http://localhost:9545",
+            "--rollup-rpc",
+            "http://localhost:7545",
+        ];
+        TestCli::try_parse_from(required.iter().chain(args))
+    }
+
+    #[test]
+    fn test_default_args() {
+        let cli = parse(&[]).unwrap();
+        assert_eq!(cli.batcher.max_pending_txs, 1);
+        assert_eq!(cli.batcher.batch_type, BatchTypeArg::Single);
+    }
+
+    #[test]
+    fn test_zero_max_pending_txs_rejected() {
+        assert!(parse(&["--max-pending-tx", "0"]).is_err());
+        assert_eq!(parse(&["--max-pending-tx", "4"]).unwrap().batcher.max_pending_txs, 4);
+    }
+
+    #[test]
+    fn test_span_batch_type() {
+        let cli = parse(&["--batch-type", "span"]).unwrap();
+        assert_eq!(cli.batcher.batch_type, BatchTypeArg::Span);
+    }
+}
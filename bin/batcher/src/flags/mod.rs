@@ -0,0 +1,7 @@
+//! CLI Flags
+
+mod batcher;
+pub use batcher::BatcherArgs;
+
+mod signer;
+pub use signer::{SignerArgs, SignerArgsParseError};
@@ -0,0 +1,180 @@
+use alloy_primitives::{Address, B256};
+use alloy_signer::k256::ecdsa;
+use alloy_signer_local::PrivateKeySigner;
+use clap::Args;
+use kona_batcher_service::BatcherSigner;
+use kona_sources::{ClientCert, RemoteSigner};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::{path::PathBuf, str::FromStr};
+use url::Url;
+
+/// Signer CLI Flags
+#[derive(Debug, Clone, Args, Default, PartialEq, Eq)]
+pub struct SignerArgs {
+    /// The private key used to sign batcher transactions.
+    #[arg(long = "private-key", env = "KONA_BATCHER_PRIVATE_KEY", conflicts_with = "endpoint")]
+    pub private_key: Option<B256>,
+    /// The URL of the remote signer endpoint, signing through `eth_signTransaction`.
+    /// This is mutually exclusive with `private-key`.
+    #[arg(long = "signer.endpoint", env = "KONA_BATCHER_SIGNER_ENDPOINT", requires = "address")]
+    pub endpoint: Option<Url>,
+    /// The address of the remote signer. Required if `signer.endpoint` is provided.
+    #[arg(long = "signer.address", env = "KONA_BATCHER_SIGNER_ADDRESS", requires = "endpoint")]
+    pub address: Option<Address>,
+    /// Headers to pass to the remote signer. Format `key=value`. When using env vars, split with
+    /// commas. When using flags one key value pair per flag.
+    #[arg(long = "signer.header", env = "KONA_BATCHER_SIGNER_HEADER", requires = "endpoint")]
+    pub header: Vec<String>,
+    /// An optional path to CA certificates to be used for the remote signer.
+    #[arg(long = "signer.tls.ca", env = "KONA_BATCHER_SIGNER_TLS_CA", requires = "endpoint")]
+    pub ca_cert: Option<PathBuf>,
+    /// An optional path to the client certificate for the remote signer. If specified,
+    /// `signer.tls.key` must also be specified.
+    #[arg(
+        long = "signer.tls.cert",
+        env = "KONA_BATCHER_SIGNER_TLS_CERT",
+        requires = "key",
+        requires = "endpoint"
+    )]
+    pub cert: Option<PathBuf>,
+    /// An optional path to the client key for the remote signer. If specified,
+    /// `signer.tls.cert` must also be specified.
+    #[arg(
+        long = "signer.tls.key",
+        env = "KONA_BATCHER_SIGNER_TLS_KEY",
+        requires = "cert",
+        requires = "endpoint"
+    )]
+    pub key: Option<PathBuf>,
+}
+
+/// Errors that can occur when parsing the signer arguments.
+#[derive(Debug, thiserror::Error)]
+pub enum SignerArgsParseError {
+    /// Neither a private key nor a remote signer was specified.
+    #[error("Either a private key or a remote signer must be specified.")]
+    MissingSigner,
+    /// The private key and remote signer cannot be specified at the same time.
+    #[error("A private key and a remote signer cannot be specified at the same time.")]
+    LocalAndRemoteSigner,
+    /// The private key is invalid.
+    #[error("The private key is invalid.")]
+    PrivateKeyInvalid(#[from] ecdsa::Error),
+    /// The address is required if `signer.endpoint` is provided.
+    #[error("The address is required if `signer.endpoint` is provided.")]
+    AddressRequired,
+    /// The header is invalid.
+    #[error("The header is invalid.")]
+    InvalidHeader,
+    /// The private key field is required if `signer.tls.cert` is provided.
+    #[error("The private key field is required if `signer.tls.cert` is provided.")]
+    KeyRequired,
+    /// The header name is invalid.
+    #[error("The header name is invalid.")]
+    InvalidHeaderName(#[from] reqwest::header::InvalidHeaderName),
+    /// The header value is invalid.
+    #[error("The header value is invalid.")]
+    InvalidHeaderValue(#[from] reqwest::header::InvalidHeaderValue),
+}
+
+impl SignerArgs {
+    /// Creates a [`BatcherSigner`] from the [`SignerArgs`].
+    pub fn signer(self) -> Result<BatcherSigner, SignerArgsParseError> {
+        let private_key = self.private_key;
+        match (private_key, self.config_remote()?) {
+            (Some(_), Some(_)) => Err(SignerArgsParseError::LocalAndRemoteSigner),
+            (Some(key), None) => Ok(PrivateKeySigner::from_bytes(&key)?.into()),
+            (None, Some(signer)) => Ok(signer.into()),
+            (None, None) => Err(SignerArgsParseError::MissingSigner),
+        }
+    }
+
+    /// Creates a [`RemoteSigner`] from the [`SignerArgs`].
+    fn config_remote(self) -> Result<Option<RemoteSigner>, SignerArgsParseError> {
+        let Some(endpoint) = self.endpoint else {
+            return Ok(None);
+        };
+
+        let Some(address) = self.address else {
+            return Err(SignerArgsParseError::AddressRequired);
+        };
+
+        let headers = self
+            .header
+            .iter()
+            .map(|h| {
+                let (key, value) = h.split_once('=').ok_or(SignerArgsParseError::InvalidHeader)?;
+                Ok((HeaderName::from_str(key)?, HeaderValue::from_str(value)?))
+            })
+            .collect::<Result<HeaderMap, SignerArgsParseError>>()?;
+
+        let client_cert = self
+            .cert
+            .map(|cert| {
+                Ok::<_, SignerArgsParseError>(ClientCert {
+                    cert,
+                    key: self.key.clone().ok_or(SignerArgsParseError::KeyRequired)?,
+                })
+            })
+            .transpose()?;
+
+        Ok(Some(RemoteSigner { address, endpoint, ca_cert: self.ca_cert, client_cert, headers }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestCli {
+        #[command(flatten)]
+        signer: SignerArgs,
+    }
+
+    #[test]
+    fn test_local_signer() {
+        let key = B256::with_last_byte(1);
+        let cli = TestCli::parse_from(["test", "--private-key", &key.to_string()]);
+        assert!(matches!(cli.signer.signer(), Ok(BatcherSigner::Local(_))));
+    }
+
+    #[test]
+    fn test_remote_signer() {
+        let cli = TestCli::parse_from([
+            "test",
+            "--signer.endpoint",
+            "http://localhost:8080",
+            "--signer.address",
+            "0x0000000000000000000000000000000000000001",
+            "--signer.header",
+            "x-api-key=secret",
+        ]);
+        let Ok(BatcherSigner::Remote(signer)) = cli.signer.signer() else {
+            panic!("expected a remote signer");
+        };
+        assert_eq!(signer.address, Address::with_last_byte(1));
+        assert_eq!(signer.headers.get("x-api-key").unwrap(), "secret");
+    }
+
+    #[test]
+    fn test_missing_signer() {
+        let cli = TestCli::parse_from(["test"]);
+        assert!(matches!(cli.signer.signer(), Err(SignerArgsParseError::MissingSigner)));
+    }
+
+    #[test]
+    fn test_local_and_remote_conflict() {
+        let result = TestCli::try_parse_from([
+            "test",
+            "--private-key",
+            &B256::with_last_byte(1).to_string(),
+            "--signer.endpoint",
+            "http://localhost:8080",
+            "--signer.address",
+            "0x0000000000000000000000000000000000000001",
+        ]);
+        assert!(result.is_err());
+    }
+}
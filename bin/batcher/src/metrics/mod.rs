@@ -0,0 +1,4 @@
+//! Metrics module
+
+mod version;
+pub use version::VersionInfo;
@@ -0,0 +1,70 @@
+//! Contains the compression analyzer CLI.
+
+use crate::flags::{PriceArgs, SourceArgs, StrategyArgs};
+use anyhow::Result;
+use clap::Parser;
+use kona_batcher_analyzer::Analyzer;
+use kona_cli::{LogArgs, LogConfig, cli_styles};
+use tracing::info;
+
+/// CLI replaying L2 blocks through batcher compression strategies.
+#[derive(Parser, Debug)]
+#[command(
+    name = "kona-comp-analyzer",
+    about = "Compares batcher compression strategies on real L2 blocks",
+    styles = cli_styles()
+)]
+pub struct Cli {
+    /// Global args
+    #[command(flatten)]
+    pub global: LogArgs,
+
+    /// Block source args
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    /// Strategy args
+    #[command(flatten)]
+    pub strategies: StrategyArgs,
+
+    /// L1 price args
+    #[command(flatten)]
+    pub prices: PriceArgs,
+}
+
+impl Cli {
+    /// Runs the CLI.
+    pub fn run(self) -> Result<()> {
+        self.init_logs(&self.global)?;
+
+        let rollup_config = self.source.rollup_config()?;
+        let strategies = self.strategies.strategies()?;
+        let blocks = Self::tokio_runtime()?.block_on(self.source.blocks())?;
+        info!(
+            target: "analyzer",
+            blocks = blocks.len(),
+            strategies = strategies.len(),
+            "Replaying blocks"
+        );
+
+        let report = Analyzer::new(&rollup_config, self.prices.prices())
+            .with_strategies(strategies)
+            .analyze(&blocks)?;
+        print!("{report}");
+        Ok(())
+    }
+
+    /// Creates a new default tokio multi-thread [`Runtime`](tokio::runtime::Runtime) with all
+    /// features enabled
+    pub fn tokio_runtime() -> Result<tokio::runtime::Runtime, std::io::Error> {
+        tokio::runtime::Builder::new_multi_thread().enable_all().build()
+    }
+
+    /// Initializes the telemetry stack.
+    pub fn init_logs(&self, args: &LogArgs) -> Result<()> {
+        let filter = tracing_subscriber::EnvFilter::from_default_env();
+
+        LogConfig::new(args.clone()).init_tracing_subscriber(Some(filter))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+//! CLI Flags
+
+mod prices;
+pub use prices::PriceArgs;
+
+mod source;
+pub use source::SourceArgs;
+
+mod strategy;
+pub use strategy::StrategyArgs;
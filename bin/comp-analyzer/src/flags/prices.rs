@@ -0,0 +1,26 @@
+use clap::Args;
+use kona_batcher_analyzer::L1Prices;
+
+/// Arguments setting the L1 prices the batcher transactions are priced at.
+#[derive(Args, Debug)]
+pub struct PriceArgs {
+    /// The L1 base fee, in wei.
+    #[arg(long = "l1-base-fee", default_value_t = 1_000_000_000)]
+    pub l1_base_fee: u128,
+
+    /// The L1 blob base fee, in wei.
+    #[arg(long = "l1-blob-base-fee", default_value_t = 1)]
+    pub l1_blob_base_fee: u128,
+
+    /// Price calldata without the EIP-7623 calldata floor, as before Pectra.
+    #[arg(long = "no-calldata-floor", default_value_t = false)]
+    pub no_calldata_floor: bool,
+}
+
+impl PriceArgs {
+    /// Returns the L1 prices.
+    pub const fn prices(&self) -> L1Prices {
+        L1Prices::new(self.l1_base_fee, self.l1_blob_base_fee)
+            .with_calldata_floor(!self.no_calldata_floor)
+    }
+}
@@ -0,0 +1,141 @@
+use alloy_provider::RootProvider;
+use anyhow::{Context as _, Result, bail};
+use clap::Args;
+use kona_batcher_analyzer::{fetch_blocks, into_op_block, read_fixture, write_fixture};
+use kona_comp::load_rollup_config;
+use kona_genesis::RollupConfig;
+use op_alloy_consensus::OpBlock;
+use op_alloy_network::Optimism;
+use std::{fs::File, io::BufReader, path::PathBuf};
+use tracing::info;
+use url::Url;
+
+/// Arguments selecting the L2 blocks to replay.
+#[derive(Args, Debug)]
+pub struct SourceArgs {
+    /// HTTP provider URL for the L2 execution client to fetch the blocks from.
+    #[arg(
+        long = "l2-eth-rpc",
+        env = "KONA_COMP_ANALYZER_L2_ETH_RPC",
+        requires_all = ["from", "to"],
+        required_unless_present = "fixture",
+        conflicts_with = "fixture"
+    )]
+    pub l2_eth_rpc: Option<Url>,
+
+    /// The first L2 block to fetch.
+    #[arg(long = "from")]
+    pub from: Option<u64>,
+
+    /// The last L2 block to fetch, inclusive.
+    #[arg(long = "to")]
+    pub to: Option<u64>,
+
+    /// Path to a fixture holding a JSON array of `eth_getBlockByNumber` results with full
+    /// transactions, replayed instead of fetching blocks.
+    #[arg(long = "fixture")]
+    pub fixture: Option<PathBuf>,
+
+    /// Path to store the fetched blocks as a fixture.
+    #[arg(long = "save-fixture", requires = "l2_eth_rpc")]
+    pub save_fixture: Option<PathBuf>,
+
+    /// The L2 chain ID, used to load the rollup config from the superchain registry.
+    #[arg(long = "l2-chain-id", env = "KONA_COMP_ANALYZER_L2_CHAIN_ID", default_value_t = 10)]
+    pub l2_chain_id: u64,
+
+    /// Path to a custom rollup config file, overriding the superchain registry.
+    #[arg(long = "rollup-config", env = "KONA_COMP_ANALYZER_ROLLUP_CONFIG")]
+    pub rollup_config: Option<PathBuf>,
+}
+
+impl SourceArgs {
+    /// Loads the blocks, either from the fixture or from the L2 execution client.
+    pub async fn blocks(&self) -> Result<Vec<OpBlock>> {
+        if let Some(path) = &self.fixture {
+            let file = File::open(path)
+                .with_context(|| format!("Failed to open fixture {}", path.display()))?;
+            return read_fixture(BufReader::new(file)).context("Failed to read fixture");
+        }
+
+        let (Some(url), Some(from), Some(to)) = (&self.l2_eth_rpc, self.from, self.to) else {
+            bail!("Either a fixture or an L2 RPC with a block range is required");
+        };
+        if from > to {
+            bail!("Invalid block range {from}..={to}");
+        }
+
+        info!(target: "analyzer", from, to, "Fetching L2 blocks");
+        let provider = RootProvider::<Optimism>::new_http(url.clone());
+        let blocks = fetch_blocks(&provider, from..=to).await?;
+        if let Some(path) = &self.save_fixture {
+            let file = File::create(path)
+                .with_context(|| format!("Failed to create fixture {}", path.display()))?;
+            write_fixture(file, &blocks).context("Failed to write fixture")?;
+            info!(target: "analyzer", path = %path.display(), "Saved fixture");
+        }
+        Ok(blocks.into_iter().map(into_op_block).collect())
+    }
+
+    /// Loads the rollup config, either from a file or from the superchain registry.
+    pub fn rollup_config(&self) -> Result<RollupConfig> {
+        Ok(load_rollup_config(self.rollup_config.as_deref(), self.l2_chain_id)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    struct TestCli {
+        #[command(flatten)]
+        source: SourceArgs,
+    }
+
+    #[test]
+    fn test_rpc_requires_range() {
+        assert!(
+            TestCli::try_parse_from(["test", "--l2-eth-rpc", "http://localhost:9545"]).is_err()
+        );
+        let cli = TestCli::parse_from([
+            "test",
+            "--l2-eth-rpc",
+            "http://localhost:9545",
+            "--from",
+            "1",
+            "--to",
+            "10",
+        ]);
+        assert_eq!((cli.source.from, cli.source.to), (Some(1), Some(10)));
+    }
+
+    #[test]
+    fn test_requires_source() {
+        assert!(TestCli::try_parse_from(["test"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "--fixture", "blocks.json"]).is_ok());
+    }
+
+    #[test]
+    fn test_fixture_conflicts_with_rpc() {
+        let result = TestCli::try_parse_from([
+            "test",
+            "--fixture",
+            "blocks.json",
+            "--l2-eth-rpc",
+            "http://localhost:9545",
+            "--from",
+            "1",
+            "--to",
+            "10",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_registry_rollup_config() {
+        let cli = TestCli::parse_from(["test", "--fixture", "blocks.json"]);
+        assert_eq!(cli.source.rollup_config().unwrap().l2_chain_id.id(), 10);
+    }
+}
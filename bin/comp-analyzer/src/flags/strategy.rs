@@ -0,0 +1,187 @@
+use anyhow::{Context as _, Result};
+use clap::Args;
+use kona_batcher_analyzer::Strategy;
+use kona_batcher_core::{ChannelConfig, MAX_BLOB_FRAME_SIZE, MAX_CALLDATA_FRAME_SIZE};
+use kona_comp::{
+    BatchTypeArg, CompressionAlgo, CompressorArg, DEFAULT_APPROX_COMPR_RATIO, DataAvailabilityArg,
+    parse_compression_algo,
+};
+
+/// Arguments selecting the strategies to compare. Every combination of the given values is
+/// replayed.
+#[derive(Args, Debug)]
+pub struct StrategyArgs {
+    /// The compressors to compare.
+    #[arg(
+        long = "compressors",
+        value_enum,
+        value_delimiter = ',',
+        default_values = ["ratio", "shadow"]
+    )]
+    pub compressors: Vec<CompressorArg>,
+
+    /// The compression algorithms to compare: zlib, brotli-9, brotli-10 or brotli-11.
+    #[arg(
+        long = "compression-algos",
+        value_delimiter = ',',
+        default_values = ["zlib", "brotli-9", "brotli-10", "brotli-11"],
+        value_parser = parse_compression_algo
+    )]
+    pub compression_algos: Vec<CompressionAlgo>,
+
+    /// The batch types to compare.
+    #[arg(
+        long = "batch-types",
+        value_enum,
+        value_delimiter = ',',
+        default_values = ["single", "span"]
+    )]
+    pub batch_types: Vec<BatchTypeArg>,
+
+    /// The data availability types to compare.
+    #[arg(
+        long = "data-availability-types",
+        value_enum,
+        value_delimiter = ',',
+        default_values = ["calldata", "blobs"]
+    )]
+    pub da_types: Vec<DataAvailabilityArg>,
+
+    /// The numbers of blobs per transaction to compare. Calldata transactions always carry a
+    /// single frame.
+    #[arg(long = "target-num-frames", value_delimiter = ',', default_values = ["6"])]
+    pub target_num_frames: Vec<usize>,
+
+    /// The maximum frame sizes to compare, in bytes. Defaults to the largest frame of each data
+    /// availability type.
+    #[arg(long = "max-frame-sizes", value_delimiter = ',')]
+    pub max_frame_sizes: Vec<usize>,
+
+    /// The number of L1 blocks a channel may stay open. Zero disables the limit.
+    #[arg(long = "max-channel-duration", default_value_t = 0)]
+    pub max_channel_duration: u64,
+
+    /// The compression ratio assumed by the ratio compressor.
+    #[arg(long = "approx-compr-ratio", default_value_t = DEFAULT_APPROX_COMPR_RATIO)]
+    pub approx_compr_ratio: f64,
+}
+
+impl StrategyArgs {
+    /// Returns the channel configs to compare.
+    pub fn channels(&self) -> Result<Vec<ChannelConfig>> {
+        let frame_sizes = |max: usize| {
+            if self.max_frame_sizes.is_empty() { vec![max] } else { self.max_frame_sizes.clone() }
+        };
+
+        let mut channels = Vec::new();
+        for da_type in &self.da_types {
+            match da_type {
+                DataAvailabilityArg::Calldata => {
+                    for size in frame_sizes(MAX_CALLDATA_FRAME_SIZE) {
+                        channels.push(ChannelConfig::calldata().with_max_frame_size(size));
+                    }
+                }
+                DataAvailabilityArg::Blobs => {
+                    for &num_frames in &self.target_num_frames {
+                        for size in frame_sizes(MAX_BLOB_FRAME_SIZE) {
+                            channels
+                                .push(ChannelConfig::blobs(num_frames).with_max_frame_size(size));
+                        }
+                    }
+                }
+            }
+        }
+
+        channels
+            .into_iter()
+            .map(|channel| {
+                let channel = channel.with_max_channel_duration(self.max_channel_duration);
+                channel
+                    .validate()
+                    .with_context(|| format!("Invalid channel config {channel:?}"))?;
+                Ok(channel)
+            })
+            .collect()
+    }
+
+    /// Returns every combination of compressor, compression algorithm, batch type and channel
+    /// config.
+    pub fn strategies(&self) -> Result<Vec<Strategy>> {
+        let compressors: Vec<_> = self.compressors.iter().map(|&arg| arg.into()).collect();
+        let batch_types: Vec<_> = self.batch_types.iter().map(|&arg| arg.into()).collect();
+        Ok(Strategy::matrix(&compressors, &self.compression_algos, &batch_types, &self.channels()?)
+            .into_iter()
+            .map(|strategy| strategy.with_approx_compr_ratio(self.approx_compr_ratio))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use kona_comp::CompressorType;
+    use kona_protocol::BatchType;
+
+    #[derive(Parser, Debug)]
+    struct TestCli {
+        #[command(flatten)]
+        strategies: StrategyArgs,
+    }
+
+    #[test]
+    fn test_default_strategies() {
+        let cli = TestCli::parse_from(["test"]);
+        // 2 compressors, 4 algorithms, 2 batch types, calldata and 6 blobs per transaction.
+        assert_eq!(cli.strategies.strategies().unwrap().len(), 32);
+    }
+
+    #[test]
+    fn test_strategy_matrix() {
+        let cli = TestCli::parse_from([
+            "test",
+            "--compressors",
+            "shadow",
+            "--compression-algos",
+            "zlib,brotli-11",
+            "--batch-types",
+            "span",
+            "--data-availability-types",
+            "blobs",
+            "--target-num-frames",
+            "1,3,6",
+            "--max-frame-sizes",
+            "100000,130043",
+            "--approx-compr-ratio",
+            "0.4",
+        ]);
+        let strategies = cli.strategies.strategies().unwrap();
+        assert_eq!(strategies.len(), 2 * 3 * 2);
+        assert!(strategies.iter().all(|strategy| strategy.compressor == CompressorType::Shadow));
+        assert!(strategies.iter().all(|strategy| strategy.approx_compr_ratio == 0.4));
+        assert!(strategies.iter().all(|strategy| strategy.channel.batch_type == BatchType::Span));
+        assert_eq!(strategies[0].label(), "shadow/zlib/span/blobs:1x100000");
+    }
+
+    #[test]
+    fn test_invalid_channel() {
+        let cli = TestCli::parse_from([
+            "test",
+            "--data-availability-types",
+            "blobs",
+            "--max-frame-sizes",
+            "200000",
+        ]);
+        assert!(cli.strategies.strategies().is_err());
+    }
+
+    #[test]
+    fn test_unknown_batch_type() {
+        assert!(TestCli::try_parse_from(["test", "--batch-types", "double"]).is_err());
+    }
+
+    #[test]
+    fn test_unknown_algo() {
+        assert!(TestCli::try_parse_from(["test", "--compression-algos", "lz4"]).is_err());
+    }
+}
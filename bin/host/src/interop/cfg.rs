@@ -9,7 +9,7 @@ use crate::{
 use alloy_primitives::{B256, Bytes};
 use alloy_provider::{Provider, RootProvider};
 use clap::Parser;
-use kona_cli::cli_styles;
+use kona_cli::{CliError, cli_styles, validate_rollup_config};
 use kona_genesis::{L1ChainConfig, RollupConfig};
 use kona_preimage::{
     BidirectionalChannel, Channel, HintReader, HintWriter, OracleReader, OracleServer,
@@ -124,6 +124,9 @@ pub enum InteropHostError {
     /// An error when no provider found for chain ID.
     #[error("No provider found for chain ID: {0}")]
     RootProviderError(u64),
+    /// The rollup config failed validation.
+    #[error(transparent)]
+    InvalidRollupConfig(#[from] CliError),
     /// Any other error.
     #[error("Error: {0}")]
     Other(&'static str),
@@ -132,6 +135,8 @@ pub enum InteropHostError {
 impl InteropHost {
     /// Starts the [InteropHost] application.
     pub async fn start(self) -> Result<(), InteropHostError> {
+        self.check_rollup_configs()?;
+
         if self.server {
             let hint = FileChannel::new(FileDescriptor::HintRead, FileDescriptor::HintWrite);
             let preimage =
@@ -143,6 +148,16 @@ impl InteropHost {
         }
     }
 
+    /// Validates the [RollupConfig]s passed with `--rollup-config-paths`, if any.
+    pub fn check_rollup_configs(&self) -> Result<(), InteropHostError> {
+        if let Some(configs) = self.read_rollup_configs() {
+            for config in configs?.values() {
+                validate_rollup_config(config)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Starts the preimage server, communicating with the client over the provided channels.
     async fn start_server<C>(
         &self,
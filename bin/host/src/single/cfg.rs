@@ -9,7 +9,7 @@ use crate::{
 use alloy_primitives::B256;
 use alloy_provider::RootProvider;
 use clap::Parser;
-use kona_cli::cli_styles;
+use kona_cli::{CliError, cli_styles, validate_rollup_config};
 use kona_genesis::{L1ChainConfig, RollupConfig};
 use kona_preimage::{
     BidirectionalChannel, Channel, HintReader, HintWriter, OracleReader, OracleServer,
@@ -137,6 +137,9 @@ pub enum SingleChainHostError {
     /// No l1 config found.
     #[error("No l1 config found")]
     NoL1Config,
+    /// The rollup config failed validation.
+    #[error(transparent)]
+    InvalidRollupConfig(#[from] CliError),
     /// Any other error.
     #[error("Error: {0}")]
     Other(&'static str),
@@ -145,6 +148,8 @@ pub enum SingleChainHostError {
 impl SingleChainHost {
     /// Starts the [SingleChainHost] application.
     pub async fn start(self) -> Result<(), SingleChainHostError> {
+        self.check_rollup_config()?;
+
         if self.server {
             let hint = FileChannel::new(FileDescriptor::HintRead, FileDescriptor::HintWrite);
             let preimage =
@@ -156,6 +161,14 @@ impl SingleChainHost {
         }
     }
 
+    /// Validates the [RollupConfig] passed with `--rollup-config-path`, if any.
+    pub fn check_rollup_config(&self) -> Result<(), SingleChainHostError> {
+        if self.rollup_config_path.is_some() {
+            validate_rollup_config(&self.read_rollup_config()?)?;
+        }
+        Ok(())
+    }
+
     /// Starts the preimage server, communicating with the client over the provided channels.
     pub async fn start_server<C>(
         &self,
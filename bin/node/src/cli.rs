@@ -1,7 +1,9 @@
 //! Contains the node CLI.
 
 use crate::{
-    commands::{BootstoreCommand, InfoCommand, NetCommand, NodeCommand, RegistryCommand},
+    commands::{
+        BootstoreCommand, CheckConfigCommand, InfoCommand, NetCommand, NodeCommand, RegistryCommand,
+    },
     flags::{GlobalArgs, init_unified_metrics},
     version,
 };
@@ -27,6 +29,9 @@ pub enum Commands {
     Bootstore(BootstoreCommand),
     /// Get info about op chain.
     Info(InfoCommand),
+    /// Validates a rollup config without starting the node.
+    #[command(alias = "check")]
+    CheckConfig(CheckConfigCommand),
 }
 
 /// The node CLI.
@@ -58,6 +63,7 @@ impl Cli {
             Commands::Registry(ref registry) => registry.init_logs(&self.global)?,
             Commands::Bootstore(ref bootstore) => bootstore.init_logs(&self.global)?,
             Commands::Info(ref info) => info.init_logs(&self.global)?,
+            Commands::CheckConfig(ref check) => check.init_logs(&self.global)?,
         }
 
         // Initialize unified metrics
@@ -78,6 +84,7 @@ impl Cli {
             Commands::Registry(registry) => registry.run(&self.global),
             Commands::Bootstore(bootstore) => bootstore.run(&self.global),
             Commands::Info(info) => info.run(&self.global),
+            Commands::CheckConfig(check) => check.run(&self.global),
         }
     }
 
@@ -122,6 +129,8 @@ mod tests {
     #[case::bootstore_subcommand_long(Commands::Bootstore(Default::default()), "boot")]
     #[case::bootstore_subcommand_long2(Commands::Bootstore(Default::default()), "store")]
     #[case::info_subcommand(Commands::Info(Default::default()), "info")]
+    #[case::check_config_subcommand_long(Commands::CheckConfig(Default::default()), "check-config")]
+    #[case::check_config_subcommand_short(Commands::CheckConfig(Default::default()), "check")]
     fn test_parse_cli(#[case] subcommand: Commands, #[case] subcommand_alias: &str) {
         let args = vec!["kona-node", subcommand_alias, "--help"];
         let cli = Cli::parse_from(args);
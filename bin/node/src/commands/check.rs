@@ -0,0 +1,65 @@
+//! Check Config Subcommand
+
+use crate::{commands::NodeCommand, flags::GlobalArgs};
+use clap::Parser;
+use kona_cli::{LogConfig, validate_rollup_config};
+use std::path::PathBuf;
+use tracing::info;
+
+/// The `check-config` Subcommand
+///
+/// The `check-config` subcommand validates a rollup config without starting the node. The config
+/// is read from `--l2-config-file` if set, or from the superchain registry for `--chain`.
+///
+/// # Usage
+///
+/// ```sh
+/// kona-node check-config --l2-config-file rollup.json
+/// ```
+#[derive(Parser, Default, PartialEq, Debug, Clone)]
+#[command(about = "Validates a rollup config without starting the node.")]
+pub struct CheckConfigCommand {
+    /// Path to a custom L2 rollup configuration file.
+    #[arg(long, visible_alias = "rollup-cfg", env = "KONA_NODE_ROLLUP_CONFIG")]
+    pub l2_config_file: Option<PathBuf>,
+}
+
+impl CheckConfigCommand {
+    /// Initializes the logging system based on global arguments.
+    pub fn init_logs(&self, args: &GlobalArgs) -> anyhow::Result<()> {
+        LogConfig::new(args.log_args.clone()).init_tracing_subscriber(None)?;
+        Ok(())
+    }
+
+    /// Runs the subcommand.
+    pub fn run(&self, args: &GlobalArgs) -> anyhow::Result<()> {
+        let cfg = NodeCommand::read_l2_config(self.l2_config_file.as_deref(), args)?;
+        validate_rollup_config(&cfg)?;
+        info!(target: "check_config", chain_id = cfg.l2_chain_id.id(), "Rollup config is valid");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_registry_config() {
+        let args = GlobalArgs { l2_chain_id: 10.into(), ..Default::default() };
+        assert!(CheckConfigCommand::default().run(&args).is_ok());
+    }
+
+    #[test]
+    fn test_check_invalid_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rollup.json");
+        let mut cfg = kona_registry::ROLLUP_CONFIGS[&10].clone();
+        cfg.block_time = 0;
+        std::fs::write(&path, serde_json::to_string(&cfg).unwrap()).unwrap();
+
+        let cmd = CheckConfigCommand { l2_config_file: Some(path) };
+        let err = cmd.run(&GlobalArgs::default()).unwrap_err();
+        assert!(err.to_string().contains("Block time cannot be 0"));
+    }
+}
@@ -14,3 +14,6 @@ pub use net::NetCommand;
 
 mod registry;
 pub use registry::RegistryCommand;
+
+mod check;
+pub use check::CheckConfigCommand;
@@ -8,13 +8,18 @@ use alloy_rpc_types_engine::JwtSecret;
 use anyhow::{Result, bail};
 use backon::{ExponentialBuilder, Retryable};
 use clap::Parser;
-use kona_cli::{LogConfig, MetricsArgs};
+use kona_cli::{LogConfig, MetricsArgs, validate_rollup_config};
+use kona_engine::{EngineEndpoint, SyncMode};
 use kona_genesis::{L1ChainConfig, RollupConfig};
 use kona_node_service::{NodeMode, RollupNode, RollupNodeService};
 use kona_registry::{L1Config, scr_rollup_config_by_alloy_ident};
 use op_alloy_provider::ext::engine::OpEngineApi;
 use serde_json::from_reader;
-use std::{fs::File, path::PathBuf, sync::Arc};
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 use strum::IntoEnumIterator;
 use tracing::{debug, error, info};
 use url::Url;
@@ -79,6 +84,22 @@ pub struct NodeCommand {
         )
     )]
     pub node_mode: NodeMode,
+    /// The strategy used to sync the execution layer.
+    #[arg(
+        long = "syncmode",
+        visible_alias = "sync-mode",
+        default_value_t = SyncMode::ExecutionLayer,
+        env = "KONA_NODE_SYNCMODE",
+        help = format!(
+            "The strategy used to sync the execution layer. Supported modes are: {}",
+            SyncMode::MODES
+                .iter()
+                .map(|mode| format!("\"{mode}\""))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    )]
+    pub sync_mode: SyncMode,
     /// URL of the L1 execution client RPC API.
     #[arg(long, visible_alias = "l1", env = "KONA_NODE_L1_ETH_RPC")]
     pub l1_eth_rpc: Url,
@@ -110,6 +131,31 @@ pub struct NodeCommand {
     /// This MUST be a valid path to a file containing the hex-encoded JWT secret.
     #[arg(long, visible_alias = "l2.jwt-secret", env = "KONA_NODE_L2_ENGINE_AUTH")]
     pub l2_engine_jwt_secret: Option<PathBuf>,
+    /// Path to the engine API IPC socket of the L2 execution client.
+    /// When set, the engine sends all of its calls over this socket instead of the `--l2`
+    /// endpoint, including the L2 block lookups it makes itself. The `--l2` endpoint and
+    /// `--l2.jwt-secret` are still required: they are used to validate the JWT secret at startup,
+    /// and by the derivation pipeline and the sequencer to read L2 blocks.
+    #[arg(long, visible_alias = "l2.ipc", env = "KONA_NODE_L2_ENGINE_IPC")]
+    pub l2_engine_ipc: Option<PathBuf>,
+    /// URL of the engine API endpoint of a shadow L2 execution client.
+    /// The shadow receives the same `engine_newPayload` calls as the primary execution client, as
+    /// well as the `engine_forkchoiceUpdated` calls that carry no payload attributes. Any
+    /// divergence between the two is reported.
+    #[arg(long, visible_alias = "l2.shadow", env = "KONA_NODE_L2_SHADOW_RPC")]
+    pub l2_shadow_rpc: Option<Url>,
+    /// Path to the engine API IPC socket of a shadow L2 execution client.
+    #[arg(
+        long,
+        visible_alias = "l2.shadow.ipc",
+        env = "KONA_NODE_L2_SHADOW_IPC",
+        conflicts_with = "l2_shadow_rpc"
+    )]
+    pub l2_shadow_ipc: Option<PathBuf>,
+    /// JWT secret for the auth-rpc endpoint of the shadow execution client.
+    /// Defaults to the JWT secret of the primary execution client.
+    #[arg(long, visible_alias = "l2.shadow.jwt-secret", env = "KONA_NODE_L2_SHADOW_AUTH")]
+    pub l2_shadow_jwt_secret: Option<PathBuf>,
     /// Path to a custom L2 rollup configuration file
     /// (overrides the default rollup configuration from the registry)
     #[arg(long, visible_alias = "rollup-cfg", env = "KONA_NODE_ROLLUP_CONFIG")]
@@ -138,9 +184,14 @@ impl Default for NodeCommand {
             l2_engine_rpc: Url::parse("http://localhost:8551").unwrap(),
             l2_trust_rpc: true,
             l2_engine_jwt_secret: None,
+            l2_engine_ipc: None,
+            l2_shadow_rpc: None,
+            l2_shadow_ipc: None,
+            l2_shadow_jwt_secret: None,
             l2_config_file: None,
             l1_config_file: None,
             node_mode: NodeMode::Validator,
+            sync_mode: SyncMode::ExecutionLayer,
             p2p_flags: P2PArgs::default(),
             rpc_flags: RpcArgs::default(),
             sequencer_flags: SequencerArgs::default(),
@@ -276,12 +327,14 @@ impl NodeCommand {
     /// Run the Node subcommand.
     pub async fn run(self, args: &GlobalArgs) -> anyhow::Result<()> {
         let cfg = self.get_l2_config(args)?;
+        validate_rollup_config(&cfg)?;
         let l1_cfg = self.get_l1_config(cfg.l1_chain_id)?;
 
         // If metrics are enabled, initialize the global cli metrics.
         args.metrics.enabled.then(|| init_rollup_config_metrics(&cfg));
 
         let jwt_secret = self.validate_jwt(&cfg).await?;
+        let shadow_engine = self.shadow_engine(jwt_secret)?;
 
         self.p2p_flags.check_ports()?;
         let p2p_config = self.p2p_flags.config(&cfg, args, Some(self.l1_eth_rpc.clone())).await?;
@@ -298,12 +351,15 @@ impl NodeCommand {
 
         RollupNode::builder(cfg, l1_cfg)
             .with_mode(self.node_mode)
+            .with_sync_mode(self.sync_mode)
             .with_jwt_secret(jwt_secret)
             .with_l1_provider_rpc_url(self.l1_eth_rpc)
             .with_l1_trust_rpc(self.l1_trust_rpc)
             .with_l1_beacon_api_url(self.l1_beacon)
             .with_l2_engine_rpc_url(self.l2_engine_rpc)
             .with_l2_trust_rpc(self.l2_trust_rpc)
+            .with_l2_engine_ipc(self.l2_engine_ipc)
+            .with_shadow_engine(shadow_engine)
             .with_p2p_config(p2p_config)
             .with_rpc_config(rpc_config)
             .with_sequencer_config(self.sequencer_flags.config())
@@ -339,7 +395,12 @@ impl NodeCommand {
 
     /// Get the L2 rollup config, either from a file or the superchain registry.
     pub fn get_l2_config(&self, args: &GlobalArgs) -> Result<RollupConfig> {
-        match &self.l2_config_file {
+        Self::read_l2_config(self.l2_config_file.as_deref(), args)
+    }
+
+    /// Reads the L2 rollup config from the given file, falling back to the superchain registry.
+    pub fn read_l2_config(path: Option<&Path>, args: &GlobalArgs) -> Result<RollupConfig> {
+        match path {
             Some(path) => {
                 debug!("Loading l2 config from file: {:?}", path);
                 let file = File::open(path)
@@ -368,6 +429,27 @@ impl NodeCommand {
         Self::default_jwt_secret()
     }
 
+    /// Returns the [`EngineEndpoint`] of the shadow execution client, if one is configured.
+    ///
+    /// The shadow uses the primary JWT secret unless `--l2.shadow.jwt-secret` is set.
+    pub fn shadow_engine(&self, primary_jwt: JwtSecret) -> Result<Option<EngineEndpoint>> {
+        if let Some(path) = &self.l2_shadow_ipc {
+            return Ok(Some(EngineEndpoint::Ipc(path.clone())));
+        }
+        let Some(url) = &self.l2_shadow_rpc else {
+            return Ok(None);
+        };
+        let jwt = match &self.l2_shadow_jwt_secret {
+            Some(path) => {
+                let secret = std::fs::read_to_string(path)?;
+                JwtSecret::from_hex(secret.trim())
+                    .map_err(|e| anyhow::anyhow!("Invalid shadow JWT secret: {e}"))?
+            }
+            None => primary_jwt,
+        };
+        Ok(Some(EngineEndpoint::Http { url: url.clone(), jwt }))
+    }
+
     /// Uses the current directory to attempt to read
     /// the JWT secret from a file named `jwt.hex`.
     /// If the file is not found, it will return `None`.
@@ -424,6 +506,59 @@ mod tests {
     fn test_node_cli_defaults() {
         let args = NodeCommand::parse_from(["node"].iter().chain(default_flags().iter()).copied());
         assert_eq!(args.node_mode, NodeMode::Validator);
+        assert_eq!(args.sync_mode, SyncMode::ExecutionLayer);
+    }
+
+    #[test]
+    fn test_node_cli_syncmode() {
+        let args = NodeCommand::parse_from(
+            ["node", "--syncmode", "consensus-layer"].iter().chain(default_flags().iter()).copied(),
+        );
+        assert_eq!(args.sync_mode, SyncMode::ConsensusLayer);
+
+        let err = NodeCommand::try_parse_from(
+            ["node", "--syncmode", "snap"].iter().chain(default_flags().iter()).copied(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Unknown sync mode"));
+    }
+
+    #[test]
+    fn test_node_cli_shadow_engine() {
+        let jwt = JwtSecret::random();
+        let args = NodeCommand::parse_from(["node"].iter().chain(default_flags().iter()).copied());
+        assert!(args.shadow_engine(jwt).unwrap().is_none());
+
+        let args = NodeCommand::parse_from(
+            ["node", "--l2.shadow", "http://localhost:9551"]
+                .iter()
+                .chain(default_flags().iter())
+                .copied(),
+        );
+        assert_eq!(
+            args.shadow_engine(jwt).unwrap(),
+            Some(EngineEndpoint::Http { url: Url::parse("http://localhost:9551").unwrap(), jwt })
+        );
+
+        let args = NodeCommand::parse_from(
+            ["node", "--l2.shadow.ipc", "/tmp/shadow.ipc", "--l2.ipc", "/tmp/engine.ipc"]
+                .iter()
+                .chain(default_flags().iter())
+                .copied(),
+        );
+        assert_eq!(args.l2_engine_ipc, Some(PathBuf::from("/tmp/engine.ipc")));
+        assert_eq!(
+            args.shadow_engine(jwt).unwrap(),
+            Some(EngineEndpoint::Ipc(PathBuf::from("/tmp/shadow.ipc")))
+        );
+
+        let err = NodeCommand::try_parse_from(
+            ["node", "--l2.shadow", "http://localhost:9551", "--l2.shadow.ipc", "/tmp/shadow.ipc"]
+                .iter()
+                .chain(default_flags().iter())
+                .copied(),
+        );
+        assert!(err.is_err());
     }
 
     #[test]
@@ -16,7 +16,7 @@ use kona_disc::LocalNode;
 use kona_genesis::RollupConfig;
 use kona_gossip::GaterConfig;
 use kona_node_service::NetworkConfig;
-use kona_peers::{BootStoreFile, PeerMonitoring, PeerScoreLevel};
+use kona_peers::{BootStoreFile, PeerDbFile, PeerMonitoring, PeerScoreLevel};
 use kona_providers_alloy::AlloyChainProvider;
 use libp2p::identity::Keypair;
 use std::{
@@ -158,6 +158,18 @@ pub struct P2PArgs {
     /// Disables the bootstore.
     #[arg(long = "p2p.no-bootstore", env = "KONA_NODE_P2P_NO_BOOTSTORE")]
     pub disable_bootstore: bool,
+    /// The file to persist the peer database to. Defaults to `~/.kona/<chain_id>/peerdb.json`.
+    ///
+    /// The peer database keeps peer scores, bans and connection history across restarts.
+    #[arg(long = "p2p.peerdb", env = "KONA_NODE_P2P_PEERDB")]
+    pub peer_db: Option<PathBuf>,
+    /// Disables the peer database. Bans and scores are then only kept in memory.
+    #[arg(long = "p2p.no-peerdb", env = "KONA_NODE_P2P_NO_PEERDB")]
+    pub disable_peer_db: bool,
+    /// The duration in hours after which peers that have not been seen are dropped from the peer
+    /// database. Banned peers are kept until their ban expires.
+    #[arg(long = "p2p.peerdb.ttl", default_value = "168", env = "KONA_NODE_P2P_PEERDB_TTL")]
+    pub peer_db_ttl: u64,
     /// Peer Redialing threshold is the maximum amount of times to attempt to redial a peer that
     /// disconnects. By default, peers are *not* redialed. If set to 0, the peer will be
     /// redialed indefinitely.
@@ -396,6 +408,15 @@ impl P2PArgs {
             ))
         };
 
+        let peer_db = if self.disable_peer_db {
+            None
+        } else {
+            Some(self.peer_db.map_or(
+                PeerDbFile::Default { chain_id: args.l2_chain_id.into() },
+                PeerDbFile::Custom,
+            ))
+        };
+
         Ok(NetworkConfig {
             discovery_config,
             discovery_interval: Duration::from_secs(self.discovery_interval),
@@ -409,6 +430,8 @@ impl P2PArgs {
             scoring: self.scoring,
             monitor_peers,
             bootstore,
+            peer_db,
+            peer_db_ttl: Duration::from_secs(self.peer_db_ttl.saturating_mul(60 * 60)),
             topic_scoring: self.topic_scoring,
             gater_config: GaterConfig {
                 peer_redialing: self.peer_redial,
@@ -417,6 +440,7 @@ impl P2PArgs {
             bootnodes: self.bootnodes,
             rollup_config: config.clone(),
             gossip_signer: self.signer.config(args)?,
+            memory_transport: false,
         })
     }
 
@@ -506,6 +530,25 @@ mod tests {
         assert_eq!(args.p2p.discovery_randomize, None);
     }
 
+    #[test]
+    fn test_p2p_args_peer_db() {
+        let args = MockCommand::parse_from(["test"]);
+        assert_eq!(args.p2p.peer_db, None);
+        assert!(!args.p2p.disable_peer_db);
+        assert_eq!(args.p2p.peer_db_ttl, 168);
+        let args = MockCommand::parse_from([
+            "test",
+            "--p2p.peerdb",
+            "peerdb.json",
+            "--p2p.peerdb.ttl",
+            "24",
+        ]);
+        assert_eq!(args.p2p.peer_db, Some(PathBuf::from("peerdb.json")));
+        assert_eq!(args.p2p.peer_db_ttl, 24);
+        let args = MockCommand::parse_from(["test", "--p2p.no-peerdb"]);
+        assert!(args.p2p.disable_peer_db);
+    }
+
     #[test]
     fn test_p2p_args_no_discovery() {
         let args = MockCommand::parse_from(["test", "--p2p.no-discovery"]);
@@ -55,6 +55,7 @@ impl From<RpcArgs> for Option<RpcBuilder> {
         Some(RpcBuilder {
             no_restart: args.no_restart,
             socket: SocketAddr::new(args.listen_addr, args.listen_port),
+            listener: None,
             enable_admin: args.enable_admin,
             admin_persistence: args.admin_persistence,
             ws_enabled: args.ws_enabled,
@@ -5,8 +5,8 @@
 //! [op-node]: https://github.com/ethereum-optimism/optimism/blob/develop/op-node/flags/flags.go#L233-L265
 
 use clap::Parser;
-use kona_node_service::SequencerConfig;
-use std::{num::ParseIntError, time::Duration};
+use kona_node_service::{SequencerConfig, SequencerTxLimits};
+use std::{num::ParseIntError, path::PathBuf, time::Duration};
 use url::Url;
 
 /// Sequencer CLI Flags
@@ -55,6 +55,21 @@ pub struct SequencerArgs {
         value_parser = |arg: &str| -> Result<Duration, ParseIntError> {Ok(Duration::from_secs(arg.parse()?))}
     )]
     pub conductor_rpc_timeout: Duration,
+
+    /// Path to a file of hex-encoded transactions, one per line, that the sequencer includes
+    /// ahead of the transaction pool. The file is consumed on every block.
+    #[arg(long = "sequencer.tx-source-file", env = "KONA_NODE_SEQUENCER_TX_SOURCE_FILE")]
+    pub tx_source_file: Option<PathBuf>,
+
+    /// Maximum estimated DA size, in bytes, of a single transaction supplied by the transaction
+    /// source.
+    #[arg(long = "sequencer.max-da-tx-size", env = "KONA_NODE_SEQUENCER_MAX_DA_TX_SIZE")]
+    pub max_da_tx_size: Option<u64>,
+
+    /// Maximum estimated DA size, in bytes, of all transactions supplied by the transaction
+    /// source in a block.
+    #[arg(long = "sequencer.max-da-block-size", env = "KONA_NODE_SEQUENCER_MAX_DA_BLOCK_SIZE")]
+    pub max_da_block_size: Option<u64>,
 }
 
 impl Default for SequencerArgs {
@@ -73,6 +88,11 @@ impl SequencerArgs {
             sequencer_recovery_mode: self.recover,
             conductor_rpc_url: self.conductor_rpc.clone(),
             l1_conf_delay: self.l1_confs,
+            tx_source_file: self.tx_source_file.clone(),
+            tx_limits: SequencerTxLimits {
+                max_da_tx_size: self.max_da_tx_size,
+                max_da_block_size: self.max_da_block_size,
+            },
         }
     }
 }
@@ -1,16 +1,35 @@
 //! Contains the supervisor CLI.
 
-use crate::{flags::SupervisorArgs, metrics::VersionInfo};
-use anyhow::Result;
-use clap::Parser;
+use crate::{commands::DbCommand, flags::SupervisorArgs, metrics::VersionInfo};
+use anyhow::{Result, anyhow};
+use clap::{ArgMatches, Args, Command, FromArgMatches, Id, Parser, Subcommand, error::Error};
 use kona_cli::{LogArgs, LogConfig, MetricsArgs, cli_styles};
 use kona_supervisor_service::Service;
 use tracing::{error, info};
 
+/// Subcommands of the supervisor CLI.
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum Commands {
+    /// Operates on the supervisor database.
+    Db(DbCommand),
+}
+
 /// CLI for the Rust implementation of the OP Supervisor.
+///
+/// Runs the supervisor, unless a subcommand is given.
 #[derive(Parser, Debug)]
-#[command(name = "op-supervisor", about = "Rust implementation of the OP Supervisor", styles = cli_styles())]
+#[command(
+    name = "op-supervisor",
+    about = "Rust implementation of the OP Supervisor",
+    styles = cli_styles(),
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 pub struct Cli {
+    /// The subcommand to run instead of the supervisor.
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+
     /// Global args
     #[command(flatten)]
     pub global: LogArgs,
@@ -19,14 +38,60 @@ pub struct Cli {
     #[command(flatten)]
     pub metrics: MetricsArgs,
 
-    /// Supervisor args
+    /// Supervisor args, required unless a subcommand is given.
     #[command(flatten)]
-    pub supervisor: SupervisorArgs,
+    pub supervisor: RunArgs,
+}
+
+/// The [`SupervisorArgs`] of a [`Cli`], which are only parsed if no subcommand is given.
+///
+/// Flattening an `Option<SupervisorArgs>` is not enough, since the arguments with default values
+/// would always be present.
+#[derive(Debug)]
+pub struct RunArgs(pub Option<SupervisorArgs>);
+
+impl FromArgMatches for RunArgs {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, Error> {
+        if matches.subcommand().is_some() {
+            return Ok(Self(None));
+        }
+        SupervisorArgs::from_arg_matches(matches).map(|args| Self(Some(args)))
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), Error> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl Args for RunArgs {
+    fn group_id() -> Option<Id> {
+        SupervisorArgs::group_id()
+    }
+
+    fn augment_args(cmd: Command) -> Command {
+        SupervisorArgs::augment_args(cmd)
+    }
+
+    fn augment_args_for_update(cmd: Command) -> Command {
+        SupervisorArgs::augment_args_for_update(cmd)
+    }
 }
 
 impl Cli {
     /// Runs the CLI.
     pub fn run(self) -> Result<()> {
+        if let Some(command) = self.command {
+            return match command {
+                Commands::Db(db) => {
+                    db.init_logs(&self.global)?;
+                    db.run()
+                }
+            };
+        }
+        let supervisor =
+            self.supervisor.0.ok_or_else(|| anyhow!("missing supervisor arguments"))?;
+
         self.metrics.init_metrics()?;
         // Register build metrics
         VersionInfo::from_build().register_version_metrics();
@@ -34,7 +99,7 @@ impl Cli {
         self.init_logs(&self.global)?;
 
         Self::run_until_ctrl_c(async move {
-            let config = self.supervisor.init_config().await?;
+            let config = supervisor.init_config().await?;
             let mut service = Service::new(config);
 
             tokio::select! {
@@ -78,3 +143,59 @@ impl Cli {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{DbCheckCommand, DbSubcommand};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse_supervisor_args() {
+        let cli = Cli::parse_from([
+            "kona-supervisor",
+            "--l1-rpc",
+            "http://localhost:8545",
+            "--datadir",
+            "/supervisor_data",
+            "--dependency-set",
+            "/deps.json",
+            "--rollup-config-paths",
+            "/configs/rollup-*.json",
+        ]);
+        assert!(cli.command.is_none());
+        let supervisor = cli.supervisor.0.expect("supervisor args");
+        assert_eq!(supervisor.datadir, PathBuf::from("/supervisor_data"));
+    }
+
+    #[test]
+    fn test_parse_db_subcommand() {
+        let cli =
+            Cli::parse_from(["kona-supervisor", "db", "check", "--datadir", "/supervisor_data"]);
+        assert!(cli.supervisor.0.is_none());
+        assert_eq!(
+            cli.command,
+            Some(Commands::Db(DbCommand {
+                command: DbSubcommand::Check(DbCheckCommand {
+                    datadir: PathBuf::from("/supervisor_data"),
+                    chain_ids: Vec::new(),
+                    repair: false,
+                }),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_supervisor_args_conflict_with_subcommands() {
+        let result = Cli::try_parse_from([
+            "kona-supervisor",
+            "--l1-rpc",
+            "http://localhost:8545",
+            "db",
+            "check",
+            "--datadir",
+            "/supervisor_data",
+        ]);
+        assert!(result.is_err());
+    }
+}
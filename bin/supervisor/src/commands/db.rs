@@ -0,0 +1,184 @@
+//! Database Subcommand
+
+use alloy_primitives::ChainId;
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use kona_cli::{LogArgs, LogConfig};
+use kona_supervisor_storage::{ChainDb, IntegrityChecker};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// The `db` Subcommand
+///
+/// The `db` subcommand operates on the supervisor database offline. The database is only opened for
+/// writing when it is repaired, in which case the supervisor must not be running on the same data
+/// directory.
+///
+/// # Usage
+///
+/// ```sh
+/// kona-supervisor db check --datadir /supervisor_data [--chain-id 10] [--repair]
+/// ```
+#[derive(Parser, Debug, Clone, PartialEq, Eq)]
+#[command(about = "Operates on the supervisor database.")]
+pub struct DbCommand {
+    /// The database subcommand to run.
+    #[command(subcommand)]
+    pub command: DbSubcommand,
+}
+
+/// Subcommands of the [`DbCommand`].
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum DbSubcommand {
+    /// Checks the consistency of the database of each chain.
+    Check(DbCheckCommand),
+}
+
+/// The `db check` Subcommand
+///
+/// Checks that the log storage, the derivation storage and the safety head references of each
+/// chain are consistent with each other, and reports all violations. The databases are opened
+/// read-only, unless `--repair` is given, in which case the database of an inconsistent chain is
+/// rewound to its last consistent block.
+#[derive(Parser, Debug, Clone, PartialEq, Eq)]
+pub struct DbCheckCommand {
+    /// Directory the supervisor stores its data in.
+    #[arg(long, env = "DATADIR")]
+    pub datadir: PathBuf,
+
+    /// Chains to check. Defaults to all chains with a database in the data directory.
+    #[arg(long = "chain-id", value_delimiter = ',')]
+    pub chain_ids: Vec<ChainId>,
+
+    /// Rewinds the database of inconsistent chains to their last consistent block.
+    #[arg(long, default_value_t = false)]
+    pub repair: bool,
+}
+
+impl DbCommand {
+    /// Initializes the logging system based on the global arguments.
+    pub fn init_logs(&self, args: &LogArgs) -> Result<()> {
+        LogConfig::new(args.clone()).init_tracing_subscriber(None)?;
+        Ok(())
+    }
+
+    /// Runs the database subcommand.
+    pub fn run(self) -> Result<()> {
+        match self.command {
+            DbSubcommand::Check(command) => command.run(),
+        }
+    }
+}
+
+impl DbCheckCommand {
+    /// Runs the integrity check, returning an error if any checked chain is left inconsistent.
+    pub fn run(&self) -> Result<()> {
+        let chain_ids = if self.chain_ids.is_empty() {
+            Self::stored_chain_ids(&self.datadir)?
+        } else {
+            self.chain_ids.clone()
+        };
+
+        let mut inconsistent_chains = Vec::new();
+        for chain_id in chain_ids {
+            let path = self.datadir.join(chain_id.to_string());
+            if !path.is_dir() {
+                anyhow::bail!("no database found for chain {chain_id} at {}", path.display());
+            }
+
+            info!(target: "supervisor::db", chain_id, path = %path.display(), "Checking database");
+            let db = if self.repair {
+                ChainDb::new(chain_id, &path)
+            } else {
+                ChainDb::open_read_only(chain_id, &path)
+            }
+            .with_context(|| format!("failed to open database of chain {chain_id}"))?;
+            let checker = IntegrityChecker::new(&db);
+
+            let report = checker.check()?;
+            if report.is_consistent() {
+                println!("Chain {chain_id}: consistent");
+                continue;
+            }
+
+            println!("Chain {chain_id}: {} violation(s)", report.violations.len());
+            for violation in &report.violations {
+                println!("  - {violation}");
+            }
+
+            if !self.repair {
+                inconsistent_chains.push(chain_id);
+                continue;
+            }
+            match checker.repair()? {
+                Some(to) => println!("Chain {chain_id}: rewound to block {}", to.number),
+                None => {
+                    println!("Chain {chain_id}: cannot be repaired by rewinding");
+                    inconsistent_chains.push(chain_id);
+                }
+            }
+        }
+
+        if !inconsistent_chains.is_empty() {
+            anyhow::bail!("inconsistent databases for chains {inconsistent_chains:?}");
+        }
+        Ok(())
+    }
+
+    /// Returns the ids of the chains with a database in the data directory.
+    fn stored_chain_ids(datadir: &Path) -> Result<Vec<ChainId>> {
+        let mut chain_ids = Vec::new();
+        let entries = std::fs::read_dir(datadir)
+            .with_context(|| format!("failed to read data directory {}", datadir.display()))?;
+        for entry in entries {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            if let Some(chain_id) = entry.file_name().to_str().and_then(|name| name.parse().ok()) {
+                chain_ids.push(chain_id);
+            }
+        }
+        chain_ids.sort_unstable();
+        Ok(chain_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_db_check_command() {
+        let command = DbCommand::parse_from([
+            "kona-supervisor db",
+            "check",
+            "--datadir",
+            "/supervisor_data",
+            "--chain-id",
+            "10,8453",
+            "--repair",
+        ]);
+        let DbSubcommand::Check(check) = command.command;
+        assert_eq!(
+            check,
+            DbCheckCommand {
+                datadir: PathBuf::from("/supervisor_data"),
+                chain_ids: vec![10, 8453],
+                repair: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_stored_chain_ids() {
+        let datadir = tempfile::tempdir().unwrap();
+        for name in ["8453", "10", "not-a-chain"] {
+            std::fs::create_dir(datadir.path().join(name)).unwrap();
+        }
+        std::fs::write(datadir.path().join("11"), b"").unwrap();
+
+        let chain_ids = DbCheckCommand::stored_chain_ids(datadir.path()).unwrap();
+        assert_eq!(chain_ids, vec![10, 8453]);
+    }
+}
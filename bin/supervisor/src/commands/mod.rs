@@ -0,0 +1,4 @@
+//! Subcommands of the supervisor CLI.
+
+mod db;
+pub use db::{DbCheckCommand, DbCommand, DbSubcommand};
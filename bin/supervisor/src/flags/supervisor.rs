@@ -4,11 +4,13 @@ use alloy_rpc_types_engine::JwtSecret;
 use anyhow::{Context as _, Ok, Result, anyhow};
 use clap::Args;
 use glob::glob;
+use kona_cli::validate_rollup_config;
 use kona_genesis::RollupConfig;
 use kona_interop::DependencySet;
 use kona_protocol::BlockInfo;
 use kona_supervisor_core::{
-    config::{Config, RollupConfigSet},
+    config::{Config, EventExportConfig, RollupConfigSet},
+    event::ExportTarget,
     syncnode::ClientConfig,
 };
 use serde::de::DeserializeOwned;
@@ -26,6 +28,9 @@ pub struct SupervisorArgs {
     pub l1_rpc: String,
 
     /// L2 consensus rollup node RPC addresses.
+    ///
+    /// Several nodes may serve the same chain: one of them is used as the primary, and the others
+    /// take over if it becomes unhealthy.
     #[arg(long = "l2-consensus.nodes", env = "L2_CONSENSUS_NODES", value_delimiter = ',')]
     pub l2_consensus_nodes: Vec<String>,
 
@@ -66,6 +71,26 @@ pub struct SupervisorArgs {
     /// Enable the Supervisor Admin API.
     #[arg(long = "rpc.enable-admin", env = "RPC_ENABLE_ADMIN", default_value_t = false)]
     pub enable_admin_api: bool,
+
+    /// Enables pruning of the databases, keeping the given safety margin, in seconds, on top of
+    /// the message expiry window.
+    #[arg(long = "db.prune-safety-margin", env = "DB_PRUNE_SAFETY_MARGIN")]
+    pub prune_safety_margin: Option<u64>,
+
+    /// Enable the export of safety events, including the `supervisor_subscribeSafetyEvents`
+    /// subscription. Implied by `--events.export`.
+    #[arg(long = "events.enable", env = "EVENTS_ENABLE", default_value_t = false)]
+    pub events_enable: bool,
+
+    /// Targets safety events are delivered to as newline-delimited JSON, e.g.
+    /// '/data/events.ndjson', 'tcp://127.0.0.1:9000' or 'unix:///run/events.sock'.
+    #[arg(long = "events.export", env = "EVENTS_EXPORT", value_delimiter = ',')]
+    pub events_export: Vec<ExportTarget>,
+
+    /// Number of recent safety events retained for subscribers resuming from their own cursor.
+    /// Exports lagging further behind keep up to ten times as many pending events.
+    #[arg(long = "events.retention", env = "EVENTS_RETENTION", default_value_t = 10_000)]
+    pub events_retention: usize,
 }
 
 impl SupervisorArgs {
@@ -99,7 +124,9 @@ impl SupervisorArgs {
         let mut rollup_configs = Vec::new();
         for entry in glob(pattern)? {
             let path = entry?;
-            let rollup_config = Self::read_json_file(&path).await?;
+            let rollup_config: RollupConfig = Self::read_json_file(&path).await?;
+            validate_rollup_config(&rollup_config)
+                .with_context(|| format!("Invalid rollup config '{}'", path.display()))?;
             rollup_configs.push(rollup_config);
         }
         Ok(rollup_configs)
@@ -203,8 +230,19 @@ impl SupervisorArgs {
             datadir: self.datadir.clone(),
             rpc_addr,
             enable_admin_api: self.enable_admin_api,
-            dependency_set,
+            dependency_set: dependency_set.into(),
+            dependency_set_path: Some(self.dependency_set.clone()),
             rollup_config_set,
+            prune_safety_margin: self.prune_safety_margin,
+            event_export: self.init_event_export_config(),
+        })
+    }
+
+    /// Returns the safety event export configuration, if enabled.
+    pub fn init_event_export_config(&self) -> Option<EventExportConfig> {
+        (self.events_enable || !self.events_export.is_empty()).then(|| EventExportConfig {
+            retention: self.events_retention,
+            targets: self.events_export.clone(),
         })
     }
 }
@@ -331,6 +369,10 @@ mod tests {
             rpc_address: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         let result = args.init_dependency_set().await;
@@ -338,8 +380,8 @@ mod tests {
 
         let loaded_depset = result.unwrap();
         let mut expected_dependencies = HashMap::default();
-        expected_dependencies.insert(1, ChainDependency {});
-        expected_dependencies.insert(2, ChainDependency {});
+        expected_dependencies.insert(1, ChainDependency::default());
+        expected_dependencies.insert(2, ChainDependency::default());
 
         let expected_depset = DependencySet {
             dependencies: expected_dependencies,
@@ -363,6 +405,10 @@ mod tests {
             rpc_address: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         let result = args.init_dependency_set().await;
@@ -389,6 +435,10 @@ mod tests {
             rpc_address: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         let result = args.init_dependency_set().await;
@@ -463,6 +513,10 @@ mod tests {
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         let configs = args.get_rollup_configs().await?;
@@ -485,6 +539,10 @@ mod tests {
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         let configs = args.get_rollup_configs().await?;
@@ -510,6 +568,10 @@ mod tests {
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         let result = args.get_rollup_configs().await;
@@ -530,6 +592,10 @@ mod tests {
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
         let result = args.get_rollup_configs().await;
         assert!(result.is_err());
@@ -550,6 +616,10 @@ mod tests {
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
         let result = args.init_managed_nodes_config();
         assert!(result.is_err());
@@ -577,6 +647,10 @@ mod tests {
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         let res = args.init_managed_nodes_config();
@@ -607,6 +681,10 @@ mod tests {
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         let res = args.init_managed_nodes_config().unwrap();
@@ -628,6 +706,10 @@ mod tests {
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         let err = args.init_managed_nodes_config().unwrap_err();
@@ -651,6 +733,10 @@ mod tests {
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         let err = args.init_managed_nodes_config().unwrap_err();
@@ -671,6 +757,10 @@ mod tests {
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         let res = args.init_managed_nodes_config();
@@ -762,6 +852,10 @@ mod tests {
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
             enable_admin_api: false,
+            prune_safety_margin: None,
+            events_enable: false,
+            events_export: vec![],
+            events_retention: 10_000,
         };
 
         // This will fail at the L1 RPC call unless you mock RootProvider.
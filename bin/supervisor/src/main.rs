@@ -7,6 +7,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 pub mod cli;
+pub mod commands;
 pub mod flags;
 pub mod metrics;
 pub(crate) mod version;
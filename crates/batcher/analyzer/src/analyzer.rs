@@ -0,0 +1,272 @@
+//! The [`Analyzer`] replays L2 blocks through batcher strategies.
+
+use crate::{AnalysisReport, AnalyzerError, L1Prices, Strategy, StrategyReport};
+use alloy_eips::eip2718::Encodable2718;
+use kona_batcher_core::{ChannelManager, ChannelManagerError};
+use kona_comp::BatcherCompressor;
+use kona_genesis::RollupConfig;
+use kona_protocol::{BlockInfo, L2BlockInfo};
+use op_alloy_consensus::OpBlock;
+use tracing::debug;
+
+/// Replays a range of L2 blocks through a set of [`Strategy`]s and reports the channels, frames
+/// and L1 cost each strategy produces.
+///
+/// Every strategy runs its own [`ChannelManager`], exactly as the batcher would. The L1 head
+/// follows the L1 origin of the replayed blocks, so that
+/// [`ChannelConfig::max_channel_duration`](kona_batcher_core::ChannelConfig::max_channel_duration)
+/// closes channels as it would on a live chain, and every transaction is confirmed right away.
+#[derive(Debug)]
+pub struct Analyzer<'a> {
+    /// The rollup config of the replayed chain.
+    rollup_config: &'a RollupConfig,
+    /// The L1 prices the transactions are priced at.
+    prices: L1Prices,
+    /// The strategies to replay.
+    strategies: Vec<Strategy>,
+}
+
+impl<'a> Analyzer<'a> {
+    /// Creates a new [`Analyzer`] without strategies.
+    pub const fn new(rollup_config: &'a RollupConfig, prices: L1Prices) -> Self {
+        Self { rollup_config, prices, strategies: Vec::new() }
+    }
+
+    /// Adds a strategy to replay.
+    pub fn with_strategy(mut self, strategy: Strategy) -> Self {
+        self.strategies.push(strategy);
+        self
+    }
+
+    /// Adds the given strategies to replay.
+    pub fn with_strategies(mut self, strategies: impl IntoIterator<Item = Strategy>) -> Self {
+        self.strategies.extend(strategies);
+        self
+    }
+
+    /// Returns the strategies to replay.
+    pub fn strategies(&self) -> &[Strategy] {
+        &self.strategies
+    }
+
+    /// Replays the given consecutive blocks through every strategy.
+    pub fn analyze(&self, blocks: &[OpBlock]) -> Result<AnalysisReport, AnalyzerError> {
+        let strategies = self
+            .strategies
+            .iter()
+            .map(|strategy| self.replay(strategy, blocks))
+            .collect::<Result<_, _>>()?;
+        Ok(AnalysisReport { prices: self.prices, strategies })
+    }
+
+    /// Replays the given consecutive blocks through a single strategy.
+    pub fn replay(
+        &self,
+        strategy: &Strategy,
+        blocks: &[OpBlock],
+    ) -> Result<StrategyReport, AnalyzerError> {
+        if blocks.is_empty() {
+            return Err(AnalyzerError::NoBlocks);
+        }
+
+        let compressor = BatcherCompressor::from(strategy.compressor_config());
+        let mut manager =
+            ChannelManager::new(self.rollup_config, strategy.channel.clone(), compressor)?;
+        let mut report = StrategyReport::new(strategy.clone());
+        let mut l1_head: Option<BlockInfo> = None;
+
+        for block in blocks {
+            let info = L2BlockInfo::from_block_and_genesis(block, &self.rollup_config.genesis)
+                .map_err(ChannelManagerError::from)?;
+            if l1_head.is_none_or(|head| head.number < info.l1_origin.number) {
+                let head = BlockInfo {
+                    hash: info.l1_origin.hash,
+                    number: info.l1_origin.number,
+                    timestamp: block.header.timestamp,
+                    ..Default::default()
+                };
+                manager.update_l1_head(head)?;
+                l1_head = Some(head);
+            }
+
+            manager.add_block(block)?;
+            report.blocks += 1;
+            report.input_bytes += block
+                .body
+                .transactions
+                .iter()
+                .filter(|tx| !tx.is_deposit())
+                .map(|tx| tx.encode_2718_len())
+                .sum::<usize>();
+            self.submit(&mut manager, &mut report, l1_head.unwrap_or_default())?;
+        }
+
+        manager.close()?;
+        self.submit(&mut manager, &mut report, l1_head.unwrap_or_default())?;
+
+        debug!(
+            target: "analyzer",
+            strategy = %strategy.label(),
+            channels = report.channels,
+            frames = report.frames,
+            fee = report.fee,
+            "Replayed strategy"
+        );
+        Ok(report)
+    }
+
+    /// Submits and confirms all pending transactions of the manager.
+    fn submit(
+        &self,
+        manager: &mut ChannelManager<'_, BatcherCompressor>,
+        report: &mut StrategyReport,
+        l1_head: BlockInfo,
+    ) -> Result<(), AnalyzerError> {
+        while let Some(tx) = manager.next_tx()? {
+            report.record_tx(&tx, self.prices.tx_cost(&tx));
+            manager.tx_confirmed(tx.id, l1_head);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Block, BlockBody, Header, SignableTransaction, TxLegacy};
+    use alloy_primitives::{Address, Sealed, Signature, TxKind, keccak256};
+    use kona_batcher_core::{ChannelConfig, DataAvailabilityType};
+    use kona_comp::{CompressionAlgo, CompressorType};
+    use kona_protocol::{BatchType, L1BlockInfoBedrock, L1BlockInfoTx};
+    use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
+
+    /// Builds `count` consecutive blocks two seconds apart on top of a default genesis, with an
+    /// L1 info deposit and `num_txs` user transactions each.
+    fn blocks(count: u64, num_txs: u64) -> Vec<OpBlock> {
+        let mut parent = Header::default();
+        (1..=count)
+            .map(|number| {
+                let l1_info = L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+                    number: 0,
+                    block_hash: keccak256([0u8; 8]),
+                    sequence_number: number - 1,
+                    ..Default::default()
+                });
+                let deposit = TxDeposit { input: l1_info.encode_calldata(), ..Default::default() };
+                let mut transactions = vec![OpTxEnvelope::Deposit(Sealed::new(deposit))];
+                transactions.extend((0..num_txs).map(|i| {
+                    let tx = TxLegacy {
+                        chain_id: Some(10),
+                        nonce: number * num_txs + i,
+                        gas_limit: 21_000,
+                        to: TxKind::Call(Address::with_last_byte(i as u8)),
+                        input: keccak256((number * num_txs + i).to_be_bytes()).to_vec().into(),
+                        ..Default::default()
+                    };
+                    OpTxEnvelope::Legacy(tx.into_signed(Signature::test_signature()))
+                }));
+
+                let header = Header {
+                    number,
+                    parent_hash: parent.hash_slow(),
+                    timestamp: number * 2,
+                    ..Default::default()
+                };
+                parent = header.clone();
+                Block {
+                    header,
+                    body: BlockBody { transactions, ommers: vec![], withdrawals: None },
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_no_blocks() {
+        let rollup_config = RollupConfig::default();
+        let analyzer = Analyzer::new(&rollup_config, L1Prices::default()).with_strategy(
+            Strategy::new(CompressorType::Shadow, CompressionAlgo::Zlib, ChannelConfig::calldata()),
+        );
+        assert!(matches!(analyzer.analyze(&[]), Err(AnalyzerError::NoBlocks)));
+    }
+
+    #[test]
+    fn test_replays_every_strategy() {
+        let rollup_config = RollupConfig::default();
+        let strategies = Strategy::matrix(
+            &[CompressorType::Ratio, CompressorType::Shadow],
+            &[CompressionAlgo::Zlib, CompressionAlgo::Brotli10],
+            &[BatchType::Single],
+            &[ChannelConfig::calldata(), ChannelConfig::blobs(6)],
+        );
+        let analyzer =
+            Analyzer::new(&rollup_config, L1Prices::default()).with_strategies(strategies);
+        let blocks = blocks(20, 10);
+        let report = analyzer.analyze(&blocks).unwrap();
+
+        assert_eq!(report.strategies.len(), 8);
+        for strategy in &report.strategies {
+            assert_eq!(strategy.blocks, 20);
+            assert_eq!(strategy.input_bytes, report.strategies[0].input_bytes);
+            // All blocks fit into a single channel, posted in a single transaction.
+            assert_eq!(strategy.channels, 1);
+            assert_eq!(strategy.txs, 1);
+            assert!(strategy.compression_ratio() > 0.0);
+            assert!(strategy.fee > 0);
+            match strategy.strategy.channel.da_type {
+                DataAvailabilityType::Calldata => assert_eq!(strategy.blob_gas, 0),
+                DataAvailabilityType::Blobs => assert!(strategy.blob_utilization().unwrap() > 0.0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_span_batches() {
+        let mut rollup_config =
+            RollupConfig { block_time: 2, l2_chain_id: 10u64.into(), ..Default::default() };
+        rollup_config.hardforks.delta_time = Some(0);
+        let strategies = Strategy::matrix(
+            &[CompressorType::Shadow],
+            &[CompressionAlgo::Zlib],
+            &[BatchType::Single, BatchType::Span],
+            &[ChannelConfig::calldata()],
+        );
+        let analyzer =
+            Analyzer::new(&rollup_config, L1Prices::default()).with_strategies(strategies);
+        let report = analyzer.analyze(&blocks(20, 10)).unwrap();
+
+        let (single, span) = (&report.strategies[0], &report.strategies[1]);
+        assert_eq!(span.strategy.channel.batch_type, BatchType::Span);
+        assert_eq!(span.blocks, 20);
+        assert_eq!(span.input_bytes, single.input_bytes);
+        assert_eq!(span.channels, 1);
+        // A span batch shares the parent hash, L1 origin and timestamps of its blocks.
+        assert!(span.posted_bytes < single.posted_bytes);
+    }
+
+    #[test]
+    fn test_small_frames() {
+        let rollup_config = RollupConfig::default();
+        let channel = ChannelConfig::calldata().with_max_frame_size(4_000);
+        let analyzer = Analyzer::new(&rollup_config, L1Prices::default())
+            .with_strategy(Strategy::new(CompressorType::Shadow, CompressionAlgo::Zlib, channel));
+        let report = analyzer.analyze(&blocks(40, 10)).unwrap();
+
+        let strategy = &report.strategies[0];
+        assert!(strategy.channels > 1);
+        assert_eq!(strategy.frames, strategy.txs);
+        assert!(strategy.frames >= strategy.channels);
+        assert!(strategy.posted_bytes <= strategy.txs * 4_001);
+    }
+
+    #[test]
+    fn test_rejects_gaps() {
+        let rollup_config = RollupConfig::default();
+        let analyzer = Analyzer::new(&rollup_config, L1Prices::default()).with_strategy(
+            Strategy::new(CompressorType::Shadow, CompressionAlgo::Zlib, ChannelConfig::calldata()),
+        );
+        let mut blocks = blocks(3, 1);
+        blocks.remove(1);
+        assert!(matches!(analyzer.analyze(&blocks), Err(AnalyzerError::ChannelManager(_))));
+    }
+}
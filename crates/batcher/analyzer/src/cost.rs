@@ -0,0 +1,141 @@
+//! L1 cost estimation of batcher transactions.
+
+use alloy_eips::eip4844::DATA_GAS_PER_BLOB;
+use kona_batcher_core::{DataAvailabilityType, TxCandidate};
+
+/// The intrinsic gas of every L1 transaction.
+const TX_BASE_GAS: u64 = 21_000;
+
+/// The gas charged per zero byte of calldata.
+const CALLDATA_ZERO_BYTE_GAS: u64 = 4;
+
+/// The gas charged per non-zero byte of calldata.
+const CALLDATA_NON_ZERO_BYTE_GAS: u64 = 16;
+
+/// The [EIP-7623] floor gas charged per calldata token, where a non-zero byte counts as four
+/// tokens.
+///
+/// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+const CALLDATA_FLOOR_GAS_PER_TOKEN: u64 = 10;
+
+/// Returns the gas used by an L1 transaction carrying the given calldata and no execution.
+///
+/// With `floor`, the [EIP-7623] calldata floor introduced by Pectra is applied.
+///
+/// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+pub fn calldata_gas(data: &[u8], floor: bool) -> u64 {
+    let zeros = data.iter().filter(|&&byte| byte == 0).count() as u64;
+    let non_zeros = data.len() as u64 - zeros;
+    let standard =
+        TX_BASE_GAS + zeros * CALLDATA_ZERO_BYTE_GAS + non_zeros * CALLDATA_NON_ZERO_BYTE_GAS;
+    if !floor {
+        return standard;
+    }
+    let tokens = zeros + non_zeros * 4;
+    standard.max(TX_BASE_GAS + tokens * CALLDATA_FLOOR_GAS_PER_TOKEN)
+}
+
+/// The L1 fee market used to price batcher transactions.
+///
+/// Priority fees are not included, since they depend on the L1 congestion at submission time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct L1Prices {
+    /// The L1 base fee, in wei per gas.
+    pub base_fee: u128,
+    /// The L1 blob base fee, in wei per blob gas.
+    pub blob_base_fee: u128,
+    /// Whether the [EIP-7623] calldata floor is charged.
+    ///
+    /// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+    pub calldata_floor: bool,
+}
+
+impl Default for L1Prices {
+    fn default() -> Self {
+        Self::new(1_000_000_000, 1)
+    }
+}
+
+impl L1Prices {
+    /// Creates new [`L1Prices`], charging the calldata floor.
+    pub const fn new(base_fee: u128, blob_base_fee: u128) -> Self {
+        Self { base_fee, blob_base_fee, calldata_floor: true }
+    }
+
+    /// Sets whether the calldata floor is charged.
+    pub const fn with_calldata_floor(mut self, calldata_floor: bool) -> Self {
+        self.calldata_floor = calldata_floor;
+        self
+    }
+
+    /// Returns the cost of the given batcher transaction.
+    pub fn tx_cost(&self, tx: &TxCandidate) -> TxCost {
+        let (gas, blob_gas) = match tx.da_type {
+            DataAvailabilityType::Calldata => {
+                (calldata_gas(&tx.calldata(), self.calldata_floor), 0)
+            }
+            DataAvailabilityType::Blobs => {
+                (TX_BASE_GAS, tx.frames.len() as u64 * DATA_GAS_PER_BLOB)
+            }
+        };
+        let fee = gas as u128 * self.base_fee + blob_gas as u128 * self.blob_base_fee;
+        TxCost { gas, blob_gas, fee }
+    }
+}
+
+/// The L1 cost of a batcher transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TxCost {
+    /// The gas used by the transaction.
+    pub gas: u64,
+    /// The blob gas used by the transaction.
+    pub blob_gas: u64,
+    /// The fee paid, in wei.
+    pub fee: u128,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_batcher_core::TxId;
+    use kona_protocol::Frame;
+
+    fn candidate(da_type: DataAvailabilityType, num_frames: usize) -> TxCandidate {
+        let channel_id = [0xAA; 16];
+        let frames = (0..num_frames)
+            .map(|i| Frame::new(channel_id, i as u16, vec![0xFF; 100], i + 1 == num_frames))
+            .collect();
+        TxCandidate { id: TxId(0), channel_id, frames, da_type }
+    }
+
+    #[test]
+    fn test_calldata_gas() {
+        let data = [0, 0, 1, 2];
+        assert_eq!(calldata_gas(&data, false), 21_000 + 2 * 4 + 2 * 16);
+        // 2 + 2 * 4 tokens at 10 gas each exceed the standard cost.
+        assert_eq!(calldata_gas(&data, true), 21_000 + 10 * 10);
+        assert_eq!(calldata_gas(&[], true), 21_000);
+    }
+
+    #[test]
+    fn test_calldata_tx_cost() {
+        let tx = candidate(DataAvailabilityType::Calldata, 1);
+        let prices = L1Prices::new(10, 1).with_calldata_floor(false);
+        let gas = calldata_gas(&tx.calldata(), false);
+        assert_eq!(prices.tx_cost(&tx), TxCost { gas, blob_gas: 0, fee: gas as u128 * 10 });
+    }
+
+    #[test]
+    fn test_blob_tx_cost() {
+        let tx = candidate(DataAvailabilityType::Blobs, 3);
+        let cost = L1Prices::new(10, 2).tx_cost(&tx);
+        assert_eq!(
+            cost,
+            TxCost {
+                gas: 21_000,
+                blob_gas: 3 * DATA_GAS_PER_BLOB,
+                fee: 21_000 * 10 + 3 * DATA_GAS_PER_BLOB as u128 * 2,
+            }
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! Errors of the [`Analyzer`](crate::Analyzer).
+
+use alloy_transport::TransportError;
+use kona_batcher_core::ChannelManagerError;
+
+/// An error returned while loading or replaying L2 blocks.
+#[derive(Debug, thiserror::Error)]
+pub enum AnalyzerError {
+    /// There are no blocks to replay.
+    #[error("No blocks to analyze")]
+    NoBlocks,
+    /// The channel manager rejected a block or failed to build a channel.
+    #[error(transparent)]
+    ChannelManager(#[from] ChannelManagerError),
+    /// An L2 block could not be fetched.
+    #[error("Failed to fetch L2 block {number}: {source}")]
+    Rpc {
+        /// The number of the block.
+        number: u64,
+        /// The transport error.
+        source: TransportError,
+    },
+    /// An L2 block does not exist.
+    #[error("L2 block {0} not found")]
+    BlockNotFound(u64),
+    /// A fixture could not be read or written.
+    #[error("Invalid block fixture: {0}")]
+    Fixture(#[from] serde_json::Error),
+}
@@ -0,0 +1,25 @@
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/op-rs/kona/main/assets/square.png",
+    html_favicon_url = "https://raw.githubusercontent.com/op-rs/kona/main/assets/favicon.ico",
+    issue_tracker_base_url = "https://github.com/op-rs/kona/issues/"
+)]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod analyzer;
+pub use analyzer::Analyzer;
+
+mod cost;
+pub use cost::{L1Prices, TxCost, calldata_gas};
+
+mod errors;
+pub use errors::AnalyzerError;
+
+mod report;
+pub use report::{AnalysisReport, StrategyReport};
+
+mod source;
+pub use source::{RpcBlock, fetch_blocks, into_op_block, read_fixture, write_fixture};
+
+mod strategy;
+pub use strategy::Strategy;
@@ -0,0 +1,244 @@
+//! The results of an [`Analyzer`](crate::Analyzer) run.
+
+use crate::{L1Prices, Strategy, TxCost};
+use kona_batcher_core::{DataAvailabilityType, TxCandidate};
+use kona_protocol::BLOB_MAX_DATA_SIZE;
+use std::fmt;
+
+/// The outcome of replaying L2 blocks through a single [`Strategy`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategyReport {
+    /// The replayed strategy.
+    pub strategy: Strategy,
+    /// The number of replayed L2 blocks.
+    pub blocks: usize,
+    /// The size of the encoded user transactions of the replayed blocks, in bytes.
+    pub input_bytes: usize,
+    /// The number of channels built.
+    pub channels: usize,
+    /// The number of frames built.
+    pub frames: usize,
+    /// The number of L1 transactions.
+    pub txs: usize,
+    /// The size of the compressed channel data, in bytes.
+    pub compressed_bytes: usize,
+    /// The size of the data posted to L1 including frame headers and version bytes, in bytes.
+    pub posted_bytes: usize,
+    /// The gas used by all L1 transactions.
+    pub gas: u64,
+    /// The blob gas used by all L1 transactions.
+    pub blob_gas: u64,
+    /// The fee paid by all L1 transactions, in wei.
+    pub fee: u128,
+}
+
+impl StrategyReport {
+    /// Creates an empty [`StrategyReport`] for the given strategy.
+    pub const fn new(strategy: Strategy) -> Self {
+        Self {
+            strategy,
+            blocks: 0,
+            input_bytes: 0,
+            channels: 0,
+            frames: 0,
+            txs: 0,
+            compressed_bytes: 0,
+            posted_bytes: 0,
+            gas: 0,
+            blob_gas: 0,
+            fee: 0,
+        }
+    }
+
+    /// Records a batcher transaction with the given cost.
+    pub fn record_tx(&mut self, tx: &TxCandidate, cost: TxCost) {
+        self.txs += 1;
+        self.frames += tx.frames.len();
+        self.channels += tx.frames.iter().filter(|frame| frame.is_last).count();
+        self.compressed_bytes += tx.frames.iter().map(|frame| frame.data.len()).sum::<usize>();
+        // Every transaction, or every blob, starts with the derivation version byte.
+        self.posted_bytes += match tx.da_type {
+            DataAvailabilityType::Calldata => tx.calldata().len(),
+            DataAvailabilityType::Blobs => {
+                tx.frames.iter().map(|frame| frame.encode().len() + 1).sum::<usize>()
+            }
+        };
+        self.gas += cost.gas;
+        self.blob_gas += cost.blob_gas;
+        self.fee += cost.fee;
+    }
+
+    /// Returns the average number of frames per channel.
+    pub fn frames_per_channel(&self) -> f64 {
+        ratio(self.frames, self.channels)
+    }
+
+    /// Returns the size of the compressed channel data relative to the input size.
+    pub fn compression_ratio(&self) -> f64 {
+        ratio(self.compressed_bytes, self.input_bytes)
+    }
+
+    /// Returns the share of the posted blob space that carries data, or [`None`] for calldata.
+    pub fn blob_utilization(&self) -> Option<f64> {
+        (self.strategy.channel.da_type == DataAvailabilityType::Blobs)
+            .then(|| ratio(self.posted_bytes, self.frames * BLOB_MAX_DATA_SIZE))
+    }
+
+    /// Returns the average fee paid per L2 block, in wei.
+    pub fn fee_per_block(&self) -> u128 {
+        self.fee.checked_div(self.blocks as u128).unwrap_or_default()
+    }
+}
+
+/// The outcome of replaying L2 blocks through a set of [`Strategy`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalysisReport {
+    /// The L1 prices the transactions were priced at.
+    pub prices: L1Prices,
+    /// The report of every strategy, in the order the strategies were given.
+    pub strategies: Vec<StrategyReport>,
+}
+
+impl AnalysisReport {
+    /// Returns the strategy with the lowest L1 fee.
+    pub fn cheapest(&self) -> Option<&StrategyReport> {
+        self.strategies.iter().min_by_key(|report| report.fee)
+    }
+}
+
+impl fmt::Display for AnalysisReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(first) = self.strategies.first() else {
+            return writeln!(f, "No strategies analyzed");
+        };
+        writeln!(
+            f,
+            "Replayed {} L2 blocks ({} bytes of transactions) at a base fee of {} wei and a blob \
+             base fee of {} wei",
+            first.blocks, first.input_bytes, self.prices.base_fee, self.prices.blob_base_fee
+        )?;
+        writeln!(f)?;
+
+        let width = self.strategies.iter().map(|report| report.strategy.label().len()).max();
+        let width = width.unwrap_or_default().max("strategy".len());
+        writeln!(
+            f,
+            "{:<width$}  {:>8}  {:>8}  {:>8}  {:>6}  {:>6}  {:>8}  {:>12}  {:>12}  {:>14}",
+            "strategy",
+            "channels",
+            "frames",
+            "fr/chan",
+            "txs",
+            "ratio",
+            "blob use",
+            "gas",
+            "blob gas",
+            "fee (gwei)",
+        )?;
+        for report in &self.strategies {
+            let blob_utilization = report
+                .blob_utilization()
+                .map_or_else(|| "-".to_string(), |share| format!("{:.1}%", share * 100.0));
+            writeln!(
+                f,
+                "{:<width$}  {:>8}  {:>8}  {:>8.2}  {:>6}  {:>6.3}  {:>8}  {:>12}  {:>12}  {:>14.3}",
+                report.strategy.label(),
+                report.channels,
+                report.frames,
+                report.frames_per_channel(),
+                report.txs,
+                report.compression_ratio(),
+                blob_utilization,
+                report.gas,
+                report.blob_gas,
+                report.fee as f64 / 1e9,
+            )?;
+        }
+
+        if let Some(cheapest) = self.cheapest() {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "Cheapest: {} at {:.3} gwei per L2 block",
+                cheapest.strategy.label(),
+                cheapest.fee_per_block() as f64 / 1e9
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns `numerator / denominator`, or zero if the denominator is zero.
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 { 0.0 } else { numerator as f64 / denominator as f64 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_batcher_core::{ChannelConfig, TxId};
+    use kona_comp::{CompressionAlgo, CompressorType};
+    use kona_protocol::Frame;
+
+    fn report(channel: ChannelConfig) -> StrategyReport {
+        StrategyReport::new(Strategy::new(CompressorType::Shadow, CompressionAlgo::Zlib, channel))
+    }
+
+    fn tx(da_type: DataAvailabilityType, frames: Vec<Frame>) -> TxCandidate {
+        TxCandidate { id: TxId(0), channel_id: frames[0].id, frames, da_type }
+    }
+
+    #[test]
+    fn test_record_calldata_tx() {
+        let mut report = report(ChannelConfig::calldata());
+        report.input_bytes = 100;
+        let tx =
+            tx(DataAvailabilityType::Calldata, vec![Frame::new([1; 16], 0, vec![7; 50], true)]);
+        report.record_tx(&tx, TxCost { gas: 10, blob_gas: 0, fee: 20 });
+
+        assert_eq!(report.txs, 1);
+        assert_eq!(report.frames, 1);
+        assert_eq!(report.channels, 1);
+        assert_eq!(report.compressed_bytes, 50);
+        assert_eq!(report.posted_bytes, tx.calldata().len());
+        assert_eq!(report.compression_ratio(), 0.5);
+        assert_eq!(report.blob_utilization(), None);
+    }
+
+    #[test]
+    fn test_record_blob_tx() {
+        let mut report = report(ChannelConfig::blobs(2));
+        let frames = vec![
+            Frame::new([1; 16], 0, vec![7; 1000], false),
+            Frame::new([1; 16], 1, vec![7; 1000], false),
+        ];
+        report.record_tx(&tx(DataAvailabilityType::Blobs, frames.clone()), TxCost::default());
+        report.record_tx(
+            &tx(DataAvailabilityType::Blobs, vec![Frame::new([1; 16], 2, vec![7; 10], true)]),
+            TxCost::default(),
+        );
+
+        assert_eq!(report.txs, 2);
+        assert_eq!(report.frames, 3);
+        assert_eq!(report.channels, 1);
+        assert_eq!(report.frames_per_channel(), 3.0);
+        let posted = 2 * (frames[0].encode().len() + 1) + 23 + 10 + 1;
+        assert_eq!(report.posted_bytes, posted);
+        assert_eq!(
+            report.blob_utilization(),
+            Some(posted as f64 / (3 * BLOB_MAX_DATA_SIZE) as f64)
+        );
+    }
+
+    #[test]
+    fn test_cheapest() {
+        let mut calldata = report(ChannelConfig::calldata());
+        calldata.fee = 100;
+        let mut blobs = report(ChannelConfig::blobs(6));
+        blobs.fee = 10;
+        let analysis =
+            AnalysisReport { prices: L1Prices::default(), strategies: vec![calldata, blobs] };
+        assert_eq!(analysis.cheapest().unwrap().strategy.channel, ChannelConfig::blobs(6));
+        assert!(analysis.to_string().contains("Cheapest: shadow/zlib/single/blobs:6x130043"));
+    }
+}
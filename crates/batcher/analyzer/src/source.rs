@@ -0,0 +1,50 @@
+//! Loading the L2 blocks to replay, from an RPC or from a local fixture.
+
+use crate::AnalyzerError;
+use alloy_provider::{Provider, RootProvider};
+use op_alloy_consensus::OpBlock;
+use op_alloy_network::Optimism;
+use std::{
+    io::{Read, Write},
+    ops::RangeInclusive,
+};
+use tracing::debug;
+
+/// An L2 block as returned by `eth_getBlockByNumber` with full transactions.
+pub type RpcBlock = alloy_rpc_types_eth::Block<op_alloy_rpc_types::Transaction>;
+
+/// Fetches the L2 blocks in the given range, in order.
+pub async fn fetch_blocks(
+    provider: &RootProvider<Optimism>,
+    range: RangeInclusive<u64>,
+) -> Result<Vec<RpcBlock>, AnalyzerError> {
+    let mut blocks = Vec::with_capacity(range.clone().count());
+    for number in range {
+        let block = provider
+            .get_block_by_number(number.into())
+            .full()
+            .await
+            .map_err(|source| AnalyzerError::Rpc { number, source })?
+            .ok_or(AnalyzerError::BlockNotFound(number))?;
+        debug!(target: "analyzer", number, txs = block.transactions.len(), "Fetched L2 block");
+        blocks.push(block);
+    }
+    Ok(blocks)
+}
+
+/// Reads a fixture holding a JSON array of [`RpcBlock`]s, and converts the blocks to
+/// [`OpBlock`]s.
+pub fn read_fixture<R: Read>(reader: R) -> Result<Vec<OpBlock>, AnalyzerError> {
+    let blocks: Vec<RpcBlock> = serde_json::from_reader(reader)?;
+    Ok(blocks.into_iter().map(into_op_block).collect())
+}
+
+/// Writes the given blocks as a fixture that can be read with [`read_fixture`].
+pub fn write_fixture<W: Write>(writer: W, blocks: &[RpcBlock]) -> Result<(), AnalyzerError> {
+    Ok(serde_json::to_writer(writer, blocks)?)
+}
+
+/// Converts an [`RpcBlock`] into an [`OpBlock`].
+pub fn into_op_block(block: RpcBlock) -> OpBlock {
+    block.into_consensus().map_transactions(|tx| tx.inner.inner.into_inner())
+}
@@ -0,0 +1,130 @@
+//! The batcher settings compared by the [`Analyzer`](crate::Analyzer).
+
+use kona_batcher_core::{ChannelConfig, DataAvailabilityType};
+use kona_comp::{CompressionAlgo, CompressorType, Config, DEFAULT_APPROX_COMPR_RATIO};
+use kona_protocol::BatchType;
+
+/// A combination of compressor and channel settings the batcher can run with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Strategy {
+    /// The compressor deciding when a channel is full.
+    pub compressor: CompressorType,
+    /// The compression algorithm.
+    pub compression_algo: CompressionAlgo,
+    /// The compression ratio assumed by the ratio compressor.
+    pub approx_compr_ratio: f64,
+    /// The channel settings, including the batch type, the data availability type and the frame
+    /// size.
+    pub channel: ChannelConfig,
+}
+
+impl Strategy {
+    /// Creates a new [`Strategy`] with the default approximate compression ratio.
+    pub const fn new(
+        compressor: CompressorType,
+        compression_algo: CompressionAlgo,
+        channel: ChannelConfig,
+    ) -> Self {
+        Self {
+            compressor,
+            compression_algo,
+            approx_compr_ratio: DEFAULT_APPROX_COMPR_RATIO,
+            channel,
+        }
+    }
+
+    /// Sets the compression ratio assumed by the ratio compressor.
+    pub const fn with_approx_compr_ratio(mut self, approx_compr_ratio: f64) -> Self {
+        self.approx_compr_ratio = approx_compr_ratio;
+        self
+    }
+
+    /// Returns every combination of the given compressors, compression algorithms, batch types
+    /// and channel configs.
+    pub fn matrix(
+        compressors: &[CompressorType],
+        compression_algos: &[CompressionAlgo],
+        batch_types: &[BatchType],
+        channels: &[ChannelConfig],
+    ) -> Vec<Self> {
+        let mut strategies = Vec::new();
+        for channel in channels {
+            for batch_type in batch_types {
+                let channel = channel.clone().with_batch_type(batch_type.clone());
+                for &compressor in compressors {
+                    for &algo in compression_algos {
+                        strategies.push(Self::new(compressor, algo, channel.clone()));
+                    }
+                }
+            }
+        }
+        strategies
+    }
+
+    /// Returns the compressor config, targeting the channel size of the channel config.
+    pub fn compressor_config(&self) -> Config {
+        Config {
+            target_output_size: self.channel.target_channel_size() as u64,
+            approx_compr_ratio: self.approx_compr_ratio,
+            kind: self.compressor,
+            compression_algo: self.compression_algo,
+        }
+    }
+
+    /// Returns a short human readable name, e.g. `shadow/zlib/span/blobs:6x130043`.
+    pub fn label(&self) -> String {
+        let compressor = match self.compressor {
+            CompressorType::Ratio => "ratio",
+            CompressorType::Shadow => "shadow",
+        };
+        let algo = match self.compression_algo {
+            CompressionAlgo::Brotli9 => "brotli-9",
+            CompressionAlgo::Brotli10 => "brotli-10",
+            CompressionAlgo::Brotli11 => "brotli-11",
+            CompressionAlgo::Zlib => "zlib",
+        };
+        let batch_type = match self.channel.batch_type {
+            BatchType::Single => "single",
+            BatchType::Span => "span",
+        };
+        let da = match self.channel.da_type {
+            DataAvailabilityType::Calldata => "calldata",
+            DataAvailabilityType::Blobs => "blobs",
+        };
+        format!(
+            "{compressor}/{algo}/{batch_type}/{da}:{}x{}",
+            self.channel.target_num_frames, self.channel.max_frame_size
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix() {
+        let strategies = Strategy::matrix(
+            &[CompressorType::Ratio, CompressorType::Shadow],
+            &[CompressionAlgo::Zlib, CompressionAlgo::Brotli10],
+            &[BatchType::Single, BatchType::Span],
+            &[ChannelConfig::calldata(), ChannelConfig::blobs(6)],
+        );
+        assert_eq!(strategies.len(), 16);
+        assert_eq!(strategies[0].label(), "ratio/zlib/single/calldata:1x119999");
+        assert_eq!(strategies[4].label(), "ratio/zlib/span/calldata:1x119999");
+        assert_eq!(strategies[4].channel.batch_type, BatchType::Span);
+        assert_eq!(strategies[15].label(), "shadow/brotli-10/span/blobs:6x130043");
+    }
+
+    #[test]
+    fn test_compressor_config() {
+        let strategy =
+            Strategy::new(CompressorType::Ratio, CompressionAlgo::Zlib, ChannelConfig::blobs(3))
+                .with_approx_compr_ratio(0.4);
+        let config = strategy.compressor_config();
+        assert_eq!(config.target_output_size, strategy.channel.target_channel_size() as u64);
+        assert_eq!(config.approx_compr_ratio, 0.4);
+        assert_eq!(config.kind, CompressorType::Ratio);
+    }
+}
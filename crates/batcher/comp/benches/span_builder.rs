@@ -0,0 +1,101 @@
+#![allow(missing_docs)]
+//! Compares the [SpanBatchBuilder] against re-encoding a [SpanBatch] after every block.
+
+use alloy_consensus::{SignableTransaction, TxEip1559, TxEnvelope};
+use alloy_eips::{BlockNumHash, eip2718::Encodable2718};
+use alloy_primitives::{Address, B256, Bytes, Signature, TxKind};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use kona_comp::{ChannelOut, SpanBatchBuilder, ZlibCompressor};
+use kona_genesis::RollupConfig;
+use kona_protocol::{Batch, BlockInfo, ChannelId, L2BlockInfo, SingleBatch, SpanBatch};
+
+/// The number of transactions in every block.
+const TXS_PER_BLOCK: u64 = 20;
+
+fn config() -> RollupConfig {
+    let mut config =
+        RollupConfig { l2_chain_id: 10u64.into(), block_time: 2, ..Default::default() };
+    config.hardforks.delta_time = Some(0);
+    config
+}
+
+fn blocks(count: u64) -> Vec<(L2BlockInfo, Vec<Bytes>)> {
+    (0..count)
+        .map(|i| {
+            let number = i + 1;
+            let info = L2BlockInfo::new(
+                BlockInfo::new(B256::ZERO, number, B256::ZERO, 2 * number),
+                BlockNumHash { number: i / 6, hash: B256::ZERO },
+                i % 6,
+            );
+            let txs = (0..TXS_PER_BLOCK)
+                .map(|j| {
+                    let tx = TxEip1559 {
+                        chain_id: 10,
+                        nonce: i * TXS_PER_BLOCK + j,
+                        gas_limit: 100_000,
+                        to: TxKind::Call(Address::repeat_byte(j as u8)),
+                        input: vec![j as u8; 100].into(),
+                        ..Default::default()
+                    };
+                    let envelope: TxEnvelope = tx.into_signed(Signature::test_signature()).into();
+                    envelope.encoded_2718().into()
+                })
+                .collect();
+            (info, txs)
+        })
+        .collect()
+}
+
+fn span_builder(c: &mut Criterion) {
+    let mut g = c.benchmark_group("span_builder");
+    g.sample_size(10);
+
+    let config = config();
+    for count in [100, 500] {
+        let blocks = blocks(count);
+
+        // Tracking the encoded size by encoding the whole span batch after every block.
+        g.bench_with_input(BenchmarkId::new("Re-encode", count), &blocks, |b, blocks| {
+            b.iter(|| {
+                let mut span = SpanBatch { chain_id: 10, ..Default::default() };
+                let mut size = 0;
+                for (info, txs) in blocks {
+                    let batch = SingleBatch {
+                        parent_hash: info.block_info.parent_hash,
+                        epoch_num: info.l1_origin.number,
+                        epoch_hash: info.l1_origin.hash,
+                        timestamp: info.block_info.timestamp,
+                        transactions: txs.clone(),
+                    };
+                    span.append_singular_batch(batch, info.seq_num).unwrap();
+                    let mut encoded = Vec::new();
+                    Batch::Span(span.clone()).encode(&mut encoded).unwrap();
+                    size = encoded.len();
+                }
+                let mut channel =
+                    ChannelOut::new(ChannelId::default(), &config, ZlibCompressor::new());
+                channel.add_batch(Batch::Span(span)).unwrap();
+                (size, channel.ready_bytes())
+            });
+        });
+
+        // Tracking the encoded size incrementally.
+        g.bench_with_input(BenchmarkId::new("SpanBatchBuilder", count), &blocks, |b, blocks| {
+            b.iter(|| {
+                let channel = ChannelOut::new(ChannelId::default(), &config, ZlibCompressor::new());
+                let mut builder = SpanBatchBuilder::new(channel);
+                let mut size = 0;
+                for (info, txs) in blocks {
+                    builder.add_l2_block(*info, txs.clone()).unwrap();
+                    size = builder.span_batch_size();
+                }
+                let channel = builder.into_channel().unwrap();
+                (size, channel.ready_bytes())
+            });
+        });
+    }
+}
+
+criterion_group!(benches, span_builder);
+criterion_main!(benches);
@@ -0,0 +1,112 @@
+//! Contains the [BatcherCompressor], selecting the compressor of the batcher from its config.
+
+use crate::{
+    ChannelCompressor, CompressorResult, CompressorType, CompressorWriter, Config, RatioCompressor,
+    ShadowCompressor,
+};
+
+/// The compressor used for the channels of the batcher, selected by the
+/// [`CompressorType`] of the [`Config`].
+#[derive(Debug, Clone)]
+pub enum BatcherCompressor {
+    /// The ratio compressor, estimating the compressed size from the input size.
+    Ratio(RatioCompressor),
+    /// The shadow compressor, bounding the compressed size with a second compressor.
+    Shadow(ShadowCompressor),
+}
+
+impl From<Config> for BatcherCompressor {
+    fn from(config: Config) -> Self {
+        match config.kind {
+            CompressorType::Ratio => Self::Ratio(config.into()),
+            CompressorType::Shadow => Self::Shadow(config.into()),
+        }
+    }
+}
+
+impl CompressorWriter for BatcherCompressor {
+    fn write(&mut self, data: &[u8]) -> CompressorResult<usize> {
+        match self {
+            Self::Ratio(compressor) => compressor.write(data),
+            Self::Shadow(compressor) => compressor.write(data),
+        }
+    }
+
+    fn flush(&mut self) -> CompressorResult<()> {
+        match self {
+            Self::Ratio(compressor) => compressor.flush(),
+            Self::Shadow(compressor) => compressor.flush(),
+        }
+    }
+
+    fn close(&mut self) -> CompressorResult<()> {
+        match self {
+            Self::Ratio(compressor) => compressor.close(),
+            Self::Shadow(compressor) => compressor.close(),
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Self::Ratio(compressor) => compressor.reset(),
+            Self::Shadow(compressor) => compressor.reset(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Ratio(compressor) => compressor.len(),
+            Self::Shadow(compressor) => compressor.len(),
+        }
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> CompressorResult<usize> {
+        match self {
+            Self::Ratio(compressor) => compressor.read(buf),
+            Self::Shadow(compressor) => compressor.read(buf),
+        }
+    }
+
+    fn read_at(&mut self, offset: usize, buf: &mut [u8]) -> CompressorResult<usize> {
+        match self {
+            Self::Ratio(compressor) => compressor.read_at(offset, buf),
+            Self::Shadow(compressor) => compressor.read_at(offset, buf),
+        }
+    }
+}
+
+impl ChannelCompressor for BatcherCompressor {
+    fn get_compressed(&self) -> Vec<u8> {
+        match self {
+            Self::Ratio(compressor) => compressor.get_compressed(),
+            Self::Shadow(compressor) => compressor.get_compressed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CompressionAlgo;
+
+    fn config(kind: CompressorType) -> Config {
+        Config {
+            target_output_size: 1_000,
+            approx_compr_ratio: 0.6,
+            kind,
+            compression_algo: CompressionAlgo::Zlib,
+        }
+    }
+
+    #[test]
+    fn test_from_config() {
+        assert!(matches!(
+            BatcherCompressor::from(config(CompressorType::Ratio)),
+            BatcherCompressor::Ratio(_)
+        ));
+        assert!(matches!(
+            BatcherCompressor::from(config(CompressorType::Shadow)),
+            BatcherCompressor::Shadow(_)
+        ));
+    }
+}
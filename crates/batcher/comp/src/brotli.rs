@@ -1,6 +1,7 @@
 //! Contains brotli compression utilities.
 
 use crate::{ChannelCompressor, CompressorError, CompressorResult, CompressorWriter};
+use kona_protocol::BatchReader;
 use std::vec::Vec;
 
 /// The brotli encoding level used in Optimism.
@@ -90,9 +91,12 @@ impl CompressorWriter for BrotliCompressor {
         // First append the new data to the raw buffer.
         self.raw.extend_from_slice(data);
 
-        // Compress the raw buffer.
-        self.compressed =
+        // Compress the raw buffer, prefixed with the brotli channel version byte.
+        let compressed =
             compress_brotli(&self.raw, self.level).map_err(|_| CompressorError::Brotli)?;
+        self.compressed.clear();
+        self.compressed.push(BatchReader::CHANNEL_VERSION_BROTLI);
+        self.compressed.extend_from_slice(&compressed);
 
         Ok(data.len())
     }
@@ -119,6 +123,13 @@ impl CompressorWriter for BrotliCompressor {
         Ok(len)
     }
 
+    fn read_at(&mut self, offset: usize, buf: &mut [u8]) -> CompressorResult<usize> {
+        let compressed = self.compressed.get(offset..).unwrap_or_default();
+        let len = compressed.len().min(buf.len());
+        buf[..len].copy_from_slice(&compressed[..len]);
+        Ok(len)
+    }
+
     fn len(&self) -> usize {
         self.compressed.len()
     }
@@ -146,7 +157,8 @@ mod test {
         compressor.write(&decompressed).unwrap();
         compressor.close().unwrap();
         let compressed = compressor.get_compressed();
-        assert_eq!(compressed, expected);
+        assert_eq!(compressed[0], BatchReader::CHANNEL_VERSION_BROTLI);
+        assert_eq!(compressed[1..], expected);
     }
 
     #[test]
@@ -162,7 +174,8 @@ mod test {
         compressor.write(&raw_batch_decompressed).unwrap();
         compressor.close().unwrap();
         let compressed = compressor.get_compressed();
-        assert_eq!(compressed, raw_batch);
+        assert_eq!(compressed[0], BatchReader::CHANNEL_VERSION_BROTLI);
+        assert_eq!(compressed[1..], raw_batch);
     }
 
     #[test]
@@ -177,7 +190,7 @@ mod test {
         let compressed = compressor.get_compressed();
 
         let decompressed =
-            decompress_brotli(&compressed, MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize).unwrap();
+            decompress_brotli(&compressed[1..], MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize).unwrap();
         assert_eq!(decompressed, raw_batch_decompressed);
     }
 }
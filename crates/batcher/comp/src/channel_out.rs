@@ -2,6 +2,7 @@
 
 use crate::{ChannelCompressor, CompressorError};
 use alloc::{vec, vec::Vec};
+use alloy_rlp::Header;
 use kona_genesis::RollupConfig;
 use kona_protocol::{Batch, ChannelId, Frame};
 use rand::{RngCore, SeedableRng, rngs::SmallRng};
@@ -33,7 +34,7 @@ pub enum ChannelOutError {
 }
 
 /// [ChannelOut] constructs a channel from compressed, encoded batch data.
-#[allow(missing_debug_implementations)]
+#[derive(Debug)]
 pub struct ChannelOut<'a, C>
 where
     C: ChannelCompressor,
@@ -52,6 +53,8 @@ where
     pub frame_number: u16,
     /// The compressor.
     pub compressor: C,
+    /// The number of compressed bytes already output to frames.
+    output_offset: usize,
 }
 
 impl<'a, C> ChannelOut<'a, C>
@@ -60,7 +63,15 @@ where
 {
     /// Creates a new [ChannelOut] with the given [ChannelId].
     pub const fn new(id: ChannelId, config: &'a RollupConfig, compressor: C) -> Self {
-        Self { id, config, rlp_length: 0, frame_number: 0, closed: false, compressor }
+        Self {
+            id,
+            config,
+            rlp_length: 0,
+            frame_number: 0,
+            closed: false,
+            compressor,
+            output_offset: 0,
+        }
     }
 
     /// Resets the [ChannelOut] to its initial state.
@@ -68,6 +79,7 @@ where
         self.rlp_length = 0;
         self.frame_number = 0;
         self.closed = false;
+        self.output_offset = 0;
         self.compressor.reset();
         // `getrandom` isn't available for wasm and risc targets
         // Thread-based RNGs are not available for no_std
@@ -83,9 +95,12 @@ where
             return Err(ChannelOutError::ChannelClosed);
         }
 
-        // Encode the batch.
-        let mut buf = vec![];
-        batch.encode(&mut buf).map_err(|_| ChannelOutError::BatchEncoding)?;
+        // Encode the batch, wrapped in an RLP string as expected by the channel reader.
+        let mut encoded = vec![];
+        batch.encode(&mut encoded).map_err(|_| ChannelOutError::BatchEncoding)?;
+        let mut buf = Vec::with_capacity(encoded.len() + 9);
+        Header { list: false, payload_length: encoded.len() }.encode(&mut buf);
+        buf.extend_from_slice(&encoded);
 
         // Validate that the RLP length is within the channel's limits.
         let max_rlp_bytes_per_channel = self.config.max_rlp_bytes_per_channel(batch.timestamp());
@@ -94,6 +109,7 @@ where
         }
 
         self.compressor.write(&buf)?;
+        self.rlp_length += buf.len() as u64;
 
         Ok(())
     }
@@ -105,7 +121,7 @@ where
 
     /// Returns the number of bytes ready to be output to a frame.
     pub fn ready_bytes(&self) -> usize {
-        self.compressor.len()
+        self.compressor.len().saturating_sub(self.output_offset)
     }
 
     /// Flush the internal compressor.
@@ -120,26 +136,22 @@ where
     }
 
     /// Outputs a [Frame] from the [ChannelOut].
+    ///
+    /// Each frame carries the next `max_size - FRAME_V0_OVERHEAD` compressed bytes that were not
+    /// output yet. The frame is marked as the last one once the channel is closed and all of the
+    /// compressed data has been output.
     pub fn output_frame(&mut self, max_size: usize) -> Result<Frame, ChannelOutError> {
         if max_size < FRAME_V0_OVERHEAD {
             return Err(ChannelOutError::MaxFrameSizeTooSmall);
         }
+        let size = (max_size - FRAME_V0_OVERHEAD).min(self.ready_bytes());
 
-        // Construct an empty frame.
-        let mut frame =
-            Frame { id: self.id, number: self.frame_number, is_last: self.closed, data: vec![] };
-
-        let mut max_size = max_size - FRAME_V0_OVERHEAD;
-        if max_size > self.ready_bytes() {
-            max_size = self.ready_bytes();
-        }
-
-        // Read `max_size` bytes from the compressed data.
-        let mut data = Vec::with_capacity(max_size);
-        self.compressor.read(&mut data).map_err(ChannelOutError::Compression)?;
-        frame.data.extend_from_slice(data.as_slice());
+        let mut data = vec![0u8; size];
+        self.compressor.read_at(self.output_offset, &mut data)?;
+        self.output_offset += size;
 
-        // Update the compressed data.
+        let is_last = self.closed && self.ready_bytes() == 0;
+        let frame = Frame { id: self.id, number: self.frame_number, is_last, data };
         self.frame_number += 1;
         Ok(frame)
     }
@@ -148,7 +160,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{CompressorWriter, test_utils::MockCompressor};
+    use crate::{CompressorWriter, ZlibCompressor, test_utils::MockCompressor};
+    use alloy_primitives::Bytes;
     use kona_protocol::{SingleBatch, SpanBatch};
 
     #[test]
@@ -194,10 +207,12 @@ mod tests {
             closed: true,
             frame_number: 11,
             compressor: MockCompressor::default(),
+            output_offset: 5,
         };
         channel.reset();
         assert_eq!(channel.rlp_length, 0);
         assert_eq!(channel.frame_number, 0);
+        assert_eq!(channel.output_offset, 0);
         // The odds of a randomized channel id being equal to the
         // default are so astronomically low, this test will always pass.
         // The randomized [u8; 16] is about 1/255^16.
@@ -220,6 +235,47 @@ mod tests {
         assert_eq!(channel.ready_bytes(), 3);
     }
 
+    #[test]
+    fn test_channel_out_output_frames() {
+        let config = RollupConfig::default();
+        let mut channel = ChannelOut::new(ChannelId::default(), &config, MockCompressor::default());
+        channel.compressor.write(&[1, 2, 3, 4, 5]).unwrap();
+
+        let frame = channel.output_frame(FRAME_V0_OVERHEAD + 3).unwrap();
+        assert_eq!(frame.data, vec![1, 2, 3]);
+        assert!(!frame.is_last);
+        assert_eq!(channel.ready_bytes(), 2);
+
+        channel.close();
+        let frame = channel.output_frame(FRAME_V0_OVERHEAD + 3).unwrap();
+        assert_eq!(frame.number, 1);
+        assert_eq!(frame.data, vec![4, 5]);
+        assert!(frame.is_last);
+        assert_eq!(channel.ready_bytes(), 0);
+    }
+
+    #[test]
+    fn test_channel_out_frames_concatenate_to_compressed_data() {
+        let config = RollupConfig::default();
+        let mut channel = ChannelOut::new(ChannelId::default(), &config, ZlibCompressor::new());
+        let transactions = vec![Bytes::from(vec![0xAB; 2048])];
+        channel
+            .add_batch(Batch::Single(SingleBatch { transactions, ..Default::default() }))
+            .unwrap();
+        channel.close();
+
+        let mut data = vec![];
+        loop {
+            let frame = channel.output_frame(FRAME_V0_OVERHEAD + 10).unwrap();
+            data.extend_from_slice(&frame.data);
+            if frame.is_last {
+                break;
+            }
+        }
+        assert!(channel.frame_number > 1);
+        assert_eq!(data, channel.compressor.get_compressed());
+    }
+
     #[test]
     fn test_channel_out_close() {
         let config = RollupConfig::default();
@@ -267,5 +323,6 @@ mod tests {
 
         let batch = Batch::Single(SingleBatch::default());
         assert_eq!(channel.add_batch(batch), Ok(()));
+        assert!(channel.input_bytes() > 0);
     }
 }
@@ -0,0 +1,124 @@
+//! Command line arguments shared by the binaries configuring a batcher.
+
+use crate::{CompressionAlgo, CompressorType};
+use clap::ValueEnum;
+use kona_genesis::RollupConfig;
+use kona_protocol::BatchType;
+use std::{fs::File, path::Path};
+
+/// How the batcher posts frames to L1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DataAvailabilityArg {
+    /// Post frames as transaction calldata.
+    Calldata,
+    /// Post frames as EIP-4844 blobs.
+    Blobs,
+}
+
+/// The type of batches the batcher writes to channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BatchTypeArg {
+    /// One singular batch per L2 block.
+    Single,
+    /// One span batch per channel once Delta is active, singular batches before that.
+    Span,
+}
+
+impl From<BatchTypeArg> for BatchType {
+    fn from(arg: BatchTypeArg) -> Self {
+        match arg {
+            BatchTypeArg::Single => Self::Single,
+            BatchTypeArg::Span => Self::Span,
+        }
+    }
+}
+
+/// The compressor deciding when a channel is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CompressorArg {
+    /// Estimates the compressed size from the input size and the approximate compression ratio.
+    Ratio,
+    /// Bounds the compressed size with a second compressor.
+    Shadow,
+}
+
+impl From<CompressorArg> for CompressorType {
+    fn from(arg: CompressorArg) -> Self {
+        match arg {
+            CompressorArg::Ratio => Self::Ratio,
+            CompressorArg::Shadow => Self::Shadow,
+        }
+    }
+}
+
+/// Parses a compression algorithm: `zlib`, `brotli-9`, `brotli-10` or `brotli-11`.
+pub fn parse_compression_algo(s: &str) -> Result<CompressionAlgo, String> {
+    match s {
+        "zlib" => Ok(CompressionAlgo::Zlib),
+        "brotli-9" => Ok(CompressionAlgo::Brotli9),
+        "brotli-10" => Ok(CompressionAlgo::Brotli10),
+        "brotli-11" => Ok(CompressionAlgo::Brotli11),
+        _ => Err(format!("unknown compression algorithm: {s}")),
+    }
+}
+
+/// An error loading a rollup config with [`load_rollup_config`].
+#[derive(Debug, thiserror::Error)]
+pub enum RollupConfigLoadError {
+    /// The rollup config file could not be opened.
+    #[error("Failed to open rollup config {path}: {source}")]
+    Open {
+        /// The path of the rollup config file.
+        path: String,
+        /// The underlying error.
+        source: std::io::Error,
+    },
+    /// The rollup config file is not a valid rollup config.
+    #[error("Failed to parse rollup config: {0}")]
+    Parse(#[from] serde_json::Error),
+    /// The superchain registry has no rollup config for the chain.
+    #[error("No rollup config found for chain {0}")]
+    NotFound(u64),
+}
+
+/// Loads the rollup config, either from the given file or from the superchain registry.
+pub fn load_rollup_config(
+    path: Option<&Path>,
+    l2_chain_id: u64,
+) -> Result<RollupConfig, RollupConfigLoadError> {
+    match path {
+        Some(path) => {
+            let file = File::open(path).map_err(|source| RollupConfigLoadError::Open {
+                path: path.display().to_string(),
+                source,
+            })?;
+            Ok(serde_json::from_reader(file)?)
+        }
+        None => kona_registry::ROLLUP_CONFIGS
+            .get(&l2_chain_id)
+            .cloned()
+            .ok_or(RollupConfigLoadError::NotFound(l2_chain_id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_compression_algo() {
+        assert_eq!(parse_compression_algo("zlib"), Ok(CompressionAlgo::Zlib));
+        assert_eq!(parse_compression_algo("brotli-11"), Ok(CompressionAlgo::Brotli11));
+        assert!(parse_compression_algo("lz4").is_err());
+    }
+
+    #[test]
+    fn test_load_rollup_config() {
+        assert_eq!(load_rollup_config(None, 10).unwrap().l2_chain_id.id(), 10);
+        assert!(matches!(load_rollup_config(None, 0), Err(RollupConfigLoadError::NotFound(0))));
+        assert!(matches!(
+            load_rollup_config(Some(Path::new("missing.json")), 10),
+            Err(RollupConfigLoadError::Open { .. })
+        ));
+    }
+}
@@ -12,6 +12,9 @@ extern crate alloc;
 mod channel_out;
 pub use channel_out::{ChannelOut, ChannelOutError};
 
+mod span_builder;
+pub use span_builder::{DEFAULT_APPROX_COMPR_RATIO, SpanBatchBuilder, SpanBatchBuilderError};
+
 mod traits;
 pub use traits::{ChannelCompressor, CompressorWriter};
 
@@ -34,6 +37,11 @@ mod variant;
 #[cfg(feature = "std")]
 pub use variant::VariantCompressor;
 
+#[cfg(feature = "std")]
+mod batcher;
+#[cfg(feature = "std")]
+pub use batcher::BatcherCompressor;
+
 #[cfg(feature = "std")]
 mod shadow;
 #[cfg(feature = "std")]
@@ -44,5 +52,13 @@ mod ratio;
 #[cfg(feature = "std")]
 pub use ratio::RatioCompressor;
 
+#[cfg(feature = "cli")]
+mod cli;
+#[cfg(feature = "cli")]
+pub use cli::{
+    BatchTypeArg, CompressorArg, DataAvailabilityArg, RollupConfigLoadError, load_rollup_config,
+    parse_compression_algo,
+};
+
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
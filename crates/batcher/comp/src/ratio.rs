@@ -4,7 +4,7 @@
 //!
 //! [rc]: https://github.com/ethereum-optimism/optimism/blob/develop/op-batcher/compressor/ratio_compressor.go#L7
 
-use crate::{CompressorResult, CompressorWriter, Config, VariantCompressor};
+use crate::{ChannelCompressor, CompressorResult, CompressorWriter, Config, VariantCompressor};
 
 /// Ratio Compressor
 ///
@@ -84,6 +84,16 @@ impl CompressorWriter for RatioCompressor {
     fn read(&mut self, buf: &mut [u8]) -> CompressorResult<usize> {
         self.compressor.read(buf)
     }
+
+    fn read_at(&mut self, offset: usize, buf: &mut [u8]) -> CompressorResult<usize> {
+        self.compressor.read_at(offset, buf)
+    }
+}
+
+impl ChannelCompressor for RatioCompressor {
+    fn get_compressed(&self) -> Vec<u8> {
+        self.compressor.get_compressed()
+    }
 }
 
 #[cfg(test)]
@@ -120,7 +130,7 @@ mod tests {
         assert!(!compressor.is_full());
         compressor.write(&[0; 2048]).unwrap();
         assert!(compressor.is_full());
-        assert_eq!(compressor.len(), 18);
+        assert_eq!(compressor.len(), 24);
 
         let mut buf = [];
         compressor.read(&mut buf).unwrap();
@@ -0,0 +1,540 @@
+//! Contains the [`SpanBatchBuilder`], which accumulates L2 blocks into a [`ChannelOut`].
+
+use crate::{ChannelCompressor, ChannelOut, ChannelOutError};
+use alloc::vec::Vec;
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::Bytes;
+use alloy_rlp::Header;
+use kona_genesis::RollupConfig;
+use kona_protocol::{
+    Batch, FromBlockError, L2BlockInfo, SingleBatch, SpanBatch, SpanBatchError,
+    SpanBatchTransactions,
+};
+use op_alloy_consensus::OpBlock;
+
+/// The compression ratio assumed by default when estimating the compressed size of the pending
+/// span batch, matching the default of the op-batcher.
+pub const DEFAULT_APPROX_COMPR_RATIO: f64 = 0.6;
+
+/// An error returned by the [`SpanBatchBuilder`].
+#[derive(Debug, thiserror::Error)]
+pub enum SpanBatchBuilderError {
+    /// The L2 block info could not be derived from the block.
+    #[error("Invalid L2 block: {0}")]
+    InvalidBlock(#[from] FromBlockError),
+    /// The block is older than the last block in the span batch.
+    #[error("Block at timestamp {timestamp} is older than the last span batch block at {last}")]
+    Unordered {
+        /// The timestamp of the added block.
+        timestamp: u64,
+        /// The timestamp of the last block in the span batch.
+        last: u64,
+    },
+    /// The transactions of the block cannot be added to a span batch.
+    #[error("Invalid span batch transactions: {0}")]
+    SpanBatch(#[from] SpanBatchError),
+    /// An error adding a batch to the channel.
+    #[error("Channel error: {0}")]
+    Channel(#[from] ChannelOutError),
+}
+
+/// Accumulates L2 blocks into a [`ChannelOut`], as a single span batch once Delta is active, or
+/// as one singular batch per block before that.
+///
+/// Singular batches are written to the channel as soon as they are added. Blocks of a span batch
+/// are appended to the pending span batch in time linear in their size, while the encoded size
+/// of the span batch is kept up to date without encoding it. The span batch is encoded and
+/// written to the channel once, when it is [flushed](Self::flush).
+#[derive(Debug)]
+pub struct SpanBatchBuilder<'a, C>
+where
+    C: ChannelCompressor,
+{
+    /// The channel the batches are written to.
+    channel: ChannelOut<'a, C>,
+    /// The pending span batch.
+    span: SpanBatch,
+    /// The compression ratio assumed for the pending span batch.
+    approx_compr_ratio: f64,
+    /// The encoded size of the block transaction counts of the pending span batch.
+    block_tx_counts_size: usize,
+    /// The encoded size of the transactions of the pending span batch, excluding bitlists.
+    txs_size: usize,
+}
+
+impl<'a, C> SpanBatchBuilder<'a, C>
+where
+    C: ChannelCompressor,
+{
+    /// Creates a new [`SpanBatchBuilder`] writing to the given [`ChannelOut`].
+    pub fn new(channel: ChannelOut<'a, C>) -> Self {
+        let config = channel.config;
+        let span = SpanBatch {
+            genesis_timestamp: config.genesis.l2_time,
+            chain_id: config.l2_chain_id.id(),
+            ..Default::default()
+        };
+        Self {
+            channel,
+            span,
+            approx_compr_ratio: DEFAULT_APPROX_COMPR_RATIO,
+            block_tx_counts_size: 0,
+            txs_size: 0,
+        }
+    }
+
+    /// Sets the compression ratio assumed when estimating the compressed size of the pending
+    /// span batch.
+    pub const fn with_approx_compr_ratio(mut self, approx_compr_ratio: f64) -> Self {
+        self.approx_compr_ratio = approx_compr_ratio;
+        self
+    }
+
+    /// Returns the [`RollupConfig`] of the channel.
+    pub const fn config(&self) -> &'a RollupConfig {
+        self.channel.config
+    }
+
+    /// Returns the channel the batches are written to.
+    pub const fn channel(&self) -> &ChannelOut<'a, C> {
+        &self.channel
+    }
+
+    /// Returns a mutable reference to the channel the batches are written to.
+    pub const fn channel_mut(&mut self) -> &mut ChannelOut<'a, C> {
+        &mut self.channel
+    }
+
+    /// Returns the number of blocks in the pending span batch.
+    pub fn pending_blocks(&self) -> usize {
+        self.span.batches.len()
+    }
+
+    /// Returns the RLP encoded size of the pending span batch, as it will be written to the
+    /// channel, or zero if there is no pending span batch.
+    pub fn span_batch_size(&self) -> usize {
+        let (Some(first), Some(last)) = (self.span.batches.first(), self.span.batches.last())
+        else {
+            return 0;
+        };
+        span_batch_size(
+            first.timestamp - self.span.genesis_timestamp,
+            last.epoch_num,
+            self.span.batches.len() as u64,
+            self.block_tx_counts_size,
+            self.span.txs.total_block_tx_count,
+            self.span.txs.legacy_tx_count,
+            self.txs_size,
+        )
+    }
+
+    /// Returns the total RLP encoded size of the channel, including the pending span batch.
+    pub fn input_bytes(&self) -> u64 {
+        self.channel.input_bytes() + self.span_batch_size() as u64
+    }
+
+    /// Returns an estimate of the compressed size of the channel, assuming the pending span batch
+    /// compresses with the configured compression ratio.
+    pub fn estimated_compressed_size(&self) -> usize {
+        let pending = self.span_batch_size() as f64 * self.approx_compr_ratio;
+        self.channel.ready_bytes() + pending as usize
+    }
+
+    /// Adds an L2 block.
+    ///
+    /// Deposit transactions are excluded from the batch, since they are derived from L1.
+    pub fn add_block(&mut self, block: &OpBlock) -> Result<(), SpanBatchBuilderError> {
+        let info = L2BlockInfo::from_block_and_genesis(block, &self.config().genesis)?;
+        let transactions = block
+            .body
+            .transactions
+            .iter()
+            .filter(|tx| !tx.is_deposit())
+            .map(|tx| Bytes::from(tx.encoded_2718()))
+            .collect();
+        self.add_l2_block(info, transactions)
+    }
+
+    /// Adds an L2 block with the given non-deposit transactions.
+    ///
+    /// Before Delta, the block is written to the channel as a singular batch. After Delta, the
+    /// block is appended to the pending span batch, which is left unchanged if an error is
+    /// returned.
+    pub fn add_l2_block(
+        &mut self,
+        info: L2BlockInfo,
+        transactions: Vec<Bytes>,
+    ) -> Result<(), SpanBatchBuilderError> {
+        let batch = SingleBatch {
+            parent_hash: info.block_info.parent_hash,
+            epoch_num: info.l1_origin.number,
+            epoch_hash: info.l1_origin.hash,
+            timestamp: info.block_info.timestamp,
+            transactions,
+        };
+
+        if !self.config().is_delta_active(batch.timestamp) {
+            self.flush()?;
+            self.channel.add_batch(Batch::Single(batch))?;
+            return Ok(());
+        }
+        if self.channel.closed {
+            return Err(ChannelOutError::ChannelClosed.into());
+        }
+        if let Some(last) = self.span.batches.last().filter(|last| last.timestamp > batch.timestamp)
+        {
+            return Err(SpanBatchBuilderError::Unordered {
+                timestamp: batch.timestamp,
+                last: last.timestamp,
+            });
+        }
+
+        // Decode the transactions on their own, so that the span batch is not modified if they
+        // are invalid or exceed the channel size.
+        let mut txs = SpanBatchTransactions::default();
+        txs.add_txs(batch.transactions.clone(), self.span.chain_id)?;
+        let txs_size = txs.tx_sigs.len() * 64 +
+            txs.tx_tos.len() * 20 +
+            txs.tx_data.iter().map(Vec::len).sum::<usize>() +
+            txs.tx_nonces.iter().map(|nonce| varint_size(*nonce)).sum::<usize>() +
+            txs.tx_gases.iter().map(|gas| varint_size(*gas)).sum::<usize>();
+        let block_tx_count_size = varint_size(txs.total_block_tx_count);
+
+        // Check that the grown span batch still fits into the channel.
+        let first_timestamp = self.span.batches.first().map_or(batch.timestamp, |b| b.timestamp);
+        let grown_size = span_batch_size(
+            first_timestamp - self.span.genesis_timestamp,
+            batch.epoch_num,
+            self.span.batches.len() as u64 + 1,
+            self.block_tx_counts_size + block_tx_count_size,
+            self.span.txs.total_block_tx_count + txs.total_block_tx_count,
+            self.span.txs.legacy_tx_count + txs.legacy_tx_count,
+            self.txs_size + txs_size,
+        );
+        let max_rlp_bytes = self.config().max_rlp_bytes_per_channel(batch.timestamp);
+        if self.channel.input_bytes() + grown_size as u64 > max_rlp_bytes {
+            return Err(ChannelOutError::ExceedsMaxRlpBytesPerChannel.into());
+        }
+
+        self.push(batch, info.seq_num, txs);
+        self.block_tx_counts_size += block_tx_count_size;
+        self.txs_size += txs_size;
+        Ok(())
+    }
+
+    /// Writes the pending span batch to the channel, if any.
+    pub fn flush(&mut self) -> Result<(), SpanBatchBuilderError> {
+        if self.span.batches.is_empty() {
+            return Ok(());
+        }
+        let span = SpanBatch {
+            genesis_timestamp: self.span.genesis_timestamp,
+            chain_id: self.span.chain_id,
+            ..Default::default()
+        };
+        let span = core::mem::replace(&mut self.span, span);
+        self.block_tx_counts_size = 0;
+        self.txs_size = 0;
+        self.channel.add_batch(Batch::Span(span))?;
+        Ok(())
+    }
+
+    /// Writes the pending span batch to the channel, and returns the channel.
+    pub fn into_channel(mut self) -> Result<ChannelOut<'a, C>, SpanBatchBuilderError> {
+        self.flush()?;
+        Ok(self.channel)
+    }
+
+    /// Appends a block to the pending span batch, with its already decoded transactions.
+    fn push(&mut self, batch: SingleBatch, seq_num: u64, txs: SpanBatchTransactions) {
+        let SingleBatch { parent_hash, epoch_hash, .. } = batch;
+        self.span.batches.push(batch.into());
+        self.span.l1_origin_check = epoch_hash[..20].try_into().expect("Sub-slice cannot fail");
+
+        let len = self.span.batches.len();
+        let epoch_bit = if len == 1 {
+            self.span.parent_check = parent_hash[..20].try_into().expect("Sub-slice cannot fail");
+            seq_num == 0
+        } else {
+            self.span.batches[len - 2].epoch_num < self.span.batches[len - 1].epoch_num
+        };
+        self.span.origin_bits.set_bit(len - 1, epoch_bit);
+        self.span.block_tx_counts.push(txs.total_block_tx_count);
+        self.span.txs.extend(txs);
+    }
+}
+
+/// Returns the RLP encoded size of a span batch from the sizes of its parts.
+///
+/// `block_tx_counts_size` and `txs_size` are the encoded sizes of the block transaction counts
+/// and of the transactions without their bitlists, which grow with every added block.
+fn span_batch_size(
+    rel_timestamp: u64,
+    l1_origin_num: u64,
+    block_count: u64,
+    block_tx_counts_size: usize,
+    tx_count: u64,
+    legacy_tx_count: u64,
+    txs_size: usize,
+) -> usize {
+    let prefix_size = varint_size(rel_timestamp) + varint_size(l1_origin_num) + 40;
+    let payload_size = varint_size(block_count) +
+        bitlist_size(block_count) +
+        block_tx_counts_size +
+        // The contract creation bits and the signature y parity bits.
+        2 * bitlist_size(tx_count) +
+        txs_size +
+        bitlist_size(legacy_tx_count);
+
+    // The batch type byte is followed by the prefix and the payload.
+    let batch_size = 1 + prefix_size + payload_size;
+    Header { list: false, payload_length: batch_size }.length_with_payload()
+}
+
+/// Returns the size of the unsigned varint encoding of the given value.
+fn varint_size(value: u64) -> usize {
+    let mut buf = [0u8; 10];
+    unsigned_varint::encode::u64(value, &mut buf).len()
+}
+
+/// Returns the size of a span batch bitlist with the given number of bits.
+const fn bitlist_size(bits: u64) -> usize {
+    bits.div_ceil(8) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockCompressor;
+    use alloc::vec;
+    use alloy_consensus::{SignableTransaction, TxEip1559, TxEnvelope, TxLegacy};
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::{Address, B256, Signature, TxKind};
+    use alloy_rlp::Buf;
+    use kona_protocol::{BlockInfo, ChannelId};
+
+    fn config() -> RollupConfig {
+        let mut config =
+            RollupConfig { l2_chain_id: 10u64.into(), block_time: 2, ..Default::default() };
+        config.hardforks.delta_time = Some(0);
+        config
+    }
+
+    fn transaction(nonce: u64) -> Bytes {
+        let to =
+            if nonce % 3 == 0 { TxKind::Create } else { TxKind::Call(Address::repeat_byte(1)) };
+        let input = vec![nonce as u8; nonce as usize % 50].into();
+        let sig = Signature::test_signature();
+        let envelope: TxEnvelope = match nonce % 4 {
+            0 => TxLegacy {
+                chain_id: Some(10),
+                nonce,
+                gas_limit: 21_000,
+                to,
+                input,
+                ..Default::default()
+            }
+            .into_signed(sig)
+            .into(),
+            1 => TxLegacy { nonce, gas_limit: 21_000, to, input, ..Default::default() }
+                .into_signed(sig)
+                .into(),
+            _ => TxEip1559 {
+                chain_id: 10,
+                nonce,
+                gas_limit: 50_000,
+                to,
+                input,
+                ..Default::default()
+            }
+            .into_signed(sig)
+            .into(),
+        };
+        envelope.encoded_2718().into()
+    }
+
+    /// Returns L2 blocks with three blocks per epoch and a varying number of transactions.
+    fn blocks(count: u64) -> Vec<(L2BlockInfo, Vec<Bytes>)> {
+        let mut nonce = 0;
+        (0..count)
+            .map(|i| {
+                let number = i + 1;
+                let info = L2BlockInfo::new(
+                    BlockInfo::new(
+                        B256::with_last_byte(number as u8),
+                        number,
+                        B256::with_last_byte(i as u8),
+                        2 * number,
+                    ),
+                    BlockNumHash { number: i / 3, hash: B256::repeat_byte((i / 3) as u8) },
+                    i % 3,
+                );
+                let txs = (0..i % 5)
+                    .map(|_| {
+                        nonce += 1;
+                        transaction(nonce)
+                    })
+                    .collect();
+                (info, txs)
+            })
+            .collect()
+    }
+
+    fn builder(config: &RollupConfig) -> SpanBatchBuilder<'_, MockCompressor> {
+        SpanBatchBuilder::new(ChannelOut::new(
+            ChannelId::default(),
+            config,
+            MockCompressor::default(),
+        ))
+    }
+
+    /// Returns the batches written to a channel with a [`MockCompressor`].
+    fn written_batches(
+        config: &RollupConfig,
+        channel: &ChannelOut<'_, MockCompressor>,
+    ) -> Vec<Batch> {
+        let data = channel.compressor.get_compressed();
+        let mut data = data.as_slice();
+        let mut batches = vec![];
+        while !data.is_empty() {
+            let header = Header::decode(&mut data).unwrap();
+            let mut batch_data = &data[..header.payload_length];
+            batches.push(Batch::decode(&mut batch_data, config).unwrap());
+            data.advance(header.payload_length);
+        }
+        batches
+    }
+
+    #[test]
+    fn test_span_batch_matches_appended_span_batch() {
+        let config = config();
+        let mut builder = builder(&config);
+        let mut expected = SpanBatch { chain_id: 10, genesis_timestamp: 0, ..Default::default() };
+
+        for (info, txs) in blocks(20) {
+            let batch = SingleBatch {
+                parent_hash: info.block_info.parent_hash,
+                epoch_num: info.l1_origin.number,
+                epoch_hash: info.l1_origin.hash,
+                timestamp: info.block_info.timestamp,
+                transactions: txs.clone(),
+            };
+            expected.append_singular_batch(batch, info.seq_num).unwrap();
+            builder.add_l2_block(info, txs).unwrap();
+
+            let mut encoded = vec![];
+            Batch::Span(expected.clone()).encode(&mut encoded).unwrap();
+            let size = Header { list: false, payload_length: encoded.len() }.length_with_payload();
+            assert_eq!(builder.span_batch_size(), size);
+        }
+        assert_eq!(builder.span, expected);
+        assert_eq!(builder.pending_blocks(), 20);
+
+        let size = builder.span_batch_size();
+        let channel = builder.into_channel().unwrap();
+        assert_eq!(channel.input_bytes(), size as u64);
+    }
+
+    #[test]
+    fn test_span_batch_decodes() {
+        let config = config();
+        let mut builder = builder(&config);
+        let blocks = blocks(10);
+        for (info, txs) in blocks.clone() {
+            builder.add_l2_block(info, txs).unwrap();
+        }
+        let channel = builder.into_channel().unwrap();
+
+        let batches = written_batches(&config, &channel);
+        assert_eq!(batches.len(), 1);
+        let Batch::Span(span) = &batches[0] else { panic!("expected a span batch") };
+        assert_eq!(span.batches.len(), blocks.len());
+        for (element, (info, txs)) in span.batches.iter().zip(blocks) {
+            assert_eq!(element.timestamp, info.block_info.timestamp);
+            assert_eq!(element.epoch_num, info.l1_origin.number);
+            assert_eq!(element.transactions, txs);
+        }
+    }
+
+    #[test]
+    fn test_singular_batches_before_delta() {
+        let mut config = config();
+        config.hardforks.delta_time = Some(10);
+        let mut builder = builder(&config);
+        for (info, txs) in blocks(8) {
+            builder.add_l2_block(info, txs).unwrap();
+        }
+        // Blocks 1 to 4 are written as singular batches, the others to the pending span batch.
+        assert_eq!(builder.pending_blocks(), 4);
+        assert!(builder.estimated_compressed_size() > builder.channel().ready_bytes());
+
+        let channel = builder.into_channel().unwrap();
+        let batches = written_batches(&config, &channel);
+        assert_eq!(batches.len(), 5);
+        assert!(batches[..4].iter().all(|batch| matches!(batch, Batch::Single(_))));
+        let Batch::Span(span) = &batches[4] else { panic!("expected a span batch") };
+        assert_eq!(span.batches.len(), 4);
+        assert_eq!(span.batches[0].timestamp, 10);
+    }
+
+    #[test]
+    fn test_exceeding_channel_size_leaves_span_batch_unchanged() {
+        let config = config();
+        let mut builder = builder(&config);
+        let mut blocks = blocks(5).into_iter();
+        for (info, txs) in blocks.by_ref().take(4) {
+            builder.add_l2_block(info, txs).unwrap();
+        }
+        let span = builder.span.clone();
+        let size = builder.span_batch_size();
+
+        builder.channel.rlp_length = config.max_rlp_bytes_per_channel(0) - size as u64 - 10;
+        let (info, txs) = blocks.next().unwrap();
+        let err = builder.add_l2_block(info, txs).unwrap_err();
+        assert!(matches!(
+            err,
+            SpanBatchBuilderError::Channel(ChannelOutError::ExceedsMaxRlpBytesPerChannel)
+        ));
+        assert_eq!(builder.span, span);
+        assert_eq!(builder.span_batch_size(), size);
+    }
+
+    #[test]
+    fn test_invalid_transaction_leaves_span_batch_unchanged() {
+        let config = config();
+        let mut builder = builder(&config);
+        let mut blocks = blocks(3).into_iter();
+        for (info, txs) in blocks.by_ref().take(2) {
+            builder.add_l2_block(info, txs).unwrap();
+        }
+        let span = builder.span.clone();
+
+        let (info, mut txs) = blocks.next().unwrap();
+        txs.push(Bytes::from_static(&[0x02, 0xFF]));
+        let err = builder.add_l2_block(info, txs).unwrap_err();
+        assert!(matches!(err, SpanBatchBuilderError::SpanBatch(_)));
+        assert_eq!(builder.span, span);
+    }
+
+    #[test]
+    fn test_unordered_block() {
+        let config = config();
+        let mut builder = builder(&config);
+        let blocks = blocks(2);
+        builder.add_l2_block(blocks[1].0, vec![]).unwrap();
+        let err = builder.add_l2_block(blocks[0].0, vec![]).unwrap_err();
+        assert!(matches!(err, SpanBatchBuilderError::Unordered { timestamp: 2, last: 4 }));
+        assert_eq!(builder.pending_blocks(), 1);
+    }
+
+    #[test]
+    fn test_closed_channel() {
+        let config = config();
+        let mut channel = ChannelOut::new(ChannelId::default(), &config, MockCompressor::default());
+        channel.close();
+        let mut builder = SpanBatchBuilder::new(channel);
+        let (info, txs) = blocks(1).remove(0);
+        let err = builder.add_l2_block(info, txs).unwrap_err();
+        assert!(matches!(err, SpanBatchBuilderError::Channel(ChannelOutError::ChannelClosed)));
+    }
+}
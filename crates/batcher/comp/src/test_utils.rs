@@ -41,8 +41,20 @@ impl CompressorWriter for MockCompressor {
         if self.read_error {
             return Err(CompressorError::Full);
         }
-        let len = self.compressed.as_ref().map(|b| b.len()).unwrap_or(0);
-        buf[..len].copy_from_slice(self.compressed.as_ref().unwrap());
+        let compressed = self.compressed.as_deref().unwrap_or_default();
+        let len = compressed.len().min(buf.len());
+        buf[..len].copy_from_slice(&compressed[..len]);
+        Ok(len)
+    }
+
+    fn read_at(&mut self, offset: usize, buf: &mut [u8]) -> CompressorResult<usize> {
+        if self.read_error {
+            return Err(CompressorError::Full);
+        }
+        let compressed = self.compressed.as_deref().unwrap_or_default();
+        let compressed = compressed.get(offset..).unwrap_or_default();
+        let len = compressed.len().min(buf.len());
+        buf[..len].copy_from_slice(&compressed[..len]);
         Ok(len)
     }
 }
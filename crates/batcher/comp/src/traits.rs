@@ -28,6 +28,10 @@ pub trait CompressorWriter {
     /// Reads the compressed data into the given buffer.
     /// Returns the number of bytes read.
     fn read(&mut self, buf: &mut [u8]) -> CompressorResult<usize>;
+
+    /// Reads the compressed data starting at the given offset into the given buffer.
+    /// Returns the number of bytes read.
+    fn read_at(&mut self, offset: usize, buf: &mut [u8]) -> CompressorResult<usize>;
 }
 
 /// Channel Compressor
@@ -69,6 +69,13 @@ impl CompressorWriter for VariantCompressor {
             Self::Zlib(compressor) => compressor.read(buf),
         }
     }
+
+    fn read_at(&mut self, offset: usize, buf: &mut [u8]) -> CompressorResult<usize> {
+        match self {
+            Self::Brotli(compressor) => compressor.read_at(offset, buf),
+            Self::Zlib(compressor) => compressor.read_at(offset, buf),
+        }
+    }
 }
 
 impl ChannelCompressor for VariantCompressor {
@@ -9,12 +9,12 @@ const BEST_ZLIB_COMPRESSION: u8 = 9;
 
 /// Method to compress data using ZLIB.
 pub fn compress_zlib(data: &[u8]) -> Vec<u8> {
-    miniz_oxide::deflate::compress_to_vec(data, BEST_ZLIB_COMPRESSION)
+    miniz_oxide::deflate::compress_to_vec_zlib(data, BEST_ZLIB_COMPRESSION)
 }
 
 /// Method to decompress data using ZLIB.
 pub fn decompress_zlib(data: &[u8]) -> Result<Vec<u8>, DecompressError> {
-    miniz_oxide::inflate::decompress_to_vec(data)
+    miniz_oxide::inflate::decompress_to_vec_zlib(data)
 }
 
 /// The ZLIB compressor.
@@ -64,6 +64,13 @@ impl CompressorWriter for ZlibCompressor {
         buf[..len].copy_from_slice(&self.compressed[..len]);
         Ok(len)
     }
+
+    fn read_at(&mut self, offset: usize, buf: &mut [u8]) -> CompressorResult<usize> {
+        let compressed = self.compressed.get(offset..).unwrap_or_default();
+        let len = compressed.len().min(buf.len());
+        buf[..len].copy_from_slice(&compressed[..len]);
+        Ok(len)
+    }
 }
 
 impl ChannelCompressor for ZlibCompressor {
@@ -0,0 +1,254 @@
+//! Submission state of a single channel.
+
+use crate::TxId;
+use kona_comp::{
+    ChannelCompressor, ChannelOutError, CompressorError, SpanBatchBuilder, SpanBatchBuilderError,
+};
+use kona_protocol::{Batch, BatchType, ChannelId, Frame, L2BlockInfo, SingleBatch};
+use std::{collections::BTreeMap, ops::Range};
+
+/// A transaction carrying frames of a [`PendingChannel`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SubmittedTx {
+    /// The range of frame numbers carried by the transaction.
+    frames: Range<usize>,
+    /// The L1 block number the transaction was included in, if it is confirmed.
+    inclusion_block: Option<u64>,
+}
+
+/// A channel built by the [`ChannelManager`](crate::ChannelManager), together with the
+/// submission state of its frames.
+///
+/// Frames are only output once the channel is closed, since the compressors may rewrite
+/// previously compressed bytes on every write. They are handed out in order from a frame cursor,
+/// which is rewound when a transaction fails or is reorged, so that derivation always sees the
+/// frames of a channel in order.
+#[derive(Debug)]
+pub(crate) struct PendingChannel<'a, C>
+where
+    C: ChannelCompressor,
+{
+    /// The builder of the channel.
+    builder: SpanBatchBuilder<'a, C>,
+    /// The type of batches written to the channel.
+    batch_type: BatchType,
+    /// The number of L2 blocks in the channel.
+    pub(crate) num_blocks: usize,
+    /// The L1 block number at which the channel was opened.
+    pub(crate) opened_at: u64,
+    /// The frames of the closed channel, indexed by frame number.
+    frames: Vec<Frame>,
+    /// The number of the next frame to submit.
+    cursor: usize,
+    /// The transactions carrying frames of the channel, in or before the cursor.
+    txs: BTreeMap<TxId, SubmittedTx>,
+}
+
+impl<'a, C> PendingChannel<'a, C>
+where
+    C: ChannelCompressor,
+{
+    /// Creates a new empty channel writing batches of the given type.
+    pub(crate) const fn new(
+        builder: SpanBatchBuilder<'a, C>,
+        batch_type: BatchType,
+        opened_at: u64,
+    ) -> Self {
+        Self {
+            builder,
+            batch_type,
+            num_blocks: 0,
+            opened_at,
+            frames: Vec::new(),
+            cursor: 0,
+            txs: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the id of the channel.
+    pub(crate) const fn id(&self) -> ChannelId {
+        self.builder.channel().id
+    }
+
+    /// Returns whether the channel is closed to new blocks.
+    pub(crate) const fn is_closed(&self) -> bool {
+        self.builder.channel().closed
+    }
+
+    /// Returns the number of compressed bytes in the channel, estimating the compressed size of
+    /// the pending span batch.
+    pub(crate) fn compressed_size(&self) -> usize {
+        self.builder.estimated_compressed_size()
+    }
+
+    /// Adds the batch of an L2 block to the channel.
+    ///
+    /// Span batches are written to the compressor at once when the channel is closed, which
+    /// cannot reject them as full only if nothing was written before. So once Delta activates,
+    /// a channel holding singular batches is reported as full instead of starting a span batch.
+    pub(crate) fn add_block(
+        &mut self,
+        info: L2BlockInfo,
+        batch: SingleBatch,
+    ) -> Result<(), SpanBatchBuilderError> {
+        match self.batch_type {
+            BatchType::Single => self.builder.channel_mut().add_batch(Batch::Single(batch))?,
+            BatchType::Span => {
+                if self.builder.config().is_delta_active(batch.timestamp) &&
+                    self.builder.pending_blocks() == 0 &&
+                    self.builder.input_bytes() > 0
+                {
+                    return Err(ChannelOutError::Compression(CompressorError::Full).into());
+                }
+                self.builder.add_l2_block(info, batch.transactions)?
+            }
+        }
+        self.num_blocks += 1;
+        Ok(())
+    }
+
+    /// Closes the channel and splits its compressed data into frames.
+    pub(crate) fn close(&mut self, max_frame_size: usize) -> Result<(), SpanBatchBuilderError> {
+        self.builder.flush()?;
+        let out = self.builder.channel_mut();
+        out.flush()?;
+        out.close();
+        loop {
+            let frame = out.output_frame(max_frame_size)?;
+            let is_last = frame.is_last;
+            self.frames.push(frame);
+            if is_last {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Returns whether frames are waiting to be submitted.
+    pub(crate) const fn has_pending_frames(&self) -> bool {
+        self.cursor < self.frames.len()
+    }
+
+    /// Returns whether any transaction of the channel is not confirmed yet.
+    pub(crate) fn has_in_flight_txs(&self) -> bool {
+        self.txs.values().any(|tx| tx.inclusion_block.is_none())
+    }
+
+    /// Takes up to `count` frames from the cursor for the given transaction.
+    pub(crate) fn take_frames(&mut self, id: TxId, count: usize) -> Vec<Frame> {
+        let frames = self.cursor..self.frames.len().min(self.cursor + count);
+        self.cursor = frames.end;
+        let taken = self.frames[frames.clone()].to_vec();
+        self.txs.insert(id, SubmittedTx { frames, inclusion_block: None });
+        taken
+    }
+
+    /// Returns whether the given transaction carries frames of this channel.
+    pub(crate) fn contains_tx(&self, id: TxId) -> bool {
+        self.txs.contains_key(&id)
+    }
+
+    /// Marks the given transaction as included in the given L1 block.
+    pub(crate) fn confirm(&mut self, id: TxId, block_number: u64) {
+        if let Some(tx) = self.txs.get_mut(&id) {
+            tx.inclusion_block = Some(block_number);
+        }
+    }
+
+    /// Rewinds the frame cursor to the first frame of the given transaction.
+    ///
+    /// The transaction and all transactions carrying later frames are forgotten, whether they are
+    /// in flight or confirmed, so that all of their frames are submitted again in order.
+    pub(crate) fn rewind(&mut self, id: TxId) {
+        let Some(start) = self.txs.get(&id).map(|tx| tx.frames.start) else {
+            return;
+        };
+        self.txs.retain(|_, tx| tx.frames.start < start);
+        self.cursor = start;
+    }
+
+    /// Rewinds the frame cursor to the first frame, forgetting all transactions.
+    pub(crate) fn rewind_all(&mut self) {
+        self.txs.clear();
+        self.cursor = 0;
+    }
+
+    /// Returns whether all frames of the closed channel are confirmed.
+    pub(crate) fn is_fully_confirmed(&self) -> bool {
+        self.is_closed() && !self.has_pending_frames() && !self.has_in_flight_txs()
+    }
+
+    /// Returns the lowest and highest L1 block numbers the confirmed frames were included in.
+    pub(crate) fn inclusion_range(&self) -> Option<(u64, u64)> {
+        let blocks = self.txs.values().filter_map(|tx| tx.inclusion_block);
+        let min = blocks.clone().min()?;
+        let max = blocks.max()?;
+        Some((min, max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_comp::{ChannelOut, CompressorWriter, test_utils::MockCompressor};
+    use kona_genesis::RollupConfig;
+
+    fn closed_channel(config: &RollupConfig) -> PendingChannel<'_, MockCompressor> {
+        let out = ChannelOut::new(ChannelId::default(), config, MockCompressor::default());
+        let mut channel = PendingChannel::new(SpanBatchBuilder::new(out), BatchType::Single, 0);
+        channel.builder.channel_mut().compressor.write(&[0xFF; 10]).unwrap();
+        channel.close(24).unwrap();
+        channel
+    }
+
+    #[test]
+    fn test_close_outputs_frames() {
+        let config = RollupConfig::default();
+        let channel = closed_channel(&config);
+        assert_eq!(channel.frames.len(), 10);
+        assert!(
+            channel
+                .frames
+                .iter()
+                .enumerate()
+                .all(|(number, frame)| frame.number as usize == number)
+        );
+        assert!(channel.frames.iter().take(9).all(|frame| !frame.is_last));
+        assert!(channel.frames[9].is_last);
+    }
+
+    #[test]
+    fn test_frames_are_resubmitted_in_order() {
+        let config = RollupConfig::default();
+        let mut channel = closed_channel(&config);
+        let numbers = |frames: Vec<Frame>| frames.iter().map(|f| f.number).collect::<Vec<_>>();
+
+        assert_eq!(numbers(channel.take_frames(TxId(0), 3)), vec![0, 1, 2]);
+        assert_eq!(numbers(channel.take_frames(TxId(1), 3)), vec![3, 4, 5]);
+        assert_eq!(numbers(channel.take_frames(TxId(2), 3)), vec![6, 7, 8]);
+        channel.confirm(TxId(0), 5);
+        channel.confirm(TxId(2), 6);
+
+        // The middle transaction failed, so its frames and all later frames are submitted again,
+        // even though the last transaction was already confirmed.
+        channel.rewind(TxId(1));
+        assert!(!channel.contains_tx(TxId(1)));
+        assert!(!channel.contains_tx(TxId(2)));
+        assert_eq!(channel.inclusion_range(), Some((5, 5)));
+        assert_eq!(numbers(channel.take_frames(TxId(3), 3)), vec![3, 4, 5]);
+        assert_eq!(numbers(channel.take_frames(TxId(4), 3)), vec![6, 7, 8]);
+        assert_eq!(numbers(channel.take_frames(TxId(5), 3)), vec![9]);
+        assert!(!channel.has_pending_frames());
+
+        for id in 3..=5 {
+            channel.confirm(TxId(id), 7);
+        }
+        assert!(channel.is_fully_confirmed());
+        assert_eq!(channel.inclusion_range(), Some((5, 7)));
+
+        // The first transaction was reorged out, so the whole channel is submitted again.
+        channel.rewind(TxId(0));
+        assert!(!channel.is_fully_confirmed());
+        assert_eq!(channel.inclusion_range(), None);
+        assert_eq!(numbers(channel.take_frames(TxId(6), 1)), vec![0]);
+    }
+}
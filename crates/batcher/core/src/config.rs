@@ -0,0 +1,170 @@
+//! Configuration of the [`ChannelManager`](crate::ChannelManager).
+
+use crate::ChannelConfigError;
+use kona_protocol::{BLOB_MAX_DATA_SIZE, BatchType};
+
+/// The maximum size of a calldata frame.
+///
+/// Matches the op-batcher default of 120KB transactions, minus the derivation version byte.
+pub const MAX_CALLDATA_FRAME_SIZE: usize = 120_000 - 1;
+
+/// The maximum size of a blob frame: the blob capacity minus the derivation version byte.
+pub const MAX_BLOB_FRAME_SIZE: usize = BLOB_MAX_DATA_SIZE - 1;
+
+/// The maximum number of blobs a single transaction may carry.
+pub const MAX_BLOBS_PER_TX: usize = 6;
+
+/// The smallest frame that can carry data: the frame header plus a single byte.
+const MIN_FRAME_SIZE: usize = 24;
+
+/// The way frames are posted to L1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DataAvailabilityType {
+    /// Frames are posted as transaction calldata.
+    #[default]
+    Calldata,
+    /// Frames are posted as EIP-4844 blobs, one frame per blob.
+    Blobs,
+}
+
+/// Configuration of the channels built by the [`ChannelManager`](crate::ChannelManager).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelConfig {
+    /// How frames are posted to L1.
+    pub da_type: DataAvailabilityType,
+    /// The maximum size of an encoded frame, in bytes.
+    pub max_frame_size: usize,
+    /// The number of frames packed into a single transaction. For blobs this is the number of
+    /// blobs per transaction. Channels are closed once their compressed data fills this many
+    /// frames.
+    pub target_num_frames: usize,
+    /// The number of L1 blocks a channel may stay open before it is closed. Zero disables the
+    /// limit.
+    pub max_channel_duration: u64,
+    /// The number of L1 blocks before the channel timeout at which a partially confirmed channel
+    /// is given up and its blocks are resubmitted in a new channel.
+    pub sub_safety_margin: u64,
+    /// The type of batches written to the channels. Span batches are only written once Delta is
+    /// active, and singular batches before that.
+    pub batch_type: BatchType,
+    /// The seed used to derive channel ids.
+    pub seed: u64,
+}
+
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        Self::calldata()
+    }
+}
+
+impl ChannelConfig {
+    /// Returns a config posting one maximum size frame per calldata transaction.
+    pub const fn calldata() -> Self {
+        Self {
+            da_type: DataAvailabilityType::Calldata,
+            max_frame_size: MAX_CALLDATA_FRAME_SIZE,
+            target_num_frames: 1,
+            max_channel_duration: 0,
+            sub_safety_margin: 10,
+            batch_type: BatchType::Single,
+            seed: 0,
+        }
+    }
+
+    /// Returns a config posting the given number of full blobs per transaction.
+    pub const fn blobs(target_num_frames: usize) -> Self {
+        Self {
+            da_type: DataAvailabilityType::Blobs,
+            max_frame_size: MAX_BLOB_FRAME_SIZE,
+            target_num_frames,
+            max_channel_duration: 0,
+            sub_safety_margin: 10,
+            batch_type: BatchType::Single,
+            seed: 0,
+        }
+    }
+
+    /// Sets the maximum frame size.
+    pub const fn with_max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.max_frame_size = max_frame_size;
+        self
+    }
+
+    /// Sets the maximum channel duration, in L1 blocks.
+    pub const fn with_max_channel_duration(mut self, max_channel_duration: u64) -> Self {
+        self.max_channel_duration = max_channel_duration;
+        self
+    }
+
+    /// Sets the sub safety margin, in L1 blocks.
+    pub const fn with_sub_safety_margin(mut self, sub_safety_margin: u64) -> Self {
+        self.sub_safety_margin = sub_safety_margin;
+        self
+    }
+
+    /// Sets the type of batches written to the channels.
+    pub const fn with_batch_type(mut self, batch_type: BatchType) -> Self {
+        self.batch_type = batch_type;
+        self
+    }
+
+    /// Sets the seed used to derive channel ids.
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns the compressed size at which a channel is closed.
+    pub const fn target_channel_size(&self) -> usize {
+        self.max_frame_size.saturating_mul(self.target_num_frames)
+    }
+
+    /// Checks that the frame sizes fit the data availability type.
+    pub const fn validate(&self) -> Result<(), ChannelConfigError> {
+        if self.target_num_frames == 0 {
+            return Err(ChannelConfigError::ZeroTargetNumFrames);
+        }
+        if self.max_frame_size < MIN_FRAME_SIZE {
+            return Err(ChannelConfigError::MaxFrameSizeTooSmall(self.max_frame_size));
+        }
+        if matches!(self.da_type, DataAvailabilityType::Blobs) {
+            if self.max_frame_size > MAX_BLOB_FRAME_SIZE {
+                return Err(ChannelConfigError::MaxFrameSizeTooLarge {
+                    size: self.max_frame_size,
+                    max: MAX_BLOB_FRAME_SIZE,
+                });
+            }
+            if self.target_num_frames > MAX_BLOBS_PER_TX {
+                return Err(ChannelConfigError::TooManyBlobs(self.target_num_frames));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_config() {
+        assert_eq!(ChannelConfig::calldata().validate(), Ok(()));
+        assert_eq!(ChannelConfig::blobs(6).validate(), Ok(()));
+        assert_eq!(
+            ChannelConfig::blobs(0).validate(),
+            Err(ChannelConfigError::ZeroTargetNumFrames)
+        );
+        assert_eq!(ChannelConfig::blobs(7).validate(), Err(ChannelConfigError::TooManyBlobs(7)));
+        assert_eq!(
+            ChannelConfig::calldata().with_max_frame_size(23).validate(),
+            Err(ChannelConfigError::MaxFrameSizeTooSmall(23))
+        );
+        assert_eq!(
+            ChannelConfig::blobs(1).with_max_frame_size(MAX_BLOB_FRAME_SIZE + 1).validate(),
+            Err(ChannelConfigError::MaxFrameSizeTooLarge {
+                size: MAX_BLOB_FRAME_SIZE + 1,
+                max: MAX_BLOB_FRAME_SIZE
+            })
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Error types for the batcher core.
+
+use alloy_primitives::B256;
+use kona_comp::{ChannelOutError, SpanBatchBuilderError};
+use kona_protocol::FromBlockError;
+
+/// An invalid [`ChannelConfig`](crate::ChannelConfig).
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelConfigError {
+    /// The target number of frames per transaction is zero.
+    #[error("Target number of frames must be at least one")]
+    ZeroTargetNumFrames,
+    /// The max frame size cannot fit the frame header and any data.
+    #[error("Max frame size too small: {0} bytes")]
+    MaxFrameSizeTooSmall(usize),
+    /// The max frame size does not fit into a blob.
+    #[error("Max frame size too large: {size} bytes, max {max}")]
+    MaxFrameSizeTooLarge {
+        /// The configured max frame size.
+        size: usize,
+        /// The largest frame that fits the data availability type.
+        max: usize,
+    },
+    /// More blobs per transaction than allowed.
+    #[error("Too many blobs per transaction: {0}")]
+    TooManyBlobs(usize),
+}
+
+/// An error returned by the [`ChannelManager`](crate::ChannelManager).
+#[derive(Debug, thiserror::Error)]
+pub enum ChannelManagerError {
+    /// The channel config is invalid.
+    #[error("Invalid channel config: {0}")]
+    Config(#[from] ChannelConfigError),
+    /// The added block does not build on the previously added block.
+    #[error("L2 reorg detected: block {number} has parent {parent}, expected {expected}")]
+    Reorg {
+        /// The number of the added block.
+        number: u64,
+        /// The parent hash of the added block.
+        parent: B256,
+        /// The hash of the previously added block.
+        expected: B256,
+    },
+    /// The L2 block info could not be derived from the block.
+    #[error("Invalid L2 block: {0}")]
+    InvalidBlock(#[from] FromBlockError),
+    /// A single block does not fit into an empty channel.
+    #[error("Block {0} does not fit into a channel")]
+    BlockTooLarge(u64),
+    /// An error building the channel.
+    #[error("Channel error: {0}")]
+    Channel(#[from] ChannelOutError),
+    /// An error building the span batch of the channel.
+    #[error("Span batch error: {0}")]
+    SpanBatch(#[from] SpanBatchBuilderError),
+}
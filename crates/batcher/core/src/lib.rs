@@ -0,0 +1,27 @@
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/op-rs/kona/main/assets/square.png",
+    html_favicon_url = "https://raw.githubusercontent.com/op-rs/kona/main/assets/favicon.ico",
+    issue_tracker_base_url = "https://github.com/op-rs/kona/issues/"
+)]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod channel;
+
+mod config;
+pub use config::{
+    ChannelConfig, DataAvailabilityType, MAX_BLOB_FRAME_SIZE, MAX_BLOBS_PER_TX,
+    MAX_CALLDATA_FRAME_SIZE,
+};
+
+mod errors;
+pub use errors::{ChannelConfigError, ChannelManagerError};
+
+mod manager;
+pub use manager::ChannelManager;
+
+mod tx;
+pub use tx::{TxCandidate, TxId};
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
@@ -0,0 +1,805 @@
+//! The [`ChannelManager`] turns L2 blocks into batcher transactions.
+
+use crate::{ChannelConfig, ChannelManagerError, TxCandidate, TxId, channel::PendingChannel};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::Bytes;
+use kona_comp::{
+    ChannelCompressor, ChannelOut, ChannelOutError, CompressorError, SpanBatchBuilder,
+    SpanBatchBuilderError,
+};
+use kona_genesis::RollupConfig;
+use kona_protocol::{BlockInfo, ChannelId, L2BlockInfo, SingleBatch};
+use op_alloy_consensus::OpBlock;
+use rand::{RngCore, SeedableRng, rngs::SmallRng};
+use std::collections::VecDeque;
+use tracing::{debug, info, warn};
+
+/// An L2 block waiting to be confirmed on L1.
+#[derive(Debug, Clone)]
+struct PendingBlock {
+    /// The L2 block.
+    info: L2BlockInfo,
+    /// The batch of the block.
+    batch: SingleBatch,
+}
+
+/// The channel manager turns a stream of L2 blocks into a deterministic queue of
+/// [`TxCandidate`]s.
+///
+/// Blocks are added with [`Self::add_block`] and packed into channels that are closed once they
+/// are full, once they were open for [`ChannelConfig::max_channel_duration`] L1 blocks, or when
+/// [`Self::close`] is called. The frames of closed channels are handed out by
+/// [`Self::next_tx`], and the outcome of every transaction is reported back through
+/// [`Self::tx_confirmed`], [`Self::tx_failed`] and [`Self::tx_reorged`].
+///
+/// Channels carry singular batches, or a single span batch once Delta is active if the
+/// [`ChannelConfig::batch_type`] is [`BatchType::Span`](kona_protocol::BatchType::Span).
+///
+/// Like the op-batcher, frames are submitted strictly in order: a channel is only handed out
+/// once all transactions of earlier channels are confirmed, and a failed or reorged transaction
+/// rewinds its frames, all later frames of its channel and all later channels, which are then
+/// submitted again.
+///
+/// Blocks are kept until their channel is fully confirmed. If a channel can no longer be
+/// confirmed within the channel timeout, it is dropped together with all later channels and
+/// its blocks are packed into a new channel.
+#[derive(Debug)]
+pub struct ChannelManager<'a, C>
+where
+    C: ChannelCompressor + Clone,
+{
+    /// The rollup config.
+    rollup_config: &'a RollupConfig,
+    /// The channel config.
+    config: ChannelConfig,
+    /// The compressor cloned into every new channel.
+    compressor: C,
+    /// The source of channel ids.
+    rng: SmallRng,
+    /// The blocks that are not yet fully confirmed.
+    blocks: VecDeque<PendingBlock>,
+    /// The index of the first block in [`Self::blocks`] that is not part of a channel.
+    block_cursor: usize,
+    /// The last added L2 block.
+    tip: Option<BlockInfo>,
+    /// The current L1 head.
+    l1_head: Option<BlockInfo>,
+    /// The channels that are not yet fully confirmed, oldest first.
+    channels: VecDeque<PendingChannel<'a, C>>,
+    /// The id of the next transaction.
+    next_tx_id: u64,
+}
+
+impl<'a, C> ChannelManager<'a, C>
+where
+    C: ChannelCompressor + Clone,
+{
+    /// Creates a new [`ChannelManager`].
+    ///
+    /// The given compressor is cloned into every new channel, so it should not contain any data.
+    pub fn new(
+        rollup_config: &'a RollupConfig,
+        config: ChannelConfig,
+        compressor: C,
+    ) -> Result<Self, ChannelManagerError> {
+        config.validate()?;
+        let rng = SmallRng::seed_from_u64(config.seed);
+        Ok(Self {
+            rollup_config,
+            config,
+            compressor,
+            rng,
+            blocks: VecDeque::new(),
+            block_cursor: 0,
+            tip: None,
+            l1_head: None,
+            channels: VecDeque::new(),
+            next_tx_id: 0,
+        })
+    }
+
+    /// Returns the channel config.
+    pub const fn config(&self) -> &ChannelConfig {
+        &self.config
+    }
+
+    /// Returns the last added L2 block.
+    pub const fn tip(&self) -> Option<BlockInfo> {
+        self.tip
+    }
+
+    /// Returns the number of blocks that are not yet fully confirmed.
+    pub fn pending_blocks(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns the number of channels that are not yet fully confirmed.
+    pub fn pending_channels(&self) -> usize {
+        self.channels.len()
+    }
+
+    /// Adds an L2 block to be batched.
+    ///
+    /// Returns [`ChannelManagerError::Reorg`] if the block does not build on the previously added
+    /// block, in which case the caller should [`Self::clear`] the manager.
+    pub fn add_block(&mut self, block: &OpBlock) -> Result<(), ChannelManagerError> {
+        let info = L2BlockInfo::from_block_and_genesis(block, &self.rollup_config.genesis)?;
+        let transactions = block
+            .body
+            .transactions
+            .iter()
+            .filter(|tx| !tx.is_deposit())
+            .map(|tx| Bytes::from(tx.encoded_2718()))
+            .collect();
+        let batch = SingleBatch {
+            parent_hash: block.header.parent_hash,
+            epoch_num: info.l1_origin.number,
+            epoch_hash: info.l1_origin.hash,
+            timestamp: block.header.timestamp,
+            transactions,
+        };
+        self.add_batch(info, batch)
+    }
+
+    /// Adds the batch of an L2 block to be batched.
+    ///
+    /// Returns [`ChannelManagerError::Reorg`] if the block does not build on the previously added
+    /// block, in which case the caller should [`Self::clear`] the manager.
+    pub fn add_batch(
+        &mut self,
+        info: L2BlockInfo,
+        batch: SingleBatch,
+    ) -> Result<(), ChannelManagerError> {
+        if let Some(tip) = self.tip.filter(|tip| tip.hash != info.block_info.parent_hash) {
+            return Err(ChannelManagerError::Reorg {
+                number: info.block_info.number,
+                parent: info.block_info.parent_hash,
+                expected: tip.hash,
+            });
+        }
+        self.tip = Some(info.block_info);
+        self.blocks.push_back(PendingBlock { info, batch });
+        Ok(())
+    }
+
+    /// Drops all blocks and channels, e.g. after an L2 reorg.
+    ///
+    /// Transactions that are still in flight are forgotten, and their outcome is ignored.
+    pub fn clear(&mut self) {
+        info!(
+            target: "batcher",
+            blocks = self.blocks.len(),
+            channels = self.channels.len(),
+            "Clearing channel manager state"
+        );
+        self.blocks.clear();
+        self.block_cursor = 0;
+        self.tip = None;
+        self.channels.clear();
+    }
+
+    /// Updates the L1 head.
+    ///
+    /// Closes the open channel once it exceeded the max channel duration, and drops channels
+    /// that cannot be confirmed before the channel timeout anymore.
+    pub fn update_l1_head(&mut self, head: BlockInfo) -> Result<(), ChannelManagerError> {
+        self.l1_head = Some(head);
+        self.fill_channels()?;
+
+        let max_duration = self.config.max_channel_duration;
+        if let Some(channel) = self.channels.back_mut().filter(|channel| {
+            !channel.is_closed() &&
+                max_duration > 0 &&
+                head.number >= channel.opened_at.saturating_add(max_duration)
+        }) {
+            debug!(
+                target: "batcher",
+                channel_id = ?channel.id(),
+                opened_at = channel.opened_at,
+                l1_head = head.number,
+                "Closing channel after max duration"
+            );
+            channel.close(self.config.max_frame_size)?;
+        }
+
+        let timeout = self.rollup_config.channel_timeout(head.timestamp);
+        let deadline = timeout.saturating_sub(self.config.sub_safety_margin);
+        if let Some(index) = self.channels.iter().position(|channel| {
+            !channel.is_fully_confirmed() &&
+                channel
+                    .inclusion_range()
+                    .is_some_and(|(min, _)| head.number >= min.saturating_add(deadline))
+        }) {
+            self.invalidate_channel(index);
+        }
+        Ok(())
+    }
+
+    /// Closes the open channel, so that all added blocks can be submitted.
+    pub fn close(&mut self) -> Result<(), ChannelManagerError> {
+        self.fill_channels()?;
+        if let Some(channel) = self.channels.back_mut().filter(|channel| !channel.is_closed()) {
+            channel.close(self.config.max_frame_size)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the next transaction to submit, if any.
+    ///
+    /// Frames are handed out in channel order, so that a channel is only submitted once all
+    /// transactions of earlier channels are confirmed.
+    pub fn next_tx(&mut self) -> Result<Option<TxCandidate>, ChannelManagerError> {
+        self.fill_channels()?;
+
+        let Some(index) = self.channels.iter().position(PendingChannel::has_pending_frames) else {
+            return Ok(None);
+        };
+        if self.channels.iter().take(index).any(PendingChannel::has_in_flight_txs) {
+            return Ok(None);
+        }
+
+        let channel = &mut self.channels[index];
+
+        let id = TxId(self.next_tx_id);
+        self.next_tx_id += 1;
+        let frames = channel.take_frames(id, self.config.target_num_frames);
+        debug!(
+            target: "batcher",
+            tx_id = id.0,
+            channel_id = ?channel.id(),
+            frames = frames.len(),
+            "Planned batcher transaction"
+        );
+        Ok(Some(TxCandidate { id, channel_id: channel.id(), frames, da_type: self.config.da_type }))
+    }
+
+    /// Records that the given transaction was included in the given L1 block.
+    pub fn tx_confirmed(&mut self, id: TxId, inclusion_block: BlockInfo) {
+        let Some(index) = self.channel_of(id) else {
+            warn!(target: "batcher", tx_id = id.0, "Confirmed transaction is unknown");
+            return;
+        };
+
+        let channel = &mut self.channels[index];
+        channel.confirm(id, inclusion_block.number);
+
+        // Derivation drops channels whose frames span more than the channel timeout.
+        let timeout = self.rollup_config.channel_timeout(inclusion_block.timestamp);
+        if channel.inclusion_range().is_some_and(|(min, max)| max - min >= timeout) {
+            self.invalidate_channel(index);
+            return;
+        }
+
+        // Release the blocks of all fully confirmed channels at the front of the queue.
+        while self.channels.front().is_some_and(PendingChannel::is_fully_confirmed) {
+            let Some(channel) = self.channels.pop_front() else {
+                break;
+            };
+            self.blocks.drain(..channel.num_blocks);
+            self.block_cursor -= channel.num_blocks;
+            info!(
+                target: "batcher",
+                channel_id = ?channel.id(),
+                blocks = channel.num_blocks,
+                "Channel fully confirmed"
+            );
+        }
+    }
+
+    /// Records that the given transaction failed, so its frames and all later frames are
+    /// submitted again.
+    pub fn tx_failed(&mut self, id: TxId) {
+        if !self.rewind(id) {
+            warn!(target: "batcher", tx_id = id.0, "Failed transaction is unknown");
+        }
+    }
+
+    /// Records that the given confirmed transaction was reorged out of L1, so its frames and all
+    /// later frames are submitted again.
+    pub fn tx_reorged(&mut self, id: TxId) {
+        if !self.rewind(id) {
+            warn!(target: "batcher", tx_id = id.0, "Reorged transaction is unknown");
+        }
+    }
+
+    /// Returns the index of the channel carrying the given transaction.
+    fn channel_of(&self, id: TxId) -> Option<usize> {
+        self.channels.iter().position(|channel| channel.contains_tx(id))
+    }
+
+    /// Rewinds the frames of the given transaction, all later frames of its channel and all
+    /// later channels. Returns whether the transaction is known.
+    ///
+    /// The outcome of the transactions carrying the rewound frames is ignored from then on.
+    fn rewind(&mut self, id: TxId) -> bool {
+        let Some(index) = self.channel_of(id) else {
+            return false;
+        };
+        self.channels[index].rewind(id);
+        for channel in self.channels.iter_mut().skip(index + 1) {
+            channel.rewind_all();
+        }
+        debug!(
+            target: "batcher",
+            tx_id = id.0,
+            channel_id = ?self.channels[index].id(),
+            later_channels = self.channels.len() - index - 1,
+            "Rewound frames for resubmission"
+        );
+        true
+    }
+
+    /// Drops the channel at the given index and all later channels, and rewinds the block
+    /// cursor to the first block of the dropped channel.
+    fn invalidate_channel(&mut self, index: usize) {
+        let cursor = self.channels.iter().take(index).map(|channel| channel.num_blocks).sum();
+        warn!(
+            target: "batcher",
+            channel_id = ?self.channels[index].id(),
+            dropped_channels = self.channels.len() - index,
+            requeued_blocks = self.block_cursor - cursor,
+            "Channel timed out, resubmitting its blocks"
+        );
+        self.channels.truncate(index);
+        self.block_cursor = cursor;
+    }
+
+    /// Packs all blocks that are not part of a channel yet into channels.
+    fn fill_channels(&mut self) -> Result<(), ChannelManagerError> {
+        while self.block_cursor < self.blocks.len() {
+            if self.channels.back().is_none_or(PendingChannel::is_closed) {
+                self.open_channel();
+            }
+            let Some(channel) = self.channels.back_mut() else {
+                break;
+            };
+
+            let block = &self.blocks[self.block_cursor];
+            match channel.add_block(block.info, block.batch.clone()) {
+                Ok(()) => {
+                    self.block_cursor += 1;
+                    if channel.compressed_size() >= self.config.target_channel_size() {
+                        channel.close(self.config.max_frame_size)?;
+                    }
+                }
+                Err(SpanBatchBuilderError::Channel(
+                    ChannelOutError::Compression(CompressorError::Full) |
+                    ChannelOutError::ExceedsMaxRlpBytesPerChannel,
+                )) => {
+                    if channel.num_blocks == 0 {
+                        return Err(ChannelManagerError::BlockTooLarge(
+                            block.info.block_info.number,
+                        ));
+                    }
+                    channel.close(self.config.max_frame_size)?;
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Opens a new channel at the end of the queue.
+    fn open_channel(&mut self) {
+        let mut id = ChannelId::default();
+        self.rng.fill_bytes(&mut id);
+        let mut compressor = self.compressor.clone();
+        compressor.reset();
+        let opened_at = self.l1_head.map(|head| head.number).unwrap_or_default();
+        debug!(target: "batcher", channel_id = ?id, opened_at, "Opened channel");
+        self.channels.push_back(PendingChannel::new(
+            SpanBatchBuilder::new(ChannelOut::new(id, self.rollup_config, compressor)),
+            self.config.batch_type.clone(),
+            opened_at,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DataAvailabilityType, test_utils::TestL1};
+    use alloy_consensus::{SignableTransaction, TxEip1559, TxEnvelope};
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::{Address, B256, Signature, TxKind};
+    use kona_comp::ZlibCompressor;
+    use kona_protocol::{Batch, BatchReader, BatchType, Channel};
+    use rand::Rng;
+
+    fn rollup_config() -> RollupConfig {
+        RollupConfig {
+            channel_timeout: 50,
+            block_time: 2,
+            l2_chain_id: 10u64.into(),
+            ..Default::default()
+        }
+    }
+
+    fn block_hash(number: u64) -> B256 {
+        B256::left_padding_from(&number.to_be_bytes())
+    }
+
+    /// Returns an L2 block with a batch of incompressible transactions.
+    fn block(number: u64) -> (L2BlockInfo, SingleBatch) {
+        let mut rng = SmallRng::seed_from_u64(number);
+        let transactions = (0..4)
+            .map(|i| {
+                let mut input = vec![0u8; 100];
+                rng.fill(input.as_mut_slice());
+                let tx = TxEip1559 {
+                    chain_id: 10,
+                    nonce: number * 4 + i,
+                    gas_limit: 50_000,
+                    to: TxKind::Call(Address::repeat_byte(1)),
+                    input: input.into(),
+                    ..Default::default()
+                };
+                let tx: TxEnvelope = tx.into_signed(Signature::test_signature()).into();
+                Bytes::from(tx.encoded_2718())
+            })
+            .collect();
+        let info = L2BlockInfo::new(
+            BlockInfo::new(block_hash(number), number, block_hash(number - 1), number * 2),
+            BlockNumHash { number: 1, hash: B256::with_last_byte(1) },
+            number - 1,
+        );
+        let batch = SingleBatch {
+            parent_hash: block_hash(number - 1),
+            epoch_num: 1,
+            epoch_hash: B256::with_last_byte(1),
+            timestamp: number * 2,
+            transactions,
+        };
+        (info, batch)
+    }
+
+    fn manager(
+        rollup_config: &RollupConfig,
+        config: ChannelConfig,
+        blocks: u64,
+    ) -> ChannelManager<'_, ZlibCompressor> {
+        let mut manager =
+            ChannelManager::new(rollup_config, config, ZlibCompressor::new()).unwrap();
+        for number in 1..=blocks {
+            let (info, batch) = block(number);
+            manager.add_batch(info, batch).unwrap();
+        }
+        manager
+    }
+
+    /// Submits all planned transactions and mines a block including them.
+    fn submit_and_mine(manager: &mut ChannelManager<'_, ZlibCompressor>, l1: &mut TestL1) {
+        while let Some(tx) = manager.next_tx().unwrap() {
+            l1.submit(tx);
+        }
+        let block = l1.mine();
+        for tx in &block.txs {
+            manager.tx_confirmed(tx.id, block.info);
+        }
+        manager.update_l1_head(block.info).unwrap();
+    }
+
+    /// Submits and mines transactions until all channels are confirmed.
+    fn submit_all(manager: &mut ChannelManager<'_, ZlibCompressor>, l1: &mut TestL1) {
+        for _ in 0..100 {
+            if manager.pending_channels() == 0 {
+                return;
+            }
+            submit_and_mine(manager, l1);
+        }
+        panic!("channels were not confirmed");
+    }
+
+    /// Reassembles the channels posted on L1 and decodes their batches.
+    fn posted(config: &RollupConfig, l1: &TestL1) -> Vec<Batch> {
+        let mut channels: Vec<Channel> = Vec::new();
+        for (block, frame) in l1.frames() {
+            let index = match channels.iter().position(|channel| channel.id() == frame.id) {
+                Some(index) => index,
+                None => {
+                    channels.push(Channel::new(frame.id, block));
+                    channels.len() - 1
+                }
+            };
+            channels[index].add_frame(frame, block).unwrap();
+        }
+
+        let mut batches = Vec::new();
+        for channel in channels.iter().filter(|channel| channel.is_ready()) {
+            let mut reader = BatchReader::new(channel.frame_data().unwrap(), usize::MAX);
+            while let Some(batch) = reader.next_batch(config) {
+                batches.push(batch);
+            }
+        }
+        batches
+    }
+
+    /// Returns the singular batches posted on L1.
+    fn posted_batches(config: &RollupConfig, l1: &TestL1) -> Vec<SingleBatch> {
+        posted(config, l1)
+            .into_iter()
+            .map(|batch| {
+                let Batch::Single(batch) = batch else { panic!("unexpected span batch") };
+                batch
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let config = rollup_config();
+        let result = ChannelManager::new(&config, ChannelConfig::blobs(0), ZlibCompressor::new());
+        assert!(matches!(result, Err(ChannelManagerError::Config(_))));
+    }
+
+    #[test]
+    fn test_reorg_detected() {
+        let config = rollup_config();
+        let mut manager = manager(&config, ChannelConfig::calldata(), 2);
+        let (info, batch) = block(4);
+        let err = manager.add_batch(info, batch).unwrap_err();
+        assert!(matches!(err, ChannelManagerError::Reorg { number: 4, .. }));
+
+        manager.clear();
+        let (info, batch) = block(4);
+        manager.add_batch(info, batch).unwrap();
+        assert_eq!(manager.pending_blocks(), 1);
+    }
+
+    #[test]
+    fn test_open_channel_is_not_submitted() {
+        let config = rollup_config();
+        let mut manager = manager(&config, ChannelConfig::calldata(), 3);
+        assert_eq!(manager.next_tx().unwrap(), None);
+        assert_eq!(manager.pending_channels(), 1);
+
+        manager.close().unwrap();
+        let tx = manager.next_tx().unwrap().unwrap();
+        assert_eq!(tx.frames.len(), 1);
+        assert!(tx.frames[0].is_last);
+    }
+
+    #[test]
+    fn test_calldata_channels_roundtrip() {
+        let config = rollup_config();
+        let channel_config = ChannelConfig::calldata().with_max_frame_size(500);
+        let mut manager = manager(&config, channel_config, 20);
+        let mut l1 = TestL1::new();
+
+        manager.close().unwrap();
+        submit_all(&mut manager, &mut l1);
+
+        assert_eq!(manager.pending_blocks(), 0);
+        assert_eq!(manager.pending_channels(), 0);
+        let expected = (1..=20).map(|number| block(number).1).collect::<Vec<_>>();
+        assert_eq!(posted_batches(&config, &l1), expected);
+    }
+
+    #[test]
+    fn test_span_batch_channels_roundtrip() {
+        let mut config = rollup_config();
+        config.hardforks.delta_time = Some(0);
+        let channel_config =
+            ChannelConfig::calldata().with_max_frame_size(500).with_batch_type(BatchType::Span);
+        let mut manager = manager(&config, channel_config, 20);
+        let mut l1 = TestL1::new();
+
+        manager.close().unwrap();
+        submit_all(&mut manager, &mut l1);
+        assert_eq!(manager.pending_blocks(), 0);
+
+        let elements = posted(&config, &l1)
+            .into_iter()
+            .flat_map(|batch| {
+                let Batch::Span(span) = batch else { panic!("unexpected singular batch") };
+                span.batches
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(elements.len(), 20);
+        for (number, element) in (1..=20).zip(elements) {
+            let (_, batch) = block(number);
+            assert_eq!(element.timestamp, batch.timestamp);
+            assert_eq!(element.transactions, batch.transactions);
+        }
+    }
+
+    #[test]
+    fn test_span_batches_start_a_new_channel_at_delta() {
+        let mut config = rollup_config();
+        config.hardforks.delta_time = Some(6);
+        let channel_config = ChannelConfig::calldata().with_batch_type(BatchType::Span);
+        let mut manager = manager(&config, channel_config, 4);
+        let mut l1 = TestL1::new();
+
+        // Blocks 1 and 2 are batched as singular batches, and the span batch of blocks 3 and 4
+        // starts a new channel.
+        manager.close().unwrap();
+        assert_eq!(manager.pending_channels(), 2);
+        submit_all(&mut manager, &mut l1);
+
+        let batches = posted(&config, &l1);
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0], Batch::Single(block(1).1));
+        assert_eq!(batches[1], Batch::Single(block(2).1));
+        let Batch::Span(span) = &batches[2] else { panic!("expected a span batch") };
+        assert_eq!(span.batches.len(), 2);
+        assert_eq!(span.batches[0].timestamp, 6);
+    }
+
+    #[test]
+    fn test_blob_frames_are_packed() {
+        let config = rollup_config();
+        let channel_config = ChannelConfig::blobs(3).with_max_frame_size(500);
+        let mut manager = manager(&config, channel_config, 20);
+
+        let tx = manager.next_tx().unwrap().unwrap();
+        assert_eq!(tx.da_type, DataAvailabilityType::Blobs);
+        assert_eq!(tx.frames.len(), 3);
+        assert_eq!(tx.blobs().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_deterministic_tx_queue() {
+        let config = rollup_config();
+        let channel_config = ChannelConfig::calldata().with_max_frame_size(500).with_seed(7);
+        let mut first = manager(&config, channel_config.clone(), 10);
+        let mut second = manager(&config, channel_config, 10);
+        first.close().unwrap();
+        second.close().unwrap();
+
+        while let Some(tx) = first.next_tx().unwrap() {
+            assert_eq!(second.next_tx().unwrap(), Some(tx));
+        }
+        assert_eq!(second.next_tx().unwrap(), None);
+    }
+
+    #[test]
+    fn test_failed_tx_is_resubmitted() {
+        let config = rollup_config();
+        let channel_config = ChannelConfig::calldata().with_max_frame_size(500);
+        let mut manager = manager(&config, channel_config, 5);
+        let mut l1 = TestL1::new();
+        manager.close().unwrap();
+
+        let failed = manager.next_tx().unwrap().unwrap();
+        manager.tx_failed(failed.id);
+
+        let retried = manager.next_tx().unwrap().unwrap();
+        assert_ne!(retried.id, failed.id);
+        assert_eq!(retried.frames, failed.frames);
+
+        l1.submit(retried);
+        submit_all(&mut manager, &mut l1);
+        assert_eq!(manager.pending_blocks(), 0);
+        assert_eq!(posted_batches(&config, &l1).len(), 5);
+    }
+
+    #[test]
+    fn test_reorged_tx_is_resubmitted() {
+        let config = rollup_config();
+        let channel_config = ChannelConfig::calldata().with_max_frame_size(500);
+        let mut manager = manager(&config, channel_config, 5);
+        let mut l1 = TestL1::new();
+        manager.close().unwrap();
+
+        // Only the first frame is confirmed before the reorg.
+        l1.submit(manager.next_tx().unwrap().unwrap());
+        let block = l1.mine();
+        manager.tx_confirmed(block.txs[0].id, block.info);
+        for id in l1.reorg(1) {
+            manager.tx_reorged(id);
+        }
+
+        submit_all(&mut manager, &mut l1);
+        assert_eq!(manager.pending_blocks(), 0);
+        assert_eq!(posted_batches(&config, &l1).len(), 5);
+    }
+
+    #[test]
+    fn test_frames_are_submitted_in_order() {
+        let config = rollup_config();
+        let channel_config = ChannelConfig::calldata().with_max_frame_size(200);
+        let mut manager = manager(&config, channel_config, 2);
+        let mut l1 = TestL1::new();
+        manager.close().unwrap();
+        assert_eq!(manager.pending_channels(), 2);
+
+        // The second channel is not handed out while frames of the first one are in flight.
+        let mut txs = Vec::new();
+        while let Some(tx) = manager.next_tx().unwrap() {
+            txs.push(tx);
+        }
+        assert!(txs.len() >= 3);
+        assert!(txs.iter().all(|tx| tx.channel_id == txs[0].channel_id));
+
+        // The second transaction failed, so its frames and all later frames are rewound.
+        l1.submit(txs[0].clone());
+        let inclusion = l1.mine();
+        manager.tx_confirmed(txs[0].id, inclusion.info);
+        manager.tx_failed(txs[1].id);
+
+        let mut retried = Vec::new();
+        while let Some(tx) = manager.next_tx().unwrap() {
+            retried.push(tx);
+        }
+        let frames =
+            |txs: &[TxCandidate]| txs.iter().flat_map(|tx| tx.frames.clone()).collect::<Vec<_>>();
+        assert_eq!(frames(&retried), frames(&txs[1..]));
+
+        // The outcome of a rewound transaction is ignored.
+        manager.tx_confirmed(txs[2].id, inclusion.info);
+        assert_eq!(manager.pending_channels(), 2);
+
+        for tx in retried {
+            l1.submit(tx);
+        }
+        let inclusion = l1.mine();
+        for tx in &inclusion.txs {
+            manager.tx_confirmed(tx.id, inclusion.info);
+        }
+        assert_eq!(manager.pending_channels(), 1);
+        let tx = manager.next_tx().unwrap().unwrap();
+        assert_ne!(tx.channel_id, txs[0].channel_id);
+        assert_eq!(tx.frames[0].number, 0);
+
+        l1.submit(tx);
+        submit_all(&mut manager, &mut l1);
+        let expected = (1..=2).map(|number| block(number).1).collect::<Vec<_>>();
+        assert_eq!(posted_batches(&config, &l1), expected);
+    }
+
+    #[test]
+    fn test_max_channel_duration() {
+        let config = rollup_config();
+        let channel_config = ChannelConfig::calldata().with_max_channel_duration(2);
+        let mut manager = manager(&config, channel_config, 3);
+        let mut l1 = TestL1::new();
+
+        manager.update_l1_head(l1.mine().info).unwrap();
+        assert_eq!(manager.next_tx().unwrap(), None);
+        manager.update_l1_head(l1.mine().info).unwrap();
+        assert_eq!(manager.next_tx().unwrap(), None);
+        manager.update_l1_head(l1.mine().info).unwrap();
+        assert!(manager.next_tx().unwrap().is_some());
+    }
+
+    #[test]
+    fn test_timed_out_channel_is_resubmitted() {
+        let config = rollup_config();
+        let channel_config =
+            ChannelConfig::calldata().with_max_frame_size(500).with_sub_safety_margin(10);
+        let mut manager = manager(&config, channel_config, 5);
+        let mut l1 = TestL1::new();
+        manager.close().unwrap();
+
+        // The first frame is confirmed, but the others are stuck.
+        let first = manager.next_tx().unwrap().unwrap();
+        let stuck = manager.next_tx().unwrap().unwrap();
+        l1.submit(first.clone());
+        let block = l1.mine();
+        manager.tx_confirmed(first.id, block.info);
+        manager.update_l1_head(block.info).unwrap();
+        while l1.head().number < block.info.number + 40 {
+            manager.update_l1_head(l1.mine().info).unwrap();
+        }
+
+        // The channel can no longer be confirmed in time, so its blocks go into a new channel.
+        assert_eq!(manager.pending_channels(), 0);
+        manager.tx_failed(stuck.id);
+        manager.close().unwrap();
+        let tx = manager.next_tx().unwrap().unwrap();
+        assert_ne!(tx.channel_id, first.channel_id);
+        assert_eq!(tx.frames[0].number, 0);
+        assert_eq!(manager.pending_blocks(), 5);
+    }
+
+    #[test]
+    fn test_block_too_large() {
+        let config = rollup_config();
+        let mut manager = manager(&config, ChannelConfig::calldata(), 0);
+        let (info, mut batch) = block(1);
+        batch.transactions = vec![Bytes::from(vec![0u8; 11_000_000])];
+        manager.add_batch(info, batch).unwrap();
+        assert!(matches!(manager.next_tx(), Err(ChannelManagerError::BlockTooLarge(1))));
+    }
+}
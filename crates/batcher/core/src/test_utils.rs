@@ -0,0 +1,107 @@
+//! Test utilities for the batcher core.
+
+use crate::{DataAvailabilityType, TxCandidate, TxId};
+use alloy_primitives::keccak256;
+use kona_protocol::{BlockInfo, Frame};
+
+/// The L1 block time of the [`TestL1`], in seconds.
+const L1_BLOCK_TIME: u64 = 12;
+
+/// A block mined by the [`TestL1`].
+#[derive(Debug, Clone)]
+pub struct TestL1Block {
+    /// The block.
+    pub info: BlockInfo,
+    /// The batcher transactions included in the block.
+    pub txs: Vec<TxCandidate>,
+}
+
+/// An in-memory stand-in for L1 that includes batcher transactions into blocks.
+#[derive(Debug, Clone)]
+pub struct TestL1 {
+    /// The canonical chain, starting at the genesis block.
+    pub blocks: Vec<TestL1Block>,
+    /// The transactions waiting to be included.
+    pub mempool: Vec<TxCandidate>,
+    /// The number of reorgs so far, used to give replaced blocks new hashes.
+    reorgs: u64,
+}
+
+impl Default for TestL1 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestL1 {
+    /// Creates a new [`TestL1`] with only a genesis block.
+    pub fn new() -> Self {
+        let genesis = BlockInfo { hash: keccak256([0u8; 16]), ..Default::default() };
+        Self {
+            blocks: vec![TestL1Block { info: genesis, txs: vec![] }],
+            mempool: vec![],
+            reorgs: 0,
+        }
+    }
+
+    /// Returns the head of the chain.
+    pub fn head(&self) -> BlockInfo {
+        self.blocks.last().map(|block| block.info).unwrap_or_default()
+    }
+
+    /// Adds a transaction to the mempool.
+    pub fn submit(&mut self, tx: TxCandidate) {
+        self.mempool.push(tx);
+    }
+
+    /// Removes a transaction from the mempool, as if it failed. Returns whether it was found.
+    pub fn drop_tx(&mut self, id: TxId) -> bool {
+        let len = self.mempool.len();
+        self.mempool.retain(|tx| tx.id != id);
+        self.mempool.len() != len
+    }
+
+    /// Mines a block including all transactions in the mempool, and returns it.
+    pub fn mine(&mut self) -> TestL1Block {
+        let parent = self.head();
+        let number = parent.number + 1;
+        let mut seed = [0u8; 16];
+        seed[..8].copy_from_slice(&number.to_be_bytes());
+        seed[8..].copy_from_slice(&self.reorgs.to_be_bytes());
+        let info = BlockInfo {
+            hash: keccak256(seed),
+            number,
+            parent_hash: parent.hash,
+            timestamp: parent.timestamp + L1_BLOCK_TIME,
+        };
+        let block = TestL1Block { info, txs: std::mem::take(&mut self.mempool) };
+        self.blocks.push(block.clone());
+        block
+    }
+
+    /// Removes the given number of blocks from the head of the chain, and returns the ids of the
+    /// transactions they included. The transactions are not added back to the mempool.
+    pub fn reorg(&mut self, depth: usize) -> Vec<TxId> {
+        self.reorgs += 1;
+        let keep = self.blocks.len().saturating_sub(depth).max(1);
+        self.blocks.drain(keep..).flat_map(|block| block.txs.into_iter().map(|tx| tx.id)).collect()
+    }
+
+    /// Returns all frames posted on the canonical chain, with the block they were included in.
+    pub fn frames(&self) -> Vec<(BlockInfo, Frame)> {
+        self.blocks
+            .iter()
+            .flat_map(|block| {
+                block.txs.iter().flat_map(move |tx| {
+                    let frames = match tx.da_type {
+                        DataAvailabilityType::Calldata => {
+                            Frame::parse_frames(&tx.calldata()).expect("valid calldata")
+                        }
+                        DataAvailabilityType::Blobs => tx.frames.clone(),
+                    };
+                    frames.into_iter().map(move |frame| (block.info, frame))
+                })
+            })
+            .collect()
+    }
+}
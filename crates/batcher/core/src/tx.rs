@@ -0,0 +1,81 @@
+//! Transactions planned by the [`ChannelManager`](crate::ChannelManager).
+
+use crate::DataAvailabilityType;
+use alloy_eips::eip4844::Blob;
+use alloy_primitives::Bytes;
+use kona_protocol::{BlobEncodingError, ChannelId, DERIVATION_VERSION_0, Frame, encode_blob};
+
+/// The identifier of a [`TxCandidate`], unique within a [`ChannelManager`](crate::ChannelManager).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TxId(pub u64);
+
+/// A batcher transaction that is ready to be signed and sent to L1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxCandidate {
+    /// The identifier used to report the outcome of the transaction.
+    pub id: TxId,
+    /// The channel the frames belong to.
+    pub channel_id: ChannelId,
+    /// The frames carried by the transaction.
+    pub frames: Vec<Frame>,
+    /// How the frames are posted.
+    pub da_type: DataAvailabilityType,
+}
+
+impl TxCandidate {
+    /// Returns the calldata carrying all frames: `DERIVATION_VERSION_0 ++ frame ++ frame ...`.
+    pub fn calldata(&self) -> Bytes {
+        let mut data = vec![DERIVATION_VERSION_0];
+        for frame in &self.frames {
+            data.extend_from_slice(&frame.encode());
+        }
+        data.into()
+    }
+
+    /// Returns one blob per frame, each carrying `DERIVATION_VERSION_0 ++ frame`.
+    pub fn blobs(&self) -> Result<Vec<Blob>, BlobEncodingError> {
+        self.frames
+            .iter()
+            .map(|frame| {
+                let mut data = vec![DERIVATION_VERSION_0];
+                data.extend_from_slice(&frame.encode());
+                encode_blob(&data)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate() -> TxCandidate {
+        let channel_id = [0xAA; 16];
+        TxCandidate {
+            id: TxId(0),
+            channel_id,
+            frames: vec![
+                Frame::new(channel_id, 0, vec![1, 2, 3], false),
+                Frame::new(channel_id, 1, vec![4], true),
+            ],
+            da_type: DataAvailabilityType::Calldata,
+        }
+    }
+
+    #[test]
+    fn test_calldata_roundtrip() {
+        let tx = candidate();
+        assert_eq!(Frame::parse_frames(&tx.calldata()).unwrap(), tx.frames);
+    }
+
+    #[test]
+    fn test_blobs() {
+        let tx = candidate();
+        let blobs = tx.blobs().unwrap();
+        assert_eq!(blobs.len(), 2);
+
+        let mut data = vec![DERIVATION_VERSION_0];
+        data.extend_from_slice(&tx.frames[1].encode());
+        assert_eq!(blobs[1], encode_blob(&data).unwrap());
+    }
+}
@@ -0,0 +1,79 @@
+//! Configuration of the [`BatcherService`](crate::BatcherService).
+
+use kona_batcher_core::ChannelConfig;
+use kona_comp::{CompressionAlgo, CompressorType, Config, DEFAULT_APPROX_COMPR_RATIO};
+use kona_genesis::RollupConfig;
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+/// Configuration of the [`TxManager`](crate::TxManager).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxManagerConfig {
+    /// The number of L1 blocks on top of the inclusion block after which a transaction is
+    /// confirmed.
+    pub num_confirmations: u64,
+    /// The number of L1 blocks to wait for a transaction to be included before it is resubmitted
+    /// with bumped fees.
+    pub resubmission_timeout: u64,
+    /// The maximum number of transactions in flight.
+    pub max_pending_txs: usize,
+}
+
+impl Default for TxManagerConfig {
+    fn default() -> Self {
+        Self { num_confirmations: 10, resubmission_timeout: 4, max_pending_txs: 1 }
+    }
+}
+
+/// Configuration of the [`BatcherService`](crate::BatcherService).
+#[derive(Debug, Clone)]
+pub struct BatcherConfig {
+    /// The rollup config of the L2 chain.
+    pub rollup_config: Arc<RollupConfig>,
+    /// The configuration of the channels.
+    pub channel: ChannelConfig,
+    /// The kind of compressor deciding when a channel is full.
+    pub compressor_kind: CompressorType,
+    /// The compression algorithm.
+    pub compression_algo: CompressionAlgo,
+    /// The compression ratio assumed by the ratio compressor.
+    pub approx_compr_ratio: f64,
+    /// The configuration of the transaction manager.
+    pub tx_manager: TxManagerConfig,
+    /// The interval at which the L1 head and the sync status are polled.
+    pub poll_interval: Duration,
+    /// Whether the batcher waits for `admin_startBatcher` before submitting.
+    pub start_stopped: bool,
+    /// The address of the admin RPC server.
+    pub rpc_addr: SocketAddr,
+}
+
+impl BatcherConfig {
+    /// Creates a new [`BatcherConfig`] with the default settings for the given rollup config.
+    pub fn new(rollup_config: Arc<RollupConfig>) -> Self {
+        Self {
+            rollup_config,
+            channel: ChannelConfig::default(),
+            compressor_kind: CompressorType::Shadow,
+            compression_algo: CompressionAlgo::Zlib,
+            approx_compr_ratio: DEFAULT_APPROX_COMPR_RATIO,
+            tx_manager: TxManagerConfig::default(),
+            poll_interval: Duration::from_secs(6),
+            start_stopped: false,
+            rpc_addr: SocketAddr::from((Ipv4Addr::LOCALHOST, 8548)),
+        }
+    }
+
+    /// Returns the compressor config, targeting the channel size of the channel config.
+    pub fn compressor_config(&self) -> Config {
+        Config {
+            target_output_size: self.channel.target_channel_size() as u64,
+            approx_compr_ratio: self.approx_compr_ratio,
+            kind: self.compressor_kind,
+            compression_algo: self.compression_algo,
+        }
+    }
+}
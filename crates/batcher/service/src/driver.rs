@@ -0,0 +1,444 @@
+//! The [`BatchSubmitter`] drives the channel manager and the transaction manager.
+
+use crate::{
+    BatcherAdminError, BatcherAdminQuery, BatcherConfig, L1Client, L2Source, SourceError,
+    TxManager, TxManagerError, TxOutcome,
+};
+use kona_batcher_core::{ChannelManager, ChannelManagerError};
+use kona_comp::BatcherCompressor;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, warn};
+
+/// An error returned by the [`BatchSubmitter`].
+#[derive(Debug, thiserror::Error)]
+pub enum BatchSubmitterError {
+    /// An error from the L1 or L2 sources.
+    #[error(transparent)]
+    Source(#[from] SourceError),
+    /// An error from the channel manager.
+    #[error(transparent)]
+    ChannelManager(#[from] ChannelManagerError),
+    /// An error from the transaction manager.
+    #[error(transparent)]
+    TxManager(#[from] TxManagerError),
+}
+
+/// The batch submitter loads unsafe L2 blocks, packs them into channels and submits the frames
+/// to L1.
+///
+/// Every [`Self::step`] reports the confirmed, reorged and failed transactions to the channel
+/// manager, loads the new unsafe blocks up to the unsafe head of the rollup node, and sends the
+/// planned transactions while the transaction manager has capacity. When the batcher is stopped
+/// through the admin API, the open channel is closed and no new blocks are loaded, but the
+/// remaining frames are still submitted.
+#[derive(Debug)]
+pub struct BatchSubmitter<L1, L2> {
+    /// The batcher config.
+    config: BatcherConfig,
+    /// The L1 client.
+    l1: L1,
+    /// The L2 source.
+    l2: L2,
+    /// The transaction manager.
+    txmgr: TxManager,
+    /// The receiver of admin queries.
+    admin: mpsc::Receiver<BatcherAdminQuery>,
+    /// Whether new L2 blocks are loaded.
+    active: bool,
+}
+
+impl<L1, L2> BatchSubmitter<L1, L2>
+where
+    L1: L1Client,
+    L2: L2Source,
+{
+    /// Creates a new [`BatchSubmitter`].
+    pub const fn new(
+        config: BatcherConfig,
+        l1: L1,
+        l2: L2,
+        txmgr: TxManager,
+        admin: mpsc::Receiver<BatcherAdminQuery>,
+    ) -> Self {
+        let active = !config.start_stopped;
+        Self { config, l1, l2, txmgr, admin, active }
+    }
+
+    /// Returns whether new L2 blocks are loaded.
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Returns the transaction manager.
+    pub const fn txmgr(&self) -> &TxManager {
+        &self.txmgr
+    }
+
+    /// Creates the channel manager for the given rollup config.
+    pub fn channel_manager<'a>(
+        &self,
+        rollup_config: &'a kona_genesis::RollupConfig,
+    ) -> Result<ChannelManager<'a, BatcherCompressor>, ChannelManagerError> {
+        let compressor = BatcherCompressor::from(self.config.compressor_config());
+        ChannelManager::new(rollup_config, self.config.channel.clone(), compressor)
+    }
+
+    /// Runs the batch submitter until the cancellation token is cancelled.
+    pub async fn run(mut self, cancel: CancellationToken) -> Result<(), BatchSubmitterError> {
+        let rollup_config = Arc::clone(&self.config.rollup_config);
+        let mut manager = self.channel_manager(&rollup_config)?;
+        let mut interval = tokio::time::interval(self.config.poll_interval);
+        kona_macros::set!(gauge, crate::Metrics::BATCHER_ACTIVE, self.active as u8 as f64);
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    info!(target: "batcher", "Received shutdown signal, stopping batch submitter");
+                    return Ok(());
+                }
+                Some(query) = self.admin.recv() => self.handle_admin_query(&mut manager, query),
+                _ = interval.tick() => {
+                    if let Err(err) = self.step(&mut manager).await {
+                        warn!(target: "batcher", %err, "Batch submitter step failed");
+                    }
+                }
+            }
+        }
+    }
+
+    /// Handles a query of the admin API.
+    pub fn handle_admin_query(
+        &mut self,
+        manager: &mut ChannelManager<'_, BatcherCompressor>,
+        query: BatcherAdminQuery,
+    ) {
+        match query {
+            BatcherAdminQuery::Start(tx) => {
+                let result = if self.active {
+                    Err(BatcherAdminError::AlreadyRunning)
+                } else {
+                    info!(target: "batcher", "Starting batcher");
+                    self.active = true;
+                    Ok(())
+                };
+                let _ = tx.send(result);
+            }
+            BatcherAdminQuery::Stop(tx) => {
+                let result = if self.active {
+                    info!(target: "batcher", "Stopping batcher, submitting the remaining blocks");
+                    self.active = false;
+                    if let Err(err) = manager.close() {
+                        error!(target: "batcher", %err, "Failed to close the open channel");
+                    }
+                    Ok(())
+                } else {
+                    Err(BatcherAdminError::NotRunning)
+                };
+                let _ = tx.send(result);
+            }
+        }
+        kona_macros::set!(gauge, crate::Metrics::BATCHER_ACTIVE, self.active as u8 as f64);
+    }
+
+    /// Runs a single iteration of the batch submitter.
+    pub async fn step(
+        &mut self,
+        manager: &mut ChannelManager<'_, BatcherCompressor>,
+    ) -> Result<(), BatchSubmitterError> {
+        let head = self.l1.head().await?;
+        for outcome in self.txmgr.poll(&self.l1, head).await? {
+            match outcome {
+                TxOutcome::Confirmed(id, block) => manager.tx_confirmed(id, block),
+                TxOutcome::Reorged(id) => manager.tx_reorged(id),
+                TxOutcome::Failed(id) => manager.tx_failed(id),
+            }
+        }
+
+        if self.active {
+            self.load_blocks(manager).await?;
+        }
+        manager.update_l1_head(head)?;
+        self.publish(manager, head.number).await?;
+
+        kona_macros::set!(gauge, crate::Metrics::PENDING_BLOCKS, manager.pending_blocks() as f64);
+        kona_macros::set!(
+            gauge,
+            crate::Metrics::PENDING_CHANNELS,
+            manager.pending_channels() as f64
+        );
+        kona_macros::set!(gauge, crate::Metrics::PENDING_TXS, self.txmgr.pending_txs() as f64);
+        Ok(())
+    }
+
+    /// Adds the unsafe blocks after the last added block to the channel manager.
+    async fn load_blocks(
+        &mut self,
+        manager: &mut ChannelManager<'_, BatcherCompressor>,
+    ) -> Result<(), BatchSubmitterError> {
+        let status = self.l2.sync_status().await?;
+        let safe = status.safe_l2.block_info.number;
+
+        // Blocks below the safe head were already submitted, e.g. by another batcher.
+        if let Some(tip) = manager.tip().filter(|tip| tip.number < safe) {
+            info!(target: "batcher", tip = tip.number, safe, "Safe head is ahead of the batcher");
+            manager.clear();
+        }
+
+        let start = manager.tip().map_or(safe, |tip| tip.number) + 1;
+        for number in start..=status.unsafe_l2.block_info.number {
+            let block = self.l2.block_by_number(number).await?;
+            match manager.add_block(&block) {
+                Ok(()) => {}
+                Err(err @ ChannelManagerError::Reorg { .. }) => {
+                    warn!(target: "batcher", %err, "Clearing channel manager after L2 reorg");
+                    kona_macros::inc!(counter, crate::Metrics::L2_REORG_COUNT);
+                    manager.clear();
+                    return Ok(());
+                }
+                Err(err) => return Err(err.into()),
+            }
+            debug!(target: "batcher", number, "Added L2 block");
+            kona_macros::set!(gauge, crate::Metrics::L2_TIP, number as f64);
+        }
+        Ok(())
+    }
+
+    /// Sends the planned transactions while the transaction manager has capacity.
+    ///
+    /// The channel manager only hands out a channel once all transactions of earlier channels
+    /// are confirmed, and a failed send rewinds the frames of the transaction and all later
+    /// frames, so sending several transactions at once never reorders frames on L1.
+    async fn publish(
+        &mut self,
+        manager: &mut ChannelManager<'_, BatcherCompressor>,
+        head: u64,
+    ) -> Result<(), BatchSubmitterError> {
+        while self.txmgr.has_capacity() {
+            let Some(candidate) = manager.next_tx()? else {
+                break;
+            };
+            if let Err(err) = self.txmgr.send(&self.l1, &candidate, head).await {
+                warn!(target: "batcher", tx_id = candidate.id.0, %err, "Failed to send transaction");
+                kona_macros::inc!(counter, crate::Metrics::TXS_FAILED);
+                manager.tx_failed(candidate.id);
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BatcherSignerHandler, TxManagerConfig,
+        test_utils::{MockL1, MockL2},
+    };
+    use alloy_consensus::{Transaction, TxEnvelope};
+    use alloy_primitives::{Address, B256};
+    use alloy_signer_local::PrivateKeySigner;
+    use kona_batcher_core::ChannelConfig;
+    use kona_genesis::RollupConfig;
+    use kona_protocol::{Batch, BatchReader, Channel, Frame, SingleBatch};
+    use std::collections::HashSet;
+    use tokio::sync::oneshot;
+
+    fn config() -> BatcherConfig {
+        let rollup_config = RollupConfig {
+            l1_chain_id: 1,
+            channel_timeout: 50,
+            batch_inbox_address: Address::repeat_byte(0xFF),
+            ..Default::default()
+        };
+        let mut config = BatcherConfig::new(Arc::new(rollup_config));
+        config.channel = ChannelConfig::calldata().with_max_channel_duration(2);
+        config.tx_manager =
+            TxManagerConfig { num_confirmations: 1, resubmission_timeout: 2, max_pending_txs: 4 };
+        config
+    }
+
+    fn submitter(
+        config: BatcherConfig,
+        l1: &MockL1,
+        l2: &MockL2,
+    ) -> (BatchSubmitter<MockL1, MockL2>, mpsc::Sender<BatcherAdminQuery>) {
+        let signer = PrivateKeySigner::from_bytes(&B256::with_last_byte(1)).unwrap();
+        let txmgr = TxManager::new(
+            config.tx_manager.clone(),
+            BatcherSignerHandler::Local(signer),
+            config.rollup_config.l1_chain_id,
+            config.rollup_config.batch_inbox_address,
+        );
+        let (sender, receiver) = mpsc::channel(1);
+        (BatchSubmitter::new(config, l1.clone(), l2.clone(), txmgr, receiver), sender)
+    }
+
+    /// Reassembles the channels posted on L1 and decodes their batches.
+    fn posted_batches(config: &RollupConfig, l1: &MockL1) -> Vec<SingleBatch> {
+        let mut channels: Vec<Channel> = Vec::new();
+        for (block, frame) in l1.frames() {
+            let index = match channels.iter().position(|channel| channel.id() == frame.id) {
+                Some(index) => index,
+                None => {
+                    channels.push(Channel::new(frame.id, block));
+                    channels.len() - 1
+                }
+            };
+            channels[index].add_frame(frame, block).unwrap();
+        }
+
+        let mut batches = Vec::new();
+        for channel in channels.iter().filter(|channel| channel.is_ready()) {
+            let mut reader = BatchReader::new(channel.frame_data().unwrap(), usize::MAX);
+            while let Some(batch) = reader.next_batch(config) {
+                let Batch::Single(batch) = batch else { panic!("unexpected span batch") };
+                batches.push(batch);
+            }
+        }
+        batches
+    }
+
+    #[tokio::test]
+    async fn test_submits_unsafe_blocks() {
+        let config = config();
+        let rollup_config = Arc::clone(&config.rollup_config);
+        let (l1, l2) = (MockL1::new(), MockL2::new());
+        let (mut submitter, _) = submitter(config, &l1, &l2);
+        let mut manager = submitter.channel_manager(&rollup_config).unwrap();
+
+        for _ in 0..10 {
+            l2.build_block(3);
+            submitter.step(&mut manager).await.unwrap();
+            l1.mine();
+        }
+        for _ in 0..4 {
+            submitter.step(&mut manager).await.unwrap();
+            l1.mine();
+        }
+
+        let batches = posted_batches(&rollup_config, &l1);
+        assert_eq!(batches.len(), 10);
+        for (i, batch) in batches.iter().enumerate() {
+            let block = l2.block(i as u64 + 1).unwrap();
+            assert_eq!(batch.timestamp, block.header.timestamp);
+            assert_eq!(batch.transactions.len(), 3);
+        }
+        assert_eq!(manager.pending_blocks(), 0);
+        assert_eq!(submitter.txmgr().pending_txs(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_stop_closes_channel() {
+        let mut config = config();
+        config.channel = config.channel.with_max_channel_duration(0);
+        let rollup_config = Arc::clone(&config.rollup_config);
+        let (l1, l2) = (MockL1::new(), MockL2::new());
+        let (mut submitter, _) = submitter(config, &l1, &l2);
+        let mut manager = submitter.channel_manager(&rollup_config).unwrap();
+
+        l2.build_block(2);
+        submitter.step(&mut manager).await.unwrap();
+        assert!(l1.mempool().is_empty());
+
+        let (tx, rx) = oneshot::channel();
+        submitter.handle_admin_query(&mut manager, BatcherAdminQuery::Stop(tx));
+        assert_eq!(rx.await.unwrap(), Ok(()));
+        assert!(!submitter.is_active());
+
+        // New blocks are not loaded anymore, but the closed channel is submitted.
+        l2.build_block(2);
+        submitter.step(&mut manager).await.unwrap();
+        assert_eq!(l1.mempool().len(), 1);
+        l1.mine();
+        submitter.step(&mut manager).await.unwrap();
+        assert_eq!(posted_batches(&rollup_config, &l1).len(), 1);
+        assert_eq!(manager.pending_blocks(), 0);
+
+        let (tx, rx) = oneshot::channel();
+        submitter.handle_admin_query(&mut manager, BatcherAdminQuery::Stop(tx));
+        assert_eq!(rx.await.unwrap(), Err(BatcherAdminError::NotRunning));
+
+        let (tx, rx) = oneshot::channel();
+        submitter.handle_admin_query(&mut manager, BatcherAdminQuery::Start(tx));
+        assert_eq!(rx.await.unwrap(), Ok(()));
+        submitter.step(&mut manager).await.unwrap();
+        assert_eq!(manager.pending_blocks(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_later_channels_wait_for_confirmation() {
+        let mut config = config();
+        config.channel = ChannelConfig::calldata().with_max_frame_size(100);
+        config.tx_manager.max_pending_txs = 16;
+        let rollup_config = Arc::clone(&config.rollup_config);
+        let (l1, l2) = (MockL1::new(), MockL2::new());
+        let (mut submitter, _) = submitter(config, &l1, &l2);
+        let mut manager = submitter.channel_manager(&rollup_config).unwrap();
+
+        // Each block fills a channel of several frames.
+        l2.build_block(3);
+        l2.build_block(3);
+        submitter.step(&mut manager).await.unwrap();
+        assert_eq!(manager.pending_channels(), 2);
+
+        // Only the frames of the first channel are in flight, although there is capacity left.
+        let channel_ids = |txs: Vec<TxEnvelope>| {
+            txs.iter()
+                .flat_map(|tx| Frame::parse_frames(tx.input()).unwrap())
+                .map(|frame| frame.id)
+                .collect::<HashSet<_>>()
+        };
+        assert!(l1.mempool().len() > 1);
+        assert_eq!(channel_ids(l1.mempool()).len(), 1);
+        assert!(submitter.txmgr().has_capacity());
+
+        l1.mine();
+        submitter.step(&mut manager).await.unwrap();
+        assert_eq!(manager.pending_channels(), 1);
+        assert_eq!(channel_ids(l1.mempool()).len(), 1);
+
+        l1.mine();
+        submitter.step(&mut manager).await.unwrap();
+        assert_eq!(posted_batches(&rollup_config, &l1).len(), 2);
+        assert_eq!(manager.pending_blocks(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_resubmits_stuck_and_failed_transactions() {
+        let config = config();
+        let rollup_config = Arc::clone(&config.rollup_config);
+        let (l1, l2) = (MockL1::new(), MockL2::new());
+        let (mut submitter, _) = submitter(config, &l1, &l2);
+        let mut manager = submitter.channel_manager(&rollup_config).unwrap();
+
+        // The first transaction is rejected, and its frames are sent again in the next step.
+        l1.reject_next(1);
+        l1.set_include_txs(false);
+        l2.build_block(3);
+        submitter.step(&mut manager).await.unwrap();
+        l1.mine();
+        l1.mine();
+        submitter.step(&mut manager).await.unwrap();
+        assert_eq!(l1.sent(), 0);
+        submitter.step(&mut manager).await.unwrap();
+        assert_eq!(l1.sent(), 1);
+
+        // The transaction is stuck, so it is resubmitted with bumped fees after the timeout.
+        let fee = l1.mempool()[0].max_fee_per_gas();
+        l1.mine();
+        l1.mine();
+        submitter.step(&mut manager).await.unwrap();
+        assert_eq!(l1.sent(), 2);
+        assert!(l1.mempool()[0].max_fee_per_gas() > fee);
+
+        l1.set_include_txs(true);
+        l1.mine();
+        submitter.step(&mut manager).await.unwrap();
+        assert_eq!(posted_batches(&rollup_config, &l1).len(), 1);
+        assert_eq!(manager.pending_blocks(), 0);
+        assert_eq!(submitter.txmgr().pending_txs(), 0);
+    }
+}
@@ -0,0 +1,34 @@
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/op-rs/kona/main/assets/square.png",
+    html_favicon_url = "https://raw.githubusercontent.com/op-rs/kona/main/assets/favicon.ico",
+    issue_tracker_base_url = "https://github.com/op-rs/kona/issues/"
+)]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod config;
+pub use config::{BatcherConfig, TxManagerConfig};
+
+mod driver;
+pub use driver::{BatchSubmitter, BatchSubmitterError};
+
+mod metrics;
+pub use metrics::Metrics;
+
+mod rpc;
+pub use rpc::{BatcherAdminApiServer, BatcherAdminError, BatcherAdminQuery, BatcherAdminRpc};
+
+mod service;
+pub use service::{BatcherService, BatcherServiceError};
+
+mod signer;
+pub use signer::{BatcherSigner, BatcherSignerError, BatcherSignerHandler};
+
+mod sources;
+pub use sources::{AlloyL1Client, AlloyL2Source, L1Client, L2Source, SourceError};
+
+mod txmgr;
+pub use txmgr::{TxManager, TxManagerError, TxOutcome};
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
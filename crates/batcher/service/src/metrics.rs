@@ -0,0 +1,110 @@
+//! Metrics for the batcher service.
+
+/// Container for metrics.
+#[derive(Debug, Clone)]
+pub struct Metrics;
+
+impl Metrics {
+    /// Identifier for the gauge that tracks whether the batcher is submitting.
+    pub const BATCHER_ACTIVE: &str = "kona_batcher_active";
+
+    /// Identifier for the gauge that tracks the number of the last L2 block added to a channel.
+    pub const L2_TIP: &str = "kona_batcher_l2_tip";
+
+    /// Identifier for the gauge that tracks the number of L2 blocks that are not yet fully
+    /// confirmed.
+    pub const PENDING_BLOCKS: &str = "kona_batcher_pending_blocks";
+
+    /// Identifier for the gauge that tracks the number of channels that are not yet fully
+    /// confirmed.
+    pub const PENDING_CHANNELS: &str = "kona_batcher_pending_channels";
+
+    /// Identifier for the gauge that tracks the number of transactions in flight.
+    pub const PENDING_TXS: &str = "kona_batcher_pending_txs";
+
+    /// Identifier for the counter of sent batcher transactions.
+    pub const TXS_SENT: &str = "kona_batcher_txs_sent";
+
+    /// Identifier for the counter of resubmitted batcher transactions.
+    pub const TXS_RESUBMITTED: &str = "kona_batcher_txs_resubmitted";
+
+    /// Identifier for the counter of confirmed batcher transactions.
+    pub const TXS_CONFIRMED: &str = "kona_batcher_txs_confirmed";
+
+    /// Identifier for the counter of confirmed batcher transactions that were reorged out of L1.
+    pub const TXS_REORGED: &str = "kona_batcher_txs_reorged";
+
+    /// Identifier for the counter of batcher transactions that could not be sent or whose nonce
+    /// was used by another transaction.
+    pub const TXS_FAILED: &str = "kona_batcher_txs_failed";
+
+    /// Identifier for the counter of L2 reorgs detected by the batcher.
+    pub const L2_REORG_COUNT: &str = "kona_batcher_l2_reorg_count";
+
+    /// Initializes metrics for the batcher service.
+    ///
+    /// This does two things:
+    /// * Describes various metrics.
+    /// * Initializes metrics to 0 so they can be queried immediately.
+    #[cfg(feature = "metrics")]
+    pub fn init() {
+        Self::describe();
+        Self::zero();
+    }
+
+    /// Describes metrics used in [`kona-batcher-service`][crate].
+    #[cfg(feature = "metrics")]
+    pub fn describe() {
+        metrics::describe_gauge!(Self::BATCHER_ACTIVE, "Whether the batcher is submitting");
+        metrics::describe_gauge!(Self::L2_TIP, "Last L2 block added to a channel");
+        metrics::describe_gauge!(
+            Self::PENDING_BLOCKS,
+            metrics::Unit::Count,
+            "L2 blocks that are not yet fully confirmed on L1"
+        );
+        metrics::describe_gauge!(
+            Self::PENDING_CHANNELS,
+            metrics::Unit::Count,
+            "Channels that are not yet fully confirmed on L1"
+        );
+        metrics::describe_gauge!(
+            Self::PENDING_TXS,
+            metrics::Unit::Count,
+            "Batcher transactions in flight"
+        );
+        metrics::describe_counter!(Self::TXS_SENT, metrics::Unit::Count, "Sent transactions");
+        metrics::describe_counter!(
+            Self::TXS_RESUBMITTED,
+            metrics::Unit::Count,
+            "Transactions resubmitted with bumped fees"
+        );
+        metrics::describe_counter!(
+            Self::TXS_CONFIRMED,
+            metrics::Unit::Count,
+            "Confirmed transactions"
+        );
+        metrics::describe_counter!(
+            Self::TXS_REORGED,
+            metrics::Unit::Count,
+            "Confirmed transactions reorged out of L1"
+        );
+        metrics::describe_counter!(
+            Self::TXS_FAILED,
+            metrics::Unit::Count,
+            "Transactions that could not be sent or whose nonce was used by another transaction"
+        );
+        metrics::describe_counter!(Self::L2_REORG_COUNT, metrics::Unit::Count, "L2 reorg count");
+    }
+
+    /// Initializes metrics to `0` so they can be queried immediately by consumers of prometheus
+    /// metrics.
+    #[cfg(feature = "metrics")]
+    pub fn zero() {
+        kona_macros::set!(counter, Self::TXS_SENT, 0);
+        kona_macros::set!(counter, Self::TXS_RESUBMITTED, 0);
+        kona_macros::set!(counter, Self::TXS_CONFIRMED, 0);
+        kona_macros::set!(counter, Self::TXS_REORGED, 0);
+        kona_macros::set!(counter, Self::TXS_FAILED, 0);
+        kona_macros::set!(counter, Self::L2_REORG_COUNT, 0);
+    }
+}
@@ -0,0 +1,103 @@
+//! The admin RPC of the batcher.
+
+use async_trait::async_trait;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{ErrorCode, ErrorObject},
+};
+use tokio::sync::{mpsc, oneshot};
+
+/// The admin API of the batcher.
+#[rpc(server, namespace = "admin")]
+pub trait BatcherAdminApi {
+    /// Starts submitting unsafe L2 blocks.
+    #[method(name = "startBatcher")]
+    async fn start_batcher(&self) -> RpcResult<()>;
+
+    /// Stops loading new L2 blocks. Blocks that were already loaded are still submitted.
+    #[method(name = "stopBatcher")]
+    async fn stop_batcher(&self) -> RpcResult<()>;
+}
+
+/// An error returned by the admin API of the batcher.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum BatcherAdminError {
+    /// The batcher is already running.
+    #[error("batcher is already running")]
+    AlreadyRunning,
+    /// The batcher is not running.
+    #[error("batcher is not running")]
+    NotRunning,
+}
+
+/// The queries sent by the admin RPC to the [`BatchSubmitter`](crate::BatchSubmitter).
+#[derive(Debug)]
+pub enum BatcherAdminQuery {
+    /// A query to start the batcher.
+    Start(oneshot::Sender<Result<(), BatcherAdminError>>),
+    /// A query to stop the batcher.
+    Stop(oneshot::Sender<Result<(), BatcherAdminError>>),
+}
+
+/// The admin RPC server of the batcher.
+#[derive(Debug, Clone)]
+pub struct BatcherAdminRpc {
+    /// The sender to the batch submitter.
+    sender: mpsc::Sender<BatcherAdminQuery>,
+}
+
+impl BatcherAdminRpc {
+    /// Creates a new [`BatcherAdminRpc`].
+    pub const fn new(sender: mpsc::Sender<BatcherAdminQuery>) -> Self {
+        Self { sender }
+    }
+
+    /// Sends a query to the batch submitter and waits for the response.
+    async fn query(
+        &self,
+        query: impl FnOnce(oneshot::Sender<Result<(), BatcherAdminError>>) -> BatcherAdminQuery,
+    ) -> RpcResult<()> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(query(tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?.map_err(|err| {
+            ErrorObject::owned(ErrorCode::InvalidRequest.code(), err.to_string(), None::<()>)
+        })
+    }
+}
+
+#[async_trait]
+impl BatcherAdminApiServer for BatcherAdminRpc {
+    async fn start_batcher(&self) -> RpcResult<()> {
+        self.query(BatcherAdminQuery::Start).await
+    }
+
+    async fn stop_batcher(&self) -> RpcResult<()> {
+        self.query(BatcherAdminQuery::Stop).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_admin_rpc_forwards_queries() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let rpc = BatcherAdminRpc::new(sender);
+
+        let handle = tokio::spawn(async move {
+            let Some(BatcherAdminQuery::Stop(tx)) = receiver.recv().await else {
+                panic!("expected a stop query");
+            };
+            tx.send(Err(BatcherAdminError::NotRunning)).unwrap();
+        });
+
+        let err = rpc.stop_batcher().await.unwrap_err();
+        assert_eq!(err.message(), "batcher is not running");
+        handle.await.unwrap();
+    }
+}
@@ -0,0 +1,81 @@
+//! The [`BatcherService`] runs the batch submitter and the admin RPC server.
+
+use crate::{
+    BatchSubmitter, BatchSubmitterError, BatcherAdminApiServer, BatcherAdminRpc, BatcherConfig,
+    BatcherSigner, L1Client, L2Source, TxManager,
+};
+use jsonrpsee::server::ServerBuilder;
+use kona_sources::RemoteSignerStartError;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+/// An error returned by the [`BatcherService`].
+#[derive(Debug, thiserror::Error)]
+pub enum BatcherServiceError {
+    /// The signer could not be started.
+    #[error("Failed to start the signer: {0}")]
+    Signer(#[from] RemoteSignerStartError),
+    /// The admin RPC server could not be started.
+    #[error("Failed to start the admin RPC server: {0}")]
+    Rpc(#[from] std::io::Error),
+    /// The batch submitter failed.
+    #[error(transparent)]
+    Submitter(#[from] BatchSubmitterError),
+}
+
+/// The batcher service.
+#[derive(Debug)]
+pub struct BatcherService<L1, L2> {
+    /// The batcher config.
+    config: BatcherConfig,
+    /// The L1 client.
+    l1: L1,
+    /// The L2 source.
+    l2: L2,
+    /// The signer of the batcher transactions.
+    signer: BatcherSigner,
+}
+
+impl<L1, L2> BatcherService<L1, L2>
+where
+    L1: L1Client,
+    L2: L2Source,
+{
+    /// Creates a new [`BatcherService`].
+    pub const fn new(config: BatcherConfig, l1: L1, l2: L2, signer: BatcherSigner) -> Self {
+        Self { config, l1, l2, signer }
+    }
+
+    /// Runs the service until the cancellation token is cancelled.
+    pub async fn run(self, cancel: CancellationToken) -> Result<(), BatcherServiceError> {
+        let signer = self.signer.start().await?;
+        let rollup_config = &self.config.rollup_config;
+        info!(
+            target: "batcher",
+            sender = %signer.address(),
+            inbox = %rollup_config.batch_inbox_address,
+            da_type = ?self.config.channel.da_type,
+            "Starting batcher"
+        );
+        let txmgr = TxManager::new(
+            self.config.tx_manager.clone(),
+            signer,
+            rollup_config.l1_chain_id,
+            rollup_config.batch_inbox_address,
+        );
+
+        let (admin_tx, admin_rx) = mpsc::channel(16);
+        let server = ServerBuilder::default().build(self.config.rpc_addr).await?;
+        info!(target: "batcher", addr = %self.config.rpc_addr, "Admin RPC server bound to address");
+        let handle = server.start(BatcherAdminRpc::new(admin_tx).into_rpc());
+
+        let result =
+            BatchSubmitter::new(self.config, self.l1, self.l2, txmgr, admin_rx).run(cancel).await;
+
+        if handle.stop().is_err() {
+            warn!(target: "batcher", "Admin RPC server was already stopped");
+        }
+        Ok(result?)
+    }
+}
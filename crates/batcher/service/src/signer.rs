@@ -0,0 +1,69 @@
+//! Signers for batcher transactions.
+
+use alloy_consensus::{SignableTransaction, Transaction};
+use alloy_primitives::{Address, Signature};
+use alloy_signer::SignerSync;
+use alloy_signer_local::PrivateKeySigner;
+use derive_more::From;
+use kona_sources::{RemoteSigner, RemoteSignerError, RemoteSignerHandler, RemoteSignerStartError};
+
+/// A builder for the signer of batcher transactions.
+#[derive(Debug, Clone, From)]
+pub enum BatcherSigner {
+    /// A signer holding the private key locally.
+    Local(#[from] PrivateKeySigner),
+    /// A remote signer, signing through `eth_signTransaction`.
+    Remote(#[from] RemoteSigner),
+}
+
+/// A started [`BatcherSigner`].
+#[derive(Debug)]
+pub enum BatcherSignerHandler {
+    /// A signer holding the private key locally.
+    Local(PrivateKeySigner),
+    /// A remote signer, signing through `eth_signTransaction`.
+    Remote(RemoteSignerHandler),
+}
+
+/// Errors that can occur when signing a batcher transaction.
+#[derive(Debug, thiserror::Error)]
+pub enum BatcherSignerError {
+    /// An error signing with the local signer.
+    #[error(transparent)]
+    Local(#[from] alloy_signer::Error),
+    /// An error signing with the remote signer.
+    #[error(transparent)]
+    Remote(#[from] RemoteSignerError),
+}
+
+impl BatcherSigner {
+    /// Starts the signer.
+    pub async fn start(self) -> Result<BatcherSignerHandler, RemoteSignerStartError> {
+        match self {
+            Self::Local(signer) => Ok(BatcherSignerHandler::Local(signer)),
+            Self::Remote(signer) => Ok(BatcherSignerHandler::Remote(signer.start().await?)),
+        }
+    }
+}
+
+impl BatcherSignerHandler {
+    /// Returns the address of the signer, which sends the batcher transactions.
+    pub const fn address(&self) -> Address {
+        match self {
+            Self::Local(signer) => signer.address(),
+            Self::Remote(signer) => signer.address(),
+        }
+    }
+
+    /// Signs the given transaction.
+    pub async fn sign<T>(&self, tx: &T) -> Result<Signature, BatcherSignerError>
+    where
+        T: Transaction + SignableTransaction<Signature>,
+    {
+        let signature = match self {
+            Self::Local(signer) => signer.sign_hash_sync(&tx.signature_hash())?,
+            Self::Remote(signer) => signer.sign_transaction(tx).await?,
+        };
+        Ok(signature)
+    }
+}
@@ -0,0 +1,169 @@
+//! The L1 and L2 sources of the batcher.
+
+use alloy_eips::{BlockNumHash, BlockNumberOrTag, eip1559::Eip1559Estimation};
+use alloy_primitives::{Address, B256, Bytes};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_client::RpcClient;
+use alloy_transport::TransportError;
+use async_trait::async_trait;
+use kona_protocol::{BlockInfo, SyncStatus};
+use op_alloy_consensus::OpBlock;
+use op_alloy_network::Optimism;
+use thiserror::Error;
+
+/// An error returned by an [`L1Client`] or an [`L2Source`].
+#[derive(Debug, Error)]
+pub enum SourceError {
+    /// The RPC request failed.
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+    /// The requested block does not exist.
+    #[error("Block {0} not found")]
+    BlockNotFound(BlockNumberOrTag),
+    /// The transaction was rejected by the node.
+    #[error("Transaction rejected: {0}")]
+    Rejected(String),
+}
+
+/// The L1 execution client the batcher submits transactions to.
+#[async_trait]
+pub trait L1Client: Send + Sync {
+    /// Returns the latest block.
+    async fn head(&self) -> Result<BlockInfo, SourceError>;
+
+    /// Returns the latest finalized block.
+    async fn finalized(&self) -> Result<BlockInfo, SourceError>;
+
+    /// Returns the block with the given number.
+    async fn block_by_number(&self, number: u64) -> Result<BlockInfo, SourceError>;
+
+    /// Returns the nonce of the next transaction of the given account, excluding pending
+    /// transactions.
+    async fn nonce(&self, address: Address) -> Result<u64, SourceError>;
+
+    /// Returns the nonce of the next transaction of the given account, including pending
+    /// transactions.
+    async fn pending_nonce(&self, address: Address) -> Result<u64, SourceError>;
+
+    /// Returns the suggested EIP-1559 fees.
+    async fn fees(&self) -> Result<Eip1559Estimation, SourceError>;
+
+    /// Returns the current blob base fee.
+    async fn blob_base_fee(&self) -> Result<u128, SourceError>;
+
+    /// Sends a signed, EIP-2718 encoded transaction and returns its hash.
+    async fn send_raw_transaction(&self, tx: Bytes) -> Result<B256, SourceError>;
+
+    /// Returns the block the transaction was included in, if any.
+    async fn inclusion_block(&self, hash: B256) -> Result<Option<BlockNumHash>, SourceError>;
+}
+
+/// The L2 rollup node and execution client the batcher reads unsafe blocks from.
+#[async_trait]
+pub trait L2Source: Send + Sync {
+    /// Returns the sync status of the rollup node.
+    async fn sync_status(&self) -> Result<SyncStatus, SourceError>;
+
+    /// Returns the L2 block with the given number.
+    async fn block_by_number(&self, number: u64) -> Result<OpBlock, SourceError>;
+}
+
+/// An [`L1Client`] backed by an alloy provider.
+#[derive(Debug, Clone)]
+pub struct AlloyL1Client {
+    /// The L1 provider.
+    provider: RootProvider,
+}
+
+impl AlloyL1Client {
+    /// Creates a new [`AlloyL1Client`].
+    pub const fn new(provider: RootProvider) -> Self {
+        Self { provider }
+    }
+
+    /// Fetches the block with the given number or tag.
+    async fn block(&self, id: BlockNumberOrTag) -> Result<BlockInfo, SourceError> {
+        let block =
+            self.provider.get_block_by_number(id).await?.ok_or(SourceError::BlockNotFound(id))?;
+        Ok(block.into())
+    }
+}
+
+#[async_trait]
+impl L1Client for AlloyL1Client {
+    async fn head(&self) -> Result<BlockInfo, SourceError> {
+        self.block(BlockNumberOrTag::Latest).await
+    }
+
+    async fn finalized(&self) -> Result<BlockInfo, SourceError> {
+        self.block(BlockNumberOrTag::Finalized).await
+    }
+
+    async fn block_by_number(&self, number: u64) -> Result<BlockInfo, SourceError> {
+        self.block(number.into()).await
+    }
+
+    async fn nonce(&self, address: Address) -> Result<u64, SourceError> {
+        Ok(self.provider.get_transaction_count(address).latest().await?)
+    }
+
+    async fn pending_nonce(&self, address: Address) -> Result<u64, SourceError> {
+        Ok(self.provider.get_transaction_count(address).pending().await?)
+    }
+
+    async fn fees(&self) -> Result<Eip1559Estimation, SourceError> {
+        Ok(self.provider.estimate_eip1559_fees().await?)
+    }
+
+    async fn blob_base_fee(&self) -> Result<u128, SourceError> {
+        Ok(self.provider.get_blob_base_fee().await?)
+    }
+
+    async fn send_raw_transaction(&self, tx: Bytes) -> Result<B256, SourceError> {
+        let pending = self.provider.send_raw_transaction(&tx).await?;
+        Ok(*pending.tx_hash())
+    }
+
+    async fn inclusion_block(&self, hash: B256) -> Result<Option<BlockNumHash>, SourceError> {
+        let receipt = self.provider.get_transaction_receipt(hash).await?;
+        Ok(receipt.and_then(|receipt| {
+            Some(BlockNumHash::new(receipt.block_number?, receipt.block_hash?))
+        }))
+    }
+}
+
+/// An [`L2Source`] backed by the RPC of a rollup node and an alloy provider for the L2
+/// execution client.
+#[derive(Debug, Clone)]
+pub struct AlloyL2Source {
+    /// The rollup node RPC client.
+    rollup_node: RpcClient,
+    /// The L2 execution client provider.
+    provider: RootProvider<Optimism>,
+}
+
+impl AlloyL2Source {
+    /// Creates a new [`AlloyL2Source`].
+    pub const fn new(rollup_node: RpcClient, provider: RootProvider<Optimism>) -> Self {
+        Self { rollup_node, provider }
+    }
+}
+
+#[async_trait]
+impl L2Source for AlloyL2Source {
+    async fn sync_status(&self) -> Result<SyncStatus, SourceError> {
+        Ok(self.rollup_node.request("optimism_syncStatus", ()).await?)
+    }
+
+    async fn block_by_number(&self, number: u64) -> Result<OpBlock, SourceError> {
+        let block = self
+            .provider
+            .get_block_by_number(number.into())
+            .full()
+            .await?
+            .ok_or(SourceError::BlockNotFound(number.into()))?
+            .into_consensus()
+            .map_transactions(|t| t.inner.inner.into_inner());
+        Ok(block)
+    }
+}
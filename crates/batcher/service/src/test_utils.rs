@@ -0,0 +1,352 @@
+//! In-process mocks of L1 and L2 for testing the batcher.
+
+use crate::{L1Client, L2Source, SourceError};
+use alloy_consensus::{
+    Block, BlockBody, Header, SignableTransaction, Transaction, TxEnvelope, TxLegacy,
+};
+use alloy_eips::{BlockNumHash, eip1559::Eip1559Estimation, eip2718::Decodable2718};
+use alloy_primitives::{Address, B256, Bytes, Sealed, Signature, TxKind, keccak256};
+use async_trait::async_trait;
+use kona_protocol::{BlockInfo, Frame, L1BlockInfoBedrock, L1BlockInfoTx, L2BlockInfo, SyncStatus};
+use op_alloy_consensus::{OpBlock, OpTxEnvelope, TxDeposit};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// The L1 block time of the [`MockL1`], in seconds.
+const L1_BLOCK_TIME: u64 = 12;
+
+/// The L2 block time of the [`MockL2`], in seconds.
+const L2_BLOCK_TIME: u64 = 2;
+
+/// The state of the [`MockL1`].
+#[derive(Debug, Default)]
+struct MockL1State {
+    /// The canonical chain, starting at the genesis block.
+    blocks: Vec<BlockInfo>,
+    /// The transactions included in each block.
+    block_txs: Vec<Vec<TxEnvelope>>,
+    /// The transactions waiting to be included, by nonce.
+    mempool: HashMap<u64, TxEnvelope>,
+    /// The inclusion block of every included transaction.
+    included: HashMap<B256, u64>,
+    /// The nonce of the next transaction to include.
+    nonce: u64,
+    /// The number of the finalized block.
+    finalized: u64,
+    /// The number of reorgs, so that re-mined blocks get a new hash.
+    reorgs: u64,
+    /// The number of transactions sent, including replacements.
+    sent: usize,
+    /// The number of upcoming transactions to reject.
+    reject: usize,
+    /// Whether mined blocks include the mempool.
+    include_txs: bool,
+}
+
+/// An in-process [`L1Client`] that includes the transactions of a single sender into blocks
+/// mined on demand.
+///
+/// The mock is cheap to clone, and all clones share the same chain.
+#[derive(Debug, Clone)]
+pub struct MockL1 {
+    /// The shared state.
+    state: Arc<Mutex<MockL1State>>,
+}
+
+impl Default for MockL1 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockL1 {
+    /// Creates a new [`MockL1`] with only a genesis block.
+    pub fn new() -> Self {
+        let genesis = BlockInfo { hash: keccak256([0u8; 8]), ..Default::default() };
+        let state = MockL1State {
+            blocks: vec![genesis],
+            block_txs: vec![vec![]],
+            include_txs: true,
+            ..Default::default()
+        };
+        Self { state: Arc::new(Mutex::new(state)) }
+    }
+
+    /// Mines a block including the mempool transactions with consecutive nonces, and returns it.
+    pub fn mine(&self) -> BlockInfo {
+        let mut state = self.state.lock().unwrap();
+        let parent = *state.blocks.last().expect("genesis block");
+        let number = parent.number + 1;
+        let info = BlockInfo {
+            hash: keccak256([number.to_be_bytes(), state.reorgs.to_be_bytes()].concat()),
+            number,
+            parent_hash: parent.hash,
+            timestamp: parent.timestamp + L1_BLOCK_TIME,
+        };
+
+        let mut txs = Vec::new();
+        while state.include_txs {
+            let nonce = state.nonce;
+            let Some(tx) = state.mempool.remove(&nonce) else {
+                break;
+            };
+            state.included.insert(*tx.tx_hash(), number);
+            state.nonce += 1;
+            txs.push(tx);
+        }
+
+        state.blocks.push(info);
+        state.block_txs.push(txs);
+        info
+    }
+
+    /// Removes the given number of blocks from the tip of the chain, and puts their transactions
+    /// back into the mempool.
+    pub fn reorg(&self, depth: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.reorgs += 1;
+        for _ in 0..depth.min(state.blocks.len() - 1) {
+            state.blocks.pop();
+            for tx in state.block_txs.pop().unwrap_or_default() {
+                state.included.remove(tx.tx_hash());
+                state.nonce = state.nonce.min(tx.nonce());
+                state.mempool.insert(tx.nonce(), tx);
+            }
+        }
+    }
+
+    /// Finalizes the block with the given number.
+    pub fn finalize(&self, number: u64) {
+        self.state.lock().unwrap().finalized = number;
+    }
+
+    /// Uses the next nonce for a transaction that was not sent through the mock, as if it was
+    /// sent by another batcher, dropping the mempool transaction with that nonce.
+    pub fn use_nonce(&self) {
+        let mut state = self.state.lock().unwrap();
+        let nonce = state.nonce;
+        state.mempool.remove(&nonce);
+        state.nonce += 1;
+    }
+
+    /// Sets whether mined blocks include the mempool, to simulate transactions that are stuck.
+    pub fn set_include_txs(&self, include_txs: bool) {
+        self.state.lock().unwrap().include_txs = include_txs;
+    }
+
+    /// Rejects the given number of upcoming transactions.
+    pub fn reject_next(&self, count: usize) {
+        self.state.lock().unwrap().reject = count;
+    }
+
+    /// Returns the number of transactions sent, including replacements.
+    pub fn sent(&self) -> usize {
+        self.state.lock().unwrap().sent
+    }
+
+    /// Returns the transactions waiting to be included.
+    pub fn mempool(&self) -> Vec<TxEnvelope> {
+        self.state.lock().unwrap().mempool.values().cloned().collect()
+    }
+
+    /// Returns all frames posted as calldata on the chain, with the block they were included
+    /// in.
+    pub fn frames(&self) -> Vec<(BlockInfo, Frame)> {
+        let state = self.state.lock().unwrap();
+        state
+            .blocks
+            .iter()
+            .zip(&state.block_txs)
+            .flat_map(|(block, txs)| {
+                txs.iter()
+                    .filter(|tx| !matches!(tx, TxEnvelope::Eip4844(_)))
+                    .flat_map(|tx| Frame::parse_frames(tx.input()).expect("valid calldata"))
+                    .map(move |frame| (*block, frame))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl L1Client for MockL1 {
+    async fn head(&self) -> Result<BlockInfo, SourceError> {
+        Ok(*self.state.lock().unwrap().blocks.last().expect("genesis block"))
+    }
+
+    async fn finalized(&self) -> Result<BlockInfo, SourceError> {
+        let finalized = self.state.lock().unwrap().finalized;
+        self.block_by_number(finalized).await
+    }
+
+    async fn block_by_number(&self, number: u64) -> Result<BlockInfo, SourceError> {
+        let state = self.state.lock().unwrap();
+        state.blocks.get(number as usize).copied().ok_or(SourceError::BlockNotFound(number.into()))
+    }
+
+    async fn nonce(&self, _: Address) -> Result<u64, SourceError> {
+        Ok(self.state.lock().unwrap().nonce)
+    }
+
+    async fn pending_nonce(&self, _: Address) -> Result<u64, SourceError> {
+        let state = self.state.lock().unwrap();
+        let mut nonce = state.nonce;
+        while state.mempool.contains_key(&nonce) {
+            nonce += 1;
+        }
+        Ok(nonce)
+    }
+
+    async fn fees(&self) -> Result<Eip1559Estimation, SourceError> {
+        Ok(Eip1559Estimation { max_fee_per_gas: 2_000_000_000, max_priority_fee_per_gas: 1_000 })
+    }
+
+    async fn blob_base_fee(&self) -> Result<u128, SourceError> {
+        Ok(1)
+    }
+
+    async fn send_raw_transaction(&self, tx: Bytes) -> Result<B256, SourceError> {
+        let mut state = self.state.lock().unwrap();
+        if state.reject > 0 {
+            state.reject -= 1;
+            return Err(SourceError::Rejected("rejected by mock".to_string()));
+        }
+
+        let tx = TxEnvelope::decode_2718(&mut tx.as_ref())
+            .map_err(|err| SourceError::Rejected(err.to_string()))?;
+        if tx.nonce() < state.nonce {
+            return Err(SourceError::Rejected("nonce too low".to_string()));
+        }
+        if state
+            .mempool
+            .get(&tx.nonce())
+            .is_some_and(|pending| pending.max_fee_per_gas() >= tx.max_fee_per_gas())
+        {
+            return Err(SourceError::Rejected("replacement transaction underpriced".to_string()));
+        }
+
+        let hash = *tx.tx_hash();
+        state.sent += 1;
+        state.mempool.insert(tx.nonce(), tx);
+        Ok(hash)
+    }
+
+    async fn inclusion_block(&self, hash: B256) -> Result<Option<BlockNumHash>, SourceError> {
+        let state = self.state.lock().unwrap();
+        Ok(state.included.get(&hash).map(|number| state.blocks[*number as usize].id()))
+    }
+}
+
+/// The state of the [`MockL2`].
+#[derive(Debug, Default)]
+struct MockL2State {
+    /// The chain, starting at the genesis block.
+    blocks: Vec<OpBlock>,
+    /// The number of the safe head.
+    safe: u64,
+}
+
+/// An in-process [`L2Source`] serving a chain of blocks with random user transactions.
+///
+/// The mock is cheap to clone, and all clones share the same chain.
+#[derive(Debug, Clone)]
+pub struct MockL2 {
+    /// The shared state.
+    state: Arc<Mutex<MockL2State>>,
+}
+
+impl Default for MockL2 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockL2 {
+    /// Creates a new [`MockL2`] with only a genesis block.
+    pub fn new() -> Self {
+        let genesis = Block { header: Header::default(), body: BlockBody::default() };
+        Self { state: Arc::new(Mutex::new(MockL2State { blocks: vec![genesis], safe: 0 })) }
+    }
+
+    /// Builds a new unsafe block with the given number of user transactions on top of the
+    /// chain, and returns it.
+    pub fn build_block(&self, num_txs: u64) -> OpBlock {
+        let mut state = self.state.lock().unwrap();
+        let parent = &state.blocks.last().expect("genesis block").header;
+        let number = parent.number + 1;
+        let parent_hash = parent.hash_slow();
+
+        let l1_info = L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+            number: 0,
+            block_hash: keccak256([0u8; 8]),
+            sequence_number: number - 1,
+            ..Default::default()
+        });
+        let deposit = TxDeposit { input: l1_info.encode_calldata(), ..Default::default() };
+        let mut transactions = vec![OpTxEnvelope::Deposit(Sealed::new(deposit))];
+        transactions.extend((0..num_txs).map(|i| {
+            let tx = TxLegacy {
+                chain_id: Some(10),
+                nonce: number * num_txs + i,
+                gas_limit: 21_000,
+                to: TxKind::Call(Address::with_last_byte(i as u8)),
+                input: keccak256((number * num_txs + i).to_be_bytes()).to_vec().into(),
+                ..Default::default()
+            };
+            OpTxEnvelope::Legacy(tx.into_signed(Signature::test_signature()))
+        }));
+
+        let block = Block {
+            header: Header {
+                number,
+                parent_hash,
+                timestamp: number * L2_BLOCK_TIME,
+                ..Default::default()
+            },
+            body: BlockBody { transactions, ommers: vec![], withdrawals: None },
+        };
+        state.blocks.push(block.clone());
+        block
+    }
+
+    /// Sets the safe head.
+    pub fn set_safe(&self, number: u64) {
+        self.state.lock().unwrap().safe = number;
+    }
+
+    /// Returns the block with the given number.
+    pub fn block(&self, number: u64) -> Option<OpBlock> {
+        self.state.lock().unwrap().blocks.get(number as usize).cloned()
+    }
+}
+
+#[async_trait]
+impl L2Source for MockL2 {
+    async fn sync_status(&self) -> Result<SyncStatus, SourceError> {
+        let state = self.state.lock().unwrap();
+        let info = |number: u64| {
+            let block = &state.blocks[number as usize];
+            L2BlockInfo { block_info: BlockInfo::from(block), ..Default::default() }
+        };
+        let unsafe_l2 = info(state.blocks.len() as u64 - 1);
+        let safe_l2 = info(state.safe);
+        Ok(SyncStatus {
+            current_l1: BlockInfo::default(),
+            current_l1_finalized: BlockInfo::default(),
+            head_l1: BlockInfo::default(),
+            safe_l1: BlockInfo::default(),
+            finalized_l1: BlockInfo::default(),
+            unsafe_l2,
+            safe_l2,
+            finalized_l2: safe_l2,
+            cross_unsafe_l2: unsafe_l2,
+            local_safe_l2: safe_l2,
+            el_sync: None,
+        })
+    }
+
+    async fn block_by_number(&self, number: u64) -> Result<OpBlock, SourceError> {
+        self.block(number).ok_or(SourceError::BlockNotFound(number.into()))
+    }
+}
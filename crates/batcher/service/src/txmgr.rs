@@ -0,0 +1,561 @@
+//! Signing, sending and tracking of batcher transactions.
+
+use crate::{BatcherSignerError, BatcherSignerHandler, L1Client, SourceError, TxManagerConfig};
+use alloy_consensus::{
+    SignableTransaction, TxEip1559, TxEip4844, TxEip4844Variant, TxEip4844WithSidecar, TxEnvelope,
+    TypedTransaction,
+};
+use alloy_eips::{eip1559::Eip1559Estimation, eip2718::Encodable2718};
+use alloy_primitives::{Address, B256, Bytes, TxKind, U256};
+use kona_batcher_core::{DataAvailabilityType, TxCandidate, TxId};
+use kona_protocol::{BlobEncodingError, BlockInfo, blob_sidecar};
+use std::collections::BTreeMap;
+use tracing::{debug, info, warn};
+
+/// The gas of a transaction without calldata.
+const TX_BASE_GAS: u64 = 21_000;
+
+/// The floor gas cost of a calldata token under EIP-7623. A calldata-only transaction never
+/// uses more gas than the floor, so it is used as the gas limit.
+const FLOOR_GAS_PER_TOKEN: u64 = 10;
+
+/// The percentage by which the fees are bumped when a transaction is resubmitted, the minimum
+/// replacement bump of the transaction pool.
+const FEE_BUMP_PERCENT: u128 = 10;
+
+/// The percentage by which the blob fee is bumped when a blob transaction is resubmitted, the
+/// minimum replacement bump for blob transactions of the transaction pool.
+const BLOB_FEE_BUMP_PERCENT: u128 = 100;
+
+/// An error returned by the [`TxManager`].
+#[derive(Debug, thiserror::Error)]
+pub enum TxManagerError {
+    /// An error from the L1 client.
+    #[error(transparent)]
+    Source(#[from] SourceError),
+    /// An error signing a transaction.
+    #[error(transparent)]
+    Signer(#[from] BatcherSignerError),
+    /// A frame does not fit into a blob.
+    #[error(transparent)]
+    Blob(#[from] BlobEncodingError),
+    /// The KZG commitments of the blobs could not be computed.
+    #[error("KZG error: {0:?}")]
+    Kzg(c_kzg::Error),
+}
+
+/// The outcome of a transaction sent by the [`TxManager`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxOutcome {
+    /// The transaction reached the configured number of confirmations in the given block.
+    Confirmed(TxId, BlockInfo),
+    /// The confirmed transaction was reorged out of L1 before its inclusion block was finalized.
+    Reorged(TxId),
+    /// The nonce of the transaction was used by another transaction, so it is never included.
+    Failed(TxId),
+}
+
+/// A transaction sent to L1 that is not yet confirmed.
+#[derive(Debug, Clone)]
+struct PendingTx {
+    /// The id of the transaction in the channel manager.
+    id: TxId,
+    /// The unsigned transaction, with the fees of the last submission.
+    tx: TypedTransaction,
+    /// The hashes of all submissions, any of which may be included.
+    hashes: Vec<B256>,
+    /// The L1 head when the transaction was last submitted.
+    sent_at: u64,
+}
+
+/// A confirmed transaction whose inclusion block is not yet finalized.
+#[derive(Debug, Clone)]
+struct ConfirmedTx {
+    /// The id of the transaction in the channel manager.
+    id: TxId,
+    /// The hash of the included submission.
+    hash: B256,
+    /// The block the transaction was included in.
+    block: BlockInfo,
+}
+
+/// The transaction manager signs and sends batcher transactions to the batch inbox, and tracks
+/// them until they are confirmed.
+///
+/// Transactions that are not included within the resubmission timeout are resubmitted with the
+/// same nonce and bumped fees. Confirmed transactions are tracked until their inclusion block is
+/// finalized, so that they are reported as reorged if their receipt disappears or moves to
+/// another block.
+#[derive(Debug)]
+pub struct TxManager {
+    /// The configuration.
+    config: TxManagerConfig,
+    /// The signer of the transactions.
+    signer: BatcherSignerHandler,
+    /// The L1 chain id.
+    chain_id: u64,
+    /// The batch inbox address.
+    inbox: Address,
+    /// The nonce of the next transaction, or `None` if it must be fetched from L1.
+    nonce: Option<u64>,
+    /// The transactions in flight, by nonce.
+    pending: BTreeMap<u64, PendingTx>,
+    /// The confirmed transactions whose inclusion block is not finalized, by nonce.
+    confirmed: BTreeMap<u64, ConfirmedTx>,
+}
+
+impl TxManager {
+    /// Creates a new [`TxManager`].
+    pub const fn new(
+        config: TxManagerConfig,
+        signer: BatcherSignerHandler,
+        chain_id: u64,
+        inbox: Address,
+    ) -> Self {
+        Self {
+            config,
+            signer,
+            chain_id,
+            inbox,
+            nonce: None,
+            pending: BTreeMap::new(),
+            confirmed: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the address sending the transactions.
+    pub const fn sender(&self) -> Address {
+        self.signer.address()
+    }
+
+    /// Returns the number of transactions in flight.
+    pub const fn pending_txs(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns the number of confirmed transactions whose inclusion block is not finalized.
+    pub const fn confirmed_txs(&self) -> usize {
+        self.confirmed.len()
+    }
+
+    /// Returns whether another transaction may be sent.
+    pub const fn has_capacity(&self) -> bool {
+        self.pending.len() < self.config.max_pending_txs
+    }
+
+    /// Signs and sends the given transaction candidate.
+    ///
+    /// If this returns an error, the transaction was not sent and the candidate should be
+    /// reported as failed.
+    pub async fn send<L: L1Client>(
+        &mut self,
+        l1: &L,
+        candidate: &TxCandidate,
+        head: u64,
+    ) -> Result<(), TxManagerError> {
+        let nonce = match self.nonce {
+            Some(nonce) => nonce,
+            None => l1.pending_nonce(self.sender()).await?,
+        };
+        let tx = self.build_tx(l1, candidate, nonce).await?;
+
+        let hash = match self.sign_and_send(l1, &tx).await {
+            Ok(hash) => hash,
+            Err(err) => {
+                // The nonce may be out of sync with L1, so it is fetched again for the next
+                // transaction.
+                self.nonce = None;
+                return Err(err);
+            }
+        };
+
+        info!(
+            target: "batcher::txmgr",
+            tx_id = candidate.id.0,
+            %hash,
+            nonce,
+            frames = candidate.frames.len(),
+            "Sent batcher transaction"
+        );
+        kona_macros::inc!(counter, crate::Metrics::TXS_SENT);
+
+        self.nonce = Some(nonce + 1);
+        self.pending
+            .insert(nonce, PendingTx { id: candidate.id, tx, hashes: vec![hash], sent_at: head });
+        Ok(())
+    }
+
+    /// Checks the transactions in flight and the unfinalized confirmed transactions against the
+    /// given L1 head.
+    ///
+    /// Returns the transactions that reached the configured number of confirmations with their
+    /// inclusion block, the confirmed transactions that were reorged out, and the transactions
+    /// whose nonce was used by another transaction. The transactions that were not included
+    /// within the resubmission timeout are resubmitted.
+    pub async fn poll<L: L1Client>(
+        &mut self,
+        l1: &L,
+        head: BlockInfo,
+    ) -> Result<Vec<TxOutcome>, TxManagerError> {
+        let mut outcomes = self.check_confirmed(l1).await?;
+        if self.pending.is_empty() {
+            return Ok(outcomes);
+        }
+
+        // The account nonce is fetched before the receipts, so that a transaction included in
+        // between is not taken for one whose nonce was used by another transaction.
+        let account_nonce = l1.nonce(self.sender()).await?;
+        let nonces = self.pending.keys().copied().collect::<Vec<_>>();
+        for nonce in nonces {
+            let Some(pending) = self.pending.get(&nonce) else {
+                continue;
+            };
+
+            let mut included = None;
+            for hash in &pending.hashes {
+                if let Some(inclusion) = l1.inclusion_block(*hash).await? {
+                    included = Some((*hash, inclusion));
+                    break;
+                }
+            }
+
+            match included {
+                Some((hash, inclusion))
+                    if head.number.saturating_add(1) >=
+                        inclusion.number.saturating_add(self.config.num_confirmations) =>
+                {
+                    let block = l1.block_by_number(inclusion.number).await?;
+                    if block.hash != inclusion.hash {
+                        // The receipt is from a block that was just reorged out, it is checked
+                        // again on the next poll.
+                        continue;
+                    }
+                    debug!(
+                        target: "batcher::txmgr",
+                        tx_id = pending.id.0,
+                        block = block.number,
+                        "Batcher transaction confirmed"
+                    );
+                    kona_macros::inc!(counter, crate::Metrics::TXS_CONFIRMED);
+                    outcomes.push(TxOutcome::Confirmed(pending.id, block));
+                    self.confirmed.insert(nonce, ConfirmedTx { id: pending.id, hash, block });
+                    self.pending.remove(&nonce);
+                }
+                None if nonce < account_nonce => {
+                    warn!(
+                        target: "batcher::txmgr",
+                        tx_id = pending.id.0,
+                        nonce,
+                        "Nonce of batcher transaction was used by another transaction"
+                    );
+                    kona_macros::inc!(counter, crate::Metrics::TXS_FAILED);
+                    outcomes.push(TxOutcome::Failed(pending.id));
+                    self.pending.remove(&nonce);
+                    self.nonce = None;
+                }
+                None if head.number >=
+                    pending.sent_at.saturating_add(self.config.resubmission_timeout) =>
+                {
+                    if let Err(err) = self.resubmit(l1, nonce, head.number).await {
+                        warn!(target: "batcher::txmgr", nonce, %err, "Failed to resubmit transaction");
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(outcomes)
+    }
+
+    /// Checks the receipts of the confirmed transactions, and stops tracking the transactions
+    /// whose inclusion block is finalized.
+    ///
+    /// Returns the transactions whose receipt disappeared or moved to another block.
+    async fn check_confirmed<L: L1Client>(
+        &mut self,
+        l1: &L,
+    ) -> Result<Vec<TxOutcome>, TxManagerError> {
+        if self.confirmed.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let finalized = l1.finalized().await?;
+        self.confirmed.retain(|_, tx| tx.block.number > finalized.number);
+
+        let mut reorged = Vec::new();
+        for (nonce, tx) in &self.confirmed {
+            let inclusion = l1.inclusion_block(tx.hash).await?;
+            if inclusion.is_none_or(|inclusion| inclusion.hash != tx.block.hash) {
+                reorged.push(*nonce);
+            }
+        }
+
+        let mut outcomes = Vec::with_capacity(reorged.len());
+        for nonce in reorged {
+            let Some(tx) = self.confirmed.remove(&nonce) else {
+                continue;
+            };
+            warn!(
+                target: "batcher::txmgr",
+                tx_id = tx.id.0,
+                block = tx.block.number,
+                "Confirmed batcher transaction was reorged out"
+            );
+            kona_macros::inc!(counter, crate::Metrics::TXS_REORGED);
+            outcomes.push(TxOutcome::Reorged(tx.id));
+        }
+        if !outcomes.is_empty() {
+            // The reorged transactions may have been dropped, freeing their nonces.
+            self.nonce = None;
+        }
+        Ok(outcomes)
+    }
+
+    /// Resubmits the transaction with the given nonce with bumped fees.
+    async fn resubmit<L: L1Client>(
+        &mut self,
+        l1: &L,
+        nonce: u64,
+        head: u64,
+    ) -> Result<(), TxManagerError> {
+        let Some(mut tx) = self.pending.get(&nonce).map(|pending| pending.tx.clone()) else {
+            return Ok(());
+        };
+
+        let fees = l1.fees().await?;
+        let blob_base_fee = match tx {
+            TypedTransaction::Eip4844(_) => Some(l1.blob_base_fee().await?),
+            _ => None,
+        };
+        bump_fees(&mut tx, fees, blob_base_fee);
+        let hash = self.sign_and_send(l1, &tx).await?;
+
+        info!(target: "batcher::txmgr", %hash, nonce, "Resubmitted batcher transaction");
+        kona_macros::inc!(counter, crate::Metrics::TXS_RESUBMITTED);
+
+        if let Some(pending) = self.pending.get_mut(&nonce) {
+            pending.tx = tx;
+            pending.hashes.push(hash);
+            pending.sent_at = head;
+        }
+        Ok(())
+    }
+
+    /// Builds the unsigned transaction carrying the frames of the candidate.
+    async fn build_tx<L: L1Client>(
+        &self,
+        l1: &L,
+        candidate: &TxCandidate,
+        nonce: u64,
+    ) -> Result<TypedTransaction, TxManagerError> {
+        let fees = l1.fees().await?;
+        match candidate.da_type {
+            DataAvailabilityType::Calldata => {
+                let input = candidate.calldata();
+                Ok(TypedTransaction::Eip1559(TxEip1559 {
+                    chain_id: self.chain_id,
+                    nonce,
+                    gas_limit: calldata_gas(&input),
+                    max_fee_per_gas: fees.max_fee_per_gas,
+                    max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+                    to: TxKind::Call(self.inbox),
+                    value: U256::ZERO,
+                    access_list: Default::default(),
+                    input,
+                }))
+            }
+            DataAvailabilityType::Blobs => {
+                let sidecar = blob_sidecar(candidate.blobs()?).map_err(TxManagerError::Kzg)?;
+                let blob_base_fee = l1.blob_base_fee().await?;
+                let tx = TxEip4844 {
+                    chain_id: self.chain_id,
+                    nonce,
+                    gas_limit: TX_BASE_GAS,
+                    max_fee_per_gas: fees.max_fee_per_gas,
+                    max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+                    to: self.inbox,
+                    value: U256::ZERO,
+                    access_list: Default::default(),
+                    blob_versioned_hashes: sidecar.versioned_hashes().collect(),
+                    // Leave room for the blob base fee to double before the transaction is
+                    // included.
+                    max_fee_per_blob_gas: blob_base_fee.saturating_mul(2).max(1),
+                    input: Bytes::new(),
+                };
+                Ok(TypedTransaction::Eip4844(TxEip4844Variant::TxEip4844WithSidecar(
+                    TxEip4844WithSidecar::from_tx_and_sidecar(tx, sidecar),
+                )))
+            }
+        }
+    }
+
+    /// Signs the transaction and sends it to L1, returning its hash.
+    async fn sign_and_send<L: L1Client>(
+        &self,
+        l1: &L,
+        tx: &TypedTransaction,
+    ) -> Result<B256, TxManagerError> {
+        let signature = self.signer.sign(tx).await?;
+        let envelope = TxEnvelope::from(tx.clone().into_signed(signature));
+        Ok(l1.send_raw_transaction(envelope.encoded_2718().into()).await?)
+    }
+}
+
+/// Returns the gas limit of a transaction to the batch inbox with the given calldata.
+fn calldata_gas(data: &[u8]) -> u64 {
+    let zeros = data.iter().filter(|byte| **byte == 0).count() as u64;
+    let tokens = zeros + (data.len() as u64 - zeros) * 4;
+    TX_BASE_GAS + tokens * FLOOR_GAS_PER_TOKEN
+}
+
+/// Bumps the given fee by the given percentage, and raises it to the suggested fee if that is
+/// higher.
+const fn bump(fee: u128, percent: u128, suggested: u128) -> u128 {
+    let bumped = fee.saturating_add(fee.saturating_mul(percent) / 100 + 1);
+    if bumped > suggested { bumped } else { suggested }
+}
+
+/// Bumps the fees of the transaction for a resubmission.
+fn bump_fees(tx: &mut TypedTransaction, fees: Eip1559Estimation, blob_base_fee: Option<u128>) {
+    match tx {
+        TypedTransaction::Eip1559(tx) => {
+            tx.max_fee_per_gas = bump(tx.max_fee_per_gas, FEE_BUMP_PERCENT, fees.max_fee_per_gas);
+            tx.max_priority_fee_per_gas =
+                bump(tx.max_priority_fee_per_gas, FEE_BUMP_PERCENT, fees.max_priority_fee_per_gas);
+        }
+        TypedTransaction::Eip4844(
+            TxEip4844Variant::TxEip4844(tx) |
+            TxEip4844Variant::TxEip4844WithSidecar(TxEip4844WithSidecar { tx, .. }),
+        ) => {
+            tx.max_fee_per_gas =
+                bump(tx.max_fee_per_gas, BLOB_FEE_BUMP_PERCENT, fees.max_fee_per_gas);
+            tx.max_priority_fee_per_gas = bump(
+                tx.max_priority_fee_per_gas,
+                BLOB_FEE_BUMP_PERCENT,
+                fees.max_priority_fee_per_gas,
+            );
+            tx.max_fee_per_blob_gas = bump(
+                tx.max_fee_per_blob_gas,
+                BLOB_FEE_BUMP_PERCENT,
+                blob_base_fee.unwrap_or_default().saturating_mul(2),
+            );
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockL1;
+    use alloy_signer_local::PrivateKeySigner;
+    use kona_protocol::Frame;
+
+    fn txmgr() -> TxManager {
+        let config =
+            TxManagerConfig { num_confirmations: 1, resubmission_timeout: 2, max_pending_txs: 1 };
+        let signer = PrivateKeySigner::from_bytes(&B256::with_last_byte(1)).unwrap();
+        TxManager::new(config, BatcherSignerHandler::Local(signer), 1, Address::repeat_byte(0xFF))
+    }
+
+    fn candidate(id: u64) -> TxCandidate {
+        let channel_id = [0xAA; 16];
+        TxCandidate {
+            id: TxId(id),
+            channel_id,
+            frames: vec![Frame::new(channel_id, 0, vec![1, 2, 3], true)],
+            da_type: DataAvailabilityType::Calldata,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_tracks_confirmed_txs_until_finalized() {
+        let l1 = MockL1::new();
+        let mut txmgr = txmgr();
+
+        txmgr.send(&l1, &candidate(0), 0).await.unwrap();
+        let block = l1.mine();
+        assert_eq!(
+            txmgr.poll(&l1, block).await.unwrap(),
+            vec![TxOutcome::Confirmed(TxId(0), block)]
+        );
+        assert_eq!(txmgr.pending_txs(), 0);
+        assert_eq!(txmgr.confirmed_txs(), 1);
+
+        let head = l1.mine();
+        assert!(txmgr.poll(&l1, head).await.unwrap().is_empty());
+        assert_eq!(txmgr.confirmed_txs(), 1);
+
+        l1.finalize(block.number);
+        assert!(txmgr.poll(&l1, head).await.unwrap().is_empty());
+        assert_eq!(txmgr.confirmed_txs(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_poll_reports_reorged_txs() {
+        let l1 = MockL1::new();
+        let mut txmgr = txmgr();
+
+        txmgr.send(&l1, &candidate(0), 0).await.unwrap();
+        let block = l1.mine();
+        assert_eq!(txmgr.poll(&l1, block).await.unwrap().len(), 1);
+
+        // The transaction is included again in a block with the same number but another hash.
+        l1.reorg(1);
+        let head = l1.mine();
+        assert_eq!(head.number, block.number);
+        assert_ne!(head.hash, block.hash);
+        assert_eq!(txmgr.poll(&l1, head).await.unwrap(), vec![TxOutcome::Reorged(TxId(0))]);
+        assert_eq!(txmgr.confirmed_txs(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_poll_fails_txs_whose_nonce_was_used() {
+        let l1 = MockL1::new();
+        let mut txmgr = txmgr();
+
+        l1.set_include_txs(false);
+        txmgr.send(&l1, &candidate(0), 0).await.unwrap();
+        assert!(!txmgr.has_capacity());
+
+        l1.use_nonce();
+        let head = l1.mine();
+        assert_eq!(txmgr.poll(&l1, head).await.unwrap(), vec![TxOutcome::Failed(TxId(0))]);
+        assert!(txmgr.has_capacity());
+
+        // The next transaction uses the next free nonce.
+        l1.set_include_txs(true);
+        txmgr.send(&l1, &candidate(1), head.number).await.unwrap();
+        let head = l1.mine();
+        assert_eq!(txmgr.poll(&l1, head).await.unwrap(), vec![TxOutcome::Confirmed(TxId(1), head)]);
+    }
+
+    #[test]
+    fn test_calldata_gas() {
+        assert_eq!(calldata_gas(&[]), 21_000);
+        assert_eq!(calldata_gas(&[0, 0]), 21_000 + 2 * 10);
+        assert_eq!(calldata_gas(&[0, 1]), 21_000 + 5 * 10);
+    }
+
+    #[test]
+    fn test_bump() {
+        assert_eq!(bump(100, FEE_BUMP_PERCENT, 0), 111);
+        assert_eq!(bump(100, FEE_BUMP_PERCENT, 200), 200);
+        assert_eq!(bump(100, BLOB_FEE_BUMP_PERCENT, 0), 201);
+        assert_eq!(bump(0, FEE_BUMP_PERCENT, 0), 1);
+    }
+
+    #[test]
+    fn test_bump_fees_calldata() {
+        let mut tx = TypedTransaction::Eip1559(TxEip1559 {
+            max_fee_per_gas: 1_000,
+            max_priority_fee_per_gas: 100,
+            ..Default::default()
+        });
+        let fees = Eip1559Estimation { max_fee_per_gas: 1_050, max_priority_fee_per_gas: 500 };
+        bump_fees(&mut tx, fees, None);
+
+        let TypedTransaction::Eip1559(tx) = tx else { panic!("expected an EIP-1559 transaction") };
+        assert_eq!(tx.max_fee_per_gas, 1_101);
+        assert_eq!(tx.max_priority_fee_per_gas, 500);
+    }
+}
@@ -1,7 +1,7 @@
 //! Contains a builder for the discovery service.
 
 use discv5::{Config, Discv5, Enr, enr::k256};
-use kona_peers::{BootStoreFile, OpStackEnr};
+use kona_peers::{BootStoreFile, OpStackEnr, PeerDb, PeerDbFile};
 use std::net::IpAddr;
 use tokio::time::Duration;
 
@@ -89,6 +89,8 @@ pub struct Discv5Builder {
     store_interval: Option<Duration>,
     /// Whether or not to forward the initial set of valid ENRs to the gossip layer.
     forward: bool,
+    /// An optional path to the peer database.
+    peer_db: Option<PeerDbFile>,
 }
 
 impl Discv5Builder {
@@ -104,6 +106,7 @@ impl Discv5Builder {
             bootnodes: Vec::new(),
             store_interval: None,
             forward: true,
+            peer_db: None,
         }
     }
 
@@ -113,6 +116,12 @@ impl Discv5Builder {
         self
     }
 
+    /// Sets the peer database file to load bans from.
+    pub fn with_peer_db_file(mut self, peer_db: Option<PeerDbFile>) -> Self {
+        self.peer_db = peer_db;
+        self
+    }
+
     /// Sets the initial bootnodes to add to the bootstore.
     pub fn with_bootnodes(mut self, bootnodes: Vec<Enr>) -> Self {
         self.bootnodes = bootnodes;
@@ -181,6 +190,13 @@ impl Discv5Builder {
         driver.store_interval = self.store_interval.unwrap_or(Duration::from_secs(60));
         driver.forward = self.forward;
         driver.remove_interval = self.randomize;
+        // The file handle is dropped since the gossip driver owns writes to the peer database.
+        if let Some(file) = self.peer_db {
+            let db: PeerDb = file
+                .try_into()
+                .map_err(|e: std::io::Error| Discv5BuilderError::PeerDbOpenFailed(e.to_string()))?;
+            driver.peer_db = PeerDb { file: None, ..db };
+        }
         Ok(driver)
     }
 }
@@ -3,7 +3,10 @@
 use backon::{ExponentialBuilder, RetryableWithContext};
 use derive_more::Debug;
 use discv5::{Config, Discv5, Enr, enr::NodeId};
-use kona_peers::{BootNode, BootNodes, BootStore, BootStoreFile, EnrValidation, enr_to_multiaddr};
+use kona_peers::{
+    BootNode, BootNodes, BootStore, BootStoreFile, EnrValidation, PeerBan, PeerDb,
+    enr_to_multiaddr, enr_to_peer_id, unix_now,
+};
 use libp2p::Multiaddr;
 use tokio::{
     sync::mpsc::channel,
@@ -47,6 +50,11 @@ pub struct Discv5Driver {
     /// The frequency at which to remove random nodes from the discovery table.
     /// This is not enabled (`None`) by default.
     pub remove_interval: Option<Duration>,
+    /// The persisted [`PeerDb`].
+    ///
+    /// Peers banned in the database are kept out of the discovery table. The discovery service
+    /// only reads the database, writes are owned by the gossip driver.
+    pub peer_db: PeerDb,
 }
 
 impl Discv5Driver {
@@ -82,6 +90,7 @@ impl Discv5Driver {
             forward: true,
             remove_interval: None,
             store_interval: Duration::from_secs(60),
+            peer_db: PeerDb::default(),
         })
     }
 
@@ -179,6 +188,10 @@ impl Discv5Driver {
         // Instead of erroring, we log the failure as a debug log.
         let mut count = 0;
         for enr in self.store.valid_peers() {
+            if self.is_banned(enr) {
+                trace!(target: "discovery", "Ignoring banned Bootstore ENR: {:?}", enr);
+                continue;
+            }
             let validation = EnrValidation::validate(enr, self.chain_id);
             if validation.is_invalid() {
                 trace!(target: "discovery", "Ignoring Invalid Bootnode ENR: {:?}. {:?}", enr, validation);
@@ -219,8 +232,43 @@ impl Discv5Driver {
         );
     }
 
+    /// Returns `true` if the node behind the [`Enr`] is banned in the [`PeerDb`].
+    fn is_banned(&self, enr: &Enr) -> bool {
+        enr_to_peer_id(enr).is_some_and(|peer_id| self.peer_db.is_banned(&peer_id))
+    }
+
+    /// Applies the bans persisted in the [`PeerDb`] to the [`Discv5`] service.
+    fn apply_peer_db(&self) {
+        let now = unix_now();
+        let mut banned = 0;
+        for enr in &self.store.peers {
+            let Some(ban) = enr_to_peer_id(enr)
+                .and_then(|peer_id| self.peer_db.get(&peer_id))
+                .and_then(|record| record.ban.filter(|ban| ban.is_active(now)))
+            else {
+                continue;
+            };
+            let duration = match ban {
+                PeerBan::Permanent => None,
+                PeerBan::Until(expiry) => Some(Duration::from_secs(expiry.saturating_sub(now))),
+            };
+            self.disc.ban_node(&enr.node_id(), duration);
+            banned += 1;
+        }
+        for addr in &self.peer_db.blocked_addrs {
+            self.disc.ban_ip(*addr, None);
+        }
+        info!(
+            target: "discovery",
+            banned_nodes = banned,
+            banned_ips = self.peer_db.blocked_addrs.len(),
+            "Applied peer database bans to discovery"
+        );
+    }
+
     /// Bootstraps the [`Discv5`] service with the bootnodes.
     async fn bootstrap(&mut self) {
+        self.apply_peer_db();
         self.bootnode_bootstrap();
         self.bootstore_bootstrap();
         self.enode_bootstrap().await;
@@ -232,6 +280,9 @@ impl Discv5Driver {
             return;
         }
         for enr in self.store.valid_peers_with_chain_id(self.chain_id) {
+            if self.is_banned(enr) {
+                continue;
+            }
             if let Err(e) = enr_sender.send(enr.clone()).await {
                 debug!(target: "discovery", "Failed to forward enr: {:?}", e);
             }
@@ -12,4 +12,8 @@ pub enum Discv5BuilderError {
     /// Failed to build the ENR.
     #[error("failed to build ENR")]
     EnrBuildFailed,
+    /// Failed to open the peer database.
+    #[error("failed to open peer database: {0}")]
+    #[from(skip)]
+    PeerDbOpenFailed(String),
 }
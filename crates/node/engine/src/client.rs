@@ -1,11 +1,14 @@
 //! An Engine API Client.
 
-use crate::Metrics;
+use crate::{
+    Metrics,
+    shadow::{ShadowCall, ShadowEngine},
+};
 use alloy_eips::eip1898::BlockNumberOrTag;
 use alloy_network::Network;
 use alloy_primitives::{B256, BlockHash, Bytes};
 use alloy_provider::{Provider, RootProvider};
-use alloy_rpc_client::RpcClient;
+use alloy_rpc_client::{ClientBuilder, IpcConnect, RpcClient};
 use alloy_rpc_types_engine::{
     ClientVersionV1, ExecutionPayloadBodiesV1, ExecutionPayloadEnvelopeV2, ExecutionPayloadInputV2,
     ExecutionPayloadV3, ForkchoiceState, ForkchoiceUpdated, JwtSecret, PayloadId, PayloadStatus,
@@ -30,7 +33,7 @@ use op_alloy_rpc_types_engine::{
     OpExecutionPayloadEnvelopeV3, OpExecutionPayloadEnvelopeV4, OpExecutionPayloadV4,
     OpPayloadAttributes, ProtocolVersion,
 };
-use std::{sync::Arc, time::Instant};
+use std::{path::PathBuf, sync::Arc, time::Instant};
 use thiserror::Error;
 use tower::ServiceBuilder;
 use url::Url;
@@ -46,8 +49,37 @@ pub enum EngineClientError {
     #[error("An error occurred while decoding the payload: {0}")]
     BlockInfoDecodeError(#[from] FromBlockError),
 }
+
+/// The transport used to reach an execution layer's Engine API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EngineEndpoint {
+    /// JWT-authenticated HTTP, typically on port 8551.
+    Http {
+        /// The Engine API endpoint URL.
+        url: Url,
+        /// The JWT secret used to authenticate with the Engine API.
+        jwt: JwtSecret,
+    },
+    /// A Unix domain socket. IPC connections are trusted by the execution layer and do not use
+    /// JWT authentication.
+    Ipc(PathBuf),
+}
+
+impl EngineEndpoint {
+    /// Connects to the endpoint, returning the Engine API provider.
+    pub async fn connect(&self) -> Result<RootProvider<Optimism>, EngineClientError> {
+        match self {
+            Self::Http { url, jwt } => Ok(EngineClient::rpc_client(url.clone(), *jwt)),
+            Self::Ipc(path) => {
+                let client = ClientBuilder::default().ipc(IpcConnect::new(path.clone())).await?;
+                Ok(RootProvider::new(client))
+            }
+        }
+    }
+}
 /// A Hyper HTTP client with a JWT authentication layer.
-type HyperAuthClient<B = Full<Bytes>> = HyperClient<B, AuthService<Client<HttpConnector, B>>>;
+pub(crate) type HyperAuthClient<B = Full<Bytes>> =
+    HyperClient<B, AuthService<Client<HttpConnector, B>>>;
 
 /// An Engine API client that provides authenticated HTTP communication with an execution layer.
 ///
@@ -83,6 +115,9 @@ pub struct EngineClient {
     l1_provider: RootProvider,
     /// The [`RollupConfig`] for determining Engine API versions based on hardfork activations.
     cfg: Arc<RollupConfig>,
+    /// An optional shadow execution layer that mirrors `engine_newPayload` calls and the
+    /// `engine_forkchoiceUpdated` calls without payload attributes.
+    shadow: Option<ShadowEngine>,
 }
 
 impl EngineClient {
@@ -113,7 +148,58 @@ impl EngineClient {
         let engine = Self::rpc_client::<Optimism>(engine, jwt);
         let l1_provider = RootProvider::new_http(l1_rpc);
 
-        Self { engine, l1_provider, cfg }
+        Self { engine, l1_provider, cfg, shadow: None }
+    }
+
+    /// Creates a new [`EngineClient`] for the given [`EngineEndpoint`].
+    ///
+    /// Over IPC, the Engine API is reached through a Unix domain socket, which is useful when the
+    /// node and the execution layer are co-located. The connection to the L1 chain always uses
+    /// HTTP.
+    pub async fn connect(
+        endpoint: &EngineEndpoint,
+        l1_rpc: Url,
+        cfg: Arc<RollupConfig>,
+    ) -> Result<Self, EngineClientError> {
+        let engine = endpoint.connect().await?;
+        let l1_provider = RootProvider::new_http(l1_rpc);
+
+        Ok(Self { engine, l1_provider, cfg, shadow: None })
+    }
+
+    /// Creates a new [`EngineClient`] whose Engine API and L1 requests are answered by the given
+    /// [`Asserter`], in call order.
+    ///
+    /// [`Asserter`]: alloy_transport::mock::Asserter
+    #[cfg(test)]
+    pub(crate) fn mocked(
+        asserter: alloy_transport::mock::Asserter,
+        cfg: Arc<RollupConfig>,
+    ) -> Self {
+        use alloy_transport::mock::MockTransport;
+
+        let engine = RootProvider::new(RpcClient::new(MockTransport::new(asserter.clone()), false));
+        let l1_provider = RootProvider::new(RpcClient::new(MockTransport::new(asserter), false));
+
+        Self { engine, l1_provider, cfg, shadow: None }
+    }
+
+    /// Mirrors `engine_newPayload` calls, and `engine_forkchoiceUpdated` calls without payload
+    /// attributes, to the given [`ShadowEngine`].
+    pub fn with_shadow(self, shadow: ShadowEngine) -> Self {
+        Self { shadow: Some(shadow), ..self }
+    }
+
+    /// Returns the [`ShadowEngine`], if any.
+    pub const fn shadow(&self) -> Option<&ShadowEngine> {
+        self.shadow.as_ref()
+    }
+
+    /// Mirrors a call to the [`ShadowEngine`], if one is configured.
+    fn mirror(&self, call: impl FnOnce() -> ShadowCall, primary: &PayloadStatus) {
+        if let Some(shadow) = &self.shadow {
+            shadow.mirror(call(), primary);
+        }
     }
 
     /// Returns a reference to the inner L2 [`RootProvider`].
@@ -159,12 +245,17 @@ impl OpEngineApi<Optimism, Http<HyperAuthClient>> for EngineClient {
         &self,
         payload: ExecutionPayloadInputV2,
     ) -> TransportResult<PayloadStatus> {
+        let shadow_payload = self.shadow.as_ref().map(|_| payload.clone());
         let call = <RootProvider<Optimism> as OpEngineApi<
             Optimism,
             Http<HyperAuthClient>,
         >>::new_payload_v2(&self.engine, payload);
 
-        record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await
+        let status = record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await?;
+        if let Some(payload) = shadow_payload {
+            self.mirror(|| ShadowCall::NewPayloadV2(payload), &status);
+        }
+        Ok(status)
     }
 
     async fn new_payload_v3(
@@ -172,12 +263,17 @@ impl OpEngineApi<Optimism, Http<HyperAuthClient>> for EngineClient {
         payload: ExecutionPayloadV3,
         parent_beacon_block_root: B256,
     ) -> TransportResult<PayloadStatus> {
+        let shadow_payload = self.shadow.as_ref().map(|_| payload.clone());
         let call = <RootProvider<Optimism> as OpEngineApi<
             Optimism,
             Http<HyperAuthClient>,
         >>::new_payload_v3(&self.engine, payload, parent_beacon_block_root);
 
-        record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await
+        let status = record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await?;
+        if let Some(payload) = shadow_payload {
+            self.mirror(|| ShadowCall::NewPayloadV3(payload, parent_beacon_block_root), &status);
+        }
+        Ok(status)
     }
 
     async fn new_payload_v4(
@@ -185,12 +281,17 @@ impl OpEngineApi<Optimism, Http<HyperAuthClient>> for EngineClient {
         payload: OpExecutionPayloadV4,
         parent_beacon_block_root: B256,
     ) -> TransportResult<PayloadStatus> {
+        let shadow_payload = self.shadow.as_ref().map(|_| payload.clone());
         let call = <RootProvider<Optimism> as OpEngineApi<
             Optimism,
             Http<HyperAuthClient>,
         >>::new_payload_v4(&self.engine, payload, parent_beacon_block_root);
 
-        record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await
+        let status = record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await?;
+        if let Some(payload) = shadow_payload {
+            self.mirror(|| ShadowCall::NewPayloadV4(payload, parent_beacon_block_root), &status);
+        }
+        Ok(status)
     }
 
     async fn fork_choice_updated_v2(
@@ -198,12 +299,23 @@ impl OpEngineApi<Optimism, Http<HyperAuthClient>> for EngineClient {
         fork_choice_state: ForkchoiceState,
         payload_attributes: Option<OpPayloadAttributes>,
     ) -> TransportResult<ForkchoiceUpdated> {
+        // Only forkchoice updates without payload attributes are mirrored. With attributes, the
+        // shadow would start building a payload that is never fetched, and its payload id could
+        // not be compared with the primary's.
+        let mirrored = payload_attributes.is_none();
         let call = <RootProvider<Optimism> as OpEngineApi<
             Optimism,
             Http<HyperAuthClient>,
         >>::fork_choice_updated_v2(&self.engine, fork_choice_state, payload_attributes);
 
-        record_call_time(call, Metrics::FORKCHOICE_UPDATE_METHOD).await
+        let updated = record_call_time(call, Metrics::FORKCHOICE_UPDATE_METHOD).await?;
+        if mirrored {
+            self.mirror(
+                || ShadowCall::ForkchoiceUpdatedV2(fork_choice_state),
+                &updated.payload_status,
+            );
+        }
+        Ok(updated)
     }
 
     async fn fork_choice_updated_v3(
@@ -211,12 +323,23 @@ impl OpEngineApi<Optimism, Http<HyperAuthClient>> for EngineClient {
         fork_choice_state: ForkchoiceState,
         payload_attributes: Option<OpPayloadAttributes>,
     ) -> TransportResult<ForkchoiceUpdated> {
+        // Only forkchoice updates without payload attributes are mirrored. With attributes, the
+        // shadow would start building a payload that is never fetched, and its payload id could
+        // not be compared with the primary's.
+        let mirrored = payload_attributes.is_none();
         let call = <RootProvider<Optimism> as OpEngineApi<
             Optimism,
             Http<HyperAuthClient>,
         >>::fork_choice_updated_v3(&self.engine, fork_choice_state, payload_attributes);
 
-        record_call_time(call, Metrics::FORKCHOICE_UPDATE_METHOD).await
+        let updated = record_call_time(call, Metrics::FORKCHOICE_UPDATE_METHOD).await?;
+        if mirrored {
+            self.mirror(
+                || ShadowCall::ForkchoiceUpdatedV3(fork_choice_state),
+                &updated.payload_status,
+            );
+        }
+        Ok(updated)
     }
 
     async fn get_payload_v2(
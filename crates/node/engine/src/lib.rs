@@ -19,19 +19,21 @@
 //!        ▼                   ▼                   ▼
 //! ┌─────────────┐    ┌──────────────┐    ┌─────────────┐
 //! │ Engine API  │    │ Engine State │    │ Rollup      │
-//! │ (HTTP/JWT)  │    │   Updates    │    │ Config      │
+//! │ (HTTP/IPC)  │    │   Updates    │    │ Config      │
 //! └─────────────┘    └──────────────┘    └─────────────┘
 //! ```
 //!
 //! ## Module Organization
 //!
 //! - **Task Queue** - Core engine task queue and execution logic via [`Engine`]
-//! - **Client** - HTTP client for Engine API communication via [`EngineClient`]
+//! - **Client** - HTTP or IPC client for Engine API communication via [`EngineClient`]
+//! - **Shadow** - Mirroring of Engine API calls to a second execution layer via [`ShadowEngine`]
 //! - **State** - Engine state management and synchronization via [`EngineState`]
 //! - **Versions** - Engine API version selection via [`EngineForkchoiceVersion`],
 //!   [`EngineNewPayloadVersion`], [`EngineGetPayloadVersion`]
 //! - **Attributes** - Payload attribute validation via [`AttributesMatch`]
 //! - **Kinds** - Engine client type identification via [`EngineKind`]
+//! - **Mode** - Execution layer sync strategy selection via [`SyncMode`]
 //! - **Query** - Engine query interface via [`EngineQueries`]
 //! - **Metrics** - Optional Prometheus metrics collection via [`Metrics`]
 
@@ -50,7 +52,10 @@ mod attributes;
 pub use attributes::{AttributesMatch, AttributesMismatch};
 
 mod client;
-pub use client::{EngineClient, EngineClientError};
+pub use client::{EngineClient, EngineClientError, EngineEndpoint};
+
+mod shadow;
+pub use shadow::{SHADOW_QUEUE_CAPACITY, ShadowDivergence, ShadowEngine};
 
 mod versions;
 pub use versions::{EngineForkchoiceVersion, EngineGetPayloadVersion, EngineNewPayloadVersion};
@@ -61,6 +66,9 @@ pub use state::{EngineState, EngineSyncState, EngineSyncStateUpdate};
 mod kinds;
 pub use kinds::EngineKind;
 
+mod mode;
+pub use mode::{SyncMode, SyncModeParseError};
+
 mod query;
 pub use query::{EngineQueries, EngineQueriesError, EngineQuerySender};
 
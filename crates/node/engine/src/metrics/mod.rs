@@ -72,6 +72,15 @@ impl Metrics {
     /// Identifier for the counter that tracks the number of times the engine has been reset.
     pub const ENGINE_RESET_COUNT: &str = "kona_node_engine_reset_count";
 
+    /// Identifier for the counter that tracks divergences between the primary and the shadow
+    /// execution layers.
+    pub const ENGINE_SHADOW_DIVERGENCE: &str = "kona_node_engine_shadow_divergence";
+    /// Identifier for the counter that tracks failed calls to the shadow execution layer.
+    pub const ENGINE_SHADOW_ERROR: &str = "kona_node_engine_shadow_error";
+    /// Identifier for the counter that tracks calls that were not mirrored to the shadow execution
+    /// layer because its queue was full.
+    pub const ENGINE_SHADOW_DROPPED: &str = "kona_node_engine_shadow_dropped";
+
     /// Initializes metrics for the engine.
     ///
     /// This does two things:
@@ -106,6 +115,23 @@ impl Metrics {
             metrics::Unit::Count,
             "Engine reset count"
         );
+
+        // Shadow engine counters
+        metrics::describe_counter!(
+            Self::ENGINE_SHADOW_DIVERGENCE,
+            metrics::Unit::Count,
+            "Divergences between the primary and the shadow execution layers"
+        );
+        metrics::describe_counter!(
+            Self::ENGINE_SHADOW_ERROR,
+            metrics::Unit::Count,
+            "Failed calls to the shadow execution layer"
+        );
+        metrics::describe_counter!(
+            Self::ENGINE_SHADOW_DROPPED,
+            metrics::Unit::Count,
+            "Calls dropped because the shadow execution layer fell behind"
+        );
     }
 
     /// Initializes metrics to `0` so they can be queried immediately by consumers of prometheus
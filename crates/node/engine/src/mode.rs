@@ -0,0 +1,97 @@
+//! Contains the sync modes the engine can operate in.
+
+use core::{fmt, str::FromStr};
+use kona_protocol::ElSyncStatus;
+
+/// The strategy used to bring the execution layer to the tip of the L2 chain.
+///
+/// Mirrors the op-node's `--syncmode` flag.
+///
+/// # Examples
+///
+/// ```rust
+/// use kona_engine::SyncMode;
+/// use std::str::FromStr;
+///
+/// let mode = SyncMode::from_str("execution-layer").unwrap();
+/// assert_eq!(mode, SyncMode::ExecutionLayer);
+/// assert_eq!(SyncMode::ConsensusLayer.to_string(), "consensus-layer");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SyncMode {
+    /// Consensus-layer sync. Every L2 block is derived from L1 data, and the execution layer is
+    /// driven block-by-block.
+    ConsensusLayer,
+    /// Execution-layer sync. The forkchoice is driven to the unsafe tip received over gossip, and
+    /// the execution layer is left to sync the chain on its own (e.g. via snap sync). Derivation
+    /// only begins once the execution layer reports that it has finished syncing.
+    #[default]
+    ExecutionLayer,
+}
+
+impl SyncMode {
+    /// Contains all valid sync modes.
+    pub const MODES: [Self; 2] = [Self::ConsensusLayer, Self::ExecutionLayer];
+
+    /// Returns `true` if [`Self`] is [`Self::ExecutionLayer`].
+    pub const fn is_execution_layer(&self) -> bool {
+        matches!(self, Self::ExecutionLayer)
+    }
+
+    /// Returns the [`ElSyncStatus`] the engine starts in for this sync mode.
+    pub const fn initial_el_sync_status(&self) -> ElSyncStatus {
+        match self {
+            Self::ConsensusLayer => ElSyncStatus::ConsensusLayer,
+            Self::ExecutionLayer => ElSyncStatus::WillStart,
+        }
+    }
+}
+
+impl fmt::Display for SyncMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConsensusLayer => write!(f, "consensus-layer"),
+            Self::ExecutionLayer => write!(f, "execution-layer"),
+        }
+    }
+}
+
+/// An error returned when parsing an unknown [`SyncMode`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Unknown sync mode: {0}")]
+pub struct SyncModeParseError(String);
+
+impl FromStr for SyncMode {
+    type Err = SyncModeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "consensus-layer" => Ok(Self::ConsensusLayer),
+            "execution-layer" => Ok(Self::ExecutionLayer),
+            _ => Err(SyncModeParseError(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_mode_roundtrip() {
+        for mode in SyncMode::MODES {
+            assert_eq!(SyncMode::from_str(&mode.to_string()).unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn test_sync_mode_unknown() {
+        assert!(SyncMode::from_str("snap").is_err());
+    }
+
+    #[test]
+    fn test_initial_el_sync_status() {
+        assert!(SyncMode::ConsensusLayer.initial_el_sync_status().is_finished());
+        assert!(SyncMode::ExecutionLayer.initial_el_sync_status().is_syncing());
+    }
+}
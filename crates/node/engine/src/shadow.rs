@@ -0,0 +1,271 @@
+//! A shadow execution layer that mirrors the Engine API calls sent to the primary execution layer.
+
+use crate::{Metrics, client::HyperAuthClient};
+use alloy_primitives::B256;
+use alloy_provider::RootProvider;
+use alloy_rpc_types_engine::{
+    ExecutionPayloadInputV2, ExecutionPayloadV3, ForkchoiceState, PayloadStatus, PayloadStatusEnum,
+};
+use alloy_transport::TransportResult;
+use alloy_transport_http::Http;
+use op_alloy_network::Optimism;
+use op_alloy_provider::ext::engine::OpEngineApi;
+use op_alloy_rpc_types_engine::OpExecutionPayloadV4;
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+/// The maximum number of calls queued for the shadow execution layer.
+///
+/// Once the queue is full, new calls are dropped rather than buffered, so that a stalled shadow
+/// cannot grow the node's memory without bound.
+pub const SHADOW_QUEUE_CAPACITY: usize = 1024;
+
+/// An Engine API call mirrored to the shadow execution layer.
+#[derive(Debug, Clone)]
+pub(crate) enum ShadowCall {
+    /// `engine_newPayloadV2`
+    NewPayloadV2(ExecutionPayloadInputV2),
+    /// `engine_newPayloadV3`
+    NewPayloadV3(ExecutionPayloadV3, B256),
+    /// `engine_newPayloadV4`
+    NewPayloadV4(OpExecutionPayloadV4, B256),
+    /// `engine_forkchoiceUpdatedV2`, without payload attributes.
+    ForkchoiceUpdatedV2(ForkchoiceState),
+    /// `engine_forkchoiceUpdatedV3`, without payload attributes.
+    ForkchoiceUpdatedV3(ForkchoiceState),
+}
+
+impl ShadowCall {
+    /// Returns the metric label of the Engine API method.
+    const fn method(&self) -> &'static str {
+        match self {
+            Self::NewPayloadV2(_) | Self::NewPayloadV3(..) | Self::NewPayloadV4(..) => {
+                Metrics::NEW_PAYLOAD_METHOD
+            }
+            Self::ForkchoiceUpdatedV2(..) | Self::ForkchoiceUpdatedV3(..) => {
+                Metrics::FORKCHOICE_UPDATE_METHOD
+            }
+        }
+    }
+
+    /// Sends the call to the given Engine API provider, returning the [`PayloadStatus`].
+    async fn execute(self, engine: &RootProvider<Optimism>) -> TransportResult<PayloadStatus> {
+        match self {
+            Self::NewPayloadV2(payload) => <RootProvider<Optimism> as OpEngineApi<
+                Optimism,
+                Http<HyperAuthClient>,
+            >>::new_payload_v2(engine, payload)
+            .await,
+            Self::NewPayloadV3(payload, root) => <RootProvider<Optimism> as OpEngineApi<
+                Optimism,
+                Http<HyperAuthClient>,
+            >>::new_payload_v3(
+                engine, payload, root
+            )
+            .await,
+            Self::NewPayloadV4(payload, root) => <RootProvider<Optimism> as OpEngineApi<
+                Optimism,
+                Http<HyperAuthClient>,
+            >>::new_payload_v4(
+                engine, payload, root
+            )
+            .await,
+            Self::ForkchoiceUpdatedV2(state) => <RootProvider<Optimism> as OpEngineApi<
+                Optimism,
+                Http<HyperAuthClient>,
+            >>::fork_choice_updated_v2(
+                engine, state, None
+            )
+            .await
+            .map(|updated| updated.payload_status),
+            Self::ForkchoiceUpdatedV3(state) => <RootProvider<Optimism> as OpEngineApi<
+                Optimism,
+                Http<HyperAuthClient>,
+            >>::fork_choice_updated_v3(
+                engine, state, None
+            )
+            .await
+            .map(|updated| updated.payload_status),
+        }
+    }
+}
+
+/// A divergence between the primary and the shadow execution layers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowDivergence {
+    /// The Engine API method that diverged.
+    pub method: &'static str,
+    /// The [`PayloadStatus`] returned by the primary execution layer.
+    pub primary: PayloadStatus,
+    /// The [`PayloadStatus`] returned by the shadow execution layer.
+    pub shadow: PayloadStatus,
+}
+
+impl ShadowDivergence {
+    /// Compares the [`PayloadStatus`]es returned by the primary and the shadow execution layers.
+    ///
+    /// Only conclusive statuses are compared: a shadow that is still syncing, or that only
+    /// accepted the payload, is lagging behind rather than diverging. Returns a
+    /// [`ShadowDivergence`] if one execution layer considers the payload valid and the other
+    /// invalid, or if both consider it valid but report different latest valid block hashes.
+    pub fn check(
+        method: &'static str,
+        primary: &PayloadStatus,
+        shadow: &PayloadStatus,
+    ) -> Option<Self> {
+        let conclusive = |s: &PayloadStatusEnum| {
+            matches!(s, PayloadStatusEnum::Valid | PayloadStatusEnum::Invalid { .. })
+        };
+        if !conclusive(&primary.status) || !conclusive(&shadow.status) {
+            return None;
+        }
+
+        let diverged = primary.status.is_valid() != shadow.status.is_valid() ||
+            (primary.status.is_valid() && primary.latest_valid_hash != shadow.latest_valid_hash);
+        diverged.then(|| Self { method, primary: primary.clone(), shadow: shadow.clone() })
+    }
+}
+
+/// A request for the shadow execution layer, paired with the primary execution layer's response.
+#[derive(Debug)]
+struct ShadowRequest {
+    call: ShadowCall,
+    primary: PayloadStatus,
+}
+
+/// A shadow execution layer.
+///
+/// The shadow receives the same `engine_newPayload` calls as the primary execution layer, after
+/// the primary has responded, along with the `engine_forkchoiceUpdated` calls that carry no
+/// payload attributes. Block building is not mirrored, as the shadow would build payloads that are
+/// never fetched. Its responses are never used to drive the node: they are only compared with the
+/// primary's responses, and any [`ShadowDivergence`] is reported through logs and metrics. This
+/// allows validating a new execution client release against production traffic without switching
+/// over to it.
+///
+/// Calls are forwarded to the shadow in order by a background task so that a slow shadow never
+/// delays the primary execution layer. At most [`SHADOW_QUEUE_CAPACITY`] calls are queued; calls
+/// made while the queue is full are dropped and counted.
+#[derive(Debug, Clone)]
+pub struct ShadowEngine {
+    sender: mpsc::Sender<ShadowRequest>,
+}
+
+impl ShadowEngine {
+    /// Spawns the background task that forwards calls to the given shadow Engine API provider.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    pub fn spawn(engine: RootProvider<Optimism>) -> Self {
+        let (sender, receiver) = mpsc::channel(SHADOW_QUEUE_CAPACITY);
+        tokio::spawn(Self::run(engine, receiver));
+        Self { sender }
+    }
+
+    /// Mirrors a call that the primary execution layer answered with `primary`.
+    pub(crate) fn mirror(&self, call: ShadowCall, primary: &PayloadStatus) {
+        let request = ShadowRequest { call, primary: primary.clone() };
+        match self.sender.try_send(request) {
+            Ok(()) => {}
+            Err(TrySendError::Full(request)) => {
+                let method = request.call.method();
+                debug!(target: "engine::shadow", method, "Shadow engine queue is full, dropping call");
+                kona_macros::inc!(counter, Metrics::ENGINE_SHADOW_DROPPED, "method" => method);
+            }
+            Err(TrySendError::Closed(_)) => {
+                warn!(target: "engine::shadow", "Shadow engine task has stopped, dropping call");
+            }
+        }
+    }
+
+    async fn run(engine: RootProvider<Optimism>, mut receiver: mpsc::Receiver<ShadowRequest>) {
+        while let Some(ShadowRequest { call, primary }) = receiver.recv().await {
+            let method = call.method();
+            let shadow = match call.execute(&engine).await {
+                Ok(shadow) => shadow,
+                Err(err) => {
+                    warn!(target: "engine::shadow", method, ?err, "Shadow engine call failed");
+                    kona_macros::inc!(counter, Metrics::ENGINE_SHADOW_ERROR, "method" => method);
+                    continue;
+                }
+            };
+
+            match ShadowDivergence::check(method, &primary, &shadow) {
+                Some(divergence) => {
+                    error!(
+                        target: "engine::shadow",
+                        method,
+                        primary_status = %divergence.primary.status,
+                        primary_hash = ?divergence.primary.latest_valid_hash,
+                        shadow_status = %divergence.shadow.status,
+                        shadow_hash = ?divergence.shadow.latest_valid_hash,
+                        "Shadow engine diverged from the primary engine"
+                    );
+                    kona_macros::inc!(counter, Metrics::ENGINE_SHADOW_DIVERGENCE, "method" => method);
+                }
+                None => {
+                    trace!(target: "engine::shadow", method, status = %shadow.status, "Shadow engine call matched");
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(status: PayloadStatusEnum, hash: Option<B256>) -> PayloadStatus {
+        PayloadStatus { status, latest_valid_hash: hash }
+    }
+
+    #[test]
+    fn test_shadow_matching_statuses() {
+        let valid = status(PayloadStatusEnum::Valid, Some(B256::with_last_byte(1)));
+        assert_eq!(ShadowDivergence::check(Metrics::NEW_PAYLOAD_METHOD, &valid, &valid), None);
+    }
+
+    #[test]
+    fn test_shadow_syncing_is_not_a_divergence() {
+        let valid = status(PayloadStatusEnum::Valid, Some(B256::with_last_byte(1)));
+        let syncing = status(PayloadStatusEnum::Syncing, None);
+        assert_eq!(ShadowDivergence::check(Metrics::NEW_PAYLOAD_METHOD, &valid, &syncing), None);
+        assert_eq!(ShadowDivergence::check(Metrics::NEW_PAYLOAD_METHOD, &syncing, &valid), None);
+    }
+
+    #[test]
+    fn test_shadow_validity_divergence() {
+        let valid = status(PayloadStatusEnum::Valid, Some(B256::with_last_byte(1)));
+        let invalid = status(
+            PayloadStatusEnum::Invalid { validation_error: "bad state root".to_string() },
+            Some(B256::ZERO),
+        );
+        let divergence =
+            ShadowDivergence::check(Metrics::NEW_PAYLOAD_METHOD, &valid, &invalid).unwrap();
+        assert_eq!(divergence.primary, valid);
+        assert_eq!(divergence.shadow, invalid);
+    }
+
+    #[test]
+    fn test_shadow_block_hash_divergence() {
+        let primary = status(PayloadStatusEnum::Valid, Some(B256::with_last_byte(1)));
+        let shadow = status(PayloadStatusEnum::Valid, Some(B256::with_last_byte(2)));
+        assert!(
+            ShadowDivergence::check(Metrics::FORKCHOICE_UPDATE_METHOD, &primary, &shadow).is_some()
+        );
+    }
+
+    #[test]
+    fn test_shadow_drops_calls_when_full() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let shadow = ShadowEngine { sender };
+        let primary = status(PayloadStatusEnum::Valid, Some(B256::with_last_byte(1)));
+        let call = ShadowCall::ForkchoiceUpdatedV2(ForkchoiceState::default());
+
+        shadow.mirror(call.clone(), &primary);
+        shadow.mirror(call, &primary);
+
+        assert_eq!(receiver.try_recv().unwrap().primary, primary);
+        assert!(receiver.try_recv().is_err());
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::Metrics;
 use alloy_rpc_types_engine::ForkchoiceState;
-use kona_protocol::L2BlockInfo;
+use kona_protocol::{ElSyncStatus, L2BlockInfo};
 use serde::{Deserialize, Serialize};
 
 /// The synchronization state of the execution layer across different safety levels.
@@ -149,8 +149,8 @@ pub struct EngineState {
     /// The sync state of the engine.
     pub sync_state: EngineSyncState,
 
-    /// Whether or not the EL has finished syncing.
-    pub el_sync_finished: bool,
+    /// The progress of the execution layer sync.
+    pub el_sync_status: ElSyncStatus,
 
     /// Track when the rollup node changes the forkchoice to restore previous
     /// known unsafe chain. e.g. Unsafe Reorg caused by Invalid span batch.
@@ -161,6 +161,11 @@ pub struct EngineState {
 }
 
 impl EngineState {
+    /// Returns whether or not the EL has finished syncing.
+    pub const fn el_sync_finished(&self) -> bool {
+        self.el_sync_status.is_finished()
+    }
+
     /// Returns if consolidation is needed.
     ///
     /// [Consolidation] is only performed by a rollup node when the unsafe head
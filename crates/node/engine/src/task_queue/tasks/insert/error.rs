@@ -3,7 +3,8 @@
 //! [InsertTask]: crate::InsertTask
 
 use crate::{
-    EngineTaskError, SynchronizeTaskError, task_queue::tasks::task::EngineTaskErrorSeverity,
+    EngineClientError, EngineTaskError, SynchronizeTaskError,
+    task_queue::tasks::task::EngineTaskErrorSeverity,
 };
 use alloy_rpc_types_engine::PayloadStatusEnum;
 use alloy_transport::{RpcError, TransportErrorKind};
@@ -33,6 +34,9 @@ pub enum InsertTaskError {
     /// The forkchoice update call to consolidate the block into the engine state failed.
     #[error(transparent)]
     ForkchoiceUpdateFailed(#[from] SynchronizeTaskError),
+    /// Failed to fetch the finalized block prior to starting the execution layer sync.
+    #[error("Failed to fetch the finalized block: {0}")]
+    FinalizedBlockFetchFailed(EngineClientError),
 }
 
 impl EngineTaskError for InsertTaskError {
@@ -44,6 +48,7 @@ impl EngineTaskError for InsertTaskError {
             Self::L2BlockInfoConstruction(_) => EngineTaskErrorSeverity::Critical,
             Self::InconsistentForkchoiceState => EngineTaskErrorSeverity::Reset,
             Self::ForkchoiceUpdateFailed(inner) => inner.severity(),
+            Self::FinalizedBlockFetchFailed(_) => EngineTaskErrorSeverity::Temporary,
         }
     }
 }
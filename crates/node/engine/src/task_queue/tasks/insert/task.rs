@@ -4,14 +4,14 @@ use crate::{
     EngineClient, EngineState, EngineTaskExt, InsertTaskError, SynchronizeTask,
     state::EngineSyncStateUpdate,
 };
-use alloy_eips::eip7685::EMPTY_REQUESTS_HASH;
+use alloy_eips::{BlockNumberOrTag, eip7685::EMPTY_REQUESTS_HASH};
 use alloy_provider::ext::EngineApi;
 use alloy_rpc_types_engine::{
     CancunPayloadFields, ExecutionPayloadInputV2, PayloadStatusEnum, PraguePayloadFields,
 };
 use async_trait::async_trait;
 use kona_genesis::RollupConfig;
-use kona_protocol::L2BlockInfo;
+use kona_protocol::{ElSyncStatus, L2BlockInfo};
 use op_alloy_consensus::OpBlock;
 use op_alloy_provider::ext::engine::OpEngineApi;
 use op_alloy_rpc_types_engine::{
@@ -44,9 +44,63 @@ impl InsertTask {
         Self { client, rollup_config, envelope, is_payload_safe: is_attributes_derived }
     }
 
-    /// Checks the response of the `engine_newPayload` call.
-    const fn check_new_payload_status(&self, status: &PayloadStatusEnum) -> bool {
-        matches!(status, PayloadStatusEnum::Valid | PayloadStatusEnum::Syncing)
+    /// Checks the response of the `engine_newPayload` call, and updates the sync status if
+    /// necessary.
+    ///
+    /// In execution-layer sync mode, `SYNCING` and `ACCEPTED` responses are allowed, and a `VALID`
+    /// response marks the end of the execution layer sync. In consensus-layer sync mode, only
+    /// `VALID` responses are allowed.
+    fn check_new_payload_status(
+        &self,
+        state: &mut EngineState,
+        status: &PayloadStatusEnum,
+    ) -> bool {
+        if state.el_sync_status == ElSyncStatus::ConsensusLayer {
+            return matches!(status, PayloadStatusEnum::Valid);
+        }
+
+        if matches!(status, PayloadStatusEnum::Valid) &&
+            state.el_sync_status == ElSyncStatus::Started
+        {
+            state.el_sync_status = ElSyncStatus::FinishedNotFinalized;
+        }
+        matches!(
+            status,
+            PayloadStatusEnum::Valid | PayloadStatusEnum::Syncing | PayloadStatusEnum::Accepted
+        )
+    }
+
+    /// Decides whether or not to start the execution layer sync, upon receipt of the first unsafe
+    /// payload. Returns `true` if the execution layer sync was skipped.
+    ///
+    /// The execution layer sync is skipped if the execution layer already has a finalized block
+    /// past genesis, in which case the node derives the rest of the chain from L1.
+    async fn maybe_start_el_sync(&self, state: &mut EngineState) -> Result<bool, InsertTaskError> {
+        if state.el_sync_status != ElSyncStatus::WillStart {
+            return Ok(false);
+        }
+
+        let finalized = self
+            .client
+            .l2_block_info_by_label(BlockNumberOrTag::Finalized)
+            .await
+            .map_err(InsertTaskError::FinalizedBlockFetchFailed)?;
+        match finalized {
+            Some(finalized) if finalized.block_info.hash != self.rollup_config.genesis.l2.hash => {
+                info!(
+                    target: "engine",
+                    finalized = finalized.block_info.number,
+                    "Skipping EL sync and going straight to CL sync because there is a finalized block"
+                );
+                state.el_sync_status = ElSyncStatus::Finished;
+                Ok(true)
+            }
+            _ => {
+                info!(target: "engine", "Starting EL sync");
+                state.el_sync_status = ElSyncStatus::Started;
+                Ok(false)
+            }
+        }
     }
 }
 
@@ -59,6 +113,12 @@ impl EngineTaskExt for InsertTask {
     async fn execute(&self, state: &mut EngineState) -> Result<(), InsertTaskError> {
         let time_start = Instant::now();
 
+        // Check if the execution layer sync should be started. If it is skipped, the payload is
+        // dropped, and the chain is derived from L1 instead.
+        if self.maybe_start_el_sync(state).await? {
+            return Ok(());
+        }
+
         // Insert the new payload.
         // Form the new unsafe block ref from the execution payload.
         let parent_beacon_block_root = self.envelope.parent_beacon_block_root.unwrap_or_default();
@@ -114,7 +174,7 @@ impl EngineTaskExt for InsertTask {
             Ok(resp) => resp,
             Err(e) => return Err(InsertTaskError::InsertFailed(e)),
         };
-        if !self.check_new_payload_status(&response.status) {
+        if !self.check_new_payload_status(state, &response.status) {
             return Err(InsertTaskError::UnexpectedPayloadStatus(response.status));
         }
         let insert_duration = insert_time_start.elapsed();
@@ -123,6 +183,11 @@ impl EngineTaskExt for InsertTask {
             L2BlockInfo::from_block_and_genesis(&block, &self.rollup_config.genesis)
                 .map_err(InsertTaskError::L2BlockInfoConstruction)?;
 
+        // If the execution layer just finished syncing, the new block is checkpointed as the safe
+        // and finalized head, so that derivation can start from it.
+        let checkpoint = state.el_sync_status == ElSyncStatus::FinishedNotFinalized;
+        let is_payload_safe = self.is_payload_safe || checkpoint;
+
         // Send a FCU to canonicalize the imported block.
         SynchronizeTask::new(
             Arc::clone(&self.client),
@@ -130,14 +195,23 @@ impl EngineTaskExt for InsertTask {
             EngineSyncStateUpdate {
                 cross_unsafe_head: Some(new_unsafe_ref),
                 unsafe_head: Some(new_unsafe_ref),
-                local_safe_head: self.is_payload_safe.then_some(new_unsafe_ref),
-                safe_head: self.is_payload_safe.then_some(new_unsafe_ref),
-                ..Default::default()
+                local_safe_head: is_payload_safe.then_some(new_unsafe_ref),
+                safe_head: is_payload_safe.then_some(new_unsafe_ref),
+                finalized_head: checkpoint.then_some(new_unsafe_ref),
             },
         )
         .execute(state)
         .await?;
 
+        if checkpoint {
+            info!(
+                target: "engine",
+                finalized = new_unsafe_ref.block_info.number,
+                "Finished EL sync"
+            );
+            state.el_sync_status = ElSyncStatus::Finished;
+        }
+
         let total_duration = time_start.elapsed();
 
         info!(
@@ -152,3 +226,159 @@ impl EngineTaskExt for InsertTask {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Block, BlockBody, Header, transaction::Recovered};
+    use alloy_primitives::{B256, Sealable, Sealed, keccak256};
+    use alloy_rpc_types_engine::{ExecutionPayloadV1, ForkchoiceUpdated, PayloadStatus};
+    use alloy_rpc_types_eth::{BlockTransactions, Header as RpcHeader};
+    use alloy_transport::mock::Asserter;
+    use kona_protocol::{L1BlockInfoBedrock, L1BlockInfoTx};
+    use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
+
+    /// Returns the L2 genesis block.
+    fn genesis() -> OpBlock {
+        Block { header: Header::default(), body: BlockBody::default() }
+    }
+
+    /// Returns the L2 block following [`genesis`], which starts with an L1 info deposit.
+    fn block_one() -> OpBlock {
+        let l1_info = L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+            block_hash: keccak256([1u8; 8]),
+            ..Default::default()
+        });
+        let deposit = TxDeposit { input: l1_info.encode_calldata(), ..Default::default() };
+        Block {
+            header: Header {
+                number: 1,
+                parent_hash: genesis().header.hash_slow(),
+                timestamp: 2,
+                ..Default::default()
+            },
+            body: BlockBody {
+                transactions: vec![OpTxEnvelope::Deposit(Sealed::new(deposit))],
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Converts a block into the response of an `eth_getBlockByNumber` call.
+    fn rpc_block(block: &OpBlock) -> alloy_rpc_types_eth::Block<op_alloy_rpc_types::Transaction> {
+        let hash = block.header.hash_slow();
+        let transactions = block
+            .body
+            .transactions
+            .iter()
+            .enumerate()
+            .map(|(index, tx)| op_alloy_rpc_types::Transaction {
+                inner: alloy_rpc_types_eth::Transaction {
+                    inner: Recovered::new_unchecked(tx.clone(), Default::default()),
+                    block_hash: Some(hash),
+                    block_number: Some(block.header.number),
+                    transaction_index: Some(index as u64),
+                    effective_gas_price: Some(0),
+                },
+                deposit_nonce: None,
+                deposit_receipt_version: None,
+            })
+            .collect();
+
+        alloy_rpc_types_eth::Block {
+            header: RpcHeader::from_consensus(block.header.clone().seal_slow(), None, None),
+            uncles: vec![],
+            transactions: BlockTransactions::Full(transactions),
+            withdrawals: None,
+        }
+    }
+
+    fn rollup_config() -> Arc<RollupConfig> {
+        let mut cfg = RollupConfig::default();
+        cfg.genesis.l2.hash = genesis().header.hash_slow();
+        Arc::new(cfg)
+    }
+
+    fn payload() -> OpExecutionPayload {
+        OpExecutionPayload::V1(ExecutionPayloadV1::from_block_slow(&block_one()))
+    }
+
+    fn task(asserter: Asserter) -> InsertTask {
+        let cfg = rollup_config();
+        InsertTask::new(
+            Arc::new(EngineClient::mocked(asserter, Arc::clone(&cfg))),
+            cfg,
+            OpExecutionPayloadEnvelope {
+                parent_beacon_block_root: None,
+                execution_payload: payload(),
+            },
+            false,
+        )
+    }
+
+    fn block_one_info() -> L2BlockInfo {
+        L2BlockInfo::from_payload_and_genesis(payload(), None, &rollup_config().genesis).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_el_sync_starts_without_finalized_block() {
+        let asserter = Asserter::new();
+        asserter.push_success(&rpc_block(&genesis()));
+        asserter.push_success(&PayloadStatus::from_status(PayloadStatusEnum::Syncing));
+        asserter.push_success(&ForkchoiceUpdated::from_status(PayloadStatusEnum::Syncing));
+
+        let mut state = EngineState::default();
+        task(asserter).execute(&mut state).await.unwrap();
+
+        assert_eq!(state.el_sync_status, ElSyncStatus::Started);
+        assert_eq!(state.sync_state.unsafe_head(), block_one_info());
+        assert_eq!(state.sync_state.safe_head(), L2BlockInfo::default());
+        assert_eq!(state.sync_state.finalized_head(), L2BlockInfo::default());
+    }
+
+    #[tokio::test]
+    async fn test_el_sync_skipped_with_finalized_block() {
+        let asserter = Asserter::new();
+        asserter.push_success(&rpc_block(&block_one()));
+
+        let mut state = EngineState::default();
+        task(asserter).execute(&mut state).await.unwrap();
+
+        // The payload is dropped, and the chain is derived from L1 instead.
+        assert_eq!(state.el_sync_status, ElSyncStatus::Finished);
+        assert_eq!(state.sync_state, Default::default());
+    }
+
+    #[tokio::test]
+    async fn test_el_sync_finishes_on_valid_payload() {
+        let asserter = Asserter::new();
+        asserter.push_success(&PayloadStatus::from_status(PayloadStatusEnum::Valid));
+        asserter.push_success(&ForkchoiceUpdated::from_status(PayloadStatusEnum::Valid));
+
+        let mut state = EngineState { el_sync_status: ElSyncStatus::Started, ..Default::default() };
+        task(asserter).execute(&mut state).await.unwrap();
+
+        // The first valid payload is checkpointed as the safe and finalized head.
+        let head = block_one_info();
+        assert_eq!(state.el_sync_status, ElSyncStatus::Finished);
+        assert_eq!(state.sync_state.unsafe_head(), head);
+        assert_eq!(state.sync_state.safe_head(), head);
+        assert_eq!(state.sync_state.finalized_head(), head);
+    }
+
+    #[tokio::test]
+    async fn test_consensus_layer_sync_rejects_syncing_payload() {
+        let asserter = Asserter::new();
+        asserter.push_success(&PayloadStatus::from_status(PayloadStatusEnum::Syncing));
+
+        let mut state =
+            EngineState { el_sync_status: ElSyncStatus::ConsensusLayer, ..Default::default() };
+        let err = task(asserter).execute(&mut state).await.unwrap_err();
+
+        assert!(matches!(
+            err,
+            InsertTaskError::UnexpectedPayloadStatus(PayloadStatusEnum::Syncing)
+        ));
+        assert_eq!(state.sync_state, Default::default());
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
 use alloy_rpc_types_engine::{INVALID_FORK_CHOICE_STATE_ERROR, PayloadStatusEnum};
 use async_trait::async_trait;
 use kona_genesis::RollupConfig;
+use kona_protocol::ElSyncStatus;
 use op_alloy_provider::ext::engine::OpEngineApi;
 use std::sync::Arc;
 use tokio::time::Instant;
@@ -56,6 +57,10 @@ impl SynchronizeTask {
 
     /// Checks the response of the `engine_forkchoiceUpdated` call, and updates the sync status if
     /// necessary.
+    ///
+    /// When the execution layer is syncing, `SYNCING` responses are accepted and a `VALID`
+    /// response marks the end of the execution layer sync. Otherwise, only `VALID` responses are
+    /// accepted.
     fn check_forkchoice_updated_status(
         &self,
         state: &mut EngineState,
@@ -63,17 +68,17 @@ impl SynchronizeTask {
     ) -> Result<(), SynchronizeTaskError> {
         match status {
             PayloadStatusEnum::Valid => {
-                if !state.el_sync_finished {
+                if state.el_sync_status == ElSyncStatus::Started {
                     info!(
                         target: "engine",
-                        "Finished execution layer sync."
+                        "Finished execution layer sync, awaiting finalized checkpoint."
                     );
-                    state.el_sync_finished = true;
+                    state.el_sync_status = ElSyncStatus::FinishedNotFinalized;
                 }
 
                 Ok(())
             }
-            PayloadStatusEnum::Syncing => {
+            PayloadStatusEnum::Syncing if state.el_sync_status != ElSyncStatus::ConsensusLayer => {
                 // If we're not building a new payload, we're driving EL sync.
                 debug!(target: "engine", "Attempting to update forkchoice state while EL syncing");
                 Ok(())
@@ -158,3 +163,73 @@ impl EngineTaskExt for SynchronizeTask {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use alloy_rpc_types_engine::ForkchoiceUpdated;
+    use alloy_transport::mock::Asserter;
+    use kona_protocol::{BlockInfo, L2BlockInfo};
+
+    fn task(asserter: Asserter, unsafe_head: L2BlockInfo) -> SynchronizeTask {
+        let rollup = Arc::new(RollupConfig::default());
+        SynchronizeTask::new(
+            Arc::new(EngineClient::mocked(asserter, Arc::clone(&rollup))),
+            rollup,
+            EngineSyncStateUpdate { unsafe_head: Some(unsafe_head), ..Default::default() },
+        )
+    }
+
+    fn block(number: u64) -> L2BlockInfo {
+        L2BlockInfo {
+            block_info: BlockInfo {
+                number,
+                hash: B256::with_last_byte(number as u8),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_valid_forkchoice_finishes_el_sync() {
+        let asserter = Asserter::new();
+        asserter.push_success(&ForkchoiceUpdated::from_status(PayloadStatusEnum::Valid));
+
+        let mut state = EngineState { el_sync_status: ElSyncStatus::Started, ..Default::default() };
+        task(asserter, block(1)).execute(&mut state).await.unwrap();
+
+        assert_eq!(state.el_sync_status, ElSyncStatus::FinishedNotFinalized);
+        assert_eq!(state.sync_state.unsafe_head(), block(1));
+    }
+
+    #[tokio::test]
+    async fn test_syncing_forkchoice_during_el_sync() {
+        let asserter = Asserter::new();
+        asserter.push_success(&ForkchoiceUpdated::from_status(PayloadStatusEnum::Syncing));
+
+        let mut state = EngineState { el_sync_status: ElSyncStatus::Started, ..Default::default() };
+        task(asserter, block(1)).execute(&mut state).await.unwrap();
+
+        assert_eq!(state.el_sync_status, ElSyncStatus::Started);
+        assert_eq!(state.sync_state.unsafe_head(), block(1));
+    }
+
+    #[tokio::test]
+    async fn test_syncing_forkchoice_in_consensus_layer_sync() {
+        let asserter = Asserter::new();
+        asserter.push_success(&ForkchoiceUpdated::from_status(PayloadStatusEnum::Syncing));
+
+        let mut state =
+            EngineState { el_sync_status: ElSyncStatus::ConsensusLayer, ..Default::default() };
+        let err = task(asserter, block(1)).execute(&mut state).await.unwrap_err();
+
+        assert!(matches!(
+            err,
+            SynchronizeTaskError::UnexpectedPayloadStatus(PayloadStatusEnum::Syncing)
+        ));
+        assert_eq!(state.el_sync_status, ElSyncStatus::ConsensusLayer);
+        assert_eq!(state.sync_state, Default::default());
+    }
+}
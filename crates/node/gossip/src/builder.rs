@@ -2,10 +2,18 @@
 
 use alloy_primitives::Address;
 use kona_genesis::RollupConfig;
-use kona_peers::{PeerMonitoring, PeerScoreLevel};
+use kona_peers::{PeerDbFile, PeerMonitoring, PeerScoreLevel};
 use libp2p::{
-    Multiaddr, StreamProtocol, SwarmBuilder, gossipsub::Config, identity::Keypair,
-    noise::Config as NoiseConfig, tcp::Config as TcpConfig, yamux::Config as YamuxConfig,
+    Multiaddr, StreamProtocol, SwarmBuilder, Transport,
+    core::{
+        transport::{MemoryTransport, OptionalTransport},
+        upgrade::Version,
+    },
+    gossipsub::Config,
+    identity::Keypair,
+    noise::Config as NoiseConfig,
+    tcp::Config as TcpConfig,
+    yamux::Config as YamuxConfig,
 };
 use std::time::Duration;
 use tokio::sync::watch::{self};
@@ -36,6 +44,10 @@ pub struct GossipDriverBuilder {
     gater_config: Option<GaterConfig>,
     /// Topic scoring. Disabled by default.
     topic_scoring: bool,
+    /// An optional path to the peer database.
+    peer_db: Option<PeerDbFile>,
+    /// Whether to add libp2p's in-memory transport to the swarm. Disabled by default.
+    memory_transport: bool,
 }
 
 impl GossipDriverBuilder {
@@ -57,9 +69,28 @@ impl GossipDriverBuilder {
             gater_config: None,
             rollup_config,
             topic_scoring: false,
+            peer_db: None,
+            memory_transport: false,
         }
     }
 
+    /// Adds libp2p's in-memory transport to the swarm, and lets the connection gater dial
+    /// `/memory/<port>` addresses.
+    ///
+    /// This allows running several nodes in a single process without binding sockets, e.g. in
+    /// tests. It must not be enabled in production: in-memory addresses bypass the gater's IP
+    /// address and subnet checks.
+    pub const fn with_memory_transport(mut self, memory_transport: bool) -> Self {
+        self.memory_transport = memory_transport;
+        self
+    }
+
+    /// Sets the file used to persist the peer database.
+    pub fn with_peer_db(mut self, peer_db: Option<PeerDbFile>) -> Self {
+        self.peer_db = peer_db;
+        self
+    }
+
     /// Sets the configuration for the connection gater.
     pub const fn with_gater_config(mut self, config: GaterConfig) -> Self {
         self.gater_config = Some(config);
@@ -137,6 +168,7 @@ impl GossipDriverBuilder {
         let rollup_config = self.rollup_config;
         let l2_chain_id = rollup_config.l2_chain_id;
         let block_time = rollup_config.block_time;
+        let memory_transport = self.memory_transport;
 
         let (signer_tx, signer_rx) = watch::channel(signer_recv);
 
@@ -205,14 +237,39 @@ impl GossipDriverBuilder {
                 YamuxConfig::default,
             )
             .map_err(|_| GossipDriverBuilderError::TcpError)?
+            // The in-memory transport only serves `/memory/<port>` addresses, and is only added
+            // when explicitly enabled.
+            .with_other_transport(|i: &Keypair| {
+                let memory = if memory_transport {
+                    OptionalTransport::some(MemoryTransport::default())
+                } else {
+                    OptionalTransport::none()
+                };
+                Ok::<_, Box<dyn std::error::Error + Send + Sync>>(
+                    memory
+                        .upgrade(Version::V1)
+                        .authenticate(NoiseConfig::new(i)?)
+                        .multiplex(YamuxConfig::default()),
+                )
+            })
+            .map_err(|_| GossipDriverBuilderError::MemoryTransportError)?
             .with_behaviour(|_| behaviour)
             .map_err(|_| GossipDriverBuilderError::WithBehaviourError)?
             .with_swarm_config(|c| c.with_idle_connection_timeout(timeout))
             .build();
 
         let gater_config = self.gater_config.take().unwrap_or_default();
-        let gate = crate::ConnectionGater::new(gater_config);
+        let mut gate = crate::ConnectionGater::new(gater_config);
+        gate.allow_memory_addrs = memory_transport;
+
+        let mut driver = GossipDriver::new(swarm, addr, handler, sync_handler, sync_protocol, gate);
+
+        if let Some(file) = self.peer_db {
+            driver.peer_db = file.try_into().map_err(|e: std::io::Error| {
+                GossipDriverBuilderError::PeerDbOpenFailed(e.to_string())
+            })?;
+        }
 
-        Ok((GossipDriver::new(swarm, addr, handler, sync_handler, sync_protocol, gate), signer_tx))
+        Ok((driver, signer_tx))
     }
 }
@@ -5,7 +5,7 @@ use derive_more::Debug;
 use discv5::Enr;
 use futures::{AsyncReadExt, AsyncWriteExt, stream::StreamExt};
 use kona_genesis::RollupConfig;
-use kona_peers::{EnrValidation, PeerMonitoring, enr_to_multiaddr};
+use kona_peers::{EnrValidation, PeerDb, PeerMonitoring, enr_to_multiaddr};
 use libp2p::{
     Multiaddr, PeerId, Swarm, TransportError,
     gossipsub::{IdentTopic, MessageId},
@@ -19,7 +19,8 @@ use std::{
     sync::Arc,
     time::{Duration, Instant},
 };
-use tokio::sync::Mutex;
+use tokio::{sync::Mutex, task::JoinHandle};
+use tokio_util::time::DelayQueue;
 
 use crate::{
     Behaviour, BlockHandler, ConnectionGate, ConnectionGater, Event, GossipDriverBuilder, Handler,
@@ -61,6 +62,18 @@ pub struct GossipDriver<G: ConnectionGate> {
     pub connection_gate: G,
     /// Tracks ping times for peers.
     pub ping: Arc<Mutex<HashMap<PeerId, Duration>>>,
+    /// The persistent peer database.
+    ///
+    /// Bans and blocked addresses recorded in the database are restored into the connection
+    /// gate when the driver starts.
+    pub peer_db: PeerDb,
+    /// Whether the [`PeerDb`] changed since it was last written to disk.
+    pub peer_db_dirty: bool,
+    /// The in-flight write of the [`PeerDb`] to disk, if any.
+    pub peer_db_write: Option<JoinHandle<()>>,
+    /// The deadlines at which temporary peer bans expire.
+    #[debug(skip)]
+    pub ban_expiries: DelayQueue<PeerId>,
 }
 
 impl<G> GossipDriver<G>
@@ -97,6 +110,10 @@ where
             sync_protocol: Some(sync_protocol),
             connection_gate: gate,
             ping: Arc::new(Mutex::new(Default::default())),
+            peer_db: Default::default(),
+            peer_db_dirty: false,
+            peer_db_write: None,
+            ban_expiries: DelayQueue::new(),
         }
     }
 
@@ -189,6 +206,9 @@ where
         // Start the sync request/response protocol handler.
         self.sync_protocol_handler();
 
+        // Restore the bans persisted in the peer database.
+        self.restore_peer_db();
+
         match self.swarm.listen_on(self.addr.clone()) {
             Ok(id) => loop {
                 if let SwarmEvent::NewListenAddr { address, listener_id } =
@@ -210,6 +230,139 @@ where
         }
     }
 
+    /// Restores the bans and blocked addresses persisted in the [`PeerDb`] into the connection
+    /// gate and the gossipsub blacklist.
+    pub fn restore_peer_db(&mut self) {
+        let banned = self.peer_db.banned_peers();
+        for (peer_id, _) in &banned {
+            self.connection_gate.block_peer(peer_id);
+            self.swarm.behaviour_mut().gossipsub.blacklist_peer(peer_id);
+            if let Some(remaining) = self.peer_db.ban_remaining(peer_id) {
+                self.ban_expiries.insert(*peer_id, remaining);
+            }
+        }
+        for addr in &self.peer_db.blocked_addrs {
+            self.connection_gate.block_addr(*addr);
+        }
+        for subnet in &self.peer_db.blocked_subnets {
+            self.connection_gate.block_subnet(*subnet);
+        }
+        info!(
+            target: "gossip",
+            peers = self.peer_db.len(),
+            banned = banned.len(),
+            blocked_addrs = self.peer_db.blocked_addrs.len(),
+            blocked_subnets = self.peer_db.blocked_subnets.len(),
+            "Restored peer database"
+        );
+    }
+
+    /// Bans a peer, persisting the ban in the [`PeerDb`].
+    ///
+    /// A `None` duration bans the peer until it is explicitly unbanned. Otherwise, the ban is
+    /// lifted once the duration elapses.
+    pub fn ban_peer(&mut self, peer_id: PeerId, duration: Option<Duration>) {
+        self.connection_gate.block_peer(&peer_id);
+        self.swarm.behaviour_mut().gossipsub.blacklist_peer(&peer_id);
+        if let Some(score) = self.swarm.behaviour().gossipsub.peer_score(&peer_id) {
+            self.peer_db.record_score(peer_id, score);
+        }
+        self.peer_db.ban(peer_id, duration);
+        if let Some(duration) = duration {
+            self.ban_expiries.insert(peer_id, duration);
+        }
+        self.persist_peer_db();
+    }
+
+    /// Lifts a ban on a peer, removing it from the [`PeerDb`].
+    pub fn unban_peer(&mut self, peer_id: &PeerId) {
+        self.connection_gate.unblock_peer(peer_id);
+        self.swarm.behaviour_mut().gossipsub.remove_blacklisted_peer(peer_id);
+        self.peer_db.unban(peer_id);
+        self.persist_peer_db();
+    }
+
+    /// Lifts the ban on a peer once its deadline has passed.
+    ///
+    /// The peer is left banned if its ban was extended or made permanent in the meantime.
+    fn expire_ban(&mut self, peer_id: PeerId) {
+        if self.peer_db.is_banned(&peer_id) {
+            return;
+        }
+        debug!(target: "gossip", ?peer_id, "Peer ban expired");
+        self.unban_peer(&peer_id);
+    }
+
+    /// Marks the [`PeerDb`] as changed, and writes it to disk unless a write is already in
+    /// flight.
+    ///
+    /// Called whenever a ban or block changes so that it survives a crash. Changes made while a
+    /// write is in flight are picked up by the next call to [`Self::flush_peer_db`].
+    pub fn persist_peer_db(&mut self) {
+        self.peer_db_dirty = true;
+        self.flush_peer_db();
+    }
+
+    /// Writes the [`PeerDb`] to disk if it changed since the last write.
+    ///
+    /// The write happens on a blocking thread so that the file I/O does not stall the swarm. At
+    /// most one write is in flight at a time; if one is, the database is left marked as changed.
+    pub fn flush_peer_db(&mut self) {
+        if !self.peer_db_dirty || self.peer_db_write.as_ref().is_some_and(|w| !w.is_finished()) {
+            return;
+        }
+
+        match self.peer_db.snapshot() {
+            Ok(snapshot) => {
+                self.peer_db_dirty = false;
+                self.peer_db_write = snapshot.map(|snapshot| {
+                    tokio::task::spawn_blocking(move || {
+                        if let Err(e) = snapshot.write() {
+                            warn!(target: "gossip", "Failed to sync peer database: {:?}", e);
+                        }
+                    })
+                });
+            }
+            Err(e) => warn!(target: "gossip", "Failed to snapshot peer database: {:?}", e),
+        }
+    }
+
+    /// Waits for the in-flight write of the [`PeerDb`], then writes any remaining changes to
+    /// disk and waits for them to be flushed. Called on shutdown.
+    pub async fn close_peer_db(&mut self) {
+        if let Some(write) = self.peer_db_write.take() {
+            _ = write.await;
+        }
+        self.flush_peer_db();
+        if let Some(write) = self.peer_db_write.take() {
+            _ = write.await;
+        }
+    }
+
+    /// Garbage collects the [`PeerDb`] and persists it to disk.
+    ///
+    /// Peers that have not been seen within `ttl` are dropped from the database. Expired bans are
+    /// normally lifted at their deadline; any that are still in the database are lifted here.
+    pub fn gc_peer_db(&mut self, ttl: Duration) {
+        // Snapshot the scores of connected peers before persisting.
+        let scores = self
+            .swarm
+            .connected_peers()
+            .filter_map(|p| self.swarm.behaviour().gossipsub.peer_score(p).map(|s| (*p, s)))
+            .collect::<Vec<_>>();
+        for (peer_id, score) in scores {
+            self.peer_db.record_score(peer_id, score);
+        }
+
+        for peer_id in self.peer_db.gc(ttl) {
+            debug!(target: "gossip", ?peer_id, "Peer ban expired");
+            self.connection_gate.unblock_peer(&peer_id);
+            self.swarm.behaviour_mut().gossipsub.remove_blacklisted_peer(&peer_id);
+        }
+
+        self.persist_peer_db();
+    }
+
     /// Returns the local peer id.
     pub fn local_peer_id(&self) -> &libp2p::PeerId {
         self.swarm.local_peer_id()
@@ -221,8 +374,17 @@ where
     }
 
     /// Attempts to select the next event from the Swarm.
+    ///
+    /// Peer bans that expire while waiting for the next event are lifted.
     pub async fn next(&mut self) -> Option<SwarmEvent<Event>> {
-        self.swarm.next().await
+        loop {
+            tokio::select! {
+                event = self.swarm.next() => return event,
+                Some(expired) = self.ban_expiries.next(), if !self.ban_expiries.is_empty() => {
+                    self.expire_ban(expired.into_inner());
+                }
+            }
+        }
     }
 
     /// Returns the number of connected peers.
@@ -331,6 +493,7 @@ where
         match event {
             libp2p::identify::Event::Received { connection_id, peer_id, info } => {
                 debug!(target: "gossip", ?connection_id, ?peer_id, ?info, "Received identify info from peer");
+                self.peer_db.record_seen(peer_id, info.listen_addrs.iter().cloned());
                 self.peerstore.insert(peer_id, info);
             }
             libp2p::identify::Event::Sent { connection_id, peer_id } => {
@@ -406,6 +569,7 @@ where
                 kona_macros::set!(gauge, crate::Metrics::GOSSIP_PEER_COUNT, peer_count as f64);
 
                 self.peer_connection_start.insert(peer_id, Instant::now());
+                self.peer_db.record_connected(peer_id);
             }
             SwarmEvent::OutgoingConnectionError { peer_id: _peer_id, error, .. } => {
                 debug!(target: "gossip", "Outgoing connection error: {:?}", error);
@@ -448,16 +612,18 @@ where
                     );
                 }
 
-                // Record the peer score in the metrics if available.
-                if let Some(_peer_score) = self.behaviour_mut().gossipsub.peer_score(&peer_id) {
+                // Record the peer score in the metrics and the peer database if available.
+                if let Some(peer_score) = self.behaviour_mut().gossipsub.peer_score(&peer_id) {
                     kona_macros::record!(
                         histogram,
                         crate::Metrics::PEER_SCORES,
                         "peer",
                         peer_id.to_string(),
-                        _peer_score
+                        peer_score
                     );
+                    self.peer_db.record_score(peer_id, peer_score);
                 }
+                self.peer_db.record_disconnected(&peer_id);
 
                 let pings = Arc::clone(&self.ping);
                 tokio::spawn(async move {
@@ -54,6 +54,9 @@ pub enum GossipDriverBuilderError {
     /// A TCP error.
     #[error("TCP error")]
     TcpError,
+    /// An error when setting up the in-memory transport.
+    #[error("in-memory transport error")]
+    MemoryTransportError,
     /// An error when setting the behaviour on the swarm builder.
     #[error("error setting behaviour on swarm builder")]
     WithBehaviourError,
@@ -66,6 +69,9 @@ pub enum GossipDriverBuilderError {
     /// The sync request/response protocol has already been accepted.
     #[error("sync request/response protocol already accepted")]
     SyncReqRespAlreadyAccepted,
+    /// Failed to open the peer database.
+    #[error("failed to open peer database: {0}")]
+    PeerDbOpenFailed(String),
 }
 
 /// An error type representing reasons why a peer cannot be dialed.
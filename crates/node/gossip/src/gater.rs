@@ -81,6 +81,12 @@ pub struct ConnectionGater {
     pub blocked_addrs: HashSet<IpAddr>,
     /// A set of blocked subnets that cannot be connected to.
     pub blocked_subnets: HashSet<IpNet>,
+    /// Whether in-memory transport addresses may be dialed.
+    ///
+    /// In-memory addresses carry no IP, so the address and subnet checks do not apply to them.
+    /// This is only enabled alongside the in-memory transport, see
+    /// [`GossipDriverBuilder::with_memory_transport`](crate::GossipDriverBuilder::with_memory_transport).
+    pub allow_memory_addrs: bool,
 }
 
 impl ConnectionGater {
@@ -95,6 +101,7 @@ impl ConnectionGater {
             blocked_peers: HashSet::new(),
             blocked_addrs: HashSet::new(),
             blocked_subnets: HashSet::new(),
+            allow_memory_addrs: false,
         }
     }
 
@@ -142,6 +149,11 @@ impl ConnectionGater {
         })
     }
 
+    /// Returns whether the [`Multiaddr`] is an in-memory transport address.
+    pub fn is_memory_addr(addr: &Multiaddr) -> bool {
+        addr.iter().any(|component| matches!(component, libp2p::multiaddr::Protocol::Memory(_)))
+    }
+
     /// Checks if a given [`IpAddr`] is within any of the `blocked_subnets`.
     pub fn check_ip_in_blocked_subnets(&self, ip_addr: &IpAddr) -> bool {
         for subnet in &self.blocked_subnets {
@@ -188,6 +200,11 @@ impl ConnectionGate for ConnectionGater {
             return Err(DialError::PeerBlocked { peer_id });
         }
 
+        // In-memory addresses are only reachable from within the process and carry no IP.
+        if self.allow_memory_addrs && Self::is_memory_addr(addr) {
+            return Ok(());
+        }
+
         // There must be a reachable IP Address in the Multiaddr protocol stack.
         let ip_addr = Self::ip_from_addr(addr).ok_or_else(|| {
             warn!(target: "p2p", peer=?addr, "Failed to extract IpAddr from Multiaddr");
@@ -369,3 +386,27 @@ fn test_dial_error_handling() {
     let result = gater.can_dial(&valid_addr);
     assert!(matches!(result, Err(DialError::AlreadyDialing { .. })));
 }
+
+#[test]
+fn test_can_dial_memory_addr() {
+    use crate::{ConnectionGate, DialError};
+    use std::str::FromStr;
+
+    let mut gater = ConnectionGater::new(GaterConfig::default());
+
+    let addr = Multiaddr::from_str(
+        "/memory/1234/p2p/12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp",
+    )
+    .unwrap();
+    assert!(ConnectionGater::is_memory_addr(&addr));
+
+    // In-memory addresses are rejected unless explicitly allowed.
+    assert!(matches!(gater.can_dial(&addr), Err(DialError::InvalidIpAddress { .. })));
+    gater.allow_memory_addrs = true;
+    assert!(gater.can_dial(&addr).is_ok());
+
+    // Blocked peers are still rejected.
+    let peer_id = ConnectionGater::peer_id_from_addr(&addr).unwrap();
+    gater.blocked_peers.insert(peer_id);
+    assert!(matches!(gater.can_dial(&addr), Err(DialError::PeerBlocked { .. })));
+}
@@ -29,8 +29,8 @@ pub use metrics::Metrics;
 
 mod rpc;
 pub use rpc::{
-    Connectedness, Direction, GossipScores, P2pRpcRequest, PeerCount, PeerDump, PeerInfo,
-    PeerScores, PeerStats, ReqRespScores, TopicScores,
+    Connectedness, Direction, GossipScores, P2pRpcRequest, PeerCount, PeerDbDump, PeerDump,
+    PeerInfo, PeerScores, PeerStats, ReqRespScores, TopicScores,
 };
 
 mod behaviour;
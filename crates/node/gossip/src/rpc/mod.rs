@@ -11,6 +11,7 @@
 //! - [`PeerStats`]: Connection statistics and performance metrics
 //! - [`PeerCount`]: Current peer count across different connection states
 //! - [`PeerDump`]: Complete dump of all known peers
+//! - [`PeerDbDump`]: Export of the persistent peer database
 //!
 //! ### Scoring and Quality
 //! - [`PeerScores`]: Peer reputation scores used for mesh maintenance
@@ -48,6 +49,6 @@ pub use request::P2pRpcRequest;
 
 mod types;
 pub use types::{
-    Connectedness, Direction, GossipScores, PeerCount, PeerDump, PeerInfo, PeerScores, PeerStats,
-    ReqRespScores, TopicScores,
+    Connectedness, Direction, GossipScores, PeerCount, PeerDbDump, PeerDump, PeerInfo, PeerScores,
+    PeerStats, ReqRespScores, TopicScores,
 };
@@ -15,7 +15,7 @@ use libp2p::{Multiaddr, PeerId, gossipsub::TopicHash};
 use tokio::sync::oneshot::Sender;
 
 use super::{
-    PeerDump, PeerStats,
+    PeerDbDump, PeerDump, PeerStats,
     types::{Connectedness, Direction, PeerInfo, PeerScores},
 };
 use crate::ConnectionGate;
@@ -103,6 +103,8 @@ pub enum P2pRpcRequest {
     /// This information can be used to briefly monitor the current state of the p2p network for a
     /// given peer.
     PeerStats(Sender<PeerStats>),
+    /// Exports the persistent peer database as a [`PeerDbDump`].
+    PeerDb(Sender<PeerDbDump>),
 }
 
 impl P2pRpcRequest {
@@ -127,6 +129,7 @@ impl P2pRpcRequest {
             Self::BlockSubnet { address } => Self::block_subnet(address, gossip),
             Self::UnblockSubnet { address } => Self::unblock_subnet(address, gossip),
             Self::ListBlockedSubnets(s) => Self::list_blocked_subnets(s, gossip),
+            Self::PeerDb(s) => Self::handle_peer_db(s, gossip),
         }
     }
 
@@ -140,10 +143,14 @@ impl P2pRpcRequest {
 
     fn block_addr<G: ConnectionGate>(address: IpAddr, gossip: &mut GossipDriver<G>) {
         gossip.connection_gate.block_addr(address);
+        gossip.peer_db.block_addr(address);
+        gossip.persist_peer_db();
     }
 
     fn unblock_addr<G: ConnectionGate>(address: IpAddr, gossip: &mut GossipDriver<G>) {
         gossip.connection_gate.unblock_addr(address);
+        gossip.peer_db.unblock_addr(&address);
+        gossip.persist_peer_db();
     }
 
     fn list_blocked_addrs<G: ConnectionGate>(s: Sender<Vec<IpAddr>>, gossip: &GossipDriver<G>) {
@@ -154,13 +161,11 @@ impl P2pRpcRequest {
     }
 
     fn block_peer<G: ConnectionGate>(id: PeerId, gossip: &mut GossipDriver<G>) {
-        gossip.connection_gate.block_peer(&id);
-        gossip.swarm.behaviour_mut().gossipsub.blacklist_peer(&id);
+        gossip.ban_peer(id, None);
     }
 
     fn unblock_peer<G: ConnectionGate>(id: PeerId, gossip: &mut GossipDriver<G>) {
-        gossip.connection_gate.unblock_peer(&id);
-        gossip.swarm.behaviour_mut().gossipsub.remove_blacklisted_peer(&id);
+        gossip.unban_peer(&id);
     }
 
     fn list_blocked_peers<G: ConnectionGate>(s: Sender<Vec<PeerId>>, gossip: &GossipDriver<G>) {
@@ -172,10 +177,14 @@ impl P2pRpcRequest {
 
     fn block_subnet<G: ConnectionGate>(address: IpNet, gossip: &mut GossipDriver<G>) {
         gossip.connection_gate.block_subnet(address);
+        gossip.peer_db.block_subnet(address);
+        gossip.persist_peer_db();
     }
 
     fn unblock_subnet<G: ConnectionGate>(address: IpNet, gossip: &mut GossipDriver<G>) {
         gossip.connection_gate.unblock_subnet(address);
+        gossip.peer_db.unblock_subnet(&address);
+        gossip.persist_peer_db();
     }
 
     fn connect_peer<G: ConnectionGate>(address: Multiaddr, gossip: &mut GossipDriver<G>) {
@@ -206,6 +215,18 @@ impl P2pRpcRequest {
         }
     }
 
+    fn handle_peer_db<G: ConnectionGate>(s: Sender<PeerDbDump>, gossip: &GossipDriver<G>) {
+        let db = &gossip.peer_db;
+        let dump = PeerDbDump {
+            peers: db.peers.iter().map(|(id, record)| (id.to_string(), record.clone())).collect(),
+            blocked_ips: db.blocked_addrs.iter().copied().collect(),
+            blocked_subnets: db.blocked_subnets.iter().copied().collect(),
+        };
+        if let Err(e) = s.send(dump) {
+            warn!(target: "p2p::rpc", "Failed to send peer database through response channel: {:?}", e);
+        }
+    }
+
     fn handle_discovery_table(sender: Sender<Vec<String>>, disc: &Discv5Handler) {
         let enrs = disc.table_enrs();
         tokio::spawn(async move {
@@ -189,6 +189,19 @@ pub struct PeerDump {
     pub banned_subnets: Vec<ipnet::IpNet>,
 }
 
+/// A dump of the persistent peer database.
+#[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerDbDump {
+    /// A map from peer id to the persisted peer record.
+    pub peers: HashMap<String, kona_peers::PeerRecord>,
+    /// The persisted blocked ip addresses.
+    #[serde(rename = "blockedIPS")]
+    pub blocked_ips: Vec<IpAddr>,
+    /// The persisted blocked subnets.
+    pub blocked_subnets: Vec<ipnet::IpNet>,
+}
+
 /// Peer stats.
 ///
 /// <https://github.com/ethereum-optimism/optimism/blob/develop/op-node/p2p/rpc_server.go#L203>
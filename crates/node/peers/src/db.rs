@@ -0,0 +1,545 @@
+//! Persistent Peer Database
+
+use ipnet::IpNet;
+use libp2p::{Multiaddr, PeerId};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fs::File,
+    io::{BufReader, ErrorKind, Seek, SeekFrom},
+    net::IpAddr,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The maximum number of connection records kept for a single peer.
+const MAX_CONNECTION_HISTORY: usize = 16;
+
+/// The maximum number of addresses kept for a single peer.
+const MAX_PEER_ADDRS: usize = 8;
+
+/// Returns the current unix timestamp in seconds.
+pub fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default()
+}
+
+/// A single connection to a peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionRecord {
+    /// The unix timestamp (in seconds) at which the connection was established.
+    pub connected_at: u64,
+    /// The unix timestamp (in seconds) at which the connection was closed, if it was closed.
+    pub disconnected_at: Option<u64>,
+}
+
+/// The ban state of a peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PeerBan {
+    /// The peer is banned until it is explicitly unbanned, ie through `opp2p_unblockPeer`.
+    Permanent,
+    /// The peer is banned until the given unix timestamp (in seconds).
+    Until(u64),
+}
+
+impl PeerBan {
+    /// Returns `true` if the ban is still in effect at the given unix timestamp.
+    pub const fn is_active(&self, now: u64) -> bool {
+        match self {
+            Self::Permanent => true,
+            Self::Until(expiry) => *expiry > now,
+        }
+    }
+}
+
+/// Everything the node remembers about a peer.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerRecord {
+    /// The most recently observed addresses for the peer.
+    #[serde(default)]
+    pub addrs: Vec<Multiaddr>,
+    /// The unix timestamp (in seconds) at which the peer was last seen.
+    pub last_seen: u64,
+    /// The last recorded gossip score for the peer.
+    #[serde(default)]
+    pub score: f64,
+    /// The ban applied to the peer, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ban: Option<PeerBan>,
+    /// The total number of connections established with the peer.
+    #[serde(default)]
+    pub total_connections: u64,
+    /// The most recent connections with the peer, oldest first.
+    #[serde(default)]
+    pub connections: VecDeque<ConnectionRecord>,
+}
+
+impl PeerRecord {
+    /// Returns `true` if the peer is banned at the given unix timestamp.
+    pub fn is_banned(&self, now: u64) -> bool {
+        self.ban.is_some_and(|ban| ban.is_active(now))
+    }
+}
+
+/// The peer database file location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeerDbFile {
+    /// Default path for the peer database, ie `~/.kona/<chain_id>/peerdb.json`.
+    Default {
+        /// The l2 chain ID.
+        chain_id: u64,
+    },
+    /// A custom peer database path is used. This must be a valid path to a file.
+    Custom(PathBuf),
+}
+
+impl TryInto<PathBuf> for PeerDbFile {
+    type Error = std::io::Error;
+
+    fn try_into(self) -> Result<PathBuf, std::io::Error> {
+        match self {
+            Self::Default { chain_id } => {
+                let mut path = dirs::home_dir()
+                    .ok_or(std::io::Error::other("Failed to get home directory"))?;
+                path.push(".kona");
+                path.push(chain_id.to_string());
+                path.push("peerdb.json");
+                Ok(path)
+            }
+            Self::Custom(path) => Ok(path),
+        }
+    }
+}
+
+impl TryInto<File> for PeerDbFile {
+    type Error = std::io::Error;
+
+    fn try_into(self) -> Result<File, std::io::Error> {
+        let path = TryInto::<PathBuf>::try_into(self)?;
+        open_db_file(&path)
+    }
+}
+
+impl TryInto<PeerDb> for PeerDbFile {
+    type Error = std::io::Error;
+
+    /// Opens the peer database.
+    ///
+    /// A file that holds data but cannot be parsed is moved aside to `<path>.corrupt.<timestamp>`
+    /// so that it can be inspected, and the node starts with an empty database.
+    fn try_into(self) -> Result<PeerDb, std::io::Error> {
+        let path = TryInto::<PathBuf>::try_into(self)?;
+        match PeerDb::try_from(open_db_file(&path)?) {
+            Err(e) if e.kind() == ErrorKind::InvalidData => {
+                let mut backup = path.clone().into_os_string();
+                backup.push(format!(".corrupt.{}", unix_now()));
+                std::fs::rename(&path, &backup)?;
+                error!(
+                    target: "peerdb",
+                    "Peer database {} is corrupt, moved it to {:?}: {:?}",
+                    path.display(),
+                    backup,
+                    e
+                );
+                PeerDb::try_from(open_db_file(&path)?)
+            }
+            res => res,
+        }
+    }
+}
+
+/// Opens the peer database file, creating it and its parent directories if necessary.
+fn open_db_file(path: &Path) -> Result<File, std::io::Error> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    File::options().read(true).write(true).create(true).truncate(false).open(path)
+}
+
+/// On-disk database of known peers.
+///
+/// The [`PeerDb`] is a JSON file that persists what the node has learned about its peers across
+/// restarts: the last time each peer was seen, its last recorded score, any ban applied to it and
+/// its recent connection history. Blocked ip addresses and subnets are persisted alongside.
+///
+/// Entries for peers that have not been seen within a given time-to-live and that are not banned
+/// are removed by [`PeerDb::gc`].
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerDb {
+    /// The file for the [`PeerDb`].
+    #[serde(skip)]
+    pub file: Option<File>,
+    /// Known peers, keyed by their [`PeerId`].
+    #[serde(default, with = "peer_map")]
+    pub peers: HashMap<PeerId, PeerRecord>,
+    /// Blocked ip addresses.
+    #[serde(default)]
+    pub blocked_addrs: HashSet<IpAddr>,
+    /// Blocked subnets.
+    #[serde(default)]
+    pub blocked_subnets: HashSet<IpNet>,
+}
+
+impl TryFrom<File> for PeerDb {
+    type Error = std::io::Error;
+
+    /// Reads the [`PeerDb`] from the given file.
+    ///
+    /// An empty file yields an empty database, which is expected the first time the database is
+    /// opened. A file that cannot be parsed returns an [`ErrorKind::InvalidData`] error.
+    fn try_from(file: File) -> Result<Self, Self::Error> {
+        debug!(target: "peerdb", "Reading peer database from disk: {:?}", file);
+        if file.metadata()?.len() == 0 {
+            return Ok(Self { file: Some(file), ..Default::default() });
+        }
+        let db = serde_json::from_reader::<_, Self>(BufReader::new(&file))
+            .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+        Ok(Self { file: Some(file), ..db })
+    }
+}
+
+impl PeerDb {
+    /// Returns the number of peers in the database.
+    pub fn len(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// Returns if the database holds no peers.
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+
+    /// Returns the [`PeerRecord`] for the given peer, if any.
+    pub fn get(&self, peer_id: &PeerId) -> Option<&PeerRecord> {
+        self.peers.get(peer_id)
+    }
+
+    /// Records that the peer was seen at the given addresses.
+    pub fn record_seen(&mut self, peer_id: PeerId, addrs: impl IntoIterator<Item = Multiaddr>) {
+        let record = self.peers.entry(peer_id).or_default();
+        record.last_seen = unix_now();
+        for addr in addrs {
+            if !record.addrs.contains(&addr) {
+                record.addrs.push(addr);
+            }
+        }
+        if record.addrs.len() > MAX_PEER_ADDRS {
+            let excess = record.addrs.len() - MAX_PEER_ADDRS;
+            record.addrs.drain(..excess);
+        }
+    }
+
+    /// Records a new connection with the peer.
+    pub fn record_connected(&mut self, peer_id: PeerId) {
+        let now = unix_now();
+        let record = self.peers.entry(peer_id).or_default();
+        record.last_seen = now;
+        record.total_connections += 1;
+        record.connections.push_back(ConnectionRecord { connected_at: now, disconnected_at: None });
+        if record.connections.len() > MAX_CONNECTION_HISTORY {
+            record.connections.pop_front();
+        }
+    }
+
+    /// Records that the latest connection with the peer was closed.
+    pub fn record_disconnected(&mut self, peer_id: &PeerId) {
+        let now = unix_now();
+        let Some(record) = self.peers.get_mut(peer_id) else {
+            return;
+        };
+        record.last_seen = now;
+        if let Some(conn) = record.connections.back_mut() {
+            conn.disconnected_at.get_or_insert(now);
+        }
+    }
+
+    /// Records the latest gossip score for the peer.
+    pub fn record_score(&mut self, peer_id: PeerId, score: f64) {
+        let record = self.peers.entry(peer_id).or_default();
+        record.last_seen = unix_now();
+        record.score = score;
+    }
+
+    /// Bans the peer. A `None` duration bans the peer permanently.
+    pub fn ban(&mut self, peer_id: PeerId, duration: Option<Duration>) {
+        let now = unix_now();
+        let record = self.peers.entry(peer_id).or_default();
+        record.last_seen = now;
+        record.ban = Some(match duration {
+            Some(duration) => PeerBan::Until(now.saturating_add(duration.as_secs())),
+            None => PeerBan::Permanent,
+        });
+    }
+
+    /// Lifts any ban on the peer.
+    pub fn unban(&mut self, peer_id: &PeerId) {
+        if let Some(record) = self.peers.get_mut(peer_id) {
+            record.ban = None;
+        }
+    }
+
+    /// Returns `true` if the peer is currently banned.
+    pub fn is_banned(&self, peer_id: &PeerId) -> bool {
+        let now = unix_now();
+        self.peers.get(peer_id).is_some_and(|record| record.is_banned(now))
+    }
+
+    /// Returns the peers that are currently banned along with their ban.
+    pub fn banned_peers(&self) -> Vec<(PeerId, PeerBan)> {
+        let now = unix_now();
+        self.peers
+            .iter()
+            .filter_map(|(id, record)| {
+                record.ban.filter(|ban| ban.is_active(now)).map(|b| (*id, b))
+            })
+            .collect()
+    }
+
+    /// Persists a blocked ip address.
+    pub fn block_addr(&mut self, addr: IpAddr) {
+        self.blocked_addrs.insert(addr);
+    }
+
+    /// Removes a blocked ip address.
+    pub fn unblock_addr(&mut self, addr: &IpAddr) {
+        self.blocked_addrs.remove(addr);
+    }
+
+    /// Persists a blocked subnet.
+    pub fn block_subnet(&mut self, subnet: IpNet) {
+        self.blocked_subnets.insert(subnet);
+    }
+
+    /// Removes a blocked subnet.
+    pub fn unblock_subnet(&mut self, subnet: &IpNet) {
+        self.blocked_subnets.remove(subnet);
+    }
+
+    /// Garbage collects the database.
+    ///
+    /// Expired bans are cleared and returned, so that the caller can lift them from the
+    /// connection gate. Peers that have not been seen within `ttl` and that are not banned are
+    /// removed from the database.
+    pub fn gc(&mut self, ttl: Duration) -> Vec<PeerId> {
+        self.gc_at(unix_now(), ttl)
+    }
+
+    fn gc_at(&mut self, now: u64, ttl: Duration) -> Vec<PeerId> {
+        let mut expired = Vec::new();
+        for (id, record) in self.peers.iter_mut() {
+            if record.ban.is_some_and(|ban| !ban.is_active(now)) {
+                record.ban = None;
+                expired.push(*id);
+            }
+        }
+
+        let cutoff = now.saturating_sub(ttl.as_secs());
+        let before = self.peers.len();
+        self.peers.retain(|_, record| record.ban.is_some() || record.last_seen >= cutoff);
+        debug!(
+            target: "peerdb",
+            pruned = before - self.peers.len(),
+            expired_bans = expired.len(),
+            "Garbage collected peer database"
+        );
+        expired
+    }
+
+    /// Returns the [`Duration`] until the ban on the peer expires, if the peer is banned for a
+    /// limited time.
+    pub fn ban_remaining(&self, peer_id: &PeerId) -> Option<Duration> {
+        match self.peers.get(peer_id)?.ban? {
+            PeerBan::Until(expiry) => Some(Duration::from_secs(expiry.saturating_sub(unix_now()))),
+            PeerBan::Permanent => None,
+        }
+    }
+
+    /// Takes a [`PeerDbSnapshot`] of the database, which can be written to disk off the async
+    /// runtime. Returns `None` if the database is not backed by a file.
+    pub fn snapshot(&self) -> Result<Option<PeerDbSnapshot>, std::io::Error> {
+        let Some(file) = &self.file else {
+            return Ok(None);
+        };
+        Ok(Some(PeerDbSnapshot { file: file.try_clone()?, contents: serde_json::to_vec(&self)? }))
+    }
+
+    /// Syncs the [`PeerDb`] with the contents on disk.
+    pub fn sync(&mut self) -> Result<(), std::io::Error> {
+        self.snapshot()?.map_or(Ok(()), PeerDbSnapshot::write)
+    }
+}
+
+/// The serialized contents of a [`PeerDb`], along with a handle to its file.
+#[derive(Debug)]
+pub struct PeerDbSnapshot {
+    /// A handle to the [`PeerDb`] file.
+    file: File,
+    /// The serialized [`PeerDb`].
+    contents: Vec<u8>,
+}
+
+impl PeerDbSnapshot {
+    /// Overwrites the [`PeerDb`] file with the snapshot, and flushes it to disk.
+    ///
+    /// This blocks on file I/O. Snapshots of the same database must be written one at a time.
+    pub fn write(mut self) -> Result<(), std::io::Error> {
+        use std::io::Write;
+        // Reset the file pointer AND truncate to overwrite the file.
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.set_len(0)?;
+        self.file.write_all(&self.contents)?;
+        self.file.sync_data()
+    }
+}
+
+/// (De)serializes a map keyed by [`PeerId`] using the base58 string form of the peer id.
+mod peer_map {
+    use super::PeerRecord;
+    use libp2p::PeerId;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::{BTreeMap, HashMap};
+
+    pub(super) fn serialize<S: Serializer>(
+        peers: &HashMap<PeerId, PeerRecord>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let peers: BTreeMap<String, &PeerRecord> =
+            peers.iter().map(|(id, record)| (id.to_string(), record)).collect();
+        peers.serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<PeerId, PeerRecord>, D::Error> {
+        let peers: HashMap<String, PeerRecord> = Deserialize::deserialize(deserializer)?;
+        Ok(peers
+            .into_iter()
+            .filter_map(|(id, record)| match id.parse::<PeerId>() {
+                Ok(id) => Some((id, record)),
+                Err(e) => {
+                    warn!(target: "peerdb", "Ignoring invalid peer id {}: {:?}", id, e);
+                    None
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peer_db_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("peerdb.json");
+        let peer = PeerId::random();
+        let addr: Multiaddr = "/ip4/127.0.0.1/tcp/9222".parse().unwrap();
+
+        let mut db: PeerDb = PeerDbFile::Custom(path.clone()).try_into().unwrap();
+        assert!(db.is_empty());
+        db.record_seen(peer, [addr.clone()]);
+        db.record_connected(peer);
+        db.record_disconnected(&peer);
+        db.record_score(peer, -12.5);
+        db.ban(peer, None);
+        db.block_addr("10.0.0.1".parse().unwrap());
+        db.block_subnet("192.168.0.0/16".parse().unwrap());
+        db.sync().unwrap();
+        drop(db);
+
+        let db: PeerDb = PeerDbFile::Custom(path).try_into().unwrap();
+        let record = db.get(&peer).unwrap();
+        assert_eq!(record.addrs, vec![addr]);
+        assert_eq!(record.score, -12.5);
+        assert_eq!(record.total_connections, 1);
+        assert!(record.connections[0].disconnected_at.is_some());
+        assert!(db.is_banned(&peer));
+        assert_eq!(db.banned_peers(), vec![(peer, PeerBan::Permanent)]);
+        assert!(db.blocked_addrs.contains(&"10.0.0.1".parse().unwrap()));
+        assert!(db.blocked_subnets.contains(&"192.168.0.0/16".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_peer_db_connection_history_is_bounded() {
+        let mut db = PeerDb::default();
+        let peer = PeerId::random();
+        for _ in 0..MAX_CONNECTION_HISTORY + 4 {
+            db.record_connected(peer);
+            db.record_disconnected(&peer);
+        }
+        let record = db.get(&peer).unwrap();
+        assert_eq!(record.connections.len(), MAX_CONNECTION_HISTORY);
+        assert_eq!(record.total_connections, MAX_CONNECTION_HISTORY as u64 + 4);
+    }
+
+    #[test]
+    fn test_peer_db_gc() {
+        let mut db = PeerDb::default();
+        let stale = PeerId::random();
+        let fresh = PeerId::random();
+        let expired = PeerId::random();
+        let banned = PeerId::random();
+        db.peers.insert(stale, PeerRecord { last_seen: 100, ..Default::default() });
+        db.peers.insert(fresh, PeerRecord { last_seen: 1_000, ..Default::default() });
+        db.peers.insert(
+            expired,
+            PeerRecord { last_seen: 100, ban: Some(PeerBan::Until(500)), ..Default::default() },
+        );
+        db.peers.insert(
+            banned,
+            PeerRecord { last_seen: 100, ban: Some(PeerBan::Until(2_000)), ..Default::default() },
+        );
+
+        let lifted = db.gc_at(1_000, Duration::from_secs(500));
+        assert_eq!(lifted, vec![expired]);
+        assert!(db.get(&stale).is_none());
+        assert!(db.get(&expired).is_none());
+        assert!(db.get(&fresh).is_some());
+        assert!(db.get(&banned).is_some_and(|r| r.is_banned(1_000)));
+    }
+
+    #[test]
+    fn test_peer_db_ban_remaining() {
+        let mut db = PeerDb::default();
+        let peer = PeerId::random();
+        assert_eq!(db.ban_remaining(&peer), None);
+
+        db.ban(peer, Some(Duration::from_secs(60)));
+        let remaining = db.ban_remaining(&peer).unwrap();
+        assert!(remaining <= Duration::from_secs(60) && remaining >= Duration::from_secs(59));
+
+        db.ban(peer, None);
+        assert_eq!(db.ban_remaining(&peer), None);
+    }
+
+    #[test]
+    fn test_peer_db_rejects_corrupt_file() {
+        let mut file = tempfile::tempfile().unwrap();
+        std::io::Write::write_all(&mut file, b"not json").unwrap();
+        let err = PeerDb::try_from(file).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_peer_db_backs_up_corrupt_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("peerdb.json");
+        std::fs::write(&path, b"not json").unwrap();
+
+        let db: PeerDb = PeerDbFile::Custom(path.clone()).try_into().unwrap();
+        assert!(db.is_empty());
+        assert!(db.file.is_some());
+        assert_eq!(std::fs::read(&path).unwrap(), b"");
+
+        let backups = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name.starts_with("peerdb.json.corrupt."))
+            .collect::<Vec<_>>();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(std::fs::read(dir.path().join(&backups[0])).unwrap(), b"not json");
+    }
+}
@@ -21,6 +21,9 @@ pub use nodes::{BootNodes, OP_RAW_BOOTNODES, OP_RAW_TESTNET_BOOTNODES};
 mod store;
 pub use store::{BootStore, BootStoreFile};
 
+mod db;
+pub use db::{ConnectionRecord, PeerBan, PeerDb, PeerDbFile, PeerDbSnapshot, PeerRecord, unix_now};
+
 mod score;
 pub use score::PeerScoreLevel;
 
@@ -38,7 +41,8 @@ pub use record::{NodeRecord, NodeRecordParseError};
 
 mod utils;
 pub use utils::{
-    PeerIdConversionError, enr_to_multiaddr, local_id_to_p2p_id, peer_id_to_secp256k1_pubkey,
+    PeerIdConversionError, enr_to_multiaddr, enr_to_peer_id, local_id_to_p2p_id,
+    peer_id_to_secp256k1_pubkey,
 };
 
 mod monitoring;
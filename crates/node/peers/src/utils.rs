@@ -23,6 +23,15 @@ pub fn enr_to_multiaddr(enr: &Enr) -> Option<Multiaddr> {
         return None;
     };
 
+    addr.push(Protocol::P2p(enr_to_peer_id(enr)?));
+
+    Some(addr)
+}
+
+/// Converts an [`Enr`] into the [`libp2p::PeerId`] of the node it describes.
+///
+/// Returns `None` if the [`Enr`] is not signed with a secp256k1 key.
+pub fn enr_to_peer_id(enr: &Enr) -> Option<libp2p::PeerId> {
     let CombinedPublicKey::Secp256k1(pub_key) = enr.public_key() else {
         return None;
     };
@@ -30,9 +39,7 @@ pub fn enr_to_multiaddr(enr: &Enr) -> Option<Multiaddr> {
     let pub_key = libp2p_identity::secp256k1::PublicKey::try_from_bytes(&pub_key.encode()).ok()?;
     let pub_key = libp2p_identity::PublicKey::from(pub_key);
 
-    addr.push(Protocol::P2p(libp2p::PeerId::from_public_key(&pub_key)));
-
-    Some(addr)
+    Some(libp2p::PeerId::from_public_key(&pub_key))
 }
 
 /// Converts an uncompressed [`PeerId`] to a [`secp256k1::PublicKey`] by prepending the [`PeerId`]
@@ -1,6 +1,10 @@
 //! Contains the RPC Configuration.
 
-use std::{net::SocketAddr, path::PathBuf};
+use std::{
+    net::{SocketAddr, TcpListener},
+    path::PathBuf,
+    sync::Arc,
+};
 
 /// The RPC configuration.
 #[derive(Debug, Clone)]
@@ -9,6 +13,10 @@ pub struct RpcBuilder {
     pub no_restart: bool,
     /// The RPC socket address.
     pub socket: SocketAddr,
+    /// An already bound listener to serve the RPC on, in place of binding `socket`.
+    ///
+    /// This lets the caller bind an ephemeral port and learn its address before the node starts.
+    pub listener: Option<Arc<TcpListener>>,
     /// Enable the admin API.
     pub enable_admin: bool,
     /// File path used to persist state changes made via the admin API so they persist across
@@ -45,4 +53,10 @@ impl RpcBuilder {
     pub fn set_addr(self, addr: SocketAddr) -> Self {
         Self { socket: addr, ..self }
     }
+
+    /// Serves the RPC on the given bound [`TcpListener`], whose address becomes the socket of the
+    /// [`RpcBuilder`]. The listener must be in non-blocking mode.
+    pub fn with_listener(self, listener: TcpListener) -> Result<Self, std::io::Error> {
+        Ok(Self { socket: listener.local_addr()?, listener: Some(Arc::new(listener)), ..self })
+    }
 }
@@ -10,7 +10,7 @@ use jsonrpsee::{
     proc_macros::rpc,
 };
 use kona_genesis::RollupConfig;
-use kona_gossip::{PeerCount, PeerDump, PeerInfo, PeerStats};
+use kona_gossip::{PeerCount, PeerDbDump, PeerDump, PeerInfo, PeerStats};
 use kona_protocol::SyncStatus;
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
 
@@ -72,6 +72,10 @@ pub trait OpP2PApi {
     #[method(name = "peerStats")]
     async fn opp2p_peer_stats(&self) -> RpcResult<PeerStats>;
 
+    /// Exports the persistent peer database, including scores, bans and connection history.
+    #[method(name = "peerDb")]
+    async fn opp2p_peer_db(&self) -> RpcResult<PeerDbDump>;
+
     /// Returns the discovery table
     #[method(name = "discoveryTable")]
     async fn opp2p_discovery_table(&self) -> RpcResult<Vec<String>>;
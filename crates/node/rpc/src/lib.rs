@@ -30,6 +30,8 @@ mod dev;
 pub use dev::DevEngineRpc;
 
 mod jsonrpsee;
+#[cfg(feature = "client")]
+pub use jsonrpsee::{AdminApiClient, OpP2PApiClient, RollupNodeApiClient};
 pub use jsonrpsee::{
     AdminApiServer, DevEngineApiServer, MinerApiExtServer, OpAdminApiServer, OpP2PApiServer,
     RollupNodeApiServer, WsServer,
@@ -12,7 +12,7 @@ use jsonrpsee::{
     core::RpcResult,
     types::{ErrorCode, ErrorObject},
 };
-use kona_gossip::{P2pRpcRequest, PeerCount, PeerDump, PeerInfo, PeerStats};
+use kona_gossip::{P2pRpcRequest, PeerCount, PeerDbDump, PeerDump, PeerInfo, PeerStats};
 use std::{net::IpAddr, str::FromStr, time::Duration};
 
 use crate::{OpP2PApiServer, net::P2pRpc};
@@ -69,6 +69,17 @@ impl OpP2PApiServer for P2pRpc {
         Ok(stats)
     }
 
+    async fn opp2p_peer_db(&self) -> RpcResult<PeerDbDump> {
+        kona_macros::inc!(gauge, kona_gossip::Metrics::RPC_CALLS, "method" => "opp2p_peerDb");
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(P2pRpcRequest::PeerDb(tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
     async fn opp2p_discovery_table(&self) -> RpcResult<Vec<String>> {
         kona_macros::inc!(gauge, kona_gossip::Metrics::RPC_CALLS, "method" => "opp2p_discoveryTable");
         let (tx, rx) = tokio::sync::oneshot::channel();
@@ -57,6 +57,7 @@ impl RollupRpc {
             local_safe_l2: l2_sync_status.sync_state.local_safe_head(),
             safe_l2: l2_sync_status.sync_state.safe_head(),
             finalized_l2: l2_sync_status.sync_state.finalized_head(),
+            el_sync: Some(l2_sync_status.el_sync_status),
         }
     }
 }
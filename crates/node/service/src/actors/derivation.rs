@@ -42,6 +42,10 @@ where
     engine_l2_safe_head: watch::Receiver<L2BlockInfo>,
     /// A receiver used by the engine to signal derivation to begin. Completing EL sync consumes
     /// the instance.
+    ///
+    /// In consensus-layer sync mode, the signal is sent right after the initial engine reset. In
+    /// execution-layer sync mode, derivation is held back until the EL has synced to the unsafe
+    /// tip, and resumes from the finalized checkpoint set upon EL sync completion.
     el_sync_complete_rx: oneshot::Receiver<()>,
     /// A receiver that sends a [`Signal`] to the derivation pipeline.
     ///
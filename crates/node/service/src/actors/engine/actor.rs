@@ -6,14 +6,14 @@ use async_trait::async_trait;
 use futures::future::OptionFuture;
 use kona_derive::{ResetSignal, Signal};
 use kona_engine::{
-    BuildTask, ConsolidateTask, Engine, EngineClient, EngineQueries,
-    EngineState as InnerEngineState, EngineTask, EngineTaskError, EngineTaskErrorSeverity,
-    InsertTask,
+    BuildTask, ConsolidateTask, Engine, EngineClient, EngineClientError, EngineEndpoint,
+    EngineQueries, EngineState as InnerEngineState, EngineTask, EngineTaskError,
+    EngineTaskErrorSeverity, InsertTask, ShadowEngine, SyncMode,
 };
 use kona_genesis::RollupConfig;
 use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 use tokio::{
     sync::{mpsc, oneshot, watch},
     task::JoinHandle,
@@ -88,37 +88,62 @@ pub struct EngineBuilder {
     pub l1_rpc_url: Url,
     /// The engine jwt secret.
     pub jwt_secret: JwtSecret,
+    /// An optional path to the engine IPC socket. When set, Engine API calls are sent over IPC
+    /// instead of the engine rpc url.
+    pub engine_ipc: Option<PathBuf>,
+    /// An optional shadow execution layer that mirrors the Engine API calls of the primary one.
+    pub shadow: Option<EngineEndpoint>,
     /// The mode of operation for the node.
     /// When the node is in sequencer mode, the engine actor will receive requests to build blocks
     /// from the sequencer actor.
     pub mode: NodeMode,
+    /// The strategy used to sync the execution layer.
+    pub sync_mode: SyncMode,
 }
 
 impl EngineBuilder {
     /// Launches the [`Engine`]. Returns the [`Engine`] and a channel to receive engine state
     /// updates.
-    fn build_state(self) -> EngineActorState {
-        let client = self.client();
-        let state = InnerEngineState::default();
+    async fn build_state(self) -> Result<EngineActorState, EngineClientError> {
+        let client = self.client().await?;
+        let state = InnerEngineState {
+            el_sync_status: self.sync_mode.initial_el_sync_status(),
+            ..Default::default()
+        };
         let (engine_state_send, _) = tokio::sync::watch::channel(state);
         let (engine_queue_length_send, _) = tokio::sync::watch::channel(0);
 
-        EngineActorState {
+        Ok(EngineActorState {
             rollup: self.config,
             client,
             engine: Engine::new(state, engine_state_send, engine_queue_length_send),
-        }
+            reset_performed: false,
+        })
     }
 
-    /// Returns the [`EngineClient`].
-    pub fn client(&self) -> Arc<EngineClient> {
-        EngineClient::new_http(
-            self.engine_url.clone(),
-            self.l1_rpc_url.clone(),
-            self.config.clone(),
-            self.jwt_secret,
+    /// Returns the [`EngineEndpoint`] of the primary execution layer.
+    pub fn endpoint(&self) -> EngineEndpoint {
+        self.engine_ipc.clone().map_or_else(
+            || EngineEndpoint::Http { url: self.engine_url.clone(), jwt: self.jwt_secret },
+            EngineEndpoint::Ipc,
         )
-        .into()
+    }
+
+    /// Connects to the execution layer and returns the [`EngineClient`].
+    ///
+    /// If a shadow execution layer is configured, the returned client mirrors its Engine API
+    /// calls to it.
+    pub async fn client(&self) -> Result<Arc<EngineClient>, EngineClientError> {
+        let mut client =
+            EngineClient::connect(&self.endpoint(), self.l1_rpc_url.clone(), self.config.clone())
+                .await?;
+
+        if let Some(shadow) = &self.shadow {
+            info!(target: "engine", "Mirroring Engine API calls to a shadow engine");
+            client = client.with_shadow(ShadowEngine::spawn(shadow.connect().await?));
+        }
+
+        Ok(client.into())
     }
 }
 
@@ -131,6 +156,8 @@ pub(super) struct EngineActorState {
     pub(super) client: Arc<EngineClient>,
     /// The [`Engine`] task queue.
     pub(super) engine: Engine,
+    /// Whether or not the [`Engine`] has been reset at least once.
+    pub(super) reset_performed: bool,
 }
 
 /// The communication context used by the engine actor.
@@ -234,6 +261,7 @@ impl EngineActorState {
         // Reset the engine.
         let (l2_safe_head, l1_origin, system_config) =
             self.engine.reset(self.client.clone(), self.rollup.clone()).await?;
+        self.reset_performed = true;
 
         // Attempt to update the safe head following the reset.
         // IMPORTANT NOTE: We need to update the safe head BEFORE sending the reset signal to the
@@ -316,6 +344,11 @@ impl EngineActorState {
     }
 
     /// Checks if the EL has finished syncing, notifying the derivation actor if it has.
+    ///
+    /// In consensus-layer sync mode, the EL sync is considered finished from the start, and the
+    /// derivation actor is notified after the initial engine reset. In execution-layer sync mode,
+    /// the notification is held back until the EL has synced to the unsafe tip received over
+    /// gossip.
     async fn check_el_sync(
         &mut self,
         derivation_signal_tx: &mpsc::Sender<Signal>,
@@ -323,18 +356,20 @@ impl EngineActorState {
         sync_complete_tx: &mut Option<oneshot::Sender<()>>,
         finalizer: &mut L2Finalizer,
     ) -> Result<(), EngineError> {
-        if self.engine.state().el_sync_finished {
+        if self.engine.state().el_sync_finished() {
             let Some(sync_complete_tx) = std::mem::take(sync_complete_tx) else {
                 return Ok(());
             };
 
-            // Only reset the engine if the sync state does not already know about a finalized
-            // block.
-            if self.engine.state().sync_state.finalized_head() != L2BlockInfo::default() {
+            // Only reset the engine if it has not already been reset, e.g. upon request of the
+            // sequencer actor.
+            if self.reset_performed {
                 return Ok(());
             }
 
-            // If the sync status is finished, we can reset the engine and start derivation.
+            // If the sync status is finished, we can reset the engine and start derivation. The
+            // reset also signals the derivation pipeline to start from the safe head, which is
+            // the finalized checkpoint if the EL just finished syncing.
             info!(target: "engine", "Performing initial engine reset");
             self.reset(derivation_signal_tx, engine_l2_safe_head_tx, finalizer).await?;
             sync_complete_tx.send(()).ok();
@@ -379,7 +414,7 @@ impl NodeActor for EngineActor {
             mut engine_unsafe_head_tx,
         }: Self::OutboundData,
     ) -> Result<(), Self::Error> {
-        let mut state = self.builder.build_state();
+        let mut state = self.builder.build_state().await?;
 
         // Start the engine query server in a separate task to avoid blocking the main task.
         let handle = state.start_query_task(self.inbound_queries);
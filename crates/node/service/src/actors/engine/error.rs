@@ -2,7 +2,7 @@
 //!
 //! [`EngineActor`]: super::EngineActor
 
-use kona_engine::{EngineResetError, EngineTaskErrors};
+use kona_engine::{EngineClientError, EngineResetError, EngineTaskErrors};
 
 /// An error from the [`EngineActor`].
 ///
@@ -12,6 +12,9 @@ pub enum EngineError {
     /// Closed channel error.
     #[error("a channel has been closed unexpectedly")]
     ChannelClosed,
+    /// Failed to connect to the execution layer.
+    #[error("failed to connect to the engine: {0}")]
+    EngineClient(#[from] EngineClientError),
     /// Engine reset error.
     #[error(transparent)]
     EngineReset(#[from] EngineResetError),
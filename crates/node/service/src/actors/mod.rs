@@ -34,6 +34,8 @@ pub use network::{
 mod sequencer;
 pub use sequencer::{
     AttributesBuilderConfig, ConductorClient, ConductorError, DelayedL1OriginSelectorProvider,
-    L1OriginSelector, L1OriginSelectorError, L1OriginSelectorProvider, SequencerActor,
-    SequencerActorError, SequencerBuilder, SequencerConfig, SequencerContext, SequencerInboundData,
+    FileTxSource, L1OriginSelector, L1OriginSelectorError, L1OriginSelectorProvider, NoopTxSource,
+    SequencerActor, SequencerActorError, SequencerBuilder, SequencerConfig, SequencerContext,
+    SequencerInboundData, SequencerTxLimits, SequencerTxSource, SequencerTxSourceError,
+    TxSelection, select_transactions, validate_transactions,
 };
@@ -164,6 +164,9 @@ impl NodeActor for NetworkActor {
                         target: "network",
                         "Received shutdown signal. Exiting network task."
                     );
+                    // Persist the peer database before exiting.
+                    handler.gossip.gc_peer_db(handler.peer_db_ttl);
+                    handler.gossip.close_peer_db().await;
                     return Ok(());
                 }
                 block = unsafe_block_rx.recv() => {
@@ -243,6 +246,12 @@ impl NodeActor for NetworkActor {
                 _ = handler.peer_score_inspector.tick(), if handler.gossip.peer_monitoring.as_ref().is_some() => {
                     handler.handle_peer_monitoring().await;
                 },
+                _ = handler.peer_db_gc.tick() => {
+                    handler.gossip.gc_peer_db(handler.peer_db_ttl);
+                },
+                _ = handler.peer_db_flush.tick() => {
+                    handler.gossip.flush_peer_db();
+                },
                 Some(NetworkAdminQuery::PostUnsafePayload { payload }) = self.admin_rpc.recv(), if !self.admin_rpc.is_closed() => {
                     debug!(target: "node::p2p", "Broadcasting unsafe payload from admin api");
                     if unsafe_block_tx.send(payload).is_err() {
@@ -5,7 +5,7 @@ use discv5::{Config as Discv5Config, Enr};
 use kona_disc::{Discv5Builder, LocalNode};
 use kona_genesis::RollupConfig;
 use kona_gossip::{GaterConfig, GossipDriverBuilder};
-use kona_peers::{BootStoreFile, PeerMonitoring, PeerScoreLevel};
+use kona_peers::{BootStoreFile, PeerDbFile, PeerMonitoring, PeerScoreLevel};
 use kona_sources::BlockSigner;
 use libp2p::{Multiaddr, identity::Keypair};
 use std::time::Duration;
@@ -29,6 +29,8 @@ pub struct NetworkBuilder {
     /// This may be set to false if the node is configured to use a static advertised address (when
     /// used with a nat for example).
     pub(super) enr_update: bool,
+    /// Peers that have not been seen for longer than this are dropped from the peer database.
+    pub(super) peer_db_ttl: Duration,
 }
 
 impl From<NetworkConfig> for NetworkBuilder {
@@ -52,6 +54,9 @@ impl From<NetworkConfig> for NetworkBuilder {
         .with_peer_monitoring(config.monitor_peers)
         .with_topic_scoring(config.topic_scoring)
         .with_gater_config(config.gater_config)
+        .with_peer_db(config.peer_db)
+        .with_peer_db_ttl(config.peer_db_ttl)
+        .with_memory_transport(config.memory_transport)
     }
 }
 
@@ -80,6 +85,7 @@ impl NetworkBuilder {
             ),
             signer,
             enr_update: true,
+            peer_db_ttl: NetworkConfig::DEFAULT_PEER_DB_TTL,
         }
     }
 
@@ -93,6 +99,13 @@ impl NetworkBuilder {
         Self { gossip: self.gossip.with_gater_config(config), ..self }
     }
 
+    /// Adds libp2p's in-memory transport to the gossip swarm.
+    ///
+    /// See [`GossipDriverBuilder::with_memory_transport`].
+    pub fn with_memory_transport(self, memory_transport: bool) -> Self {
+        Self { gossip: self.gossip.with_memory_transport(memory_transport), ..self }
+    }
+
     /// Sets the signer for the [`NetworkBuilder`].
     pub fn with_signer(self, signer: Option<BlockSigner>) -> Self {
         Self { signer, ..self }
@@ -103,6 +116,21 @@ impl NetworkBuilder {
         Self { discovery: self.discovery.with_bootstore_file(bootstore), ..self }
     }
 
+    /// Sets the peer database file for both the [`GossipDriverBuilder`] and the
+    /// [`Discv5Builder`].
+    pub fn with_peer_db(self, peer_db: Option<PeerDbFile>) -> Self {
+        Self {
+            discovery: self.discovery.with_peer_db_file(peer_db.clone()),
+            gossip: self.gossip.with_peer_db(peer_db),
+            ..self
+        }
+    }
+
+    /// Sets the time after which unseen peers are dropped from the peer database.
+    pub fn with_peer_db_ttl(self, peer_db_ttl: Duration) -> Self {
+        Self { peer_db_ttl, ..self }
+    }
+
     /// Sets the interval at which to randomize discovery peers.
     pub fn with_discovery_randomize(self, randomize: Option<Duration>) -> Self {
         Self { discovery: self.discovery.with_discovery_randomize(randomize), ..self }
@@ -169,6 +197,7 @@ impl NetworkBuilder {
             unsafe_block_signer_sender,
             signer: self.signer,
             enr_update: self.enr_update,
+            peer_db_ttl: self.peer_db_ttl,
         })
     }
 }
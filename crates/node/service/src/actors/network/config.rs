@@ -5,7 +5,7 @@ use discv5::Enr;
 use kona_disc::LocalNode;
 use kona_genesis::RollupConfig;
 use kona_gossip::GaterConfig;
-use kona_peers::{BootStoreFile, PeerMonitoring, PeerScoreLevel};
+use kona_peers::{BootStoreFile, PeerDbFile, PeerMonitoring, PeerScoreLevel};
 use kona_sources::BlockSigner;
 use libp2p::{Multiaddr, identity::Keypair};
 use tokio::time::Duration;
@@ -40,6 +40,10 @@ pub struct NetworkConfig {
     pub monitor_peers: Option<PeerMonitoring>,
     /// An optional path to the bootstore.
     pub bootstore: Option<BootStoreFile>,
+    /// An optional path to the peer database.
+    pub peer_db: Option<PeerDbFile>,
+    /// Peers that have not been seen for longer than this are dropped from the peer database.
+    pub peer_db_ttl: Duration,
     /// The configuration for the connection gater.
     pub gater_config: GaterConfig,
     /// An optional list of bootnode ENRs to start the node with.
@@ -48,12 +52,19 @@ pub struct NetworkConfig {
     pub rollup_config: RollupConfig,
     /// A signer for gossip payloads.
     pub gossip_signer: Option<BlockSigner>,
+    /// Whether to gossip over libp2p's in-memory transport in addition to TCP.
+    ///
+    /// This is only meant for running several nodes in a single process, e.g. in tests.
+    pub memory_transport: bool,
 }
 
 impl NetworkConfig {
     const DEFAULT_DISCOVERY_INTERVAL: Duration = Duration::from_secs(5);
     const DEFAULT_DISCOVERY_RANDOMIZE: Option<Duration> = None;
 
+    /// The default time after which unseen peers are dropped from the peer database.
+    pub const DEFAULT_PEER_DB_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
     /// Returns the [`discv5::Config`] from the CLI arguments.
     pub fn discv5_config(listen_config: discv5::ListenConfig, static_ip: bool) -> discv5::Config {
         // We can use a default listen config here since it
@@ -90,12 +101,15 @@ impl NetworkConfig {
             keypair: Keypair::generate_secp256k1(),
             bootnodes: Default::default(),
             bootstore: Default::default(),
+            peer_db: Default::default(),
+            peer_db_ttl: Self::DEFAULT_PEER_DB_TTL,
             gater_config: Default::default(),
             gossip_config: Default::default(),
             scoring: Default::default(),
             topic_scoring: Default::default(),
             monitor_peers: Default::default(),
             gossip_signer: Default::default(),
+            memory_transport: false,
         }
     }
 }
@@ -1,4 +1,7 @@
-use std::net::{IpAddr, SocketAddr};
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
 
 use alloy_primitives::Address;
 use discv5::multiaddr::Protocol;
@@ -27,6 +30,8 @@ pub struct NetworkDriver {
     pub unsafe_block_signer_sender: watch::Sender<Address>,
     /// A block signer. This is optional and should be set if the node is configured to sign blocks
     pub signer: Option<BlockSigner>,
+    /// Peers that have not been seen for longer than this are dropped from the peer database.
+    pub peer_db_ttl: Duration,
 }
 
 /// An error from the [`NetworkDriver`].
@@ -44,6 +49,12 @@ pub enum NetworkDriverError {
 }
 
 impl NetworkDriver {
+    /// The interval at which the peer database is garbage collected and persisted to disk.
+    const PEER_DB_GC_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+    /// The interval at which pending changes to the peer database are flushed to disk.
+    const PEER_DB_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
     /// Starts the network.
     pub async fn start(mut self) -> Result<NetworkHandler, NetworkDriverError> {
         // Start the libp2p Swarm
@@ -81,6 +92,11 @@ impl NetworkDriver {
         // We are checking the peer scores every [`PEER_SCORE_INSPECT_FREQUENCY`] seconds.
         let peer_score_inspector = tokio::time::interval(*PEER_SCORE_INSPECT_FREQUENCY);
 
+        // The first tick completes immediately, skip it since the peer database was just loaded.
+        let mut peer_db_gc = tokio::time::interval(Self::PEER_DB_GC_INTERVAL);
+        peer_db_gc.reset();
+        let peer_db_flush = tokio::time::interval(Self::PEER_DB_FLUSH_INTERVAL);
+
         // Start the block signer if it is configured.
         let signer =
             OptionFuture::from(self.signer.map(async |s| s.start().await)).await.transpose()?;
@@ -92,6 +108,9 @@ impl NetworkDriver {
             unsafe_block_signer_sender: self.unsafe_block_signer_sender,
             peer_score_inspector,
             signer,
+            peer_db_gc,
+            peer_db_flush,
+            peer_db_ttl: self.peer_db_ttl,
         })
     }
 }
@@ -22,6 +22,13 @@ pub struct NetworkHandler {
     pub peer_score_inspector: tokio::time::Interval,
     /// A handler for the block signer.
     pub signer: Option<BlockSignerHandler>,
+    /// The interval at which the peer database is garbage collected and persisted to disk.
+    pub peer_db_gc: tokio::time::Interval,
+    /// The interval at which changes to the peer database that were not yet written are flushed
+    /// to disk.
+    pub peer_db_flush: tokio::time::Interval,
+    /// Peers that have not been seen for longer than this are dropped from the peer database.
+    pub peer_db_ttl: std::time::Duration,
 }
 
 impl NetworkHandler {
@@ -68,6 +75,9 @@ impl NetworkHandler {
                             warn!(peer = ?peer_to_remove, "Trying to disconnect a non-existing peer from the gossip driver.");
                         }
 
+                        // Persist the ban so that it survives restarts.
+                        self.gossip.ban_peer(peer_to_remove, Some(ban_peers.ban_duration));
+
                         // Record the duration of the peer connection.
                         if let Some(start_time) = self.gossip.peer_connection_start.remove(&peer_to_remove) {
                             let peer_duration = start_time.elapsed();
@@ -90,7 +90,11 @@ async fn launch(
                 .expect("Critical: Failed to build GET method proxy"),
         )
         .timeout(Duration::from_secs(2));
-    let server = Server::builder().set_http_middleware(middleware).build(config.socket).await?;
+    let builder = Server::builder().set_http_middleware(middleware);
+    let server = match &config.listener {
+        Some(listener) => builder.build_from_tcp(listener.try_clone()?)?,
+        None => builder.build(config.socket).await?,
+    };
 
     if let Ok(addr) = server.local_addr() {
         info!(target: "rpc", addr = ?addr, "RPC server bound to address");
@@ -194,6 +198,7 @@ mod tests {
     async fn test_launch_no_modules() {
         let launcher = RpcBuilder {
             socket: SocketAddr::from(([127, 0, 0, 1], 8080)),
+            listener: None,
             no_restart: false,
             enable_admin: false,
             admin_persistence: None,
@@ -208,6 +213,7 @@ mod tests {
     async fn test_launch_with_modules() {
         let launcher = RpcBuilder {
             socket: SocketAddr::from(([127, 0, 0, 1], 8081)),
+            listener: None,
             no_restart: false,
             enable_admin: false,
             admin_persistence: None,
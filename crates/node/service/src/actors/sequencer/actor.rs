@@ -2,6 +2,7 @@
 
 use super::{
     DelayedL1OriginSelectorProvider, L1OriginSelector, L1OriginSelectorError, SequencerConfig,
+    SequencerTxLimits, SequencerTxSource, TxSelection, select_transactions, validate_transactions,
 };
 use crate::{CancellableContext, NodeActor, actors::sequencer::conductor::ConductorClient};
 use alloy_provider::RootProvider;
@@ -12,7 +13,7 @@ use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
 use kona_providers_alloy::{AlloyChainProvider, AlloyL2ChainProvider};
 use kona_rpc::SequencerAdminQuery;
 use op_alloy_network::Optimism;
-use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
+use op_alloy_rpc_types_engine::{OpExecutionPayloadEnvelope, OpPayloadAttributes};
 use std::{
     sync::Arc,
     time::{Duration, Instant, SystemTime, UNIX_EPOCH},
@@ -60,6 +61,12 @@ pub(super) struct SequencerActorState<AB: AttributesBuilder> {
     /// ## Default value
     /// At startup, the sequencer is _NOT_ in recovery mode.
     pub is_recovery_mode: bool,
+    /// The [`SequencerTxSource`] supplying transactions to include ahead of the transaction pool.
+    pub tx_source: Arc<dyn SequencerTxSource>,
+    /// The [`SequencerTxLimits`] applied to the supplied transactions.
+    pub tx_limits: SequencerTxLimits,
+    /// The L2 provider, used to validate the supplied transactions against the parent state.
+    pub l2_provider: RootProvider<Optimism>,
 }
 
 /// A trait for building [`AttributesBuilder`]s.
@@ -81,6 +88,8 @@ impl SequencerActorState<StatefulAttributesBuilder<AlloyChainProvider, AlloyL2Ch
             sequencer_recovery_mode,
             conductor_rpc_url,
             l1_conf_delay,
+            tx_source_file: _,
+            tx_limits,
         } = seq_builder.seq_cfg.clone();
 
         let cfg = seq_builder.rollup_cfg.clone();
@@ -90,6 +99,8 @@ impl SequencerActorState<StatefulAttributesBuilder<AlloyChainProvider, AlloyL2Ch
             l1_conf_delay,
         );
         let conductor = conductor_rpc_url.map(ConductorClient::new_http);
+        let tx_source = seq_builder.tx_source.clone();
+        let l2_provider = seq_builder.l2_provider.clone();
 
         let builder = seq_builder.build();
         let build_ticker = tokio::time::interval(Duration::from_secs(cfg.block_time));
@@ -104,6 +115,9 @@ impl SequencerActorState<StatefulAttributesBuilder<AlloyChainProvider, AlloyL2Ch
             conductor,
             is_active: !sequencer_stopped,
             is_recovery_mode: sequencer_recovery_mode,
+            tx_source,
+            tx_limits,
+            l2_provider,
         }
     }
 }
@@ -127,6 +141,8 @@ pub struct SequencerBuilder {
     pub l2_provider: RootProvider<Optimism>,
     /// Whether to trust the L2 RPC.
     pub l2_trust_rpc: bool,
+    /// The [`SequencerTxSource`] supplying transactions to include ahead of the transaction pool.
+    pub tx_source: Arc<dyn SequencerTxSource>,
 }
 
 impl AttributesBuilderConfig for SequencerBuilder {
@@ -350,6 +366,12 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
             attributes.no_tx_pool = Some(true);
         }
 
+        // Include the transactions supplied by the transaction source ahead of the transaction
+        // pool, unless the block must be empty.
+        if attributes.no_tx_pool == Some(false) {
+            self.include_source_transactions(&mut attributes, unsafe_head).await;
+        }
+
         let attrs_with_parent = OpAttributesWithParent::new(attributes, unsafe_head, None, false);
 
         // Log the attributes build duration, if metrics are enabled.
@@ -372,6 +394,11 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
 
         let payload = self.try_wait_for_payload(ctx, payload_rx).await?;
 
+        // The supplied transactions included in the block are now settled.
+        if let Err(err) = self.tx_source.confirm().await {
+            warn!(target: "sequencer", ?err, "Failed to confirm supplied transactions");
+        }
+
         // Log the block building job duration, if metrics are enabled.
         kona_macros::set!(
             gauge,
@@ -407,6 +434,84 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
         self.schedule_gossip(ctx, payload).await
     }
 
+    /// Appends the transactions supplied by the [`SequencerTxSource`] that fit into the block to
+    /// the given attributes, and hands the ones that do not fit back to the source.
+    async fn include_source_transactions(
+        &self,
+        attributes: &mut OpPayloadAttributes,
+        parent: L2BlockInfo,
+    ) {
+        let timestamp = attributes.payload_attributes.timestamp;
+        let candidates = match self.tx_source.next_transactions(parent, timestamp).await {
+            Ok(candidates) => candidates,
+            Err(err) => {
+                warn!(target: "sequencer", ?err, "Failed to read transactions from the transaction source");
+                return;
+            }
+        };
+        if candidates.is_empty() {
+            return;
+        }
+
+        let TxSelection { included, deferred, mut dropped } =
+            select_transactions(&self.cfg, attributes, &self.tx_limits, candidates);
+
+        // Transactions that cannot execute on top of the parent would fail the block, so they are
+        // dropped before reaching the execution layer.
+        let included = match validate_transactions(&self.l2_provider, &parent, included).await {
+            Ok((valid, invalid)) => {
+                dropped.extend(invalid);
+                valid
+            }
+            Err(err) => {
+                warn!(target: "sequencer", ?err, "Failed to validate supplied transactions");
+                self.tx_source.requeue([included, deferred].concat());
+                return;
+            }
+        };
+
+        if !dropped.is_empty() {
+            warn!(
+                target: "sequencer",
+                count = dropped.len(),
+                "Dropped supplied transactions that cannot be included"
+            );
+        }
+        debug!(
+            target: "sequencer",
+            included = included.len(),
+            deferred = deferred.len(),
+            "Including supplied transactions ahead of the transaction pool"
+        );
+
+        kona_macros::set!(
+            gauge,
+            crate::Metrics::SEQUENCER_SOURCE_TRANSACTIONS,
+            "outcome",
+            "included",
+            included.len() as f64
+        );
+        kona_macros::set!(
+            gauge,
+            crate::Metrics::SEQUENCER_SOURCE_TRANSACTIONS,
+            "outcome",
+            "deferred",
+            deferred.len() as f64
+        );
+        kona_macros::set!(
+            gauge,
+            crate::Metrics::SEQUENCER_SOURCE_TRANSACTIONS,
+            "outcome",
+            "dropped",
+            dropped.len() as f64
+        );
+
+        if !deferred.is_empty() {
+            self.tx_source.requeue(deferred);
+        }
+        attributes.transactions.get_or_insert_default().extend(included);
+    }
+
     /// Waits for the next payload to be built and returns it, if there is a payload receiver
     /// present.
     async fn try_wait_for_payload(
@@ -2,6 +2,8 @@
 //!
 //! [`SequencerActor`]: super::SequencerActor
 
+use super::{FileTxSource, NoopTxSource, SequencerTxLimits, SequencerTxSource};
+use std::{path::PathBuf, sync::Arc};
 use url::Url;
 
 /// Configuration for the [`SequencerActor`].
@@ -17,4 +19,21 @@ pub struct SequencerConfig {
     pub conductor_rpc_url: Option<Url>,
     /// The confirmation delay for the sequencer.
     pub l1_conf_delay: u64,
+    /// An optional path to a file holding transactions to include ahead of the transaction pool.
+    /// See [`FileTxSource`].
+    pub tx_source_file: Option<PathBuf>,
+    /// The [`SequencerTxLimits`] applied to the transactions supplied by the
+    /// [`SequencerTxSource`].
+    pub tx_limits: SequencerTxLimits,
+}
+
+impl SequencerConfig {
+    /// Returns the [`SequencerTxSource`] for this configuration: a [`FileTxSource`] if a
+    /// transaction source file is set, and a [`NoopTxSource`] otherwise.
+    pub fn tx_source(&self) -> Arc<dyn SequencerTxSource> {
+        self.tx_source_file.as_ref().map_or_else(
+            || Arc::new(NoopTxSource) as Arc<dyn SequencerTxSource>,
+            |path| Arc::new(FileTxSource::new(path.clone())),
+        )
+    }
 }
@@ -17,5 +17,11 @@ pub use actor::{
 
 mod rpc;
 
+mod tx_source;
+pub use tx_source::{
+    FileTxSource, NoopTxSource, SequencerTxLimits, SequencerTxSource, SequencerTxSourceError,
+    TxSelection, select_transactions, validate_transactions,
+};
+
 mod conductor;
 pub use conductor::{ConductorClient, ConductorError};
@@ -0,0 +1,652 @@
+//! Sequencer-supplied transactions, included ahead of the execution layer's transaction pool.
+
+use alloy_consensus::{Transaction, transaction::SignerRecoverable};
+use alloy_eips::{BlockId, eip2718::Decodable2718};
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_provider::Provider;
+use alloy_transport::TransportError;
+use async_trait::async_trait;
+use kona_genesis::RollupConfig;
+use kona_protocol::{L1BlockInfoJovian, L1BlockInfoTx, L2BlockInfo, fjord_estimated_da_size};
+use op_alloy_consensus::{OpTxEnvelope, OpTxType};
+use op_alloy_network::Optimism;
+use op_alloy_rpc_types_engine::OpPayloadAttributes;
+use std::{
+    collections::{HashMap, VecDeque, hash_map::Entry},
+    ffi::OsString,
+    fmt::Debug,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+/// An error returned by a [`SequencerTxSource`].
+#[derive(Debug, thiserror::Error)]
+pub enum SequencerTxSourceError {
+    /// An I/O error occurred while reading the source.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The source contained a malformed transaction.
+    #[error("Malformed transaction: {0}")]
+    Malformed(String),
+    /// The account state needed to validate the transactions could not be fetched.
+    #[error("Failed to fetch account state: {0}")]
+    AccountState(#[from] TransportError),
+}
+
+/// A source of transactions that the sequencer includes in the blocks it builds, ahead of the
+/// execution layer's transaction pool.
+///
+/// Transactions are returned in priority order as EIP-2718 encoded bytes. The sequencer drops
+/// transactions that would make the block's batch invalid, and hands the transactions that do not
+/// fit into the block back to the source through [`SequencerTxSource::requeue`].
+#[async_trait]
+pub trait SequencerTxSource: Debug + Send + Sync {
+    /// Returns the transactions to include in the block built on top of `parent` with the given
+    /// `timestamp`.
+    async fn next_transactions(
+        &self,
+        parent: L2BlockInfo,
+        timestamp: u64,
+    ) -> Result<Vec<Bytes>, SequencerTxSourceError>;
+
+    /// Hands back transactions that did not fit into the block, in priority order. They should be
+    /// returned first by the next call to [`SequencerTxSource::next_transactions`].
+    ///
+    /// The default implementation drops them.
+    fn requeue(&self, _transactions: Vec<Bytes>) {}
+
+    /// Confirms that the block built with the transactions last returned by
+    /// [`SequencerTxSource::next_transactions`] was sealed. The transactions that were not handed
+    /// back through [`SequencerTxSource::requeue`] are settled: they were either included or
+    /// dropped.
+    ///
+    /// The default implementation does nothing.
+    async fn confirm(&self) -> Result<(), SequencerTxSourceError> {
+        Ok(())
+    }
+}
+
+/// A [`SequencerTxSource`] that never supplies any transactions, leaving transaction selection
+/// entirely to the execution layer's transaction pool.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopTxSource;
+
+#[async_trait]
+impl SequencerTxSource for NoopTxSource {
+    async fn next_transactions(
+        &self,
+        _: L2BlockInfo,
+        _: u64,
+    ) -> Result<Vec<Bytes>, SequencerTxSourceError> {
+        Ok(Vec::new())
+    }
+}
+
+/// A [`SequencerTxSource`] that reads a queue of transactions from a local file.
+///
+/// The file holds one hex-encoded EIP-2718 transaction per line. Before each block, the source
+/// claims the file by renaming it to `<path>.incoming`, appends its transactions to the queue and
+/// then deletes it. Lines that are not valid hex are logged and skipped.
+///
+/// The queue is kept on disk in `<path>.claimed` until the block that includes its transactions
+/// has been sealed, so that no transaction is lost if the node stops in between. Both files are
+/// picked up again on restart. A transaction that was included right before a restart is read
+/// again, and then dropped by [`validate_transactions`] since its nonce has been used.
+///
+/// Writers should replace the file atomically (e.g. write to a temporary file and rename it)
+/// rather than appending to it in place.
+#[derive(Debug)]
+pub struct FileTxSource {
+    /// The path of the queue file.
+    path: PathBuf,
+    /// The state of the queue.
+    state: Mutex<FileTxSourceState>,
+}
+
+/// The state of a [`FileTxSource`].
+#[derive(Debug, Default)]
+struct FileTxSourceState {
+    /// Transactions that have not been handed out yet.
+    pending: VecDeque<Bytes>,
+    /// Transactions handed out for the block being built, that were not handed back.
+    in_flight: Vec<Bytes>,
+    /// Whether the claimed file left behind by a previous run has been read.
+    loaded: bool,
+}
+
+impl FileTxSourceState {
+    /// Returns the transactions that have not been settled yet, in order.
+    fn unsettled(&self) -> Vec<Bytes> {
+        self.in_flight.iter().chain(&self.pending).cloned().collect()
+    }
+}
+
+impl FileTxSource {
+    /// Creates a new [`FileTxSource`] reading from the given path.
+    pub const fn new(path: PathBuf) -> Self {
+        let state =
+            FileTxSourceState { pending: VecDeque::new(), in_flight: Vec::new(), loaded: false };
+        Self { path, state: Mutex::new(state) }
+    }
+
+    /// Returns the path of the queue file with the given suffix appended.
+    fn path_with_suffix(&self, suffix: &str) -> PathBuf {
+        let mut path = OsString::from(self.path.as_os_str());
+        path.push(suffix);
+        path.into()
+    }
+
+    /// Returns the path of the file holding the transactions that have not been settled yet.
+    fn claimed_path(&self) -> PathBuf {
+        self.path_with_suffix(".claimed")
+    }
+
+    /// Returns the path to which the queue file is renamed while it is being read.
+    fn incoming_path(&self) -> PathBuf {
+        self.path_with_suffix(".incoming")
+    }
+
+    /// Locks the state of the queue.
+    fn state(&self) -> MutexGuard<'_, FileTxSourceState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Reads the claimed file left behind by a previous run, then claims and reads the queue
+    /// file, appending their transactions to the pending queue.
+    async fn read_file(&self) -> Result<(), SequencerTxSourceError> {
+        if !self.state().loaded {
+            let claimed = self.claimed_path();
+            if tokio::fs::try_exists(&claimed).await? {
+                warn!(target: "sequencer", path = ?claimed, "Reading unsettled transactions");
+                let transactions = Self::read_transactions(&claimed).await?;
+                self.state().pending.extend(transactions);
+            }
+            self.state().loaded = true;
+        }
+
+        let incoming = self.incoming_path();
+        if !tokio::fs::try_exists(&incoming).await? {
+            match tokio::fs::rename(&self.path, &incoming).await {
+                Ok(()) => {}
+                Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        let transactions = Self::read_transactions(&incoming).await?;
+        let unsettled = {
+            let mut state = self.state();
+            state.pending.extend(transactions);
+            state.unsettled()
+        };
+        self.persist(&unsettled).await?;
+        tokio::fs::remove_file(&incoming).await?;
+        Ok(())
+    }
+
+    /// Reads the transactions of a queue file.
+    async fn read_transactions(path: &Path) -> Result<Vec<Bytes>, SequencerTxSourceError> {
+        let contents = tokio::fs::read(path).await?;
+        Ok(Self::parse(&String::from_utf8_lossy(&contents)))
+    }
+
+    /// Atomically replaces the claimed file with the given transactions, or removes it if there
+    /// are none.
+    async fn persist(&self, transactions: &[Bytes]) -> Result<(), SequencerTxSourceError> {
+        let claimed = self.claimed_path();
+        if transactions.is_empty() {
+            return match tokio::fs::remove_file(&claimed).await {
+                Err(err) if err.kind() != ErrorKind::NotFound => Err(err.into()),
+                _ => Ok(()),
+            };
+        }
+
+        let contents = transactions.iter().map(|tx| format!("{tx}\n")).collect::<String>();
+        let tmp = self.path_with_suffix(".claimed.tmp");
+        let file = tokio::fs::File::create(&tmp).await?;
+        let mut file = tokio::io::BufWriter::new(file);
+        tokio::io::AsyncWriteExt::write_all(&mut file, contents.as_bytes()).await?;
+        tokio::io::AsyncWriteExt::flush(&mut file).await?;
+        file.into_inner().sync_all().await?;
+        tokio::fs::rename(&tmp, &claimed).await?;
+        Ok(())
+    }
+
+    /// Parses the hex-encoded transactions of a queue file, skipping malformed lines.
+    fn parse(contents: &str) -> Vec<Bytes> {
+        contents
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty())
+            .filter_map(|(number, line)| match line.parse::<Bytes>() {
+                Ok(tx) => Some(tx),
+                Err(err) => {
+                    warn!(target: "sequencer", line = number, %err, "Skipping malformed line");
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl SequencerTxSource for FileTxSource {
+    async fn next_transactions(
+        &self,
+        _: L2BlockInfo,
+        _: u64,
+    ) -> Result<Vec<Bytes>, SequencerTxSourceError> {
+        self.read_file().await?;
+
+        let mut state = self.state();
+        let transactions = state.pending.drain(..).collect::<Vec<_>>();
+        state.in_flight.extend(transactions.iter().cloned());
+        Ok(transactions)
+    }
+
+    fn requeue(&self, transactions: Vec<Bytes>) {
+        let mut state = self.state();
+        for tx in transactions.into_iter().rev() {
+            if let Some(index) = state.in_flight.iter().position(|in_flight| in_flight == &tx) {
+                state.in_flight.remove(index);
+            }
+            state.pending.push_front(tx);
+        }
+    }
+
+    async fn confirm(&self) -> Result<(), SequencerTxSourceError> {
+        let unsettled = {
+            let mut state = self.state();
+            if state.in_flight.is_empty() {
+                return Ok(());
+            }
+            state.in_flight.clear();
+            state.unsettled()
+        };
+        self.persist(&unsettled).await
+    }
+}
+
+/// Validates the supplied transactions against the state of the `parent` block, returning the
+/// transactions that can be included, in order, and the ones that must be dropped.
+///
+/// A transaction is dropped if its signer cannot be recovered, if its nonce is not the next nonce
+/// of its sender, or if its sender cannot pay for its gas limit at its maximum fee plus its value.
+/// Transactions of the same sender are checked in order, so a dropped transaction also drops the
+/// sender's following ones. The L1 data fee is not accounted for.
+pub async fn validate_transactions<P: Provider<Optimism>>(
+    provider: &P,
+    parent: &L2BlockInfo,
+    transactions: Vec<Bytes>,
+) -> Result<(Vec<Bytes>, Vec<Bytes>), SequencerTxSourceError> {
+    let block = BlockId::hash(parent.block_info.hash);
+    let mut accounts = HashMap::<Address, (u64, U256)>::new();
+    let (mut valid, mut invalid) = (Vec::new(), Vec::new());
+
+    for tx in transactions {
+        let Some((envelope, signer)) = OpTxEnvelope::decode_2718(&mut tx.as_ref())
+            .ok()
+            .and_then(|envelope| envelope.recover_signer().ok().map(|signer| (envelope, signer)))
+        else {
+            warn!(target: "sequencer", "Dropping supplied transaction with an invalid signature");
+            invalid.push(tx);
+            continue;
+        };
+
+        let (nonce, balance) = match accounts.entry(signer) {
+            Entry::Occupied(account) => account.into_mut(),
+            Entry::Vacant(account) => {
+                let nonce = provider.get_transaction_count(signer).block_id(block).await?;
+                let balance = provider.get_balance(signer).block_id(block).await?;
+                account.insert((nonce, balance))
+            }
+        };
+
+        let cost = U256::from(envelope.gas_limit())
+            .saturating_mul(U256::from(envelope.max_fee_per_gas()))
+            .saturating_add(envelope.value());
+        if envelope.nonce() != *nonce || cost > *balance {
+            warn!(
+                target: "sequencer",
+                %signer,
+                nonce = envelope.nonce(),
+                expected_nonce = *nonce,
+                %cost,
+                %balance,
+                "Dropping supplied transaction with an invalid nonce or insufficient balance"
+            );
+            invalid.push(tx);
+            continue;
+        }
+
+        *nonce += 1;
+        *balance -= cost;
+        valid.push(tx);
+    }
+
+    Ok((valid, invalid))
+}
+
+/// Limits on the data availability usage of the transactions supplied by a [`SequencerTxSource`].
+///
+/// These mirror the limits set on the execution layer through `miner_setMaxDASize`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SequencerTxLimits {
+    /// The maximum estimated DA size of a single transaction, in bytes.
+    pub max_da_tx_size: Option<u64>,
+    /// The maximum estimated DA size of all supplied transactions in a block, in bytes.
+    pub max_da_block_size: Option<u64>,
+}
+
+/// The transactions supplied by a [`SequencerTxSource`], split by [`select_transactions`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TxSelection {
+    /// Transactions to include in the block, in order.
+    pub included: Vec<Bytes>,
+    /// Transactions that did not fit into the block, to be requeued.
+    pub deferred: Vec<Bytes>,
+    /// Transactions that can never be included in a valid batch.
+    pub dropped: Vec<Bytes>,
+}
+
+/// Selects the supplied transactions to include in the block described by `attributes`.
+///
+/// Transactions that would make the block's batch invalid are dropped: empty transactions,
+/// deposits, EIP-7702 transactions before Isthmus, undecodable transactions and transactions above
+/// the per-transaction DA limit. Since Holocene, a single invalid transaction invalidates the whole
+/// span batch, so these must never reach the execution layer.
+///
+/// Transactions are then included in priority order for as long as they fit within the block gas
+/// limit left over by the deposits, the block DA limit and, after Jovian, the block DA footprint.
+/// The first transaction that does not fit and all following ones are deferred, so that the
+/// priority order (and therefore nonce order) is preserved.
+pub fn select_transactions(
+    cfg: &RollupConfig,
+    attributes: &OpPayloadAttributes,
+    limits: &SequencerTxLimits,
+    candidates: Vec<Bytes>,
+) -> TxSelection {
+    let timestamp = attributes.payload_attributes.timestamp;
+    let deposits = attributes.transactions.as_deref().unwrap_or_default();
+
+    let gas_limit = attributes.gas_limit.unwrap_or_default();
+    let mut gas_left = gas_limit.saturating_sub(
+        deposits
+            .iter()
+            .filter_map(|tx| OpTxEnvelope::decode_2718(&mut tx.as_ref()).ok())
+            .map(|tx| tx.gas_limit())
+            .sum(),
+    );
+    let mut da_left = limits.max_da_block_size.unwrap_or(u64::MAX);
+    let da_footprint_scalar =
+        cfg.is_jovian_active(timestamp).then(|| da_footprint_scalar(deposits));
+    let mut footprint_left = gas_limit;
+
+    let mut selection = TxSelection::default();
+    let mut candidates = candidates.into_iter();
+    for tx in candidates.by_ref() {
+        let valid = match tx.first() {
+            None => false,
+            Some(&ty) if ty == OpTxType::Deposit as u8 => false,
+            Some(&ty) if ty == OpTxType::Eip7702 as u8 => cfg.is_isthmus_active(timestamp),
+            Some(_) => true,
+        };
+        let Some(envelope) =
+            valid.then(|| OpTxEnvelope::decode_2718(&mut tx.as_ref()).ok()).flatten()
+        else {
+            selection.dropped.push(tx);
+            continue;
+        };
+
+        let da_size = fjord_estimated_da_size(&tx);
+        if limits.max_da_tx_size.is_some_and(|max| da_size > max) {
+            selection.dropped.push(tx);
+            continue;
+        }
+
+        let footprint = da_footprint_scalar.map_or(0, |scalar| da_size * scalar as u64);
+        if envelope.gas_limit() > gas_left || da_size > da_left || footprint > footprint_left {
+            selection.deferred.push(tx);
+            break;
+        }
+
+        gas_left -= envelope.gas_limit();
+        da_left -= da_size;
+        footprint_left -= footprint;
+        selection.included.push(tx);
+    }
+    selection.deferred.extend(candidates);
+
+    selection
+}
+
+/// Returns the DA footprint gas scalar from the L1 info deposit, the first of the deposits.
+fn da_footprint_scalar(deposits: &[Bytes]) -> u16 {
+    deposits
+        .first()
+        .and_then(|tx| OpTxEnvelope::decode_2718(&mut tx.as_ref()).ok())
+        .and_then(|tx| L1BlockInfoTx::decode_calldata(tx.input()).ok())
+        .and_then(|info| info.da_footprint())
+        .unwrap_or(L1BlockInfoJovian::DEFAULT_DA_FOOTPRINT_GAS_SCALAR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{SignableTransaction, TxEip1559, TxEip7702};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{B256, Signature, U64, keccak256};
+    use alloy_provider::RootProvider;
+    use alloy_rpc_client::RpcClient;
+    use alloy_rpc_types_engine::PayloadAttributes;
+    use alloy_signer::SignerSync;
+    use alloy_signer_local::PrivateKeySigner;
+    use alloy_transport::mock::{Asserter, MockTransport};
+    use kona_genesis::HardForkConfig;
+    use kona_protocol::BlockInfo;
+
+    /// Returns an EIP-1559 transaction with `input` bytes of incompressible calldata.
+    fn eip1559(gas_limit: u64, input: usize) -> Bytes {
+        let input = (0..input.div_ceil(32) as u64)
+            .flat_map(|i| keccak256(i.to_be_bytes()).0)
+            .take(input)
+            .collect::<Vec<_>>();
+        let tx = TxEip1559 { gas_limit, input: input.into(), ..Default::default() };
+        let sig = Signature::new(U256::from(1), U256::from(2), false);
+        OpTxEnvelope::Eip1559(tx.into_signed(sig)).encoded_2718().into()
+    }
+
+    fn attributes(timestamp: u64, gas_limit: u64) -> OpPayloadAttributes {
+        OpPayloadAttributes {
+            payload_attributes: PayloadAttributes { timestamp, ..Default::default() },
+            transactions: Some(Vec::new()),
+            gas_limit: Some(gas_limit),
+            ..Default::default()
+        }
+    }
+
+    fn isthmus_cfg() -> RollupConfig {
+        RollupConfig {
+            hardforks: HardForkConfig { isthmus_time: Some(10), ..Default::default() },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_select_drops_invalid_transactions() {
+        let cfg = isthmus_cfg();
+        let sig = Signature::new(U256::from(1), U256::from(2), false);
+        let set_code: Bytes =
+            OpTxEnvelope::Eip7702(TxEip7702::default().into_signed(sig)).encoded_2718().into();
+        let deposit = Bytes::from(vec![OpTxType::Deposit as u8, 0x01]);
+        let valid = eip1559(21_000, 0);
+        let candidates = vec![Bytes::new(), deposit, set_code.clone(), valid.clone()];
+
+        let selection = select_transactions(
+            &cfg,
+            &attributes(0, 30_000_000),
+            &SequencerTxLimits::default(),
+            candidates.clone(),
+        );
+        assert_eq!(selection.included, vec![valid.clone()]);
+        assert_eq!(selection.dropped.len(), 3);
+
+        // EIP-7702 transactions are valid after Isthmus.
+        let selection = select_transactions(
+            &cfg,
+            &attributes(10, 30_000_000),
+            &SequencerTxLimits::default(),
+            candidates,
+        );
+        assert_eq!(selection.included, vec![set_code, valid]);
+    }
+
+    #[test]
+    fn test_select_respects_gas_limit() {
+        let cfg = RollupConfig::default();
+        let (a, b, c) = (eip1559(50_000, 0), eip1559(60_000, 0), eip1559(21_000, 0));
+
+        let selection = select_transactions(
+            &cfg,
+            &attributes(0, 100_000),
+            &SequencerTxLimits::default(),
+            vec![a.clone(), b.clone(), c.clone()],
+        );
+        assert_eq!(selection.included, vec![a]);
+        // The remaining transactions are deferred in order, even though `c` would fit.
+        assert_eq!(selection.deferred, vec![b, c]);
+    }
+
+    #[test]
+    fn test_select_respects_da_limits() {
+        let cfg = RollupConfig::default();
+        let small = eip1559(21_000, 0);
+        let large = eip1559(21_000, 10_000);
+        let limits =
+            SequencerTxLimits { max_da_tx_size: Some(1_000), max_da_block_size: Some(250) };
+
+        let selection = select_transactions(
+            &cfg,
+            &attributes(0, 30_000_000),
+            &limits,
+            vec![large.clone(), small.clone(), small.clone(), small.clone()],
+        );
+        assert_eq!(selection.dropped, vec![large]);
+        assert_eq!(selection.included, vec![small.clone(), small.clone()]);
+        assert_eq!(selection.deferred, vec![small]);
+    }
+
+    /// Returns an EIP-1559 transaction signed by `signer`, costing `21_000 + value` wei.
+    fn signed(signer: &PrivateKeySigner, nonce: u64, value: u64) -> Bytes {
+        let tx = TxEip1559 {
+            chain_id: 10,
+            nonce,
+            gas_limit: 21_000,
+            max_fee_per_gas: 1,
+            value: U256::from(value),
+            ..Default::default()
+        };
+        let sig = signer.sign_hash_sync(&tx.signature_hash()).unwrap();
+        OpTxEnvelope::Eip1559(tx.into_signed(sig)).encoded_2718().into()
+    }
+
+    #[tokio::test]
+    async fn test_validate_transactions() {
+        let asserter = Asserter::new();
+        let provider = RootProvider::<Optimism>::new(RpcClient::new(
+            MockTransport::new(asserter.clone()),
+            false,
+        ));
+        let parent = L2BlockInfo {
+            block_info: BlockInfo { hash: B256::with_last_byte(1), ..Default::default() },
+            ..Default::default()
+        };
+
+        let (alice, bob) = (PrivateKeySigner::random(), PrivateKeySigner::random());
+        // Alice's nonce is 5, and she can pay for three transactions.
+        asserter.push_success(&U64::from(5));
+        asserter.push_success(&U256::from(3 * 21_000));
+        // Bob's nonce is 0, and he cannot pay for a single transaction.
+        asserter.push_success(&U64::from(0));
+        asserter.push_success(&U256::from(21_000));
+
+        let (first, second, gap) =
+            (signed(&alice, 5, 0), signed(&alice, 6, 21_000), signed(&alice, 8, 0));
+        let used = signed(&alice, 4, 0);
+        let broke = signed(&bob, 0, 1);
+
+        let (valid, invalid) = validate_transactions(
+            &provider,
+            &parent,
+            vec![first.clone(), used.clone(), second.clone(), gap.clone(), broke.clone()],
+        )
+        .await
+        .unwrap();
+        assert_eq!(valid, vec![first, second]);
+        assert_eq!(invalid, vec![used, gap, broke]);
+    }
+
+    #[tokio::test]
+    async fn test_file_tx_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("txs.hex");
+        let claimed = dir.path().join("txs.hex.claimed");
+        let source = FileTxSource::new(path.clone());
+
+        assert!(source.next_transactions(L2BlockInfo::default(), 0).await.unwrap().is_empty());
+
+        std::fs::write(&path, "0x01\n\n0x0203\n").unwrap();
+        let txs = source.next_transactions(L2BlockInfo::default(), 0).await.unwrap();
+        assert_eq!(txs, vec![Bytes::from(vec![0x01]), Bytes::from(vec![0x02, 0x03])]);
+        assert!(!path.exists());
+        // The transactions stay on disk until the block is sealed.
+        assert_eq!(std::fs::read_to_string(&claimed).unwrap(), "0x01\n0x0203\n");
+
+        source.requeue(vec![Bytes::from(vec![0x02, 0x03])]);
+        source.confirm().await.unwrap();
+        assert_eq!(std::fs::read_to_string(&claimed).unwrap(), "0x0203\n");
+
+        std::fs::write(&path, "0x04\n").unwrap();
+        let txs = source.next_transactions(L2BlockInfo::default(), 0).await.unwrap();
+        assert_eq!(txs, vec![Bytes::from(vec![0x02, 0x03]), Bytes::from(vec![0x04])]);
+        source.confirm().await.unwrap();
+        assert!(!claimed.exists());
+    }
+
+    #[tokio::test]
+    async fn test_file_tx_source_skips_malformed_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("txs");
+        let source = FileTxSource::new(path.clone());
+
+        std::fs::write(&path, "0x01\nnot hex\n0x0\n0x02\n").unwrap();
+        let txs = source.next_transactions(L2BlockInfo::default(), 0).await.unwrap();
+        assert_eq!(txs, vec![Bytes::from(vec![0x01]), Bytes::from(vec![0x02])]);
+    }
+
+    #[tokio::test]
+    async fn test_file_tx_source_reads_unsettled_transactions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("txs.hex");
+        let claimed = dir.path().join("txs.hex.claimed");
+        let incoming = dir.path().join("txs.hex.incoming");
+        std::fs::write(&claimed, "0x01\n").unwrap();
+        std::fs::write(&incoming, "0x02\n").unwrap();
+        std::fs::write(&path, "0x03\n").unwrap();
+
+        let source = FileTxSource::new(path.clone());
+        let txs = source.next_transactions(L2BlockInfo::default(), 0).await.unwrap();
+        assert_eq!(txs, vec![Bytes::from(vec![0x01]), Bytes::from(vec![0x02])]);
+        assert!(!incoming.exists());
+        assert!(path.exists());
+
+        let txs = source.next_transactions(L2BlockInfo::default(), 0).await.unwrap();
+        assert_eq!(txs, vec![Bytes::from(vec![0x03])]);
+        assert_eq!(std::fs::read_to_string(&claimed).unwrap(), "0x01\n0x02\n0x03\n");
+
+        source.confirm().await.unwrap();
+        assert!(!claimed.exists());
+    }
+}
@@ -29,6 +29,10 @@ impl Metrics {
     pub const SEQUENCER_CONDUCTOR_COMMITMENT_DURATION: &str =
         "kona_node_sequencer_conductor_commitment_duration";
 
+    /// Gauge for the number of transactions supplied by the sequencer transaction source for the
+    /// last block, labeled by whether they were included, deferred or dropped.
+    pub const SEQUENCER_SOURCE_TRANSACTIONS: &str = "kona_node_sequencer_source_transactions";
+
     /// Initializes metrics for the node service.
     ///
     /// This does two things:
@@ -75,6 +79,12 @@ impl Metrics {
             Self::SEQUENCER_CONDUCTOR_COMMITMENT_DURATION,
             "Duration of the sequencer conductor commitment"
         );
+
+        // Sequencer transaction source
+        metrics::describe_gauge!(
+            Self::SEQUENCER_SOURCE_TRANSACTIONS,
+            "Transactions supplied by the sequencer transaction source for the last block"
+        );
     }
 
     /// Initializes metrics to `0` so they can be queried immediately by consumers of prometheus
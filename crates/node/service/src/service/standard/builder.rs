@@ -1,6 +1,9 @@
 //! Contains the builder for the [`RollupNode`].
 
-use crate::{EngineBuilder, InteropMode, NetworkConfig, NodeMode, RollupNode, SequencerConfig};
+use crate::{
+    EngineBuilder, InteropMode, NetworkConfig, NodeMode, RollupNode, SequencerConfig,
+    SequencerTxSource,
+};
 use alloy_primitives::Bytes;
 use alloy_provider::RootProvider;
 use alloy_rpc_client::RpcClient;
@@ -11,10 +14,11 @@ use alloy_transport_http::{
 };
 use http_body_util::Full;
 use op_alloy_network::Optimism;
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 use tower::ServiceBuilder;
 use url::Url;
 
+use kona_engine::{EngineEndpoint, SyncMode};
 use kona_genesis::{L1ChainConfig, RollupConfig};
 use kona_providers_alloy::OnlineBeaconClient;
 use kona_rpc::RpcBuilder;
@@ -36,6 +40,10 @@ pub struct RollupNodeBuilder {
     l2_engine_rpc_url: Option<Url>,
     /// Whether to trust the L2 RPC.
     l2_trust_rpc: bool,
+    /// An optional path to the L2 engine IPC socket.
+    l2_engine_ipc: Option<PathBuf>,
+    /// An optional shadow execution layer.
+    shadow_engine: Option<EngineEndpoint>,
     /// The JWT secret.
     jwt_secret: Option<JwtSecret>,
     /// The [`NetworkConfig`].
@@ -44,8 +52,12 @@ pub struct RollupNodeBuilder {
     rpc_config: Option<RpcBuilder>,
     /// The [`SequencerConfig`].
     sequencer_config: Option<SequencerConfig>,
+    /// A custom [`SequencerTxSource`].
+    sequencer_tx_source: Option<Arc<dyn SequencerTxSource>>,
     /// The mode to run the node in.
     mode: NodeMode,
+    /// The strategy used to sync the execution layer.
+    sync_mode: SyncMode,
     /// Whether to run the node in interop mode.
     interop_mode: InteropMode,
 }
@@ -61,6 +73,11 @@ impl RollupNodeBuilder {
         Self { mode, ..self }
     }
 
+    /// Sets the [`SyncMode`] on the [`RollupNodeBuilder`].
+    pub fn with_sync_mode(self, sync_mode: SyncMode) -> Self {
+        Self { sync_mode, ..self }
+    }
+
     /// Appends an L1 EL provider RPC URL to the builder.
     pub fn with_l1_provider_rpc_url(self, l1_provider_rpc_url: Url) -> Self {
         Self { l1_provider_rpc_url: Some(l1_provider_rpc_url), ..self }
@@ -86,6 +103,17 @@ impl RollupNodeBuilder {
         Self { l2_trust_rpc, ..self }
     }
 
+    /// Sends Engine API calls over the IPC socket at the given path instead of the L2 engine RPC
+    /// URL.
+    pub fn with_l2_engine_ipc(self, l2_engine_ipc: Option<PathBuf>) -> Self {
+        Self { l2_engine_ipc, ..self }
+    }
+
+    /// Mirrors Engine API calls to a shadow execution layer at the given [`EngineEndpoint`].
+    pub fn with_shadow_engine(self, shadow_engine: Option<EngineEndpoint>) -> Self {
+        Self { shadow_engine, ..self }
+    }
+
     /// Appends a JWT secret to the builder.
     pub fn with_jwt_secret(self, jwt_secret: JwtSecret) -> Self {
         Self { jwt_secret: Some(jwt_secret), ..self }
@@ -106,6 +134,12 @@ impl RollupNodeBuilder {
         Self { sequencer_config: Some(sequencer_config), ..self }
     }
 
+    /// Sets a custom [`SequencerTxSource`], supplying transactions that the sequencer includes
+    /// ahead of the transaction pool. Overrides the transaction source of the [`SequencerConfig`].
+    pub fn with_sequencer_tx_source(self, tx_source: Arc<dyn SequencerTxSource>) -> Self {
+        Self { sequencer_tx_source: Some(tx_source), ..self }
+    }
+
     /// Assembles the [`RollupNode`] service.
     ///
     /// ## Panics
@@ -143,7 +177,10 @@ impl RollupNodeBuilder {
             l1_rpc_url,
             engine_url,
             jwt_secret,
+            engine_ipc: self.l2_engine_ipc,
+            shadow: self.shadow_engine,
             mode: self.mode,
+            sync_mode: self.sync_mode,
         };
 
         let p2p_config = self.p2p_config.expect("P2P config not set");
@@ -162,6 +199,7 @@ impl RollupNodeBuilder {
             rpc_builder: self.rpc_config,
             p2p_config,
             sequencer_config,
+            sequencer_tx_source: self.sequencer_tx_source,
         }
     }
 }
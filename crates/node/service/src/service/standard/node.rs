@@ -2,7 +2,7 @@
 use crate::{
     DerivationActor, DerivationBuilder, EngineActor, EngineBuilder, InteropMode, L1WatcherRpc,
     L1WatcherRpcState, NetworkActor, NetworkBuilder, NetworkConfig, NodeMode, RollupNodeBuilder,
-    RollupNodeService, RpcActor, SequencerConfig,
+    RollupNodeService, RpcActor, SequencerConfig, SequencerTxSource,
     actors::{SequencerActor, SequencerBuilder},
 };
 use alloy_provider::RootProvider;
@@ -45,6 +45,8 @@ pub struct RollupNode {
     pub(crate) p2p_config: NetworkConfig,
     /// The [`SequencerConfig`] for the node.
     pub(crate) sequencer_config: SequencerConfig,
+    /// A custom [`SequencerTxSource`], overriding the one from the [`SequencerConfig`].
+    pub(crate) sequencer_tx_source: Option<Arc<dyn SequencerTxSource>>,
 }
 
 impl RollupNode {
@@ -89,6 +91,10 @@ impl RollupNodeService for RollupNode {
             l1_trust_rpc: self.l1_trust_rpc,
             l2_provider: self.l2_provider.clone(),
             l2_trust_rpc: self.l2_trust_rpc,
+            tx_source: self
+                .sequencer_tx_source
+                .clone()
+                .unwrap_or_else(|| self.sequencer_config.tx_source()),
         }
     }
 
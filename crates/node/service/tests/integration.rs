@@ -2,3 +2,6 @@
 
 /// Tests for the node actors.
 mod actors;
+
+/// An in-process multi-node test network.
+mod testnet;
@@ -0,0 +1,209 @@
+//! A builder for the [`TestNet`].
+
+use std::{
+    net::{Ipv4Addr, TcpListener},
+    time::Duration,
+};
+
+use alloy_chains::Chain;
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{Address, B256, U256};
+use alloy_rpc_types_engine::JwtSecret;
+use alloy_signer::k256;
+use alloy_signer_local::PrivateKeySigner;
+use jsonrpsee::http_client::HttpClientBuilder;
+use kona_disc::LocalNode;
+use kona_engine::SyncMode;
+use kona_genesis::{ChainGenesis, HardForkConfig, L1ChainConfig, RollupConfig, SystemConfig};
+use kona_node_service::{NetworkConfig, NodeMode, RollupNode, RollupNodeService, SequencerConfig};
+use kona_rpc::RpcBuilder;
+use kona_sources::BlockSigner;
+use libp2p::{
+    Multiaddr,
+    identity::{Keypair, secp256k1},
+    multiaddr::Protocol,
+};
+
+use crate::testnet::{
+    TestNet, TestNode,
+    mocks::{ExecutionNetwork, L1_CHAIN_ID, MockBeacon, MockEngine, MockL1},
+};
+
+/// The L2 chain ID of the test network.
+const L2_CHAIN_ID: u64 = 901;
+
+/// The private key of the unsafe block signer, shared by all the sequencers of the network.
+const SEQUENCER_KEY: B256 = B256::repeat_byte(0x5e);
+
+/// A builder for the [`TestNet`].
+#[derive(Debug)]
+pub(crate) struct TestNetBuilder {
+    /// The number of nodes in the network.
+    nodes: usize,
+}
+
+impl TestNetBuilder {
+    /// Creates a new [`TestNetBuilder`] for a network of two nodes.
+    pub(crate) const fn new() -> Self {
+        Self { nodes: 2 }
+    }
+
+    /// Sets the number of nodes in the network.
+    pub(crate) const fn with_nodes(mut self, nodes: usize) -> Self {
+        self.nodes = nodes;
+        self
+    }
+
+    /// Returns the rollup config of the network: every hardfork up to Ecotone is active at
+    /// genesis, and the sequencing window is long enough for no batch to ever be required.
+    fn rollup_config(l1: &MockL1, l2_genesis: B256, l2_time: u64) -> RollupConfig {
+        let (l1_genesis, _) = l1.genesis();
+        RollupConfig {
+            genesis: ChainGenesis {
+                l1: l1_genesis,
+                l2: BlockNumHash { number: 0, hash: l2_genesis },
+                l2_time,
+                system_config: Some(SystemConfig {
+                    batcher_address: Address::repeat_byte(0xba),
+                    scalar: U256::from(1),
+                    gas_limit: 30_000_000,
+                    base_fee_scalar: Some(0),
+                    blob_base_fee_scalar: Some(0),
+                    ..Default::default()
+                }),
+            },
+            block_time: 1,
+            max_sequencer_drift: 600,
+            seq_window_size: 3600,
+            channel_timeout: 300,
+            l1_chain_id: L1_CHAIN_ID,
+            l2_chain_id: Chain::from_id(L2_CHAIN_ID),
+            hardforks: HardForkConfig {
+                regolith_time: Some(0),
+                canyon_time: Some(0),
+                delta_time: Some(0),
+                ecotone_time: Some(0),
+                ..Default::default()
+            },
+            batch_inbox_address: Address::repeat_byte(0xff),
+            deposit_contract_address: Address::repeat_byte(0xde),
+            l1_system_config_address: Address::repeat_byte(0x5c),
+            ..Default::default()
+        }
+    }
+
+    /// Returns a deterministic secp256k1 key for the node at the given index.
+    fn node_key(index: usize) -> anyhow::Result<secp256k1::SecretKey> {
+        let mut bytes = B256::with_last_byte(index as u8 + 1).0;
+        Ok(secp256k1::SecretKey::try_from_bytes(&mut bytes)?)
+    }
+
+    /// Builds and starts the node at the given index.
+    ///
+    /// Every node runs in sequencer mode so that leadership can be handed over to any of them, but
+    /// only the first node's sequencer is started.
+    async fn node(
+        index: usize,
+        config: &RollupConfig,
+        l1: &MockL1,
+        beacon: &MockBeacon,
+        engine: MockEngine,
+    ) -> anyhow::Result<TestNode> {
+        let secret = Self::node_key(index)?;
+        let keypair: Keypair = secp256k1::Keypair::from(secret.clone()).into();
+        let peer_id = keypair.public().to_peer_id();
+        let signing_key = k256::ecdsa::SigningKey::from_slice(&secret.to_bytes())?;
+
+        // Each node listens on its own in-memory address. Memory ports are global to the process,
+        // so they are picked at random to avoid clashes between tests running concurrently.
+        let gossip_addr = Multiaddr::empty().with(Protocol::Memory(rand::random::<u64>() | 1));
+
+        let signer = PrivateKeySigner::from_bytes(&SEQUENCER_KEY)?;
+        let mut p2p_config = NetworkConfig::new(
+            config.clone(),
+            LocalNode::new(signing_key, Ipv4Addr::LOCALHOST.into(), 0, 0),
+            gossip_addr.clone(),
+            signer.address(),
+        );
+        p2p_config.keypair = keypair;
+        p2p_config.enr_update = false;
+        p2p_config.gossip_signer = Some(BlockSigner::Local(signer));
+        p2p_config.memory_transport = true;
+
+        // The RPC listener is bound here rather than by the node, so that its port is known
+        // before the node starts and cannot be taken by another test in the meantime.
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        listener.set_nonblocking(true)?;
+        let rpc_config = RpcBuilder {
+            no_restart: true,
+            socket: listener.local_addr()?,
+            listener: None,
+            enable_admin: true,
+            admin_persistence: None,
+            ws_enabled: false,
+            dev_enabled: false,
+        }
+        .with_listener(listener)?;
+        let rpc_addr = rpc_config.socket();
+
+        let sequencer_config = SequencerConfig {
+            sequencer_stopped: index != 0,
+            l1_conf_delay: 0,
+            ..Default::default()
+        };
+
+        let node = RollupNode::builder(config.clone(), L1ChainConfig::default())
+            .with_mode(NodeMode::Sequencer)
+            .with_sync_mode(SyncMode::ConsensusLayer)
+            .with_l1_provider_rpc_url(l1.url())
+            .with_l1_trust_rpc(true)
+            .with_l1_beacon_api_url(beacon.url())
+            .with_l2_engine_rpc_url(engine.url())
+            .with_l2_trust_rpc(true)
+            .with_jwt_secret(JwtSecret::random())
+            .with_p2p_config(p2p_config)
+            .with_rpc_config(Some(rpc_config))
+            .with_sequencer_config(sequencer_config)
+            .build();
+        let handle = tokio::spawn(async move { node.start().await });
+
+        let rpc = HttpClientBuilder::default()
+            .request_timeout(Duration::from_secs(30))
+            .build(format!("http://{rpc_addr}"))?;
+
+        Ok(TestNode::new(index, engine, rpc, peer_id, gossip_addr, handle))
+    }
+
+    /// Builds and starts the [`TestNet`], returning once every node serves its RPC API and the
+    /// nodes are fully connected.
+    pub(crate) async fn build(self) -> anyhow::Result<TestNet> {
+        let l1 = MockL1::start().await?;
+        let (_, l1_genesis_time) = l1.genesis();
+        let beacon = MockBeacon::start(l1_genesis_time).await?;
+
+        // The L2 genesis is shared by all the execution clients.
+        let genesis = MockEngine::genesis(l1_genesis_time);
+        let config = Self::rollup_config(&l1, genesis.header.hash_slow(), l1_genesis_time);
+        let execution_network = ExecutionNetwork::default();
+
+        let mut nodes = Vec::with_capacity(self.nodes);
+        for index in 0..self.nodes {
+            let engine = MockEngine::start(
+                L2_CHAIN_ID,
+                genesis.clone(),
+                config.genesis,
+                execution_network.clone(),
+            )
+            .await?;
+            nodes.push(Self::node(index, &config, &l1, &beacon, engine).await?);
+        }
+
+        let net = TestNet::new(l1, beacon, nodes);
+        for node in &net.nodes {
+            node.wait_for_rpc().await?;
+        }
+        net.connect_all().await?;
+
+        Ok(net)
+    }
+}
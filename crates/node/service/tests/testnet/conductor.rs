@@ -0,0 +1,29 @@
+//! Tests that sequencing can be handed over between nodes without forking the unsafe chain.
+
+use crate::testnet::TestNetBuilder;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn leadership_transfer_keeps_the_chain_continuous() -> anyhow::Result<()> {
+    let net = TestNetBuilder::new().with_nodes(3).build().await?;
+    net.nodes[0].wait_for_unsafe(3).await?;
+
+    // Hand leadership around the whole network and back to the first node.
+    let mut leader = 0;
+    for next in [1, 2, 0] {
+        let last = net.transfer_leadership(leader, next).await?;
+        assert!(!net.nodes[leader].sequencer_active().await?);
+        assert!(net.nodes[next].sequencer_active().await?);
+
+        // The new leader builds on top of the previous leader's last block, and every node follows
+        // its chain.
+        let head = net.nodes[next].wait_for_unsafe(last.block_info.number + 2).await?;
+        for node in &net.nodes {
+            node.wait_for_unsafe(head.block_info.number).await?;
+            assert_eq!(node.engine.block(last.block_info.number), Some(last.block_info.id()));
+            assert_eq!(node.engine.block(head.block_info.number), Some(head.block_info.id()));
+        }
+        leader = next;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+//! Tests that followers track the sequencer's unsafe chain over gossip.
+
+use crate::testnet::TestNetBuilder;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn followers_track_the_unsafe_chain() -> anyhow::Result<()> {
+    let net = TestNetBuilder::new().with_nodes(3).build().await?;
+    let leader = &net.nodes[0];
+
+    let head = leader.wait_for_unsafe(5).await?;
+    for follower in &net.nodes[1..] {
+        follower.wait_for_unsafe(head.block_info.number).await?;
+        assert_eq!(follower.engine.block(head.block_info.number), Some(head.block_info.id()));
+        assert!(!follower.sequencer_active().await?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+//! A mock L1 beacon node.
+//!
+//! The test network never posts batches to L1, so blobs are never fetched. The blob provider
+//! still loads the beacon genesis time and slot interval on startup, which this mock serves.
+
+use kona_providers_alloy::{APIConfigResponse, APIGenesisResponse, ReducedGenesisData};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    task::JoinHandle,
+};
+use url::Url;
+
+/// The beacon chain slot interval, in seconds.
+const SECONDS_PER_SLOT: u64 = 12;
+
+/// A mock beacon node answering the beacon genesis and config spec endpoints.
+#[derive(Debug)]
+pub(crate) struct MockBeacon {
+    url: Url,
+    handle: JoinHandle<()>,
+}
+
+impl MockBeacon {
+    /// Starts the mock beacon node with the given genesis time.
+    pub(crate) async fn start(genesis_time: u64) -> anyhow::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = Url::parse(&format!("http://{}", listener.local_addr()?))?;
+
+        let genesis = serde_json::to_string(&APIGenesisResponse {
+            data: ReducedGenesisData { genesis_time },
+        })?;
+        let spec = serde_json::to_string(&APIConfigResponse::new(SECONDS_PER_SLOT))?;
+
+        let handle = tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let (genesis, spec) = (genesis.clone(), spec.clone());
+                tokio::spawn(async move {
+                    let mut buf = vec![0; 4096];
+                    let Ok(read) = stream.read(&mut buf).await else { return };
+                    let request = String::from_utf8_lossy(&buf[..read]);
+                    let path = request.split_whitespace().nth(1).unwrap_or_default();
+
+                    let (status, body) = if path.ends_with("/beacon/genesis") {
+                        ("200 OK", genesis)
+                    } else if path.ends_with("/config/spec") {
+                        ("200 OK", spec)
+                    } else {
+                        ("404 Not Found", String::new())
+                    };
+                    let response = format!(
+                        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        Ok(Self { url, handle })
+    }
+
+    /// Returns the URL of the beacon API.
+    pub(crate) fn url(&self) -> Url {
+        self.url.clone()
+    }
+}
+
+impl Drop for MockBeacon {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
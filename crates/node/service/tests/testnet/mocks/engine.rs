@@ -0,0 +1,447 @@
+//! A mock L2 execution client.
+//!
+//! The mock implements the subset of the Engine API and the `eth` namespace that the rollup node
+//! uses post-Ecotone. It does not execute transactions: blocks are built from the payload
+//! attributes as-is, with a state root derived from the parent's so that every node that builds or
+//! imports the same chain ends up with the same block hashes.
+//!
+//! The mock execution clients of a test network share the blocks they know of through an
+//! [`ExecutionNetwork`], which stands in for the execution layer's own peer-to-peer network: a node
+//! that missed blocks, e.g. during a partition of the consensus layer network, backfills them from
+//! there when it receives a block whose parent it does not know, as a real execution client would.
+
+use alloy_consensus::{
+    EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH, Header, constants::EMPTY_WITHDRAWALS,
+    proofs::calculate_transaction_root, transaction::Recovered,
+};
+use alloy_eips::{BlockNumHash, BlockNumberOrTag, Decodable2718, Encodable2718};
+use alloy_primitives::{Address, B256, Sealable, U64, U256, keccak256};
+use alloy_rpc_types_engine::{
+    BlobsBundleV1, CancunPayloadFields, ExecutionPayloadV3, ForkchoiceState, ForkchoiceUpdated,
+    PayloadId, PayloadStatus, PayloadStatusEnum,
+};
+use alloy_rpc_types_eth::{Block, BlockTransactions, Header as RpcHeader};
+use jsonrpsee::{RpcModule, server::ServerHandle, types::ErrorObjectOwned};
+use kona_genesis::ChainGenesis;
+use kona_protocol::L2BlockInfo;
+use op_alloy_consensus::{OpBlock, OpTxEnvelope};
+use op_alloy_rpc_types_engine::{
+    OpExecutionPayload, OpExecutionPayloadEnvelopeV3, OpExecutionPayloadSidecar,
+    OpPayloadAttributes,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+};
+use tokio::sync::watch;
+use url::Url;
+
+use crate::testnet::TIMEOUT;
+
+/// The gas limit of the genesis block.
+const GENESIS_GAS_LIMIT: u64 = 30_000_000;
+
+/// Returns the state root of the child of a block with the given state root.
+fn next_state_root(parent_state_root: B256, number: u64) -> B256 {
+    keccak256([parent_state_root.as_slice(), &number.to_be_bytes()].concat())
+}
+
+/// Returns an invalid params error with the given message.
+fn invalid_params(msg: impl Into<String>) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(-32602, msg.into(), None::<()>)
+}
+
+/// The blocks known to the execution layer network, by hash.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ExecutionNetwork(Arc<Mutex<HashMap<B256, OpBlock>>>);
+
+impl ExecutionNetwork {
+    fn publish(&self, hash: B256, block: &OpBlock) {
+        self.0.lock().unwrap().entry(hash).or_insert_with(|| block.clone());
+    }
+
+    fn get(&self, hash: &B256) -> Option<OpBlock> {
+        self.0.lock().unwrap().get(hash).cloned()
+    }
+}
+
+/// The state of the mock execution client.
+#[derive(Debug)]
+struct L2Chain {
+    /// The execution layer network the client backfills missing blocks from.
+    network: ExecutionNetwork,
+    /// The genesis of the rollup, used to decode the L1 origin of blocks.
+    genesis: ChainGenesis,
+    /// Notified of the canonical head every time a forkchoice update changes it.
+    head: watch::Sender<L2BlockInfo>,
+    /// All known blocks, canonical or not.
+    blocks: HashMap<B256, OpBlock>,
+    /// The canonical chain, from number to hash.
+    canonical: BTreeMap<u64, B256>,
+    /// The forkchoice state set by the last `engine_forkchoiceUpdated` call.
+    forkchoice: ForkchoiceState,
+    /// Payloads built upon request of `engine_forkchoiceUpdated`, by payload id.
+    payloads: HashMap<PayloadId, OpBlock>,
+    /// The id of the next payload.
+    next_payload_id: u64,
+}
+
+impl L2Chain {
+    fn new(
+        genesis: OpBlock,
+        rollup_genesis: ChainGenesis,
+        network: ExecutionNetwork,
+    ) -> anyhow::Result<Self> {
+        let hash = genesis.header.hash_slow();
+        network.publish(hash, &genesis);
+        let (head, _) =
+            watch::channel(L2BlockInfo::from_block_and_genesis(&genesis, &rollup_genesis)?);
+        Ok(Self {
+            network,
+            genesis: rollup_genesis,
+            head,
+            blocks: HashMap::from([(hash, genesis)]),
+            canonical: BTreeMap::from([(0, hash)]),
+            forkchoice: ForkchoiceState {
+                head_block_hash: hash,
+                safe_block_hash: hash,
+                finalized_block_hash: hash,
+            },
+            payloads: HashMap::new(),
+            next_payload_id: 0,
+        })
+    }
+
+    /// Makes the given block the canonical head, rewinding the canonical chain if needed.
+    fn set_head(&mut self, head: B256) {
+        self.canonical.clear();
+        let mut cursor = self.blocks.get(&head);
+        while let Some(block) = cursor {
+            self.canonical.insert(block.header.number, block.header.hash_slow());
+            cursor = self.blocks.get(&block.header.parent_hash);
+        }
+    }
+
+    fn by_tag(&self, tag: BlockNumberOrTag) -> Option<&OpBlock> {
+        let hash = match tag {
+            BlockNumberOrTag::Latest | BlockNumberOrTag::Pending => self.forkchoice.head_block_hash,
+            BlockNumberOrTag::Safe => self.forkchoice.safe_block_hash,
+            BlockNumberOrTag::Finalized => self.forkchoice.finalized_block_hash,
+            BlockNumberOrTag::Earliest => *self.canonical.get(&0)?,
+            BlockNumberOrTag::Number(number) => *self.canonical.get(&number)?,
+        };
+        self.blocks.get(&hash)
+    }
+
+    /// Builds a block on top of the given parent from the payload attributes.
+    fn build(&self, parent: &OpBlock, attributes: OpPayloadAttributes) -> Result<OpBlock, String> {
+        let transactions = attributes
+            .transactions
+            .unwrap_or_default()
+            .iter()
+            .map(|tx| OpTxEnvelope::decode_2718(&mut tx.as_ref()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("invalid transaction: {e}"))?;
+
+        let number = parent.header.number + 1;
+        let header = Header {
+            parent_hash: parent.header.hash_slow(),
+            ommers_hash: EMPTY_OMMER_ROOT_HASH,
+            beneficiary: attributes.payload_attributes.suggested_fee_recipient,
+            state_root: next_state_root(parent.header.state_root, number),
+            transactions_root: calculate_transaction_root(&transactions),
+            receipts_root: EMPTY_ROOT_HASH,
+            withdrawals_root: Some(EMPTY_WITHDRAWALS),
+            number,
+            gas_limit: attributes.gas_limit.unwrap_or(parent.header.gas_limit),
+            timestamp: attributes.payload_attributes.timestamp,
+            mix_hash: attributes.payload_attributes.prev_randao,
+            base_fee_per_gas: Some(1),
+            blob_gas_used: Some(0),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: attributes.payload_attributes.parent_beacon_block_root,
+            ..Default::default()
+        };
+
+        Ok(OpBlock {
+            header,
+            body: alloy_consensus::BlockBody {
+                transactions,
+                ommers: vec![],
+                withdrawals: Some(Default::default()),
+            },
+        })
+    }
+
+    fn forkchoice_updated(
+        &mut self,
+        state: ForkchoiceState,
+        attributes: Option<OpPayloadAttributes>,
+    ) -> Result<ForkchoiceUpdated, ErrorObjectOwned> {
+        let Some(head) = self.blocks.get(&state.head_block_hash).cloned() else {
+            return Ok(ForkchoiceUpdated::from_status(PayloadStatusEnum::Syncing));
+        };
+
+        let info = L2BlockInfo::from_block_and_genesis(&head, &self.genesis)
+            .map_err(|e| invalid_params(format!("invalid head block: {e}")))?;
+
+        self.forkchoice = state;
+        self.set_head(state.head_block_hash);
+        self.head.send_if_modified(|current| std::mem::replace(current, info) != info);
+
+        let updated = ForkchoiceUpdated::new(PayloadStatus::new(
+            PayloadStatusEnum::Valid,
+            Some(state.head_block_hash),
+        ));
+        let Some(attributes) = attributes else {
+            return Ok(updated);
+        };
+
+        let block = self.build(&head, attributes).map_err(invalid_params)?;
+        let id = PayloadId::new(self.next_payload_id.to_be_bytes());
+        self.next_payload_id += 1;
+        self.payloads.insert(id, block);
+        Ok(updated.with_payload_id(id))
+    }
+
+    fn new_payload(
+        &mut self,
+        payload: ExecutionPayloadV3,
+        parent_beacon_block_root: B256,
+    ) -> PayloadStatus {
+        let expected_hash = payload.payload_inner.payload_inner.block_hash;
+        let block: OpBlock = match OpExecutionPayload::V3(payload).try_into_block_with_sidecar(
+            &OpExecutionPayloadSidecar::v3(CancunPayloadFields::new(
+                parent_beacon_block_root,
+                vec![],
+            )),
+        ) {
+            Ok(block) => block,
+            Err(e) => {
+                return PayloadStatus::from_status(PayloadStatusEnum::Invalid {
+                    validation_error: e.to_string(),
+                });
+            }
+        };
+
+        let hash = block.header.hash_slow();
+        if hash != expected_hash {
+            return PayloadStatus::from_status(PayloadStatusEnum::Invalid {
+                validation_error: format!("block hash mismatch: {hash} != {expected_hash}"),
+            });
+        }
+
+        if !self.backfill(block.header.parent_hash) {
+            return PayloadStatus::from_status(PayloadStatusEnum::Syncing);
+        }
+        if let Err(validation_error) = self.import(hash, block) {
+            return PayloadStatus::from_status(PayloadStatusEnum::Invalid { validation_error });
+        }
+        PayloadStatus::new(PayloadStatusEnum::Valid, Some(hash))
+    }
+
+    /// Imports a block whose parent is known, publishing it to the [`ExecutionNetwork`].
+    fn import(&mut self, hash: B256, block: OpBlock) -> Result<(), String> {
+        let parent = self.blocks.get(&block.header.parent_hash).ok_or("unknown parent")?;
+        if block.header.state_root != next_state_root(parent.header.state_root, block.header.number)
+        {
+            return Err("state root mismatch".to_string());
+        }
+
+        self.network.publish(hash, &block);
+        self.blocks.insert(hash, block);
+        Ok(())
+    }
+
+    /// Imports the given block and its unknown ancestors from the [`ExecutionNetwork`]. Returns
+    /// whether the block is known afterwards.
+    fn backfill(&mut self, hash: B256) -> bool {
+        let mut missing = vec![];
+        let mut cursor = hash;
+        while !self.blocks.contains_key(&cursor) {
+            let Some(block) = self.network.get(&cursor) else {
+                return false;
+            };
+            let parent = block.header.parent_hash;
+            missing.push((cursor, block));
+            cursor = parent;
+        }
+
+        missing.into_iter().rev().all(|(hash, block)| self.import(hash, block).is_ok())
+    }
+}
+
+/// Converts a block into an RPC block.
+fn rpc_block(block: &OpBlock, full: bool) -> Block<op_alloy_rpc_types::Transaction> {
+    let hash = block.header.hash_slow();
+    let transactions = block.body.transactions.iter();
+    let transactions = if full {
+        BlockTransactions::Full(
+            transactions
+                .enumerate()
+                .map(|(index, tx)| {
+                    // Only deposits are ever included: the test network has no transaction pool.
+                    let signer = match tx {
+                        OpTxEnvelope::Deposit(deposit) => deposit.from,
+                        _ => Address::ZERO,
+                    };
+                    op_alloy_rpc_types::Transaction {
+                        inner: alloy_rpc_types_eth::Transaction {
+                            inner: Recovered::new_unchecked(tx.clone(), signer),
+                            block_hash: Some(hash),
+                            block_number: Some(block.header.number),
+                            transaction_index: Some(index as u64),
+                            effective_gas_price: Some(0),
+                        },
+                        deposit_nonce: None,
+                        deposit_receipt_version: None,
+                    }
+                })
+                .collect(),
+        )
+    } else {
+        BlockTransactions::Hashes(transactions.map(|tx| keccak256(tx.encoded_2718())).collect())
+    };
+
+    Block {
+        header: RpcHeader::from_consensus(block.header.clone().seal_slow(), None, None),
+        uncles: vec![],
+        transactions,
+        withdrawals: block.body.withdrawals.clone(),
+    }
+}
+
+/// A mock L2 execution client.
+///
+/// The JWT sent by the rollup node is not checked.
+#[derive(Debug)]
+pub(crate) struct MockEngine {
+    chain: Arc<Mutex<L2Chain>>,
+    head: watch::Receiver<L2BlockInfo>,
+    url: Url,
+    _server: ServerHandle,
+}
+
+impl MockEngine {
+    /// Returns the genesis block shared by all the execution clients of a test network.
+    pub(crate) fn genesis(timestamp: u64) -> OpBlock {
+        OpBlock {
+            header: Header {
+                ommers_hash: EMPTY_OMMER_ROOT_HASH,
+                state_root: keccak256("genesis"),
+                transactions_root: EMPTY_ROOT_HASH,
+                receipts_root: EMPTY_ROOT_HASH,
+                withdrawals_root: Some(EMPTY_WITHDRAWALS),
+                gas_limit: GENESIS_GAS_LIMIT,
+                timestamp,
+                base_fee_per_gas: Some(1),
+                blob_gas_used: Some(0),
+                excess_blob_gas: Some(0),
+                parent_beacon_block_root: Some(B256::ZERO),
+                ..Default::default()
+            },
+            body: alloy_consensus::BlockBody {
+                transactions: vec![],
+                ommers: vec![],
+                withdrawals: Some(Default::default()),
+            },
+        }
+    }
+
+    /// Starts a mock execution client with the given chain ID and genesis block, connected to the
+    /// given [`ExecutionNetwork`].
+    pub(crate) async fn start(
+        chain_id: u64,
+        genesis: OpBlock,
+        rollup_genesis: ChainGenesis,
+        network: ExecutionNetwork,
+    ) -> anyhow::Result<Self> {
+        let chain = L2Chain::new(genesis, rollup_genesis, network)?;
+        let head = chain.head.subscribe();
+        let chain = Arc::new(Mutex::new(chain));
+
+        let mut module = RpcModule::new(Arc::clone(&chain));
+        module.register_method("eth_chainId", move |_, _, _| {
+            Ok::<_, ErrorObjectOwned>(U64::from(chain_id))
+        })?;
+        module.register_method("eth_blockNumber", |_, chain, _| {
+            let chain = chain.lock().unwrap();
+            let head = chain.by_tag(BlockNumberOrTag::Latest).map_or(0, |b| b.header.number);
+            Ok::<_, ErrorObjectOwned>(U64::from(head))
+        })?;
+        module.register_method("eth_getBlockByNumber", |params, chain, _| {
+            let (tag, full) = params.parse::<(BlockNumberOrTag, bool)>()?;
+            let chain = chain.lock().unwrap();
+            Ok::<_, ErrorObjectOwned>(chain.by_tag(tag).map(|block| rpc_block(block, full)))
+        })?;
+        module.register_method("eth_getBlockByHash", |params, chain, _| {
+            let (hash, full) = params.parse::<(B256, bool)>()?;
+            let chain = chain.lock().unwrap();
+            Ok::<_, ErrorObjectOwned>(chain.blocks.get(&hash).map(|block| rpc_block(block, full)))
+        })?;
+        module.register_method("engine_forkchoiceUpdatedV3", |params, chain, _| {
+            let (state, attributes) =
+                params.parse::<(ForkchoiceState, Option<OpPayloadAttributes>)>()?;
+            chain.lock().unwrap().forkchoice_updated(state, attributes)
+        })?;
+        module.register_method("engine_newPayloadV3", |params, chain, _| {
+            let (payload, _versioned_hashes, parent_beacon_block_root) =
+                params.parse::<(ExecutionPayloadV3, Vec<B256>, B256)>()?;
+            Ok::<_, ErrorObjectOwned>(
+                chain.lock().unwrap().new_payload(payload, parent_beacon_block_root),
+            )
+        })?;
+        module.register_method("engine_getPayloadV3", |params, chain, _| {
+            let id = params.one::<PayloadId>()?;
+            let chain = chain.lock().unwrap();
+            let block = chain
+                .payloads
+                .get(&id)
+                .ok_or_else(|| ErrorObjectOwned::owned(-38001, "Unknown payload", None::<()>))?;
+            Ok::<_, ErrorObjectOwned>(OpExecutionPayloadEnvelopeV3 {
+                execution_payload: ExecutionPayloadV3::from_block_slow(block),
+                block_value: U256::ZERO,
+                blobs_bundle: BlobsBundleV1::empty(),
+                should_override_builder: false,
+                parent_beacon_block_root: block.header.parent_beacon_block_root.unwrap_or_default(),
+            })
+        })?;
+
+        let (url, server) = super::serve(module).await?;
+        Ok(Self { chain, head, url, _server: server })
+    }
+
+    /// Returns the URL of the Engine API and JSON-RPC server.
+    pub(crate) fn url(&self) -> Url {
+        self.url.clone()
+    }
+
+    /// Returns the canonical block at the given height.
+    pub(crate) fn block(&self, number: u64) -> Option<BlockNumHash> {
+        let chain = self.chain.lock().unwrap();
+        chain.canonical.get(&number).map(|hash| BlockNumHash { number, hash: *hash })
+    }
+
+    /// Returns the head of the canonical chain, as set by the last forkchoice update.
+    pub(crate) fn head(&self) -> L2BlockInfo {
+        *self.head.borrow()
+    }
+
+    /// Waits until a forkchoice update makes a block that satisfies the condition the head of the
+    /// canonical chain, and returns it. Returns immediately if the current head satisfies it.
+    ///
+    /// The condition is only evaluated when the head changes, so it must not depend on anything
+    /// else that may change in the meantime.
+    pub(crate) async fn wait_for_head(
+        &self,
+        what: &str,
+        condition: impl FnMut(&L2BlockInfo) -> bool,
+    ) -> anyhow::Result<L2BlockInfo> {
+        let mut head = self.head.clone();
+        match tokio::time::timeout(TIMEOUT, head.wait_for(condition)).await {
+            Ok(Ok(head)) => Ok(*head),
+            Ok(Err(_)) => anyhow::bail!("execution client stopped before {what}"),
+            Err(_) => anyhow::bail!("timed out waiting until {what}"),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! A mock L1 execution client.
+//!
+//! The mock serves a canonical chain of empty blocks that tests extend, reorg and finalize. Blocks
+//! that are reorged out are forgotten, so that looking them up by hash returns `null` just like on
+//! a real execution client.
+
+use alloy_consensus::{
+    EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH, Header, constants::EMPTY_WITHDRAWALS,
+};
+use alloy_eips::{BlockId, BlockNumHash, BlockNumberOrTag};
+use alloy_primitives::{B256, Bytes, Sealable, Sealed, U64};
+use alloy_rpc_types_eth::{Block, BlockTransactions, Header as RpcHeader, Transaction};
+use jsonrpsee::{RpcModule, server::ServerHandle, types::ErrorObjectOwned};
+use std::{
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use url::Url;
+
+/// The chain ID of the mock L1.
+pub(crate) const L1_CHAIN_ID: u64 = 900;
+
+/// The canonical L1 chain.
+#[derive(Debug, Default)]
+struct L1Chain {
+    /// The canonical blocks, indexed by number.
+    blocks: Vec<Sealed<Header>>,
+    /// The number of the finalized block.
+    finalized: u64,
+    /// The number of reorgs so far, mixed into new blocks so that replacement blocks get new
+    /// hashes.
+    forks: u64,
+}
+
+impl L1Chain {
+    /// Appends a new block to the canonical chain.
+    fn mine(&mut self) -> BlockNumHash {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let parent = self.blocks.last();
+        let header = Header {
+            parent_hash: parent.map(|p| p.hash()).unwrap_or_default(),
+            ommers_hash: EMPTY_OMMER_ROOT_HASH,
+            state_root: EMPTY_ROOT_HASH,
+            transactions_root: EMPTY_ROOT_HASH,
+            receipts_root: EMPTY_ROOT_HASH,
+            withdrawals_root: Some(EMPTY_WITHDRAWALS),
+            number: parent.map_or(0, |p| p.number + 1),
+            gas_limit: 30_000_000,
+            timestamp: parent.map_or(now, |p| now.max(p.timestamp + 1)),
+            extra_data: Bytes::copy_from_slice(&self.forks.to_be_bytes()),
+            base_fee_per_gas: Some(1_000_000_000),
+            blob_gas_used: Some(0),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: Some(B256::ZERO),
+            ..Default::default()
+        };
+
+        let block = header.seal_slow();
+        let id = BlockNumHash { number: block.number, hash: block.hash() };
+        self.blocks.push(block);
+        id
+    }
+
+    fn by_tag(&self, tag: BlockNumberOrTag) -> Option<&Sealed<Header>> {
+        match tag {
+            BlockNumberOrTag::Latest | BlockNumberOrTag::Pending => self.blocks.last(),
+            BlockNumberOrTag::Safe | BlockNumberOrTag::Finalized => {
+                self.blocks.get(self.finalized as usize)
+            }
+            BlockNumberOrTag::Earliest => self.blocks.first(),
+            BlockNumberOrTag::Number(number) => self.blocks.get(number as usize),
+        }
+    }
+
+    fn by_hash(&self, hash: B256) -> Option<&Sealed<Header>> {
+        self.blocks.iter().find(|block| block.hash() == hash)
+    }
+
+    fn by_id(&self, id: BlockId) -> Option<&Sealed<Header>> {
+        match id {
+            BlockId::Hash(hash) => self.by_hash(hash.block_hash),
+            BlockId::Number(tag) => self.by_tag(tag),
+        }
+    }
+}
+
+/// Converts a header into an RPC block without transactions.
+fn rpc_block(header: &Sealed<Header>) -> Block<Transaction> {
+    Block {
+        header: RpcHeader::from_consensus(header.clone(), None, None),
+        uncles: vec![],
+        transactions: BlockTransactions::Full(vec![]),
+        withdrawals: Some(Default::default()),
+    }
+}
+
+/// A mock L1 execution client, serving the `eth` namespace methods used by the rollup node.
+#[derive(Debug)]
+pub(crate) struct MockL1 {
+    chain: Arc<Mutex<L1Chain>>,
+    url: Url,
+    _server: ServerHandle,
+}
+
+impl MockL1 {
+    /// Starts the mock L1 with a genesis block.
+    pub(crate) async fn start() -> anyhow::Result<Self> {
+        let mut chain = L1Chain::default();
+        chain.mine();
+        let chain = Arc::new(Mutex::new(chain));
+
+        let mut module = RpcModule::new(Arc::clone(&chain));
+        module.register_method("eth_chainId", |_, _, _| {
+            Ok::<_, ErrorObjectOwned>(U64::from(L1_CHAIN_ID))
+        })?;
+        module.register_method("eth_blockNumber", |_, chain, _| {
+            Ok::<_, ErrorObjectOwned>(U64::from(chain.lock().unwrap().blocks.len() as u64 - 1))
+        })?;
+        module.register_method("eth_getBlockByNumber", |params, chain, _| {
+            let (tag, _full) = params.parse::<(BlockNumberOrTag, bool)>()?;
+            Ok::<_, ErrorObjectOwned>(chain.lock().unwrap().by_tag(tag).map(rpc_block))
+        })?;
+        module.register_method("eth_getBlockByHash", |params, chain, _| {
+            let (hash, _full) = params.parse::<(B256, bool)>()?;
+            Ok::<_, ErrorObjectOwned>(chain.lock().unwrap().by_hash(hash).map(rpc_block))
+        })?;
+        // Blocks are empty, so they have no receipts and emit no logs.
+        module.register_method("eth_getBlockReceipts", |params, chain, _| {
+            let id = params.one::<BlockId>()?;
+            let receipts = chain.lock().unwrap().by_id(id).map(|_| Vec::<serde_json::Value>::new());
+            Ok::<_, ErrorObjectOwned>(receipts)
+        })?;
+        module.register_method("eth_getLogs", |_, _, _| Vec::<serde_json::Value>::new())?;
+
+        let (url, server) = super::serve(module).await?;
+        Ok(Self { chain, url, _server: server })
+    }
+
+    /// Returns the URL of the JSON-RPC server.
+    pub(crate) fn url(&self) -> Url {
+        self.url.clone()
+    }
+
+    /// Returns the genesis block.
+    pub(crate) fn genesis(&self) -> (BlockNumHash, u64) {
+        let chain = self.chain.lock().unwrap();
+        let genesis = &chain.blocks[0];
+        (BlockNumHash { number: 0, hash: genesis.hash() }, genesis.timestamp)
+    }
+
+    /// Returns the canonical head.
+    pub(crate) fn head(&self) -> BlockNumHash {
+        let chain = self.chain.lock().unwrap();
+        let head = chain.blocks.last().expect("the genesis block always exists");
+        BlockNumHash { number: head.number, hash: head.hash() }
+    }
+
+    /// Returns whether the block is part of the canonical chain.
+    pub(crate) fn is_canonical(&self, block: BlockNumHash) -> bool {
+        let chain = self.chain.lock().unwrap();
+        chain.blocks.get(block.number as usize).is_some_and(|b| b.hash() == block.hash)
+    }
+
+    /// Mines a new block on top of the canonical head.
+    pub(crate) fn mine(&self) -> BlockNumHash {
+        self.chain.lock().unwrap().mine()
+    }
+
+    /// Replaces the last `depth` blocks with `depth + 1` new blocks, so that the new chain is
+    /// longer than the old one. Finalized blocks are never reorged.
+    pub(crate) fn reorg(&self, depth: u64) -> BlockNumHash {
+        let mut chain = self.chain.lock().unwrap();
+        let keep = (chain.blocks.len() as u64).saturating_sub(depth).max(chain.finalized + 1);
+        chain.blocks.truncate(keep as usize);
+        chain.forks += 1;
+        for _ in 0..depth {
+            chain.mine();
+        }
+        chain.mine()
+    }
+
+    /// Finalizes the canonical head.
+    pub(crate) fn finalize(&self) -> BlockNumHash {
+        let mut chain = self.chain.lock().unwrap();
+        chain.finalized = chain.blocks.len() as u64 - 1;
+        let finalized = &chain.blocks[chain.finalized as usize];
+        BlockNumHash { number: finalized.number, hash: finalized.hash() }
+    }
+}
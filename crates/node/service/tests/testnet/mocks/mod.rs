@@ -0,0 +1,25 @@
+//! In-memory stand-ins for the external services a rollup node talks to.
+
+mod beacon;
+pub(crate) use beacon::MockBeacon;
+
+mod engine;
+pub(crate) use engine::{ExecutionNetwork, MockEngine};
+
+mod l1;
+pub(crate) use l1::{L1_CHAIN_ID, MockL1};
+
+use jsonrpsee::{
+    RpcModule,
+    server::{Server, ServerHandle},
+};
+use url::Url;
+
+/// Serves the given [`RpcModule`] over HTTP on a random local port.
+async fn serve<Ctx: Send + Sync + 'static>(
+    module: RpcModule<Ctx>,
+) -> anyhow::Result<(Url, ServerHandle)> {
+    let server = Server::builder().build("127.0.0.1:0").await?;
+    let url = Url::parse(&format!("http://{}", server.local_addr()?))?;
+    Ok((url, server.start(module)))
+}
@@ -0,0 +1,30 @@
+//! An in-process test network of rollup nodes.
+//!
+//! The [`TestNet`] runs several [`RollupNode`]s in the test process, each one with all of its
+//! actors:
+//! - The L1 execution client is a [`MockL1`](mocks::MockL1) that tests extend, reorg and finalize.
+//! - Each node has its own [`MockEngine`](mocks::MockEngine) standing in for the L2 execution
+//!   client.
+//! - The nodes gossip over libp2p's in-memory transport.
+//!
+//! Tests drive the network through the nodes' RPC APIs, like an operator or a conductor would, and
+//! assert on their execution clients' forkchoice state. Keys, the chain configuration and the
+//! blocks built from it are deterministic. Tests never sleep for a fixed duration: they wait for
+//! the forkchoice updates the nodes send to their execution clients, so that they do not depend on
+//! the scheduling of the nodes' tasks. Nothing binds a port that is not known in advance: gossip
+//! runs over the in-memory transport and every RPC server is handed an already bound listener.
+//!
+//! [`RollupNode`]: kona_node_service::RollupNode
+
+mod builder;
+pub(crate) use builder::TestNetBuilder;
+
+pub(crate) mod mocks;
+
+mod net;
+pub(crate) use net::{TIMEOUT, TestNet, TestNode};
+
+mod conductor;
+mod gossip;
+mod partition;
+mod reorg;
@@ -0,0 +1,257 @@
+//! The [`TestNet`] and its [`TestNode`]s.
+
+use std::time::Duration;
+
+use alloy_primitives::B256;
+use jsonrpsee::http_client::HttpClient;
+use kona_protocol::{L2BlockInfo, SyncStatus};
+use kona_rpc::{AdminApiClient, OpP2PApiClient, RollupNodeApiClient};
+use libp2p::{Multiaddr, PeerId, multiaddr::Protocol};
+use tokio::task::JoinHandle;
+
+use crate::testnet::mocks::{MockBeacon, MockEngine, MockL1};
+
+/// How long to wait for the network to reach an expected state before failing a test.
+pub(crate) const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The interval at which conditions are polled.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Polls the condition until it holds, failing after the given timeout.
+///
+/// This is only used for state that is solely observable through a node's RPC API, such as its
+/// peers. Progress of the chain is awaited through [`MockEngine::wait_for_head`] instead, which is
+/// notified by the node's forkchoice updates.
+///
+/// Errors returned by the condition are treated as the condition not holding yet, so that
+/// transient RPC failures do not fail the test.
+async fn wait_until<F, Fut>(what: &str, timeout: Duration, mut condition: F) -> anyhow::Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = anyhow::Result<bool>>,
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut last_err = None;
+    loop {
+        match condition().await {
+            Ok(true) => return Ok(()),
+            Ok(false) => {}
+            Err(err) => last_err = Some(err),
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return Err(match last_err {
+                Some(err) => err.context(format!("timed out waiting until {what}")),
+                None => anyhow::anyhow!("timed out waiting until {what}"),
+            });
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// A rollup node running in the [`TestNet`].
+#[derive(Debug)]
+pub(crate) struct TestNode {
+    /// The index of the node in the network.
+    pub(crate) index: usize,
+    /// The node's L2 execution client.
+    pub(crate) engine: MockEngine,
+    /// A client for the node's RPC API.
+    rpc: HttpClient,
+    /// The node's libp2p peer ID.
+    peer_id: PeerId,
+    /// The node's gossip listen address.
+    gossip_addr: Multiaddr,
+    /// The task running the node.
+    handle: JoinHandle<Result<(), String>>,
+}
+
+impl TestNode {
+    /// Creates a new [`TestNode`].
+    pub(crate) const fn new(
+        index: usize,
+        engine: MockEngine,
+        rpc: HttpClient,
+        peer_id: PeerId,
+        gossip_addr: Multiaddr,
+        handle: JoinHandle<Result<(), String>>,
+    ) -> Self {
+        Self { index, engine, rpc, peer_id, gossip_addr, handle }
+    }
+
+    /// Returns the address other nodes dial to connect to this node.
+    fn dial_addr(&self) -> Multiaddr {
+        self.gossip_addr.clone().with(Protocol::P2p(self.peer_id))
+    }
+
+    /// Waits until the node serves its RPC API.
+    pub(crate) async fn wait_for_rpc(&self) -> anyhow::Result<()> {
+        wait_until(&format!("node {} serves its RPC API", self.index), TIMEOUT, || async {
+            anyhow::ensure!(!self.handle.is_finished(), "node {} exited", self.index);
+            Ok(self.rpc.op_sync_status().await.is_ok())
+        })
+        .await
+    }
+
+    /// Returns the node's sync status.
+    pub(crate) async fn sync_status(&self) -> anyhow::Result<SyncStatus> {
+        Ok(self.rpc.op_sync_status().await?)
+    }
+
+    /// Returns the node's unsafe head, as last set on its execution client.
+    pub(crate) fn unsafe_head(&self) -> L2BlockInfo {
+        self.engine.head()
+    }
+
+    /// Waits until the node's unsafe head satisfies the condition, and returns it.
+    pub(crate) async fn wait_for_head(
+        &self,
+        what: &str,
+        condition: impl FnMut(&L2BlockInfo) -> bool,
+    ) -> anyhow::Result<L2BlockInfo> {
+        self.engine.wait_for_head(&format!("node {} {what}", self.index), condition).await
+    }
+
+    /// Waits until the node's unsafe head reaches the given block number.
+    pub(crate) async fn wait_for_unsafe(&self, number: u64) -> anyhow::Result<L2BlockInfo> {
+        self.wait_for_head(&format!("reaches unsafe block {number}"), |head| {
+            head.block_info.number >= number
+        })
+        .await
+    }
+
+    /// Starts the node's sequencer.
+    pub(crate) async fn start_sequencer(&self) -> anyhow::Result<()> {
+        Ok(self.rpc.admin_start_sequencer().await?)
+    }
+
+    /// Stops the node's sequencer, returning the hash of the last block it sequenced.
+    pub(crate) async fn stop_sequencer(&self) -> anyhow::Result<B256> {
+        Ok(self.rpc.admin_stop_sequencer().await?)
+    }
+
+    /// Returns whether the node's sequencer is active.
+    pub(crate) async fn sequencer_active(&self) -> anyhow::Result<bool> {
+        Ok(self.rpc.admin_sequencer_active().await?)
+    }
+
+    /// Returns whether the node is connected to the other node.
+    pub(crate) async fn is_connected_to(&self, other: &Self) -> anyhow::Result<bool> {
+        let peers = self.rpc.opp2p_peers(true).await?;
+        Ok(peers.peers.contains_key(&other.peer_id.to_string()))
+    }
+
+    /// Connects the node to the other node.
+    pub(crate) async fn connect(&self, other: &Self) -> anyhow::Result<()> {
+        // The RPC gives up after a few seconds, which may not be enough for the connection to be
+        // reported on both sides. Keep polling until it is.
+        let _ = self.rpc.opp2p_connect_peer(other.dial_addr().to_string()).await;
+        wait_until(
+            &format!("node {} connects to node {}", self.index, other.index),
+            TIMEOUT,
+            || async {
+                Ok(self.is_connected_to(other).await? && other.is_connected_to(self).await?)
+            },
+        )
+        .await
+    }
+
+    /// Blocks the other node and drops any connection to it.
+    pub(crate) async fn block(&self, other: &Self) -> anyhow::Result<()> {
+        let peer = other.peer_id.to_string();
+        self.rpc.opp2p_block_peer(peer.clone()).await?;
+        let _ = self.rpc.opp2p_disconnect_peer(peer).await;
+        wait_until(
+            &format!("node {} disconnects from node {}", self.index, other.index),
+            TIMEOUT,
+            || async { Ok(!self.is_connected_to(other).await?) },
+        )
+        .await
+    }
+
+    /// Unblocks the other node.
+    pub(crate) async fn unblock(&self, other: &Self) -> anyhow::Result<()> {
+        Ok(self.rpc.opp2p_unblock_peer(other.peer_id.to_string()).await?)
+    }
+}
+
+impl Drop for TestNode {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// An in-process network of rollup nodes sharing a mock L1.
+///
+/// The first node is the initial sequencer; the other nodes follow its unsafe chain over gossip.
+#[derive(Debug)]
+pub(crate) struct TestNet {
+    /// The mock L1 execution client.
+    pub(crate) l1: MockL1,
+    /// The mock L1 beacon node, kept alive for the lifetime of the network.
+    _beacon: MockBeacon,
+    /// The nodes of the network.
+    pub(crate) nodes: Vec<TestNode>,
+}
+
+impl TestNet {
+    /// Creates a new [`TestNet`] from running nodes.
+    pub(crate) const fn new(l1: MockL1, beacon: MockBeacon, nodes: Vec<TestNode>) -> Self {
+        Self { l1, _beacon: beacon, nodes }
+    }
+
+    /// Connects every pair of nodes.
+    pub(crate) async fn connect_all(&self) -> anyhow::Result<()> {
+        for (i, a) in self.nodes.iter().enumerate() {
+            for b in &self.nodes[i + 1..] {
+                a.connect(b).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Splits the network in two groups of nodes that cannot reach each other.
+    pub(crate) async fn partition(&self, a: &[usize], b: &[usize]) -> anyhow::Result<()> {
+        for &i in a {
+            for &j in b {
+                self.nodes[i].block(&self.nodes[j]).await?;
+                self.nodes[j].block(&self.nodes[i]).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Heals a partition created by [`TestNet::partition`], reconnecting the two groups.
+    pub(crate) async fn heal(&self, a: &[usize], b: &[usize]) -> anyhow::Result<()> {
+        for &i in a {
+            for &j in b {
+                self.nodes[i].unblock(&self.nodes[j]).await?;
+                self.nodes[j].unblock(&self.nodes[i]).await?;
+                self.nodes[i].connect(&self.nodes[j]).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Hands sequencing over from one node to another, the way a conductor does: the leader is
+    /// stopped, the new leader waits until it has imported the leader's last block, and only then
+    /// starts sequencing on top of it. Returns the leader's last block.
+    pub(crate) async fn transfer_leadership(
+        &self,
+        from: usize,
+        to: usize,
+    ) -> anyhow::Result<L2BlockInfo> {
+        let (leader, follower) = (&self.nodes[from], &self.nodes[to]);
+        let last = leader.stop_sequencer().await?;
+        let head = follower
+            .wait_for_head(&format!("imports block {last}"), |head| head.block_info.hash == last)
+            .await?;
+        // The node records its new unsafe head once its execution client has acknowledged the
+        // forkchoice update, so wait for it to report the block before starting to sequence.
+        wait_until(&format!("node {to} reports block {last} as unsafe"), TIMEOUT, || async {
+            Ok(follower.sync_status().await?.unsafe_l2.block_info.hash == last)
+        })
+        .await?;
+        follower.start_sequencer().await?;
+        Ok(head)
+    }
+}
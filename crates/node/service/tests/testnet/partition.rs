@@ -0,0 +1,27 @@
+//! Tests that a node cut off from the sequencer catches up once the partition heals.
+
+use crate::testnet::TestNetBuilder;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn isolated_node_catches_up_after_partition() -> anyhow::Result<()> {
+    let net = TestNetBuilder::new().with_nodes(3).build().await?;
+    let (leader, isolated) = (&net.nodes[0], &net.nodes[2]);
+    leader.wait_for_unsafe(3).await?;
+    isolated.wait_for_unsafe(3).await?;
+
+    net.partition(&[0, 1], &[2]).await?;
+    let stalled = isolated.unsafe_head();
+
+    // The rest of the network keeps going while the isolated node stalls.
+    let head = net.nodes[1].wait_for_unsafe(stalled.block_info.number + 5).await?;
+    assert!(isolated.unsafe_head().block_info.number < head.block_info.number);
+
+    net.heal(&[0, 1], &[2]).await?;
+
+    // Once it receives a new block, the isolated node backfills the gap from its execution client.
+    let head = leader.wait_for_unsafe(head.block_info.number + 2).await?;
+    isolated.wait_for_unsafe(head.block_info.number).await?;
+    assert_eq!(isolated.engine.block(head.block_info.number), Some(head.block_info.id()));
+
+    Ok(())
+}
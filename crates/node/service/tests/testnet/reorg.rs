@@ -0,0 +1,37 @@
+//! Tests that the network recovers from L1 reorgs of the unsafe chain's origins.
+
+use crate::testnet::TestNetBuilder;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn unsafe_chain_follows_l1_reorg() -> anyhow::Result<()> {
+    let net = TestNetBuilder::new().build().await?;
+    let leader = &net.nodes[0];
+
+    // Give the sequencer a few L1 blocks to adopt as origins, one at a time.
+    for _ in 0..3 {
+        let block = net.l1.mine();
+        leader
+            .wait_for_head(&format!("adopts L1 block {} as origin", block.number), |head| {
+                head.l1_origin.number >= block.number
+            })
+            .await?;
+    }
+    let origin = net.l1.head();
+
+    net.l1.reorg(2);
+    assert!(!net.l1.is_canonical(origin));
+
+    // Every node ends up on an unsafe chain built on the new canonical L1 chain, and the sequencer
+    // keeps extending it.
+    let before = leader.unsafe_head();
+    for node in &net.nodes {
+        node.wait_for_head("has a canonical origin", |head| {
+            head.l1_origin.number >= origin.number && net.l1.is_canonical(head.l1_origin)
+        })
+        .await?;
+    }
+    let after = leader.wait_for_unsafe(before.block_info.number + 2).await?;
+    assert!(net.l1.is_canonical(after.l1_origin));
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
-use alloy_primitives::{Address, B256, ChainId, SignatureError};
+use alloy_consensus::{SignableTransaction, Transaction, TxEnvelope};
+use alloy_eips::eip2718::{Decodable2718, Eip2718Error};
+use alloy_primitives::{Address, B256, Bytes, ChainId, SignatureError, U64, U128, U256};
 use alloy_rpc_client::RpcClient;
 use alloy_signer::Signature;
 use notify::RecommendedWatcher;
@@ -19,6 +21,26 @@ struct BlockPayloadArgs {
     sender_address: Address,
 }
 
+/// Request parameters for signing a transaction, following `eth_signTransaction`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionArgs {
+    from: Address,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to: Option<Address>,
+    gas: U64,
+    max_fee_per_gas: U128,
+    max_priority_fee_per_gas: U128,
+    value: U256,
+    nonce: U64,
+    data: Bytes,
+    chain_id: U64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_fee_per_blob_gas: Option<U128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blob_versioned_hashes: Option<Vec<B256>>,
+}
+
 /// Response from the remote signer
 #[derive(Debug, Deserialize)]
 struct SignResponse {
@@ -67,6 +89,17 @@ pub enum RemoteSignerError {
         /// The remote signer address.
         remote_signer: Address,
     },
+    /// The signed transaction returned by the signer could not be decoded
+    #[error("Invalid signed transaction: {0}")]
+    InvalidTransaction(Eip2718Error),
+    /// The signature returned by the signer does not recover to the signer address
+    #[error("Transaction signature recovers to {recovered}, expected {expected}")]
+    SignerMismatch {
+        /// The address of the remote signer.
+        expected: Address,
+        /// The address recovered from the returned signature.
+        recovered: Address,
+    },
 }
 
 impl RemoteSignerHandler {
@@ -75,6 +108,54 @@ impl RemoteSignerHandler {
         self.watcher_handle.is_some()
     }
 
+    /// Returns the address of the signer.
+    pub const fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Signs a transaction using the remote signer via `eth_signTransaction`, and returns the
+    /// signature after checking that it recovers to the signer address.
+    pub async fn sign_transaction<T>(&self, tx: &T) -> Result<Signature, RemoteSignerError>
+    where
+        T: Transaction + SignableTransaction<Signature>,
+    {
+        let args = TransactionArgs {
+            from: self.address,
+            to: tx.to(),
+            gas: U64::from(tx.gas_limit()),
+            max_fee_per_gas: U128::from(tx.max_fee_per_gas()),
+            max_priority_fee_per_gas: U128::from(tx.max_priority_fee_per_gas().unwrap_or_default()),
+            value: tx.value(),
+            nonce: U64::from(tx.nonce()),
+            data: tx.input().clone(),
+            chain_id: U64::from(tx.chain_id().unwrap_or_default()),
+            max_fee_per_blob_gas: tx.max_fee_per_blob_gas().map(U128::from),
+            blob_versioned_hashes: tx.blob_versioned_hashes().map(<[B256]>::to_vec),
+        };
+
+        let raw: Bytes = {
+            self.client
+                .read()
+                .await
+                .request("eth_signTransaction", (args,))
+                .await
+                .map_err(RemoteSignerError::SigningRPCError)?
+        };
+
+        let envelope = TxEnvelope::decode_2718(&mut raw.as_ref())
+            .map_err(RemoteSignerError::InvalidTransaction)?;
+        let signature = *envelope.signature();
+
+        // The signer may have altered the transaction, so the signature is checked against our
+        // own signature hash rather than the returned one.
+        let recovered = signature.recover_address_from_prehash(&tx.signature_hash())?;
+        if recovered != self.address {
+            return Err(RemoteSignerError::SignerMismatch { expected: self.address, recovered });
+        }
+
+        Ok(signature)
+    }
+
     /// Signs a block payload hash using the remote signer via JSON-RPC
     pub async fn sign_block_v1(
         &self,
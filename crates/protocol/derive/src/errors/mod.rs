@@ -15,3 +15,8 @@ pub use pipeline::{PipelineEncodingError, PipelineError, PipelineErrorKind, Rese
 
 mod sources;
 pub use sources::{BlobDecodingError, BlobProviderError};
+
+#[cfg(feature = "simulator")]
+mod simulator;
+#[cfg(feature = "simulator")]
+pub use simulator::{SimulatedChannelError, SimulatorError, SimulatorProviderError};
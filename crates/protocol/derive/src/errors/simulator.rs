@@ -0,0 +1,66 @@
+//! Error types for the batch submission simulator.
+
+use crate::{PipelineError, PipelineErrorKind};
+use alloc::string::ToString;
+use kona_protocol::{BatchEncodingError, FromBlockError};
+use thiserror::Error;
+
+/// An error that prevents a [`BatchSimulator`] from running.
+///
+/// [`BatchSimulator`]: crate::BatchSimulator
+#[derive(Error, Debug)]
+pub enum SimulatorError {
+    /// The L1 info of the starting safe head could not be read.
+    #[error("Invalid safe head: {0}")]
+    InvalidSafeHead(#[from] FromBlockError),
+    /// The L1 chain does not contain the L1 origin of the starting safe head.
+    #[error("L1 block {0} is missing from the simulated L1 chain")]
+    MissingL1Block(u64),
+    /// The L1 chain is not a contiguous chain of blocks.
+    #[error("L1 block {0} does not build on its predecessor")]
+    L1ChainNotContiguous(u64),
+    /// A submission was included in a block outside of the L1 chain.
+    #[error("Submission included in L1 block {0}, which is not part of the simulated L1 chain")]
+    UnknownInclusionBlock(u64),
+    /// The pipeline could not be reset to the starting safe head.
+    #[error("Failed to reset the pipeline: {0}")]
+    Reset(PipelineErrorKind),
+}
+
+/// An error returned by the in-memory providers of the [`BatchSimulator`].
+///
+/// [`BatchSimulator`]: crate::BatchSimulator
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SimulatorProviderError {
+    /// The L1 block is not part of the simulated L1 chain.
+    #[error("L1 block not found")]
+    L1BlockNotFound,
+    /// The L2 block has not been derived yet.
+    #[error("L2 block {0} not found")]
+    L2BlockNotFound(u64),
+    /// L1 headers are not simulated.
+    #[error("L1 headers are not available in the simulator")]
+    HeaderNotFound,
+}
+
+impl From<SimulatorProviderError> for PipelineErrorKind {
+    fn from(val: SimulatorProviderError) -> Self {
+        PipelineError::Provider(val.to_string()).temp()
+    }
+}
+
+/// An error building the frames of a [`SimulatedChannel`].
+///
+/// [`SimulatedChannel`]: crate::SimulatedChannel
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SimulatedChannelError {
+    /// A batch could not be encoded.
+    #[error("Failed to encode batch: {0}")]
+    Encoding(#[from] BatchEncodingError),
+    /// The channel does not fit in the maximum number of frames.
+    #[error("Channel needs more than {} frames", u16::MAX)]
+    TooManyFrames,
+    /// The maximum frame size leaves no room for frame data.
+    #[error("Frame size {0} is too small")]
+    FrameTooSmall(usize),
+}
@@ -20,6 +20,8 @@ pub use errors::{
     BatchDecompressionError, BlobDecodingError, BlobProviderError, BuilderError,
     PipelineEncodingError, PipelineError, PipelineErrorKind, ResetError,
 };
+#[cfg(feature = "simulator")]
+pub use errors::{SimulatedChannelError, SimulatorError, SimulatorProviderError};
 
 mod pipeline;
 pub use pipeline::{
@@ -28,6 +30,14 @@ pub use pipeline::{
     L1RetrievalStage, PipelineBuilder, PolledAttributesQueueStage,
 };
 
+#[cfg(feature = "simulator")]
+mod simulator;
+#[cfg(feature = "simulator")]
+pub use simulator::{
+    BatchSimulator, BatchVerdict, DerivedBlock, SimulatedBatch, SimulatedChannel, SimulationEnd,
+    SimulationEvent, SimulationReport,
+};
+
 mod sources;
 pub use sources::{BlobData, BlobSource, CalldataSource, EthereumDataSource};
 
@@ -0,0 +1,112 @@
+//! Contains the [`SimulatedChannel`], a minimal batcher-side channel encoder.
+
+use crate::SimulatedChannelError;
+use alloc::vec::Vec;
+use alloy_primitives::Bytes;
+use alloy_rlp::Encodable;
+use kona_protocol::{Batch, ChannelId, Frame};
+use miniz_oxide::deflate::compress_to_vec_zlib;
+
+/// The zlib compression level used to compress channel data.
+const COMPRESSION_LEVEL: u8 = 6;
+
+/// A channel of [`Batch`]es, encoded the way a batcher would submit it.
+///
+/// The batches are each RLP encoded as a byte string, concatenated and compressed with zlib.
+/// The resulting channel data is split into [`Frame`]s that can be handed to
+/// [`BatchSimulator::with_frames`] in any order or spread across several L1 blocks.
+///
+/// [`BatchSimulator::with_frames`]: crate::BatchSimulator::with_frames
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimulatedChannel {
+    /// The channel id.
+    pub id: ChannelId,
+    /// The batches in the channel, in submission order.
+    pub batches: Vec<Batch>,
+}
+
+impl SimulatedChannel {
+    /// Creates a new, empty [`SimulatedChannel`] with the given id.
+    pub const fn new(id: ChannelId) -> Self {
+        Self { id, batches: Vec::new() }
+    }
+
+    /// Appends a [`Batch`] to the channel.
+    pub fn with_batch(mut self, batch: Batch) -> Self {
+        self.batches.push(batch);
+        self
+    }
+
+    /// Returns the compressed channel data.
+    pub fn encode(&self) -> Result<Bytes, SimulatedChannelError> {
+        let mut rlp = Vec::new();
+        for batch in &self.batches {
+            let mut encoded = Vec::new();
+            batch.encode(&mut encoded)?;
+            Bytes::from(encoded).encode(&mut rlp);
+        }
+        Ok(compress_to_vec_zlib(&rlp, COMPRESSION_LEVEL).into())
+    }
+
+    /// Splits the compressed channel data into [`Frame`]s carrying at most `max_frame_data`
+    /// bytes each. The last frame is marked as closing the channel.
+    pub fn frames(&self, max_frame_data: usize) -> Result<Vec<Frame>, SimulatedChannelError> {
+        if max_frame_data == 0 {
+            return Err(SimulatedChannelError::FrameTooSmall(max_frame_data));
+        }
+
+        let data = self.encode()?;
+        let chunks = data.chunks(max_frame_data).collect::<Vec<_>>();
+        if chunks.len() > u16::MAX as usize {
+            return Err(SimulatedChannelError::TooManyFrames);
+        }
+
+        let last = chunks.len() - 1;
+        Ok(chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, chunk)| Frame::new(self.id, i as u16, chunk.to_vec(), i == last))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_genesis::RollupConfig;
+    use kona_protocol::{BatchReader, SingleBatch};
+
+    fn channel() -> SimulatedChannel {
+        SimulatedChannel::new([0xAA; 16])
+            .with_batch(Batch::Single(SingleBatch { timestamp: 2, ..Default::default() }))
+            .with_batch(Batch::Single(SingleBatch { timestamp: 4, ..Default::default() }))
+    }
+
+    #[test]
+    fn test_encode_roundtrip() {
+        let channel = channel();
+        let cfg = RollupConfig::default();
+        let mut reader = BatchReader::new(channel.encode().unwrap().to_vec(), usize::MAX);
+        assert_eq!(reader.next_batch(&cfg), Some(channel.batches[0].clone()));
+        assert_eq!(reader.next_batch(&cfg), Some(channel.batches[1].clone()));
+        assert_eq!(reader.next_batch(&cfg), None);
+    }
+
+    #[test]
+    fn test_frames_split() {
+        let channel = channel();
+        let data = channel.encode().unwrap();
+        let frames = channel.frames(4).unwrap();
+
+        assert_eq!(frames.len(), data.len().div_ceil(4));
+        assert!(frames.iter().rev().skip(1).all(|f| !f.is_last && f.data.len() == 4));
+        assert!(frames.last().unwrap().is_last);
+        assert!(frames.iter().enumerate().all(|(i, f)| f.number == i as u16 && f.id == channel.id));
+        assert_eq!(frames.iter().flat_map(|f| f.data.clone()).collect::<Vec<_>>(), data.to_vec());
+    }
+
+    #[test]
+    fn test_frames_too_small() {
+        assert_eq!(channel().frames(0), Err(SimulatedChannelError::FrameTooSmall(0)));
+    }
+}
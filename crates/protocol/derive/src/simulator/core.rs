@@ -0,0 +1,705 @@
+//! Contains the [`BatchSimulator`].
+
+use super::{
+    providers::{SimulatedDataSource, SimulatedL1, SimulatedL2},
+    recorder::BatchRecorder,
+};
+use crate::{
+    ActivationSignal, AttributesProvider, BatchProvider, BatchStream, BatchVerdict,
+    ChannelProvider, ChannelReader, ChannelReaderStage, DerivedBlock, FrameQueue, L1Retrieval,
+    OriginAdvancer, OriginProvider, PipelineError, PipelineErrorKind, PipelineResult,
+    PollingTraversal, ResetError, ResetSignal, Signal, SignalReceiver, SimulatedBatch,
+    SimulationEnd, SimulationEvent, SimulationReport, SimulatorError,
+};
+use alloc::{collections::BTreeMap, sync::Arc, vec, vec::Vec};
+use alloy_consensus::{BlockBody, Header, proofs::calculate_transaction_root};
+use alloy_eips::{BlockNumHash, eip2718::Decodable2718};
+use alloy_primitives::{Address, Bytes, Sealed, TxKind, address};
+use kona_genesis::RollupConfig;
+use kona_protocol::{
+    Batch, BlockInfo, DERIVATION_VERSION_0, Frame, L1BlockInfoBedrock, L2BlockInfo, Predeploys,
+    SingleBatch, SpanBatchElement,
+};
+use op_alloy_consensus::{
+    DepositSourceDomain, L1InfoDepositSource, OpBlock, OpTxEnvelope, TxDeposit,
+};
+
+/// The address of the account sending L1 info deposits.
+const L1_INFO_DEPOSITOR_ADDRESS: Address = address!("deaddeaddeaddeaddeaddeaddeaddeaddead0001");
+
+/// The number of steps without observable progress tolerated before a run is considered
+/// stalled, on top of one step per byte of submitted data.
+const IDLE_STEP_ALLOWANCE: usize = 1_024;
+
+/// The [`BatchStream`] stage of the simulated pipeline.
+type SimulatedStream = BatchStream<
+    BatchRecorder<ChannelReaderStage<SimulatedDataSource, PollingTraversal<SimulatedL1>>>,
+    SimulatedL2,
+>;
+
+/// The [`BatchProvider`] stage of the simulated pipeline.
+type SimulatedStack = BatchProvider<SimulatedStream, SimulatedL2>;
+
+/// Simulates the batch acceptance rules of the derivation pipeline against a hypothetical
+/// sequence of batcher submissions.
+///
+/// The simulator runs the pipeline's own stages, from [`L1Retrieval`] up to the
+/// [`BatchProvider`], over an in-memory L1 chain holding the submissions. Every
+/// [`SingleBatch`] the pipeline hands out becomes a new L2 block on top of the safe head,
+/// so span batch overlap checks and later batches see the chain the submissions produce.
+/// Resets and the Holocene activation are handled the way the proof driver handles them.
+///
+/// Derived blocks carry a Bedrock L1 info deposit followed by the batch transactions that
+/// decode as [`OpTxEnvelope`]s. Transactions are not executed, and the L1 chain carries no
+/// receipts, so the system config stays at the genesis system config.
+///
+/// ```rust,ignore
+/// let frames = SimulatedChannel::new(id).with_batch(batch).frames(1_000)?;
+/// let report = BatchSimulator::new(cfg, l1_chain, safe_head)
+///     .with_frames(l1_chain[1].number, &frames)
+///     .run()
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchSimulator {
+    /// The rollup config.
+    cfg: Arc<RollupConfig>,
+    /// The contiguous L1 chain, starting at or before the L1 origin of the safe head.
+    l1_chain: Vec<BlockInfo>,
+    /// The starting L2 safe head.
+    safe_head: OpBlock,
+    /// The batcher submissions, keyed by inclusion block number.
+    submissions: BTreeMap<u64, Vec<Bytes>>,
+}
+
+impl BatchSimulator {
+    /// Creates a new [`BatchSimulator`] starting from the given safe head.
+    ///
+    /// The L1 chain must be contiguous and contain the L1 origin of the safe head.
+    pub const fn new(cfg: Arc<RollupConfig>, l1_chain: Vec<BlockInfo>, safe_head: OpBlock) -> Self {
+        Self { cfg, l1_chain, safe_head, submissions: BTreeMap::new() }
+    }
+
+    /// Adds a raw batcher transaction, included in the L1 block with the given number.
+    ///
+    /// Submissions within a block are read in the order they were added.
+    pub fn with_submission(mut self, l1_block: u64, data: Bytes) -> Self {
+        self.submissions.entry(l1_block).or_default().push(data);
+        self
+    }
+
+    /// Adds a batcher transaction carrying the given [`Frame`]s, included in the L1 block with
+    /// the given number.
+    pub fn with_frames(self, l1_block: u64, frames: &[Frame]) -> Self {
+        let mut data = vec![DERIVATION_VERSION_0];
+        frames.iter().for_each(|frame| data.extend_from_slice(&frame.encode()));
+        self.with_submission(l1_block, data.into())
+    }
+
+    /// Runs the pipeline over the whole L1 chain and reports the fate of every batch.
+    pub async fn run(self) -> Result<SimulationReport, SimulatorError> {
+        self.validate()?;
+
+        let safe_head = L2BlockInfo::from_block_and_genesis(&self.safe_head, &self.cfg.genesis)?;
+        let origin = self
+            .l1_chain
+            .iter()
+            .find(|block| block.id() == safe_head.l1_origin)
+            .copied()
+            .ok_or(SimulatorError::MissingL1Block(safe_head.l1_origin.number))?;
+        let max_idle_steps = IDLE_STEP_ALLOWANCE +
+            self.submissions.values().flatten().map(|data| data.len()).sum::<usize>();
+
+        // Compose the stage stack.
+        let system_config = self.cfg.genesis.system_config.unwrap_or_default();
+        let l2 = SimulatedL2::new(safe_head, self.safe_head, system_config);
+        let mut l1_traversal =
+            PollingTraversal::new(SimulatedL1::new(self.l1_chain.clone()), Arc::clone(&self.cfg));
+        l1_traversal.block = Some(origin);
+        let l1_retrieval =
+            L1Retrieval::new(l1_traversal, SimulatedDataSource::new(self.submissions));
+        let frame_queue = FrameQueue::new(l1_retrieval, Arc::clone(&self.cfg));
+        let channel_provider = ChannelProvider::new(Arc::clone(&self.cfg), frame_queue);
+        let channel_reader = ChannelReader::new(channel_provider, Arc::clone(&self.cfg));
+        let recorder = BatchRecorder::new(channel_reader);
+        let batch_stream = BatchStream::new(recorder, Arc::clone(&self.cfg), l2.clone());
+        let stack = BatchProvider::new(Arc::clone(&self.cfg), batch_stream, l2.clone());
+
+        let mut simulation = Simulation {
+            l1_chain: self.l1_chain,
+            stack,
+            l2,
+            max_idle_steps,
+            batches: Vec::new(),
+            elements: Vec::new(),
+            blocks: Vec::new(),
+            events: Vec::new(),
+        };
+        simulation
+            .signal(
+                ResetSignal { l2_safe_head: safe_head, l1_origin: origin, system_config: None }
+                    .signal(),
+            )
+            .await
+            .map_err(SimulatorError::Reset)?;
+
+        Ok(simulation.run(safe_head).await)
+    }
+
+    /// Checks that the L1 chain is contiguous and that every submission is included in it.
+    fn validate(&self) -> Result<(), SimulatorError> {
+        for pair in self.l1_chain.windows(2) {
+            if pair[1].number != pair[0].number + 1 || pair[1].parent_hash != pair[0].hash {
+                return Err(SimulatorError::L1ChainNotContiguous(pair[1].number));
+            }
+        }
+        if let Some(number) =
+            self.submissions.keys().find(|n| !self.l1_chain.iter().any(|b| b.number == **n))
+        {
+            return Err(SimulatorError::UnknownInclusionBlock(*number));
+        }
+        Ok(())
+    }
+}
+
+/// The state of a running simulation.
+#[derive(Debug)]
+struct Simulation {
+    /// The simulated L1 chain.
+    l1_chain: Vec<BlockInfo>,
+    /// The simulated pipeline.
+    stack: SimulatedStack,
+    /// The simulated L2 chain, shared with the pipeline.
+    l2: SimulatedL2,
+    /// The number of steps without observable progress after which the run is stalled.
+    max_idle_steps: usize,
+    /// The batches read so far.
+    batches: Vec<SimulatedBatch>,
+    /// The blocks described by each read batch, and whether each was derived.
+    elements: Vec<Vec<(SpanBatchElement, bool)>>,
+    /// The blocks derived so far.
+    blocks: Vec<DerivedBlock>,
+    /// The events observed so far.
+    events: Vec<SimulationEvent>,
+}
+
+impl Simulation {
+    /// Steps the pipeline until it runs out of L1 data, halts or stalls.
+    async fn run(mut self, mut safe_head: L2BlockInfo) -> SimulationReport {
+        let mut idle_steps = 0;
+        let mut last_reset = None;
+
+        let end = loop {
+            let result = self.stack.next_batch(safe_head).await;
+            let mut progressed = self.drain_recorder();
+
+            let err = match result {
+                Ok(batch) => {
+                    safe_head = self.derive_block(safe_head, batch);
+                    idle_steps = 0;
+                    continue;
+                }
+                Err(PipelineErrorKind::Temporary(PipelineError::Eof)) => {
+                    let Some(origin) = self.stack.origin() else {
+                        break SimulationEnd::Halted(PipelineError::MissingOrigin.crit());
+                    };
+                    if self.l1_chain.last().is_none_or(|last| last.number <= origin.number) {
+                        break SimulationEnd::L1Exhausted;
+                    }
+
+                    let result = self.stack.advance_origin().await;
+                    if let Some(next) = self.stack.origin().filter(|next| *next != origin) {
+                        self.events.push(SimulationEvent::OriginAdvanced(next));
+                        progressed = true;
+                    }
+                    match result {
+                        Ok(()) => {
+                            idle_steps = 0;
+                            continue;
+                        }
+                        Err(err) => err,
+                    }
+                }
+                Err(err) => err,
+            };
+
+            match err {
+                PipelineErrorKind::Reset(ResetError::HoloceneActivation) => {
+                    let Some(origin) = self.stack.origin() else {
+                        break SimulationEnd::Halted(PipelineError::MissingOrigin.crit());
+                    };
+                    let signal = ActivationSignal {
+                        l2_safe_head: safe_head,
+                        l1_origin: origin,
+                        system_config: None,
+                    };
+                    if let Err(err) = self.signal(signal.signal()).await {
+                        break SimulationEnd::Halted(err);
+                    }
+                    self.events.push(SimulationEvent::HoloceneActivated(origin));
+                    idle_steps = 0;
+                }
+                PipelineErrorKind::Reset(error) => {
+                    let Some(origin) = self.stack.origin() else {
+                        break SimulationEnd::Halted(PipelineError::MissingOrigin.crit());
+                    };
+                    // Resetting to the same point again would loop forever.
+                    if last_reset == Some((origin, safe_head)) {
+                        break SimulationEnd::Stalled(PipelineErrorKind::Reset(error));
+                    }
+                    last_reset = Some((origin, safe_head));
+
+                    let signal = ResetSignal {
+                        l2_safe_head: safe_head,
+                        l1_origin: origin,
+                        system_config: None,
+                    };
+                    if let Err(err) = self.signal(signal.signal()).await {
+                        break SimulationEnd::Halted(err);
+                    }
+                    self.events.push(SimulationEvent::Reset { origin, safe_head, error });
+                    idle_steps = 0;
+                }
+                PipelineErrorKind::Critical(_) => break SimulationEnd::Halted(err),
+                PipelineErrorKind::Temporary(_) => {
+                    idle_steps = if progressed { 0 } else { idle_steps + 1 };
+                    if idle_steps > self.max_idle_steps {
+                        break SimulationEnd::Stalled(err);
+                    }
+                }
+            }
+        };
+
+        self.finish(safe_head, end)
+    }
+
+    /// Sends a signal to the pipeline, with the system config of the simulated L2 chain.
+    async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        let signal = signal.with_system_config(*self.l2.system_config());
+        match self.stack.signal(signal).await {
+            Ok(()) | Err(PipelineErrorKind::Temporary(PipelineError::Eof)) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns the [`BatchStream`] stage, wherever the [`BatchProvider`] currently holds it.
+    fn stream(&mut self) -> &mut SimulatedStream {
+        match (&mut self.stack.batch_validator, &mut self.stack.batch_queue, &mut self.stack.prev) {
+            (Some(batch_validator), _, _) => &mut batch_validator.prev,
+            (_, Some(batch_queue), _) => &mut batch_queue.prev,
+            (_, _, Some(prev)) => prev,
+            _ => unreachable!("The batch provider always holds the batch stream"),
+        }
+    }
+
+    /// Moves the batches and flushes recorded since the last call into the report. Returns
+    /// whether anything was recorded.
+    fn drain_recorder(&mut self) -> bool {
+        let recorder = &mut self.stream().prev;
+        let read = core::mem::take(&mut recorder.read);
+        let flushed = core::mem::take(&mut recorder.flushed);
+        let progressed = flushed || !read.is_empty();
+
+        for batch in read {
+            let elements = match &batch.batch {
+                Batch::Single(single) => vec![SpanBatchElement::from(single.clone())],
+                Batch::Span(span) => span.batches.clone(),
+            };
+            self.events.push(SimulationEvent::BatchRead(self.batches.len()));
+            self.elements.push(elements.into_iter().map(|e| (e, false)).collect());
+            self.batches.push(SimulatedBatch {
+                inclusion_block: batch.inclusion_block,
+                batch: batch.batch,
+                derived: Vec::new(),
+                verdict: BatchVerdict::Pending,
+            });
+        }
+
+        if flushed {
+            self.events.push(SimulationEvent::ChannelFlushed {
+                origin: self.stack.origin().unwrap_or_default(),
+                batch: self.batches.len().checked_sub(1),
+            });
+        }
+        progressed
+    }
+
+    /// Builds the block described by the batch on top of the parent and makes it the new safe
+    /// head.
+    fn derive_block(&mut self, parent: L2BlockInfo, batch: SingleBatch) -> L2BlockInfo {
+        let seq_num =
+            if batch.epoch_num == parent.l1_origin.number { parent.seq_num + 1 } else { 0 };
+        let l1_info = L1BlockInfoBedrock {
+            number: batch.epoch_num,
+            time: self
+                .l1_chain
+                .iter()
+                .find(|block| block.number == batch.epoch_num)
+                .map_or(0, |block| block.timestamp),
+            block_hash: batch.epoch_hash,
+            sequence_number: seq_num,
+            batcher_address: self.l2.system_config().batcher_address,
+            ..Default::default()
+        };
+        let source = DepositSourceDomain::L1Info(L1InfoDepositSource {
+            l1_block_hash: batch.epoch_hash,
+            seq_number: seq_num,
+        });
+        let deposit = TxDeposit {
+            source_hash: source.source_hash(),
+            from: L1_INFO_DEPOSITOR_ADDRESS,
+            to: TxKind::Call(Predeploys::L1_BLOCK_INFO),
+            input: l1_info.encode_calldata(),
+            ..Default::default()
+        };
+
+        let mut transactions = vec![OpTxEnvelope::Deposit(Sealed::new(deposit))];
+        transactions.extend(
+            batch
+                .transactions
+                .iter()
+                .filter_map(|tx| OpTxEnvelope::decode_2718(&mut tx.as_ref()).ok()),
+        );
+        let header = Header {
+            parent_hash: parent.block_info.hash,
+            number: parent.block_info.number + 1,
+            timestamp: batch.timestamp,
+            transactions_root: calculate_transaction_root(&transactions),
+            ..Default::default()
+        };
+        let block = OpBlock { header, body: BlockBody { transactions, ..Default::default() } };
+        let info = L2BlockInfo {
+            block_info: BlockInfo::from(&block),
+            l1_origin: BlockNumHash { number: batch.epoch_num, hash: batch.epoch_hash },
+            seq_num,
+        };
+
+        let index = self.blocks.len();
+        let source = self.attribute(&SpanBatchElement::from(batch), index);
+        self.l2.push(info, block);
+        self.blocks.push(DerivedBlock {
+            block: info,
+            derived_from: self.stack.origin().unwrap_or_default(),
+            batch: source,
+        });
+        self.events.push(SimulationEvent::BlockDerived(index));
+        info
+    }
+
+    /// Attributes a derived block to the earliest read batch holding an identical block that
+    /// was not derived yet. Returns [`None`] if the block came from a generated empty batch.
+    fn attribute(&mut self, element: &SpanBatchElement, block: usize) -> Option<usize> {
+        let (i, j) = self.elements.iter().enumerate().find_map(|(i, elements)| {
+            elements.iter().position(|(e, derived)| !derived && e == element).map(|j| (i, j))
+        })?;
+        self.elements[i][j].1 = true;
+        self.batches[i].derived.push(block);
+        Some(i)
+    }
+
+    /// Settles the verdict of every batch and builds the report.
+    fn finish(mut self, safe_head: L2BlockInfo, end: SimulationEnd) -> SimulationReport {
+        let (queued, next_spans) = self
+            .stack
+            .batch_queue
+            .as_ref()
+            .map(|bq| (bq.batches.clone(), bq.next_spans.clone()))
+            .unwrap_or_default();
+        let stream = self.stream();
+        let staged = stream.span.clone();
+        let buffered = next_spans
+            .into_iter()
+            .chain(stream.buffer.iter().cloned())
+            .map(SpanBatchElement::from)
+            .collect::<Vec<_>>();
+
+        for (batch, elements) in self.batches.iter_mut().zip(&self.elements) {
+            let held = queued
+                .iter()
+                .any(|q| q.inclusion_block == batch.inclusion_block && q.batch == batch.batch) ||
+                matches!(&batch.batch, Batch::Span(span) if staged.as_ref() == Some(span)) ||
+                elements.iter().any(|(e, derived)| !derived && buffered.contains(e));
+
+            batch.verdict = if elements.last().is_some_and(|(_, derived)| *derived) {
+                BatchVerdict::Accepted
+            } else if held {
+                BatchVerdict::Pending
+            } else {
+                BatchVerdict::Dropped
+            };
+        }
+
+        SimulationReport {
+            batches: self.batches,
+            blocks: self.blocks,
+            events: self.events,
+            safe_head,
+            end,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimulatedChannel;
+    use alloy_primitives::B256;
+    use kona_genesis::{ChainGenesis, HardForkConfig, SystemConfig};
+    use kona_protocol::SpanBatch;
+
+    fn l1_chain(len: u64) -> Vec<BlockInfo> {
+        (0..len)
+            .map(|n| BlockInfo {
+                number: n,
+                hash: B256::with_last_byte(n as u8 + 1),
+                parent_hash: if n == 0 { B256::ZERO } else { B256::with_last_byte(n as u8) },
+                timestamp: n * 12,
+            })
+            .collect()
+    }
+
+    fn safe_head() -> OpBlock {
+        OpBlock { header: Header::default(), body: BlockBody::default() }
+    }
+
+    fn config(holocene_time: Option<u64>) -> Arc<RollupConfig> {
+        Arc::new(RollupConfig {
+            block_time: 2,
+            max_sequencer_drift: 600,
+            seq_window_size: 10,
+            channel_timeout: 30,
+            granite_channel_timeout: 30,
+            genesis: ChainGenesis {
+                l1: l1_chain(1)[0].id(),
+                l2: BlockNumHash { number: 0, hash: safe_head().header.hash_slow() },
+                system_config: Some(SystemConfig::default()),
+                ..Default::default()
+            },
+            hardforks: HardForkConfig { holocene_time, ..Default::default() },
+            ..Default::default()
+        })
+    }
+
+    fn batch(timestamp: u64) -> Batch {
+        let epoch = l1_chain(1)[0];
+        Batch::Single(SingleBatch {
+            epoch_num: epoch.number,
+            epoch_hash: epoch.hash,
+            timestamp,
+            ..Default::default()
+        })
+    }
+
+    fn channel(id: u8, batches: &[Batch]) -> SimulatedChannel {
+        batches.iter().cloned().fold(SimulatedChannel::new([id; 16]), SimulatedChannel::with_batch)
+    }
+
+    fn frames(id: u8, batches: &[Batch]) -> Vec<Frame> {
+        channel(id, batches).frames(1_000).unwrap()
+    }
+
+    /// Splits the channel into exactly two frames.
+    fn split_frames(id: u8, batches: &[Batch]) -> Vec<Frame> {
+        let channel = channel(id, batches);
+        let frames = channel.frames(channel.encode().unwrap().len().div_ceil(2)).unwrap();
+        assert_eq!(frames.len(), 2);
+        frames
+    }
+
+    #[tokio::test]
+    async fn test_simulate_holocene_in_order() {
+        let l1 = l1_chain(3);
+        let report = BatchSimulator::new(config(Some(0)), l1.clone(), safe_head())
+            .with_frames(1, &frames(1, &[batch(2), batch(4)]))
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(report.end, SimulationEnd::L1Exhausted);
+        assert_eq!(report.batches.len(), 2);
+        assert_eq!(report.batches_with(BatchVerdict::Accepted).count(), 2);
+        assert_eq!(report.blocks.len(), 2);
+        assert_eq!(report.blocks[0].batch, Some(0));
+        assert_eq!(report.blocks[1].batch, Some(1));
+        assert_eq!(report.blocks[1].derived_from, l1[1]);
+        assert_eq!(report.blocks[1].block.block_info.parent_hash, report.blocks[0].block.hash());
+        assert_eq!(report.safe_head, report.blocks[1].block);
+        assert_eq!(report.safe_head.block_info.timestamp, 4);
+        assert_eq!(report.flushes(), 0);
+        assert_eq!(report.resets(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_holocene_flushes_out_of_order_channel() {
+        let report = BatchSimulator::new(config(Some(0)), l1_chain(3), safe_head())
+            .with_frames(1, &frames(1, &[batch(4), batch(2)]))
+            .with_frames(2, &frames(2, &[batch(2)]))
+            .run()
+            .await
+            .unwrap();
+
+        // The gap drops the first batch and flushes the rest of its channel.
+        assert_eq!(report.batches.len(), 2);
+        assert_eq!(report.batches[0].verdict, BatchVerdict::Dropped);
+        assert_eq!(report.batches[1].verdict, BatchVerdict::Accepted);
+        assert_eq!(report.batches[1].inclusion_block.number, 2);
+        assert!(
+            report.events.contains(&SimulationEvent::ChannelFlushed {
+                origin: l1_chain(2)[1],
+                batch: Some(0),
+            })
+        );
+        assert_eq!(report.flushes(), 1);
+        assert_eq!(report.blocks.len(), 1);
+        assert_eq!(report.blocks[0].batch, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_simulate_holocene_drops_out_of_order_frames() {
+        let mut split = split_frames(1, &[batch(2)]);
+        split.reverse();
+
+        // Before Holocene, the channel bank reassembles the channel.
+        let report = BatchSimulator::new(config(None), l1_chain(3), safe_head())
+            .with_frames(1, &split)
+            .run()
+            .await
+            .unwrap();
+        assert_eq!(report.batches.len(), 1);
+        assert_eq!(report.batches[0].verdict, BatchVerdict::Accepted);
+
+        // With Holocene, the frame queue drops frame 0 after frame 1, and frame 1 cannot open a
+        // channel, so only the resubmitted channel is read.
+        let report = BatchSimulator::new(config(Some(0)), l1_chain(4), safe_head())
+            .with_frames(1, &split)
+            .with_frames(2, &frames(2, &[batch(2)]))
+            .run()
+            .await
+            .unwrap();
+        assert_eq!(report.end, SimulationEnd::L1Exhausted);
+        assert_eq!(report.batches.len(), 1);
+        assert_eq!(report.batches[0].verdict, BatchVerdict::Accepted);
+        assert_eq!(report.batches[0].inclusion_block.number, 2);
+        assert_eq!(report.blocks.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_span_batch() {
+        let epoch = l1_chain(1)[0];
+        let parent_hash = safe_head().header.hash_slow();
+        let mut span = SpanBatch::default();
+        for timestamp in [2, 4] {
+            let single = SingleBatch {
+                parent_hash,
+                epoch_num: epoch.number,
+                epoch_hash: epoch.hash,
+                timestamp,
+                ..Default::default()
+            };
+            span.append_singular_batch(single, 1).unwrap();
+        }
+
+        let report = BatchSimulator::new(config(Some(0)), l1_chain(3), safe_head())
+            .with_frames(1, &frames(1, &[Batch::Span(span)]))
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(report.end, SimulationEnd::L1Exhausted);
+        assert_eq!(report.batches.len(), 1);
+        assert!(matches!(report.batches[0].batch, Batch::Span(_)));
+        assert_eq!(report.batches[0].verdict, BatchVerdict::Accepted);
+        assert_eq!(report.batches[0].derived, [0, 1]);
+        assert_eq!(report.blocks.len(), 2);
+        assert!(report.blocks.iter().all(|block| block.batch == Some(0)));
+        assert_eq!(report.safe_head.block_info.timestamp, 4);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_channel_timeout() {
+        let mut cfg = (*config(Some(0))).clone();
+        cfg.channel_timeout = 2;
+        cfg.granite_channel_timeout = 2;
+        let cfg = Arc::new(cfg);
+        let l1 = l1_chain(5);
+        let split = split_frames(1, &[batch(2)]);
+
+        // The last frame is included at the last L1 block the channel is open for.
+        let report = BatchSimulator::new(Arc::clone(&cfg), l1.clone(), safe_head())
+            .with_frames(1, &split[..1])
+            .with_frames(3, &split[1..])
+            .run()
+            .await
+            .unwrap();
+        assert_eq!(report.batches.len(), 1);
+        assert_eq!(report.batches[0].verdict, BatchVerdict::Accepted);
+        assert_eq!(report.blocks[0].derived_from, l1[3]);
+
+        // One L1 block later, the channel has timed out and its last frame is ignored.
+        let report = BatchSimulator::new(cfg, l1, safe_head())
+            .with_frames(1, &split[..1])
+            .with_frames(4, &split[1..])
+            .with_frames(4, &frames(2, &[batch(2)]))
+            .run()
+            .await
+            .unwrap();
+        assert_eq!(report.end, SimulationEnd::L1Exhausted);
+        assert_eq!(report.batches.len(), 1);
+        assert_eq!(report.batches[0].verdict, BatchVerdict::Accepted);
+        assert_eq!(report.batches[0].inclusion_block.number, 4);
+        assert_eq!(report.blocks.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_holocene_activation() {
+        // Holocene activates with L1 block 2.
+        let l1 = l1_chain(4);
+        let report = BatchSimulator::new(config(Some(l1[2].timestamp)), l1.clone(), safe_head())
+            .with_frames(1, &frames(1, &[batch(2)]))
+            .with_frames(3, &frames(2, &[batch(4)]))
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(report.end, SimulationEnd::L1Exhausted);
+        assert_eq!(
+            report
+                .events
+                .iter()
+                .filter(|event| matches!(event, SimulationEvent::HoloceneActivated(_)))
+                .collect::<Vec<_>>(),
+            [&SimulationEvent::HoloceneActivated(l1[2])]
+        );
+        assert_eq!(report.resets(), 0);
+        assert_eq!(report.batches_with(BatchVerdict::Accepted).count(), 2);
+        assert_eq!(report.blocks.len(), 2);
+        assert_eq!(report.blocks[0].derived_from, l1[1]);
+        assert_eq!(report.blocks[1].derived_from, l1[3]);
+        assert_eq!(report.safe_head.block_info.timestamp, 4);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_pre_holocene_future_batch_pending() {
+        let report = BatchSimulator::new(config(None), l1_chain(3), safe_head())
+            .with_frames(1, &frames(1, &[batch(4)]))
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(report.end, SimulationEnd::L1Exhausted);
+        assert_eq!(report.batches.len(), 1);
+        assert_eq!(report.batches[0].verdict, BatchVerdict::Pending);
+        assert!(report.blocks.is_empty());
+        assert_eq!(report.flushes(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_unknown_inclusion_block() {
+        let err = BatchSimulator::new(config(Some(0)), l1_chain(3), safe_head())
+            .with_submission(9, Bytes::default())
+            .run()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SimulatorError::UnknownInclusionBlock(9)));
+    }
+}
@@ -0,0 +1,21 @@
+//! A simulator for batcher submission strategies.
+//!
+//! The [`BatchSimulator`] feeds a hypothetical sequence of batcher submissions through the
+//! batch stages of the derivation pipeline, and reports which batches the pipeline accepts,
+//! drops or keeps waiting on, along with the resets and Holocene channel flushes they cause.
+//! [`SimulatedChannel`] builds the frames a batcher would submit for a list of batches.
+
+mod channel;
+pub use channel::SimulatedChannel;
+
+mod core;
+pub use core::BatchSimulator;
+
+mod providers;
+
+mod recorder;
+
+mod report;
+pub use report::{
+    BatchVerdict, DerivedBlock, SimulatedBatch, SimulationEnd, SimulationEvent, SimulationReport,
+};
@@ -0,0 +1,172 @@
+//! In-memory providers backing the [`BatchSimulator`].
+//!
+//! [`BatchSimulator`]: crate::BatchSimulator
+
+use crate::{
+    ChainProvider, DataAvailabilityProvider, L2ChainProvider, PipelineError, PipelineResult,
+    SimulatorProviderError,
+};
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
+use alloy_consensus::{Header, Receipt, TxEnvelope};
+use alloy_primitives::{Address, B256, Bytes};
+use async_trait::async_trait;
+use kona_genesis::{RollupConfig, SystemConfig};
+use kona_protocol::{BatchValidationProvider, BlockInfo, L2BlockInfo};
+use op_alloy_consensus::OpBlock;
+use spin::RwLock;
+
+/// A [`ChainProvider`] serving a fixed, contiguous L1 chain.
+///
+/// The simulated L1 chain carries no receipts, so the system config never changes.
+#[derive(Debug, Clone)]
+pub(crate) struct SimulatedL1 {
+    blocks: Arc<Vec<BlockInfo>>,
+}
+
+impl SimulatedL1 {
+    /// Creates a new [`SimulatedL1`] from a contiguous chain of blocks.
+    pub(crate) fn new(blocks: Vec<BlockInfo>) -> Self {
+        Self { blocks: Arc::new(blocks) }
+    }
+
+    fn block_by_hash(&self, hash: B256) -> Result<BlockInfo, SimulatorProviderError> {
+        self.blocks
+            .iter()
+            .find(|b| b.hash == hash)
+            .copied()
+            .ok_or(SimulatorProviderError::L1BlockNotFound)
+    }
+}
+
+#[async_trait]
+impl ChainProvider for SimulatedL1 {
+    type Error = SimulatorProviderError;
+
+    async fn header_by_hash(&mut self, _: B256) -> Result<Header, Self::Error> {
+        Err(SimulatorProviderError::HeaderNotFound)
+    }
+
+    async fn block_info_by_number(&mut self, number: u64) -> Result<BlockInfo, Self::Error> {
+        self.blocks
+            .iter()
+            .find(|b| b.number == number)
+            .copied()
+            .ok_or(SimulatorProviderError::L1BlockNotFound)
+    }
+
+    async fn receipts_by_hash(&mut self, hash: B256) -> Result<Vec<Receipt>, Self::Error> {
+        self.block_by_hash(hash).map(|_| Vec::new())
+    }
+
+    async fn block_info_and_transactions_by_hash(
+        &mut self,
+        hash: B256,
+    ) -> Result<(BlockInfo, Vec<TxEnvelope>), Self::Error> {
+        self.block_by_hash(hash).map(|b| (b, Vec::new()))
+    }
+}
+
+/// A [`DataAvailabilityProvider`] serving the batcher submissions of each L1 block.
+///
+/// Every submission is treated as if it were sent by the batcher.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SimulatedDataSource {
+    submissions: BTreeMap<u64, Vec<Bytes>>,
+    cursor: Option<(B256, usize)>,
+}
+
+impl SimulatedDataSource {
+    /// Creates a new [`SimulatedDataSource`] from the submissions keyed by L1 block number.
+    pub(crate) const fn new(submissions: BTreeMap<u64, Vec<Bytes>>) -> Self {
+        Self { submissions, cursor: None }
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityProvider for SimulatedDataSource {
+    type Item = Bytes;
+
+    async fn next(&mut self, block_ref: &BlockInfo, _: Address) -> PipelineResult<Self::Item> {
+        let index = match self.cursor {
+            Some((hash, index)) if hash == block_ref.hash => index,
+            _ => 0,
+        };
+        let data = self
+            .submissions
+            .get(&block_ref.number)
+            .and_then(|s| s.get(index))
+            .cloned()
+            .ok_or(PipelineError::Eof.temp())?;
+        self.cursor = Some((block_ref.hash, index + 1));
+        Ok(data)
+    }
+
+    fn clear(&mut self) {
+        self.cursor = None;
+    }
+}
+
+/// An [`L2ChainProvider`] serving the L2 chain derived so far, starting at the initial safe head.
+///
+/// Clones share the same chain, so blocks pushed by the simulator are visible to every stage.
+#[derive(Debug, Clone)]
+pub(crate) struct SimulatedL2 {
+    blocks: Arc<RwLock<Vec<(L2BlockInfo, OpBlock)>>>,
+    system_config: SystemConfig,
+}
+
+impl SimulatedL2 {
+    /// Creates a new [`SimulatedL2`] whose chain starts at the given safe head.
+    pub(crate) fn new(safe_head: L2BlockInfo, block: OpBlock, system_config: SystemConfig) -> Self {
+        Self { blocks: Arc::new(RwLock::new(Vec::from([(safe_head, block)]))), system_config }
+    }
+
+    /// Returns the system config the chain was started with.
+    pub(crate) const fn system_config(&self) -> &SystemConfig {
+        &self.system_config
+    }
+
+    /// Appends a block on top of the chain.
+    pub(crate) fn push(&self, info: L2BlockInfo, block: OpBlock) {
+        self.blocks.write().push((info, block));
+    }
+
+    fn find<T>(
+        &self,
+        number: u64,
+        f: impl FnOnce(&(L2BlockInfo, OpBlock)) -> T,
+    ) -> Result<T, SimulatorProviderError> {
+        self.blocks
+            .read()
+            .iter()
+            .find(|(info, _)| info.block_info.number == number)
+            .map(f)
+            .ok_or(SimulatorProviderError::L2BlockNotFound(number))
+    }
+}
+
+#[async_trait]
+impl BatchValidationProvider for SimulatedL2 {
+    type Error = SimulatorProviderError;
+
+    async fn l2_block_info_by_number(&mut self, number: u64) -> Result<L2BlockInfo, Self::Error> {
+        self.find(number, |(info, _)| *info)
+    }
+
+    async fn block_by_number(&mut self, number: u64) -> Result<OpBlock, Self::Error> {
+        self.find(number, |(_, block)| block.clone())
+    }
+}
+
+#[async_trait]
+impl L2ChainProvider for SimulatedL2 {
+    type Error = SimulatorProviderError;
+
+    async fn system_config_by_number(
+        &mut self,
+        number: u64,
+        _: Arc<RollupConfig>,
+    ) -> Result<SystemConfig, <Self as L2ChainProvider>::Error> {
+        self.find(number, |_| self.system_config)
+    }
+}
@@ -0,0 +1,87 @@
+//! Contains the [`BatchRecorder`] stage used by the [`BatchSimulator`].
+//!
+//! [`BatchSimulator`]: crate::BatchSimulator
+
+use crate::{
+    BatchStreamProvider, OriginAdvancer, OriginProvider, PipelineError, PipelineResult, Signal,
+    SignalReceiver,
+};
+use alloc::{boxed::Box, vec::Vec};
+use async_trait::async_trait;
+use core::fmt::Debug;
+use kona_protocol::{Batch, BatchWithInclusionBlock, BlockInfo};
+
+/// A pass-through stage between the [`ChannelReader`] and the [`BatchStream`] that records every
+/// decoded [`Batch`] and every channel flush requested by the batch stages.
+///
+/// [`ChannelReader`]: crate::ChannelReader
+/// [`BatchStream`]: crate::BatchStream
+#[derive(Debug)]
+pub(crate) struct BatchRecorder<P>
+where
+    P: BatchStreamProvider + OriginAdvancer + OriginProvider + SignalReceiver + Debug,
+{
+    /// The previous stage in the derivation pipeline.
+    pub(crate) prev: P,
+    /// The batches read since the recorder was last drained.
+    pub(crate) read: Vec<BatchWithInclusionBlock>,
+    /// Whether a flush was requested since the recorder was last drained.
+    pub(crate) flushed: bool,
+}
+
+impl<P> BatchRecorder<P>
+where
+    P: BatchStreamProvider + OriginAdvancer + OriginProvider + SignalReceiver + Debug,
+{
+    /// Creates a new [`BatchRecorder`] stage.
+    pub(crate) const fn new(prev: P) -> Self {
+        Self { prev, read: Vec::new(), flushed: false }
+    }
+}
+
+#[async_trait]
+impl<P> BatchStreamProvider for BatchRecorder<P>
+where
+    P: BatchStreamProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send + Debug,
+{
+    async fn next_batch(&mut self) -> PipelineResult<Batch> {
+        let batch = self.prev.next_batch().await?;
+        let origin = self.prev.origin().ok_or(PipelineError::MissingOrigin.crit())?;
+        self.read.push(BatchWithInclusionBlock::new(origin, batch.clone()));
+        Ok(batch)
+    }
+
+    fn flush(&mut self) {
+        self.flushed = true;
+        self.prev.flush();
+    }
+}
+
+#[async_trait]
+impl<P> OriginAdvancer for BatchRecorder<P>
+where
+    P: BatchStreamProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send + Debug,
+{
+    async fn advance_origin(&mut self) -> PipelineResult<()> {
+        self.prev.advance_origin().await
+    }
+}
+
+impl<P> OriginProvider for BatchRecorder<P>
+where
+    P: BatchStreamProvider + OriginAdvancer + OriginProvider + SignalReceiver + Debug,
+{
+    fn origin(&self) -> Option<BlockInfo> {
+        self.prev.origin()
+    }
+}
+
+#[async_trait]
+impl<P> SignalReceiver for BatchRecorder<P>
+where
+    P: BatchStreamProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send + Debug,
+{
+    async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+        self.prev.signal(signal).await
+    }
+}
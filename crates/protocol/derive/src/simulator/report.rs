@@ -0,0 +1,124 @@
+//! Contains the [`SimulationReport`] produced by the [`BatchSimulator`].
+//!
+//! [`BatchSimulator`]: crate::BatchSimulator
+
+use crate::{PipelineErrorKind, ResetError};
+use alloc::vec::Vec;
+use kona_protocol::{Batch, BlockInfo, L2BlockInfo};
+
+/// The outcome of a [`BatchSimulator`] run.
+///
+/// [`BatchSimulator`]: crate::BatchSimulator
+#[derive(Debug, PartialEq, Eq)]
+pub struct SimulationReport {
+    /// Every batch decoded from the submitted channels, in the order the pipeline read them.
+    pub batches: Vec<SimulatedBatch>,
+    /// The L2 blocks derived on top of the starting safe head, in order.
+    pub blocks: Vec<DerivedBlock>,
+    /// The events observed while running the pipeline, in order.
+    pub events: Vec<SimulationEvent>,
+    /// The safe head at the end of the run.
+    pub safe_head: L2BlockInfo,
+    /// The reason the run ended.
+    pub end: SimulationEnd,
+}
+
+impl SimulationReport {
+    /// Returns an iterator over the batches with the given [`BatchVerdict`].
+    pub fn batches_with(&self, verdict: BatchVerdict) -> impl Iterator<Item = &SimulatedBatch> {
+        self.batches.iter().filter(move |b| b.verdict == verdict)
+    }
+
+    /// Returns the number of channel flushes triggered by invalid batches.
+    pub fn flushes(&self) -> usize {
+        self.events.iter().filter(|e| matches!(e, SimulationEvent::ChannelFlushed { .. })).count()
+    }
+
+    /// Returns the number of pipeline resets, excluding Holocene activation.
+    pub fn resets(&self) -> usize {
+        self.events.iter().filter(|e| matches!(e, SimulationEvent::Reset { .. })).count()
+    }
+}
+
+/// A batch decoded by the pipeline during a simulation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulatedBatch {
+    /// The L1 block in which the channel containing the batch was completed.
+    pub inclusion_block: BlockInfo,
+    /// The decoded batch.
+    pub batch: Batch,
+    /// Indices into [`SimulationReport::blocks`] of the blocks derived from this batch.
+    pub derived: Vec<usize>,
+    /// The verdict of the pipeline on this batch.
+    pub verdict: BatchVerdict,
+}
+
+/// The verdict of the pipeline on a [`SimulatedBatch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchVerdict {
+    /// The batch was fully applied: its last block became safe.
+    ///
+    /// Blocks of a span batch that overlap the starting safe chain are not derived again, so
+    /// `derived` may not cover every block of the batch.
+    Accepted,
+    /// The batch is still buffered by the pipeline, waiting for more L1 data or for earlier
+    /// batches to fill the gap before it.
+    Pending,
+    /// The batch was dropped, either as invalid, as outdated, or together with a flushed channel.
+    /// Blocks derived from a partially applied span batch remain in `derived`.
+    Dropped,
+}
+
+/// A block derived during a simulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DerivedBlock {
+    /// The derived block.
+    pub block: L2BlockInfo,
+    /// The L1 origin of the pipeline when the block was derived.
+    pub derived_from: BlockInfo,
+    /// The index into [`SimulationReport::batches`] of the batch that produced the block, or
+    /// [`None`] if the pipeline generated an empty batch because the sequencing window expired.
+    pub batch: Option<usize>,
+}
+
+/// An event observed while simulating the pipeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulationEvent {
+    /// The pipeline advanced its L1 origin to the given block.
+    OriginAdvanced(BlockInfo),
+    /// The pipeline decoded the batch at the given index of [`SimulationReport::batches`].
+    BatchRead(usize),
+    /// The block at the given index of [`SimulationReport::blocks`] was derived.
+    BlockDerived(usize),
+    /// A batch was found to be invalid after Holocene, and the channel it was read from was
+    /// flushed.
+    ChannelFlushed {
+        /// The L1 origin of the pipeline at the time of the flush.
+        origin: BlockInfo,
+        /// The index of the most recently decoded batch, which triggered the flush.
+        batch: Option<usize>,
+    },
+    /// The pipeline requested a reset, which was applied.
+    Reset {
+        /// The L1 origin the pipeline was reset to.
+        origin: BlockInfo,
+        /// The safe head the pipeline was reset to.
+        safe_head: L2BlockInfo,
+        /// The error that caused the reset.
+        error: ResetError,
+    },
+    /// The pipeline crossed the Holocene activation block and was reset with an activation
+    /// signal.
+    HoloceneActivated(BlockInfo),
+}
+
+/// The reason a simulation ended.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SimulationEnd {
+    /// All submitted data was processed, and the pipeline needs a new L1 block to progress.
+    L1Exhausted,
+    /// The pipeline returned a critical error, or rejected a signal.
+    Halted(PipelineErrorKind),
+    /// The pipeline stopped making progress, repeatedly returning the given error.
+    Stalled(PipelineErrorKind),
+}
@@ -4,15 +4,7 @@ use crate::BlobDecodingError;
 use alloc::{boxed::Box, vec};
 use alloy_eips::eip4844::{BYTES_PER_BLOB, Blob, VERSIONED_HASH_VERSION_KZG};
 use alloy_primitives::Bytes;
-
-/// The blob encoding version
-pub(crate) const BLOB_ENCODING_VERSION: u8 = 0;
-
-/// Maximum blob data size
-pub(crate) const BLOB_MAX_DATA_SIZE: usize = (4 * 31 + 3) * 1024 - 4; // 130044
-
-/// Blob Encoding/Decoding Rounds
-pub(crate) const BLOB_ENCODING_ROUNDS: usize = 1024;
+use kona_protocol::{BLOB_ENCODING_ROUNDS, BLOB_ENCODING_VERSION, BLOB_MAX_DATA_SIZE};
 
 /// The Blob Data
 #[derive(Default, Clone, Debug)]
@@ -168,6 +160,8 @@ impl BlobData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use kona_protocol::{encode_blob, encode_blobs};
+    use proptest::prelude::*;
 
     #[test]
     fn test_reassemble_bytes() {
@@ -284,4 +278,38 @@ mod tests {
         assert_eq!(blob_data.decode_field_element(0, 0, &mut output), Ok((0, 32, 32)));
         assert_eq!(output, vec![1u8; 31]);
     }
+
+    #[test]
+    fn test_decode_encoded_empty_blob() {
+        let blob = encode_blob(&[]).unwrap();
+        let blob_data = BlobData { data: Some(Bytes::from(blob)), ..Default::default() };
+        assert_eq!(blob_data.decode(), Ok(Bytes::new()));
+    }
+
+    proptest! {
+        #[test]
+        fn test_encode_decode_roundtrip(
+            data in prop::collection::vec(any::<u8>(), 0..=BLOB_MAX_DATA_SIZE)
+        ) {
+            let blob = encode_blob(&data).unwrap();
+            let blob_data = BlobData { data: Some(Bytes::from(blob)), ..Default::default() };
+            prop_assert_eq!(blob_data.decode().unwrap(), Bytes::from(data));
+        }
+
+        #[test]
+        fn test_encode_blobs_decode_roundtrip(
+            data in prop::collection::vec(any::<u8>(), 1..=BLOB_MAX_DATA_SIZE * 3)
+        ) {
+            let blobs = encode_blobs(&data).into_iter().map(Box::new).collect::<Vec<_>>();
+            prop_assert_eq!(blobs.len(), data.len().div_ceil(BLOB_MAX_DATA_SIZE));
+
+            let mut decoded = Vec::with_capacity(data.len());
+            for index in 0..blobs.len() {
+                let mut blob_data = BlobData::default();
+                prop_assert_eq!(blob_data.fill(&blobs, index), Ok(true));
+                decoded.extend_from_slice(&blob_data.decode().unwrap());
+            }
+            prop_assert_eq!(decoded, data);
+        }
+    }
 }
@@ -0,0 +1,413 @@
+//! Semantic validation of the [`RollupConfig`].
+
+use crate::{GRANITE_CHANNEL_TIMEOUT, RollupConfig};
+use alloc::{string::String, vec::Vec};
+
+/// The keccak256 AltDA commitment type.
+const KECCAK_COMMITMENT_TYPE: &str = "KeccakCommitment";
+
+/// The generic AltDA commitment type.
+const GENERIC_COMMITMENT_TYPE: &str = "GenericCommitment";
+
+/// An error found while validating a [`RollupConfig`].
+///
+/// See [`RollupConfig::validate`].
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+pub enum RollupConfigError {
+    /// The L2 block time is zero.
+    #[error("Block time cannot be 0")]
+    ZeroBlockTime,
+    /// The channel timeout is zero.
+    #[error("Channel timeout cannot be 0")]
+    MissingChannelTimeout,
+    /// The Granite channel timeout does not match the protocol constant.
+    #[error("Granite channel timeout must be {expected}, got {actual}")]
+    GraniteChannelTimeoutMismatch {
+        /// The expected channel timeout.
+        expected: u64,
+        /// The configured channel timeout.
+        actual: u64,
+    },
+    /// The sequencing window is too small.
+    #[error("Sequencing window size must be at least 2, got {0}")]
+    InvalidSeqWindowSize(u64),
+    /// The genesis L1 block hash is zero.
+    #[error("Missing genesis L1 block hash")]
+    MissingGenesisL1Hash,
+    /// The genesis L2 block hash is zero.
+    #[error("Missing genesis L2 block hash")]
+    MissingGenesisL2Hash,
+    /// The genesis L1 and L2 block hashes are the same.
+    #[error("Genesis L1 and L2 block hashes cannot be the same")]
+    GenesisHashesSame,
+    /// The genesis L2 timestamp is zero.
+    #[error("Missing genesis L2 timestamp")]
+    MissingGenesisL2Time,
+    /// The genesis system config is missing.
+    #[error("Missing genesis system config")]
+    MissingGenesisSystemConfig,
+    /// The genesis batcher address is zero.
+    #[error("Missing genesis batcher address")]
+    MissingBatcherAddress,
+    /// The genesis gas limit is zero.
+    #[error("Missing genesis gas limit")]
+    MissingGasLimit,
+    /// The L2 chain ID is zero.
+    #[error("L2 chain ID cannot be 0")]
+    ZeroL2ChainId,
+    /// The L1 and L2 chain IDs are the same.
+    #[error("L1 and L2 chain IDs cannot be the same: {0}")]
+    ChainIdsSame(u64),
+    /// The batch inbox address is zero.
+    #[error("Missing batch inbox address")]
+    MissingBatchInboxAddress,
+    /// The deposit contract address is zero.
+    #[error("Missing deposit contract address")]
+    MissingDepositContractAddress,
+    /// A hardfork activates before a hardfork that precedes it.
+    #[error("Hardfork {fork} at {time} activates before prior hardfork {prior} at {prior_time}")]
+    HardforkOutOfOrder {
+        /// The name of the hardfork.
+        fork: &'static str,
+        /// The activation timestamp of the hardfork.
+        time: u64,
+        /// The name of the prior hardfork.
+        prior: &'static str,
+        /// The activation timestamp of the prior hardfork.
+        prior_time: u64,
+    },
+    /// The interop activation timestamp is not aligned to the L2 block time.
+    #[error("Interop time {time} is not a multiple of the block time {block_time} after genesis")]
+    InteropTimeNotAligned {
+        /// The interop activation timestamp.
+        time: u64,
+        /// The L2 block time.
+        block_time: u64,
+    },
+    /// AltDA fields are set, but AltDA is not enabled.
+    #[error("AltDA config is set, but no DA challenge address is configured")]
+    AltDaFieldsWithoutAltDa,
+    /// AltDA is enabled without a challenge window.
+    #[error("Missing AltDA challenge window")]
+    MissingAltDaChallengeWindow,
+    /// AltDA is enabled without a resolve window.
+    #[error("Missing AltDA resolve window")]
+    MissingAltDaResolveWindow,
+    /// The AltDA commitment type is unknown.
+    #[error("Invalid AltDA commitment type: {0}")]
+    InvalidAltDaCommitmentType(String),
+}
+
+impl RollupConfig {
+    /// Checks the [`RollupConfig`] for semantic errors, mirroring `op-node`'s config checks.
+    ///
+    /// All checks are run, and every error found is returned.
+    pub fn validate(&self) -> Result<(), Vec<RollupConfigError>> {
+        let mut errors = Vec::new();
+
+        if self.block_time == 0 {
+            errors.push(RollupConfigError::ZeroBlockTime);
+        }
+        if self.channel_timeout == 0 {
+            errors.push(RollupConfigError::MissingChannelTimeout);
+        }
+        if self.hardforks.granite_time.is_some() &&
+            self.granite_channel_timeout != GRANITE_CHANNEL_TIMEOUT
+        {
+            errors.push(RollupConfigError::GraniteChannelTimeoutMismatch {
+                expected: GRANITE_CHANNEL_TIMEOUT,
+                actual: self.granite_channel_timeout,
+            });
+        }
+        if self.seq_window_size < 2 {
+            errors.push(RollupConfigError::InvalidSeqWindowSize(self.seq_window_size));
+        }
+
+        self.validate_genesis(&mut errors);
+
+        if self.l2_chain_id.id() == 0 {
+            errors.push(RollupConfigError::ZeroL2ChainId);
+        } else if self.l2_chain_id.id() == self.l1_chain_id {
+            errors.push(RollupConfigError::ChainIdsSame(self.l1_chain_id));
+        }
+        if self.batch_inbox_address.is_zero() {
+            errors.push(RollupConfigError::MissingBatchInboxAddress);
+        }
+        if self.deposit_contract_address.is_zero() {
+            errors.push(RollupConfigError::MissingDepositContractAddress);
+        }
+
+        self.validate_hardforks(&mut errors);
+        self.validate_alt_da(&mut errors);
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    /// Checks the genesis block references and system config.
+    fn validate_genesis(&self, errors: &mut Vec<RollupConfigError>) {
+        let genesis = &self.genesis;
+        if genesis.l1.hash.is_zero() {
+            errors.push(RollupConfigError::MissingGenesisL1Hash);
+        }
+        if genesis.l2.hash.is_zero() {
+            errors.push(RollupConfigError::MissingGenesisL2Hash);
+        }
+        if !genesis.l1.hash.is_zero() && genesis.l1.hash == genesis.l2.hash {
+            errors.push(RollupConfigError::GenesisHashesSame);
+        }
+        if genesis.l2_time == 0 {
+            errors.push(RollupConfigError::MissingGenesisL2Time);
+        }
+
+        let Some(system_config) = &genesis.system_config else {
+            errors.push(RollupConfigError::MissingGenesisSystemConfig);
+            return;
+        };
+        if system_config.batcher_address.is_zero() {
+            errors.push(RollupConfigError::MissingBatcherAddress);
+        }
+        if system_config.gas_limit == 0 {
+            errors.push(RollupConfigError::MissingGasLimit);
+        }
+    }
+
+    /// Checks that the scheduled hardforks activate in order, and that interop activates on a
+    /// block boundary.
+    ///
+    /// Unscheduled hardforks are skipped, since a later hardfork implies the earlier ones.
+    fn validate_hardforks(&self, errors: &mut Vec<RollupConfigError>) {
+        let forks = &self.hardforks;
+        let schedule = [
+            ("Regolith", forks.regolith_time),
+            ("Canyon", forks.canyon_time),
+            ("Delta", forks.delta_time),
+            ("Ecotone", forks.ecotone_time),
+            ("Fjord", forks.fjord_time),
+            ("Granite", forks.granite_time),
+            ("Holocene", forks.holocene_time),
+            ("Isthmus", forks.isthmus_time),
+            ("Jovian", forks.jovian_time),
+            ("Interop", forks.interop_time),
+        ];
+
+        let mut prior: Option<(&'static str, u64)> = None;
+        for (fork, time) in schedule {
+            let Some(time) = time else { continue };
+            if let Some((prior, prior_time)) = prior.filter(|(_, prior_time)| *prior_time > time) {
+                errors.push(RollupConfigError::HardforkOutOfOrder {
+                    fork,
+                    time,
+                    prior,
+                    prior_time,
+                });
+            }
+            prior = Some((fork, time));
+        }
+
+        let l2_time = self.genesis.l2_time;
+        let misaligned = forks.interop_time.filter(|time| {
+            self.block_time != 0 && *time > l2_time && (time - l2_time) % self.block_time != 0
+        });
+        if let Some(time) = misaligned {
+            errors.push(RollupConfigError::InteropTimeNotAligned {
+                time,
+                block_time: self.block_time,
+            });
+        }
+    }
+
+    /// Checks that the AltDA config is consistent with whether AltDA is enabled.
+    fn validate_alt_da(&self, errors: &mut Vec<RollupConfigError>) {
+        let Some(alt_da) = &self.alt_da_config else {
+            return;
+        };
+
+        if !self.is_alt_da_enabled() {
+            if alt_da.da_challenge_address.is_some_and(|addr| !addr.is_zero()) ||
+                alt_da.da_challenge_window.is_some() ||
+                alt_da.da_resolve_window.is_some() ||
+                alt_da.da_commitment_type.is_some()
+            {
+                errors.push(RollupConfigError::AltDaFieldsWithoutAltDa);
+            }
+            return;
+        }
+
+        if alt_da.da_challenge_window.unwrap_or_default() == 0 {
+            errors.push(RollupConfigError::MissingAltDaChallengeWindow);
+        }
+        if alt_da.da_resolve_window.unwrap_or_default() == 0 {
+            errors.push(RollupConfigError::MissingAltDaResolveWindow);
+        }
+        let unknown = alt_da
+            .da_commitment_type
+            .as_ref()
+            .filter(|ty| !matches!(ty.as_str(), KECCAK_COMMITMENT_TYPE | GENERIC_COMMITMENT_TYPE));
+        if let Some(ty) = unknown {
+            errors.push(RollupConfigError::InvalidAltDaCommitmentType(ty.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AltDAConfig, ChainGenesis, HardForkConfig, SystemConfig};
+    use alloc::vec;
+    use alloy_chains::Chain;
+    use alloy_eips::eip1898::BlockNumHash;
+    use alloy_primitives::{Address, B256, address, b256};
+
+    fn valid_config() -> RollupConfig {
+        RollupConfig {
+            genesis: ChainGenesis {
+                l1: BlockNumHash {
+                    number: 1,
+                    hash: b256!("438335a20d98863a4c0c97999eb2481921ccd28553eac6f913af7c12aec04108"),
+                },
+                l2: BlockNumHash {
+                    number: 2,
+                    hash: b256!("dbf6a80fef073de06add9b0d14026d6e5a86c85f6d102c36d3d8e9cf89c2afd3"),
+                },
+                l2_time: 1_686_068_903,
+                system_config: Some(SystemConfig {
+                    batcher_address: address!("6887246668a3b87f54deb3b94ba47a6f63f32985"),
+                    gas_limit: 30_000_000,
+                    ..Default::default()
+                }),
+            },
+            block_time: 2,
+            seq_window_size: 3600,
+            channel_timeout: 300,
+            l1_chain_id: 1,
+            l2_chain_id: Chain::from_id(10),
+            batch_inbox_address: address!("ff00000000000000000000000000000000000010"),
+            deposit_contract_address: address!("beb5fc579115071764c7423a4f12edde41f106ed"),
+            hardforks: HardForkConfig {
+                canyon_time: Some(1_704_992_401),
+                fjord_time: Some(1_720_627_201),
+                granite_time: Some(1_726_070_401),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_valid_config() {
+        assert_eq!(valid_config().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_default_config() {
+        let errors = RollupConfig::default().validate().unwrap_err();
+        assert!(errors.contains(&RollupConfigError::ZeroBlockTime));
+        assert!(errors.contains(&RollupConfigError::MissingGenesisL1Hash));
+        assert!(errors.contains(&RollupConfigError::MissingGenesisL2Hash));
+        assert!(errors.contains(&RollupConfigError::MissingGenesisSystemConfig));
+        assert!(errors.contains(&RollupConfigError::ZeroL2ChainId));
+        assert!(errors.contains(&RollupConfigError::MissingBatchInboxAddress));
+        assert!(errors.contains(&RollupConfigError::MissingDepositContractAddress));
+    }
+
+    #[test]
+    fn test_validate_genesis_hashes_same() {
+        let mut config = valid_config();
+        config.genesis.l2.hash = config.genesis.l1.hash;
+        assert_eq!(config.validate(), Err(vec![RollupConfigError::GenesisHashesSame]));
+    }
+
+    #[test]
+    fn test_validate_chain_ids_same() {
+        let mut config = valid_config();
+        config.l2_chain_id = Chain::from_id(1);
+        assert_eq!(config.validate(), Err(vec![RollupConfigError::ChainIdsSame(1)]));
+    }
+
+    #[test]
+    fn test_validate_hardfork_out_of_order() {
+        let mut config = valid_config();
+        config.hardforks.granite_time = Some(1_700_000_000);
+        assert_eq!(
+            config.validate(),
+            Err(vec![RollupConfigError::HardforkOutOfOrder {
+                fork: "Granite",
+                time: 1_700_000_000,
+                prior: "Fjord",
+                prior_time: 1_720_627_201,
+            }])
+        );
+    }
+
+    #[test]
+    fn test_validate_interop_alignment() {
+        let mut config = valid_config();
+        config.hardforks.interop_time = Some(config.genesis.l2_time + 3);
+        assert_eq!(
+            config.validate(),
+            Err(vec![RollupConfigError::InteropTimeNotAligned {
+                time: config.genesis.l2_time + 3,
+                block_time: 2,
+            }])
+        );
+
+        config.hardforks.interop_time = Some(config.genesis.l2_time + 4);
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_granite_channel_timeout() {
+        let mut config = valid_config();
+        config.granite_channel_timeout = 300;
+        assert_eq!(
+            config.validate(),
+            Err(vec![RollupConfigError::GraniteChannelTimeoutMismatch {
+                expected: GRANITE_CHANNEL_TIMEOUT,
+                actual: 300,
+            }])
+        );
+
+        config.hardforks.granite_time = None;
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_alt_da() {
+        let mut config = valid_config();
+        config.alt_da_config =
+            Some(AltDAConfig { da_challenge_window: Some(3600), ..Default::default() });
+        assert_eq!(config.validate(), Err(vec![RollupConfigError::AltDaFieldsWithoutAltDa]));
+
+        let challenge = address!("97a2da87d3439b172e6dd027220e01c9cb565b80");
+        config.da_challenge_address = Some(challenge);
+        config.alt_da_config = Some(AltDAConfig {
+            da_challenge_address: Some(challenge),
+            da_challenge_window: Some(3600),
+            da_resolve_window: None,
+            da_commitment_type: Some("Sha256Commitment".into()),
+        });
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                RollupConfigError::MissingAltDaResolveWindow,
+                RollupConfigError::InvalidAltDaCommitmentType("Sha256Commitment".into()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_missing_addresses() {
+        let mut config = valid_config();
+        config.batch_inbox_address = Address::ZERO;
+        config.deposit_contract_address = Address::ZERO;
+        config.genesis.l1.hash = B256::ZERO;
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                RollupConfigError::MissingGenesisL1Hash,
+                RollupConfigError::MissingBatchInboxAddress,
+                RollupConfigError::MissingDepositContractAddress,
+            ])
+        );
+    }
+}
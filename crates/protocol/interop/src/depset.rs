@@ -3,10 +3,26 @@ use alloy_primitives::ChainId;
 use kona_registry::HashMap;
 
 /// Configuration for a dependency of a chain
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-pub struct ChainDependency {}
+pub struct ChainDependency {
+    /// Timestamp from which the chain is part of the dependency set.
+    ///
+    /// The chain is part of the dependency set from its interop activation if unset.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub activation_time: Option<u64>,
+}
+
+impl ChainDependency {
+    /// Returns `true` if the chain is part of the dependency set at the given timestamp.
+    pub const fn is_active(&self, timestamp: u64) -> bool {
+        match self.activation_time {
+            Some(activation_time) => timestamp >= activation_time,
+            None => true,
+        }
+    }
+}
 
 /// Configuration for the dependency set
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -28,6 +44,13 @@ impl DependencySet {
             _ => MESSAGE_EXPIRY_WINDOW,
         }
     }
+
+    /// Returns `true` if the given chain is part of the dependency set at the given timestamp.
+    ///
+    /// Chains that are not listed in the dependency set are never active.
+    pub fn is_active(&self, chain_id: ChainId, timestamp: u64) -> bool {
+        self.dependencies.get(&chain_id).is_some_and(|dep| dep.is_active(timestamp))
+    }
 }
 
 #[cfg(test)]
@@ -55,6 +78,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_active() {
+        let mut deps = HashMap::default();
+        deps.insert(1, ChainDependency::default());
+        deps.insert(2, ChainDependency { activation_time: Some(100) });
+        let ds = create_dependency_set(deps, 0);
+
+        assert!(ds.is_active(1, 0));
+        assert!(!ds.is_active(2, 99));
+        assert!(ds.is_active(2, 100));
+        assert!(!ds.is_active(3, 100), "Unknown chains should never be active");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_chain_dependency_serde() {
+        let dep: ChainDependency = serde_json::from_str("{}").unwrap();
+        assert_eq!(dep, ChainDependency::default());
+
+        let dep: ChainDependency = serde_json::from_str(r#"{"activationTime":100}"#).unwrap();
+        assert_eq!(dep.activation_time, Some(100));
+        assert_eq!(serde_json::to_string(&dep).unwrap(), r#"{"activationTime":100}"#);
+    }
+
     #[test]
     fn test_get_message_expiry_window_override() {
         let deps = HashMap::default();
@@ -64,6 +64,14 @@ pub enum MessageGraphError<E: Debug> {
         /// The timestamp of the initiating message
         initiating_message_time: u64,
     },
+    /// A chain is not part of the dependency set at the time of the message.
+    #[error("Chain ID {chain_id} is not part of the dependency set at timestamp {timestamp}")]
+    InactiveDependency {
+        /// The chain ID that is not part of the dependency set
+        chain_id: u64,
+        /// The timestamp at which the chain was checked
+        timestamp: u64,
+    },
     /// Message is in the future
     #[error("Message is in the future. Expected timestamp to be <= {max}, got {actual}")]
     MessageInFuture {
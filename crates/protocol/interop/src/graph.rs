@@ -1,7 +1,7 @@
 //! Interop [`MessageGraph`].
 
 use crate::{
-    MESSAGE_EXPIRY_WINDOW, RawMessagePayload,
+    DependencySet, MESSAGE_EXPIRY_WINDOW, RawMessagePayload,
     errors::{MessageGraphError, MessageGraphResult},
     message::{EnrichedExecutingMessage, extract_executing_messages},
     traits::InteropProvider,
@@ -36,6 +36,8 @@ pub struct MessageGraph<'a, P> {
     provider: &'a P,
     /// Backup rollup configs for each chain.
     rollup_configs: &'a HashMap<u64, RollupConfig>,
+    /// The dependency set the messages are checked against, if any.
+    dependency_set: Option<&'a DependencySet>,
 }
 
 impl<'a, P> MessageGraph<'a, P>
@@ -73,7 +75,17 @@ where
             num_messages = messages.len(),
             "Derived message graph successfully",
         );
-        Ok(Self { messages, provider, rollup_configs })
+        Ok(Self { messages, provider, rollup_configs, dependency_set: None })
+    }
+
+    /// Checks messages against the given [`DependencySet`].
+    ///
+    /// Both the initiating and the executing chain of a message must be part of the dependency
+    /// set at the time of the respective message, and the message expiry window of the dependency
+    /// set is used instead of the default one.
+    pub const fn with_dependency_set(mut self, dependency_set: &'a DependencySet) -> Self {
+        self.dependency_set = Some(dependency_set);
+        self
     }
 
     /// Checks the validity of all messages within the graph.
@@ -145,11 +157,23 @@ where
         message: &EnrichedExecutingMessage,
     ) -> MessageGraphResult<(), P> {
         // ChainID Invariant: The chain id of the initiating message MUST be in the dependency set
-        // This is enforced implicitly by the graph constructor and the provider.
+        // This is enforced implicitly by the graph constructor and the provider, and explicitly
+        // when a dependency set is configured.
 
         let initiating_chain_id = message.inner.identifier.chainId.saturating_to();
         let initiating_timestamp = message.inner.identifier.timestamp.saturating_to::<u64>();
 
+        if let Some(dependency_set) = self.dependency_set {
+            for (chain_id, timestamp) in [
+                (initiating_chain_id, initiating_timestamp),
+                (message.executing_chain_id, message.executing_timestamp),
+            ] {
+                if !dependency_set.is_active(chain_id, timestamp) {
+                    return Err(MessageGraphError::InactiveDependency { chain_id, timestamp });
+                }
+            }
+        }
+
         // Attempt to fetch the rollup config for the initiating chain from the registry. If the
         // rollup config is not found, fall back to the local rollup configs.
         let rollup_config = ROLLUP_CONFIGS
@@ -177,8 +201,10 @@ where
         // Message expiry invariant: The timestamp of the initiating message must be no more than
         // `MESSAGE_EXPIRY_WINDOW` seconds in the past, relative to the timestamp of the executing
         // message.
-        if initiating_timestamp < message.executing_timestamp.saturating_sub(MESSAGE_EXPIRY_WINDOW)
-        {
+        let expiry_window = self
+            .dependency_set
+            .map_or(MESSAGE_EXPIRY_WINDOW, DependencySet::get_message_expiry_window);
+        if initiating_timestamp < message.executing_timestamp.saturating_sub(expiry_window) {
             return Err(MessageGraphError::MessageExpired {
                 initiating_timestamp,
                 executing_timestamp: message.executing_timestamp,
@@ -247,10 +273,11 @@ where
 mod test {
     use super::{MESSAGE_EXPIRY_WINDOW, MessageGraph};
     use crate::{
-        MessageGraphError,
+        ChainDependency, DependencySet, MessageGraphError,
         test_util::{ExecutingMessageBuilder, SuperchainBuilder},
     };
     use alloy_primitives::{Address, hex, keccak256};
+    use kona_registry::HashMap;
 
     const MOCK_MESSAGE: [u8; 4] = hex!("deadbeef");
     const CHAIN_A_ID: u64 = 1;
@@ -295,6 +322,47 @@ mod test {
         graph.resolve().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_derive_and_resolve_graph_inactive_dependency() {
+        let mut superchain = default_superchain();
+
+        let chain_a_time = superchain.chain(CHAIN_A_ID).header.timestamp;
+        let chain_b_time = superchain.chain(CHAIN_B_ID).header.timestamp;
+
+        superchain.chain(CHAIN_A_ID).add_initiating_message(MOCK_MESSAGE.into());
+        superchain.chain(CHAIN_B_ID).add_executing_message(
+            ExecutingMessageBuilder::default()
+                .with_message_hash(keccak256(MOCK_MESSAGE))
+                .with_origin_chain_id(CHAIN_A_ID)
+                .with_origin_timestamp(chain_a_time),
+        );
+
+        let (headers, cfgs, provider) = superchain.build();
+
+        // Chain B joins the dependency set after the executing message was included.
+        let mut dependencies = HashMap::default();
+        dependencies.insert(CHAIN_A_ID, ChainDependency::default());
+        dependencies
+            .insert(CHAIN_B_ID, ChainDependency { activation_time: Some(chain_b_time + 1) });
+        let dependency_set = DependencySet { dependencies, override_message_expiry_window: None };
+
+        let graph = MessageGraph::derive(&headers, &provider, &cfgs)
+            .await
+            .unwrap()
+            .with_dependency_set(&dependency_set);
+        let MessageGraphError::InvalidMessages(invalid_messages) =
+            graph.resolve().await.unwrap_err()
+        else {
+            panic!("Expected invalid messages")
+        };
+
+        assert_eq!(invalid_messages.len(), 1);
+        assert_eq!(
+            *invalid_messages.get(&CHAIN_B_ID).unwrap(),
+            MessageGraphError::InactiveDependency { chain_id: CHAIN_B_ID, timestamp: chain_b_time }
+        );
+    }
+
     #[tokio::test]
     async fn test_derive_and_resolve_simple_graph_with_cycles() {
         let mut superchain = default_superchain();
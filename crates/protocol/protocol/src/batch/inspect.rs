@@ -0,0 +1,602 @@
+//! Diagnostic decoding of raw span batches.
+//!
+//! [`RawSpanBatch::decode`] stops at the first field that fails to decode and only reports which
+//! kind of field failed. [`SpanBatchInspection`] runs the same decoders section by section,
+//! records the byte offset and length of every section, list element and transaction, and keeps
+//! every section decoded before a failure.
+
+use crate::{
+    RawSpanBatch, SpanBatchBits, SpanBatchError, SpanBatchPayload, SpanBatchPrefix,
+    SpanBatchTransactions,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+/// The length of the `r` and `s` values of an encoded span batch signature, in bytes.
+const SIGNATURE_RS_LEN: usize = 64;
+
+/// A section of an encoded span batch, located by its byte offset and length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanBatchSection {
+    /// The name of the section, e.g. `rel_timestamp`, or `[3]` for the fourth element of a list.
+    pub name: String,
+    /// The offset of the section from the start of the span batch, in bytes.
+    pub offset: usize,
+    /// The length of the section, in bytes.
+    pub len: usize,
+    /// A summary of the decoded value, for sections holding a single field.
+    pub value: Option<String>,
+    /// The nested sections, in encoding order.
+    pub children: Vec<Self>,
+}
+
+impl SpanBatchSection {
+    /// Returns the offset of the first byte after the section.
+    pub const fn end(&self) -> usize {
+        self.offset + self.len
+    }
+
+    /// Writes the section and its children as an indented tree.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(f, "{:indent$}{} @{}+{}", "", self.name, self.offset, self.len, indent = depth * 2)?;
+        if let Some(value) = &self.value {
+            write!(f, ": {value}")?;
+        }
+        writeln!(f)?;
+        self.children.iter().try_for_each(|child| child.fmt_tree(f, depth + 1))
+    }
+}
+
+/// The failure that stopped a [`SpanBatchInspection`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanBatchInspectionError {
+    /// The path of the section that failed to decode, e.g. `payload.txs.tx_data[3]`.
+    pub path: String,
+    /// The offset at which the failing section starts.
+    pub offset: usize,
+    /// The decoding error.
+    pub error: SpanBatchError,
+}
+
+impl fmt::Display for SpanBatchInspectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}: {}", self.path, self.offset, self.error)
+    }
+}
+
+/// A field by field decoding of a [`RawSpanBatch`], locating every decoded section in the input.
+///
+/// Decoding stops at the first failure, like [`RawSpanBatch::decode`], but the prefix, the
+/// payload and the sections decoded up to that point are kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanBatchInspection {
+    /// The size of the inspected input, in bytes.
+    pub len: usize,
+    /// The number of bytes decoded before decoding completed or failed.
+    pub decoded: usize,
+    /// The span batch prefix, decoded up to the point of failure.
+    pub prefix: SpanBatchPrefix,
+    /// The span batch payload, decoded up to the point of failure.
+    pub payload: SpanBatchPayload,
+    /// The top level `prefix` and `payload` sections.
+    pub sections: Vec<SpanBatchSection>,
+    /// The failure that stopped decoding, if any.
+    pub error: Option<SpanBatchInspectionError>,
+}
+
+impl SpanBatchInspection {
+    /// Inspects an encoded [`RawSpanBatch`], without the leading batch type byte.
+    pub fn inspect(data: &[u8]) -> Self {
+        let mut inspector = Inspector::new(data);
+        let mut prefix = SpanBatchPrefix::default();
+        let mut payload = SpanBatchPayload::default();
+        let error =
+            inspector.prefix(&mut prefix).and_then(|()| inspector.payload(&mut payload)).err();
+
+        Self {
+            len: data.len(),
+            decoded: inspector.offset(),
+            prefix,
+            payload,
+            sections: inspector.finish(),
+            error,
+        }
+    }
+
+    /// Returns `true` if the whole span batch decoded.
+    pub const fn is_complete(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Returns the number of bytes left after the decoded sections.
+    pub const fn trailing(&self) -> usize {
+        self.len - self.decoded
+    }
+
+    /// Returns the decoded [`RawSpanBatch`], or [`None`] if decoding failed.
+    pub fn raw_batch(&self) -> Option<RawSpanBatch> {
+        self.is_complete()
+            .then(|| RawSpanBatch { prefix: self.prefix.clone(), payload: self.payload.clone() })
+    }
+}
+
+impl fmt::Display for SpanBatchInspection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "span batch: {} bytes, {} decoded", self.len, self.decoded)?;
+        self.sections.iter().try_for_each(|section| section.fmt_tree(f, 1))?;
+        match &self.error {
+            Some(error) => writeln!(f, "error: {error}"),
+            None if self.trailing() > 0 => writeln!(f, "{} trailing bytes", self.trailing()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Decodes the span batch fields while building the section tree.
+#[derive(Debug)]
+struct Inspector<'a> {
+    /// The inspected input.
+    input: &'a [u8],
+    /// The part of the input left to decode.
+    r: &'a [u8],
+    /// The sections being decoded, outermost first.
+    open: Vec<SpanBatchSection>,
+    /// The decoded top level sections.
+    sections: Vec<SpanBatchSection>,
+}
+
+impl<'a> Inspector<'a> {
+    const fn new(input: &'a [u8]) -> Self {
+        Self { input, r: input, open: Vec::new(), sections: Vec::new() }
+    }
+
+    /// Returns the offset of the next byte to decode.
+    const fn offset(&self) -> usize {
+        self.input.len() - self.r.len()
+    }
+
+    /// Starts a section holding the next decoded sections.
+    fn open(&mut self, name: &str) {
+        let section = SpanBatchSection {
+            name: name.to_string(),
+            offset: self.offset(),
+            len: 0,
+            value: None,
+            children: Vec::new(),
+        };
+        self.open.push(section);
+    }
+
+    /// Ends the innermost open section.
+    fn close(&mut self) {
+        if let Some(mut section) = self.open.pop() {
+            section.len = self.offset() - section.offset;
+            self.push(section);
+        }
+    }
+
+    /// Adds a section to the innermost open section.
+    fn push(&mut self, section: SpanBatchSection) {
+        match self.open.last_mut() {
+            Some(parent) => parent.children.push(section),
+            None => self.sections.push(section),
+        }
+    }
+
+    /// Closes the open sections, which only covers what was decoded if decoding failed.
+    fn finish(mut self) -> Vec<SpanBatchSection> {
+        while !self.open.is_empty() {
+            self.close();
+        }
+        self.sections
+    }
+
+    /// Returns an error for the section with the given name, starting at the current offset.
+    fn fail(&self, name: &str, error: SpanBatchError) -> SpanBatchInspectionError {
+        let mut path = String::new();
+        for name in self.open.iter().map(|section| section.name.as_str()).chain([name]) {
+            if !path.is_empty() && !name.starts_with('[') {
+                path.push('.');
+            }
+            path.push_str(name);
+        }
+        SpanBatchInspectionError { path, offset: self.offset(), error }
+    }
+
+    /// Decodes a single field, recording it as a section summarized by `describe`. The input is
+    /// only consumed if the field decodes.
+    fn field<T>(
+        &mut self,
+        name: &str,
+        decode: impl FnOnce(&mut &'a [u8]) -> Result<T, SpanBatchError>,
+        describe: impl FnOnce(&T) -> String,
+    ) -> Result<T, SpanBatchInspectionError> {
+        let offset = self.offset();
+        let mut r = self.r;
+        let value = decode(&mut r).map_err(|error| self.fail(name, error))?;
+        self.r = r;
+
+        let section = SpanBatchSection {
+            name: name.to_string(),
+            offset,
+            len: self.offset() - offset,
+            value: Some(describe(&value)),
+            children: Vec::new(),
+        };
+        self.push(section);
+        Ok(value)
+    }
+
+    /// Splits the last decoded section into consecutive child sections: an optional leading
+    /// section with the given name, length and summary, followed by one section per list element
+    /// with the given length and summary.
+    fn split(
+        &mut self,
+        header: Option<(&str, usize, String)>,
+        elements: impl IntoIterator<Item = (usize, String)>,
+    ) {
+        let siblings = match self.open.last_mut() {
+            Some(parent) => &mut parent.children,
+            None => &mut self.sections,
+        };
+        let Some(section) = siblings.last_mut() else {
+            return;
+        };
+
+        let mut offset = section.offset;
+        let header = header.map(|(name, len, value)| (name.to_string(), len, value));
+        let elements = elements
+            .into_iter()
+            .enumerate()
+            .map(|(i, (len, value))| (format!("[{i}]"), len, value));
+        for (name, len, value) in header.into_iter().chain(elements) {
+            section.children.push(SpanBatchSection {
+                name,
+                offset,
+                len,
+                value: Some(value),
+                children: Vec::new(),
+            });
+            offset += len;
+        }
+    }
+
+    /// Decodes the [`SpanBatchPrefix`].
+    fn prefix(&mut self, prefix: &mut SpanBatchPrefix) -> Result<(), SpanBatchInspectionError> {
+        self.open("prefix");
+        self.field(
+            "rel_timestamp",
+            |r| {
+                prefix.decode_rel_timestamp(r)?;
+                Ok(prefix.rel_timestamp)
+            },
+            ToString::to_string,
+        )?;
+        self.field(
+            "l1_origin_num",
+            |r| {
+                prefix.decode_l1_origin_num(r)?;
+                Ok(prefix.l1_origin_num)
+            },
+            ToString::to_string,
+        )?;
+        self.field(
+            "parent_check",
+            |r| {
+                prefix.decode_parent_check(r)?;
+                Ok(prefix.parent_check)
+            },
+            ToString::to_string,
+        )?;
+        self.field(
+            "l1_origin_check",
+            |r| {
+                prefix.decode_l1_origin_check(r)?;
+                Ok(prefix.l1_origin_check)
+            },
+            ToString::to_string,
+        )?;
+        self.close();
+        Ok(())
+    }
+
+    /// Decodes the [`SpanBatchPayload`].
+    fn payload(&mut self, payload: &mut SpanBatchPayload) -> Result<(), SpanBatchInspectionError> {
+        self.open("payload");
+        self.field(
+            "block_count",
+            |r| {
+                payload.decode_block_count(r)?;
+                Ok(payload.block_count)
+            },
+            ToString::to_string,
+        )?;
+        self.field(
+            "origin_bits",
+            |r| {
+                payload.decode_origin_bits(r)?;
+                Ok(bits_set(&payload.origin_bits))
+            },
+            |changes| format!("{changes} L1 origin changes"),
+        )?;
+        self.field(
+            "block_tx_counts",
+            |r| {
+                payload.decode_block_tx_counts(r)?;
+                Ok(payload.block_tx_counts.iter().sum::<u64>())
+            },
+            |total| format!("{total} transactions"),
+        )?;
+        self.split(None, payload.block_tx_counts.iter().map(|count| varint_element(*count)));
+
+        payload.txs.total_block_tx_count =
+            payload.total_block_tx_count().map_err(|error| self.fail("txs", error))?;
+        self.txs(&mut payload.txs)?;
+
+        self.close();
+        Ok(())
+    }
+
+    /// Decodes the [`SpanBatchTransactions`], with a section for every transaction in every
+    /// field list.
+    fn txs(&mut self, txs: &mut SpanBatchTransactions) -> Result<(), SpanBatchInspectionError> {
+        self.open("txs");
+        self.field(
+            "contract_creation_bits",
+            |r| {
+                txs.decode_contract_creation_bits(r)?;
+                Ok(txs.contract_creation_count())
+            },
+            |creations| format!("{creations} contract creations"),
+        )?;
+
+        let (sigs_len, _) = self.field(
+            "tx_sigs",
+            |r| {
+                let len = r.len();
+                txs.decode_tx_sigs(r)?;
+                Ok((len - r.len(), txs.tx_sigs.len()))
+            },
+            |(_, sigs)| format!("{sigs} signatures"),
+        )?;
+        // The y parity bits precede the `r` and `s` values of the signatures.
+        let y_parities = txs.tx_sigs.iter().filter(|sig| sig.v()).count();
+        self.split(
+            Some((
+                "y_parity_bits",
+                sigs_len - txs.tx_sigs.len() * SIGNATURE_RS_LEN,
+                format!("{y_parities} odd y parities"),
+            )),
+            txs.tx_sigs.iter().map(|sig| {
+                let value = format!("r={:#x}, s={:#x}, y_parity={}", sig.r(), sig.s(), sig.v());
+                (SIGNATURE_RS_LEN, value)
+            }),
+        );
+
+        self.field(
+            "tx_tos",
+            |r| {
+                txs.decode_tx_tos(r)?;
+                Ok(txs.tx_tos.len())
+            },
+            |tos| format!("{tos} recipients"),
+        )?;
+        self.split(None, txs.tx_tos.iter().map(|to| (to.len(), to.to_string())));
+
+        self.field(
+            "tx_data",
+            |r| {
+                txs.decode_tx_data(r)?;
+                Ok(txs.legacy_tx_count)
+            },
+            |legacy| format!("{legacy} legacy transactions"),
+        )?;
+        self.split(
+            None,
+            txs.tx_data
+                .iter()
+                .zip(&txs.tx_types)
+                .map(|(data, ty)| (data.len(), format!("{ty}, {} bytes", data.len()))),
+        );
+
+        self.field(
+            "tx_nonces",
+            |r| {
+                txs.decode_tx_nonces(r)?;
+                Ok(txs.tx_nonces.len())
+            },
+            |nonces| format!("{nonces} nonces"),
+        )?;
+        self.split(None, txs.tx_nonces.iter().map(|nonce| varint_element(*nonce)));
+
+        self.field(
+            "tx_gases",
+            |r| {
+                txs.decode_tx_gases(r)?;
+                Ok(txs.tx_gases.len())
+            },
+            |gases| format!("{gases} gas limits"),
+        )?;
+        self.split(None, txs.tx_gases.iter().map(|gas| varint_element(*gas)));
+
+        self.field(
+            "protected_bits",
+            |r| {
+                txs.decode_protected_bits(r)?;
+                Ok(bits_set(&txs.protected_bits))
+            },
+            |protected| format!("{protected} protected legacy transactions"),
+        )?;
+        self.close();
+        Ok(())
+    }
+}
+
+/// Returns the length and the summary of an element of a varint list.
+fn varint_element(value: u64) -> (usize, String) {
+    let mut buf = unsigned_varint::encode::u64_buffer();
+    (unsigned_varint::encode::u64(value, &mut buf).len(), value.to_string())
+}
+
+/// Returns the number of set bits in a bitlist.
+fn bits_set(bits: &SpanBatchBits) -> u64 {
+    bits.as_ref().iter().map(|b| b.count_ones() as u64).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SpanDecodingError;
+    use alloy_primitives::address;
+
+    const RAW_BATCH: &[u8] = include_bytes!("./testdata/raw_batch.hex");
+
+    fn prefix() -> Vec<u8> {
+        let prefix = SpanBatchPrefix {
+            rel_timestamp: 0xFF,
+            l1_origin_num: 0xEE,
+            parent_check: address!("beef00000000000000000000000000000000beef").into(),
+            l1_origin_check: address!("babe00000000000000000000000000000000babe").into(),
+        };
+        let mut buf = Vec::new();
+        prefix.encode_prefix(&mut buf);
+        buf
+    }
+
+    fn child<'a>(section: &'a SpanBatchSection, name: &str) -> &'a SpanBatchSection {
+        section.children.iter().find(|child| child.name == name).unwrap()
+    }
+
+    fn assert_contiguous(section: &SpanBatchSection) {
+        let mut offset = section.offset;
+        for child in &section.children {
+            assert_eq!(child.offset, offset, "{} in {}", child.name, section.name);
+            assert_contiguous(child);
+            offset = child.end();
+        }
+        if !section.children.is_empty() {
+            assert_eq!(offset, section.end(), "{}", section.name);
+        }
+    }
+
+    #[test]
+    fn test_inspect_raw_batch() {
+        let inspection = SpanBatchInspection::inspect(RAW_BATCH);
+        let expected = RawSpanBatch::decode(&mut &RAW_BATCH[..]).unwrap();
+
+        assert!(inspection.is_complete());
+        assert_eq!(inspection.raw_batch(), Some(expected.clone()));
+        assert_eq!(inspection.decoded, RAW_BATCH.len());
+        assert_eq!(inspection.trailing(), 0);
+
+        let [prefix, payload] = inspection.sections.as_slice() else { panic!("two sections") };
+        assert_eq!((prefix.name.as_str(), prefix.offset), ("prefix", 0));
+        assert_eq!(payload.offset, prefix.end());
+        assert_eq!(payload.end(), RAW_BATCH.len());
+        assert_contiguous(prefix);
+        assert_contiguous(payload);
+
+        let txs = child(payload, "txs");
+        let total = expected.payload.txs.total_block_tx_count as usize;
+        assert_eq!(child(txs, "tx_data").children.len(), total);
+        assert_eq!(child(txs, "tx_nonces").children.len(), total);
+        // The y parity bits precede the signatures.
+        assert_eq!(child(txs, "tx_sigs").children.len(), total + 1);
+        for (section, data) in
+            child(txs, "tx_data").children.iter().zip(&expected.payload.txs.tx_data)
+        {
+            assert_eq!(&RAW_BATCH[section.offset..section.end()], data.as_slice());
+        }
+
+        let tree = inspection.to_string();
+        assert!(tree.starts_with(&format!("span batch: {0} bytes, {0} decoded", RAW_BATCH.len())));
+        assert!(tree.contains("\n  prefix @0+"));
+        assert!(tree.contains("\n      tx_data @"));
+    }
+
+    #[test]
+    fn test_inspect_truncated_tx_data() {
+        let full = SpanBatchInspection::inspect(RAW_BATCH);
+        let txs = child(&full.sections[1], "txs");
+        let tx_data = child(txs, "tx_data");
+        let truncated = &RAW_BATCH[..tx_data.offset + tx_data.len / 2];
+
+        let inspection = SpanBatchInspection::inspect(truncated);
+        let error = inspection.error.clone().unwrap();
+        assert_eq!(error.path, "payload.txs.tx_data");
+        assert_eq!(error.offset, tx_data.offset);
+        assert_eq!(
+            error.error,
+            SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData)
+        );
+        assert_eq!(inspection.decoded, error.offset);
+        assert!(inspection.raw_batch().is_none());
+
+        // Everything before the failing section is kept.
+        assert_eq!(inspection.prefix, full.prefix);
+        assert_eq!(inspection.payload.block_tx_counts, full.payload.block_tx_counts);
+        assert_eq!(inspection.payload.txs.tx_sigs, full.payload.txs.tx_sigs);
+        assert_eq!(inspection.payload.txs.tx_tos, full.payload.txs.tx_tos);
+        assert!(inspection.payload.txs.tx_data.is_empty());
+
+        let txs = child(&inspection.sections[1], "txs");
+        assert!(txs.children.iter().all(|section| section.name != "tx_data"));
+        assert_eq!(txs.end(), error.offset);
+        assert!(inspection.to_string().ends_with(&format!("error: {error}\n")));
+    }
+
+    #[test]
+    fn test_inspect_truncated_tx_gases() {
+        let full = SpanBatchInspection::inspect(RAW_BATCH);
+        let tx_gases = child(child(&full.sections[1], "txs"), "tx_gases");
+        let truncated = &RAW_BATCH[..tx_gases.offset];
+
+        let error = SpanBatchInspection::inspect(truncated).error.unwrap();
+        assert_eq!(error.path, "payload.txs.tx_gases");
+        assert_eq!(error.error, SpanBatchError::Decoding(SpanDecodingError::TxGases));
+    }
+
+    #[test]
+    fn test_inspect_truncated_prefix() {
+        let buf = prefix();
+        let inspection = SpanBatchInspection::inspect(&buf[..10]);
+
+        let error = inspection.error.unwrap();
+        assert_eq!(error.path, "prefix.parent_check");
+        assert_eq!(error.offset, 4);
+        assert_eq!(error.error, SpanBatchError::Decoding(SpanDecodingError::ParentCheck));
+        assert_eq!(inspection.prefix.rel_timestamp, 0xFF);
+        assert_eq!(inspection.prefix.l1_origin_num, 0xEE);
+        assert_eq!(inspection.sections[0].children.len(), 2);
+        assert_eq!(inspection.sections[0].len, 4);
+    }
+
+    #[test]
+    fn test_inspect_empty_span_batch() {
+        let mut buf = prefix();
+        buf.extend_from_slice(&[0]);
+        let inspection = SpanBatchInspection::inspect(&buf);
+
+        let error = inspection.error.unwrap();
+        assert_eq!(error.path, "payload.block_count");
+        assert_eq!(error.offset, buf.len() - 1);
+        assert_eq!(error.error, SpanBatchError::EmptySpanBatch);
+        assert_eq!(inspection.sections.len(), 2);
+        assert!(inspection.sections[1].children.is_empty());
+    }
+
+    #[test]
+    fn test_inspect_trailing_bytes() {
+        let mut buf = RAW_BATCH.to_vec();
+        buf.extend_from_slice(&[0; 3]);
+        let inspection = SpanBatchInspection::inspect(&buf);
+
+        assert!(inspection.is_complete());
+        assert_eq!(inspection.trailing(), 3);
+        assert!(inspection.to_string().ends_with("3 trailing bytes\n"));
+    }
+}
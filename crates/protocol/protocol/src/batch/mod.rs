@@ -62,6 +62,9 @@ pub use bits::SpanBatchBits;
 mod span;
 pub use span::SpanBatch;
 
+mod inspect;
+pub use inspect::{SpanBatchInspection, SpanBatchInspectionError, SpanBatchSection};
+
 mod transactions;
 pub use transactions::SpanBatchTransactions;
 
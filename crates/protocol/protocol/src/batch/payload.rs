@@ -89,6 +89,13 @@ impl SpanBatchPayload {
 
     /// Decode transactions from a reader.
     pub fn decode_txs(&mut self, r: &mut &[u8]) -> Result<(), SpanBatchError> {
+        self.txs.total_block_tx_count = self.total_block_tx_count()?;
+        self.txs.decode(r)?;
+        Ok(())
+    }
+
+    /// Returns the total number of transactions of the blocks in the span batch.
+    pub fn total_block_tx_count(&self) -> Result<u64, SpanBatchError> {
         if self.block_tx_counts.is_empty() {
             return Err(SpanBatchError::EmptySpanBatch);
         }
@@ -103,9 +110,7 @@ impl SpanBatchPayload {
         if total_block_tx_count > MAX_SPAN_BATCH_ELEMENTS {
             return Err(SpanBatchError::TooBigSpanBatchSize);
         }
-        self.txs.total_block_tx_count = total_block_tx_count;
-        self.txs.decode(r)?;
-        Ok(())
+        Ok(total_block_tx_count)
     }
 
     /// Encode the origin bits into a writer.
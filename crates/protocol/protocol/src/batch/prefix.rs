@@ -47,7 +47,9 @@ impl SpanBatchPrefix {
 
     /// Decodes the parent check from a reader.
     pub fn decode_parent_check(&mut self, r: &mut &[u8]) -> Result<(), SpanBatchError> {
-        let (parent_check, remaining) = r.split_at(20);
+        let (parent_check, remaining) = r
+            .split_at_checked(20)
+            .ok_or(SpanBatchError::Decoding(SpanDecodingError::ParentCheck))?;
         let parent_check = FixedBytes::<20>::from_slice(parent_check);
         *r = remaining;
         self.parent_check = parent_check;
@@ -56,7 +58,9 @@ impl SpanBatchPrefix {
 
     /// Decodes the L1 origin check from a reader.
     pub fn decode_l1_origin_check(&mut self, r: &mut &[u8]) -> Result<(), SpanBatchError> {
-        let (l1_origin_check, remaining) = r.split_at(20);
+        let (l1_origin_check, remaining) = r
+            .split_at_checked(20)
+            .ok_or(SpanBatchError::Decoding(SpanDecodingError::L1OriginCheck))?;
         let l1_origin_check = FixedBytes::<20>::from_slice(l1_origin_check);
         *r = remaining;
         self.l1_origin_check = l1_origin_check;
@@ -93,4 +97,28 @@ mod test {
 
         assert_eq!(SpanBatchPrefix::decode_prefix(&mut buf.as_slice()).unwrap(), expected);
     }
+
+    #[test]
+    fn test_span_batch_prefix_truncated_checks() {
+        let prefix = SpanBatchPrefix {
+            rel_timestamp: 0xFF,
+            l1_origin_num: 0xEE,
+            parent_check: address!("beef00000000000000000000000000000000beef").into(),
+            l1_origin_check: address!("babe00000000000000000000000000000000babe").into(),
+        };
+        let mut buf = Vec::new();
+        prefix.encode_prefix(&mut buf);
+
+        // The relative timestamp and L1 origin number take two bytes each.
+        let mut r = &buf[..4 + 19];
+        assert_eq!(
+            SpanBatchPrefix::decode_prefix(&mut r),
+            Err(SpanBatchError::Decoding(SpanDecodingError::ParentCheck))
+        );
+        let mut r = &buf[..buf.len() - 1];
+        assert_eq!(
+            SpanBatchPrefix::decode_prefix(&mut r),
+            Err(SpanBatchError::Decoding(SpanDecodingError::L1OriginCheck))
+        );
+    }
 }
@@ -4,12 +4,13 @@
 use crate::{
     MAX_SPAN_BATCH_ELEMENTS, SpanBatchBits, SpanBatchError, SpanBatchTransactionData,
     SpanDecodingError, read_tx_data,
+    utils::{read_tx_sig, read_tx_to},
 };
 use alloc::vec::Vec;
 use alloy_consensus::{Transaction, TxEnvelope, TxType};
 use alloy_eips::eip2718::Encodable2718;
-use alloy_primitives::{Address, Bytes, Signature, U256, bytes};
-use alloy_rlp::{Buf, Decodable, Encodable};
+use alloy_primitives::{Address, Bytes, Signature, bytes};
+use alloy_rlp::{Decodable, Encodable};
 
 /// This struct contains the decoded information for transactions in a span batch.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -153,10 +154,7 @@ impl SpanBatchTransactions {
         let mut sigs = Vec::with_capacity(self.total_block_tx_count as usize);
         for i in 0..self.total_block_tx_count {
             let y_parity = y_parity_bits.get_bit(i as usize).expect("same length");
-            let r_val = U256::from_be_slice(&r[..32]);
-            let s_val = U256::from_be_slice(&r[32..64]);
-            sigs.push(Signature::new(r_val, s_val, y_parity == 1));
-            r.advance(64);
+            sigs.push(read_tx_sig(r, y_parity == 1)?);
         }
         self.tx_sigs = sigs;
         Ok(())
@@ -180,7 +178,7 @@ impl SpanBatchTransactions {
         let mut gases = Vec::with_capacity(self.total_block_tx_count as usize);
         for _ in 0..self.total_block_tx_count {
             let (gas, remaining) = unsigned_varint::decode::u64(r)
-                .map_err(|_| SpanBatchError::Decoding(SpanDecodingError::TxNonces))?;
+                .map_err(|_| SpanBatchError::Decoding(SpanDecodingError::TxGases))?;
             gases.push(gas);
             *r = remaining;
         }
@@ -193,9 +191,7 @@ impl SpanBatchTransactions {
         let mut tos = Vec::with_capacity(self.total_block_tx_count as usize);
         let contract_creation_count = self.contract_creation_count();
         for _ in 0..(self.total_block_tx_count - contract_creation_count) {
-            let to = Address::from_slice(&r[..20]);
-            tos.push(to);
-            r.advance(20);
+            tos.push(read_tx_to(r)?);
         }
         self.tx_tos = tos;
         Ok(())
@@ -343,13 +339,34 @@ impl SpanBatchTransactions {
         self.total_block_tx_count += total_block_tx_count;
         Ok(())
     }
+
+    /// Appends the transactions of another [`SpanBatchTransactions`] after the transactions in
+    /// `self`, without decoding them again.
+    pub fn extend(&mut self, other: Self) {
+        for i in 0..other.total_block_tx_count as usize {
+            let bit = other.contract_creation_bits.get_bit(i) == Some(1);
+            self.contract_creation_bits.set_bit(self.total_block_tx_count as usize + i, bit);
+        }
+        for i in 0..other.legacy_tx_count as usize {
+            let bit = other.protected_bits.get_bit(i) == Some(1);
+            self.protected_bits.set_bit(self.legacy_tx_count as usize + i, bit);
+        }
+        self.total_block_tx_count += other.total_block_tx_count;
+        self.legacy_tx_count += other.legacy_tx_count;
+        self.tx_sigs.extend(other.tx_sigs);
+        self.tx_nonces.extend(other.tx_nonces);
+        self.tx_gases.extend(other.tx_gases);
+        self.tx_tos.extend(other.tx_tos);
+        self.tx_data.extend(other.tx_data);
+        self.tx_types.extend(other.tx_types);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloc::vec;
-    use alloy_consensus::{Signed, TxEip1559, TxEip2930, TxEip7702};
+    use alloy_consensus::{Signed, TxEip1559, TxEip2930, TxEip7702, TxLegacy};
     use alloy_primitives::{Signature, TxKind, address};
 
     #[test]
@@ -436,4 +453,91 @@ mod tests {
         assert_eq!(result, Ok(()));
         assert_eq!(span_batch_txs.total_block_tx_count, 1);
     }
+
+    #[test]
+    fn test_span_batch_transactions_extend() {
+        let sig = Signature::test_signature();
+        let to = address!("0123456789012345678901234567890123456789");
+        let encode = |tx: TxEnvelope| {
+            let mut buf = vec![];
+            tx.encode(&mut buf);
+            Bytes::from(buf)
+        };
+        let txs = vec![
+            encode(TxEnvelope::Legacy(Signed::new_unchecked(
+                TxLegacy { to: TxKind::Create, chain_id: Some(1), ..Default::default() },
+                sig,
+                Default::default(),
+            ))),
+            encode(TxEnvelope::Eip1559(Signed::new_unchecked(
+                TxEip1559 { to: TxKind::Call(to), chain_id: 1, nonce: 1, ..Default::default() },
+                sig,
+                Default::default(),
+            ))),
+            encode(TxEnvelope::Legacy(Signed::new_unchecked(
+                TxLegacy { to: TxKind::Call(to), nonce: 2, ..Default::default() },
+                sig,
+                Default::default(),
+            ))),
+            encode(TxEnvelope::Eip1559(Signed::new_unchecked(
+                TxEip1559 { to: TxKind::Create, chain_id: 1, nonce: 3, ..Default::default() },
+                sig,
+                Default::default(),
+            ))),
+        ];
+
+        let mut expected = SpanBatchTransactions::default();
+        expected.add_txs(txs.clone(), 1).unwrap();
+
+        let mut extended = SpanBatchTransactions::default();
+        extended.add_txs(txs[..1].to_vec(), 1).unwrap();
+        let mut other = SpanBatchTransactions::default();
+        other.add_txs(txs[1..].to_vec(), 1).unwrap();
+        extended.extend(other);
+
+        assert_eq!(extended, expected);
+    }
+
+    #[test]
+    fn test_span_batch_transactions_decode_truncated() {
+        let sig = Signature::test_signature();
+        let to = address!("0123456789012345678901234567890123456789");
+        let txs = (0..2)
+            .map(|nonce| {
+                let tx = TxEnvelope::Eip1559(Signed::new_unchecked(
+                    TxEip1559 { to: TxKind::Call(to), chain_id: 1, nonce, ..Default::default() },
+                    sig,
+                    Default::default(),
+                ));
+                let mut buf = vec![];
+                tx.encode(&mut buf);
+                Bytes::from(buf)
+            })
+            .collect();
+        let mut span_batch_txs = SpanBatchTransactions::default();
+        span_batch_txs.add_txs(txs, 1).unwrap();
+
+        let mut sigs = vec![];
+        span_batch_txs.encode_tx_sigs(&mut sigs).unwrap();
+        let mut decoded = SpanBatchTransactions { total_block_tx_count: 2, ..Default::default() };
+        assert_eq!(
+            decoded.decode_tx_sigs(&mut &sigs[..sigs.len() - 1]),
+            Err(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionSignature))
+        );
+
+        let mut tos = vec![];
+        span_batch_txs.encode_tx_tos(&mut tos).unwrap();
+        decoded.contract_creation_bits = span_batch_txs.contract_creation_bits.clone();
+        assert_eq!(
+            decoded.decode_tx_tos(&mut &tos[..tos.len() - 1]),
+            Err(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))
+        );
+
+        let mut tx_data = vec![];
+        span_batch_txs.encode_tx_data(&mut tx_data).unwrap();
+        assert_eq!(
+            decoded.decode_tx_data(&mut &tx_data[..tx_data.len() - 1]),
+            Err(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))
+        );
+    }
 }
@@ -0,0 +1,212 @@
+//! Encoding of batcher data into EIP-4844 blobs.
+//!
+//! The OP Stack packs frame data into blobs so that every 32-byte field element stays below the
+//! BLS modulus. Each round of 4 field elements carries 127 bytes of data: 31 bytes in the low
+//! bytes of each field element, plus 3 bytes split into 6-bit chunks across their high bytes.
+//! The first field element additionally carries the encoding version and the 3-byte big-endian
+//! length of the data.
+//!
+//! # Layout of the first field element
+//!
+//! ```text
+//! | high byte | version | length (3 bytes) | data (27 bytes) |
+//! ```
+
+use alloc::vec::Vec;
+use alloy_eips::eip4844::{BYTES_PER_FIELD_ELEMENT, Blob};
+
+/// The version of the blob encoding.
+pub const BLOB_ENCODING_VERSION: u8 = 0;
+
+/// The maximum number of data bytes that fit into a single blob.
+pub const BLOB_MAX_DATA_SIZE: usize = (4 * 31 + 3) * 1024 - 4; // 130044
+
+/// The number of 4 field element rounds in a blob.
+pub const BLOB_ENCODING_ROUNDS: usize = 1024;
+
+/// An error encountered while encoding data into a blob.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlobEncodingError {
+    /// The data does not fit into a single blob.
+    #[error("Blob data too large: {0} bytes, max {BLOB_MAX_DATA_SIZE}")]
+    DataTooLarge(usize),
+}
+
+/// Encodes the given data into a single blob.
+///
+/// Returns [`BlobEncodingError::DataTooLarge`] if the data exceeds [`BLOB_MAX_DATA_SIZE`]. Use
+/// [`encode_blobs`] to split larger payloads across several blobs.
+pub fn encode_blob(data: &[u8]) -> Result<Blob, BlobEncodingError> {
+    if data.len() > BLOB_MAX_DATA_SIZE {
+        return Err(BlobEncodingError::DataTooLarge(data.len()));
+    }
+
+    let mut blob = Blob::ZERO;
+    let mut reader = data;
+    let mut field_element = 0;
+    for round in 0..BLOB_ENCODING_ROUNDS {
+        if reader.is_empty() {
+            break;
+        }
+
+        // The first field element of round 0 is prefixed with the version and the data length.
+        let mut chunk = [0u8; 31];
+        if round == 0 {
+            chunk[0] = BLOB_ENCODING_VERSION;
+            chunk[1..4].copy_from_slice(&(data.len() as u32).to_be_bytes()[1..]);
+            read_into(&mut reader, &mut chunk[4..]);
+        } else {
+            read_into(&mut reader, &mut chunk);
+        }
+        let x = read_byte(&mut reader);
+        write_field_element(&mut blob, field_element, x & 0b0011_1111, &chunk);
+
+        read_into(&mut reader, &mut chunk);
+        let y = read_byte(&mut reader);
+        let high = (y & 0b0000_1111) | ((x & 0b1100_0000) >> 2);
+        write_field_element(&mut blob, field_element + 1, high, &chunk);
+
+        read_into(&mut reader, &mut chunk);
+        let z = read_byte(&mut reader);
+        write_field_element(&mut blob, field_element + 2, z & 0b0011_1111, &chunk);
+
+        read_into(&mut reader, &mut chunk);
+        let high = ((z & 0b1100_0000) >> 2) | ((y & 0b1111_0000) >> 4);
+        write_field_element(&mut blob, field_element + 3, high, &chunk);
+
+        field_element += 4;
+    }
+
+    Ok(blob)
+}
+
+/// Encodes the given data into as many blobs as needed, filling each blob with up to
+/// [`BLOB_MAX_DATA_SIZE`] bytes.
+///
+/// Empty data produces no blobs.
+pub fn encode_blobs(data: &[u8]) -> Vec<Blob> {
+    data.chunks(BLOB_MAX_DATA_SIZE)
+        .map(|chunk| encode_blob(chunk).expect("chunk fits into a blob"))
+        .collect()
+}
+
+/// Builds a [`BlobTransactionSidecar`] for the given blobs, computing the KZG commitment and
+/// proof of each blob with the default trusted setup.
+///
+/// [`BlobTransactionSidecar`]: alloy_eips::eip4844::BlobTransactionSidecar
+#[cfg(feature = "kzg")]
+pub fn blob_sidecar(
+    blobs: Vec<Blob>,
+) -> Result<alloy_eips::eip4844::BlobTransactionSidecar, c_kzg::Error> {
+    use alloy_eips::eip4844::{BlobTransactionSidecar, Bytes48, env_settings::EnvKzgSettings};
+
+    let kzg_settings = EnvKzgSettings::Default;
+    let mut commitments = Vec::with_capacity(blobs.len());
+    let mut proofs = Vec::with_capacity(blobs.len());
+    for blob in &blobs {
+        let kzg_blob = c_kzg::Blob::new(blob.0);
+        let commitment =
+            kzg_settings.get().blob_to_kzg_commitment(&kzg_blob).map(|c| c.to_bytes())?;
+        let proof = kzg_settings
+            .get()
+            .compute_blob_kzg_proof(&kzg_blob, &commitment)
+            .map(|proof| proof.to_bytes())?;
+        commitments.push(Bytes48::from(*commitment));
+        proofs.push(Bytes48::from(*proof));
+    }
+    Ok(BlobTransactionSidecar::new(blobs, commitments, proofs))
+}
+
+/// Copies as many bytes from the reader as fit into `out`, zeroing the rest of `out`.
+fn read_into(reader: &mut &[u8], out: &mut [u8]) {
+    let n = out.len().min(reader.len());
+    out[..n].copy_from_slice(&reader[..n]);
+    out[n..].fill(0);
+    *reader = &reader[n..];
+}
+
+/// Reads a single byte from the reader, returning zero once it is exhausted.
+fn read_byte(reader: &mut &[u8]) -> u8 {
+    let Some((byte, rest)) = reader.split_first() else {
+        return 0;
+    };
+    *reader = rest;
+    *byte
+}
+
+/// Writes the field element at the given index from its high byte and 31 low bytes.
+fn write_field_element(blob: &mut Blob, index: usize, high: u8, chunk: &[u8; 31]) {
+    let offset = index * BYTES_PER_FIELD_ELEMENT;
+    blob[offset] = high;
+    blob[offset + 1..offset + BYTES_PER_FIELD_ELEMENT].copy_from_slice(chunk);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip4844::{BYTES_PER_BLOB, FIELD_ELEMENTS_PER_BLOB};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_encode_blob_header() {
+        let blob = encode_blob(&[0xFF; 300]).unwrap();
+        assert_eq!(blob[0], 0b0011_1111);
+        assert_eq!(blob[1], BLOB_ENCODING_VERSION);
+        assert_eq!(&blob[2..5], &[0x00, 0x01, 0x2C]);
+        assert_eq!(&blob[5..32], &[0xFF; 27]);
+    }
+
+    #[test]
+    fn test_encode_blob_too_large() {
+        let data = vec![0u8; BLOB_MAX_DATA_SIZE + 1];
+        assert_eq!(
+            encode_blob(&data),
+            Err(BlobEncodingError::DataTooLarge(BLOB_MAX_DATA_SIZE + 1))
+        );
+    }
+
+    #[test]
+    fn test_encode_blob_max_size() {
+        let blob = encode_blob(&[0xFF; BLOB_MAX_DATA_SIZE]).unwrap();
+        assert_eq!(&blob[BYTES_PER_BLOB - 31..], &[0xFF; 31]);
+    }
+
+    #[test]
+    fn test_encode_blobs_empty() {
+        assert!(encode_blobs(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_encode_blobs_splits_data() {
+        let data = vec![0xAB; BLOB_MAX_DATA_SIZE * 2 + 1];
+        let blobs = encode_blobs(&data);
+        assert_eq!(blobs.len(), 3);
+        assert_eq!(blobs[0], encode_blob(&data[..BLOB_MAX_DATA_SIZE]).unwrap());
+        assert_eq!(blobs[2], encode_blob(&[0xAB]).unwrap());
+    }
+
+    #[cfg(feature = "kzg")]
+    #[test]
+    fn test_blob_sidecar() {
+        let blobs = encode_blobs(&[0x01; 1000]);
+        let sidecar = blob_sidecar(blobs).unwrap();
+        assert_eq!(sidecar.blobs.len(), 1);
+        assert_eq!(sidecar.commitments.len(), 1);
+        assert_eq!(sidecar.proofs.len(), 1);
+        let hashes = sidecar.versioned_hashes().collect::<Vec<_>>();
+        let settings = alloy_eips::eip4844::env_settings::EnvKzgSettings::Default;
+        assert!(sidecar.validate(&hashes, settings.get()).is_ok());
+    }
+
+    proptest! {
+        #[test]
+        fn test_encode_blob_valid_field_elements(
+            data in prop::collection::vec(any::<u8>(), 0..=BLOB_MAX_DATA_SIZE)
+        ) {
+            let blob = encode_blob(&data).unwrap();
+            for i in 0..FIELD_ELEMENTS_PER_BLOB as usize {
+                prop_assert_eq!(blob[i * BYTES_PER_FIELD_ELEMENT] & 0b1100_0000, 0);
+            }
+        }
+    }
+}
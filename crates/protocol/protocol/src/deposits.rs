@@ -1,8 +1,8 @@
 //! Contains deposit transaction types and helper methods.
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use alloy_eips::eip2718::Encodable2718;
-use alloy_primitives::{Address, B256, Bytes, Log, TxKind, U64, U256, b256};
+use alloy_primitives::{Address, B256, Bytes, Log, TxKind, U64, U256, b256, hex, keccak256};
 use op_alloy_consensus::{TxDeposit, UserDepositSource};
 
 /// Deposit log event abi signature.
@@ -68,115 +68,287 @@ pub enum DepositError {
     GasDecode(Bytes),
 }
 
-/// Derives a deposit transaction from an EVM log event emitted by the deposit contract.
+/// The selector of `OptimismPortal.depositTransaction(address,uint256,uint64,bool,bytes)`.
+pub const DEPOSIT_TRANSACTION_SELECTOR: [u8; 4] = hex!("e9e05c42");
+
+/// The length of the version 0 opaque data without the transaction data: the mint, value, gas
+/// limit and creation flag.
+const OPAQUE_DATA_HEADER_LEN: usize = 32 + 32 + 8 + 1;
+
+/// A user deposit made through `OptimismPortal.depositTransaction`.
 ///
-/// The emitted log must be in format:
-/// ```solidity
-/// event TransactionDeposited(
-///    address indexed from,
-///    address indexed to,
-///    uint256 indexed version,
-///    bytes opaqueData
-/// );
-/// ```
-pub fn decode_deposit(block_hash: B256, index: usize, log: &Log) -> Result<Bytes, DepositError> {
-    let topics = log.data.topics();
-    if topics.len() != 4 {
-        return Err(DepositError::UnexpectedTopicsLen(topics.len()));
-    }
-    if topics[0] != DEPOSIT_EVENT_ABI_HASH {
-        return Err(DepositError::InvalidSelector(DEPOSIT_EVENT_ABI_HASH, topics[0]));
+/// A deposit can be built from its fields to produce the `TransactionDeposited` log and the
+/// portal calldata, or decoded from a version 0 `TransactionDeposited` log with
+/// [`Self::decode_log`]. The resulting L2 deposit transaction is identified by the L1 block hash
+/// and the index of the log in that block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserDeposit {
+    /// The L2 sender: the L1 caller of the portal, aliased if it is a contract.
+    pub from: Address,
+    /// The L2 recipient. Zero for contract creations.
+    pub to: Address,
+    /// The ETH minted on L2, i.e. the `msg.value` sent to the portal, in wei.
+    pub mint: u128,
+    /// The ETH transferred to the recipient on L2, in wei.
+    pub value: U256,
+    /// The L2 gas limit.
+    pub gas_limit: u64,
+    /// Whether the deposit creates a contract.
+    pub is_creation: bool,
+    /// The L2 calldata, or the init code of a contract creation.
+    pub data: Bytes,
+}
+
+impl UserDeposit {
+    /// Returns the source hash of the deposit emitted by the log with the given index in the L1
+    /// block with the given hash.
+    pub fn source_hash(l1_block_hash: B256, log_index: u64) -> B256 {
+        UserDepositSource::new(l1_block_hash, log_index).source_hash()
     }
-    if log.data.data.len() < 64 {
-        return Err(DepositError::IncompleteOpaqueData(log.data.data.len()));
+
+    /// Returns the L2 destination of the deposit.
+    pub const fn kind(&self) -> TxKind {
+        if self.is_creation { TxKind::Create } else { TxKind::Call(self.to) }
     }
-    if log.data.data.len() % 32 != 0 {
-        return Err(DepositError::UnalignedData(log.data.data.len()));
+
+    /// Returns the L2 deposit transaction with the given source hash.
+    pub fn to_tx(&self, source_hash: B256) -> TxDeposit {
+        TxDeposit {
+            source_hash,
+            from: self.from,
+            to: self.kind(),
+            mint: self.mint,
+            value: self.value,
+            gas_limit: self.gas_limit,
+            is_system_transaction: false,
+            input: self.data.clone(),
+        }
     }
 
-    // Validate the `from` address.
-    let mut from_bytes = [0u8; 20];
-    from_bytes.copy_from_slice(&topics[1].as_slice()[12..]);
-    if topics[1].iter().take(12).any(|&b| b != 0) {
-        return Err(DepositError::FromDecode(topics[1]));
+    /// Returns the hash of the L2 deposit transaction of the deposit emitted by the log with the
+    /// given index in the L1 block with the given hash.
+    pub fn tx_hash(&self, l1_block_hash: B256, log_index: u64) -> B256 {
+        let tx = self.to_tx(Self::source_hash(l1_block_hash, log_index));
+        keccak256(tx.encoded_2718())
     }
 
-    // Validate the `to` address.
-    let mut to_bytes = [0u8; 20];
-    to_bytes.copy_from_slice(&topics[2].as_slice()[12..]);
-    if topics[2].iter().take(12).any(|&b| b != 0) {
-        return Err(DepositError::ToDecode(topics[2]));
+    /// Returns the version 0 opaque data of the deposit, packed as
+    /// `abi.encodePacked(mint, value, gasLimit, isCreation, data)`.
+    pub fn opaque_data(&self) -> Bytes {
+        let mut data = Vec::with_capacity(OPAQUE_DATA_HEADER_LEN + self.data.len());
+        data.extend_from_slice(&U256::from(self.mint).to_be_bytes::<32>());
+        data.extend_from_slice(&self.value.to_be_bytes::<32>());
+        data.extend_from_slice(&self.gas_limit.to_be_bytes());
+        data.push(self.is_creation as u8);
+        data.extend_from_slice(&self.data);
+        data.into()
     }
 
-    let from = Address::from(from_bytes);
-    let to = Address::from(to_bytes);
-    let version = log.data.topics()[3];
-
-    // Solidity serializes the event's Data field as follows:
-    //
-    // ```solidity
-    // abi.encode(abi.encodPacked(uint256 mint, uint256 value, uint64 gasLimit, uint8 isCreation, bytes data))
-    // ```
-    //
-    // The opaqueData will be packed as shown below:
-    //
-    // ------------------------------------------------------------
-    // | offset | 256 byte content                                |
-    // ------------------------------------------------------------
-    // | 0      | [0; 24] . {U64 big endian, hex encoded offset}  |
-    // ------------------------------------------------------------
-    // | 32     | [0; 24] . {U64 big endian, hex encoded length}  |
-    // ------------------------------------------------------------
-
-    let opaque_content_offset: U64 = U64::try_from_be_slice(&log.data.data[24..32]).ok_or(
-        DepositError::InvalidOpaqueDataOffset(Bytes::copy_from_slice(&log.data.data[24..32])),
-    )?;
-    if opaque_content_offset != U64::from(32) {
-        return Err(DepositError::InvalidOpaqueDataOffset(Bytes::copy_from_slice(
-            &log.data.data[24..32],
-        )));
+    /// Returns the version 0 `TransactionDeposited` log emitted by the portal at the given
+    /// address.
+    pub fn to_log(&self, portal: Address) -> Log {
+        let topics = vec![
+            DEPOSIT_EVENT_ABI_HASH,
+            self.from.into_word(),
+            self.to.into_word(),
+            DEPOSIT_EVENT_VERSION_0,
+        ];
+        let mut data = Vec::new();
+        push_word(&mut data, U256::from(32));
+        push_bytes(&mut data, &self.opaque_data());
+        Log::new_unchecked(portal, topics, data.into())
     }
 
-    // The next 32 bytes indicate the length of the opaqueData content.
-    let opaque_content_len =
-        u64::from_be_bytes(log.data.data[56..64].try_into().map_err(|_| {
-            DepositError::InvalidOpaqueDataLength(Bytes::copy_from_slice(&log.data.data[56..64]))
-        })?);
-    if opaque_content_len as usize > log.data.data.len() - 64 {
-        return Err(DepositError::OpaqueDataOverflow(
-            opaque_content_len as usize,
-            log.data.data.len() - 64,
-        ));
+    /// Returns the calldata of the `OptimismPortal.depositTransaction` call making the deposit.
+    ///
+    /// The call must be sent by [`Self::from`], or by the contract it is the alias of, with a
+    /// value of [`Self::mint`].
+    pub fn deposit_transaction_calldata(&self) -> Bytes {
+        let mut data = DEPOSIT_TRANSACTION_SELECTOR.to_vec();
+        push_word(&mut data, U256::from_be_slice(self.to.as_slice()));
+        push_word(&mut data, self.value);
+        push_word(&mut data, U256::from(self.gas_limit));
+        push_word(&mut data, U256::from(self.is_creation as u8));
+        // The offset of the dynamic `bytes` argument, following the five head words.
+        push_word(&mut data, U256::from(5 * 32));
+        push_bytes(&mut data, &self.data);
+        data.into()
     }
-    let padded_len = opaque_content_len.checked_add(32).ok_or(DepositError::OpaqueDataOverflow(
-        opaque_content_len as usize,
-        log.data.data.len() - 64,
-    ))?;
-    if padded_len as usize <= log.data.data.len() - 64 {
-        return Err(DepositError::PaddedOpaqueDataOverflow(
-            log.data.data.len() - 64,
-            opaque_content_len as usize,
-        ));
+
+    /// Decodes a deposit from a `TransactionDeposited` log emitted by the deposit contract.
+    ///
+    /// The emitted log must be in format:
+    /// ```solidity
+    /// event TransactionDeposited(
+    ///    address indexed from,
+    ///    address indexed to,
+    ///    uint256 indexed version,
+    ///    bytes opaqueData
+    /// );
+    /// ```
+    pub fn decode_log(log: &Log) -> Result<Self, DepositError> {
+        let topics = log.data.topics();
+        if topics.len() != 4 {
+            return Err(DepositError::UnexpectedTopicsLen(topics.len()));
+        }
+        if topics[0] != DEPOSIT_EVENT_ABI_HASH {
+            return Err(DepositError::InvalidSelector(DEPOSIT_EVENT_ABI_HASH, topics[0]));
+        }
+        if log.data.data.len() < 64 {
+            return Err(DepositError::IncompleteOpaqueData(log.data.data.len()));
+        }
+        if log.data.data.len() % 32 != 0 {
+            return Err(DepositError::UnalignedData(log.data.data.len()));
+        }
+
+        // Validate the `from` address.
+        let mut from_bytes = [0u8; 20];
+        from_bytes.copy_from_slice(&topics[1].as_slice()[12..]);
+        if topics[1].iter().take(12).any(|&b| b != 0) {
+            return Err(DepositError::FromDecode(topics[1]));
+        }
+
+        // Validate the `to` address.
+        let mut to_bytes = [0u8; 20];
+        to_bytes.copy_from_slice(&topics[2].as_slice()[12..]);
+        if topics[2].iter().take(12).any(|&b| b != 0) {
+            return Err(DepositError::ToDecode(topics[2]));
+        }
+
+        let from = Address::from(from_bytes);
+        let to = Address::from(to_bytes);
+        let version = log.data.topics()[3];
+
+        // Solidity serializes the event's Data field as follows:
+        //
+        // ```solidity
+        // abi.encode(abi.encodPacked(uint256 mint, uint256 value, uint64 gasLimit, uint8 isCreation, bytes data))
+        // ```
+        //
+        // The opaqueData will be packed as shown below:
+        //
+        // ------------------------------------------------------------
+        // | offset | 256 byte content                                |
+        // ------------------------------------------------------------
+        // | 0      | [0; 24] . {U64 big endian, hex encoded offset}  |
+        // ------------------------------------------------------------
+        // | 32     | [0; 24] . {U64 big endian, hex encoded length}  |
+        // ------------------------------------------------------------
+
+        let opaque_content_offset: U64 = U64::try_from_be_slice(&log.data.data[24..32]).ok_or(
+            DepositError::InvalidOpaqueDataOffset(Bytes::copy_from_slice(&log.data.data[24..32])),
+        )?;
+        if opaque_content_offset != U64::from(32) {
+            return Err(DepositError::InvalidOpaqueDataOffset(Bytes::copy_from_slice(
+                &log.data.data[24..32],
+            )));
+        }
+
+        // The next 32 bytes indicate the length of the opaqueData content.
+        let opaque_content_len =
+            u64::from_be_bytes(log.data.data[56..64].try_into().map_err(|_| {
+                DepositError::InvalidOpaqueDataLength(Bytes::copy_from_slice(
+                    &log.data.data[56..64],
+                ))
+            })?);
+        if opaque_content_len as usize > log.data.data.len() - 64 {
+            return Err(DepositError::OpaqueDataOverflow(
+                opaque_content_len as usize,
+                log.data.data.len() - 64,
+            ));
+        }
+        let padded_len = opaque_content_len.checked_add(32).ok_or(
+            DepositError::OpaqueDataOverflow(opaque_content_len as usize, log.data.data.len() - 64),
+        )?;
+        if padded_len as usize <= log.data.data.len() - 64 {
+            return Err(DepositError::PaddedOpaqueDataOverflow(
+                log.data.data.len() - 64,
+                opaque_content_len as usize,
+            ));
+        }
+
+        // The remaining data is the opaqueData which is tightly packed and then padded to 32 bytes
+        // by the EVM.
+        let opaque_data = &log.data.data[64..64 + opaque_content_len as usize];
+
+        // Can only handle version 0 for now
+        if !version.is_zero() {
+            return Err(DepositError::InvalidVersion(version));
+        }
+
+        Self::decode_opaque_data(from, to, opaque_data)
     }
 
-    // The remaining data is the opaqueData which is tightly packed and then padded to 32 bytes by
-    // the EVM.
-    let opaque_data = &log.data.data[64..64 + opaque_content_len as usize];
-    let source = UserDepositSource::new(block_hash, index as u64);
-
-    let mut deposit_tx = TxDeposit {
-        from,
-        is_system_transaction: false,
-        source_hash: source.source_hash(),
-        ..Default::default()
-    };
-
-    // Can only handle version 0 for now
-    if !version.is_zero() {
-        return Err(DepositError::InvalidVersion(version));
+    /// Decodes a deposit from the version 0 opaque data of a `TransactionDeposited` log.
+    pub fn decode_opaque_data(
+        from: Address,
+        to: Address,
+        data: &[u8],
+    ) -> Result<Self, DepositError> {
+        if data.len() < OPAQUE_DATA_HEADER_LEN {
+            return Err(DepositError::UnexpectedOpaqueDataLen(data.len()));
+        }
+
+        let mut offset = 0;
+
+        let raw_mint: [u8; 16] = data[offset + 16..offset + 32].try_into().map_err(|_| {
+            DepositError::MintDecode(Bytes::copy_from_slice(&data[offset + 16..offset + 32]))
+        })?;
+        let mint = u128::from_be_bytes(raw_mint);
+        offset += 32;
+
+        // uint256 value
+        let value = U256::from_be_slice(&data[offset..offset + 32]);
+        offset += 32;
+
+        // uint64 gas
+        let raw_gas: [u8; 8] = data[offset..offset + 8].try_into().map_err(|_| {
+            DepositError::GasDecode(Bytes::copy_from_slice(&data[offset..offset + 8]))
+        })?;
+        let gas_limit = u64::from_be_bytes(raw_gas);
+        offset += 8;
+
+        // uint8 isCreation
+        // isCreation: If the boolean byte is 1 then dep.To will stay nil,
+        // and it will create a contract using L2 account nonce to determine the created address.
+        let is_creation = data[offset] != 0;
+        offset += 1;
+
+        // The remainder of the opaqueData is the transaction data (without length prefix).
+        let data = Bytes::copy_from_slice(&data[offset..]);
+
+        Ok(Self { from, to, mint, value, gas_limit, is_creation, data })
     }
+}
 
-    unmarshal_deposit_version0(&mut deposit_tx, to, opaque_data)?;
+/// Appends a 32 byte ABI word.
+fn push_word(out: &mut Vec<u8>, word: U256) {
+    out.extend_from_slice(&word.to_be_bytes::<32>());
+}
+
+/// Appends ABI encoded `bytes`: the length word followed by the data, padded to 32 bytes.
+fn push_bytes(out: &mut Vec<u8>, data: &[u8]) {
+    push_word(out, U256::from(data.len()));
+    out.extend_from_slice(data);
+    out.resize(out.len() + data.len().next_multiple_of(32) - data.len(), 0);
+}
+
+/// Derives a deposit transaction from an EVM log event emitted by the deposit contract.
+///
+/// The emitted log must be in format:
+/// ```solidity
+/// event TransactionDeposited(
+///    address indexed from,
+///    address indexed to,
+///    uint256 indexed version,
+///    bytes opaqueData
+/// );
+/// ```
+///
+/// See [`UserDeposit::decode_log`] for a typed view of the deposit.
+pub fn decode_deposit(block_hash: B256, index: usize, log: &Log) -> Result<Bytes, DepositError> {
+    let deposit = UserDeposit::decode_log(log)?;
+    let deposit_tx = deposit.to_tx(UserDeposit::source_hash(block_hash, index as u64));
 
     // Re-encode the deposit transaction
     let mut buffer = Vec::with_capacity(deposit_tx.eip2718_encoded_length());
@@ -184,60 +356,12 @@ pub fn decode_deposit(block_hash: B256, index: usize, log: &Log) -> Result<Bytes
     Ok(Bytes::from(buffer))
 }
 
-/// Unmarshals a deposit transaction from the opaque data.
-pub(crate) fn unmarshal_deposit_version0(
-    tx: &mut TxDeposit,
-    to: Address,
-    data: &[u8],
-) -> Result<(), DepositError> {
-    if data.len() < 32 + 32 + 8 + 1 {
-        return Err(DepositError::UnexpectedOpaqueDataLen(data.len()));
-    }
-
-    let mut offset = 0;
-
-    let raw_mint: [u8; 16] = data[offset + 16..offset + 32].try_into().map_err(|_| {
-        DepositError::MintDecode(Bytes::copy_from_slice(&data[offset + 16..offset + 32]))
-    })?;
-    tx.mint = u128::from_be_bytes(raw_mint);
-    offset += 32;
-
-    // uint256 value
-    tx.value = U256::from_be_slice(&data[offset..offset + 32]);
-    offset += 32;
-
-    // uint64 gas
-    let raw_gas: [u8; 8] = data[offset..offset + 8]
-        .try_into()
-        .map_err(|_| DepositError::GasDecode(Bytes::copy_from_slice(&data[offset..offset + 8])))?;
-    tx.gas_limit = u64::from_be_bytes(raw_gas);
-    offset += 8;
-
-    // uint8 isCreation
-    // isCreation: If the boolean byte is 1 then dep.To will stay nil,
-    // and it will create a contract using L2 account nonce to determine the created address.
-    if data[offset] == 0 {
-        tx.to = TxKind::Call(to);
-    } else {
-        tx.to = TxKind::Create;
-    }
-    offset += 1;
-
-    // The remainder of the opaqueData is the transaction data (without length prefix).
-    // The data may be padded to a multiple of 32 bytes
-    let tx_data_len = data.len() - offset;
-
-    // Remaining bytes fill the data
-    tx.input = Bytes::copy_from_slice(&data[offset..offset + tx_data_len]);
-
-    Ok(())
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
     use alloc::vec;
     use alloy_primitives::{LogData, address, b256, hex};
+    use alloy_sol_types::{SolCall, SolEvent, sol};
 
     #[test]
     fn test_decode_deposit_invalid_first_topic() {
@@ -446,22 +570,19 @@ mod test {
     }
 
     #[test]
-    fn test_unmarshal_deposit_version0_invalid_len() {
+    fn test_decode_opaque_data_invalid_len() {
         let data = vec![0u8; 72];
-        let mut tx = TxDeposit::default();
         let to = address!("5555555555555555555555555555555555555555");
-        let err = unmarshal_deposit_version0(&mut tx, to, &data).unwrap_err();
+        let err = UserDeposit::decode_opaque_data(Address::ZERO, to, &data).unwrap_err();
         assert_eq!(err, DepositError::UnexpectedOpaqueDataLen(72));
 
         // Data must have at least length 73
         let data = vec![0u8; 73];
-        let mut tx = TxDeposit::default();
-        let to = address!("5555555555555555555555555555555555555555");
-        unmarshal_deposit_version0(&mut tx, to, &data).unwrap();
+        UserDeposit::decode_opaque_data(Address::ZERO, to, &data).unwrap();
     }
 
     #[test]
-    fn test_unmarshal_deposit_version0() {
+    fn test_decode_opaque_data() {
         let mut data = vec![0u8; 192];
         let offset: [u8; 8] = U64::from(32).to_be_bytes();
         data[24..32].copy_from_slice(&offset);
@@ -478,16 +599,143 @@ mod test {
         data[128..136].copy_from_slice(&gas);
         // Copy the isCreation flag
         data[136] = 1;
-        let mut tx = TxDeposit {
+        let from = address!("1111111111111111111111111111111111111111");
+        let to = address!("5555555555555555555555555555555555555555");
+        let deposit = UserDeposit::decode_opaque_data(from, to, &data).unwrap();
+        assert_eq!(deposit.kind(), TxKind::Call(to));
+        assert_eq!(deposit.data.len(), 192 - 73);
+    }
+
+    sol! {
+        event TransactionDeposited(
+            address indexed from,
+            address indexed to,
+            uint256 indexed version,
+            bytes opaqueData
+        );
+
+        function depositTransaction(
+            address _to,
+            uint256 _value,
+            uint64 _gasLimit,
+            bool _isCreation,
+            bytes _data
+        ) payable;
+    }
+
+    fn user_deposit(data_len: usize) -> UserDeposit {
+        UserDeposit {
             from: address!("1111111111111111111111111111111111111111"),
-            to: TxKind::Call(address!("2222222222222222222222222222222222222222")),
+            to: address!("2222222222222222222222222222222222222222"),
+            mint: 10,
             value: U256::from(100),
             gas_limit: 1000,
-            mint: 10,
-            ..Default::default()
+            is_creation: false,
+            data: Bytes::from(vec![0xAB; data_len]),
+        }
+    }
+
+    #[test]
+    fn test_deposit_selectors() {
+        assert_eq!(keccak256(DEPOSIT_EVENT_ABI), DEPOSIT_EVENT_ABI_HASH);
+        assert_eq!(DEPOSIT_EVENT_ABI_HASH, <TransactionDeposited as SolEvent>::SIGNATURE_HASH);
+        assert_eq!(DEPOSIT_TRANSACTION_SELECTOR, depositTransactionCall::SELECTOR);
+    }
+
+    #[test]
+    fn test_user_deposit_log_matches_abi() {
+        let portal = address!("bEb5Fc579115071764c7423A4f12eDde41f106Ed");
+        for data_len in [0, 1, 31, 32, 33, 100] {
+            let deposit = user_deposit(data_len);
+            let event = TransactionDeposited {
+                from: deposit.from,
+                to: deposit.to,
+                version: U256::ZERO,
+                opaqueData: deposit.opaque_data(),
+            };
+            let log = deposit.to_log(portal);
+            assert_eq!(log.address, portal);
+            assert_eq!(log.data, event.encode_log_data());
+            assert_eq!(UserDeposit::decode_log(&log).unwrap(), deposit);
+        }
+    }
+
+    #[test]
+    fn test_user_deposit_calldata_matches_abi() {
+        for deposit in [
+            user_deposit(0),
+            user_deposit(33),
+            UserDeposit { is_creation: true, ..user_deposit(7) },
+        ] {
+            let call = depositTransactionCall {
+                _to: deposit.to,
+                _value: deposit.value,
+                _gasLimit: deposit.gas_limit,
+                _isCreation: deposit.is_creation,
+                _data: deposit.data.clone(),
+            };
+            assert_eq!(deposit.deposit_transaction_calldata(), Bytes::from(call.abi_encode()));
+        }
+    }
+
+    #[test]
+    fn test_user_deposit_tx_matches_decode_deposit() {
+        let block_hash = B256::repeat_byte(0x42);
+        let deposit = user_deposit(40);
+        let log = deposit.to_log(Address::ZERO);
+
+        let encoded = decode_deposit(block_hash, 3, &log).unwrap();
+        assert_eq!(deposit.tx_hash(block_hash, 3), keccak256(&encoded));
+
+        let tx = deposit.to_tx(UserDeposit::source_hash(block_hash, 3));
+        assert_eq!(tx.source_hash, UserDepositSource::new(block_hash, 3).source_hash());
+        assert_eq!(tx.to, TxKind::Call(deposit.to));
+        assert_eq!(tx.mint, 10);
+        assert!(!tx.is_system_transaction);
+        assert_ne!(deposit.tx_hash(block_hash, 4), deposit.tx_hash(block_hash, 3));
+    }
+
+    #[test]
+    fn test_user_deposit_creation() {
+        let deposit = UserDeposit { to: Address::ZERO, is_creation: true, ..user_deposit(10) };
+        assert_eq!(deposit.kind(), TxKind::Create);
+        let decoded = UserDeposit::decode_log(&deposit.to_log(Address::ZERO)).unwrap();
+        assert!(decoded.is_creation);
+        assert_eq!(decoded.to_tx(B256::ZERO).to, TxKind::Create);
+    }
+
+    #[test]
+    fn test_user_deposit_decodes_full_log() {
+        let mut data = vec![0u8; 192];
+        data[24..32].copy_from_slice(&U64::from(32).to_be_bytes::<8>());
+        data[56..64].copy_from_slice(&U64::from(128).to_be_bytes::<8>());
+        data[80..96].copy_from_slice(&10_u128.to_be_bytes());
+        data[96..128].copy_from_slice(&U256::from(100).to_be_bytes::<32>());
+        data[128..136].copy_from_slice(&1000_u64.to_be_bytes());
+        data[136] = 1;
+        let from = address!("1111111111111111111111111111111111111111");
+        let to = address!("2222222222222222222222222222222222222222");
+        let log = Log {
+            address: Address::default(),
+            data: LogData::new_unchecked(
+                vec![DEPOSIT_EVENT_ABI_HASH, from.into_word(), to.into_word(), B256::default()],
+                Bytes::from(data),
+            ),
         };
-        let to = address!("5555555555555555555555555555555555555555");
-        unmarshal_deposit_version0(&mut tx, to, &data).unwrap();
-        assert_eq!(tx.to, TxKind::Call(address!("5555555555555555555555555555555555555555")));
+
+        let deposit = UserDeposit::decode_log(&log).unwrap();
+        assert_eq!(
+            deposit,
+            UserDeposit {
+                from,
+                to,
+                mint: 10,
+                value: U256::from(100),
+                gas_limit: 1000,
+                is_creation: true,
+                data: Bytes::from(vec![0u8; 128 - 73]),
+            }
+        );
+        assert_eq!(deposit.to_log(Address::default()), log);
     }
 }
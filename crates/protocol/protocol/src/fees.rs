@@ -0,0 +1,542 @@
+//! L1 data fee, operator fee and DA footprint calculations.
+//!
+//! The formulas mirror the `GasPriceOracle` predeploy and the execution engine's cost functions.
+//! See the [fjord][fjord], [isthmus][isthmus] and [jovian][jovian] specs.
+//!
+//! [fjord]: https://specs.optimism.io/protocol/fjord/exec-engine.html#fees
+//! [isthmus]: https://specs.optimism.io/protocol/isthmus/exec-engine.html#operator-fee
+//! [jovian]: https://specs.optimism.io/protocol/jovian/exec-engine.html
+
+use alloc::vec;
+use alloy_primitives::U256;
+use kona_genesis::{RollupConfig, SystemConfig};
+use op_alloy_consensus::OpTxType;
+
+use crate::{L1BlockInfoEcotone, L1BlockInfoJovian, L1BlockInfoTx};
+
+/// The number of decimals the fee scalars are expressed in.
+const L1_COST_DECIMALS: u64 = 1_000_000;
+
+/// The calldata gas of the 68 bytes of signature and RLP padding that the `GasPriceOracle` adds to
+/// unsigned transactions.
+const UNSIGNED_TX_PADDING_GAS: u64 = 68 * 16;
+
+/// The number of bytes the `GasPriceOracle` adds to the compressed length of unsigned transactions.
+const UNSIGNED_TX_PADDING_BYTES: u32 = 68;
+
+/// The intercept of the Fjord linear regression, scaled by `1e6`.
+const FJORD_COST_INTERCEPT: i64 = -42_585_600;
+
+/// The FastLZ coefficient of the Fjord linear regression, scaled by `1e6`.
+const FJORD_COST_FASTLZ_COEF: i64 = 836_500;
+
+/// The minimum estimated size of a transaction, in bytes.
+const FJORD_MIN_TRANSACTION_SIZE: i64 = 100;
+
+/// The fee rules that apply to user transactions in an L2 block.
+///
+/// Variants are ordered by activation, so later schedules compare greater than earlier ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FeeSchedule {
+    /// Pre-Regolith: the data gas includes a fixed signature overhead.
+    #[default]
+    Bedrock,
+    /// Regolith: the data gas of the signed transaction plus the configured overhead.
+    Regolith,
+    /// Ecotone: the data gas is priced against both the L1 base fee and blob base fee.
+    Ecotone,
+    /// Fjord: the data size is estimated from the FastLZ compressed length.
+    Fjord,
+    /// Isthmus: adds the operator fee.
+    Isthmus,
+    /// Jovian: rescales the operator fee and accounts for the DA footprint.
+    Jovian,
+}
+
+impl FeeSchedule {
+    /// Returns the [`FeeSchedule`] for user transactions in the L2 block at the given timestamp.
+    ///
+    /// The first Ecotone block still carries a Bedrock-style L1 info transaction, so it is priced
+    /// under [`FeeSchedule::Regolith`].
+    pub fn at(rollup_config: &RollupConfig, timestamp: u64) -> Self {
+        if rollup_config.is_jovian_active(timestamp) {
+            Self::Jovian
+        } else if rollup_config.is_isthmus_active(timestamp) {
+            Self::Isthmus
+        } else if rollup_config.is_fjord_active(timestamp) {
+            Self::Fjord
+        } else if rollup_config.is_ecotone_active(timestamp) &&
+            !rollup_config.is_first_ecotone_block(timestamp)
+        {
+            Self::Ecotone
+        } else if rollup_config.is_regolith_active(timestamp) {
+            Self::Regolith
+        } else {
+            Self::Bedrock
+        }
+    }
+}
+
+/// Computes the fees a transaction pays on top of its L2 execution gas.
+///
+/// Build one with [`L1FeeCalculator::from_l1_info`] from the block's L1 info transaction, or with
+/// [`L1FeeCalculator::from_system_config`] when only the [`SystemConfig`] and the L1 fees are
+/// known.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct L1FeeCalculator {
+    /// The fee rules in force.
+    pub schedule: FeeSchedule,
+    /// The L1 origin's base fee.
+    pub l1_base_fee: U256,
+    /// The L1 origin's blob base fee.
+    pub blob_base_fee: U256,
+    /// The pre-Ecotone fee overhead.
+    pub l1_fee_overhead: U256,
+    /// The pre-Ecotone fee scalar.
+    pub l1_fee_scalar: U256,
+    /// The Ecotone base fee scalar.
+    pub base_fee_scalar: U256,
+    /// The Ecotone blob base fee scalar.
+    pub blob_base_fee_scalar: U256,
+    /// The Isthmus operator fee scalar.
+    pub operator_fee_scalar: u32,
+    /// The Isthmus operator fee constant.
+    pub operator_fee_constant: u64,
+    /// The Jovian DA footprint gas scalar.
+    pub da_footprint_gas_scalar: u16,
+}
+
+impl L1FeeCalculator {
+    /// Creates a new [`L1FeeCalculator`] from the block's [`L1BlockInfoTx`].
+    ///
+    /// A Bedrock-style info transaction caps the schedule at [`FeeSchedule::Regolith`], which is
+    /// how the first Ecotone block is priced.
+    pub fn from_l1_info(schedule: FeeSchedule, info: &L1BlockInfoTx) -> Self {
+        match info {
+            L1BlockInfoTx::Bedrock(bedrock) => Self {
+                schedule: schedule.min(FeeSchedule::Regolith),
+                l1_base_fee: U256::from(bedrock.base_fee),
+                l1_fee_overhead: bedrock.l1_fee_overhead,
+                l1_fee_scalar: bedrock.l1_fee_scalar,
+                ..Default::default()
+            },
+            _ => Self {
+                schedule,
+                l1_base_fee: info.l1_base_fee(),
+                blob_base_fee: info.blob_base_fee(),
+                base_fee_scalar: info.l1_fee_scalar(),
+                blob_base_fee_scalar: info.blob_base_fee_scalar(),
+                operator_fee_scalar: info.operator_fee_scalar(),
+                operator_fee_constant: info.operator_fee_constant(),
+                da_footprint_gas_scalar: info.da_footprint().unwrap_or_default(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Creates a new [`L1FeeCalculator`] from a [`SystemConfig`] and the L1 origin's fees.
+    ///
+    /// Post-Ecotone, the scalars are decoded from the versioned `scalar` field the same way the
+    /// L1 info transaction is built, and an unset or zero DA footprint gas scalar falls back to
+    /// [`L1BlockInfoJovian::DEFAULT_DA_FOOTPRINT_GAS_SCALAR`].
+    pub fn from_system_config(
+        schedule: FeeSchedule,
+        system_config: &SystemConfig,
+        l1_base_fee: u64,
+        blob_base_fee: u128,
+    ) -> Self {
+        if schedule < FeeSchedule::Ecotone {
+            return Self {
+                schedule,
+                l1_base_fee: U256::from(l1_base_fee),
+                l1_fee_overhead: system_config.overhead,
+                l1_fee_scalar: system_config.scalar,
+                ..Default::default()
+            };
+        }
+
+        let scalar = system_config.scalar.to_be_bytes::<32>();
+        let mut word = [0u8; 4];
+        word.copy_from_slice(&scalar[28..32]);
+        let base_fee_scalar = u32::from_be_bytes(word);
+        word.copy_from_slice(&scalar[24..28]);
+        let blob_base_fee_scalar =
+            if scalar[0] == L1BlockInfoEcotone::L1_SCALAR { u32::from_be_bytes(word) } else { 0 };
+
+        let da_footprint_gas_scalar = match system_config.da_footprint_gas_scalar {
+            Some(0) | None => L1BlockInfoJovian::DEFAULT_DA_FOOTPRINT_GAS_SCALAR,
+            Some(scalar) => scalar,
+        };
+
+        Self {
+            schedule,
+            l1_base_fee: U256::from(l1_base_fee),
+            blob_base_fee: U256::from(blob_base_fee),
+            base_fee_scalar: U256::from(base_fee_scalar),
+            blob_base_fee_scalar: U256::from(blob_base_fee_scalar),
+            operator_fee_scalar: system_config.operator_fee_scalar.unwrap_or_default(),
+            operator_fee_constant: system_config.operator_fee_constant.unwrap_or_default(),
+            da_footprint_gas_scalar,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the L1 data fee charged for the signed, EIP-2718 encoded transaction.
+    ///
+    /// Deposit transactions do not pay an L1 data fee.
+    pub fn l1_data_fee(&self, raw_tx: &[u8]) -> U256 {
+        if is_deposit(raw_tx) {
+            return U256::ZERO;
+        }
+        self.data_fee(raw_tx, false)
+    }
+
+    /// Returns the L1 data fee for an unsigned transaction, as computed by the `GasPriceOracle`'s
+    /// `getL1Fee`.
+    ///
+    /// The oracle pads the transaction to account for the signature it does not yet have.
+    pub fn estimate_l1_data_fee(&self, unsigned_tx: &[u8]) -> U256 {
+        self.data_fee(unsigned_tx, true)
+    }
+
+    /// Returns the L1 gas attributed to the signed, EIP-2718 encoded transaction.
+    ///
+    /// This is the `l1GasUsed` field of the transaction receipt.
+    pub fn l1_gas_used(&self, raw_tx: &[u8]) -> U256 {
+        if is_deposit(raw_tx) {
+            return U256::ZERO;
+        }
+        match self.schedule {
+            FeeSchedule::Bedrock => {
+                U256::from(data_gas(raw_tx) + UNSIGNED_TX_PADDING_GAS) + self.l1_fee_overhead
+            }
+            FeeSchedule::Regolith => U256::from(data_gas(raw_tx)) + self.l1_fee_overhead,
+            FeeSchedule::Ecotone => U256::from(data_gas(raw_tx)),
+            _ => U256::from(fjord_estimated_size(flz_compress_len(raw_tx)) * 16 / L1_COST_DECIMALS),
+        }
+    }
+
+    /// Returns the operator fee for a transaction that used `gas_used` L2 gas.
+    ///
+    /// Deposit transactions do not pay the operator fee; callers are expected to skip them.
+    pub fn operator_fee(&self, gas_used: u64) -> U256 {
+        let gas_used = U256::from(gas_used);
+        let scalar = U256::from(self.operator_fee_scalar);
+        let constant = U256::from(self.operator_fee_constant);
+        match self.schedule {
+            FeeSchedule::Isthmus => gas_used * scalar / U256::from(L1_COST_DECIMALS) + constant,
+            FeeSchedule::Jovian => gas_used * scalar * U256::from(100) + constant,
+            _ => U256::ZERO,
+        }
+    }
+
+    /// Returns the DA footprint of the signed, EIP-2718 encoded transaction, in gas.
+    ///
+    /// Only Jovian blocks account for the DA footprint. Deposit transactions have none.
+    pub fn da_footprint_gas_used(&self, raw_tx: &[u8]) -> u64 {
+        if self.schedule < FeeSchedule::Jovian || is_deposit(raw_tx) {
+            return 0;
+        }
+        fjord_estimated_da_size(raw_tx).saturating_mul(self.da_footprint_gas_scalar as u64)
+    }
+
+    /// Computes the data fee, optionally applying the `GasPriceOracle`'s unsigned padding.
+    fn data_fee(&self, data: &[u8], padded: bool) -> U256 {
+        let decimals = U256::from(L1_COST_DECIMALS);
+        match self.schedule {
+            FeeSchedule::Bedrock | FeeSchedule::Regolith => {
+                let padding = if padded || self.schedule == FeeSchedule::Bedrock {
+                    UNSIGNED_TX_PADDING_GAS
+                } else {
+                    0
+                };
+                let gas = U256::from(data_gas(data) + padding) + self.l1_fee_overhead;
+                gas * self.l1_base_fee * self.l1_fee_scalar / decimals
+            }
+            FeeSchedule::Ecotone => {
+                let padding = if padded { UNSIGNED_TX_PADDING_GAS } else { 0 };
+                let gas = U256::from(data_gas(data) + padding);
+                let scaled = self.l1_base_fee * U256::from(16) * self.base_fee_scalar +
+                    self.blob_base_fee * self.blob_base_fee_scalar;
+                gas * scaled / (U256::from(16) * decimals)
+            }
+            _ => {
+                let padding = if padded { UNSIGNED_TX_PADDING_BYTES } else { 0 };
+                let size = fjord_estimated_size(flz_compress_len(data) + padding);
+                let scaled = self.base_fee_scalar * U256::from(16) * self.l1_base_fee +
+                    self.blob_base_fee_scalar * self.blob_base_fee;
+                U256::from(size) * scaled / (decimals * decimals)
+            }
+        }
+    }
+}
+
+/// Returns whether the encoded transaction is a deposit.
+fn is_deposit(raw_tx: &[u8]) -> bool {
+    raw_tx.first().is_some_and(|ty| *ty == OpTxType::Deposit as u8)
+}
+
+/// Returns the calldata gas of the data: 4 per zero byte and 16 per non-zero byte.
+fn data_gas(data: &[u8]) -> u64 {
+    data.iter().map(|b| if *b == 0 { 4 } else { 16 }).sum()
+}
+
+/// Returns the Fjord estimate of the data availability usage of the signed, EIP-2718 encoded
+/// transaction, in bytes.
+///
+/// This is the size used for the Jovian DA footprint and by the execution layer to enforce the
+/// limits set through `miner_setMaxDASize`. It is never below the 100 byte minimum.
+pub fn fjord_estimated_da_size(raw_tx: &[u8]) -> u64 {
+    fjord_estimated_size(flz_compress_len(raw_tx)) / L1_COST_DECIMALS
+}
+
+/// Returns the Fjord size estimate for a FastLZ compressed length, scaled by `1e6`.
+fn fjord_estimated_size(fastlz_len: u32) -> u64 {
+    let estimate = FJORD_COST_INTERCEPT + FJORD_COST_FASTLZ_COEF * fastlz_len as i64;
+    estimate.max(FJORD_MIN_TRANSACTION_SIZE * L1_COST_DECIMALS as i64) as u64
+}
+
+/// Returns the length of the data after FastLZ compression.
+///
+/// This is a port of `LibZip.flzCompress` as used by the `GasPriceOracle`, counting the output
+/// bytes instead of producing them.
+pub fn flz_compress_len(input: &[u8]) -> u32 {
+    const HASH_LOG: u32 = 13;
+    const HASH_MASK: u32 = (1 << HASH_LOG) - 1;
+    const MAX_DISTANCE: usize = 0x1fff;
+
+    let u24 = |i: usize| -> u32 {
+        input[i] as u32 | (input[i + 1] as u32) << 8 | (input[i + 2] as u32) << 16
+    };
+    let hash = |v: u32| -> usize {
+        ((v.wrapping_mul(2654435769) >> (32 - HASH_LOG)) & HASH_MASK) as usize
+    };
+
+    let mut len = 0u32;
+    let literals = |len: &mut u32, count: usize| {
+        let count = count as u32;
+        *len += 0x21 * (count / 0x20);
+        let rest = count % 0x20;
+        if rest != 0 {
+            *len += rest + 1;
+        }
+    };
+
+    let mut table = vec![0usize; 1 << HASH_LOG];
+    let ip_limit = input.len().saturating_sub(13);
+    let mut anchor = 0;
+    let mut ip = anchor + 2;
+    while ip < ip_limit {
+        let mut reference;
+        loop {
+            let seq = u24(ip);
+            let slot = hash(seq);
+            reference = table[slot];
+            table[slot] = ip;
+            let distance = ip - reference;
+            if ip >= ip_limit {
+                break;
+            }
+            ip += 1;
+            if distance <= MAX_DISTANCE && seq == u24(reference) {
+                break;
+            }
+        }
+        if ip >= ip_limit {
+            break;
+        }
+        ip -= 1;
+        if ip > anchor {
+            literals(&mut len, ip - anchor);
+        }
+
+        // Measure the match. A mismatching byte is still counted, as in the reference.
+        let (from, to) = (reference + 3, ip + 3);
+        let end = ip_limit + 9 - to;
+        let mut matched = 0;
+        while matched < end {
+            if input[from + matched] != input[to + matched] {
+                matched += 1;
+                break;
+            }
+            matched += 1;
+        }
+
+        let encoded = matched as u32 - 1;
+        len += 3 * (encoded / 262) + if encoded % 262 >= 6 { 3 } else { 2 };
+
+        ip += matched;
+        for _ in 0..2 {
+            table[hash(u24(ip))] = ip;
+            ip += 1;
+        }
+        anchor = ip;
+    }
+    literals(&mut len, input.len() - anchor);
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{L1BlockInfoBedrock, L1BlockInfoIsthmus};
+    use alloy_primitives::{b256, hex};
+    use rstest::rstest;
+
+    /// Thirty non-zero bytes, for 480 gas of calldata.
+    const TX: [u8; 30] = [0x01; 30];
+
+    fn calculator(schedule: FeeSchedule) -> L1FeeCalculator {
+        L1FeeCalculator {
+            schedule,
+            l1_base_fee: U256::from(1_000_000_000u64),
+            blob_base_fee: U256::from(10_000_000u64),
+            l1_fee_overhead: U256::from(50),
+            l1_fee_scalar: U256::from(7_000_000),
+            base_fee_scalar: U256::from(2),
+            blob_base_fee_scalar: U256::from(3),
+            operator_fee_scalar: 1_500_000,
+            operator_fee_constant: 500,
+            da_footprint_gas_scalar: 400,
+        }
+    }
+
+    #[rstest]
+    #[case::empty(&[], 0)]
+    #[case::thousand_zeros(&[0; 1000], 21)]
+    #[case::thousand_forty_twos(&[42; 1000], 21)]
+    #[case::short_hex(&hex!("FACADE"), 4)]
+    #[case::sample_contract_call(&hex!("02f901550a758302df1483be21b88304743f94f80e51afb613d764fa61751affd3313c190a86bb870151bd62fd12adb8e41ef24f3f000000000000000000000000000000000000000000000000000000000000006e000000000000000000000000af88d065e77c8cc2239327c5edb3a432268e5831000000000000000000000000000000000000000000000000000000000003c1e5000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000000148c89ed219d02f1a5be012c689b4f5b731827bebe000000000000000000000000c001a033fd89cb37c31b2cba46b6466e040c61fc9b2a3675a7f5f493ebd5ad77c497f8a07cdf65680e238392693019b4092f610222e71b7cec06449cb922b93b6a12744e"), 202)]
+    fn test_flz_compress_len(#[case] input: &[u8], #[case] expected: u32) {
+        assert_eq!(flz_compress_len(input), expected);
+    }
+
+    #[rstest]
+    #[case::bedrock(FeeSchedule::Bedrock, 11_326_000_000_000)]
+    #[case::regolith(FeeSchedule::Regolith, 3_710_000_000_000)]
+    #[case::ecotone(FeeSchedule::Ecotone, 960_900)]
+    #[case::fjord(FeeSchedule::Fjord, 3_203_000)]
+    #[case::jovian(FeeSchedule::Jovian, 3_203_000)]
+    fn test_l1_data_fee(#[case] schedule: FeeSchedule, #[case] expected: u64) {
+        assert_eq!(calculator(schedule).l1_data_fee(&TX), U256::from(expected));
+    }
+
+    #[test]
+    fn test_estimate_l1_data_fee_pads_unsigned_tx() {
+        // (480 + 1088) * (16 * 2 * 1e9 + 3 * 1e7) / 16e6
+        assert_eq!(
+            calculator(FeeSchedule::Ecotone).estimate_l1_data_fee(&TX),
+            U256::from(3_138_940)
+        );
+        // The padded Regolith fee matches the Bedrock formula.
+        assert_eq!(
+            calculator(FeeSchedule::Regolith).estimate_l1_data_fee(&TX),
+            calculator(FeeSchedule::Bedrock).l1_data_fee(&TX)
+        );
+    }
+
+    #[test]
+    fn test_deposit_pays_no_fees() {
+        let deposit = [OpTxType::Deposit as u8, 0x01, 0x02];
+        let calc = calculator(FeeSchedule::Jovian);
+        assert_eq!(calc.l1_data_fee(&deposit), U256::ZERO);
+        assert_eq!(calc.l1_gas_used(&deposit), U256::ZERO);
+        assert_eq!(calc.da_footprint_gas_used(&deposit), 0);
+    }
+
+    #[rstest]
+    #[case::ecotone(FeeSchedule::Ecotone, 0)]
+    #[case::isthmus(FeeSchedule::Isthmus, 21_000 * 1_500_000 / 1_000_000 + 500)]
+    #[case::jovian(FeeSchedule::Jovian, 21_000 * 1_500_000 * 100 + 500)]
+    fn test_operator_fee(#[case] schedule: FeeSchedule, #[case] expected: u64) {
+        assert_eq!(calculator(schedule).operator_fee(21_000), U256::from(expected));
+    }
+
+    #[test]
+    fn test_da_footprint_gas_used() {
+        assert_eq!(calculator(FeeSchedule::Isthmus).da_footprint_gas_used(&TX), 0);
+        // Small transactions are charged the minimum size of 100 bytes.
+        assert_eq!(calculator(FeeSchedule::Jovian).da_footprint_gas_used(&TX), 100 * 400);
+    }
+
+    #[test]
+    fn test_fjord_estimated_da_size() {
+        assert_eq!(fjord_estimated_da_size(&[]), 100);
+        assert_eq!(fjord_estimated_da_size(&TX), 100);
+        // Compressible data is estimated from its FastLZ length, not its raw length.
+        assert!(fjord_estimated_da_size(&[0u8; 10_000]) < 1_000);
+    }
+
+    #[test]
+    fn test_l1_gas_used() {
+        assert_eq!(calculator(FeeSchedule::Regolith).l1_gas_used(&TX), U256::from(530));
+        assert_eq!(calculator(FeeSchedule::Ecotone).l1_gas_used(&TX), U256::from(480));
+        assert_eq!(calculator(FeeSchedule::Fjord).l1_gas_used(&TX), U256::from(1600));
+    }
+
+    #[test]
+    fn test_fee_schedule_at() {
+        let mut config = RollupConfig { block_time: 2, ..Default::default() };
+        config.hardforks.regolith_time = Some(0);
+        config.hardforks.ecotone_time = Some(10);
+        config.hardforks.fjord_time = Some(20);
+        assert_eq!(FeeSchedule::at(&config, 0), FeeSchedule::Regolith);
+        assert_eq!(FeeSchedule::at(&config, 10), FeeSchedule::Regolith);
+        assert_eq!(FeeSchedule::at(&config, 12), FeeSchedule::Ecotone);
+        assert_eq!(FeeSchedule::at(&config, 20), FeeSchedule::Fjord);
+    }
+
+    #[test]
+    fn test_from_l1_info_bedrock_caps_schedule() {
+        let info = L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+            base_fee: 1_000_000_000,
+            l1_fee_overhead: U256::from(50),
+            l1_fee_scalar: U256::from(7_000_000),
+            ..Default::default()
+        });
+        let calc = L1FeeCalculator::from_l1_info(FeeSchedule::Ecotone, &info);
+        assert_eq!(calc.schedule, FeeSchedule::Regolith);
+        assert_eq!(calc.l1_data_fee(&TX), U256::from(3_710_000_000_000u64));
+    }
+
+    #[test]
+    fn test_from_l1_info_isthmus() {
+        let info = L1BlockInfoTx::Isthmus(L1BlockInfoIsthmus {
+            base_fee: 1_000_000_000,
+            blob_base_fee: 10_000_000,
+            base_fee_scalar: 2,
+            blob_base_fee_scalar: 3,
+            operator_fee_scalar: 1_500_000,
+            operator_fee_constant: 500,
+            ..Default::default()
+        });
+        let calc = L1FeeCalculator::from_l1_info(FeeSchedule::Isthmus, &info);
+        assert_eq!(calc.l1_data_fee(&TX), U256::from(3_203_000));
+        assert_eq!(calc.operator_fee(21_000), U256::from(32_000));
+    }
+
+    #[test]
+    fn test_from_system_config() {
+        let system_config = SystemConfig {
+            scalar: b256!("0100000000000000000000000000000000000000000000000000000300000002")
+                .into(),
+            operator_fee_scalar: Some(1_500_000),
+            operator_fee_constant: Some(500),
+            ..Default::default()
+        };
+        let calc = L1FeeCalculator::from_system_config(
+            FeeSchedule::Jovian,
+            &system_config,
+            1_000_000_000,
+            10_000_000,
+        );
+        assert_eq!(calc.base_fee_scalar, U256::from(2));
+        assert_eq!(calc.blob_base_fee_scalar, U256::from(3));
+        assert_eq!(
+            calc.da_footprint_gas_scalar,
+            L1BlockInfoJovian::DEFAULT_DA_FOOTPRINT_GAS_SCALAR
+        );
+        assert_eq!(calc.l1_data_fee(&TX), U256::from(3_203_000));
+    }
+}
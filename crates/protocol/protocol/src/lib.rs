@@ -15,16 +15,24 @@ pub use batch::{
     BatchValidationProvider, BatchValidity, BatchWithInclusionBlock, DecompressionError,
     MAX_SPAN_BATCH_ELEMENTS, RawSpanBatch, SINGLE_BATCH_TYPE, SPAN_BATCH_TYPE, SingleBatch,
     SpanBatch, SpanBatchBits, SpanBatchEip1559TransactionData, SpanBatchEip2930TransactionData,
-    SpanBatchEip7702TransactionData, SpanBatchElement, SpanBatchError,
-    SpanBatchLegacyTransactionData, SpanBatchPayload, SpanBatchPrefix, SpanBatchTransactionData,
-    SpanBatchTransactions, SpanDecodingError,
+    SpanBatchEip7702TransactionData, SpanBatchElement, SpanBatchError, SpanBatchInspection,
+    SpanBatchInspectionError, SpanBatchLegacyTransactionData, SpanBatchPayload, SpanBatchPrefix,
+    SpanBatchSection, SpanBatchTransactionData, SpanBatchTransactions, SpanDecodingError,
+};
+
+mod blob;
+#[cfg(feature = "kzg")]
+pub use blob::blob_sidecar;
+pub use blob::{
+    BLOB_ENCODING_ROUNDS, BLOB_ENCODING_VERSION, BLOB_MAX_DATA_SIZE, BlobEncodingError,
+    encode_blob, encode_blobs,
 };
 
 mod brotli;
 pub use brotli::{BrotliDecompressionError, decompress_brotli};
 
 mod sync;
-pub use sync::SyncStatus;
+pub use sync::{ElSyncStatus, SyncStatus};
 
 mod attributes;
 pub use attributes::OpAttributesWithParent;
@@ -51,8 +59,8 @@ pub use channel::{
 
 mod deposits;
 pub use deposits::{
-    DEPOSIT_EVENT_ABI, DEPOSIT_EVENT_ABI_HASH, DEPOSIT_EVENT_VERSION_0, DepositError,
-    decode_deposit,
+    DEPOSIT_EVENT_ABI, DEPOSIT_EVENT_ABI_HASH, DEPOSIT_EVENT_VERSION_0,
+    DEPOSIT_TRANSACTION_SELECTOR, DepositError, UserDeposit, decode_deposit,
 };
 
 mod info;
@@ -61,6 +69,9 @@ pub use info::{
     L1BlockInfoJovian, L1BlockInfoTx,
 };
 
+mod fees;
+pub use fees::{FeeSchedule, L1FeeCalculator, fjord_estimated_da_size, flz_compress_len};
+
 mod predeploys;
 pub use predeploys::Predeploys;
 
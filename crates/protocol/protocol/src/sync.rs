@@ -64,4 +64,86 @@ pub struct SyncStatus {
     ///
     /// This is an L2 block derived from L1, not yet verified to have valid cross-L2 dependencies.
     pub local_safe_l2: L2BlockInfo,
+    /// The progress of the execution layer sync.
+    ///
+    /// This is a kona extension of the op-node sync status, and is omitted when not reported by
+    /// the rollup node.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub el_sync: Option<ElSyncStatus>,
+}
+
+/// The progress of the execution layer sync, as tracked by the rollup node's engine.
+///
+/// Mirrors the sync status state machine of the op-node's engine controller. When the node runs in
+/// consensus-layer sync mode, the execution layer is driven block-by-block and the status is always
+/// [`ElSyncStatus::ConsensusLayer`]. In execution-layer sync mode, the status advances through the
+/// remaining variants, in order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ElSyncStatus {
+    /// The node derives the chain from L1, and does not rely on the execution layer to sync.
+    ConsensusLayer,
+    /// Execution layer sync will start upon receipt of the first unsafe payload, unless the
+    /// execution layer already has a finalized block.
+    #[default]
+    WillStart,
+    /// The execution layer is syncing towards the unsafe tip.
+    Started,
+    /// The execution layer has finished syncing, but the next unsafe payload has not yet been
+    /// checkpointed as the safe and finalized head.
+    FinishedNotFinalized,
+    /// The execution layer has finished syncing.
+    Finished,
+}
+
+impl ElSyncStatus {
+    /// Returns `true` if the execution layer is not syncing, and derivation may proceed.
+    pub const fn is_finished(&self) -> bool {
+        matches!(self, Self::ConsensusLayer | Self::Finished)
+    }
+
+    /// Returns `true` if the execution layer is syncing.
+    pub const fn is_syncing(&self) -> bool {
+        !self.is_finished()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_el_sync_status_finished() {
+        assert!(ElSyncStatus::ConsensusLayer.is_finished());
+        assert!(ElSyncStatus::Finished.is_finished());
+        assert!(ElSyncStatus::WillStart.is_syncing());
+        assert!(ElSyncStatus::Started.is_syncing());
+        assert!(ElSyncStatus::FinishedNotFinalized.is_syncing());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_sync_status_el_sync_omitted() {
+        let status = SyncStatus {
+            current_l1: BlockInfo::default(),
+            current_l1_finalized: BlockInfo::default(),
+            head_l1: BlockInfo::default(),
+            safe_l1: BlockInfo::default(),
+            finalized_l1: BlockInfo::default(),
+            unsafe_l2: L2BlockInfo::default(),
+            safe_l2: L2BlockInfo::default(),
+            finalized_l2: L2BlockInfo::default(),
+            cross_unsafe_l2: L2BlockInfo::default(),
+            local_safe_l2: L2BlockInfo::default(),
+            el_sync: None,
+        };
+        let json = serde_json::to_value(&status).unwrap();
+        assert!(json.get("el_sync").is_none());
+
+        let status = SyncStatus { el_sync: Some(ElSyncStatus::FinishedNotFinalized), ..status };
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["el_sync"], "finished_not_finalized");
+        assert_eq!(serde_json::from_value::<SyncStatus>(json).unwrap(), status);
+    }
 }
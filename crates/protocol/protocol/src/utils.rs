@@ -2,7 +2,7 @@
 
 use alloc::vec::Vec;
 use alloy_consensus::{Transaction, TxType, Typed2718};
-use alloy_primitives::B256;
+use alloy_primitives::{Address, B256, Signature, U256};
 use alloy_rlp::{Buf, Header};
 use kona_genesis::{RollupConfig, SystemConfig};
 use op_alloy_consensus::{OpBlock, decode_holocene_extra_data, decode_jovian_extra_data};
@@ -97,6 +97,25 @@ pub fn to_system_config(
     Ok(cfg)
 }
 
+/// Reads the r and s values of a transaction signature from a reader.
+pub(crate) fn read_tx_sig(r: &mut &[u8], y_parity: bool) -> Result<Signature, SpanBatchError> {
+    let (rs, remaining) = r
+        .split_at_checked(64)
+        .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionSignature))?;
+    let (r_val, s_val) = rs.split_at(32);
+    *r = remaining;
+    Ok(Signature::new(U256::from_be_slice(r_val), U256::from_be_slice(s_val), y_parity))
+}
+
+/// Reads the `to` address of a transaction from a reader.
+pub(crate) fn read_tx_to(r: &mut &[u8]) -> Result<Address, SpanBatchError> {
+    let (to, remaining) = r
+        .split_at_checked(20)
+        .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
+    *r = remaining;
+    Ok(Address::from_slice(to))
+}
+
 /// Reads transaction data from a reader.
 pub fn read_tx_data(r: &mut &[u8]) -> Result<(Vec<u8>, TxType), SpanBatchError> {
     let mut tx_data = Vec::new();
@@ -118,6 +137,9 @@ pub fn read_tx_data(r: &mut &[u8]) -> Result<(Vec<u8>, TxType), SpanBatchError>
     let tx_payload = if rlp_header.list {
         // Grab the raw RLP for the transaction data from `r`. It was unaffected since we copied it.
         let payload_length_with_header = rlp_header.payload_length + rlp_header.length();
+        if r.len() < payload_length_with_header {
+            return Err(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData));
+        }
         let payload = r[0..payload_length_with_header].to_vec();
         r.advance(payload_length_with_header);
         Ok(payload)
@@ -143,6 +165,23 @@ mod tests {
     use alloy_primitives::{U256, address, bytes, uint};
     use kona_genesis::{ChainGenesis, HardForkConfig};
 
+    #[test]
+    fn test_read_tx_data_truncated() {
+        // A list header announcing a 3 byte payload, followed by only 2 bytes.
+        let data = [0x02, 0xc3, 0x01, 0x02];
+        let mut r = &data[..];
+        assert_eq!(
+            read_tx_data(&mut r),
+            Err(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))
+        );
+
+        let mut r = &[0x02, 0xc3, 0x01, 0x02, 0x03][..];
+        let (tx_data, tx_type) = read_tx_data(&mut r).unwrap();
+        assert_eq!(tx_data, [0x02, 0xc3, 0x01, 0x02, 0x03]);
+        assert_eq!(tx_type, TxType::Eip1559);
+        assert!(r.is_empty());
+    }
+
     #[test]
     fn test_to_system_config_invalid_genesis_hash() {
         let block = OpBlock::default();
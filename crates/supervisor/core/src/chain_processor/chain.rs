@@ -4,7 +4,7 @@ use super::handlers::{
 };
 use crate::{
     LogIndexer, ProcessorState,
-    event::ChainEvent,
+    event::{ChainEvent, EventSink},
     syncnode::{BlockProvider, ManagedNodeCommand},
 };
 use alloy_primitives::ChainId;
@@ -14,7 +14,7 @@ use kona_supervisor_storage::{
 };
 use std::{fmt::Debug, sync::Arc};
 use tokio::sync::mpsc;
-use tracing::debug;
+use tracing::{debug, error};
 
 /// Represents a task that processes chain events from a managed node.
 /// It listens for events emitted by the managed node and handles them accordingly.
@@ -22,6 +22,7 @@ use tracing::debug;
 pub struct ChainProcessor<P, W, V> {
     chain_id: ChainId,
     metrics_enabled: Option<bool>,
+    event_sink: Option<Arc<EventSink>>,
 
     // state
     state: ProcessorState,
@@ -83,6 +84,7 @@ where
         Self {
             chain_id,
             metrics_enabled: None,
+            event_sink: None,
 
             state: ProcessorState::new(),
 
@@ -105,6 +107,12 @@ where
         self
     }
 
+    /// Exports the safety events handled by the processor to the given [`EventSink`].
+    pub fn with_event_sink(mut self, event_sink: Arc<EventSink>) -> Self {
+        self.event_sink = Some(event_sink);
+        self
+    }
+
     /// Handles a chain event by delegating it to the appropriate handler.
     pub async fn handle_event(&mut self, event: ChainEvent) {
         let result = match event {
@@ -142,6 +150,21 @@ where
                 ?event,
                 "Failed to process event"
             );
+            // The safety change did not take effect, so there is nothing to export.
+            return;
+        }
+
+        let Some(event_sink) = &self.event_sink else {
+            return;
+        };
+        if let Err(err) = event_sink.publish(self.chain_id, &event).await {
+            error!(
+                target: "supervisor::chain_processor",
+                chain_id = self.chain_id,
+                %err,
+                ?event,
+                "Failed to export event"
+            );
         }
     }
 }
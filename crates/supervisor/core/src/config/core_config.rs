@@ -1,8 +1,8 @@
-use super::RollupConfigSet;
-use crate::syncnode::ClientConfig;
+use super::{ReloadableDependencySet, RollupConfigSet};
+use crate::{event::ExportTarget, syncnode::ClientConfig};
 use alloy_primitives::ChainId;
 use derive_more::Constructor;
-use kona_interop::{DependencySet, InteropValidationError, InteropValidator};
+use kona_interop::{InteropValidationError, InteropValidator};
 use kona_protocol::BlockInfo;
 use std::{net::SocketAddr, path::PathBuf};
 
@@ -25,10 +25,31 @@ pub struct Config {
     pub enable_admin_api: bool,
 
     /// The loaded dependency set configuration.
-    pub dependency_set: DependencySet,
+    pub dependency_set: ReloadableDependencySet,
+
+    /// The path the dependency set was loaded from. Reloading the dependency set is disabled if
+    /// unset.
+    pub dependency_set_path: Option<PathBuf>,
 
     /// The rollup configuration set.
     pub rollup_config_set: RollupConfigSet,
+
+    /// The safety margin, in seconds, kept on top of the message expiry window when pruning the
+    /// databases. Pruning is disabled if unset.
+    pub prune_safety_margin: Option<u64>,
+
+    /// Export of the safety events of all chains. Disabled if unset.
+    pub event_export: Option<EventExportConfig>,
+}
+
+/// Configuration of the safety event export, see [`EventSink`](crate::event::EventSink).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventExportConfig {
+    /// Number of recent events retained for subscribers resuming from their own cursor.
+    pub retention: usize,
+
+    /// Targets the events are delivered to as newline-delimited JSON.
+    pub targets: Vec<ExportTarget>,
 }
 
 impl InteropValidator for Config {
@@ -47,6 +68,14 @@ impl InteropValidator for Config {
             return Err(InteropValidationError::InteropNotEnabled);
         }
 
+        // Both chains must be part of the dependency set at the relevant times
+        let dependency_set = self.dependency_set.load();
+        if !dependency_set.is_active(initiating_chain_id, initiating_timestamp) ||
+            !dependency_set.is_active(executing_chain_id, executing_timestamp)
+        {
+            return Err(InteropValidationError::InteropNotEnabled);
+        }
+
         // Executing timestamp must not be earlier than the initiating timestamp
         if initiating_timestamp > executing_timestamp {
             return Err(InteropValidationError::InvalidTimestampInvariant {
@@ -56,7 +85,7 @@ impl InteropValidator for Config {
         }
 
         // Ensure the message has not expired by the time of execution
-        let expiry_window = self.dependency_set.get_message_expiry_window();
+        let expiry_window = dependency_set.get_message_expiry_window();
         let expires_at = initiating_timestamp.saturating_add(expiry_window);
         let execution_deadline = executing_timestamp.saturating_add(timeout.unwrap_or(0));
 
@@ -80,7 +109,7 @@ impl InteropValidator for Config {
 mod tests {
     use super::*;
     use crate::config::RollupConfig;
-    use kona_interop::DependencySet;
+    use kona_interop::{ChainDependency, DependencySet};
     use std::{collections::HashMap, net::SocketAddr, path::PathBuf};
 
     fn mock_rollup_config_set() -> RollupConfigSet {
@@ -103,10 +132,19 @@ mod tests {
             rpc_addr: SocketAddr::from(([127, 0, 0, 1], 8545)),
             enable_admin_api: false,
             dependency_set: DependencySet {
-                dependencies: Default::default(),
+                dependencies: [
+                    (1, ChainDependency::default()),
+                    (2, ChainDependency { activation_time: Some(150) }),
+                ]
+                .into_iter()
+                .collect(),
                 override_message_expiry_window: Some(10),
-            },
+            }
+            .into(),
+            dependency_set_path: None,
             rollup_config_set: mock_rollup_config_set(),
+            prune_safety_margin: None,
+            event_export: None,
         }
     }
 
@@ -136,6 +174,16 @@ mod tests {
         assert_eq!(res, Err(InteropValidationError::InteropNotEnabled));
     }
 
+    #[test]
+    fn test_chain_not_yet_in_dependency_set() {
+        let cfg = mock_config();
+        let res = cfg.validate_interop_timestamps(1, 120, 2, 125, None);
+        assert_eq!(res, Err(InteropValidationError::InteropNotEnabled));
+
+        let res = cfg.validate_interop_timestamps(2, 150, 1, 152, None);
+        assert_eq!(res, Ok(()));
+    }
+
     #[test]
     fn test_invalid_timestamp_invariant() {
         let cfg = mock_config();
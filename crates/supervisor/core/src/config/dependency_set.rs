@@ -0,0 +1,273 @@
+use alloy_primitives::ChainId;
+use kona_interop::DependencySet;
+use std::{
+    path::Path,
+    sync::{Arc, PoisonError, RwLock},
+};
+use thiserror::Error;
+
+/// Errors returned when reloading the [`DependencySet`].
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum DependencySetReloadError {
+    /// No dependency set file is configured.
+    #[error("dependency set path not configured")]
+    PathNotConfigured,
+
+    /// The dependency set file could not be read or parsed.
+    #[error("failed to load dependency set: {0}")]
+    Load(String),
+
+    /// The reloaded dependency set adds or removes chains.
+    #[error("chains of the dependency set cannot change at runtime")]
+    ChainsChanged,
+
+    /// The reloaded dependency set changes the message expiry window.
+    #[error("message expiry window cannot change at runtime, current: {current}, new: {new}")]
+    ExpiryWindowChanged {
+        /// Current message expiry window.
+        current: u64,
+        /// Message expiry window of the reloaded dependency set.
+        new: u64,
+    },
+
+    /// The reloaded dependency set reschedules a chain at or before cross-validated data.
+    #[error(
+        "activation of chain {chain_id} cannot change at or before validated timestamp {validated_timestamp}"
+    )]
+    ActivationConflict {
+        /// Chain whose activation time changed.
+        chain_id: ChainId,
+        /// Highest cross-validated timestamp across all chains.
+        validated_timestamp: u64,
+    },
+}
+
+/// A [`DependencySet`] that can be replaced while the supervisor is running.
+///
+/// Clones share the same underlying dependency set, so a reload is observed by every component
+/// holding the supervisor [`Config`](super::Config). Every reload bumps a generation number, which
+/// lets components caching results derived from the dependency set detect that they are stale.
+#[derive(Debug, Clone, Default)]
+pub struct ReloadableDependencySet(Arc<RwLock<Versioned>>);
+
+/// The current dependency set and the number of reloads it went through.
+#[derive(Debug, Default)]
+struct Versioned {
+    dependency_set: Arc<DependencySet>,
+    generation: u64,
+}
+
+impl ReloadableDependencySet {
+    /// Creates a new [`ReloadableDependencySet`] holding the given dependency set.
+    pub fn new(dependency_set: DependencySet) -> Self {
+        Self(Arc::new(RwLock::new(Versioned {
+            dependency_set: Arc::new(dependency_set),
+            generation: 0,
+        })))
+    }
+
+    /// Returns the current [`DependencySet`].
+    pub fn load(&self) -> Arc<DependencySet> {
+        self.0.read().unwrap_or_else(PoisonError::into_inner).dependency_set.clone()
+    }
+
+    /// Returns the generation of the current [`DependencySet`], i.e. the number of reloads.
+    pub fn generation(&self) -> u64 {
+        self.0.read().unwrap_or_else(PoisonError::into_inner).generation
+    }
+
+    /// Runs `f` if the dependency set is still at the given generation, holding off reloads until
+    /// it returns.
+    ///
+    /// Returns `None` without running `f` if the dependency set was reloaded in the meantime.
+    pub fn with_generation<T>(&self, generation: u64, f: impl FnOnce() -> T) -> Option<T> {
+        let current = self.0.read().unwrap_or_else(PoisonError::into_inner);
+        (current.generation == generation).then(f)
+    }
+
+    /// Replaces the current [`DependencySet`] if the update keeps cross-safe data consistent.
+    ///
+    /// Chains cannot be added or removed, and the message expiry window cannot change, since
+    /// both would retroactively change the validity of stored messages. Activation times may only
+    /// be changed if both the current and the new activation time lie after the validated
+    /// timestamp, i.e. the highest cross-unsafe or cross-safe timestamp of any chain.
+    ///
+    /// The validated timestamp is computed while holding the lock, so no block validated against
+    /// the current dependency set can be promoted between the check and the swap, see
+    /// [`Self::with_generation`].
+    pub fn update<E>(
+        &self,
+        dependency_set: DependencySet,
+        validated_timestamp: impl FnOnce() -> Result<u64, E>,
+    ) -> Result<(), E>
+    where
+        E: From<DependencySetReloadError>,
+    {
+        let mut current = self.0.write().unwrap_or_else(PoisonError::into_inner);
+        validate_update(&current.dependency_set, &dependency_set, validated_timestamp()?)?;
+        current.dependency_set = Arc::new(dependency_set);
+        current.generation += 1;
+        Ok(())
+    }
+}
+
+/// Reads a [`DependencySet`] from the given JSON file.
+pub async fn load_dependency_set(path: &Path) -> Result<DependencySet, DependencySetReloadError> {
+    let content = tokio::fs::read(path)
+        .await
+        .map_err(|err| DependencySetReloadError::Load(err.to_string()))?;
+    serde_json::from_slice(&content).map_err(|err| DependencySetReloadError::Load(err.to_string()))
+}
+
+impl From<DependencySet> for ReloadableDependencySet {
+    fn from(dependency_set: DependencySet) -> Self {
+        Self::new(dependency_set)
+    }
+}
+
+fn validate_update(
+    current: &DependencySet,
+    new: &DependencySet,
+    validated_timestamp: u64,
+) -> Result<(), DependencySetReloadError> {
+    if current.dependencies.len() != new.dependencies.len() ||
+        !current.dependencies.keys().all(|chain_id| new.dependencies.contains_key(chain_id))
+    {
+        return Err(DependencySetReloadError::ChainsChanged);
+    }
+
+    let (current_window, new_window) =
+        (current.get_message_expiry_window(), new.get_message_expiry_window());
+    if current_window != new_window {
+        return Err(DependencySetReloadError::ExpiryWindowChanged {
+            current: current_window,
+            new: new_window,
+        });
+    }
+
+    for (chain_id, dependency) in &current.dependencies {
+        let new_activation = new.dependencies[chain_id].activation_time;
+        if dependency.activation_time == new_activation {
+            continue;
+        }
+
+        // A chain without activation time is part of the set from its interop activation
+        let earliest =
+            dependency.activation_time.unwrap_or_default().min(new_activation.unwrap_or_default());
+        if earliest <= validated_timestamp {
+            return Err(DependencySetReloadError::ActivationConflict {
+                chain_id: *chain_id,
+                validated_timestamp,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_interop::ChainDependency;
+
+    fn validated(timestamp: u64) -> impl FnOnce() -> Result<u64, DependencySetReloadError> {
+        move || Ok(timestamp)
+    }
+
+    fn dependency_set(activations: &[(ChainId, Option<u64>)]) -> DependencySet {
+        DependencySet {
+            dependencies: activations
+                .iter()
+                .map(|(chain_id, activation_time)| {
+                    (*chain_id, ChainDependency { activation_time: *activation_time })
+                })
+                .collect(),
+            override_message_expiry_window: None,
+        }
+    }
+
+    #[test]
+    fn test_update_reschedules_future_activation() {
+        let reloadable = ReloadableDependencySet::new(dependency_set(&[(1, None), (2, Some(200))]));
+
+        reloadable.update(dependency_set(&[(1, None), (2, Some(300))]), validated(150)).unwrap();
+        assert_eq!(reloadable.load().dependencies[&2].activation_time, Some(300));
+
+        // Clones observe the update
+        let clone = reloadable.clone();
+        reloadable.update(dependency_set(&[(1, None), (2, Some(250))]), validated(150)).unwrap();
+        assert_eq!(clone.load().dependencies[&2].activation_time, Some(250));
+    }
+
+    #[test]
+    fn test_update_bumps_generation() {
+        let reloadable = ReloadableDependencySet::new(dependency_set(&[(1, None), (2, Some(200))]));
+        assert_eq!(reloadable.generation(), 0);
+        assert_eq!(reloadable.with_generation(0, || 1), Some(1));
+
+        reloadable.update(dependency_set(&[(1, None), (2, Some(300))]), validated(150)).unwrap();
+        assert_eq!(reloadable.generation(), 1);
+        assert_eq!(reloadable.with_generation(0, || 1), None);
+
+        // Rejected updates keep the generation
+        let err = reloadable.update(dependency_set(&[(1, None)]), validated(150));
+        assert!(err.is_err());
+        assert_eq!(reloadable.generation(), 1);
+    }
+
+    #[test]
+    fn test_update_rejects_activation_before_validated_timestamp() {
+        let reloadable = ReloadableDependencySet::new(dependency_set(&[(1, None), (2, Some(200))]));
+
+        let err = reloadable
+            .update(dependency_set(&[(1, None), (2, Some(100))]), validated(150))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            DependencySetReloadError::ActivationConflict { chain_id: 2, validated_timestamp: 150 }
+        );
+
+        let err =
+            reloadable.update(dependency_set(&[(1, Some(300)), (2, Some(200))]), validated(150));
+        assert!(matches!(
+            err,
+            Err(DependencySetReloadError::ActivationConflict { chain_id: 1, .. })
+        ));
+
+        // The current dependency set is kept
+        assert_eq!(reloadable.load().dependencies[&2].activation_time, Some(200));
+    }
+
+    #[tokio::test]
+    async fn test_load_dependency_set() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(
+            &mut file,
+            br#"{"dependencies":{"1":{},"2":{"activationTime":100}},"overrideMessageExpiryWindow":null}"#,
+        )
+        .unwrap();
+
+        let loaded = load_dependency_set(file.path()).await.unwrap();
+        assert_eq!(loaded, dependency_set(&[(1, None), (2, Some(100))]));
+
+        let err = load_dependency_set(Path::new("/non/existent/depset.json")).await.unwrap_err();
+        assert!(matches!(err, DependencySetReloadError::Load(_)));
+    }
+
+    #[test]
+    fn test_update_rejects_chain_and_expiry_changes() {
+        let reloadable = ReloadableDependencySet::new(dependency_set(&[(1, None), (2, None)]));
+
+        let err =
+            reloadable.update(dependency_set(&[(1, None), (3, None)]), validated(0)).unwrap_err();
+        assert_eq!(err, DependencySetReloadError::ChainsChanged);
+
+        let err = reloadable.update(dependency_set(&[(1, None)]), validated(0)).unwrap_err();
+        assert_eq!(err, DependencySetReloadError::ChainsChanged);
+
+        let mut new = dependency_set(&[(1, None), (2, None)]);
+        new.override_message_expiry_window = Some(10);
+        let err = reloadable.update(new, validated(0)).unwrap_err();
+        assert!(matches!(err, DependencySetReloadError::ExpiryWindowChanged { new: 10, .. }));
+    }
+}
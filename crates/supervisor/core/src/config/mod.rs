@@ -3,5 +3,8 @@
 mod rollup_config_set;
 pub use rollup_config_set::{Genesis, RollupConfig, RollupConfigSet};
 
+mod dependency_set;
+pub use dependency_set::{DependencySetReloadError, ReloadableDependencySet, load_dependency_set};
+
 mod core_config;
-pub use core_config::Config;
+pub use core_config::{Config, EventExportConfig};
@@ -1,6 +1,6 @@
 //! [`SupervisorService`](crate::SupervisorService) errors.
 
-use crate::syncnode::ManagedNodeError;
+use crate::{config::DependencySetReloadError, syncnode::ManagedNodeError};
 use derive_more;
 use jsonrpsee::types::{ErrorCode, ErrorObjectOwned};
 use kona_supervisor_storage::StorageError;
@@ -61,6 +61,21 @@ pub enum SupervisorError {
     /// Indicates that the chain ID could not be parsed from the access list.
     #[error("failed to parse chain id from access list")]
     ChainIdParseError(),
+
+    /// Indicates that reloading the dependency set failed.
+    #[error(transparent)]
+    DependencySetReload(#[from] DependencySetReloadError),
+
+    /// Indicates that the requested timestamp range is empty or too large.
+    #[error("invalid timestamp range from {from} to {to} with step {step}")]
+    InvalidTimestampRange {
+        /// First timestamp of the range.
+        from: u64,
+        /// Last timestamp of the range.
+        to: u64,
+        /// Step between the timestamps.
+        step: u64,
+    },
 }
 
 impl PartialEq for SupervisorError {
@@ -75,9 +90,14 @@ impl PartialEq for SupervisorError {
             (ManagedNodeError(a), ManagedNodeError(b)) => a == b,
             (AccessListError(a), AccessListError(b)) => a == b,
             (SerdeJson(a), SerdeJson(b)) => a.to_string() == b.to_string(),
+            (DependencySetReload(a), DependencySetReload(b)) => a == b,
             (L1BlockMismatch { expected: a, got: b }, L1BlockMismatch { expected: c, got: d }) => {
                 a == c && b == d
             }
+            (
+                InvalidTimestampRange { from: a, to: b, step: c },
+                InvalidTimestampRange { from: d, to: e, step: f },
+            ) => a == d && b == e && c == f,
             _ => false,
         }
     }
@@ -129,7 +149,13 @@ impl From<SupervisorError> for ErrorObjectOwned {
             SupervisorError::StorageError(_) |
             SupervisorError::AccessListError(_) |
             SupervisorError::ChainIdParseError() |
+            SupervisorError::DependencySetReload(_) |
             SupervisorError::SerdeJson(_) => ErrorObjectOwned::from(ErrorCode::InternalError),
+            SupervisorError::InvalidTimestampRange { .. } => ErrorObjectOwned::owned(
+                ErrorCode::InvalidParams.code(),
+                err.to_string(),
+                None::<()>,
+            ),
             SupervisorError::SpecError(err) => err.into(),
         }
     }
@@ -141,6 +167,8 @@ impl From<StorageError> for SpecError {
             StorageError::Database(_) => Self::from(SuperchainDAError::DataCorruption),
             StorageError::FutureData => Self::from(SuperchainDAError::FutureData),
             StorageError::EntryNotFound(_) => Self::from(SuperchainDAError::MissedData),
+            // Pruned entries are past the message expiry window, hence can no longer be referenced.
+            StorageError::EntryPruned(_) => Self::from(SuperchainDAError::ConflictingData),
             StorageError::ConflictError => Self::from(SuperchainDAError::ConflictingData),
             StorageError::BlockOutOfOrder => Self::from(SuperchainDAError::OutOfOrder),
             StorageError::DatabaseNotInitialised => Self::ErrorNotInSpec,
@@ -186,6 +214,11 @@ mod test {
         let expected_err = SpecError::SuperchainDAError(SuperchainDAError::MissedData);
 
         assert_eq!(spec_err, expected_err.into());
+
+        let spec_err = ErrorObjectOwned::from(SpecError::from(StorageError::EntryPruned(12)));
+        let expected_err = SpecError::SuperchainDAError(SuperchainDAError::ConflictingData);
+
+        assert_eq!(spec_err, expected_err.into());
     }
 
     #[test]
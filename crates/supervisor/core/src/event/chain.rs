@@ -1,5 +1,6 @@
 use kona_interop::{BlockReplacement, DerivedRefPair};
 use kona_protocol::BlockInfo;
+use kona_supervisor_rpc::{ChainEventInfo, SafetyEventKind};
 
 /// Represents chain events that are emitted from modules in the supervisor.
 /// These events are used to notify the [`ChainProcessor`](crate::chain_processor::ChainProcessor)
@@ -57,3 +58,52 @@ pub enum ChainEvent {
         derived_ref_pair: DerivedRefPair,
     },
 }
+
+impl ChainEvent {
+    /// Returns the [`SafetyEventKind`] exported for this event, or `None` if the event is only
+    /// used internally.
+    pub const fn safety_event_kind(&self) -> Option<SafetyEventKind> {
+        match *self {
+            Self::CrossUnsafeUpdate { block } => Some(SafetyEventKind::CrossUnsafeUpdate { block }),
+            Self::CrossSafeUpdate { derived_ref_pair } => Some(SafetyEventKind::CrossSafeUpdate {
+                derived: derived_ref_pair.derived,
+                source: derived_ref_pair.source,
+            }),
+            Self::InvalidateBlock { block } => Some(SafetyEventKind::InvalidateBlock { block }),
+            Self::BlockReplaced { replacement } => Some(SafetyEventKind::BlockReplaced {
+                replacement: replacement.replacement,
+                invalidated: replacement.invalidated,
+            }),
+            Self::FinalizedSourceUpdate { finalized_source_block } => {
+                Some(SafetyEventKind::FinalizedSourceUpdate { block: finalized_source_block })
+            }
+            Self::UnsafeBlock { .. } |
+            Self::DerivedBlock { .. } |
+            Self::DerivationOriginUpdate { .. } => None,
+        }
+    }
+}
+
+impl From<&ChainEvent> for ChainEventInfo {
+    fn from(event: &ChainEvent) -> Self {
+        let (kind, block) = match event {
+            ChainEvent::UnsafeBlock { block } => ("unsafeBlock", block),
+            ChainEvent::DerivedBlock { derived_ref_pair } => {
+                ("derivedBlock", &derived_ref_pair.derived)
+            }
+            ChainEvent::DerivationOriginUpdate { origin } => ("derivationOriginUpdate", origin),
+            ChainEvent::InvalidateBlock { block } => ("invalidateBlock", block),
+            ChainEvent::BlockReplaced { replacement } => {
+                ("blockReplaced", &replacement.replacement)
+            }
+            ChainEvent::FinalizedSourceUpdate { finalized_source_block } => {
+                ("finalizedSourceUpdate", finalized_source_block)
+            }
+            ChainEvent::CrossUnsafeUpdate { block } => ("crossUnsafeUpdate", block),
+            ChainEvent::CrossSafeUpdate { derived_ref_pair } => {
+                ("crossSafeUpdate", &derived_ref_pair.derived)
+            }
+        };
+        Self { kind: kind.to_string(), block: block.id() }
+    }
+}
@@ -0,0 +1,295 @@
+use crate::event::{EventSink, EventSinkError};
+use alloy_primitives::keccak256;
+use std::{
+    fmt,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+
+/// Initial delay before reconnecting to a failed export target. Doubles on every failure.
+const MIN_RETRY_DELAY: Duration = Duration::from_secs(1);
+/// Maximum delay before reconnecting to a failed export target.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Destination of the newline-delimited JSON export of safety events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportTarget {
+    /// Appends the events to a file.
+    File(PathBuf),
+    /// Streams the events to a TCP listener.
+    Tcp(SocketAddr),
+    /// Streams the events to a Unix domain socket.
+    Unix(PathBuf),
+}
+
+impl ExportTarget {
+    /// Stable name of the target, used to persist its cursor.
+    pub fn name(&self) -> String {
+        let id = keccak256(self.to_string());
+        let kind = match self {
+            Self::File(_) => "file",
+            Self::Tcp(_) => "tcp",
+            Self::Unix(_) => "unix",
+        };
+        format!("{kind}-{}", alloy_primitives::hex::encode(&id[..8]))
+    }
+
+    async fn connect(&self) -> std::io::Result<Box<dyn AsyncWrite + Send + Unpin>> {
+        Ok(match self {
+            Self::File(path) => {
+                Box::new(tokio::fs::OpenOptions::new().create(true).append(true).open(path).await?)
+            }
+            Self::Tcp(addr) => Box::new(tokio::net::TcpStream::connect(addr).await?),
+            #[cfg(unix)]
+            Self::Unix(path) => Box::new(tokio::net::UnixStream::connect(path).await?),
+            #[cfg(not(unix))]
+            Self::Unix(_) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "unix sockets are not supported on this platform",
+                ));
+            }
+        })
+    }
+}
+
+impl fmt::Display for ExportTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "file://{}", path.display()),
+            Self::Tcp(addr) => write!(f, "tcp://{addr}"),
+            Self::Unix(path) => write!(f, "unix://{}", path.display()),
+        }
+    }
+}
+
+impl FromStr for ExportTarget {
+    type Err = String;
+
+    /// Parses `file://<path>`, `tcp://<host:port>` or `unix://<path>`. A value without scheme is
+    /// treated as a file path.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(addr) = s.strip_prefix("tcp://") {
+            return addr
+                .parse()
+                .map(Self::Tcp)
+                .map_err(|err| format!("invalid TCP address '{addr}': {err}"));
+        }
+        if let Some(path) = s.strip_prefix("unix://") {
+            return Ok(Self::Unix(path.into()));
+        }
+        let path = s.strip_prefix("file://").unwrap_or(s);
+        if path.is_empty() {
+            return Err("empty export target".to_string());
+        }
+        Ok(Self::File(path.into()))
+    }
+}
+
+/// A background job delivering the events of an [`EventSink`] to an [`ExportTarget`] as
+/// newline-delimited JSON.
+///
+/// The sequence number of the next event to deliver is persisted after every delivered event, and
+/// delivery resumes from it after a restart or a failed connection. Events are therefore delivered
+/// at least once, and consumers should drop events with an already seen sequence number.
+#[derive(Debug)]
+pub struct EventExporter {
+    sink: Arc<EventSink>,
+    target: ExportTarget,
+    cursor_path: PathBuf,
+    cancel_token: CancellationToken,
+}
+
+impl EventExporter {
+    /// Creates a new [`EventExporter`], persisting its cursor in the given directory.
+    pub fn new(
+        sink: Arc<EventSink>,
+        target: ExportTarget,
+        cursor_dir: &Path,
+        cancel_token: CancellationToken,
+    ) -> Self {
+        let cursor_path = cursor_dir.join(format!("{}.cursor", target.name()));
+        Self { sink, target, cursor_path, cancel_token }
+    }
+
+    /// Runs the exporter until cancelled, reconnecting with a backoff whenever delivery fails.
+    pub async fn run(self) {
+        let mut next_sequence = match self.load_cursor().await {
+            Ok(Some(sequence)) => sequence,
+            Ok(None) => self.sink.first_sequence(),
+            Err(err) => {
+                warn!(
+                    target: "supervisor::event_sink",
+                    export = %self.target,
+                    %err,
+                    "Failed to load export cursor, exporting all retained events"
+                );
+                self.sink.first_sequence()
+            }
+        };
+        let consumer = self.target.name();
+        self.sink.ack(&consumer, next_sequence);
+
+        info!(
+            target: "supervisor::event_sink",
+            export = %self.target,
+            next_sequence,
+            "Started event exporter"
+        );
+
+        let mut retry_delay = MIN_RETRY_DELAY;
+        loop {
+            let delivered_before = next_sequence;
+            let result = self.deliver(&consumer, &mut next_sequence).await;
+            if self.cancel_token.is_cancelled() {
+                break;
+            }
+            if next_sequence > delivered_before {
+                retry_delay = MIN_RETRY_DELAY;
+            }
+            if let Err(EventSinkError::CursorExpired { requested, oldest }) = result {
+                // The journal was compacted while the exporter was not running, or the exporter
+                // fell too far behind and the events pending for it were dropped.
+                error!(
+                    target: "supervisor::event_sink",
+                    export = %self.target,
+                    requested,
+                    oldest,
+                    skipped = oldest - requested,
+                    "Events to export are no longer retained, skipping to the oldest retained event"
+                );
+                next_sequence = oldest;
+                continue;
+            }
+            if let Err(err) = result {
+                warn!(
+                    target: "supervisor::event_sink",
+                    export = %self.target,
+                    %err,
+                    ?retry_delay,
+                    "Event export failed, retrying"
+                );
+            }
+
+            tokio::select! {
+                _ = self.cancel_token.cancelled() => break,
+                _ = tokio::time::sleep(retry_delay) => {}
+            }
+            retry_delay = (retry_delay * 2).min(MAX_RETRY_DELAY);
+        }
+
+        info!(target: "supervisor::event_sink", export = %self.target, "Stopped event exporter");
+    }
+
+    // Delivers events until cancelled or failing, advancing the cursor after each event.
+    async fn deliver(&self, consumer: &str, next_sequence: &mut u64) -> Result<(), EventSinkError> {
+        let mut writer = self.target.connect().await?;
+        let mut subscription = self.sink.subscribe(Some(*next_sequence))?;
+
+        loop {
+            let event = tokio::select! {
+                _ = self.cancel_token.cancelled() => return Ok(()),
+                event = subscription.next() => event?,
+            };
+
+            let mut line = serde_json::to_vec(&event)?;
+            line.push(b'\n');
+            writer.write_all(&line).await?;
+            writer.flush().await?;
+
+            *next_sequence = event.sequence + 1;
+            self.store_cursor(*next_sequence).await?;
+            self.sink.ack(consumer, *next_sequence);
+        }
+    }
+
+    async fn load_cursor(&self) -> Result<Option<u64>, EventSinkError> {
+        match tokio::fs::read_to_string(&self.cursor_path).await {
+            Ok(contents) => contents
+                .trim()
+                .parse()
+                .map(Some)
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err).into()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn store_cursor(&self, next_sequence: u64) -> Result<(), EventSinkError> {
+        let tmp_path = self.cursor_path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, next_sequence.to_string()).await?;
+        tokio::fs::rename(&tmp_path, &self.cursor_path).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::ChainEvent;
+    use kona_protocol::BlockInfo;
+    use kona_supervisor_rpc::SafetyEvent;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_export_target() {
+        assert_eq!(
+            "tcp://127.0.0.1:9000".parse::<ExportTarget>().unwrap(),
+            ExportTarget::Tcp("127.0.0.1:9000".parse().unwrap())
+        );
+        assert_eq!(
+            "unix:///tmp/events.sock".parse::<ExportTarget>().unwrap(),
+            ExportTarget::Unix("/tmp/events.sock".into())
+        );
+        assert_eq!(
+            "/tmp/events.ndjson".parse::<ExportTarget>().unwrap(),
+            ExportTarget::File("/tmp/events.ndjson".into())
+        );
+        assert!("tcp://localhost".parse::<ExportTarget>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_exporter_resumes_from_persisted_cursor() {
+        let dir = TempDir::new().unwrap();
+        let sink = Arc::new(EventSink::open(dir.path(), 100).unwrap());
+        let output = dir.path().join("events.ndjson");
+        let target = ExportTarget::File(output.clone());
+
+        let invalidate = |number| ChainEvent::InvalidateBlock {
+            block: BlockInfo { number, ..Default::default() },
+        };
+        sink.publish(1, &invalidate(0)).await.unwrap();
+        sink.publish(1, &invalidate(1)).await.unwrap();
+
+        // The first event was delivered before a restart.
+        let exporter =
+            EventExporter::new(sink.clone(), target, dir.path(), CancellationToken::new());
+        exporter.store_cursor(1).await.unwrap();
+
+        let cancel_token = exporter.cancel_token.clone();
+        let handle = tokio::spawn(exporter.run());
+        sink.publish(2, &invalidate(2)).await.unwrap();
+
+        let sequences = loop {
+            let contents = tokio::fs::read_to_string(&output).await.unwrap_or_default();
+            let sequences: Vec<u64> = contents
+                .lines()
+                .map(|line| serde_json::from_str::<SafetyEvent>(line).unwrap().sequence)
+                .collect();
+            if sequences.len() >= 2 {
+                break sequences;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        };
+        cancel_token.cancel();
+        handle.await.unwrap();
+
+        assert_eq!(sequences, vec![1, 2]);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::event::EventSinkError;
+use alloy_primitives::ChainId;
+use kona_supervisor_rpc::{SafetyEvent, SafetyEventKind};
+use std::{
+    collections::VecDeque,
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+use tracing::warn;
+
+/// Append-only journal of [`SafetyEvent`]s, persisted as newline-delimited JSON.
+///
+/// The journal assigns the sequence numbers of the events and keeps the retained events in memory
+/// for replay. Compaction rewrites the file without the events no consumer needs anymore, but
+/// always keeps the latest event, so sequence numbers continue across restarts.
+///
+/// Writing to the file blocks, so [`EventJournal::append`] and [`EventJournal::compact`] must run
+/// on a blocking thread. The file and the retained events are guarded separately: readers of the
+/// retained events never wait on file I/O.
+#[derive(Debug)]
+pub(crate) struct EventJournal {
+    path: PathBuf,
+    writer: Mutex<BufWriter<File>>,
+    retained: Mutex<RetainedEvents>,
+}
+
+/// The events kept in memory by the [`EventJournal`].
+#[derive(Debug)]
+struct RetainedEvents {
+    events: VecDeque<SafetyEvent>,
+    next_sequence: u64,
+}
+
+impl EventJournal {
+    /// Opens the journal at the given path, creating it if it doesn't exist.
+    pub(crate) fn open(path: impl Into<PathBuf>) -> Result<Self, EventSinkError> {
+        let path = path.into();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut events = VecDeque::new();
+        if path.exists() {
+            for line in BufReader::new(File::open(&path)?).lines() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<SafetyEvent>(&line) {
+                    Ok(event) => events.push_back(event),
+                    Err(err) => {
+                        // Only the last line can be partially written by an interrupted append.
+                        warn!(
+                            target: "supervisor::event_sink",
+                            path = %path.display(),
+                            %err,
+                            "Skipping malformed event journal entry"
+                        );
+                    }
+                }
+            }
+        }
+        let next_sequence = events.back().map_or(0, |event| event.sequence + 1);
+
+        // Drop any malformed entry from the file as well.
+        let writer = Self::rewrite(&path, &events)?;
+        Ok(Self {
+            path,
+            writer: Mutex::new(writer),
+            retained: Mutex::new(RetainedEvents { events, next_sequence }),
+        })
+    }
+
+    fn open_writer(path: &Path) -> Result<BufWriter<File>, EventSinkError> {
+        Ok(BufWriter::new(OpenOptions::new().create(true).append(true).open(path)?))
+    }
+
+    /// Appends an event to the journal, returning it with its assigned sequence number.
+    pub(crate) fn append(
+        &self,
+        chain_id: ChainId,
+        kind: SafetyEventKind,
+    ) -> Result<SafetyEvent, EventSinkError> {
+        // Holding the writer serializes appends, so sequence numbers are assigned in file order.
+        let mut writer = lock(&self.writer);
+        let event = SafetyEvent { sequence: self.next_sequence(), chain_id, kind };
+        serde_json::to_writer(&mut *writer, &event)?;
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+
+        let mut retained = lock(&self.retained);
+        retained.next_sequence += 1;
+        retained.events.push_back(event.clone());
+        Ok(event)
+    }
+
+    /// Returns the event with the given sequence number, if it is retained.
+    pub(crate) fn get(&self, sequence: u64) -> Option<SafetyEvent> {
+        let retained = lock(&self.retained);
+        let first = retained.events.front()?.sequence;
+        let index = usize::try_from(sequence.checked_sub(first)?).ok()?;
+        retained.events.get(index).cloned()
+    }
+
+    /// Returns the sequence number of the oldest retained event.
+    pub(crate) fn first_sequence(&self) -> u64 {
+        let retained = lock(&self.retained);
+        retained.events.front().map_or(retained.next_sequence, |event| event.sequence)
+    }
+
+    /// Returns the sequence number the next appended event gets.
+    pub(crate) fn next_sequence(&self) -> u64 {
+        lock(&self.retained).next_sequence
+    }
+
+    /// Returns the number of retained events.
+    pub(crate) fn len(&self) -> usize {
+        lock(&self.retained).events.len()
+    }
+
+    /// Drops the events with a sequence number below `sequence`, keeping at least the latest event.
+    pub(crate) fn compact(&self, sequence: u64) -> Result<(), EventSinkError> {
+        let mut writer = lock(&self.writer);
+        let events = {
+            let mut retained = lock(&self.retained);
+            let before = retained.events.len();
+            while retained.events.len() > 1 &&
+                retained.events.front().is_some_and(|e| e.sequence < sequence)
+            {
+                retained.events.pop_front();
+            }
+            if retained.events.len() == before {
+                return Ok(());
+            }
+            retained.events.clone()
+        };
+        *writer = Self::rewrite(&self.path, &events)?;
+        Ok(())
+    }
+
+    /// Atomically replaces the journal file with the given events, returning a writer appending
+    /// to the new file.
+    fn rewrite(
+        path: &Path,
+        events: &VecDeque<SafetyEvent>,
+    ) -> Result<BufWriter<File>, EventSinkError> {
+        let tmp_path = path.with_extension("tmp");
+        {
+            let mut tmp = BufWriter::new(File::create(&tmp_path)?);
+            for event in events {
+                serde_json::to_writer(&mut tmp, event)?;
+                tmp.write_all(b"\n")?;
+            }
+            tmp.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+        }
+        fs::rename(&tmp_path, path)?;
+        Self::open_writer(path)
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_protocol::BlockInfo;
+    use tempfile::TempDir;
+
+    fn invalidate(number: u64) -> SafetyEventKind {
+        SafetyEventKind::InvalidateBlock { block: BlockInfo { number, ..Default::default() } }
+    }
+
+    #[test]
+    fn test_journal_survives_reopen_and_compaction() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("events").join("journal.ndjson");
+
+        let journal = EventJournal::open(&path).unwrap();
+        for number in 0..5 {
+            let event = journal.append(10, invalidate(number)).unwrap();
+            assert_eq!(event.sequence, number);
+        }
+        journal.compact(3).unwrap();
+        assert_eq!(journal.first_sequence(), 3);
+        assert!(journal.get(2).is_none());
+        drop(journal);
+
+        // Simulate an interrupted append.
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"sequence\":5,").unwrap();
+        drop(file);
+
+        let journal = EventJournal::open(&path).unwrap();
+        assert_eq!(journal.len(), 2);
+        assert_eq!(journal.get(4).unwrap().kind, invalidate(4));
+        assert_eq!(journal.append(10, invalidate(5)).unwrap().sequence, 5);
+
+        // The latest event is always kept, so sequences continue after a full compaction.
+        journal.compact(u64::MAX).unwrap();
+        drop(journal);
+        let journal = EventJournal::open(&path).unwrap();
+        assert_eq!(journal.first_sequence(), 5);
+        assert_eq!(journal.next_sequence(), 6);
+    }
+}
@@ -1,4 +1,16 @@
 //! Event module for the chain processor and supervisor coordination.
+//!
+//! The safety events among them can be exported to downstream consumers through the
+//! [`EventSink`], either as a JSON-RPC subscription or as newline-delimited JSON delivered by an
+//! [`EventExporter`].
 
 mod chain;
 pub use chain::ChainEvent;
+
+mod export;
+pub use export::{EventExporter, ExportTarget};
+
+mod journal;
+
+mod sink;
+pub use sink::{EventSink, EventSinkError, EventSubscription};
@@ -0,0 +1,308 @@
+use crate::event::{ChainEvent, journal::EventJournal};
+use alloy_primitives::ChainId;
+use kona_supervisor_rpc::SafetyEvent;
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, Mutex, PoisonError},
+};
+use thiserror::Error;
+use tokio::{sync::broadcast, task};
+use tracing::{error, warn};
+
+/// Capacity of the channel fanning out live events. Subscribers lagging further behind catch up
+/// from the journal.
+const LIVE_CHANNEL_CAPACITY: usize = 1024;
+
+/// Events pending for a lagging consumer are retained up to this many times the retention. Older
+/// events are dropped, and the consumer resumes after the gap, see
+/// [`EventSinkError::CursorExpired`].
+const MAX_LAGGING_RETENTION_FACTOR: u64 = 10;
+
+/// Errors of the [`EventSink`].
+#[derive(Debug, Error)]
+pub enum EventSinkError {
+    /// Reading or writing the journal, a cursor or an export target failed.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// An event could not be (de)serialized.
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+
+    /// The sink stopped publishing events.
+    #[error("event sink closed")]
+    Closed,
+
+    /// The blocking task writing the journal failed.
+    #[error("event journal task failed: {0}")]
+    JournalTask(#[from] task::JoinError),
+
+    /// The requested events were already dropped from the journal.
+    #[error("events from sequence {requested} are no longer retained, oldest is {oldest}")]
+    CursorExpired {
+        /// The requested sequence number.
+        requested: u64,
+        /// The sequence number of the oldest retained event.
+        oldest: u64,
+    },
+}
+
+/// Exports the safety events of all chains, see [`SafetyEvent`].
+///
+/// Published events are appended to a journal persisted on disk before being fanned out to the
+/// live subscribers, so every event can be replayed from its sequence number. Consumers
+/// registering a cursor with [`EventSink::ack`] get at-least-once delivery: the journal keeps all
+/// events from the oldest registered cursor on, up to [`MAX_LAGGING_RETENTION_FACTOR`] times the
+/// retention. On top of that, the most recent `retention` events are kept for subscribers resuming
+/// from their own cursor.
+///
+/// Slow subscribers never block publishing: once they fall behind the live channel, they catch up
+/// from the journal. The journal file is written on a blocking thread, so publishing never blocks
+/// the async runtime.
+#[derive(Debug)]
+pub struct EventSink {
+    journal: Arc<EventJournal>,
+    live: broadcast::Sender<SafetyEvent>,
+    cursors: Mutex<HashMap<String, u64>>,
+    retention: usize,
+}
+
+impl EventSink {
+    /// Opens the sink, persisting its journal in the given directory.
+    pub fn open(dir: &Path, retention: usize) -> Result<Self, EventSinkError> {
+        let journal = EventJournal::open(dir.join("journal.ndjson"))?;
+        let (live, _) = broadcast::channel(LIVE_CHANNEL_CAPACITY);
+        Ok(Self {
+            journal: Arc::new(journal),
+            live,
+            cursors: Mutex::new(HashMap::new()),
+            retention: retention.max(1),
+        })
+    }
+
+    /// Publishes the given chain event, if it is exported.
+    ///
+    /// Returns the published [`SafetyEvent`], or `None` for events that are not exported.
+    pub async fn publish(
+        &self,
+        chain_id: ChainId,
+        event: &ChainEvent,
+    ) -> Result<Option<SafetyEvent>, EventSinkError> {
+        let Some(kind) = event.safety_event_kind() else {
+            return Ok(None);
+        };
+
+        let journal = self.journal.clone();
+        let event = task::spawn_blocking(move || journal.append(chain_id, kind)).await??;
+        self.compact().await;
+
+        // No live subscribers is not an error, the event is in the journal.
+        let _ = self.live.send(event.clone());
+        Ok(Some(event))
+    }
+
+    /// Subscribes to the exported events, starting at the given sequence number, or with the next
+    /// published event if unset.
+    pub fn subscribe(
+        self: &Arc<Self>,
+        from_sequence: Option<u64>,
+    ) -> Result<EventSubscription, EventSinkError> {
+        // Subscribe before reading the journal, so no event is missed in between.
+        let live = self.live.subscribe();
+        let next_sequence = match from_sequence {
+            Some(sequence) => {
+                let oldest = self.journal.first_sequence();
+                if sequence < oldest {
+                    return Err(EventSinkError::CursorExpired { requested: sequence, oldest });
+                }
+                sequence
+            }
+            None => self.journal.next_sequence(),
+        };
+
+        Ok(EventSubscription { sink: self.clone(), live, next_sequence })
+    }
+
+    /// Records that the durable consumer with the given name has processed all events before
+    /// `next_sequence`. The journal retains all events from the oldest recorded cursor on.
+    pub fn ack(&self, consumer: &str, next_sequence: u64) {
+        self.cursors
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(consumer.to_string(), next_sequence);
+    }
+
+    /// Returns the sequence number of the oldest retained event.
+    pub fn first_sequence(&self) -> u64 {
+        self.journal.first_sequence()
+    }
+
+    // Compacts the journal once it holds twice the retention, so rewrites stay infrequent.
+    async fn compact(&self) {
+        let Some(keep_from) = self.compaction_target() else {
+            return;
+        };
+
+        let journal = self.journal.clone();
+        let result = task::spawn_blocking(move || journal.compact(keep_from)).await;
+        if let Err(err) = result.map_err(EventSinkError::from).and_then(|result| result) {
+            error!(target: "supervisor::event_sink", %err, "Failed to compact event journal");
+        }
+    }
+
+    /// Returns the sequence number of the oldest event to keep, if the journal is due for
+    /// compaction.
+    fn compaction_target(&self) -> Option<u64> {
+        if self.journal.len() < self.retention.saturating_mul(2) {
+            return None;
+        }
+
+        let next_sequence = self.journal.next_sequence();
+        let retention = self.retention as u64;
+        let keep_from = next_sequence.saturating_sub(retention);
+        let cap =
+            next_sequence.saturating_sub(retention.saturating_mul(MAX_LAGGING_RETENTION_FACTOR));
+        let cursors = self.cursors.lock().unwrap_or_else(PoisonError::into_inner);
+        let slowest = cursors.iter().min_by_key(|(_, cursor)| **cursor);
+        let Some((consumer, &cursor)) = slowest.filter(|(_, cursor)| **cursor < keep_from) else {
+            return Some(keep_from);
+        };
+
+        if cursor < cap {
+            error!(
+                target: "supervisor::event_sink",
+                consumer,
+                dropped = cap - cursor,
+                "Event consumer fell too far behind, dropping its oldest pending events"
+            );
+            return Some(cap);
+        }
+        warn!(
+            target: "supervisor::event_sink",
+            consumer,
+            pending = next_sequence - cursor,
+            "Event consumer is falling behind, retaining its pending events"
+        );
+        Some(cursor)
+    }
+}
+
+/// A stream of [`SafetyEvent`]s in sequence order, created by [`EventSink::subscribe`].
+#[derive(Debug)]
+pub struct EventSubscription {
+    sink: Arc<EventSink>,
+    live: broadcast::Receiver<SafetyEvent>,
+    next_sequence: u64,
+}
+
+impl EventSubscription {
+    /// Returns the sequence number of the next event.
+    pub const fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+
+    /// Waits for the next event.
+    ///
+    /// Fails with [`EventSinkError::CursorExpired`] if the subscriber fell behind the events
+    /// retained by the journal.
+    pub async fn next(&mut self) -> Result<SafetyEvent, EventSinkError> {
+        loop {
+            // Catch up from the journal first, then wait for live events.
+            let oldest = self.sink.journal.first_sequence();
+            if self.next_sequence < oldest {
+                return Err(EventSinkError::CursorExpired { requested: self.next_sequence, oldest });
+            }
+            if let Some(event) = self.sink.journal.get(self.next_sequence) {
+                self.next_sequence += 1;
+                return Ok(event);
+            }
+
+            match self.live.recv().await {
+                Ok(event) if event.sequence == self.next_sequence => {
+                    self.next_sequence += 1;
+                    return Ok(event);
+                }
+                // Already replayed from the journal, or missed and picked up on the next loop.
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => return Err(EventSinkError::Closed),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_protocol::BlockInfo;
+    use tempfile::TempDir;
+
+    fn invalidate(number: u64) -> ChainEvent {
+        ChainEvent::InvalidateBlock { block: BlockInfo { number, ..Default::default() } }
+    }
+
+    #[tokio::test]
+    async fn test_subscription_replays_then_follows_live_events() {
+        let dir = TempDir::new().unwrap();
+        let sink = Arc::new(EventSink::open(dir.path(), 100).unwrap());
+
+        let unsafe_block = ChainEvent::UnsafeBlock { block: BlockInfo::default() };
+        assert!(sink.publish(1, &unsafe_block).await.unwrap().is_none());
+        for number in 0..3 {
+            sink.publish(1, &invalidate(number)).await.unwrap();
+        }
+
+        let mut replay = sink.subscribe(Some(1)).unwrap();
+        let mut live = sink.subscribe(None).unwrap();
+        sink.publish(2, &invalidate(3)).await.unwrap();
+
+        for sequence in 1..4 {
+            assert_eq!(replay.next().await.unwrap().sequence, sequence);
+        }
+        let event = live.next().await.unwrap();
+        assert_eq!(event.sequence, 3);
+        assert_eq!(event.chain_id, 2);
+    }
+
+    #[tokio::test]
+    async fn test_compaction_retains_events_of_acknowledged_consumers() {
+        let dir = TempDir::new().unwrap();
+        let sink = Arc::new(EventSink::open(dir.path(), 2).unwrap());
+        sink.ack("file", 1);
+
+        for number in 0..10 {
+            sink.publish(1, &invalidate(number)).await.unwrap();
+        }
+        assert_eq!(sink.first_sequence(), 1);
+
+        sink.ack("file", 10);
+        sink.publish(1, &invalidate(10)).await.unwrap();
+        sink.publish(1, &invalidate(11)).await.unwrap();
+        sink.publish(1, &invalidate(12)).await.unwrap();
+        assert_eq!(sink.first_sequence(), 10);
+
+        assert!(matches!(
+            sink.subscribe(Some(0)),
+            Err(EventSinkError::CursorExpired { requested: 0, oldest: 10 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_compaction_caps_events_retained_for_lagging_consumers() {
+        let dir = TempDir::new().unwrap();
+        let sink = Arc::new(EventSink::open(dir.path(), 2).unwrap());
+        sink.ack("file", 0);
+
+        for number in 0..30 {
+            sink.publish(1, &invalidate(number)).await.unwrap();
+        }
+        // At most ten times the retention is kept for the lagging consumer.
+        assert_eq!(sink.first_sequence(), 10);
+
+        // The lagging consumer learns about the gap when resuming.
+        assert!(matches!(
+            sink.subscribe(Some(0)),
+            Err(EventSinkError::CursorExpired { requested: 0, oldest: 10 })
+        ));
+    }
+}
@@ -11,7 +11,7 @@ use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, trace};
 
-use crate::ReorgHandler;
+use crate::{ReorgHandler, RewindLock};
 
 /// A watcher that polls the L1 chain for finalized blocks.
 #[derive(Debug)]
@@ -26,6 +26,8 @@ pub struct L1Watcher<DB, F> {
     event_txs: HashMap<ChainId, mpsc::Sender<ChainEvent>>,
     /// The reorg handler.
     reorg_handler: ReorgHandler<DB>,
+    /// Holds off handling blocks while a chain is rewound.
+    rewind_lock: RewindLock,
 }
 
 impl<DB, F> L1Watcher<DB, F>
@@ -34,14 +36,27 @@ where
     DB: DbReader + StorageRewinder + Send + Sync + 'static,
 {
     /// Creates a new [`L1Watcher`] instance.
-    pub const fn new(
+    pub fn new(
         rpc_client: RpcClient,
         finalized_l1_storage: Arc<F>,
         event_txs: HashMap<ChainId, mpsc::Sender<ChainEvent>>,
         cancellation: CancellationToken,
         reorg_handler: ReorgHandler<DB>,
     ) -> Self {
-        Self { rpc_client, finalized_l1_storage, event_txs, cancellation, reorg_handler }
+        Self {
+            rpc_client,
+            finalized_l1_storage,
+            event_txs,
+            cancellation,
+            reorg_handler,
+            rewind_lock: RewindLock::new(),
+        }
+    }
+
+    /// Sets the [`RewindLock`] holding off handling blocks while a chain is rewound.
+    pub fn with_rewind_lock(mut self, rewind_lock: RewindLock) -> Self {
+        self.rewind_lock = rewind_lock;
+        self
     }
 
     /// Starts polling for finalized and latest blocks and processes them.
@@ -87,11 +102,13 @@ where
                 }
                 latest_block = latest_head_stream.next() => {
                     if let Some(latest_block) = latest_block {
+                        let _work = self.rewind_lock.work().await;
                         previous_latest_block = self.handle_new_latest_block(latest_block, previous_latest_block).await;
                     }
                 }
                 finalized_block = finalized_head_stream.next() => {
                     if let Some(finalized_block) = finalized_block {
+                        let _work = self.rewind_lock.work().await;
                         finalized_number = self.handle_new_finalized_block(finalized_block, finalized_number);
                     }
                 }
@@ -23,7 +23,10 @@ pub mod l1_watcher;
 pub mod syncnode;
 
 pub mod safety_checker;
-pub use safety_checker::{CrossSafetyCheckerJob, CrossSafetyError};
+pub use safety_checker::{CrossSafetyError, CrossSafetyScheduler};
+
+pub mod pruner;
+pub use pruner::StoragePrunerJob;
 
 mod reorg;
-pub use reorg::{ReorgHandler, ReorgHandlerError};
+pub use reorg::{ReorgHandler, ReorgHandlerError, RewindLock};
@@ -0,0 +1,9 @@
+//! Pruning of the supervisor storage.
+//!
+//! Once every chain of the dependency set has finalized past it, data older than the message expiry
+//! window can no longer be referenced by new executing messages. The [`StoragePrunerJob`]
+//! periodically removes it from the storage of a chain, keeping a configurable safety margin on
+//! top of the expiry window.
+
+mod task;
+pub use task::StoragePrunerJob;
@@ -0,0 +1,265 @@
+use crate::config::ReloadableDependencySet;
+use alloy_primitives::ChainId;
+use derive_more::Constructor;
+use kona_supervisor_storage::{
+    CrossChainSafetyProvider, HeadRefStorageReader, StorageError, StoragePruner,
+};
+use op_alloy_consensus::interop::SafetyLevel;
+use std::{sync::Arc, time::Duration};
+use thiserror::Error;
+use tokio::task::JoinError;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info};
+
+/// Errors of a single pruning pass.
+#[derive(Debug, Error)]
+enum PruneError {
+    /// Reading the finalized heads or pruning the storage failed.
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+
+    /// The blocking prune task panicked or was cancelled.
+    #[error(transparent)]
+    Join(#[from] JoinError),
+}
+
+/// A background job that prunes the storage of a given chain.
+///
+/// On each run, it removes the logs and derivation records of all blocks older than the retention
+/// period, i.e. the message expiry window of the current dependency set plus a safety margin.
+/// The retention period is counted back from the oldest finalized head across the dependency set:
+/// a chain lagging behind may still execute messages initiated by older blocks of this chain.
+#[derive(Debug, Constructor)]
+pub struct StoragePrunerJob<P, C> {
+    chain_id: ChainId,
+    provider: Arc<P>,
+    chains: Arc<C>,
+    dependency_set: ReloadableDependencySet,
+    cancel_token: CancellationToken,
+    interval: Duration,
+    block_time: u64,
+    safety_margin: u64,
+}
+
+impl<P, C> StoragePrunerJob<P, C>
+where
+    P: HeadRefStorageReader + StoragePruner + Send + Sync + 'static,
+    C: CrossChainSafetyProvider + Send + Sync + 'static,
+{
+    /// Runs the job loop until cancelled, pruning the storage every configured interval.
+    pub async fn run(self) {
+        let chain_id = self.chain_id;
+        info!(
+            target: "supervisor::pruner",
+            chain_id,
+            safety_margin = self.safety_margin,
+            "Started storage pruner"
+        );
+
+        loop {
+            tokio::select! {
+                _ = self.cancel_token.cancelled() => {
+                    info!(target: "supervisor::pruner", chain_id, "Canceled storage pruner");
+                    break;
+                }
+
+                _ = tokio::time::sleep(self.interval) => {
+                    match self.prune().await {
+                        Ok(pruned_blocks) => {
+                            debug!(target: "supervisor::pruner", chain_id, pruned_blocks, "Pruned storage");
+                        }
+                        // Some chain has no finalized block yet.
+                        Err(PruneError::Storage(StorageError::FutureData)) => {
+                            debug!(target: "supervisor::pruner", chain_id, "No finalized block to prune from");
+                        }
+                        Err(err) => {
+                            error!(target: "supervisor::pruner", chain_id, %err, "Failed to prune storage");
+                        }
+                    }
+                }
+            }
+        }
+
+        info!(target: "supervisor::pruner", chain_id, "Stopped storage pruner");
+    }
+
+    /// Prunes the storage below the first block still within the retention period.
+    ///
+    /// The batched deletes run on the blocking thread pool, so a large prune does not stall the
+    /// runtime.
+    async fn prune(&self) -> Result<u64, PruneError> {
+        let target = self.prune_target()?;
+        let provider = self.provider.clone();
+        Ok(tokio::task::spawn_blocking(move || provider.prune(target)).await??)
+    }
+
+    /// Returns the number of the oldest block that is still within the retention period.
+    fn prune_target(&self) -> Result<u64, StorageError> {
+        let dependency_set = self.dependency_set.load();
+        let retention =
+            dependency_set.get_message_expiry_window().saturating_add(self.safety_margin);
+
+        let finalized = self.provider.get_safety_head_ref(SafetyLevel::Finalized)?;
+        let mut oldest_finalized = finalized.timestamp;
+        for &chain_id in dependency_set.dependencies.keys() {
+            if chain_id != self.chain_id {
+                let head = self.chains.get_safety_head_ref(chain_id, SafetyLevel::Finalized)?;
+                oldest_finalized = oldest_finalized.min(head.timestamp);
+            }
+        }
+
+        let cutoff = oldest_finalized.saturating_sub(retention);
+        let block_time = if self.block_time == 0 { 1 } else { self.block_time };
+        Ok(finalized.number.saturating_sub(finalized.timestamp.saturating_sub(cutoff) / block_time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use kona_interop::{ChainDependency, DependencySet, DerivedRefPair};
+    use kona_protocol::BlockInfo;
+    use kona_supervisor_types::{Log, SuperHead};
+    use mockall::{mock, predicate::eq};
+
+    mock!(
+        #[derive(Debug)]
+        pub Db {}
+
+        impl HeadRefStorageReader for Db {
+            fn get_safety_head_ref(&self, level: SafetyLevel) -> Result<BlockInfo, StorageError>;
+            fn get_super_head(&self) -> Result<SuperHead, StorageError>;
+        }
+
+        impl StoragePruner for Db {
+            fn prune(&self, block_number: u64) -> Result<u64, StorageError>;
+        }
+    );
+
+    mock!(
+        #[derive(Debug)]
+        pub Chains {}
+
+        impl CrossChainSafetyProvider for Chains {
+            fn get_block(&self, chain_id: ChainId, block_number: u64) -> Result<BlockInfo, StorageError>;
+            fn get_log(&self, chain_id: ChainId, block_number: u64, log_index: u32) -> Result<Log, StorageError>;
+            fn get_block_logs(&self, chain_id: ChainId, block_number: u64) -> Result<Vec<Log>, StorageError>;
+            fn get_safety_head_ref(&self, chain_id: ChainId, level: SafetyLevel) -> Result<BlockInfo, StorageError>;
+            fn update_current_cross_unsafe(&self, chain_id: ChainId, block: &BlockInfo) -> Result<(), StorageError>;
+            fn update_current_cross_safe(&self, chain_id: ChainId, block: &BlockInfo) -> Result<DerivedRefPair, StorageError>;
+            fn revert_cross_head(&self, chain_id: ChainId, level: SafetyLevel, block: &BlockInfo) -> Result<(), StorageError>;
+        }
+    );
+
+    fn finalized(number: u64) -> BlockInfo {
+        BlockInfo { number, hash: B256::ZERO, parent_hash: B256::ZERO, timestamp: number * 2 }
+    }
+
+    fn dependency_set(chain_ids: &[ChainId], expiry: u64) -> ReloadableDependencySet {
+        DependencySet {
+            dependencies: chain_ids
+                .iter()
+                .map(|&chain_id| (chain_id, ChainDependency { activation_time: None }))
+                .collect(),
+            override_message_expiry_window: Some(expiry),
+        }
+        .into()
+    }
+
+    fn job(
+        db: MockDb,
+        chains: MockChains,
+        dependency_set: ReloadableDependencySet,
+    ) -> StoragePrunerJob<MockDb, MockChains> {
+        StoragePrunerJob::new(
+            1,
+            Arc::new(db),
+            Arc::new(chains),
+            dependency_set,
+            CancellationToken::new(),
+            Duration::from_millis(10),
+            2,
+            50,
+        )
+    }
+
+    #[tokio::test]
+    async fn prunes_below_retention_period() {
+        let mut db = MockDb::new();
+        db.expect_get_safety_head_ref()
+            .with(eq(SafetyLevel::Finalized))
+            .returning(|_| Ok(finalized(1_000)));
+        // 250 seconds of expiry and 50 seconds of margin at 2 seconds per block is 150 blocks.
+        db.expect_prune().with(eq(850)).times(1).returning(|_| Ok(849));
+
+        let job = job(db, MockChains::new(), dependency_set(&[1], 250));
+        assert_eq!(job.prune().await.unwrap(), 849);
+    }
+
+    #[tokio::test]
+    async fn retention_counts_from_oldest_finalized_dependency() {
+        let mut db = MockDb::new();
+        db.expect_get_safety_head_ref().returning(|_| Ok(finalized(1_000)));
+        // Chain 2 is finalized up to timestamp 1_600, so messages initiated 300 seconds earlier can
+        // still be executed: the first block to keep is 650, at timestamp 1_300.
+        db.expect_prune().with(eq(650)).times(1).returning(|_| Ok(649));
+
+        let mut chains = MockChains::new();
+        chains
+            .expect_get_safety_head_ref()
+            .with(eq(2), eq(SafetyLevel::Finalized))
+            .returning(|_, _| Ok(finalized(800)));
+
+        let job = job(db, chains, dependency_set(&[1, 2], 250));
+        assert_eq!(job.prune().await.unwrap(), 649);
+    }
+
+    #[tokio::test]
+    async fn retention_longer_than_chain_prunes_nothing() {
+        let mut db = MockDb::new();
+        db.expect_get_safety_head_ref().returning(|_| Ok(finalized(10)));
+        db.expect_prune().with(eq(0)).times(1).returning(|_| Ok(0));
+
+        let job = job(db, MockChains::new(), dependency_set(&[1], 250));
+        assert_eq!(job.prune().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn skips_pruning_without_finalized_block() {
+        let mut db = MockDb::new();
+        db.expect_get_safety_head_ref().returning(|_| Err(StorageError::FutureData));
+        db.expect_prune().never();
+
+        let job = job(db, MockChains::new(), dependency_set(&[1], 250));
+        assert!(matches!(job.prune().await, Err(PruneError::Storage(StorageError::FutureData))));
+    }
+
+    #[tokio::test]
+    async fn skips_pruning_without_finalized_dependency() {
+        let mut db = MockDb::new();
+        db.expect_get_safety_head_ref().returning(|_| Ok(finalized(1_000)));
+        db.expect_prune().never();
+
+        let mut chains = MockChains::new();
+        chains.expect_get_safety_head_ref().returning(|_, _| Err(StorageError::FutureData));
+
+        let job = job(db, chains, dependency_set(&[1, 2], 250));
+        assert!(matches!(job.prune().await, Err(PruneError::Storage(StorageError::FutureData))));
+    }
+
+    #[tokio::test]
+    async fn run_stops_on_cancellation() {
+        let mut db = MockDb::new();
+        db.expect_get_safety_head_ref().returning(|_| Ok(finalized(1_000)));
+        db.expect_prune().returning(|_| Ok(0));
+
+        let job = job(db, MockChains::new(), dependency_set(&[1], 250));
+        let cancel_token = job.cancel_token.clone();
+        let handle = tokio::spawn(job.run());
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        cancel_token.cancel();
+        handle.await.expect("pruner should stop");
+    }
+}
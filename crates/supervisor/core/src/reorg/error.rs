@@ -10,6 +10,10 @@ pub enum ReorgHandlerError {
     #[error("managed node not found for chain: {0}")]
     ManagedNodeMissing(u64),
 
+    /// Indicates no database is known for the chain.
+    #[error("database not found for chain: {0}")]
+    DatabaseMissing(u64),
+
     /// Indicates an error occurred while interacting with the managed node.
     #[error(transparent)]
     ManagedNodeError(#[from] ManagedNodeError),
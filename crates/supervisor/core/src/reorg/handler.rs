@@ -1,5 +1,6 @@
 use super::metrics::Metrics;
 use crate::{ReorgHandlerError, reorg::task::ReorgTask};
+use alloy_eips::BlockNumHash;
 use alloy_primitives::ChainId;
 use alloy_rpc_client::RpcClient;
 use derive_more::Constructor;
@@ -54,6 +55,31 @@ where
         self.verify_and_handle_chain_reorg().await
     }
 
+    /// Rewinds a single chain to the given derived block (inclusive), regardless of the L1 chain.
+    ///
+    /// Used by operators to roll back a misbehaving chain. The managed nodes of the chain must be
+    /// reset afterwards to follow the rewound state.
+    pub async fn rewind_chain(
+        &self,
+        chain_id: ChainId,
+        to: BlockNumHash,
+    ) -> Result<(), ReorgHandlerError> {
+        let chain_db =
+            self.chain_dbs.get(&chain_id).ok_or(ReorgHandlerError::DatabaseMissing(chain_id))?;
+        let reorg_task = ReorgTask::new(chain_id, Arc::clone(chain_db), self.rpc_client.clone());
+
+        observe_metrics_for_result_async!(
+            Metrics::SUPERVISOR_REORG_SUCCESS_TOTAL,
+            Metrics::SUPERVISOR_REORG_ERROR_TOTAL,
+            Metrics::SUPERVISOR_REORG_DURATION_SECONDS,
+            Metrics::SUPERVISOR_REORG_METHOD_REWIND_CHAIN,
+            async {
+                reorg_task.rewind_to_block(to).await
+            },
+            "chain_id" => chain_id.to_string()
+        )
+    }
+
     /// Verifies the consistency of each chain with the L1 chain and handles any reorgs, if any.
     async fn verify_and_handle_chain_reorg(&self) -> Result<(), ReorgHandlerError> {
         let mut handles = Vec::with_capacity(self.chain_dbs.len());
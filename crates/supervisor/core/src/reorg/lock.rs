@@ -0,0 +1,52 @@
+use std::sync::Arc;
+use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// Pauses the background jobs advancing the chains while a chain is rewound by an operator.
+///
+/// Jobs hold a [`Self::work`] guard for each unit of work, e.g. a round of the
+/// [`CrossSafetyScheduler`](crate::CrossSafetyScheduler) or a block handled by the
+/// [`L1Watcher`](crate::l1_watcher::L1Watcher). A rewind holds the [`Self::rewind`] guard, which
+/// waits for the work in progress to complete and holds off new work until it is dropped.
+///
+/// Clones share the same lock.
+#[derive(Debug, Clone, Default)]
+pub struct RewindLock(Arc<RwLock<()>>);
+
+impl RewindLock {
+    /// Creates a new [`RewindLock`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Waits until no rewind is in progress and returns a guard holding off rewinds.
+    pub async fn work(&self) -> RwLockReadGuard<'_, ()> {
+        self.0.read().await
+    }
+
+    /// Waits until no work is in progress and returns a guard holding off new work.
+    pub async fn rewind(&self) -> RwLockWriteGuard<'_, ()> {
+        self.0.write().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_rewind_waits_for_work_and_holds_it_off() {
+        let lock = RewindLock::new();
+
+        let work = lock.work().await;
+        let concurrent_work = lock.work().await;
+        assert!(tokio::time::timeout(Duration::from_millis(10), lock.rewind()).await.is_err());
+        drop(work);
+        drop(concurrent_work);
+
+        let rewind = lock.rewind().await;
+        assert!(tokio::time::timeout(Duration::from_millis(10), lock.work()).await.is_err());
+        drop(rewind);
+        let _work = lock.work().await;
+    }
+}
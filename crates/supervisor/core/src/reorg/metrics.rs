@@ -13,6 +13,7 @@ impl Metrics {
         "kona_supervisor_reorg_duration_seconds";
     pub(crate) const SUPERVISOR_REORG_METHOD_PROCESS_CHAIN_REORG: &'static str =
         "process_chain_reorg";
+    pub(crate) const SUPERVISOR_REORG_METHOD_REWIND_CHAIN: &'static str = "rewind_chain";
     pub(crate) const SUPERVISOR_REORG_L1_DEPTH: &'static str = "kona_supervisor_reorg_l1_depth";
     pub(crate) const SUPERVISOR_REORG_L2_DEPTH: &'static str = "kona_supervisor_reorg_l2_depth";
 
@@ -54,38 +55,43 @@ impl Metrics {
     }
 
     fn zero(chain_id: ChainId) {
+        Self::zero_method(chain_id, Self::SUPERVISOR_REORG_METHOD_PROCESS_CHAIN_REORG);
+        Self::zero_method(chain_id, Self::SUPERVISOR_REORG_METHOD_REWIND_CHAIN);
+    }
+
+    fn zero_method(chain_id: ChainId, method: &'static str) {
         metrics::counter!(
             Self::SUPERVISOR_REORG_SUCCESS_TOTAL,
             "chain_id" => chain_id.to_string(),
-            "method" => Self::SUPERVISOR_REORG_METHOD_PROCESS_CHAIN_REORG,
+            "method" => method,
         )
         .increment(0);
 
         metrics::counter!(
             Self::SUPERVISOR_REORG_ERROR_TOTAL,
             "chain_id" => chain_id.to_string(),
-            "method" => Self::SUPERVISOR_REORG_METHOD_PROCESS_CHAIN_REORG,
+            "method" => method,
         )
         .increment(0);
 
         metrics::histogram!(
             Self::SUPERVISOR_REORG_L1_DEPTH,
             "chain_id" => chain_id.to_string(),
-            "method" => Self::SUPERVISOR_REORG_METHOD_PROCESS_CHAIN_REORG,
+            "method" => method,
         )
         .record(0);
 
         metrics::histogram!(
             Self::SUPERVISOR_REORG_L2_DEPTH,
             "chain_id" => chain_id.to_string(),
-            "method" => Self::SUPERVISOR_REORG_METHOD_PROCESS_CHAIN_REORG,
+            "method" => method,
         )
         .record(0);
 
         metrics::histogram!(
             Self::SUPERVISOR_REORG_DURATION_SECONDS,
             "chain_id" => chain_id.to_string(),
-            "method" => Self::SUPERVISOR_REORG_METHOD_PROCESS_CHAIN_REORG,
+            "method" => method,
         )
         .record(0.0);
     }
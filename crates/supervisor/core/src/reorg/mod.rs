@@ -6,4 +6,7 @@ pub use handler::ReorgHandler;
 mod error;
 pub use error::ReorgHandlerError;
 
+mod lock;
+pub use lock::RewindLock;
+
 mod metrics;
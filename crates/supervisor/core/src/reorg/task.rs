@@ -1,6 +1,6 @@
 use super::metrics::Metrics;
 use crate::ReorgHandlerError;
-use alloy_eips::BlockNumberOrTag;
+use alloy_eips::{BlockNumHash, BlockNumberOrTag};
 use alloy_primitives::{B256, ChainId};
 use alloy_rpc_client::RpcClient;
 use alloy_rpc_types_eth::Block;
@@ -74,6 +74,35 @@ where
         Ok(())
     }
 
+    /// Rewinds the chain to the given derived block (inclusive), whether or not its source is
+    /// still canonical.
+    pub(crate) async fn rewind_to_block(&self, to: BlockNumHash) -> Result<(), ReorgHandlerError> {
+        info!(
+            target: "supervisor::reorg_handler",
+            chain_id = %self.chain_id,
+            target_block = to.number,
+            "Forced rewind - rewinding to derived block..."
+        );
+
+        let latest_state = self.db.latest_derivation_state()?;
+        let source = self.db.derived_to_source(to)?;
+        self.db.rewind(&to).inspect_err(|err| {
+            warn!(
+                target: "supervisor::reorg_handler::db",
+                chain_id = %self.chain_id,
+                %err,
+                "Failed to rewind DB to derived block"
+            );
+        })?;
+
+        Metrics::record_block_depth(
+            self.chain_id,
+            latest_state.source.number.saturating_sub(source.number),
+            latest_state.derived.number.saturating_sub(to.number),
+        );
+        Ok(())
+    }
+
     async fn rewind_to_target_source(
         &self,
         rewind_target_source: BlockInfo,
@@ -1250,4 +1279,55 @@ mod tests {
             ReorgHandlerError::StorageError(StorageError::LockPoisoned)
         ));
     }
+
+    #[tokio::test]
+    async fn test_rewind_to_block_success() {
+        let mut mock_db = MockDb::new();
+
+        let latest_state = DerivedRefPair {
+            source: BlockInfo::new(B256::from([1u8; 32]), 110, B256::ZERO, 12345),
+            derived: BlockInfo::new(B256::from([2u8; 32]), 60, B256::ZERO, 12346),
+        };
+        let target = BlockInfo::new(B256::from([3u8; 32]), 50, B256::ZERO, 12300);
+        let target_source = BlockInfo::new(B256::from([4u8; 32]), 100, B256::ZERO, 12200);
+
+        mock_db.expect_latest_derivation_state().times(1).returning(move || Ok(latest_state));
+        mock_db
+            .expect_derived_to_source()
+            .times(1)
+            .with(predicate::eq(target.id()))
+            .returning(move |_| Ok(target_source));
+        mock_db.expect_rewind().times(1).with(predicate::eq(target.id())).returning(|_| Ok(()));
+
+        let reorg_task = ReorgTask::new(
+            1,
+            Arc::new(mock_db),
+            RpcClient::new(MockTransport::new(Asserter::new()), false),
+        );
+
+        assert!(reorg_task.rewind_to_block(target.id()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rewind_to_block_unknown_block() {
+        let mut mock_db = MockDb::new();
+
+        mock_db.expect_latest_derivation_state().times(1).returning(|| {
+            Ok(DerivedRefPair { source: BlockInfo::default(), derived: BlockInfo::default() })
+        });
+        mock_db.expect_derived_to_source().times(1).returning(|_| Err(StorageError::ConflictError));
+        mock_db.expect_rewind().never();
+
+        let reorg_task = ReorgTask::new(
+            1,
+            Arc::new(mock_db),
+            RpcClient::new(MockTransport::new(Asserter::new()), false),
+        );
+
+        let result = reorg_task.rewind_to_block(BlockNumHash::default()).await;
+        assert!(matches!(
+            result.unwrap_err(),
+            ReorgHandlerError::StorageError(StorageError::ConflictError)
+        ));
+    }
 }
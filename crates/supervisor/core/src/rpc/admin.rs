@@ -1,4 +1,7 @@
+use super::metrics::Metrics;
 use crate::syncnode::ClientConfig;
+use alloy_eips::BlockNumHash;
+use alloy_primitives::ChainId;
 use alloy_rpc_types_engine::JwtSecret;
 use async_trait::async_trait;
 use derive_more::Constructor;
@@ -6,14 +9,15 @@ use jsonrpsee::{
     core::RpcResult,
     types::{ErrorCode, ErrorObject, ErrorObjectOwned},
 };
-use kona_supervisor_rpc::SupervisorAdminApiServer;
-use std::time::Duration;
+use kona_supervisor_rpc::{ChainEventInfo, ManagedNodeInfo, SupervisorAdminApiServer};
+use kona_supervisor_types::HexStringU64;
+use std::{fmt, time::Duration};
 use thiserror::Error;
 use tokio::{
     sync::{mpsc::Sender, oneshot},
     time::timeout,
 };
-use tracing::warn;
+use tracing::{info, warn};
 
 /// Error types for Supervisor Admin RPC operations.
 #[derive(Debug, Error)]
@@ -22,6 +26,10 @@ pub enum AdminError {
     #[error("invalid jwt secret: {0}")]
     InvalidJwtSecret(String),
 
+    /// Indicates that the chain is not managed by the supervisor.
+    #[error("chain not found: {0}")]
+    ChainNotFound(ChainId),
+
     /// Indicates that the request to the admin channel failed to send.
     #[error("failed to send admin request")]
     SendFailed,
@@ -43,7 +51,9 @@ impl From<AdminError> for ErrorObjectOwned {
     fn from(err: AdminError) -> Self {
         match err {
             // todo: handle these errors more gracefully
-            AdminError::InvalidJwtSecret(_) => ErrorObjectOwned::from(ErrorCode::InvalidParams),
+            AdminError::InvalidJwtSecret(_) | AdminError::ChainNotFound(_) => {
+                ErrorObjectOwned::from(ErrorCode::InvalidParams)
+            }
             AdminError::SendFailed |
             AdminError::SenderDropped |
             AdminError::Timeout |
@@ -65,6 +75,83 @@ pub enum AdminRequest {
         /// The response channel to send the result back.
         resp: oneshot::Sender<Result<(), AdminError>>,
     },
+    /// Removes an L2 RPC from the Supervisor.
+    RemoveL2Rpc {
+        /// The URL of the L2 RPC to remove.
+        url: String,
+        /// The response channel to send the result back.
+        resp: oneshot::Sender<Result<(), AdminError>>,
+    },
+    /// Lists the L2 RPCs attached to the Supervisor.
+    ListL2Rpcs {
+        /// The response channel to send the result back.
+        resp: oneshot::Sender<Result<Vec<ManagedNodeInfo>, AdminError>>,
+    },
+    /// Reloads the dependency set from its file.
+    ReloadDependencySet {
+        /// The response channel to send the result back.
+        resp: oneshot::Sender<Result<(), AdminError>>,
+    },
+    /// Pauses the chain processor of a chain.
+    PauseChain {
+        /// The chain to pause.
+        chain_id: ChainId,
+        /// The response channel to send the result back.
+        resp: oneshot::Sender<Result<(), AdminError>>,
+    },
+    /// Resumes the chain processor of a chain.
+    ResumeChain {
+        /// The chain to resume.
+        chain_id: ChainId,
+        /// The response channel to send the result back.
+        resp: oneshot::Sender<Result<(), AdminError>>,
+    },
+    /// Rewinds the storage of a chain to a derived block (inclusive).
+    RewindChain {
+        /// The chain to rewind.
+        chain_id: ChainId,
+        /// The derived block to rewind to.
+        block_id: BlockNumHash,
+        /// The response channel to send the result back.
+        resp: oneshot::Sender<Result<(), AdminError>>,
+    },
+    /// Resets the managed nodes of a chain.
+    ResetL2Rpcs {
+        /// The chain whose managed nodes are reset.
+        chain_id: ChainId,
+        /// The response channel to send the result back.
+        resp: oneshot::Sender<Result<(), AdminError>>,
+    },
+    /// Lists the events queued for the chain processor of a chain.
+    PendingChainEvents {
+        /// The chain to list the events of.
+        chain_id: ChainId,
+        /// The response channel to send the result back.
+        resp: oneshot::Sender<Result<Vec<ChainEventInfo>, AdminError>>,
+    },
+}
+
+/// Describes the request for the audit log, leaving out secrets.
+impl fmt::Display for AdminRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddL2Rpc { cfg, .. } => write!(f, "add_l2_rpc url={}", cfg.url),
+            Self::RemoveL2Rpc { url, .. } => write!(f, "remove_l2_rpc url={url}"),
+            Self::ListL2Rpcs { .. } => write!(f, "list_l2_rpcs"),
+            Self::ReloadDependencySet { .. } => write!(f, "reload_dependency_set"),
+            Self::PauseChain { chain_id, .. } => write!(f, "pause_chain chain_id={chain_id}"),
+            Self::ResumeChain { chain_id, .. } => write!(f, "resume_chain chain_id={chain_id}"),
+            Self::RewindChain { chain_id, block_id, .. } => write!(
+                f,
+                "rewind_chain chain_id={chain_id} block={}:{}",
+                block_id.number, block_id.hash
+            ),
+            Self::ResetL2Rpcs { chain_id, .. } => write!(f, "reset_l2_rpcs chain_id={chain_id}"),
+            Self::PendingChainEvents { chain_id, .. } => {
+                write!(f, "pending_chain_events chain_id={chain_id}")
+            }
+        }
+    }
 }
 
 /// Supervisor Admin RPC interface
@@ -84,31 +171,107 @@ impl SupervisorAdminApiServer for AdminRpc {
             ErrorObject::from(AdminError::InvalidJwtSecret(err.to_string()))
         })?;
 
-        let request = AdminRequest::AddL2Rpc {
-            cfg: ClientConfig { url: url.clone(), jwt_secret },
-            resp: resp_tx,
-        };
+        let request =
+            AdminRequest::AddL2Rpc { cfg: ClientConfig { url, jwt_secret }, resp: resp_tx };
 
+        self.request(Metrics::SUPERVISOR_RPC_METHOD_ADD_L2_RPC, request, resp_rx).await
+    }
+
+    async fn remove_l2_rpc(&self, url: String) -> RpcResult<()> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let request = AdminRequest::RemoveL2Rpc { url, resp: resp_tx };
+        self.request(Metrics::SUPERVISOR_RPC_METHOD_REMOVE_L2_RPC, request, resp_rx).await
+    }
+
+    async fn list_l2_rpcs(&self) -> RpcResult<Vec<ManagedNodeInfo>> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let request = AdminRequest::ListL2Rpcs { resp: resp_tx };
+        self.request(Metrics::SUPERVISOR_RPC_METHOD_LIST_L2_RPCS, request, resp_rx).await
+    }
+
+    async fn reload_dependency_set(&self) -> RpcResult<()> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let request = AdminRequest::ReloadDependencySet { resp: resp_tx };
+        self.request(Metrics::SUPERVISOR_RPC_METHOD_RELOAD_DEPENDENCY_SET, request, resp_rx).await
+    }
+
+    async fn pause_chain(&self, chain_id: HexStringU64) -> RpcResult<()> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let request = AdminRequest::PauseChain { chain_id: chain_id.into(), resp: resp_tx };
+        self.request(Metrics::SUPERVISOR_RPC_METHOD_PAUSE_CHAIN, request, resp_rx).await
+    }
+
+    async fn resume_chain(&self, chain_id: HexStringU64) -> RpcResult<()> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let request = AdminRequest::ResumeChain { chain_id: chain_id.into(), resp: resp_tx };
+        self.request(Metrics::SUPERVISOR_RPC_METHOD_RESUME_CHAIN, request, resp_rx).await
+    }
+
+    async fn rewind_chain(&self, chain_id: HexStringU64, block_id: BlockNumHash) -> RpcResult<()> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let request =
+            AdminRequest::RewindChain { chain_id: chain_id.into(), block_id, resp: resp_tx };
+        self.request(Metrics::SUPERVISOR_RPC_METHOD_REWIND_CHAIN, request, resp_rx).await
+    }
+
+    async fn reset_l2_rpcs(&self, chain_id: HexStringU64) -> RpcResult<()> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let request = AdminRequest::ResetL2Rpcs { chain_id: chain_id.into(), resp: resp_tx };
+        self.request(Metrics::SUPERVISOR_RPC_METHOD_RESET_L2_RPCS, request, resp_rx).await
+    }
+
+    async fn pending_chain_events(&self, chain_id: HexStringU64) -> RpcResult<Vec<ChainEventInfo>> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let request = AdminRequest::PendingChainEvents { chain_id: chain_id.into(), resp: resp_tx };
+        self.request(Metrics::SUPERVISOR_RPC_METHOD_PENDING_CHAIN_EVENTS, request, resp_rx).await
+    }
+}
+
+impl AdminRpc {
+    /// Sends the request to the service and waits for its response.
+    ///
+    /// Every request is recorded in the audit log, together with its outcome.
+    async fn request<T>(
+        &self,
+        method: &'static str,
+        request: AdminRequest,
+        resp_rx: oneshot::Receiver<Result<T, AdminError>>,
+    ) -> RpcResult<T> {
+        info!(target: "supervisor::admin_audit", %request, "Admin request received");
+
+        let result = crate::observe_rpc_call!(method, self.send(request, resp_rx).await);
+        match &result {
+            Ok(_) => info!(target: "supervisor::admin_audit", method, "Admin request succeeded"),
+            Err(err) => {
+                warn!(target: "supervisor::admin_audit", method, %err, "Admin request failed")
+            }
+        }
+        result
+    }
+
+    async fn send<T>(
+        &self,
+        request: AdminRequest,
+        resp_rx: oneshot::Receiver<Result<T, AdminError>>,
+    ) -> RpcResult<T> {
         self.admin_tx.send(request).await.map_err(|err| {
-            warn!(target: "supervisor::admin_rpc", %url, %err, "Failed to send AdminRequest");
+            warn!(target: "supervisor::admin_rpc", %err, "Failed to send AdminRequest");
             ErrorObject::from(AdminError::SendFailed)
         })?;
 
         // wait for response with a timeout
-        timeout(Duration::from_secs(ADMIN_REQUEST_TIMEOUT_SECS), resp_rx)
-            .await
-            .map_or_else(
-                |_| {
-                    warn!(target: "supervisor::admin_rpc", %url, "AdminRequest timed out");
-                    Err(ErrorObject::from(AdminError::Timeout))
-                },
-                |res| res
-                    .unwrap_or(Err(AdminError::SenderDropped))
-                    .map_err(|err| {
-                        warn!(target: "supervisor::admin_rpc", %url, %err, "Failed to process AdminRequest");
-                        ErrorObject::from(err)
-                    }),
-            )
+        timeout(Duration::from_secs(ADMIN_REQUEST_TIMEOUT_SECS), resp_rx).await.map_or_else(
+            |_| {
+                warn!(target: "supervisor::admin_rpc", "AdminRequest timed out");
+                Err(ErrorObject::from(AdminError::Timeout))
+            },
+            |res| {
+                res.unwrap_or(Err(AdminError::SenderDropped)).map_err(|err| {
+                    warn!(target: "supervisor::admin_rpc", %err, "Failed to process AdminRequest");
+                    ErrorObject::from(err)
+                })
+            },
+        )
     }
 }
 
@@ -213,4 +376,123 @@ mod tests {
         // let handler finish cleanly
         handler.await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_remove_l2_rpc_success() {
+        let (tx, mut rx) = mpsc::channel::<AdminRequest>(1);
+        let admin = AdminRpc::new(tx);
+
+        let handler = tokio::spawn(async move {
+            if let Some(AdminRequest::RemoveL2Rpc { url, resp }) = rx.recv().await {
+                assert_eq!(url, "http://node:8545");
+                let _ = resp.send(Ok(()));
+            } else {
+                panic!("expected RemoveL2Rpc request");
+            }
+        });
+
+        let res = admin.remove_l2_rpc("http://node:8545".to_string()).await;
+        assert!(res.is_ok(), "expected successful response");
+
+        handler.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_list_l2_rpcs_success() {
+        let (tx, mut rx) = mpsc::channel::<AdminRequest>(1);
+        let admin = AdminRpc::new(tx);
+
+        let node = ManagedNodeInfo {
+            chain_id: 10,
+            url: "http://node:8545".to_string(),
+            primary: true,
+            subscribed: true,
+            unsafe_head: 100,
+            local_safe: 90,
+        };
+        let expected = vec![node.clone()];
+        let handler = tokio::spawn(async move {
+            if let Some(AdminRequest::ListL2Rpcs { resp }) = rx.recv().await {
+                let _ = resp.send(Ok(vec![node]));
+            } else {
+                panic!("expected ListL2Rpcs request");
+            }
+        });
+
+        let res = admin.list_l2_rpcs().await.expect("expected successful response");
+        assert_eq!(res, expected);
+
+        handler.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reload_dependency_set_service_error() {
+        let (tx, mut rx) = mpsc::channel::<AdminRequest>(1);
+        let admin = AdminRpc::new(tx);
+
+        let handler = tokio::spawn(async move {
+            if let Some(AdminRequest::ReloadDependencySet { resp }) = rx.recv().await {
+                let _ = resp.send(Err(AdminError::ServiceError("rejected".to_string())));
+            } else {
+                panic!("expected ReloadDependencySet request");
+            }
+        });
+
+        let res = admin.reload_dependency_set().await;
+        assert!(res.is_err(), "expected rejected reload to be reported");
+
+        handler.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rewind_chain_success() {
+        let (tx, mut rx) = mpsc::channel::<AdminRequest>(1);
+        let admin = AdminRpc::new(tx);
+        let target = BlockNumHash { number: 42, hash: Default::default() };
+
+        let handler = tokio::spawn(async move {
+            if let Some(AdminRequest::RewindChain { chain_id, block_id, resp }) = rx.recv().await {
+                assert_eq!(chain_id, 10);
+                assert_eq!(block_id, target);
+                let _ = resp.send(Ok(()));
+            } else {
+                panic!("expected RewindChain request");
+            }
+        });
+
+        let res = admin.rewind_chain(HexStringU64(10), target).await;
+        assert!(res.is_ok(), "expected successful response");
+
+        handler.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_pause_chain_unknown_chain() {
+        let (tx, mut rx) = mpsc::channel::<AdminRequest>(1);
+        let admin = AdminRpc::new(tx);
+
+        let handler = tokio::spawn(async move {
+            if let Some(AdminRequest::PauseChain { chain_id, resp }) = rx.recv().await {
+                let _ = resp.send(Err(AdminError::ChainNotFound(chain_id)));
+            } else {
+                panic!("expected PauseChain request");
+            }
+        });
+
+        let err = admin.pause_chain(HexStringU64(10)).await.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidParams.code());
+
+        handler.await.unwrap();
+    }
+
+    #[test]
+    fn test_audit_description_omits_secret() {
+        let (resp, _) = oneshot::channel();
+        let cfg = ClientConfig {
+            url: "http://node:8545".to_string(),
+            jwt_secret: JwtSecret::from_hex(VALID_SECRET).unwrap(),
+        };
+        let description = AdminRequest::AddL2Rpc { cfg, resp }.to_string();
+        assert_eq!(description, "add_l2_rpc url=http://node:8545");
+    }
 }
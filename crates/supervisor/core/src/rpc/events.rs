@@ -0,0 +1,165 @@
+//! Server-side implementation of the Supervisor events subscription API.
+
+use crate::{
+    SupervisorService,
+    event::{EventSink, EventSinkError},
+};
+use async_trait::async_trait;
+use jsonrpsee::{
+    PendingSubscriptionSink,
+    core::{SubscriptionResult, to_json_raw_value},
+    types::{ErrorCode, ErrorObjectOwned},
+};
+use kona_interop::SafetyLevel;
+use kona_supervisor_rpc::{SafetyEventKind, SupervisorEventsApiServer};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+impl From<EventSinkError> for ErrorObjectOwned {
+    fn from(err: EventSinkError) -> Self {
+        let code = match err {
+            EventSinkError::CursorExpired { .. } => ErrorCode::InvalidParams,
+            EventSinkError::Io(_) | EventSinkError::Serde(_) | EventSinkError::Closed => {
+                ErrorCode::InternalError
+            }
+        };
+        Self::owned(code.code(), err.to_string(), None::<()>)
+    }
+}
+
+/// The server-side implementation struct for the [`SupervisorEventsApiServer`], streaming the
+/// events of an [`EventSink`] and the super roots they advance.
+#[derive(Debug)]
+pub struct EventsRpc<T> {
+    sink: Arc<EventSink>,
+    supervisor: Arc<T>,
+}
+
+impl<T> EventsRpc<T> {
+    /// Creates a new [`EventsRpc`] instance.
+    pub const fn new(sink: Arc<EventSink>, supervisor: Arc<T>) -> Self {
+        Self { sink, supervisor }
+    }
+}
+
+impl<T> EventsRpc<T>
+where
+    T: SupervisorService,
+{
+    /// Returns the latest timestamp that is cross-safe on all chains, if any.
+    fn cross_safe_timestamp(&self) -> Option<u64> {
+        self.supervisor
+            .chain_ids()
+            .map(|chain| self.supervisor.cross_safe(chain).ok().map(|block| block.timestamp))
+            .try_fold(u64::MAX, |min, timestamp| Some(min.min(timestamp?)))
+            .filter(|timestamp| *timestamp != u64::MAX)
+    }
+}
+
+#[async_trait]
+impl<T> SupervisorEventsApiServer for EventsRpc<T>
+where
+    T: SupervisorService + 'static,
+{
+    async fn subscribe_safety_events(
+        &self,
+        pending: PendingSubscriptionSink,
+        from_sequence: Option<u64>,
+    ) -> SubscriptionResult {
+        let mut subscription = match self.sink.subscribe(from_sequence) {
+            Ok(subscription) => subscription,
+            Err(err) => {
+                pending.reject(ErrorObjectOwned::from(err)).await;
+                return Ok(());
+            }
+        };
+        let sink = pending.accept().await?;
+        debug!(
+            target: "supervisor::rpc",
+            next_sequence = subscription.next_sequence(),
+            "Started safety events subscription"
+        );
+
+        loop {
+            let event = tokio::select! {
+                _ = sink.closed() => break,
+                event = subscription.next() => event,
+            };
+            let event = match event {
+                Ok(event) => event,
+                Err(err) => {
+                    warn!(target: "supervisor::rpc", %err, "Safety events subscription failed");
+                    return Err(err.to_string().into());
+                }
+            };
+
+            if sink.send(to_json_raw_value(&event)?).await.is_err() {
+                break;
+            }
+        }
+
+        debug!(target: "supervisor::rpc", "Safety events subscription closed");
+        Ok(())
+    }
+
+    async fn subscribe_super_roots(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        // Cross-safe promotions are observed through the safety events.
+        let mut subscription = match self.sink.subscribe(None) {
+            Ok(subscription) => subscription,
+            Err(err) => {
+                pending.reject(ErrorObjectOwned::from(err)).await;
+                return Ok(());
+            }
+        };
+        let sink = pending.accept().await?;
+        debug!(target: "supervisor::rpc", "Started super roots subscription");
+
+        let mut last_timestamp = None;
+        'outer: loop {
+            if let Some(timestamp) = self
+                .cross_safe_timestamp()
+                .filter(|timestamp| last_timestamp.is_none_or(|last| *timestamp > last))
+            {
+                match self
+                    .supervisor
+                    .super_roots_in_range(timestamp, timestamp, 1, SafetyLevel::CrossSafe)
+                    .await
+                {
+                    Ok(super_roots) => {
+                        for super_root in super_roots {
+                            if sink.send(to_json_raw_value(&super_root)?).await.is_err() {
+                                break 'outer;
+                            }
+                        }
+                        last_timestamp = Some(timestamp);
+                    }
+                    // Retried on the next cross-safe promotion.
+                    Err(err) => {
+                        warn!(target: "supervisor::rpc", %err, timestamp, "Failed to compute cross-safe super root");
+                    }
+                }
+            }
+
+            // Wait for the next cross-safe promotion.
+            loop {
+                let event = tokio::select! {
+                    _ = sink.closed() => break 'outer,
+                    event = subscription.next() => event,
+                };
+                match event {
+                    Ok(event) if matches!(event.kind, SafetyEventKind::CrossSafeUpdate { .. }) => {
+                        break;
+                    }
+                    Ok(_) => {}
+                    Err(err) => {
+                        warn!(target: "supervisor::rpc", %err, "Super roots subscription failed");
+                        return Err(err.to_string().into());
+                    }
+                }
+            }
+        }
+
+        debug!(target: "supervisor::rpc", "Super roots subscription closed");
+        Ok(())
+    }
+}
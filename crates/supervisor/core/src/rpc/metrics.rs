@@ -26,10 +26,27 @@ impl Metrics {
     pub(crate) const SUPERVISOR_RPC_METHOD_FINALIZED_L1: &'static str = "finalized_l1";
     pub(crate) const SUPERVISOR_RPC_METHOD_SUPER_ROOT_AT_TIMESTAMP: &'static str =
         "super_root_at_timestamp";
+    pub(crate) const SUPERVISOR_RPC_METHOD_SUPER_ROOTS_IN_RANGE: &'static str =
+        "super_roots_in_range";
     pub(crate) const SUPERVISOR_RPC_METHOD_SYNC_STATUS: &'static str = "sync_status";
     pub(crate) const SUPERVISOR_RPC_METHOD_ALL_SAFE_DERIVED_AT: &'static str =
         "all_safe_derived_at";
     pub(crate) const SUPERVISOR_RPC_METHOD_CHECK_ACCESS_LIST: &'static str = "check_access_list";
+    pub(crate) const SUPERVISOR_RPC_METHOD_MESSAGE_LIFECYCLE: &'static str = "message_lifecycle";
+    pub(crate) const SUPERVISOR_RPC_METHOD_EXECUTING_MESSAGES: &'static str = "executing_messages";
+
+    // --- Admin Methods ---
+    pub(crate) const SUPERVISOR_RPC_METHOD_ADD_L2_RPC: &'static str = "admin_add_l2_rpc";
+    pub(crate) const SUPERVISOR_RPC_METHOD_REMOVE_L2_RPC: &'static str = "admin_remove_l2_rpc";
+    pub(crate) const SUPERVISOR_RPC_METHOD_LIST_L2_RPCS: &'static str = "admin_list_l2_rpcs";
+    pub(crate) const SUPERVISOR_RPC_METHOD_RELOAD_DEPENDENCY_SET: &'static str =
+        "admin_reload_dependency_set";
+    pub(crate) const SUPERVISOR_RPC_METHOD_PAUSE_CHAIN: &'static str = "admin_pause_chain";
+    pub(crate) const SUPERVISOR_RPC_METHOD_RESUME_CHAIN: &'static str = "admin_resume_chain";
+    pub(crate) const SUPERVISOR_RPC_METHOD_REWIND_CHAIN: &'static str = "admin_rewind_chain";
+    pub(crate) const SUPERVISOR_RPC_METHOD_RESET_L2_RPCS: &'static str = "admin_reset_l2_rpcs";
+    pub(crate) const SUPERVISOR_RPC_METHOD_PENDING_CHAIN_EVENTS: &'static str =
+        "admin_pending_chain_events";
 
     /// Initializes metrics for the Supervisor RPC service.
     ///
@@ -89,9 +106,21 @@ impl Metrics {
         Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_FINALIZED);
         Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_FINALIZED_L1);
         Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_SUPER_ROOT_AT_TIMESTAMP);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_SUPER_ROOTS_IN_RANGE);
         Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_SYNC_STATUS);
         Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_ALL_SAFE_DERIVED_AT);
         Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_CHECK_ACCESS_LIST);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_MESSAGE_LIFECYCLE);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_EXECUTING_MESSAGES);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_ADD_L2_RPC);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_REMOVE_L2_RPC);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_LIST_L2_RPCS);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_RELOAD_DEPENDENCY_SET);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_PAUSE_CHAIN);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_RESUME_CHAIN);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_REWIND_CHAIN);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_RESET_L2_RPCS);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_PENDING_CHAIN_EVENTS);
     }
 }
 
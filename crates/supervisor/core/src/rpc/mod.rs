@@ -3,6 +3,9 @@
 mod server;
 pub use server::SupervisorRpc;
 
+mod events;
+pub use events::EventsRpc;
+
 mod admin;
 pub use admin::{AdminError, AdminRequest, AdminRpc};
 
@@ -9,9 +9,10 @@ use jsonrpsee::{core::RpcResult, types::ErrorObject};
 use kona_interop::{DependencySet, DerivedIdPair, ExecutingDescriptor, SafetyLevel};
 use kona_protocol::BlockInfo;
 use kona_supervisor_rpc::{
-    SuperRootOutputRpc, SupervisorApiServer, SupervisorChainSyncStatus, SupervisorSyncStatus,
+    ExecutingMessageStatus, MessageLifecycle, SuperRootOutputRpc, SuperRootWithOutputsRpc,
+    SupervisorApiServer, SupervisorChainSyncStatus, SupervisorSyncStatus,
 };
-use kona_supervisor_types::{HexStringU64, SuperHead};
+use kona_supervisor_types::{HexStringU64, MessageIdentifier, SuperHead};
 use std::sync::Arc;
 use tracing::{trace, warn};
 
@@ -115,7 +116,7 @@ where
                     "Received the dependency set"
                 );
 
-                Ok(self.supervisor.dependency_set().to_owned())
+                Ok(self.supervisor.dependency_set().as_ref().clone())
             }
             .await
         )
@@ -190,6 +191,37 @@ where
         )
     }
 
+    async fn super_roots_in_range(
+        &self,
+        from_hex: HexStringU64,
+        to_hex: HexStringU64,
+        safety_level: SafetyLevel,
+        step_hex: Option<HexStringU64>,
+    ) -> RpcResult<Vec<SuperRootWithOutputsRpc>> {
+        crate::observe_rpc_call!(
+            Metrics::SUPERVISOR_RPC_METHOD_SUPER_ROOTS_IN_RANGE,
+            async {
+                let from = u64::from(from_hex);
+                let to = u64::from(to_hex);
+                let step = step_hex.map_or(1, u64::from);
+                trace!(target: "supervisor::rpc",
+                    %from,
+                    %to,
+                    %step,
+                    %safety_level,
+                    "Received super_roots_in_range request"
+                );
+
+                self.supervisor.super_roots_in_range(from, to, step, safety_level)
+                    .await
+                    .map_err(|err| {
+                        warn!(target: "supervisor::rpc", %err, "Error from core supervisor super_roots_in_range");
+                        ErrorObject::from(err)
+                    })
+            }.await
+        )
+    }
+
     async fn check_access_list(
         &self,
         inbox_entries: Vec<B256>,
@@ -322,6 +354,48 @@ where
             .await
         )
     }
+
+    async fn message_lifecycle(
+        &self,
+        identifier: MessageIdentifier,
+    ) -> RpcResult<MessageLifecycle> {
+        crate::observe_rpc_call!(
+            Metrics::SUPERVISOR_RPC_METHOD_MESSAGE_LIFECYCLE,
+            async {
+                trace!(target: "supervisor::rpc",
+                    ?identifier,
+                    "Received message_lifecycle request"
+                );
+
+                self.supervisor.message_lifecycle(identifier).map_err(|err| {
+                    warn!(target: "supervisor::rpc", ?identifier, %err, "Error from core supervisor message_lifecycle");
+                    ErrorObject::from(err)
+                })
+            }
+            .await
+        )
+    }
+
+    async fn executing_messages(
+        &self,
+        identifier: MessageIdentifier,
+    ) -> RpcResult<Vec<ExecutingMessageStatus>> {
+        crate::observe_rpc_call!(
+            Metrics::SUPERVISOR_RPC_METHOD_EXECUTING_MESSAGES,
+            async {
+                trace!(target: "supervisor::rpc",
+                    ?identifier,
+                    "Received executing_messages request"
+                );
+
+                self.supervisor.executing_messages(identifier).map_err(|err| {
+                    warn!(target: "supervisor::rpc", ?identifier, %err, "Error from core supervisor executing_messages");
+                    ErrorObject::from(err)
+                })
+            }
+            .await
+        )
+    }
 }
 
 impl<T> Clone for SupervisorRpc<T> {
@@ -346,7 +420,7 @@ mod tests {
         #[async_trait]
         impl SupervisorService for SupervisorService {
             fn chain_ids(&self) -> impl Iterator<Item = ChainId>;
-            fn dependency_set(&self) -> &DependencySet;
+            fn dependency_set(&self) -> Arc<DependencySet>;
             fn super_head(&self, chain: ChainId) -> Result<SuperHead, SupervisorError>;
             fn latest_block_from(&self, l1_block: BlockNumHash, chain: ChainId) -> Result<BlockInfo, SupervisorError>;
             fn derived_to_source_block(&self, chain: ChainId, derived: BlockNumHash) -> Result<BlockInfo, SupervisorError>;
@@ -357,6 +431,9 @@ mod tests {
             fn finalized_l1(&self) -> Result<BlockInfo, SupervisorError>;
             fn check_access_list(&self, inbox_entries: Vec<B256>, min_safety: SafetyLevel, executing_descriptor: ExecutingDescriptor) -> Result<(), SupervisorError>;
             async fn super_root_at_timestamp(&self, timestamp: u64) -> Result<SuperRootOutputRpc, SupervisorError>;
+            async fn super_roots_in_range(&self, from: u64, to: u64, step: u64, safety_level: SafetyLevel) -> Result<Vec<SuperRootWithOutputsRpc>, SupervisorError>;
+            fn executing_messages(&self, identifier: MessageIdentifier) -> Result<Vec<ExecutingMessageStatus>, SupervisorError>;
+            fn message_lifecycle(&self, identifier: MessageIdentifier) -> Result<MessageLifecycle, SupervisorError>;
         }
     );
 
@@ -517,4 +594,75 @@ mod tests {
         assert_eq!(status.finalized_timestamp, 50);
         assert_eq!(status.chains.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_message_lifecycle() {
+        let identifier = MessageIdentifier { chain_id: 1, block_number: 10, log_index: 2 };
+        let initiating = kona_supervisor_rpc::InitiatingMessageStatus {
+            block: BlockNumHash::new(10, B256::ZERO),
+            log_hash: B256::ZERO,
+            safety: SafetyLevel::CrossSafe,
+        };
+        let executions = vec![ExecutingMessageStatus {
+            chain_id: 2,
+            block: BlockNumHash::new(20, B256::ZERO),
+            log_index: 0,
+            stage: kona_supervisor_rpc::MessageStage::CrossUnsafe,
+        }];
+
+        let mut mock_service = MockSupervisorService::new();
+        let lifecycle = MessageLifecycle::new(identifier, initiating, executions.clone());
+        let expected = lifecycle.clone();
+        mock_service
+            .expect_message_lifecycle()
+            .withf(move |id| *id == identifier)
+            .returning(move |_| Ok(lifecycle.clone()));
+        mock_service.expect_executing_messages().returning(move |_| Ok(executions.clone()));
+
+        let rpc = SupervisorRpc::new(Arc::new(mock_service));
+        assert_eq!(rpc.message_lifecycle(identifier).await.unwrap(), expected);
+        assert_eq!(rpc.executing_messages(identifier).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_message_lifecycle_unknown_message() {
+        let identifier = MessageIdentifier { chain_id: 1, block_number: 10, log_index: 2 };
+
+        let mut mock_service = MockSupervisorService::new();
+        mock_service.expect_message_lifecycle().returning(|_| {
+            Err(SupervisorError::StorageError(StorageError::EntryNotFound(
+                EntryNotFoundError::LogNotFound { block_number: 10, log_index: 2 },
+            )))
+        });
+
+        let rpc = SupervisorRpc::new(Arc::new(mock_service));
+        assert!(rpc.message_lifecycle(identifier).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_super_roots_in_range_defaults_step() {
+        let mut mock_service = MockSupervisorService::new();
+        mock_service
+            .expect_super_roots_in_range()
+            .withf(|from, to, step, level| {
+                *from == 10 && *to == 20 && *step == 1 && *level == SafetyLevel::Finalized
+            })
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_service.expect_super_roots_in_range().returning(|from, to, step, _| {
+            Err(SupervisorError::InvalidTimestampRange { from, to, step })
+        });
+
+        let rpc = SupervisorRpc::new(Arc::new(mock_service));
+        let roots = rpc
+            .super_roots_in_range(10.into(), 20.into(), SafetyLevel::Finalized, None)
+            .await
+            .unwrap();
+        assert!(roots.is_empty());
+
+        let err = rpc
+            .super_roots_in_range(20.into(), 10.into(), SafetyLevel::Finalized, Some(2.into()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), jsonrpsee::types::ErrorCode::InvalidParams.code());
+    }
 }
@@ -0,0 +1,110 @@
+use alloy_primitives::ChainId;
+use kona_supervisor_types::ExecutingMessage;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{PoisonError, RwLock},
+};
+
+/// Cache of executing messages whose dependency has already been resolved.
+///
+/// An entry records that the initiating message has reached the required safety level, exists in
+/// storage with a matching hash and cannot be part of a same-timestamp cycle. Re-validating a
+/// candidate block after one of its later messages was not yet safe can then skip the storage
+/// reads for the messages that were already resolved.
+///
+/// Entries are grouped by executing chain, so they can be dropped once that chain's candidate
+/// block is promoted or invalidated. Entries pointing at an initiating chain must be invalidated
+/// when that chain's head is rewound.
+#[derive(Debug, Default)]
+pub struct DependencyCache {
+    resolved: RwLock<HashMap<ChainId, HashSet<ExecutingMessage>>>,
+}
+
+impl DependencyCache {
+    /// Creates a new, empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the dependency of the message executed on `chain_id` is resolved.
+    pub fn contains(&self, chain_id: ChainId, message: &ExecutingMessage) -> bool {
+        let resolved = self.resolved.read().unwrap_or_else(PoisonError::into_inner);
+        resolved.get(&chain_id).is_some_and(|messages| messages.contains(message))
+    }
+
+    /// Records the dependency of the message executed on `chain_id` as resolved.
+    pub fn insert(&self, chain_id: ChainId, message: ExecutingMessage) {
+        let mut resolved = self.resolved.write().unwrap_or_else(PoisonError::into_inner);
+        resolved.entry(chain_id).or_default().insert(message);
+    }
+
+    /// Drops all entries of messages executed on `chain_id`.
+    pub fn clear_chain(&self, chain_id: ChainId) {
+        let mut resolved = self.resolved.write().unwrap_or_else(PoisonError::into_inner);
+        resolved.remove(&chain_id);
+    }
+
+    /// Drops all entries.
+    pub fn clear(&self) {
+        self.resolved.write().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+
+    /// Drops all entries of messages initiated on `chain_id` at or after `block_number`.
+    pub fn invalidate_from(&self, chain_id: ChainId, block_number: u64) {
+        let mut resolved = self.resolved.write().unwrap_or_else(PoisonError::into_inner);
+        for messages in resolved.values_mut() {
+            messages.retain(|message| {
+                message.chain_id != chain_id || message.block_number < block_number
+            });
+        }
+    }
+
+    /// Returns the number of cached entries.
+    pub fn len(&self) -> usize {
+        let resolved = self.resolved.read().unwrap_or_else(PoisonError::into_inner);
+        resolved.values().map(HashSet::len).sum()
+    }
+
+    /// Returns `true` if the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+
+    fn message(chain_id: ChainId, block_number: u64) -> ExecutingMessage {
+        ExecutingMessage {
+            chain_id,
+            block_number,
+            log_index: 0,
+            timestamp: 100,
+            hash: B256::from([block_number as u8; 32]),
+        }
+    }
+
+    #[test]
+    fn test_clear_chain_and_invalidate_from() {
+        let cache = DependencyCache::new();
+        cache.insert(1, message(2, 10));
+        cache.insert(1, message(2, 11));
+        cache.insert(3, message(2, 12));
+        cache.insert(3, message(4, 12));
+        assert_eq!(cache.len(), 4);
+        assert!(cache.contains(1, &message(2, 10)));
+        assert!(!cache.contains(3, &message(2, 10)));
+
+        cache.invalidate_from(2, 11);
+        assert!(cache.contains(1, &message(2, 10)));
+        assert!(!cache.contains(1, &message(2, 11)));
+        assert!(!cache.contains(3, &message(2, 12)));
+        assert!(cache.contains(3, &message(4, 12)));
+
+        cache.clear_chain(3);
+        assert!(!cache.contains(3, &message(4, 12)));
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -1,33 +1,78 @@
 use crate::{
     CrossSafetyError,
-    safety_checker::{ValidationError, ValidationError::InitiatingMessageNotFound},
+    safety_checker::{
+        DependencyCache, ValidationError, ValidationError::InitiatingMessageNotFound,
+    },
 };
 use alloy_primitives::{BlockHash, ChainId};
-use derive_more::Constructor;
 use kona_interop::InteropValidator;
 use kona_protocol::BlockInfo;
 use kona_supervisor_storage::{CrossChainSafetyProvider, StorageError};
 use kona_supervisor_types::ExecutingMessage;
 use op_alloy_consensus::interop::SafetyLevel;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// Uses a [`CrossChainSafetyProvider`] to verify the safety of cross-chain message dependencies.
-#[derive(Debug, Constructor)]
+#[derive(Debug)]
 pub struct CrossSafetyChecker<'a, P, V> {
     chain_id: ChainId,
     validator: &'a V,
     provider: &'a P,
     required_level: SafetyLevel,
+    cache: Option<&'a DependencyCache>,
+    candidates: Option<&'a HashMap<ChainId, BlockInfo>>,
 }
 
-impl<P, V> CrossSafetyChecker<'_, P, V>
+impl<'a, P, V> CrossSafetyChecker<'a, P, V>
 where
     P: CrossChainSafetyProvider,
     V: InteropValidator,
 {
+    /// Creates a new [`CrossSafetyChecker`] for the given chain and required safety level.
+    pub const fn new(
+        chain_id: ChainId,
+        validator: &'a V,
+        provider: &'a P,
+        required_level: SafetyLevel,
+    ) -> Self {
+        Self { chain_id, validator, provider, required_level, cache: None, candidates: None }
+    }
+
+    /// Reuses and records resolved message dependencies in the given [`DependencyCache`].
+    pub const fn with_cache(mut self, cache: &'a DependencyCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sets the candidate blocks evaluated alongside this chain's candidate, keyed by chain.
+    ///
+    /// A message depending on one of these candidates at the same timestamp is resolved against
+    /// the candidate block instead of waiting for it to reach the required level. The dependency
+    /// is returned by [`Self::validate_candidate`], and the candidates must then be promoted
+    /// together.
+    pub const fn with_candidates(mut self, candidates: &'a HashMap<ChainId, BlockInfo>) -> Self {
+        self.candidates = Some(candidates);
+        self
+    }
+
     /// Verifies that all executing messages in the given block are valid based on the validity
     /// checks
     pub fn validate_block(&self, block: BlockInfo) -> Result<(), CrossSafetyError> {
+        self.validate_candidate(block).map(|_| ())
+    }
+
+    /// Verifies the given block like [`Self::validate_block`], returning the chains whose
+    /// candidate block it depends on.
+    ///
+    /// Same-timestamp dependencies between candidates follow the semantics of
+    /// [`kona_interop::MessageGraph`]: every message is checked against its initiating block, and
+    /// the candidates of a cycle are valid when all of their messages are. The returned set is
+    /// always empty when no candidates are set.
+    pub fn validate_candidate(
+        &self,
+        block: BlockInfo,
+    ) -> Result<HashSet<ChainId>, CrossSafetyError> {
+        let mut candidate_dependencies = HashSet::new();
         self.map_dependent_block(&block, self.chain_id, |message, initiating_block_fetcher| {
             // Step 1: Validate interop timestamps before any dependency checks
             self.validator
@@ -40,13 +85,35 @@ where
                 )
                 .map_err(ValidationError::InteropValidationError)?;
 
+            // Messages resolved in an earlier evaluation of this block can skip the remaining
+            // steps, their initiating block is already at the required level.
+            if self.cache.is_some_and(|cache| cache.contains(self.chain_id, &message)) {
+                return Ok(());
+            }
+
             // Step 2: Verify message dependency without fetching the initiating block.
             // This avoids unnecessary I/O and ensures we skip validation when:
             //  - The current target head of the chain is behind the initiating block (must wait for
             //    that chain to process further)
             // Only if the target head is ahead but the initiating block is missing, we return a
             // validation error.
-            self.verify_message_dependency(&message)?;
+            if let Err(err) = self.verify_message_dependency(&message) {
+                let candidate = match err {
+                    CrossSafetyError::DependencyNotSafe { .. } => {
+                        self.candidate_dependency(&block, &message)
+                    }
+                    _ => None,
+                };
+                let Some(candidate) = candidate else {
+                    return Err(err);
+                };
+
+                // The initiating block is evaluated in the same round; validate the message
+                // against it and leave the promotion decision to the caller.
+                self.validate_executing_message(candidate, &message)?;
+                candidate_dependencies.insert(message.chain_id);
+                return Ok(());
+            }
 
             // Step 3: Lazily fetch the initiating block only after dependency checks pass.
             let initiating_block = initiating_block_fetcher()?;
@@ -60,10 +127,30 @@ where
                 &initiating_block,
                 message.chain_id,
                 &mut HashSet::new(),
-            )
+            )?;
+
+            // Same-timestamp results depend on the heads of other chains, only cache the rest.
+            if let Some(cache) =
+                self.cache.filter(|_| initiating_block.timestamp != block.timestamp)
+            {
+                cache.insert(self.chain_id, message);
+            }
+            Ok(())
         })?;
 
-        Ok(())
+        Ok(candidate_dependencies)
+    }
+
+    /// Returns the candidate block the message depends on, if it shares the executing block's
+    /// timestamp.
+    fn candidate_dependency(
+        &self,
+        block: &BlockInfo,
+        message: &ExecutingMessage,
+    ) -> Option<BlockInfo> {
+        self.candidates?.get(&message.chain_id).copied().filter(|candidate| {
+            candidate.number == message.block_number && candidate.timestamp == block.timestamp
+        })
     }
 
     /// Ensures that the block a message depends on satisfies the given safety level.
@@ -230,6 +317,7 @@ mod tests {
             fn get_safety_head_ref(&self, chain_id: ChainId, level: SafetyLevel) -> Result<BlockInfo, StorageError>;
             fn update_current_cross_unsafe(&self, chain_id: ChainId, block: &BlockInfo) -> Result<(), StorageError>;
             fn update_current_cross_safe(&self, chain_id: ChainId, block: &BlockInfo) -> Result<DerivedRefPair, StorageError>;
+            fn revert_cross_head(&self, chain_id: ChainId, level: SafetyLevel, block: &BlockInfo) -> Result<(), StorageError>;
         }
     );
 
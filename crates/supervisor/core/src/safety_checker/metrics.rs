@@ -0,0 +1,70 @@
+use alloy_primitives::ChainId;
+use op_alloy_consensus::interop::SafetyLevel;
+use std::time::Duration;
+
+/// Metrics for cross-safety promotion
+#[derive(Debug, Clone)]
+pub(crate) struct Metrics;
+
+impl Metrics {
+    /// Identifier for the time a block waits as promotion candidate until it is promoted.
+    /// Labels: `chain_id`, `level`
+    pub(crate) const SUPERVISOR_CROSS_PROMOTION_LATENCY_SECONDS: &'static str =
+        "kona_supervisor_cross_promotion_latency_seconds";
+
+    /// Identifier for promoted blocks.
+    /// Labels: `chain_id`, `level`
+    pub(crate) const SUPERVISOR_CROSS_PROMOTION_TOTAL: &'static str =
+        "kona_supervisor_cross_promotion_total";
+
+    pub(crate) fn init(chain_id: ChainId, level: SafetyLevel) {
+        Self::describe();
+        Self::zero(chain_id, level);
+    }
+
+    fn describe() {
+        metrics::describe_histogram!(
+            Self::SUPERVISOR_CROSS_PROMOTION_LATENCY_SECONDS,
+            metrics::Unit::Seconds,
+            "Time between a block becoming promotion candidate and its promotion",
+        );
+
+        metrics::describe_counter!(
+            Self::SUPERVISOR_CROSS_PROMOTION_TOTAL,
+            metrics::Unit::Count,
+            "Total number of blocks promoted to a cross safety level",
+        );
+    }
+
+    fn zero(chain_id: ChainId, level: SafetyLevel) {
+        metrics::histogram!(
+            Self::SUPERVISOR_CROSS_PROMOTION_LATENCY_SECONDS,
+            "chain_id" => chain_id.to_string(),
+            "level" => level.to_string(),
+        )
+        .record(0.0);
+
+        metrics::counter!(
+            Self::SUPERVISOR_CROSS_PROMOTION_TOTAL,
+            "chain_id" => chain_id.to_string(),
+            "level" => level.to_string(),
+        )
+        .increment(0);
+    }
+
+    pub(crate) fn record_promotion(chain_id: ChainId, level: SafetyLevel, latency: Duration) {
+        metrics::histogram!(
+            Self::SUPERVISOR_CROSS_PROMOTION_LATENCY_SECONDS,
+            "chain_id" => chain_id.to_string(),
+            "level" => level.to_string(),
+        )
+        .record(latency.as_secs_f64());
+
+        metrics::counter!(
+            Self::SUPERVISOR_CROSS_PROMOTION_TOTAL,
+            "chain_id" => chain_id.to_string(),
+            "level" => level.to_string(),
+        )
+        .increment(1);
+    }
+}
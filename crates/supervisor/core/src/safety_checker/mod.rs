@@ -6,15 +6,20 @@
 //!
 //! It ensures correctness in cross-chain execution by validating that initiating blocks
 //! of messages are safely committed before the messages are executed in other chains.
+//!
+//! Promotion is driven for all chains at once by the [`CrossSafetyScheduler`], which validates the
+//! candidates of independent chains concurrently.
+mod cache;
+pub use cache::DependencyCache;
 mod cross;
 pub use cross::CrossSafetyChecker;
 mod error;
-mod task;
+mod metrics;
+mod scheduler;
+pub use scheduler::CrossSafetyScheduler;
 mod traits;
 pub use traits::SafetyPromoter;
 mod promoter;
 pub use promoter::{CrossSafePromoter, CrossUnsafePromoter};
 
-pub use task::CrossSafetyCheckerJob;
-
 pub use error::{CrossSafetyError, ValidationError};
@@ -0,0 +1,727 @@
+use crate::{
+    CrossSafetyError, RewindLock,
+    config::ReloadableDependencySet,
+    event::ChainEvent,
+    safety_checker::{
+        CrossSafetyChecker, DependencyCache, metrics::Metrics, traits::SafetyPromoter,
+    },
+};
+use alloy_primitives::{B256, ChainId};
+use kona_interop::InteropValidator;
+use kona_protocol::BlockInfo;
+use kona_supervisor_storage::{CrossChainSafetyProvider, StorageError};
+use op_alloy_consensus::interop::SafetyLevel;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::{sync::mpsc, task::JoinSet};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info};
+
+/// Outcome of validating the candidate block of a chain, see
+/// [`CrossSafetyChecker::validate_candidate`].
+type CandidateOutcome = Result<HashSet<ChainId>, CrossSafetyError>;
+
+/// A background job that promotes blocks to a target safety level on all chains at once.
+///
+/// The scheduler works in rounds:
+/// - The next candidate block of every chain is collected.
+/// - The candidates are validated concurrently on the blocking thread pool, sharing a
+///   [`DependencyCache`] that persists across rounds.
+/// - Candidates depending on each other at the same timestamp are resolved as a group, following
+///   the semantics of [`kona_interop::MessageGraph`]: a cycle is promoted when all of its members
+///   are valid. All promotions of a group are committed before its events are broadcast, and
+///   reverted if one of them fails.
+///
+/// Cached dependencies are dropped whenever the dependency set is reloaded, and candidates
+/// validated against a dependency set that was reloaded in the meantime are not promoted.
+///
+/// The time each block spends as candidate until its promotion is recorded per chain.
+#[derive(Debug)]
+pub struct CrossSafetyScheduler<P, V, L> {
+    provider: Arc<P>,
+    validator: Arc<V>,
+    dependency_set: ReloadableDependencySet,
+    generation: u64,
+    promoter: L,
+    event_txs: HashMap<ChainId, mpsc::Sender<ChainEvent>>,
+    cancel_token: CancellationToken,
+    interval: Duration,
+    rewind_lock: RewindLock,
+    cache: Arc<DependencyCache>,
+    heads: HashMap<ChainId, u64>,
+    candidate_since: HashMap<ChainId, (B256, Instant)>,
+}
+
+impl<P, V, L> CrossSafetyScheduler<P, V, L>
+where
+    P: CrossChainSafetyProvider + Send + Sync + 'static,
+    V: InteropValidator + Send + Sync + 'static,
+    L: SafetyPromoter,
+{
+    /// Creates a new [`CrossSafetyScheduler`] for the chains of the given event senders.
+    pub fn new(
+        provider: Arc<P>,
+        validator: Arc<V>,
+        dependency_set: ReloadableDependencySet,
+        promoter: L,
+        event_txs: HashMap<ChainId, mpsc::Sender<ChainEvent>>,
+        cancel_token: CancellationToken,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            provider,
+            validator,
+            generation: dependency_set.generation(),
+            dependency_set,
+            promoter,
+            event_txs,
+            cancel_token,
+            interval,
+            rewind_lock: RewindLock::new(),
+            cache: Arc::new(DependencyCache::new()),
+            heads: HashMap::new(),
+            candidate_since: HashMap::new(),
+        }
+    }
+
+    /// Sets the [`RewindLock`] holding off rounds while a chain is rewound.
+    pub fn with_rewind_lock(mut self, rewind_lock: RewindLock) -> Self {
+        self.rewind_lock = rewind_lock;
+        self
+    }
+
+    /// Runs the scheduler until cancelled.
+    ///
+    /// Rounds follow each other immediately while blocks get promoted, otherwise the scheduler
+    /// waits for the configured interval.
+    pub async fn run(mut self) {
+        let target_level = self.promoter.target_level();
+        let cancel_token = self.cancel_token.clone();
+        let rewind_lock = self.rewind_lock.clone();
+        for &chain_id in self.event_txs.keys() {
+            Metrics::init(chain_id, target_level);
+        }
+
+        info!(
+            target: "supervisor::safety_checker",
+            %target_level,
+            chains = self.event_txs.len(),
+            "Started safety scheduler"
+        );
+
+        loop {
+            tokio::select! {
+                _ = cancel_token.cancelled() => {
+                    info!(target: "supervisor::safety_checker", %target_level, "Canceled safety scheduler");
+                    break;
+                }
+
+                _ = async {
+                    let promoted = {
+                        let _work = rewind_lock.work().await;
+                        self.run_round().await
+                    };
+                    if promoted == 0 {
+                        tokio::time::sleep(self.interval).await;
+                    }
+                } => {}
+            }
+        }
+
+        info!(target: "supervisor::safety_checker", %target_level, "Stopped safety scheduler");
+    }
+
+    /// Evaluates the current candidates of all chains and returns the number of promoted blocks.
+    async fn run_round(&mut self) -> usize {
+        let target_level = self.promoter.target_level();
+
+        let dependency_set = self.dependency_set.clone();
+        let generation = dependency_set.generation();
+        if generation != self.generation {
+            // Resolved dependencies were checked against the previous dependency set.
+            self.cache.clear();
+            self.generation = generation;
+        }
+
+        let mut candidates = HashMap::new();
+        for &chain_id in self.event_txs.keys() {
+            match self.find_next_candidate(chain_id) {
+                Ok(candidate) => {
+                    candidates.insert(chain_id, candidate);
+                }
+                Err(CrossSafetyError::NoBlockToPromote) => {}
+                Err(err) => {
+                    error!(
+                        target: "supervisor::safety_checker",
+                        chain_id,
+                        %target_level,
+                        %err,
+                        "Failed to find next candidate block"
+                    );
+                }
+            }
+        }
+        if candidates.is_empty() {
+            return 0;
+        }
+
+        let candidates = Arc::new(candidates);
+        let outcomes = self.validate_candidates(&candidates).await;
+
+        let promotable = resolve_promotable(&outcomes);
+        let mut promoted = 0;
+        for group in promotion_groups(&outcomes, &promotable) {
+            match dependency_set
+                .with_generation(generation, || self.promote_group(&group, &candidates))
+            {
+                Some(Ok(())) => promoted += group.len(),
+                // Revalidated against the reloaded dependency set in the next round.
+                None => {
+                    debug!(
+                        target: "supervisor::safety_checker",
+                        chains = ?group,
+                        %target_level,
+                        "Dependency set reloaded, skipping promotion of candidate blocks"
+                    );
+                }
+                Some(Err(err)) => {
+                    error!(
+                        target: "supervisor::safety_checker",
+                        chains = ?group,
+                        %target_level,
+                        %err,
+                        "Failed to promote candidate blocks"
+                    );
+                }
+            }
+        }
+
+        for (chain_id, outcome) in outcomes {
+            if promotable.contains(&chain_id) {
+                continue;
+            }
+
+            let candidate = candidates[&chain_id];
+            match outcome {
+                // Waiting on a candidate of another chain that could not be promoted.
+                Ok(_) => {
+                    debug!(
+                        target: "supervisor::safety_checker",
+                        chain_id,
+                        %target_level,
+                        block_info = %candidate,
+                        "Candidate depends on a candidate that is not promotable yet"
+                    );
+                }
+                Err(err @ CrossSafetyError::ValidationError(_)) => {
+                    self.cache.clear_chain(chain_id);
+                    // Only invalidate if we are targeting CrossSafe
+                    if target_level == SafetyLevel::CrossSafe {
+                        info!(
+                            target: "supervisor::safety_checker",
+                            chain_id,
+                            %target_level,
+                            block_info = %candidate,
+                            %err,
+                            "Triggering block invalidation for the invalid block"
+                        );
+                        self.broadcast_event(
+                            chain_id,
+                            ChainEvent::InvalidateBlock { block: candidate },
+                        );
+                    }
+                }
+                Err(err @ CrossSafetyError::DependencyNotSafe { .. }) => {
+                    debug!(
+                        target: "supervisor::safety_checker",
+                        chain_id,
+                        %target_level,
+                        %err,
+                        "Error promoting next candidate block"
+                    );
+                }
+                Err(err) => {
+                    error!(
+                        target: "supervisor::safety_checker",
+                        chain_id,
+                        %target_level,
+                        %err,
+                        "Unexpected error promoting next candidate block"
+                    );
+                }
+            }
+        }
+
+        promoted
+    }
+
+    /// Validates all candidates concurrently on the blocking thread pool.
+    async fn validate_candidates(
+        &self,
+        candidates: &Arc<HashMap<ChainId, BlockInfo>>,
+    ) -> HashMap<ChainId, CandidateOutcome> {
+        let target_level = self.promoter.target_level();
+
+        let mut tasks = JoinSet::new();
+        for (&chain_id, &candidate) in candidates.iter() {
+            let provider = self.provider.clone();
+            let validator = self.validator.clone();
+            let cache = self.cache.clone();
+            let candidates = candidates.clone();
+            tasks.spawn_blocking(move || {
+                let outcome =
+                    CrossSafetyChecker::new(chain_id, &*validator, &*provider, target_level)
+                        .with_cache(&cache)
+                        .with_candidates(&candidates)
+                        .validate_candidate(candidate);
+                (chain_id, outcome)
+            });
+        }
+
+        let mut outcomes = HashMap::with_capacity(candidates.len());
+        while let Some(result) = tasks.join_next().await {
+            match result {
+                Ok((chain_id, outcome)) => {
+                    outcomes.insert(chain_id, outcome);
+                }
+                Err(err) => {
+                    error!(
+                        target: "supervisor::safety_checker",
+                        %target_level,
+                        %err,
+                        "Candidate validation task failed"
+                    );
+                }
+            }
+        }
+        outcomes
+    }
+
+    // Finds the next block that is eligible for promotion on the given chain, invalidating
+    // cached dependencies on blocks above the target head if it moved backwards.
+    fn find_next_candidate(&mut self, chain_id: ChainId) -> Result<BlockInfo, CrossSafetyError> {
+        let current_head = self.get_head(chain_id, self.promoter.target_level())?;
+        let upper_head = self.get_head(chain_id, self.promoter.lower_bound_level())?;
+
+        let previous = self.heads.insert(chain_id, current_head.number);
+        if previous.is_some_and(|previous| current_head.number < previous) {
+            self.cache.invalidate_from(chain_id, current_head.number + 1);
+        }
+
+        if current_head.number >= upper_head.number {
+            return Err(CrossSafetyError::NoBlockToPromote);
+        }
+
+        let candidate = self.provider.get_block(chain_id, current_head.number + 1)?;
+        if self.candidate_since.get(&chain_id).is_none_or(|(hash, _)| *hash != candidate.hash) {
+            self.candidate_since.insert(chain_id, (candidate.hash, Instant::now()));
+        }
+        Ok(candidate)
+    }
+
+    fn get_head(
+        &self,
+        chain_id: ChainId,
+        level: SafetyLevel,
+    ) -> Result<BlockInfo, CrossSafetyError> {
+        self.provider.get_safety_head_ref(chain_id, level).map_err(|err| {
+            if matches!(err, StorageError::FutureData) {
+                CrossSafetyError::NoBlockToPromote
+            } else {
+                err.into()
+            }
+        })
+    }
+
+    /// Promotes a group of candidates depending on each other.
+    ///
+    /// All promotions are committed before any event is broadcast. If one of them fails, the
+    /// promotions already committed are reverted, so the group is promoted as a whole or not at
+    /// all.
+    fn promote_group(
+        &mut self,
+        group: &[ChainId],
+        candidates: &HashMap<ChainId, BlockInfo>,
+    ) -> Result<(), CrossSafetyError> {
+        let target_level = self.promoter.target_level();
+
+        let mut events = Vec::with_capacity(group.len());
+        for &chain_id in group {
+            match self.promoter.update_and_emit_event(
+                &*self.provider,
+                chain_id,
+                &candidates[&chain_id],
+            ) {
+                Ok(event) => events.push((chain_id, event)),
+                Err(err) => {
+                    for &(chain_id, _) in events.iter().rev() {
+                        let candidate = &candidates[&chain_id];
+                        if let Err(err) =
+                            self.provider.revert_cross_head(chain_id, target_level, candidate)
+                        {
+                            error!(
+                                target: "supervisor::safety_checker",
+                                chain_id,
+                                %target_level,
+                                block_info = %candidate,
+                                %err,
+                                "Failed to revert promotion of candidate block"
+                            );
+                        }
+                    }
+                    return Err(err);
+                }
+            }
+        }
+
+        for (chain_id, event) in events {
+            self.broadcast_event(chain_id, event);
+            self.record_promotion(chain_id, candidates[&chain_id]);
+        }
+        Ok(())
+    }
+
+    fn record_promotion(&mut self, chain_id: ChainId, candidate: BlockInfo) {
+        let target_level = self.promoter.target_level();
+        self.cache.clear_chain(chain_id);
+        self.heads.insert(chain_id, candidate.number);
+        if let Some((_, since)) = self.candidate_since.remove(&chain_id) {
+            Metrics::record_promotion(chain_id, target_level, since.elapsed());
+        }
+
+        debug!(
+            target: "supervisor::safety_checker",
+            chain_id,
+            %target_level,
+            block_info = %candidate,
+            "Promoted next candidate block"
+        );
+    }
+
+    fn broadcast_event(&self, chain_id: ChainId, event: ChainEvent) {
+        let Some(event_tx) = self.event_txs.get(&chain_id) else {
+            return;
+        };
+        if let Err(err) = event_tx.try_send(event) {
+            error!(
+                target: "supervisor::safety_checker",
+                chain_id,
+                target_level = %self.promoter.target_level(),
+                %err,
+                "Failed to broadcast cross head update event",
+            );
+        }
+    }
+}
+
+/// Returns the chains whose candidate can be promoted.
+///
+/// A valid candidate is promotable once every candidate it depends on is promotable as well.
+/// Candidates are removed until a fixpoint is reached, so a group of candidates depending on each
+/// other is promoted as a whole, or not at all.
+fn resolve_promotable(outcomes: &HashMap<ChainId, CandidateOutcome>) -> HashSet<ChainId> {
+    let mut promotable: HashSet<ChainId> =
+        outcomes.iter().filter(|(_, outcome)| outcome.is_ok()).map(|(&id, _)| id).collect();
+
+    loop {
+        let blocked: Vec<ChainId> = promotable
+            .iter()
+            .filter(|id| {
+                outcomes[id].as_ref().is_ok_and(|deps| !deps.iter().all(|d| promotable.contains(d)))
+            })
+            .copied()
+            .collect();
+        if blocked.is_empty() {
+            return promotable;
+        }
+        for id in blocked {
+            promotable.remove(&id);
+        }
+    }
+}
+
+/// Splits the promotable chains into groups of candidates depending on each other, directly or
+/// through other candidates of the group. Each group must be promoted as a whole.
+fn promotion_groups(
+    outcomes: &HashMap<ChainId, CandidateOutcome>,
+    promotable: &HashSet<ChainId>,
+) -> Vec<Vec<ChainId>> {
+    let mut neighbours: HashMap<ChainId, Vec<ChainId>> = HashMap::new();
+    for &chain_id in promotable {
+        if let Ok(dependencies) = &outcomes[&chain_id] {
+            for &dependency in dependencies {
+                neighbours.entry(chain_id).or_default().push(dependency);
+                neighbours.entry(dependency).or_default().push(chain_id);
+            }
+        }
+    }
+
+    let mut visited = HashSet::new();
+    let mut groups = Vec::new();
+    for &chain_id in promotable {
+        if !visited.insert(chain_id) {
+            continue;
+        }
+        let mut group = vec![chain_id];
+        let mut stack = vec![chain_id];
+        while let Some(current) = stack.pop() {
+            for &next in neighbours.get(&current).into_iter().flatten() {
+                if visited.insert(next) {
+                    group.push(next);
+                    stack.push(next);
+                }
+            }
+        }
+        group.sort_unstable();
+        groups.push(group);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::DependencySetReloadError,
+        safety_checker::{CrossSafePromoter, CrossUnsafePromoter},
+    };
+    use kona_interop::{DependencySet, DerivedRefPair, InteropValidationError};
+    use kona_supervisor_types::{ExecutingMessage, Log};
+    use mockall::mock;
+
+    mock! {
+        #[derive(Debug)]
+        pub Provider {}
+
+        impl CrossChainSafetyProvider for Provider {
+            fn get_block(&self, chain_id: ChainId, block_number: u64) -> Result<BlockInfo, StorageError>;
+            fn get_log(&self, chain_id: ChainId, block_number: u64, log_index: u32) -> Result<Log, StorageError>;
+            fn get_block_logs(&self, chain_id: ChainId, block_number: u64) -> Result<Vec<Log>, StorageError>;
+            fn get_safety_head_ref(&self, chain_id: ChainId, level: SafetyLevel) -> Result<BlockInfo, StorageError>;
+            fn update_current_cross_unsafe(&self, chain_id: ChainId, block: &BlockInfo) -> Result<(), StorageError>;
+            fn update_current_cross_safe(&self, chain_id: ChainId, block: &BlockInfo) -> Result<DerivedRefPair, StorageError>;
+            fn revert_cross_head(&self, chain_id: ChainId, level: SafetyLevel, block: &BlockInfo) -> Result<(), StorageError>;
+        }
+    }
+
+    mock! (
+        #[derive(Debug)]
+        pub Validator {}
+
+        impl InteropValidator for Validator {
+            fn validate_interop_timestamps(
+                &self,
+                initiating_chain_id: ChainId,
+                initiating_timestamp: u64,
+                executing_chain_id: ChainId,
+                executing_timestamp: u64,
+                timeout: Option<u64>,
+            ) -> Result<(), InteropValidationError>;
+
+            fn is_post_interop(&self, chain_id: ChainId, timestamp: u64) -> bool;
+
+            fn is_interop_activation_block(&self, chain_id: ChainId, block: BlockInfo) -> bool;
+        }
+    );
+
+    fn b256(chain_id: ChainId, n: u64) -> B256 {
+        let mut bytes = [0u8; 32];
+        bytes[..8].copy_from_slice(&chain_id.to_be_bytes());
+        bytes[24..].copy_from_slice(&n.to_be_bytes());
+        B256::from(bytes)
+    }
+
+    fn block(chain_id: ChainId, n: u64) -> BlockInfo {
+        BlockInfo {
+            number: n,
+            hash: b256(chain_id, n),
+            parent_hash: b256(chain_id, n - 1),
+            timestamp: 1000,
+        }
+    }
+
+    // Log `0` of each candidate initiates a message executed by log `1` of the other candidate.
+    fn cyclic_logs(chain_id: ChainId, n: u64, other_chain_id: ChainId, other_n: u64) -> Vec<Log> {
+        vec![
+            Log { index: 0, hash: b256(chain_id, n), executing_message: None },
+            Log {
+                index: 1,
+                hash: B256::ZERO,
+                executing_message: Some(ExecutingMessage {
+                    chain_id: other_chain_id,
+                    block_number: other_n,
+                    log_index: 0,
+                    timestamp: 1000,
+                    hash: b256(other_chain_id, other_n),
+                }),
+            },
+        ]
+    }
+
+    // Chain 1 candidate 100 and chain 2 candidate 50 execute each other's messages. The initiating
+    // logs stored on `corrupt_chain` don't match the executed messages.
+    fn cyclic_provider(target_level: SafetyLevel, corrupt_chain: Option<ChainId>) -> MockProvider {
+        let mut provider = MockProvider::default();
+        provider.expect_get_safety_head_ref().returning(move |chain_id, level| {
+            let head = if chain_id == 1 { 99 } else { 49 };
+            Ok(if level == target_level {
+                block(chain_id, head)
+            } else {
+                block(chain_id, head + 1)
+            })
+        });
+        provider.expect_get_block().returning(|chain_id, n| Ok(block(chain_id, n)));
+        provider.expect_get_block_logs().returning(|chain_id, n| {
+            Ok(if chain_id == 1 { cyclic_logs(1, n, 2, 50) } else { cyclic_logs(2, n, 1, 100) })
+        });
+        provider.expect_get_log().returning(move |chain_id, n, index| {
+            let hash = if corrupt_chain == Some(chain_id) { B256::ZERO } else { b256(chain_id, n) };
+            Ok(Log { index, hash, executing_message: None })
+        });
+        provider
+    }
+
+    fn valid_timestamps() -> MockValidator {
+        let mut validator = MockValidator::default();
+        validator.expect_validate_interop_timestamps().returning(|_, _, _, _, _| Ok(()));
+        validator
+    }
+
+    #[test]
+    fn test_resolve_promotable_drops_dependents_of_failed_candidates() {
+        let outcomes = HashMap::from([
+            (1, Ok(HashSet::from([2]))),
+            (2, Ok(HashSet::from([1]))),
+            (3, Ok(HashSet::from([4]))),
+            (4, Err(CrossSafetyError::NoBlockToPromote)),
+            (5, Ok(HashSet::from([3]))),
+            (6, Ok(HashSet::new())),
+        ]);
+
+        assert_eq!(resolve_promotable(&outcomes), HashSet::from([1, 2, 6]));
+    }
+
+    #[tokio::test]
+    async fn test_round_promotes_same_timestamp_cycle_together() {
+        let mut provider = cyclic_provider(SafetyLevel::CrossUnsafe, None);
+        provider.expect_update_current_cross_unsafe().times(2).returning(|_, _| Ok(()));
+
+        let (tx1, mut rx1) = mpsc::channel(10);
+        let (tx2, mut rx2) = mpsc::channel(10);
+        let mut scheduler = CrossSafetyScheduler::new(
+            Arc::new(provider),
+            Arc::new(valid_timestamps()),
+            ReloadableDependencySet::default(),
+            CrossUnsafePromoter,
+            HashMap::from([(1, tx1), (2, tx2)]),
+            CancellationToken::new(),
+            Duration::from_secs(1),
+        );
+
+        assert_eq!(scheduler.run_round().await, 2);
+        assert_eq!(rx1.recv().await, Some(ChainEvent::CrossUnsafeUpdate { block: block(1, 100) }));
+        assert_eq!(rx2.recv().await, Some(ChainEvent::CrossUnsafeUpdate { block: block(2, 50) }));
+    }
+
+    #[test]
+    fn test_promotion_groups_join_dependent_candidates() {
+        let outcomes = HashMap::from([
+            (1, Ok(HashSet::from([2]))),
+            (2, Ok(HashSet::new())),
+            (3, Ok(HashSet::from([2]))),
+            (4, Ok(HashSet::new())),
+        ]);
+        let promotable = HashSet::from([1, 2, 3, 4]);
+
+        let mut groups = promotion_groups(&outcomes, &promotable);
+        groups.sort();
+        assert_eq!(groups, vec![vec![1, 2, 3], vec![4]]);
+    }
+
+    #[tokio::test]
+    async fn test_round_reverts_cycle_when_a_promotion_fails() {
+        let mut provider = cyclic_provider(SafetyLevel::CrossUnsafe, None);
+        provider.expect_update_current_cross_unsafe().returning(|chain_id, _| {
+            if chain_id == 1 { Ok(()) } else { Err(StorageError::ConflictError) }
+        });
+        provider
+            .expect_revert_cross_head()
+            .withf(|chain_id, level, head| {
+                *chain_id == 1 && *level == SafetyLevel::CrossUnsafe && *head == block(1, 100)
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let (tx1, mut rx1) = mpsc::channel(10);
+        let (tx2, mut rx2) = mpsc::channel(10);
+        let mut scheduler = CrossSafetyScheduler::new(
+            Arc::new(provider),
+            Arc::new(valid_timestamps()),
+            ReloadableDependencySet::default(),
+            CrossUnsafePromoter,
+            HashMap::from([(1, tx1), (2, tx2)]),
+            CancellationToken::new(),
+            Duration::from_secs(1),
+        );
+
+        assert_eq!(scheduler.run_round().await, 0);
+        assert!(rx1.try_recv().is_err());
+        assert!(rx2.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_round_drops_cache_after_dependency_set_reload() {
+        let dependency_set = ReloadableDependencySet::default();
+        let mut scheduler = CrossSafetyScheduler::new(
+            Arc::new(MockProvider::new()),
+            Arc::new(valid_timestamps()),
+            dependency_set.clone(),
+            CrossUnsafePromoter,
+            HashMap::new(),
+            CancellationToken::new(),
+            Duration::from_secs(1),
+        );
+        let message = ExecutingMessage {
+            chain_id: 2,
+            block_number: 10,
+            log_index: 0,
+            timestamp: 100,
+            hash: B256::ZERO,
+        };
+
+        scheduler.cache.insert(1, message.clone());
+        assert_eq!(scheduler.run_round().await, 0);
+        assert!(scheduler.cache.contains(1, &message));
+
+        dependency_set
+            .update(DependencySet::default(), || Ok::<_, DependencySetReloadError>(0))
+            .unwrap();
+        assert_eq!(scheduler.run_round().await, 0);
+        assert!(scheduler.cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_round_invalidates_cycle_member_with_invalid_message() {
+        let mut provider = cyclic_provider(SafetyLevel::CrossSafe, Some(2));
+        provider.expect_update_current_cross_safe().never();
+
+        let (tx1, mut rx1) = mpsc::channel(10);
+        let (tx2, mut rx2) = mpsc::channel(10);
+        let mut scheduler = CrossSafetyScheduler::new(
+            Arc::new(provider),
+            Arc::new(valid_timestamps()),
+            ReloadableDependencySet::default(),
+            CrossSafePromoter,
+            HashMap::from([(1, tx1), (2, tx2)]),
+            CancellationToken::new(),
+            Duration::from_secs(1),
+        );
+
+        assert_eq!(scheduler.run_round().await, 0);
+        assert_eq!(rx1.recv().await, Some(ChainEvent::InvalidateBlock { block: block(1, 100) }));
+        assert!(rx2.try_recv().is_err());
+    }
+}
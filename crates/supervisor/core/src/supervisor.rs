@@ -2,28 +2,38 @@ use alloy_eips::BlockNumHash;
 use alloy_primitives::{B256, Bytes, ChainId, keccak256};
 use async_trait::async_trait;
 use core::fmt::Debug;
+use futures::{StreamExt, TryStreamExt, future::try_join_all, stream};
 use kona_interop::{
     DependencySet, ExecutingDescriptor, InteropValidator, OutputRootWithChain, SUPER_ROOT_VERSION,
     SafetyLevel, SuperRoot,
 };
 use kona_protocol::BlockInfo;
-use kona_supervisor_rpc::{ChainRootInfoRpc, SuperRootOutputRpc};
+use kona_supervisor_rpc::{
+    ChainOutputRpc, ChainRootInfoRpc, ExecutingMessageStatus, InitiatingMessageStatus,
+    MAX_SUPER_ROOTS_PER_REQUEST, MessageLifecycle, MessageStage, SuperRootOutputRpc,
+    SuperRootWithOutputsRpc,
+};
 use kona_supervisor_storage::{
-    ChainDb, ChainDbFactory, DerivationStorageReader, FinalizedL1Storage, HeadRefStorageReader,
-    LogStorageReader,
+    ChainDbFactory, DerivationStorageReader, FinalizedL1Storage, HeadRefStorageReader,
+    LogStorageReader, MessageIndexReader, OutputStorageReader, OutputStorageWriter, StorageError,
+    StorageFactory,
 };
-use kona_supervisor_types::{SuperHead, parse_access_list};
+use kona_supervisor_types::{ChainOutput, MessageIdentifier, SuperHead, parse_access_list};
 use op_alloy_rpc_types::SuperchainDAError;
 use std::{collections::HashMap, sync::Arc};
-use tokio::sync::RwLock;
-use tracing::{error, warn};
+use tokio::{sync::RwLock, try_join};
+use tracing::{error, info, warn};
 
 use crate::{
     SpecError, SupervisorError,
-    config::Config,
+    config::{Config, DependencySetReloadError, load_dependency_set},
     syncnode::{BlockProvider, ManagedNodeDataProvider},
 };
 
+/// Maximum number of timestamps whose super roots are fetched concurrently by
+/// [`SupervisorService::super_roots_in_range`].
+const MAX_SUPER_ROOTS_IN_FLIGHT: usize = 16;
+
 /// Defines the service for the Supervisor core logic.
 #[async_trait]
 #[auto_impl::auto_impl(&, &mut, Arc, Box)]
@@ -33,8 +43,11 @@ pub trait SupervisorService: Debug + Send + Sync {
 
     /// Returns mapping of supervised [`ChainId`]s to their [`ChainDependency`] config.
     ///
+    /// The dependency set may be reloaded at runtime, hence a snapshot of the current one is
+    /// returned.
+    ///
     /// [`ChainDependency`]: kona_interop::ChainDependency
-    fn dependency_set(&self) -> &DependencySet;
+    fn dependency_set(&self) -> Arc<DependencySet>;
 
     /// Returns [`SuperHead`] of given supervised chain.
     fn super_head(&self, chain: ChainId) -> Result<SuperHead, SupervisorError>;
@@ -86,6 +99,19 @@ pub trait SupervisorService: Debug + Send + Sync {
         timestamp: u64,
     ) -> Result<SuperRootOutputRpc, SupervisorError>;
 
+    /// Returns the super roots at the timestamps `from`, `from + step`, ... up to `to`
+    /// (inclusive), along with the outputs of all chains they were computed from.
+    ///
+    /// Timestamps past the head of the given [`SafetyLevel`] on any chain are omitted. At most
+    /// [`MAX_SUPER_ROOTS_PER_REQUEST`] timestamps can be requested at once.
+    async fn super_roots_in_range(
+        &self,
+        from: u64,
+        to: u64,
+        step: u64,
+        safety_level: SafetyLevel,
+    ) -> Result<Vec<SuperRootWithOutputsRpc>, SupervisorError>;
+
     /// Verifies if an access-list references only valid messages
     fn check_access_list(
         &self,
@@ -93,26 +119,43 @@ pub trait SupervisorService: Debug + Send + Sync {
         min_safety: SafetyLevel,
         executing_descriptor: ExecutingDescriptor,
     ) -> Result<(), SupervisorError>;
+
+    /// Returns all executing messages across the dependency set that reference the given
+    /// initiating message, along with the stage of each execution.
+    fn executing_messages(
+        &self,
+        identifier: MessageIdentifier,
+    ) -> Result<Vec<ExecutingMessageStatus>, SupervisorError>;
+
+    /// Returns the [`MessageLifecycle`] of the given initiating message.
+    fn message_lifecycle(
+        &self,
+        identifier: MessageIdentifier,
+    ) -> Result<MessageLifecycle, SupervisorError>;
 }
 
 /// The core Supervisor component responsible for monitoring and coordinating chain states.
+///
+/// Generic over the [`StorageFactory`] backing the storage of every chain, which defaults to the
+/// MDBX-backed [`ChainDbFactory`].
 #[derive(Debug)]
-pub struct Supervisor<M> {
+pub struct Supervisor<M, F = ChainDbFactory> {
     config: Arc<Config>,
-    database_factory: Arc<ChainDbFactory>,
+    database_factory: Arc<F>,
 
     // As of now supervisor only supports a single managed node per chain.
     // This is a limitation of the current implementation, but it will be extended in the future.
     managed_nodes: RwLock<HashMap<ChainId, Arc<M>>>,
 }
 
-impl<M> Supervisor<M>
+impl<M, F> Supervisor<M, F>
 where
     M: ManagedNodeDataProvider + BlockProvider + Send + Sync + Debug,
+    F: StorageFactory,
 {
     /// Creates a new [`Supervisor`] instance.
     #[allow(clippy::new_without_default, clippy::missing_const_for_fn)]
-    pub fn new(config: Arc<Config>, database_factory: Arc<ChainDbFactory>) -> Self {
+    pub fn new(config: Arc<Config>, database_factory: Arc<F>) -> Self {
         Self { config, database_factory, managed_nodes: RwLock::new(HashMap::new()) }
     }
 
@@ -123,7 +166,7 @@ where
         managed_node: Arc<M>,
     ) -> Result<(), SupervisorError> {
         // todo: instead of passing the chain ID, we should get it from the managed node
-        if !self.config.dependency_set.dependencies.contains_key(&chain_id) {
+        if !self.config.dependency_set.load().dependencies.contains_key(&chain_id) {
             warn!(target: "supervisor::service", %chain_id, "Unsupported chain ID");
             return Err(SupervisorError::UnsupportedChainId);
         }
@@ -138,6 +181,53 @@ where
         Ok(())
     }
 
+    /// Reloads the dependency set from the file it was initially loaded from.
+    ///
+    /// The reloaded dependency set is rejected if it would change the validity of messages in
+    /// blocks that are already cross-unsafe or cross-safe on any chain, see
+    /// [`ReloadableDependencySet::update`](crate::config::ReloadableDependencySet::update).
+    pub async fn reload_dependency_set(&self) -> Result<(), SupervisorError> {
+        let path = self
+            .config
+            .dependency_set_path
+            .as_ref()
+            .ok_or(DependencySetReloadError::PathNotConfigured)?;
+        let dependency_set = load_dependency_set(path).await?;
+
+        let mut validated_timestamp = 0;
+        self.config
+            .dependency_set
+            .update(dependency_set, || {
+                validated_timestamp = self.validated_timestamp()?;
+                Ok(validated_timestamp)
+            })
+            .inspect_err(|err| {
+                warn!(target: "supervisor::service", %err, validated_timestamp, "Rejected reloaded dependency set");
+            })?;
+
+        info!(target: "supervisor::service", path = %path.display(), "Reloaded dependency set");
+        Ok(())
+    }
+
+    /// Returns the highest cross-unsafe or cross-safe timestamp of any chain.
+    fn validated_timestamp(&self) -> Result<u64, SupervisorError> {
+        let mut validated_timestamp = 0;
+        for chain_id in self.chain_ids() {
+            let db = self.get_db(chain_id)?;
+            for level in [SafetyLevel::CrossUnsafe, SafetyLevel::CrossSafe] {
+                match db.get_safety_head_ref(level) {
+                    Ok(head) => validated_timestamp = validated_timestamp.max(head.timestamp),
+                    Err(StorageError::FutureData) => {}
+                    Err(err) => {
+                        error!(target: "supervisor::service", %chain_id, %err, %level, "Failed to get safety head ref for chain");
+                        return Err(SpecError::from(err).into());
+                    }
+                }
+            }
+        }
+        Ok(validated_timestamp)
+    }
+
     fn verify_safety_level(
         &self,
         chain_id: ChainId,
@@ -153,7 +243,239 @@ where
         Ok(())
     }
 
-    fn get_db(&self, chain: ChainId) -> Result<Arc<ChainDb>, SupervisorError> {
+    /// Returns the highest [`SafetyLevel`] reached by the given block number on the given chain.
+    ///
+    /// Heads that are not yet set are skipped.
+    fn safety_level_at(
+        &self,
+        chain: ChainId,
+        db: &F::ChainDb,
+        block_number: u64,
+    ) -> Result<SafetyLevel, SupervisorError> {
+        for level in [
+            SafetyLevel::Finalized,
+            SafetyLevel::CrossSafe,
+            SafetyLevel::LocalSafe,
+            SafetyLevel::CrossUnsafe,
+        ] {
+            match db.get_safety_head_ref(level) {
+                Ok(head) if head.number >= block_number => return Ok(level),
+                Ok(_) | Err(StorageError::FutureData) => {}
+                Err(err) => {
+                    error!(target: "supervisor::service", %chain, %err, %level, "Failed to get safety head ref for chain");
+                    return Err(SpecError::from(err).into());
+                }
+            }
+        }
+        Ok(SafetyLevel::LocalUnsafe)
+    }
+
+    /// Returns the supervised chain ids, sorted for a deterministic super root hash.
+    fn sorted_chain_ids(&self) -> Vec<ChainId> {
+        let mut chain_ids = self.chain_ids().collect::<Vec<_>>();
+        chain_ids.sort();
+        chain_ids
+    }
+
+    /// Computes the super root at the given timestamp from the outputs of the given chains, which
+    /// must be sorted.
+    async fn super_root_with_outputs(
+        &self,
+        chain_ids: &[ChainId],
+        timestamp: u64,
+    ) -> Result<SuperRootWithOutputsRpc, SupervisorError> {
+        let mut chain_infos = Vec::<ChainRootInfoRpc>::with_capacity(chain_ids.len());
+        let mut outputs = Vec::<ChainOutputRpc>::with_capacity(chain_ids.len());
+        let mut super_root_chains = Vec::<OutputRootWithChain>::with_capacity(chain_ids.len());
+        let mut cross_safe_source = BlockNumHash::default();
+
+        // The outputs of all chains are fetched concurrently.
+        let chain_outputs =
+            try_join_all(chain_ids.iter().map(|id| self.chain_output_at_timestamp(*id, timestamp)))
+                .await?;
+
+        for (id, output) in chain_ids.iter().zip(chain_outputs) {
+            let output_v0_string = serde_json::to_string(&output.output)
+                .inspect_err(|err| {
+                    error!(target: "supervisor::service", chain_id = %id, %err, "Failed to serialize output_v0 for chain");
+                })?;
+            let canonical_root = keccak256(output_v0_string.as_bytes());
+
+            let pending_output_v0_string = serde_json::to_string(&output.pending)
+                .inspect_err(|err| {
+                    error!(target: "supervisor::service", chain_id = %id, %err, "Failed to serialize pending_output_v0 for chain");
+                })?;
+            let pending_output_v0_bytes =
+                Bytes::copy_from_slice(pending_output_v0_string.as_bytes());
+
+            chain_infos.push(ChainRootInfoRpc {
+                chain_id: *id,
+                canonical: canonical_root,
+                pending: pending_output_v0_bytes,
+            });
+
+            super_root_chains
+                .push(OutputRootWithChain { chain_id: *id, output_root: canonical_root });
+
+            if cross_safe_source.number == 0 || cross_safe_source.number < output.source.number {
+                cross_safe_source = output.source.id();
+            }
+
+            outputs.push(ChainOutputRpc {
+                chain_id: *id,
+                block_number: output.block_number,
+                output: output.output,
+                pending: output.pending,
+            });
+        }
+
+        let super_root = SuperRoot { timestamp, output_roots: super_root_chains };
+        let super_root_hash = super_root.hash();
+
+        Ok(SuperRootWithOutputsRpc {
+            super_root: SuperRootOutputRpc {
+                cross_safe_derived_from: cross_safe_source,
+                timestamp,
+                super_root: super_root_hash,
+                chains: chain_infos,
+                version: SUPER_ROOT_VERSION,
+            },
+            outputs,
+        })
+    }
+
+    /// Returns the outputs of the given chain at the given timestamp.
+    ///
+    /// Outputs of finalized blocks are cached in the storage, so they are only fetched from the
+    /// managed node once.
+    async fn chain_output_at_timestamp(
+        &self,
+        chain: ChainId,
+        timestamp: u64,
+    ) -> Result<ChainOutput, SupervisorError> {
+        let db = self.get_db(chain)?;
+        let cached = db.get_finalized_output(timestamp).map_err(|err| {
+            error!(target: "supervisor::service", %chain, %err, "Failed to get cached output for chain");
+            SpecError::from(err)
+        })?;
+        if let Some(output) = cached {
+            return Ok(output);
+        }
+
+        let managed_node = {
+            let guard = self.managed_nodes.read().await;
+            match guard.get(&chain) {
+                Some(m) => m.clone(),
+                None => {
+                    error!(target: "supervisor::service", %chain, "Managed node not found for chain");
+                    return Err(SupervisorError::ManagedNodeMissing(chain));
+                }
+            }
+        };
+        let (output_v0, pending_output_v0, l2_block) = try_join!(
+            managed_node.output_v0_at_timestamp(timestamp),
+            managed_node.pending_output_v0_at_timestamp(timestamp),
+            managed_node.l2_block_ref_by_timestamp(timestamp),
+        )?;
+        let source = self
+            .derived_to_source_block(chain, l2_block.id())
+            .inspect_err(|err| {
+                error!(target: "supervisor::service", %chain, %err, "Failed to get derived to source block for chain");
+            })?;
+
+        let output = ChainOutput {
+            block_number: l2_block.number,
+            source,
+            output: output_v0,
+            pending: pending_output_v0,
+        };
+
+        let finalized = db
+            .get_safety_head_ref(SafetyLevel::Finalized)
+            .is_ok_and(|finalized| finalized.number >= l2_block.number);
+        if finalized {
+            // The output is still served if it can't be cached.
+            if let Err(err) = db.save_finalized_output(timestamp, output.clone()) {
+                warn!(target: "supervisor::service", %chain, %err, timestamp, "Failed to cache finalized output for chain");
+            }
+        }
+        Ok(output)
+    }
+
+    /// Returns the hash of the initiating log identified by `identifier`, or [`None`] if the log
+    /// is not known to the supervisor.
+    fn initiating_log_hash(
+        &self,
+        identifier: &MessageIdentifier,
+    ) -> Result<Option<B256>, SupervisorError> {
+        let chain = identifier.chain_id;
+        let Ok(db) = self.database_factory.get_db(chain) else {
+            return Ok(None);
+        };
+        match db.get_log(identifier.block_number, identifier.log_index) {
+            Ok(log) => Ok(Some(log.hash)),
+            Err(StorageError::EntryNotFound(_) | StorageError::EntryPruned(_)) => Ok(None),
+            Err(err) => {
+                error!(target: "supervisor::service", %chain, %err, "Failed to get initiating log for chain");
+                Err(SpecError::from(err).into())
+            }
+        }
+    }
+
+    /// Returns the status of every execution of the given initiating message across the
+    /// dependency set.
+    ///
+    /// Executions whose message hash does not match `initiating_hash` are reported as
+    /// [`MessageStage::Invalidated`]. If the initiating log is unknown, the hashes are not checked.
+    fn execution_statuses(
+        &self,
+        identifier: &MessageIdentifier,
+        initiating_hash: Option<B256>,
+    ) -> Result<Vec<ExecutingMessageStatus>, SupervisorError> {
+        let mut chain_ids = self.chain_ids().collect::<Vec<_>>();
+        // Sorting chain ids for a deterministic response
+        chain_ids.sort();
+
+        let mut executions = Vec::new();
+        for chain_id in &chain_ids {
+            let db = self.get_db(*chain_id)?;
+            let refs = db.get_executing_messages(identifier).map_err(|err| {
+                error!(target: "supervisor::service", %chain_id, %err, "Failed to get executing messages for chain");
+                SpecError::from(err)
+            })?;
+
+            for exec in refs {
+                // Executions claiming a different message than the one initiated at the
+                // referenced position are invalid, and executions whose block was reorged out are
+                // kept in the index.
+                let stage = if initiating_hash.is_some_and(|hash| hash != exec.hash) {
+                    MessageStage::Invalidated
+                } else {
+                    match db.get_block(exec.block.number) {
+                        Ok(block) if block.hash == exec.block.hash => {
+                            self.safety_level_at(*chain_id, &db, exec.block.number)?.into()
+                        }
+                        Ok(_) | Err(StorageError::EntryNotFound(_)) => MessageStage::Invalidated,
+                        Err(err) => {
+                            error!(target: "supervisor::service", %chain_id, %err, "Failed to get executing block for chain");
+                            return Err(SpecError::from(err).into());
+                        }
+                    }
+                };
+
+                executions.push(ExecutingMessageStatus {
+                    chain_id: *chain_id,
+                    block: exec.block,
+                    log_index: exec.log_index,
+                    stage,
+                });
+            }
+        }
+
+        Ok(executions)
+    }
+
+    fn get_db(&self, chain: ChainId) -> Result<Arc<F::ChainDb>, SupervisorError> {
         self.database_factory.get_db(chain).map_err(|err| {
             error!(target: "supervisor::service", %chain, %err, "Failed to get database for chain");
             SpecError::from(err).into()
@@ -162,16 +484,24 @@ where
 }
 
 #[async_trait]
-impl<M> SupervisorService for Supervisor<M>
+impl<M, F> SupervisorService for Supervisor<M, F>
 where
     M: ManagedNodeDataProvider + BlockProvider + Send + Sync + Debug,
+    F: StorageFactory,
 {
     fn chain_ids(&self) -> impl Iterator<Item = ChainId> {
-        self.config.dependency_set.dependencies.keys().copied()
+        self.config
+            .dependency_set
+            .load()
+            .dependencies
+            .keys()
+            .copied()
+            .collect::<Vec<_>>()
+            .into_iter()
     }
 
-    fn dependency_set(&self) -> &DependencySet {
-        &self.config.dependency_set
+    fn dependency_set(&self) -> Arc<DependencySet> {
+        self.config.dependency_set.load()
     }
 
     fn super_head(&self, chain: ChainId) -> Result<SuperHead, SupervisorError> {
@@ -246,71 +576,40 @@ where
         &self,
         timestamp: u64,
     ) -> Result<SuperRootOutputRpc, SupervisorError> {
-        let mut chain_ids = self.config.dependency_set.dependencies.keys().collect::<Vec<_>>();
-        // Sorting chain ids for deterministic super root hash
-        chain_ids.sort();
+        Ok(self.super_root_with_outputs(&self.sorted_chain_ids(), timestamp).await?.super_root)
+    }
 
-        let mut chain_infos = Vec::<ChainRootInfoRpc>::with_capacity(chain_ids.len());
-        let mut super_root_chains = Vec::<OutputRootWithChain>::with_capacity(chain_ids.len());
-        let mut cross_safe_source = BlockNumHash::default();
+    async fn super_roots_in_range(
+        &self,
+        from: u64,
+        to: u64,
+        step: u64,
+        safety_level: SafetyLevel,
+    ) -> Result<Vec<SuperRootWithOutputsRpc>, SupervisorError> {
+        if step == 0 || from > to || (to - from) / step >= MAX_SUPER_ROOTS_PER_REQUEST {
+            return Err(SupervisorError::InvalidTimestampRange { from, to, step });
+        }
 
-        for id in chain_ids {
-            let managed_node = {
-                let guard = self.managed_nodes.read().await;
-                match guard.get(id) {
-                    Some(m) => m.clone(),
-                    None => {
-                        error!(target: "supervisor::service", chain_id = %id, "Managed node not found for chain");
-                        return Err(SupervisorError::ManagedNodeMissing(*id));
-                    }
+        let chain_ids = self.sorted_chain_ids();
+        let mut safe_timestamp = to;
+        for id in &chain_ids {
+            match self.get_db(*id)?.get_safety_head_ref(safety_level) {
+                Ok(head) => safe_timestamp = safe_timestamp.min(head.timestamp),
+                Err(StorageError::FutureData) => return Ok(Vec::new()),
+                Err(err) => {
+                    error!(target: "supervisor::service", chain_id = %id, %err, %safety_level, "Failed to get safety head ref for chain");
+                    return Err(SpecError::from(err).into());
                 }
-            };
-            let output_v0 = managed_node.output_v0_at_timestamp(timestamp).await?;
-            let output_v0_string = serde_json::to_string(&output_v0)
-                .inspect_err(|err| {
-                    error!(target: "supervisor::service", chain_id = %id, %err, "Failed to serialize output_v0 for chain");
-                })?;
-            let canonical_root = keccak256(output_v0_string.as_bytes());
-
-            let pending_output_v0 = managed_node.pending_output_v0_at_timestamp(timestamp).await?;
-            let pending_output_v0_string = serde_json::to_string(&pending_output_v0)
-                .inspect_err(|err| {
-                    error!(target: "supervisor::service", chain_id = %id, %err, "Failed to serialize pending_output_v0 for chain");
-                })?;
-            let pending_output_v0_bytes =
-                Bytes::copy_from_slice(pending_output_v0_string.as_bytes());
-
-            chain_infos.push(ChainRootInfoRpc {
-                chain_id: *id,
-                canonical: canonical_root,
-                pending: pending_output_v0_bytes,
-            });
-
-            super_root_chains
-                .push(OutputRootWithChain { chain_id: *id, output_root: canonical_root });
-
-            let l2_block = managed_node.l2_block_ref_by_timestamp(timestamp).await?;
-            let source = self
-                .derived_to_source_block(*id, l2_block.id())
-                .inspect_err(|err| {
-                    error!(target: "supervisor::service", %id, %err, "Failed to get derived to source block for chain");
-                })?;
-
-            if cross_safe_source.number == 0 || cross_safe_source.number < source.number {
-                cross_safe_source = source.id();
             }
         }
 
-        let super_root = SuperRoot { timestamp, output_roots: super_root_chains };
-        let super_root_hash = super_root.hash();
-
-        Ok(SuperRootOutputRpc {
-            cross_safe_derived_from: cross_safe_source,
-            timestamp,
-            super_root: super_root_hash,
-            chains: chain_infos,
-            version: SUPER_ROOT_VERSION,
-        })
+        let timestamps = std::iter::successors(Some(from), |timestamp| timestamp.checked_add(step))
+            .take_while(|timestamp| *timestamp <= safe_timestamp);
+        stream::iter(timestamps)
+            .map(|timestamp| self.super_root_with_outputs(&chain_ids, timestamp))
+            .buffered(MAX_SUPER_ROOTS_IN_FLIGHT)
+            .try_collect()
+            .await
     }
 
     fn check_access_list(
@@ -381,4 +680,38 @@ where
 
         Ok(())
     }
+
+    fn executing_messages(
+        &self,
+        identifier: MessageIdentifier,
+    ) -> Result<Vec<ExecutingMessageStatus>, SupervisorError> {
+        let initiating_hash = self.initiating_log_hash(&identifier)?;
+        self.execution_statuses(&identifier, initiating_hash)
+    }
+
+    fn message_lifecycle(
+        &self,
+        identifier: MessageIdentifier,
+    ) -> Result<MessageLifecycle, SupervisorError> {
+        let chain_id = identifier.chain_id;
+        let db = self.get_db(chain_id)?;
+
+        let block = db.get_block(identifier.block_number).map_err(|err| {
+            warn!(target: "supervisor::service", %chain_id, %err, "Failed to get initiating block for chain");
+            SpecError::from(err)
+        })?;
+        let log = db.get_log(identifier.block_number, identifier.log_index).map_err(|err| {
+            warn!(target: "supervisor::service", %chain_id, %err, "Failed to get initiating log for chain");
+            SpecError::from(err)
+        })?;
+
+        let initiating = InitiatingMessageStatus {
+            block: block.id(),
+            log_hash: log.hash,
+            safety: self.safety_level_at(chain_id, &db, block.number)?,
+        };
+        let executions = self.execution_statuses(&identifier, Some(log.hash))?;
+
+        Ok(MessageLifecycle::new(identifier, initiating, executions))
+    }
 }
@@ -23,6 +23,14 @@ pub enum ManagedNodeError {
     /// Represents an error that occurred while resetting the managed node.
     #[error("failed to reset the managed node")]
     ResetFailed,
+
+    /// No managed node is available for the chain.
+    #[error("no managed node available")]
+    NoManagedNode,
+
+    /// A managed node with the same URL is already attached to the chain.
+    #[error("managed node already exists: {0}")]
+    DuplicateNode(String),
 }
 
 /// Error establishing authenticated connection to managed node.
@@ -19,5 +19,11 @@ pub use traits::{
 mod client;
 pub use client::{Client, ClientConfig, ManagedNodeClient};
 
+mod pool;
+pub use pool::{
+    DEFAULT_MAX_LAG, DEFAULT_MIRROR_TIMEOUT, ManagedNodePool, ManagedNodeStatus, NodeHealth,
+    PoolMember,
+};
+
 pub(super) mod metrics;
 pub(super) mod resetter;
@@ -0,0 +1,688 @@
+//! [`ManagedNodePool`] for attaching several managed nodes to a single chain.
+
+use super::{
+    BlockProvider, ManagedNodeController, ManagedNodeDataProvider, ManagedNodeError,
+    SubscriptionHandler,
+};
+use alloy_primitives::{B256, ChainId};
+use alloy_rpc_types_eth::BlockNumHash;
+use async_trait::async_trait;
+use futures::future::join_all;
+use kona_interop::{BlockReplacement, DerivedRefPair};
+use kona_protocol::BlockInfo;
+use kona_supervisor_types::{BlockSeal, OutputV0, Receipts};
+use std::{
+    fmt::Debug,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+use tracing::{info, warn};
+
+/// Default number of blocks the primary node may lag behind the most advanced node of its pool
+/// before the pool fails over to another node.
+pub const DEFAULT_MAX_LAG: u64 = 64;
+
+/// Default time a secondary node is given to complete a mirrored command before it is abandoned.
+pub const DEFAULT_MIRROR_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Health of a node in a [`ManagedNodePool`], as observed through its event subscription.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NodeHealth {
+    /// Whether the event subscription to the node is active.
+    pub subscribed: bool,
+    /// Number of the latest unsafe block reported by the node.
+    pub unsafe_head: u64,
+    /// Number of the latest local safe block reported by the node.
+    pub local_safe: u64,
+}
+
+impl NodeHealth {
+    /// Returns the sort key used to rank nodes when electing a primary.
+    const fn rank(&self) -> (bool, u64, u64) {
+        (self.subscribed, self.local_safe, self.unsafe_head)
+    }
+}
+
+/// Status of a node in a [`ManagedNodePool`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManagedNodeStatus {
+    /// The URL identifying the node.
+    pub url: String,
+    /// Whether the node is the primary of its pool.
+    pub primary: bool,
+    /// The observed health of the node.
+    pub health: NodeHealth,
+}
+
+#[derive(Debug)]
+struct PoolEntry<N> {
+    url: String,
+    node: Arc<N>,
+    health: NodeHealth,
+}
+
+#[derive(Debug)]
+struct PoolState<N> {
+    entries: Vec<PoolEntry<N>>,
+    primary: Option<String>,
+}
+
+impl<N> PoolState<N> {
+    fn entry(&self, url: &str) -> Option<&PoolEntry<N>> {
+        self.entries.iter().find(|entry| entry.url == url)
+    }
+
+    fn primary(&self) -> Option<&PoolEntry<N>> {
+        self.primary.as_deref().and_then(|url| self.entry(url))
+    }
+
+    /// Re-elects the primary if the current one is missing, unsubscribed or lagging more than
+    /// `max_lag` blocks behind the most advanced node. Returns the URL of the new primary, if it
+    /// changed.
+    fn elect(&mut self, max_lag: u64) -> Option<String> {
+        let best = self.entries.iter().max_by_key(|entry| entry.health.rank())?;
+        let best_unsafe = self.entries.iter().map(|entry| entry.health.unsafe_head).max()?;
+
+        let replace = match self.primary() {
+            None => true,
+            Some(primary) if primary.url == best.url => false,
+            Some(primary) => {
+                (!primary.health.subscribed && best.health.subscribed) ||
+                    best_unsafe.saturating_sub(primary.health.unsafe_head) > max_lag
+            }
+        };
+        if !replace {
+            return None;
+        }
+
+        let url = best.url.clone();
+        self.primary = Some(url.clone());
+        Some(url)
+    }
+}
+
+/// A pool of managed nodes serving the same chain.
+///
+/// One node of the pool is the primary: data requests are served by the primary, and only the
+/// primary's events are forwarded to the chain processor. Control commands are mirrored to every
+/// node of the pool so that the secondaries stay in sync with the supervisor and can take over at
+/// any time.
+///
+/// The pool fails over to the healthiest secondary when the primary's event subscription drops or
+/// when it lags too far behind. The new primary is reset to the supervisor's view of the chain so
+/// that its events pick up where the previous primary left off.
+#[derive(Debug)]
+pub struct ManagedNodePool<N> {
+    chain_id: ChainId,
+    max_lag: u64,
+    mirror_timeout: Duration,
+    state: RwLock<PoolState<N>>,
+}
+
+impl<N> ManagedNodePool<N>
+where
+    N: ManagedNodeController + Send + Sync + 'static,
+{
+    /// Creates a new, empty [`ManagedNodePool`] for the given chain.
+    pub const fn new(chain_id: ChainId) -> Self {
+        Self {
+            chain_id,
+            max_lag: DEFAULT_MAX_LAG,
+            mirror_timeout: DEFAULT_MIRROR_TIMEOUT,
+            state: RwLock::new(PoolState { entries: Vec::new(), primary: None }),
+        }
+    }
+
+    /// Sets the number of blocks the primary may lag behind before the pool fails over.
+    pub const fn with_max_lag(mut self, max_lag: u64) -> Self {
+        self.max_lag = max_lag;
+        self
+    }
+
+    /// Sets the time a secondary node is given to complete a mirrored command.
+    pub const fn with_mirror_timeout(mut self, mirror_timeout: Duration) -> Self {
+        self.mirror_timeout = mirror_timeout;
+        self
+    }
+
+    /// Returns the chain ID served by the pool.
+    pub const fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
+    /// Adds a node to the pool, returning the [`PoolMember`] that handles its events.
+    ///
+    /// The first node added to an empty pool becomes its primary.
+    pub fn add_node(
+        self: &Arc<Self>,
+        url: String,
+        node: Arc<N>,
+    ) -> Result<Arc<PoolMember<N>>, ManagedNodeError> {
+        let mut state = self.state.write().expect("pool lock poisoned");
+        if state.entry(&url).is_some() {
+            return Err(ManagedNodeError::DuplicateNode(url));
+        }
+
+        state.entries.push(PoolEntry {
+            url: url.clone(),
+            node: node.clone(),
+            health: NodeHealth::default(),
+        });
+        if state.primary.is_none() {
+            info!(target: "supervisor::node_pool", chain_id = self.chain_id, %url, "Primary managed node elected");
+            state.primary = Some(url.clone());
+        }
+
+        Ok(Arc::new(PoolMember { url, node, pool: self.clone() }))
+    }
+
+    /// Removes a node from the pool, returning whether it was part of it.
+    ///
+    /// If the node was the primary, a new primary is elected among the remaining nodes.
+    pub async fn remove_node(&self, url: &str) -> bool {
+        let promoted = {
+            let mut state = self.state.write().expect("pool lock poisoned");
+            let len = state.entries.len();
+            state.entries.retain(|entry| entry.url != url);
+            if state.entries.len() == len {
+                return false;
+            }
+            if state.primary.as_deref() == Some(url) {
+                state.primary = None;
+            }
+            self.elect(&mut state)
+        };
+
+        if let Some(node) = promoted {
+            self.promote(node).await;
+        }
+        true
+    }
+
+    /// Returns whether the pool has no node.
+    pub fn is_empty(&self) -> bool {
+        self.state.read().expect("pool lock poisoned").entries.is_empty()
+    }
+
+    /// Returns the status of every node in the pool.
+    pub fn nodes(&self) -> Vec<ManagedNodeStatus> {
+        let state = self.state.read().expect("pool lock poisoned");
+        state
+            .entries
+            .iter()
+            .map(|entry| ManagedNodeStatus {
+                url: entry.url.clone(),
+                primary: state.primary.as_deref() == Some(entry.url.as_str()),
+                health: entry.health,
+            })
+            .collect()
+    }
+
+    /// Returns the URL of the primary node, if any.
+    pub fn primary_url(&self) -> Option<String> {
+        self.state.read().expect("pool lock poisoned").primary.clone()
+    }
+
+    fn primary(&self) -> Result<Arc<N>, ManagedNodeError> {
+        let state = self.state.read().expect("pool lock poisoned");
+        state.primary().map(|entry| entry.node.clone()).ok_or(ManagedNodeError::NoManagedNode)
+    }
+
+    fn is_primary(&self, url: &str) -> bool {
+        self.state.read().expect("pool lock poisoned").primary.as_deref() == Some(url)
+    }
+
+    /// Returns every node of the pool, primary first.
+    fn all(&self) -> Vec<(String, Arc<N>)> {
+        let state = self.state.read().expect("pool lock poisoned");
+        let mut nodes: Vec<_> =
+            state.entries.iter().map(|entry| (entry.url.clone(), entry.node.clone())).collect();
+        nodes.sort_by_key(|(url, _)| state.primary.as_deref() != Some(url.as_str()));
+        nodes
+    }
+
+    fn elect(&self, state: &mut PoolState<N>) -> Option<Arc<N>> {
+        let previous = state.primary.clone();
+        let url = state.elect(self.max_lag)?;
+        warn!(
+            target: "supervisor::node_pool",
+            chain_id = self.chain_id,
+            ?previous,
+            primary = %url,
+            "Managed node failover"
+        );
+        state.entry(&url).map(|entry| entry.node.clone())
+    }
+
+    /// Updates the health of a node, failing over if the primary became unhealthy.
+    async fn update_health(&self, url: &str, update: impl FnOnce(&mut NodeHealth)) {
+        let promoted = {
+            let mut state = self.state.write().expect("pool lock poisoned");
+            let Some(entry) = state.entries.iter_mut().find(|entry| entry.url == url) else {
+                return;
+            };
+            update(&mut entry.health);
+            self.elect(&mut state)
+        };
+
+        if let Some(node) = promoted {
+            self.promote(node).await;
+        }
+    }
+
+    /// Resets a newly elected primary to the supervisor's state.
+    async fn promote(&self, node: Arc<N>) {
+        if let Err(err) = node.reset().await {
+            warn!(target: "supervisor::node_pool", chain_id = self.chain_id, %err, "Failed to reset new primary managed node");
+        }
+    }
+
+    /// Runs a command on every node of the pool concurrently, returning the primary's result.
+    ///
+    /// Secondaries that fail or do not complete within the mirror timeout are logged and
+    /// otherwise ignored, so a slow or unreachable secondary never holds up the primary.
+    async fn mirror<F, Fut>(&self, command: F) -> Result<(), ManagedNodeError>
+    where
+        F: Fn(Arc<N>) -> Fut,
+        Fut: Future<Output = Result<(), ManagedNodeError>>,
+    {
+        let mut nodes = self.all().into_iter();
+        let (_, primary) = nodes.next().ok_or(ManagedNodeError::NoManagedNode)?;
+
+        let secondaries = join_all(nodes.map(|(url, node)| {
+            let command = tokio::time::timeout(self.mirror_timeout, command(node));
+            async move {
+                match command.await {
+                    Ok(Ok(())) => {}
+                    Ok(Err(err)) => {
+                        warn!(target: "supervisor::node_pool", chain_id = self.chain_id, %url, %err, "Failed to mirror command to secondary managed node");
+                    }
+                    Err(_) => {
+                        warn!(target: "supervisor::node_pool", chain_id = self.chain_id, %url, timeout = ?self.mirror_timeout, "Timed out mirroring command to secondary managed node");
+                    }
+                }
+            }
+        }));
+
+        let (result, _) = tokio::join!(command(primary), secondaries);
+        result
+    }
+}
+
+#[async_trait]
+impl<N> BlockProvider for ManagedNodePool<N>
+where
+    N: BlockProvider + ManagedNodeController + Send + Sync + 'static,
+{
+    async fn fetch_receipts(&self, block_hash: B256) -> Result<Receipts, ManagedNodeError> {
+        self.primary()?.fetch_receipts(block_hash).await
+    }
+
+    async fn block_by_number(&self, number: u64) -> Result<BlockInfo, ManagedNodeError> {
+        self.primary()?.block_by_number(number).await
+    }
+}
+
+#[async_trait]
+impl<N> ManagedNodeDataProvider for ManagedNodePool<N>
+where
+    N: ManagedNodeDataProvider + ManagedNodeController + Send + Sync + 'static,
+{
+    async fn output_v0_at_timestamp(&self, timestamp: u64) -> Result<OutputV0, ManagedNodeError> {
+        self.primary()?.output_v0_at_timestamp(timestamp).await
+    }
+
+    async fn pending_output_v0_at_timestamp(
+        &self,
+        timestamp: u64,
+    ) -> Result<OutputV0, ManagedNodeError> {
+        self.primary()?.pending_output_v0_at_timestamp(timestamp).await
+    }
+
+    async fn l2_block_ref_by_timestamp(
+        &self,
+        timestamp: u64,
+    ) -> Result<BlockInfo, ManagedNodeError> {
+        self.primary()?.l2_block_ref_by_timestamp(timestamp).await
+    }
+}
+
+#[async_trait]
+impl<N> ManagedNodeController for ManagedNodePool<N>
+where
+    N: ManagedNodeController + Send + Sync + 'static,
+{
+    async fn update_finalized(
+        &self,
+        finalized_block_id: BlockNumHash,
+    ) -> Result<(), ManagedNodeError> {
+        self.mirror(|node| async move { node.update_finalized(finalized_block_id).await }).await
+    }
+
+    async fn update_cross_unsafe(
+        &self,
+        cross_unsafe_block_id: BlockNumHash,
+    ) -> Result<(), ManagedNodeError> {
+        self.mirror(|node| async move { node.update_cross_unsafe(cross_unsafe_block_id).await })
+            .await
+    }
+
+    async fn update_cross_safe(
+        &self,
+        source_block_id: BlockNumHash,
+        derived_block_id: BlockNumHash,
+    ) -> Result<(), ManagedNodeError> {
+        self.mirror(|node| async move {
+            node.update_cross_safe(source_block_id, derived_block_id).await
+        })
+        .await
+    }
+
+    async fn reset(&self) -> Result<(), ManagedNodeError> {
+        self.mirror(|node| async move { node.reset().await }).await
+    }
+
+    async fn invalidate_block(&self, seal: BlockSeal) -> Result<(), ManagedNodeError> {
+        self.mirror(|node| {
+            let seal = seal.clone();
+            async move { node.invalidate_block(seal).await }
+        })
+        .await
+    }
+}
+
+/// Handles the events of a single node of a [`ManagedNodePool`].
+///
+/// Every event is used to track the health of the node. Requests addressed to the node itself,
+/// such as resets and L1 exhaustion, are always handled; events that update the supervisor's view
+/// of the chain are only forwarded while the node is the primary of its pool.
+#[derive(Debug)]
+pub struct PoolMember<N> {
+    url: String,
+    node: Arc<N>,
+    pool: Arc<ManagedNodePool<N>>,
+}
+
+impl<N> PoolMember<N>
+where
+    N: ManagedNodeController + Send + Sync + 'static,
+{
+    /// Returns the URL of the node.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns whether the node is the primary of its pool.
+    pub fn is_primary(&self) -> bool {
+        self.pool.is_primary(&self.url)
+    }
+}
+
+#[async_trait]
+impl<N> SubscriptionHandler for PoolMember<N>
+where
+    N: SubscriptionHandler + ManagedNodeController + Send + Sync + Debug + 'static,
+{
+    async fn handle_exhaust_l1(
+        &self,
+        derived_ref_pair: &DerivedRefPair,
+    ) -> Result<(), ManagedNodeError> {
+        self.node.handle_exhaust_l1(derived_ref_pair).await
+    }
+
+    async fn handle_reset(&self, reset_id: &str) -> Result<(), ManagedNodeError> {
+        self.node.handle_reset(reset_id).await
+    }
+
+    async fn handle_unsafe_block(&self, block: &BlockInfo) -> Result<(), ManagedNodeError> {
+        let number = block.number;
+        self.pool.update_health(&self.url, |health| health.unsafe_head = number).await;
+        if !self.is_primary() {
+            return Ok(());
+        }
+        self.node.handle_unsafe_block(block).await
+    }
+
+    async fn handle_derivation_update(
+        &self,
+        derived_ref_pair: &DerivedRefPair,
+    ) -> Result<(), ManagedNodeError> {
+        let number = derived_ref_pair.derived.number;
+        self.pool.update_health(&self.url, |health| health.local_safe = number).await;
+        if !self.is_primary() {
+            return Ok(());
+        }
+        self.node.handle_derivation_update(derived_ref_pair).await
+    }
+
+    async fn handle_replace_block(
+        &self,
+        replacement: &BlockReplacement,
+    ) -> Result<(), ManagedNodeError> {
+        if !self.is_primary() {
+            return Ok(());
+        }
+        self.node.handle_replace_block(replacement).await
+    }
+
+    async fn handle_derivation_origin_update(
+        &self,
+        origin: &BlockInfo,
+    ) -> Result<(), ManagedNodeError> {
+        if !self.is_primary() {
+            return Ok(());
+        }
+        self.node.handle_derivation_origin_update(origin).await
+    }
+
+    async fn handle_subscription_state(&self, active: bool) {
+        self.pool.update_health(&self.url, |health| health.subscribed = active).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        #[derive(Debug)]
+        pub Node {}
+
+        #[async_trait]
+        impl ManagedNodeController for Node {
+            async fn update_finalized(&self, finalized_block_id: BlockNumHash) -> Result<(), ManagedNodeError>;
+            async fn update_cross_unsafe(&self, cross_unsafe_block_id: BlockNumHash) -> Result<(), ManagedNodeError>;
+            async fn update_cross_safe(&self, source_block_id: BlockNumHash, derived_block_id: BlockNumHash) -> Result<(), ManagedNodeError>;
+            async fn reset(&self) -> Result<(), ManagedNodeError>;
+            async fn invalidate_block(&self, seal: BlockSeal) -> Result<(), ManagedNodeError>;
+        }
+
+        #[async_trait]
+        impl SubscriptionHandler for Node {
+            async fn handle_exhaust_l1(&self, derived_ref_pair: &DerivedRefPair) -> Result<(), ManagedNodeError>;
+            async fn handle_reset(&self, reset_id: &str) -> Result<(), ManagedNodeError>;
+            async fn handle_unsafe_block(&self, block: &BlockInfo) -> Result<(), ManagedNodeError>;
+            async fn handle_derivation_update(&self, derived_ref_pair: &DerivedRefPair) -> Result<(), ManagedNodeError>;
+            async fn handle_replace_block(&self, replacement: &BlockReplacement) -> Result<(), ManagedNodeError>;
+            async fn handle_derivation_origin_update(&self, origin: &BlockInfo) -> Result<(), ManagedNodeError>;
+        }
+    }
+
+    fn block(number: u64) -> BlockInfo {
+        BlockInfo { number, ..Default::default() }
+    }
+
+    #[tokio::test]
+    async fn test_first_node_is_primary_and_duplicates_are_rejected() {
+        let pool = Arc::new(ManagedNodePool::new(1));
+        pool.add_node("a".to_string(), Arc::new(MockNode::new())).unwrap();
+        pool.add_node("b".to_string(), Arc::new(MockNode::new())).unwrap();
+
+        assert_eq!(pool.primary_url().as_deref(), Some("a"));
+        assert_eq!(
+            pool.add_node("a".to_string(), Arc::new(MockNode::new())).unwrap_err(),
+            ManagedNodeError::DuplicateNode("a".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_only_primary_events_are_forwarded() {
+        let pool = Arc::new(ManagedNodePool::new(1));
+
+        let mut primary = MockNode::new();
+        primary.expect_handle_unsafe_block().times(1).returning(|_| Ok(()));
+        let mut secondary = MockNode::new();
+        secondary.expect_handle_unsafe_block().never();
+
+        let primary = pool.add_node("a".to_string(), Arc::new(primary)).unwrap();
+        let secondary = pool.add_node("b".to_string(), Arc::new(secondary)).unwrap();
+        primary.handle_subscription_state(true).await;
+        secondary.handle_subscription_state(true).await;
+
+        primary.handle_unsafe_block(&block(10)).await.unwrap();
+        secondary.handle_unsafe_block(&block(10)).await.unwrap();
+
+        let nodes = pool.nodes();
+        assert!(nodes.iter().all(|node| node.health.unsafe_head == 10));
+    }
+
+    #[tokio::test]
+    async fn test_failover_on_subscription_drop() {
+        let pool = Arc::new(ManagedNodePool::new(1));
+
+        let primary = pool.add_node("a".to_string(), Arc::new(MockNode::new())).unwrap();
+        let mut secondary = MockNode::new();
+        secondary.expect_reset().times(1).returning(|| Ok(()));
+        let secondary = pool.add_node("b".to_string(), Arc::new(secondary)).unwrap();
+
+        primary.handle_subscription_state(true).await;
+        secondary.handle_subscription_state(true).await;
+        assert!(primary.is_primary());
+
+        primary.handle_subscription_state(false).await;
+        assert!(secondary.is_primary());
+        assert_eq!(pool.primary_url().as_deref(), Some("b"));
+    }
+
+    #[tokio::test]
+    async fn test_failover_on_lag() {
+        let pool = Arc::new(ManagedNodePool::new(1).with_max_lag(5));
+
+        let mut primary = MockNode::new();
+        primary.expect_handle_unsafe_block().returning(|_| Ok(()));
+        let primary = pool.add_node("a".to_string(), Arc::new(primary)).unwrap();
+
+        let mut secondary = MockNode::new();
+        secondary.expect_reset().times(1).returning(|| Ok(()));
+        secondary.expect_handle_unsafe_block().returning(|_| Ok(()));
+        let secondary = pool.add_node("b".to_string(), Arc::new(secondary)).unwrap();
+
+        primary.handle_subscription_state(true).await;
+        secondary.handle_subscription_state(true).await;
+
+        primary.handle_unsafe_block(&block(10)).await.unwrap();
+        secondary.handle_unsafe_block(&block(15)).await.unwrap();
+        assert!(primary.is_primary(), "lag within bounds must not fail over");
+
+        secondary.handle_unsafe_block(&block(16)).await.unwrap();
+        assert!(secondary.is_primary());
+    }
+
+    #[tokio::test]
+    async fn test_commands_are_mirrored() {
+        let pool = Arc::new(ManagedNodePool::new(1));
+
+        let mut primary = MockNode::new();
+        primary.expect_update_finalized().times(1).returning(|_| Ok(()));
+        let mut secondary = MockNode::new();
+        secondary
+            .expect_update_finalized()
+            .times(1)
+            .returning(|_| Err(ManagedNodeError::ResetFailed));
+
+        pool.add_node("a".to_string(), Arc::new(primary)).unwrap();
+        pool.add_node("b".to_string(), Arc::new(secondary)).unwrap();
+
+        // Failures of secondaries do not fail the command.
+        pool.update_finalized(BlockNumHash::default()).await.unwrap();
+    }
+
+    /// A node whose commands either succeed immediately or never complete.
+    #[derive(Debug)]
+    struct SlowNode {
+        hang: bool,
+    }
+
+    impl SlowNode {
+        async fn respond(&self) -> Result<(), ManagedNodeError> {
+            if self.hang {
+                std::future::pending::<()>().await;
+            }
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ManagedNodeController for SlowNode {
+        async fn update_finalized(&self, _: BlockNumHash) -> Result<(), ManagedNodeError> {
+            self.respond().await
+        }
+
+        async fn update_cross_unsafe(&self, _: BlockNumHash) -> Result<(), ManagedNodeError> {
+            self.respond().await
+        }
+
+        async fn update_cross_safe(
+            &self,
+            _: BlockNumHash,
+            _: BlockNumHash,
+        ) -> Result<(), ManagedNodeError> {
+            self.respond().await
+        }
+
+        async fn reset(&self) -> Result<(), ManagedNodeError> {
+            self.respond().await
+        }
+
+        async fn invalidate_block(&self, _: BlockSeal) -> Result<(), ManagedNodeError> {
+            self.respond().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hanging_secondaries_time_out_concurrently() {
+        let timeout = Duration::from_millis(200);
+        let pool = Arc::new(ManagedNodePool::new(1).with_mirror_timeout(timeout));
+        pool.add_node("a".to_string(), Arc::new(SlowNode { hang: false })).unwrap();
+        pool.add_node("b".to_string(), Arc::new(SlowNode { hang: true })).unwrap();
+        pool.add_node("c".to_string(), Arc::new(SlowNode { hang: true })).unwrap();
+
+        // Both secondaries are abandoned after a single timeout, and the primary's result wins.
+        let start = std::time::Instant::now();
+        pool.update_finalized(BlockNumHash::default()).await.unwrap();
+        let elapsed = start.elapsed();
+        assert!(elapsed >= timeout);
+        assert!(elapsed < timeout * 2, "secondaries must be awaited concurrently");
+    }
+
+    #[tokio::test]
+    async fn test_remove_primary_elects_new_primary() {
+        let pool = Arc::new(ManagedNodePool::new(1));
+        pool.add_node("a".to_string(), Arc::new(MockNode::new())).unwrap();
+        let mut secondary = MockNode::new();
+        secondary.expect_reset().times(1).returning(|| Ok(()));
+        pool.add_node("b".to_string(), Arc::new(secondary)).unwrap();
+
+        assert!(pool.remove_node("a").await);
+        assert!(!pool.remove_node("a").await);
+        assert_eq!(pool.primary_url().as_deref(), Some("b"));
+
+        assert!(pool.remove_node("b").await);
+        assert!(pool.is_empty());
+        assert_eq!(
+            pool.update_finalized(BlockNumHash::default()).await.unwrap_err(),
+            ManagedNodeError::NoManagedNode
+        );
+    }
+}
@@ -39,6 +39,11 @@ pub trait SubscriptionHandler: Send + Sync {
         &self,
         origin: &BlockInfo,
     ) -> Result<(), ManagedNodeError>;
+
+    /// Handles a change in the state of the event subscription to the node.
+    ///
+    /// Does nothing by default.
+    async fn handle_subscription_state(&self, _active: bool) {}
 }
 
 /// [`BlockProvider`] abstracts fetching blocks and receipts for a given block.
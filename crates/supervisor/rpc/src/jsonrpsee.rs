@@ -5,7 +5,10 @@ pub use jsonrpsee::{
     types::{ErrorCode, ErrorObjectOwned},
 };
 
-use crate::{SuperRootOutputRpc, SupervisorSyncStatus};
+use crate::{
+    ChainEventInfo, ExecutingMessageStatus, ManagedNodeInfo, MessageLifecycle, SafetyEvent,
+    SuperRootOutputRpc, SuperRootWithOutputsRpc, SupervisorSyncStatus,
+};
 use alloy_eips::BlockNumHash;
 use alloy_primitives::{B256, BlockHash, ChainId, map::HashMap};
 use jsonrpsee::proc_macros::rpc;
@@ -13,7 +16,9 @@ use kona_interop::{
     DependencySet, DerivedIdPair, DerivedRefPair, ExecutingDescriptor, ManagedEvent, SafetyLevel,
 };
 use kona_protocol::BlockInfo;
-use kona_supervisor_types::{BlockSeal, HexStringU64, OutputV0, Receipts, SubscriptionEvent};
+use kona_supervisor_types::{
+    BlockSeal, HexStringU64, MessageIdentifier, OutputV0, Receipts, SubscriptionEvent,
+};
 use serde::{Deserialize, Serialize};
 
 /// Supervisor API for interop.
@@ -87,6 +92,23 @@ pub trait SupervisorApi {
         timestamp: HexStringU64,
     ) -> RpcResult<SuperRootOutputRpc>;
 
+    /// Returns the super roots at the timestamps `from`, `from + step`, ... up to `to`
+    /// (inclusive), along with the [`OutputV0`] preimages of all chains. `step` defaults to one
+    /// second.
+    ///
+    /// Timestamps past the head of the given [`SafetyLevel`] on any chain are omitted, so every
+    /// returned super root is at least at that level. At most
+    /// [`MAX_SUPER_ROOTS_PER_REQUEST`](crate::MAX_SUPER_ROOTS_PER_REQUEST) timestamps can be
+    /// requested at once.
+    #[method(name = "superRootsInRange")]
+    async fn super_roots_in_range(
+        &self,
+        from: HexStringU64,
+        to: HexStringU64,
+        safety_level: SafetyLevel,
+        step: Option<HexStringU64>,
+    ) -> RpcResult<Vec<SuperRootWithOutputsRpc>>;
+
     /// Verifies if an access-list references only valid messages w.r.t. locally configured minimum
     /// [`SafetyLevel`].
     #[method(name = "checkAccessList")]
@@ -121,6 +143,20 @@ pub trait SupervisorApi {
     /// TODO: Replace the link above after the PR is merged.
     #[method(name = "dependencySetV1")]
     async fn dependency_set_v1(&self) -> RpcResult<DependencySet>;
+
+    /// Returns the lifecycle of the message initiated at the given [`MessageIdentifier`],
+    /// including every known execution of it across the dependency set.
+    #[method(name = "messageLifecycle")]
+    async fn message_lifecycle(&self, identifier: MessageIdentifier)
+    -> RpcResult<MessageLifecycle>;
+
+    /// Returns all executing messages across the dependency set that reference the message
+    /// initiated at the given [`MessageIdentifier`].
+    #[method(name = "executingMessages")]
+    async fn executing_messages(
+        &self,
+        identifier: MessageIdentifier,
+    ) -> RpcResult<Vec<ExecutingMessageStatus>>;
 }
 
 /// Supervisor API for admin operations.
@@ -130,6 +166,69 @@ pub trait SupervisorAdminApi {
     /// Adds L2RPC to the supervisor.
     #[method(name = "addL2RPC")]
     async fn add_l2_rpc(&self, url: String, jwt_secret: String) -> RpcResult<()>;
+
+    /// Removes the L2RPC with the given URL from the supervisor.
+    #[method(name = "removeL2RPC")]
+    async fn remove_l2_rpc(&self, url: String) -> RpcResult<()>;
+
+    /// Lists the L2RPCs attached to the supervisor, for every chain.
+    #[method(name = "listL2RPCs")]
+    async fn list_l2_rpcs(&self) -> RpcResult<Vec<ManagedNodeInfo>>;
+
+    /// Reloads the dependency set from the file the supervisor was started with.
+    #[method(name = "reloadDependencySet")]
+    async fn reload_dependency_set(&self) -> RpcResult<()>;
+
+    /// Pauses the processing of events for the given chain. Events keep queueing up until the
+    /// chain is resumed.
+    #[method(name = "pauseChain")]
+    async fn pause_chain(&self, chain_id: HexStringU64) -> RpcResult<()>;
+
+    /// Resumes the processing of events for the given chain.
+    #[method(name = "resumeChain")]
+    async fn resume_chain(&self, chain_id: HexStringU64) -> RpcResult<()>;
+
+    /// Rewinds the storage of the given chain to the given derived block (inclusive), and resets
+    /// the managed nodes of the chain to the rewound state. The events queued for the chain are
+    /// dropped, since they describe the chain before the rewind.
+    #[method(name = "rewindChain")]
+    async fn rewind_chain(&self, chain_id: HexStringU64, block_id: BlockNumHash) -> RpcResult<()>;
+
+    /// Resets the managed nodes of the given chain to the supervisor's state.
+    #[method(name = "resetL2RPCs")]
+    async fn reset_l2_rpcs(&self, chain_id: HexStringU64) -> RpcResult<()>;
+
+    /// Lists the events queued for processing for the given chain.
+    #[method(name = "pendingChainEvents")]
+    async fn pending_chain_events(&self, chain_id: HexStringU64) -> RpcResult<Vec<ChainEventInfo>>;
+}
+
+/// Supervisor API streaming the safety events and super roots of all chains.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "supervisor"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "supervisor"))]
+pub trait SupervisorEventsApi {
+    /// Subscribes to cross-unsafe and cross-safe promotions, invalidated and replaced blocks, and
+    /// finalized L1 updates of all chains, as [`SafetyEvent`]s.
+    ///
+    /// Events are replayed starting at `from_sequence` if set, as long as the supervisor still
+    /// retains them. Otherwise only new events are delivered.
+    #[subscription(
+        name = "subscribeSafetyEvents" => "safetyEvent",
+        item = SafetyEvent,
+        unsubscribe = "unsubscribeSafetyEvents"
+    )]
+    async fn subscribe_safety_events(&self, from_sequence: Option<u64>) -> SubscriptionResult;
+
+    /// Subscribes to the cross-safe super root, as [`SuperRootWithOutputsRpc`]s.
+    ///
+    /// A super root is delivered on subscription and whenever the cross-safe heads advance to a
+    /// later timestamp on all chains, at the latest timestamp that is cross-safe on all chains.
+    #[subscription(
+        name = "subscribeSuperRoots" => "superRoot",
+        item = SuperRootWithOutputsRpc,
+        unsubscribe = "unsubscribeSuperRoots"
+    )]
+    async fn subscribe_super_roots(&self) -> SubscriptionResult;
 }
 
 /// Represents the topics for subscriptions in the Managed Mode API.
@@ -3,9 +3,11 @@
 #[cfg(feature = "jsonrpsee")]
 pub mod jsonrpsee;
 #[cfg(all(feature = "jsonrpsee", feature = "client"))]
-pub use jsonrpsee::{ManagedModeApiClient, SupervisorAdminApiClient, SupervisorApiClient};
+pub use jsonrpsee::{
+    ManagedModeApiClient, SupervisorAdminApiClient, SupervisorApiClient, SupervisorEventsApiClient,
+};
 #[cfg(feature = "jsonrpsee")]
-pub use jsonrpsee::{SupervisorAdminApiServer, SupervisorApiServer};
+pub use jsonrpsee::{SupervisorAdminApiServer, SupervisorApiServer, SupervisorEventsApiServer};
 
 #[cfg(feature = "server")]
 pub mod config;
@@ -24,7 +26,10 @@ pub use reqwest::{CheckAccessListClient, SupervisorClient, SupervisorClientError
 
 pub mod response;
 pub use response::{
-    ChainRootInfoRpc, SuperRootOutputRpc, SupervisorChainSyncStatus, SupervisorSyncStatus,
+    ChainEventInfo, ChainOutputRpc, ChainRootInfoRpc, ExecutingMessageStatus,
+    InitiatingMessageStatus, MAX_SUPER_ROOTS_PER_REQUEST, ManagedNodeInfo, MessageLifecycle,
+    MessageStage, SafetyEvent, SafetyEventKind, SuperRootOutputRpc, SuperRootWithOutputsRpc,
+    SupervisorChainSyncStatus, SupervisorSyncStatus,
 };
 
 pub use kona_protocol::BlockInfo;
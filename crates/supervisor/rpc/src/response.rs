@@ -3,7 +3,8 @@
 use alloy_eips::BlockNumHash;
 use alloy_primitives::{B256, Bytes, ChainId, map::HashMap};
 use kona_protocol::BlockInfo;
-use kona_supervisor_types::SuperHead;
+use kona_supervisor_types::{MessageIdentifier, OutputV0, SuperHead};
+use op_alloy_consensus::interop::SafetyLevel;
 use serde::{Deserialize, Serialize, Serializer};
 
 /// Describes superchain sync status.
@@ -130,6 +131,237 @@ pub struct SuperRootOutputRpc {
     pub chains: Vec<ChainRootInfoRpc>,
 }
 
+/// Maximum number of timestamps that can be requested by a single
+/// [`super_roots_in_range`](crate::jsonrpsee::SupervisorApiServer::super_roots_in_range) call.
+pub const MAX_SUPER_ROOTS_PER_REQUEST: u64 = 1024;
+
+/// The [`OutputV0`] preimages of a chain's output roots in a [`SuperRootWithOutputsRpc`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainOutputRpc {
+    /// The chain ID.
+    #[serde(rename = "chainID", with = "alloy_serde::quantity")]
+    pub chain_id: ChainId,
+    /// The number of the latest block at or before the timestamp of the super root.
+    #[serde(with = "alloy_serde::quantity")]
+    pub block_number: u64,
+    /// The preimage of the canonical output root.
+    pub output: OutputV0,
+    /// The preimage of the pending output root.
+    pub pending: OutputV0,
+}
+
+/// A [`SuperRootOutputRpc`] along with the [`OutputV0`] preimages of all chains, so that it can be
+/// verified against the L2 nodes.
+///
+/// Returned by the
+/// [`super_roots_in_range`](crate::jsonrpsee::SupervisorApiServer::super_roots_in_range) RPC.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuperRootWithOutputsRpc {
+    /// The super root.
+    #[serde(flatten)]
+    pub super_root: SuperRootOutputRpc,
+    /// The output preimages of each chain, in the order of
+    /// [`chains`](SuperRootOutputRpc::chains).
+    pub outputs: Vec<ChainOutputRpc>,
+}
+
+/// Describes a managed node attached to the supervisor.
+///
+/// Returned by the [`list_l2_rpcs`](crate::jsonrpsee::SupervisorAdminApiServer::list_l2_rpcs) RPC.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedNodeInfo {
+    /// The chain ID served by the node.
+    #[serde(rename = "chainID", with = "alloy_serde::quantity")]
+    pub chain_id: ChainId,
+    /// The RPC URL of the node.
+    pub url: String,
+    /// Whether the node is the primary node of its chain.
+    pub primary: bool,
+    /// Whether the event subscription to the node is active.
+    pub subscribed: bool,
+    /// Number of the latest unsafe block reported by the node.
+    pub unsafe_head: u64,
+    /// Number of the latest local safe block reported by the node.
+    pub local_safe: u64,
+}
+
+/// Describes an event queued for the chain processor of a chain.
+///
+/// Returned by the
+/// [`pending_chain_events`](crate::jsonrpsee::SupervisorAdminApiServer::pending_chain_events) RPC.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainEventInfo {
+    /// The kind of the event, e.g. `unsafeBlock`.
+    pub kind: String,
+    /// The block the event refers to.
+    pub block: BlockNumHash,
+}
+
+/// A safety event exported by the supervisor, as delivered by the
+/// [`subscribe_safety_events`](crate::jsonrpsee::SupervisorEventsApiServer::subscribe_safety_events)
+/// subscription and the newline-delimited JSON export.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SafetyEvent {
+    /// Position of the event in the supervisor's event journal.
+    ///
+    /// Sequences are strictly increasing, so consumers can drop events delivered more than once.
+    pub sequence: u64,
+    /// The chain the event belongs to.
+    #[serde(rename = "chainID")]
+    pub chain_id: ChainId,
+    /// The event itself.
+    #[serde(flatten)]
+    pub kind: SafetyEventKind,
+}
+
+/// Kind and payload of a [`SafetyEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SafetyEventKind {
+    /// A block was promoted to cross-unsafe.
+    CrossUnsafeUpdate {
+        /// The new cross-unsafe block.
+        block: BlockInfo,
+    },
+    /// A block was promoted to cross-safe.
+    CrossSafeUpdate {
+        /// The new cross-safe block.
+        derived: BlockInfo,
+        /// The L1 block the cross-safe block was derived from.
+        source: BlockInfo,
+    },
+    /// A block was found invalid.
+    InvalidateBlock {
+        /// The invalidated block.
+        block: BlockInfo,
+    },
+    /// An invalidated block was replaced.
+    BlockReplaced {
+        /// The replacement block.
+        replacement: BlockInfo,
+        /// The hash of the invalidated block.
+        invalidated: B256,
+    },
+    /// A new L1 block was finalized.
+    FinalizedSourceUpdate {
+        /// The finalized L1 block.
+        block: BlockInfo,
+    },
+}
+
+/// Stage in the lifecycle of a cross-chain message.
+///
+/// Stages are ordered by progression, with [`MessageStage::Invalidated`] as a terminal stage for
+/// executions that were reorged out of the executing chain or that reference the wrong message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MessageStage {
+    /// The initiating message is known, but no executing message references it.
+    Initiated,
+    /// An executing message was included in an unsafe block.
+    Executed,
+    /// The executing block is [`CrossUnsafe`](SafetyLevel::CrossUnsafe).
+    CrossUnsafe,
+    /// The executing block is [`CrossSafe`](SafetyLevel::CrossSafe).
+    CrossSafe,
+    /// The executing block is [`Finalized`](SafetyLevel::Finalized).
+    Finalized,
+    /// The executing block is no longer canonical, or the executing message does not match the
+    /// initiating log.
+    Invalidated,
+}
+
+/// Maps the [`SafetyLevel`] of an executing block to the stage of the execution.
+impl From<SafetyLevel> for MessageStage {
+    fn from(level: SafetyLevel) -> Self {
+        match level {
+            SafetyLevel::LocalUnsafe | SafetyLevel::LocalSafe => Self::Executed,
+            SafetyLevel::CrossUnsafe => Self::CrossUnsafe,
+            SafetyLevel::CrossSafe => Self::CrossSafe,
+            SafetyLevel::Finalized => Self::Finalized,
+            SafetyLevel::Invalid => Self::Invalidated,
+        }
+    }
+}
+
+/// Describes the initiating side of a cross-chain message.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiatingMessageStatus {
+    /// The block containing the initiating log.
+    pub block: BlockNumHash,
+    /// The hash of the initiating log.
+    pub log_hash: B256,
+    /// The safety level of the block containing the initiating log.
+    pub safety: SafetyLevel,
+}
+
+/// Describes an executing message referencing an initiating message.
+///
+/// Returned by the
+/// [`executing_messages`](crate::jsonrpsee::SupervisorApiServer::executing_messages) RPC.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutingMessageStatus {
+    /// The chain ID of the executing chain.
+    #[serde(rename = "chainID", with = "alloy_serde::quantity")]
+    pub chain_id: ChainId,
+    /// The block containing the executing log.
+    pub block: BlockNumHash,
+    /// The index of the executing log within the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub log_index: u32,
+    /// The stage of the execution.
+    pub stage: MessageStage,
+}
+
+/// Describes the full lifecycle of a cross-chain message.
+///
+/// Returned by the
+/// [`message_lifecycle`](crate::jsonrpsee::SupervisorApiServer::message_lifecycle) RPC.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageLifecycle {
+    /// The identifier of the initiating message.
+    pub identifier: MessageIdentifier,
+    /// The initiating message.
+    pub initiating: InitiatingMessageStatus,
+    /// All known executions of the message, including invalidated ones.
+    pub executions: Vec<ExecutingMessageStatus>,
+    /// The overall stage of the message.
+    pub stage: MessageStage,
+}
+
+impl MessageLifecycle {
+    /// Creates a new [`MessageLifecycle`], deriving the overall stage from the executions.
+    ///
+    /// The overall stage is the most advanced stage of any valid execution. A message without
+    /// executions is [`MessageStage::Initiated`], and a message whose executions were all
+    /// reorged out is [`MessageStage::Invalidated`].
+    pub fn new(
+        identifier: MessageIdentifier,
+        initiating: InitiatingMessageStatus,
+        executions: Vec<ExecutingMessageStatus>,
+    ) -> Self {
+        let stage = executions
+            .iter()
+            .map(|exec| exec.stage)
+            .filter(|stage| *stage != MessageStage::Invalidated)
+            .max()
+            .unwrap_or(if executions.is_empty() {
+                MessageStage::Initiated
+            } else {
+                MessageStage::Invalidated
+            });
+        Self { identifier, initiating, executions, stage }
+    }
+}
+
 /// Serializes a [u8] as a hex string. Ensure that the hex string has an even length.
 ///
 /// This is used to serialize the [`SuperRootOutputRpc`]'s version field as a hex string.
@@ -323,4 +555,66 @@ mod test {
         // For SUPER_ROOT_VERSION = 1, should be 0x01
         assert_eq!(version_field, "0x01");
     }
+
+    #[test]
+    fn test_super_root_with_outputs_is_flattened() {
+        let root = SuperRootWithOutputsRpc {
+            super_root: SuperRootOutputRpc {
+                cross_safe_derived_from: BlockNumHash::default(),
+                timestamp: 10,
+                super_root: B256::default(),
+                version: SUPER_ROOT_VERSION,
+                chains: vec![],
+            },
+            outputs: vec![ChainOutputRpc {
+                chain_id: 10,
+                block_number: 5,
+                output: OutputV0::default(),
+                pending: OutputV0::default(),
+            }],
+        };
+        let v = serde_json::to_value(&root).expect("should serialize");
+        assert_eq!(v["timestamp"], "0xa");
+        assert_eq!(v["outputs"][0]["chainID"], "0xa");
+        assert_eq!(v["outputs"][0]["blockNumber"], "0x5");
+        assert!(v["outputs"][0]["pending"].get("stateRoot").is_some());
+    }
+
+    #[test]
+    fn test_message_lifecycle_stage() {
+        let identifier = MessageIdentifier { chain_id: 1, block_number: 10, log_index: 0 };
+        let initiating = InitiatingMessageStatus {
+            block: BlockNumHash::new(10, B256::ZERO),
+            log_hash: B256::ZERO,
+            safety: SafetyLevel::CrossSafe,
+        };
+        let execution = |stage| ExecutingMessageStatus {
+            chain_id: 2,
+            block: BlockNumHash::new(20, B256::ZERO),
+            log_index: 1,
+            stage,
+        };
+
+        let lifecycle = MessageLifecycle::new(identifier, initiating, vec![]);
+        assert_eq!(lifecycle.stage, MessageStage::Initiated);
+
+        let lifecycle = MessageLifecycle::new(
+            identifier,
+            initiating,
+            vec![execution(MessageStage::Invalidated), execution(MessageStage::CrossUnsafe)],
+        );
+        assert_eq!(lifecycle.stage, MessageStage::CrossUnsafe);
+
+        let lifecycle = MessageLifecycle::new(
+            identifier,
+            initiating,
+            vec![execution(MessageStage::Invalidated)],
+        );
+        assert_eq!(lifecycle.stage, MessageStage::Invalidated);
+
+        let json = serde_json::to_value(execution(MessageStage::CrossSafe)).unwrap();
+        assert_eq!(json["chainID"], "0x2");
+        assert_eq!(json["logIndex"], "0x1");
+        assert_eq!(json["stage"], "crossSafe");
+    }
 }
@@ -9,10 +9,10 @@ mod metric;
 pub use metric::MetricWorker;
 
 mod processor;
-pub use processor::ChainProcessorActor;
+pub use processor::{ChainProcessorActor, ChainProcessorControl};
 
 mod node;
-pub use node::ManagedNodeActor;
+pub use node::{ManagedNodeActor, ManagedNodeCommandActor};
 
 mod rpc;
 pub use rpc::SupervisorRpcActor;
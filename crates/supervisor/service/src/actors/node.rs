@@ -13,12 +13,14 @@ use tracing::{error, info, warn};
 
 use crate::{SupervisorActor, actors::utils::spawn_task_with_retry};
 
-/// Actor for managing a node in the supervisor environment.
+/// Actor handling the event subscription to a managed node.
+///
+/// The subscription is re-established with a backoff whenever it fails or is closed by the node,
+/// until the actor is cancelled.
 #[derive(Debug, Constructor)]
 pub struct ManagedNodeActor<C, N> {
     client: Arc<C>,
     node: Arc<N>,
-    command_rx: mpsc::Receiver<ManagedNodeCommand>,
     cancel_token: CancellationToken,
 }
 
@@ -26,43 +28,63 @@ pub struct ManagedNodeActor<C, N> {
 impl<C, N> SupervisorActor for ManagedNodeActor<C, N>
 where
     C: ManagedNodeClient + 'static,
-    N: ManagedNodeController + SubscriptionHandler + 'static,
+    N: SubscriptionHandler + 'static,
 {
-    type InboundEvent = ManagedNodeCommand;
+    type InboundEvent = ManagedEvent;
     type Error = SupervisorRpcActorError;
 
     async fn start(mut self) -> Result<(), Self::Error> {
-        // Task 1: Subscription handling
         let node = self.node.clone();
         let client = self.client.clone();
         let cancel_token = self.cancel_token.clone();
 
-        spawn_task_with_retry(
+        let task = spawn_task_with_retry(
             move || {
                 let handler = node.clone();
                 let client = client.clone();
+                let cancel_token = cancel_token.clone();
 
-                async move { run_subscription_task(client, handler).await }
+                async move { run_subscription_task(client, handler, cancel_token).await }
             },
-            cancel_token,
+            self.cancel_token.clone(),
             usize::MAX,
         );
 
-        // Task 2: Command handling
-        let node = self.node.clone();
-        let cancel_token = self.cancel_token.clone();
-        run_command_task(node, self.command_rx, cancel_token).await?;
+        if let Err(err) = task.await {
+            error!(target: "supervisor::syncnode_actor", %err, "Subscription task panicked");
+        }
         Ok(())
     }
 }
 
+/// Actor forwarding [`ManagedNodeCommand`]s to the managed nodes of a chain.
+#[derive(Debug, Constructor)]
+pub struct ManagedNodeCommandActor<N> {
+    node: Arc<N>,
+    command_rx: mpsc::Receiver<ManagedNodeCommand>,
+    cancel_token: CancellationToken,
+}
+
+#[async_trait]
+impl<N> SupervisorActor for ManagedNodeCommandActor<N>
+where
+    N: ManagedNodeController + 'static,
+{
+    type InboundEvent = ManagedNodeCommand;
+    type Error = SupervisorRpcActorError;
+
+    async fn start(mut self) -> Result<(), Self::Error> {
+        run_command_task(self.node, self.command_rx, self.cancel_token).await
+    }
+}
+
 async fn run_command_task<N>(
     node: Arc<N>,
     mut command_rx: mpsc::Receiver<ManagedNodeCommand>,
     cancel_token: CancellationToken,
 ) -> Result<(), SupervisorRpcActorError>
 where
-    N: ManagedNodeController + SubscriptionHandler + 'static,
+    N: ManagedNodeController + 'static,
 {
     info!(target: "supervisor::syncnode_actor", "Starting command task for managed node");
     loop {
@@ -140,6 +162,7 @@ where
 async fn run_subscription_task<C: ManagedNodeClient, N: SubscriptionHandler>(
     client: Arc<C>,
     handler: Arc<N>,
+    cancel_token: CancellationToken,
 ) -> Result<(), Error> {
     info!(target: "supervisor::syncnode", "Starting subscription task for managed node");
 
@@ -150,9 +173,14 @@ async fn run_subscription_task<C: ManagedNodeClient, N: SubscriptionHandler>(
             "Failed to subscribe to node events"
         );
     })?;
+    handler.handle_subscription_state(true).await;
 
     loop {
         tokio::select! {
+            _ = cancel_token.cancelled() => {
+                info!(target: "supervisor::syncnode", "Cancellation requested, shutting down subscription task");
+                break;
+            }
             incoming_event = subscription.next() => {
                 match incoming_event {
                     Some(Ok(subscription_event)) => {
@@ -166,6 +194,7 @@ async fn run_subscription_task<C: ManagedNodeClient, N: SubscriptionHandler>(
                             %err,
                             "Error in event deserialization"
                         );
+                        handler.handle_subscription_state(false).await;
                         return Err(err.into());
                     }
                     None => {
@@ -177,6 +206,7 @@ async fn run_subscription_task<C: ManagedNodeClient, N: SubscriptionHandler>(
             }
         }
     }
+    handler.handle_subscription_state(false).await;
     Ok(())
 }
 
@@ -1,16 +1,42 @@
 use async_trait::async_trait;
+use futures::future;
 use kona_interop::InteropValidator;
 use kona_supervisor_core::{ChainProcessor, event::ChainEvent, syncnode::BlockProvider};
 use kona_supervisor_storage::{
     DerivationStorage, HeadRefStorageWriter, LogStorage, StorageRewinder,
 };
+use std::collections::VecDeque;
 use thiserror::Error;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
 use tokio_util::sync::CancellationToken;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::SupervisorActor;
 
+/// Maximum number of events queued while a [`ChainProcessorActor`] is paused. Once reached, events
+/// are left in the channel, so their senders are throttled by its capacity.
+const MAX_PENDING_EVENTS: usize = 10_000;
+
+/// Commands controlling a running [`ChainProcessorActor`].
+#[derive(Debug)]
+pub enum ChainProcessorControl {
+    /// Stops processing events. Incoming events are queued until the actor is resumed, up to
+    /// [`MAX_PENDING_EVENTS`].
+    Pause,
+    /// Drops the queued events.
+    ClearPending {
+        /// The response channel to send the number of dropped events back.
+        resp: oneshot::Sender<usize>,
+    },
+    /// Resumes processing events, starting with the queued ones.
+    Resume,
+    /// Returns the events queued for processing, in order.
+    PendingEvents {
+        /// The response channel to send the events back.
+        resp: oneshot::Sender<Vec<ChainEvent>>,
+    },
+}
+
 /// Represents an actor that processes chain events using the [`ChainProcessor`].
 /// It listens for [`ChainEvent`]s and handles them accordingly.
 #[derive(Debug)]
@@ -18,6 +44,9 @@ pub struct ChainProcessorActor<P, W, V> {
     chain_processor: ChainProcessor<P, W, V>,
     cancel_token: CancellationToken,
     event_rx: mpsc::Receiver<ChainEvent>,
+    control_rx: Option<mpsc::Receiver<ChainProcessorControl>>,
+    paused: bool,
+    pending: VecDeque<ChainEvent>,
 }
 
 impl<P, W, V> ChainProcessorActor<P, W, V>
@@ -32,7 +61,76 @@ where
         cancel_token: CancellationToken,
         event_rx: mpsc::Receiver<ChainEvent>,
     ) -> Self {
-        Self { chain_processor, cancel_token, event_rx }
+        Self {
+            chain_processor,
+            cancel_token,
+            event_rx,
+            control_rx: None,
+            paused: false,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Sets the receiver of [`ChainProcessorControl`] commands.
+    pub fn with_control(mut self, control_rx: mpsc::Receiver<ChainProcessorControl>) -> Self {
+        self.control_rx = Some(control_rx);
+        self
+    }
+
+    fn handle_control(&mut self, control: ChainProcessorControl) {
+        match control {
+            ChainProcessorControl::Pause => {
+                info!(target: "supervisor::chain_processor_actor", "Pausing ChainProcessorActor");
+                self.paused = true;
+            }
+            ChainProcessorControl::Resume => {
+                info!(
+                    target: "supervisor::chain_processor_actor",
+                    pending = self.pending.len(),
+                    "Resuming ChainProcessorActor"
+                );
+                self.paused = false;
+            }
+            ChainProcessorControl::ClearPending { resp } => {
+                self.drain_events();
+                let cleared = self.pending.len();
+                self.pending.clear();
+                info!(target: "supervisor::chain_processor_actor", cleared, "Cleared queued events");
+                let _ = resp.send(cleared);
+            }
+            ChainProcessorControl::PendingEvents { resp } => {
+                self.drain_events();
+                let _ = resp.send(self.pending.iter().cloned().collect());
+            }
+        }
+    }
+
+    /// Moves the events waiting in the channel to the queue, as long as it is not full.
+    fn drain_events(&mut self) {
+        while self.can_queue() {
+            let Ok(event) = self.event_rx.try_recv() else {
+                break;
+            };
+            self.queue(event);
+        }
+    }
+
+    /// Returns `true` if events can be received: either the actor is running, or there is room
+    /// left in the queue.
+    fn can_queue(&self) -> bool {
+        !self.paused || self.pending.len() < MAX_PENDING_EVENTS
+    }
+
+    /// Queues an event received while paused.
+    fn queue(&mut self, event: ChainEvent) {
+        self.pending.push_back(event);
+        if self.pending.len() == MAX_PENDING_EVENTS {
+            warn!(
+                target: "supervisor::chain_processor_actor",
+                pending = MAX_PENDING_EVENTS,
+                "Event queue of paused ChainProcessorActor is full, leaving events in the channel"
+            );
+        }
     }
 }
 
@@ -53,16 +151,35 @@ where
         );
 
         loop {
+            if !self.paused {
+                if let Some(event) = self.pending.pop_front() {
+                    self.chain_processor.handle_event(event).await;
+                    continue;
+                }
+            }
+
             tokio::select! {
-                maybe_event = self.event_rx.recv() => {
-                    if let Some(event) = maybe_event {
-                        self.chain_processor.handle_event(event).await;
-                    } else {
-                        info!(
-                            target: "supervisor::chain_processor_actor",
-                            "Chain event receiver closed, stopping ChainProcessorActor"
-                        );
-                        return Err(ChainProcessorActorError::ReceiverClosed);
+                // control commands take effect before any further event is processed
+                biased;
+
+                maybe_control = recv_control(&mut self.control_rx) => {
+                    match maybe_control {
+                        Some(control) => self.handle_control(control),
+                        None => self.control_rx = None,
+                    }
+                }
+                maybe_event = self.event_rx.recv(), if self.can_queue() => {
+                    match maybe_event {
+                        // keep the channel flowing while paused, until the queue is full
+                        Some(event) if self.paused => self.queue(event),
+                        Some(event) => self.chain_processor.handle_event(event).await,
+                        None => {
+                            info!(
+                                target: "supervisor::chain_processor_actor",
+                                "Chain event receiver closed, stopping ChainProcessorActor"
+                            );
+                            return Err(ChainProcessorActorError::ReceiverClosed);
+                        }
                     }
                 }
                 _ = self.cancel_token.cancelled() => {
@@ -79,6 +196,16 @@ where
     }
 }
 
+/// Receives the next control command. Without a control receiver, never produces a value.
+async fn recv_control(
+    control_rx: &mut Option<mpsc::Receiver<ChainProcessorControl>>,
+) -> Option<ChainProcessorControl> {
+    match control_rx.as_mut() {
+        Some(rx) => rx.recv().await,
+        None => future::pending().await,
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ChainProcessorActorError {
     /// Error when the chain event receiver is closed.
@@ -96,8 +223,10 @@ mod tests {
     use kona_protocol::BlockInfo;
     use kona_supervisor_core::{
         LogIndexer,
+        event::EventSink,
         syncnode::{BlockProvider, ManagedNodeCommand, ManagedNodeDataProvider, ManagedNodeError},
     };
+    use kona_supervisor_rpc::SafetyEventKind;
     use kona_supervisor_storage::{
         DerivationStorageReader, DerivationStorageWriter, HeadRefStorageWriter, LogStorageReader,
         LogStorageWriter, StorageError, StorageRewinder,
@@ -321,4 +450,173 @@ mod tests {
         let result = actor.start().await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_actor_pause_and_resume() {
+        let mock_node = MockNode::new();
+        let mock_db = MockDb::new();
+        let validator = MockValidator::new();
+        let (mn_sender, mut mn_receiver) = mpsc::channel(1);
+
+        let db = Arc::new(mock_db);
+        let log_indexer = LogIndexer::new(1, Some(Arc::new(mock_node)), db.clone());
+
+        let processor =
+            ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender);
+
+        let cancel_token = CancellationToken::new();
+        let (tx, rx) = mpsc::channel(1);
+        let (control_tx, control_rx) = mpsc::channel(1);
+
+        let actor =
+            ChainProcessorActor::new(processor, cancel_token.clone(), rx).with_control(control_rx);
+        let handle = tokio::spawn(actor.start());
+
+        control_tx.send(ChainProcessorControl::Pause).await.unwrap();
+        let block = BlockInfo { number: 1, timestamp: 1000, ..Default::default() };
+        tx.send(ChainEvent::CrossUnsafeUpdate { block }).await.unwrap();
+
+        // The event stays queued while paused.
+        let (resp_tx, resp_rx) = oneshot::channel();
+        control_tx.send(ChainProcessorControl::PendingEvents { resp: resp_tx }).await.unwrap();
+        assert_eq!(resp_rx.await.unwrap(), vec![ChainEvent::CrossUnsafeUpdate { block }]);
+        assert!(mn_receiver.try_recv().is_err());
+
+        control_tx.send(ChainProcessorControl::Resume).await.unwrap();
+        assert_eq!(
+            mn_receiver.recv().await,
+            Some(ManagedNodeCommand::UpdateCrossUnsafe { block_id: block.id() })
+        );
+
+        cancel_token.cancel();
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_actor_queues_and_clears_events_while_paused() {
+        let mock_node = MockNode::new();
+        let mock_db = MockDb::new();
+        let validator = MockValidator::new();
+        let (mn_sender, mut mn_receiver) = mpsc::channel(1);
+
+        let db = Arc::new(mock_db);
+        let log_indexer = LogIndexer::new(1, Some(Arc::new(mock_node)), db.clone());
+
+        let processor =
+            ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender);
+
+        let cancel_token = CancellationToken::new();
+        let (tx, rx) = mpsc::channel(1);
+        let (control_tx, control_rx) = mpsc::channel(1);
+
+        let actor =
+            ChainProcessorActor::new(processor, cancel_token.clone(), rx).with_control(control_rx);
+        let handle = tokio::spawn(actor.start());
+
+        control_tx.send(ChainProcessorControl::Pause).await.unwrap();
+
+        // More events than the channel capacity are accepted while paused.
+        for number in 1..=3 {
+            let block = BlockInfo { number, timestamp: 1000 + number, ..Default::default() };
+            tokio::time::timeout(
+                std::time::Duration::from_secs(1),
+                tx.send(ChainEvent::CrossUnsafeUpdate { block }),
+            )
+            .await
+            .expect("event channel must keep flowing while paused")
+            .unwrap();
+        }
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+        control_tx.send(ChainProcessorControl::ClearPending { resp: resp_tx }).await.unwrap();
+        assert_eq!(resp_rx.await.unwrap(), 3);
+
+        // The cleared events are never processed.
+        control_tx.send(ChainProcessorControl::Resume).await.unwrap();
+        let (resp_tx, resp_rx) = oneshot::channel();
+        control_tx.send(ChainProcessorControl::PendingEvents { resp: resp_tx }).await.unwrap();
+        assert!(resp_rx.await.unwrap().is_empty());
+        assert!(mn_receiver.try_recv().is_err());
+
+        cancel_token.cancel();
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_actor_stops_receiving_once_queue_is_full() {
+        let mock_node = MockNode::new();
+        let mock_db = MockDb::new();
+        let validator = MockValidator::new();
+        let (mn_sender, _mn_receiver) = mpsc::channel(1);
+
+        let db = Arc::new(mock_db);
+        let log_indexer = LogIndexer::new(1, Some(Arc::new(mock_node)), db.clone());
+
+        let processor =
+            ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender);
+
+        let cancel_token = CancellationToken::new();
+        let (tx, rx) = mpsc::channel(1);
+        let (control_tx, control_rx) = mpsc::channel(1);
+
+        let actor =
+            ChainProcessorActor::new(processor, cancel_token.clone(), rx).with_control(control_rx);
+        let handle = tokio::spawn(actor.start());
+
+        control_tx.send(ChainProcessorControl::Pause).await.unwrap();
+
+        // The queue and the channel fill up, then senders are throttled.
+        let block = BlockInfo::default();
+        for _ in 0..=MAX_PENDING_EVENTS {
+            tx.send(ChainEvent::CrossUnsafeUpdate { block }).await.unwrap();
+        }
+        assert!(tx.try_send(ChainEvent::CrossUnsafeUpdate { block }).is_err());
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+        control_tx.send(ChainProcessorControl::PendingEvents { resp: resp_tx }).await.unwrap();
+        assert_eq!(resp_rx.await.unwrap().len(), MAX_PENDING_EVENTS);
+
+        cancel_token.cancel();
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_actor_exports_only_processed_events() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let event_sink = Arc::new(EventSink::open(dir.path(), 100).unwrap());
+        let block = BlockInfo { number: 1, timestamp: 1000, ..Default::default() };
+
+        // Without managed nodes the cross unsafe update fails, and the first run exports nothing.
+        for (runs, managed_nodes_alive) in [(0, false), (1, true)] {
+            let db = Arc::new(MockDb::new());
+            let log_indexer = LogIndexer::new(1, Some(Arc::new(MockNode::new())), db.clone());
+            let (mn_sender, mn_receiver) = mpsc::channel(1);
+            let _mn_receiver = managed_nodes_alive.then_some(mn_receiver);
+
+            let processor = ChainProcessor::new(
+                Arc::new(MockValidator::new()),
+                1,
+                Arc::new(log_indexer),
+                db,
+                mn_sender,
+            )
+            .with_event_sink(event_sink.clone());
+
+            let cancel_token = CancellationToken::new();
+            let (tx, rx) = mpsc::channel(1);
+            tx.send(ChainEvent::CrossUnsafeUpdate { block }).await.unwrap();
+
+            let cancel = cancel_token.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                cancel.cancel();
+            });
+            assert!(ChainProcessorActor::new(processor, cancel_token, rx).start().await.is_ok());
+
+            assert_eq!(event_sink.subscribe(None).unwrap().next_sequence(), runs);
+        }
+
+        let event = event_sink.subscribe(Some(0)).unwrap().next().await.unwrap();
+        assert_eq!(event.kind, SafetyEventKind::CrossUnsafeUpdate { block });
+    }
 }
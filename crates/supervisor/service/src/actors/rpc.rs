@@ -84,8 +84,11 @@ mod tests {
     use kona_interop::{DependencySet, ExecutingDescriptor, SafetyLevel};
     use kona_protocol::BlockInfo;
     use kona_supervisor_core::{SupervisorError, SupervisorService};
-    use kona_supervisor_rpc::{SuperRootOutputRpc, SupervisorApiServer};
-    use kona_supervisor_types::SuperHead;
+    use kona_supervisor_rpc::{
+        ExecutingMessageStatus, MessageLifecycle, SuperRootOutputRpc, SuperRootWithOutputsRpc,
+        SupervisorApiServer,
+    };
+    use kona_supervisor_types::{MessageIdentifier, SuperHead};
     use mockall::mock;
     use std::{
         net::{Ipv4Addr, SocketAddr},
@@ -101,7 +104,7 @@ mod tests {
         #[async_trait]
         impl SupervisorService for SupervisorService {
             fn chain_ids(&self) -> impl Iterator<Item = ChainId>;
-            fn dependency_set(&self) -> &DependencySet;
+            fn dependency_set(&self) -> Arc<DependencySet>;
             fn super_head(&self, chain: ChainId) -> Result<SuperHead, SupervisorError>;
             fn latest_block_from(&self, l1_block: BlockNumHash, chain: ChainId) -> Result<BlockInfo, SupervisorError>;
             fn derived_to_source_block(&self, chain: ChainId, derived: BlockNumHash) -> Result<BlockInfo, SupervisorError>;
@@ -112,6 +115,9 @@ mod tests {
             fn finalized_l1(&self) -> Result<BlockInfo, SupervisorError>;
             fn check_access_list(&self, inbox_entries: Vec<B256>, min_safety: SafetyLevel, executing_descriptor: ExecutingDescriptor) -> Result<(), SupervisorError>;
             async fn super_root_at_timestamp(&self, timestamp: u64) -> Result<SuperRootOutputRpc, SupervisorError>;
+            async fn super_roots_in_range(&self, from: u64, to: u64, step: u64, safety_level: SafetyLevel) -> Result<Vec<SuperRootWithOutputsRpc>, SupervisorError>;
+            fn executing_messages(&self, identifier: MessageIdentifier) -> Result<Vec<ExecutingMessageStatus>, SupervisorError>;
+            fn message_lifecycle(&self, identifier: MessageIdentifier) -> Result<MessageLifecycle, SupervisorError>;
         }
     );
 
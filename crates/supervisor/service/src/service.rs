@@ -1,5 +1,6 @@
 //! Contains the main Supervisor service runner.
 
+use alloy_eips::BlockNumHash;
 use alloy_primitives::ChainId;
 use alloy_provider::{RootProvider, network::Ethereum};
 use alloy_rpc_client::RpcClient;
@@ -7,45 +8,74 @@ use anyhow::Result;
 use futures::future;
 use jsonrpsee::client_transport::ws::Url;
 use kona_supervisor_core::{
-    ChainProcessor, CrossSafetyCheckerJob, LogIndexer, ReorgHandler, Supervisor,
+    ChainProcessor, CrossSafetyScheduler, LogIndexer, ReorgHandler, ReorgHandlerError, RewindLock,
+    StoragePrunerJob, Supervisor,
     config::Config,
-    event::ChainEvent,
+    event::{ChainEvent, EventExporter, EventSink},
     l1_watcher::L1Watcher,
-    rpc::{AdminError, AdminRequest, AdminRpc, SupervisorRpc},
+    rpc::{AdminError, AdminRequest, AdminRpc, EventsRpc, SupervisorRpc},
     safety_checker::{CrossSafePromoter, CrossUnsafePromoter},
-    syncnode::{Client, ClientConfig, ManagedNode, ManagedNodeClient, ManagedNodeCommand},
+    syncnode::{
+        Client, ClientConfig, ManagedNode, ManagedNodeClient, ManagedNodeCommand, ManagedNodePool,
+    },
+};
+use kona_supervisor_rpc::{
+    ChainEventInfo, ManagedNodeInfo, SupervisorAdminApiServer, SupervisorApiServer,
+    SupervisorEventsApiServer,
+};
+use kona_supervisor_storage::{
+    ChainDbFactory, DerivationStorageWriter, LogStorageWriter, StorageFactory,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinSet,
+    time::Duration,
 };
-use kona_supervisor_rpc::{SupervisorAdminApiServer, SupervisorApiServer};
-use kona_supervisor_storage::{ChainDb, ChainDbFactory, DerivationStorageWriter, LogStorageWriter};
-use std::{collections::HashMap, sync::Arc};
-use tokio::{sync::mpsc, task::JoinSet, time::Duration};
 use tokio_util::sync::CancellationToken;
-use tracing::{error, info, warn};
+use tracing::{error, info};
 
 use crate::actors::{
-    ChainProcessorActor, ManagedNodeActor, MetricWorker, SupervisorActor, SupervisorRpcActor,
+    ChainProcessorActor, ChainProcessorControl, ManagedNodeActor, ManagedNodeCommandActor,
+    MetricWorker, SupervisorActor, SupervisorRpcActor,
 };
 
-// simplify long type signature
-type ManagedLogIndexer = LogIndexer<ManagedNode<ChainDb, Client>, ChainDb>;
+// simplify long type signatures
+type ManagedNodes<DB> = ManagedNodePool<ManagedNode<DB, Client>>;
+type ManagedLogIndexer<DB> = LogIndexer<ManagedNodes<DB>, DB>;
 
 /// The main service structure for the Kona
 /// [`SupervisorService`](`kona_supervisor_core::SupervisorService`). Orchestrates the various
 /// components of the supervisor.
+///
+/// Chain state is kept in the databases handed out by the [`StorageFactory`], which defaults to
+/// the MDBX-backed [`ChainDbFactory`].
 #[derive(Debug)]
-pub struct Service {
+pub struct Service<F = ChainDbFactory>
+where
+    F: StorageFactory,
+{
     config: Arc<Config>,
 
-    supervisor: Arc<Supervisor<ManagedNode<ChainDb, Client>>>,
-    database_factory: Arc<ChainDbFactory>,
-    managed_nodes: HashMap<ChainId, Arc<ManagedNode<ChainDb, Client>>>,
-    log_indexers: HashMap<ChainId, Arc<ManagedLogIndexer>>,
+    supervisor: Arc<Supervisor<ManagedNodes<F::ChainDb>, F>>,
+    database_factory: Arc<F>,
+    managed_nodes: HashMap<ChainId, Arc<ManagedNodes<F::ChainDb>>>,
+    managed_node_tokens: HashMap<String, CancellationToken>,
+    log_indexers: HashMap<ChainId, Arc<ManagedLogIndexer<F::ChainDb>>>,
+    reorg_handler: Option<Arc<ReorgHandler<F::ChainDb>>>,
+    rewind_lock: RewindLock,
+    event_sink: Option<Arc<EventSink>>,
 
     // channels
     chain_event_senders: HashMap<ChainId, mpsc::Sender<ChainEvent>>,
     chain_event_receivers: HashMap<ChainId, mpsc::Receiver<ChainEvent>>,
     managed_node_senders: HashMap<ChainId, mpsc::Sender<ManagedNodeCommand>>,
     managed_node_receivers: HashMap<ChainId, mpsc::Receiver<ManagedNodeCommand>>,
+    chain_processor_controls: HashMap<ChainId, mpsc::Sender<ChainProcessorControl>>,
+    paused_chains: HashSet<ChainId>,
     admin_receiver: Option<mpsc::Receiver<AdminRequest>>,
 
     cancel_token: CancellationToken,
@@ -53,10 +83,22 @@ pub struct Service {
 }
 
 impl Service {
-    /// Creates a new Supervisor service instance.
+    /// Creates a new Supervisor service instance, storing chain state in MDBX databases under the
+    /// configured data directory.
     pub fn new(cfg: Config) -> Self {
+        let database_factory = ChainDbFactory::new(cfg.datadir.clone()).with_metrics();
+        Self::with_database_factory(cfg, database_factory)
+    }
+}
+
+impl<F> Service<F>
+where
+    F: StorageFactory,
+{
+    /// Creates a new Supervisor service instance backed by the given [`StorageFactory`].
+    pub fn with_database_factory(cfg: Config, database_factory: F) -> Self {
         let config = Arc::new(cfg);
-        let database_factory = Arc::new(ChainDbFactory::new(config.datadir.clone()).with_metrics());
+        let database_factory = Arc::new(database_factory);
         let supervisor = Arc::new(Supervisor::new(config.clone(), database_factory.clone()));
 
         Self {
@@ -65,12 +107,18 @@ impl Service {
             supervisor,
             database_factory,
             managed_nodes: HashMap::new(),
+            managed_node_tokens: HashMap::new(),
             log_indexers: HashMap::new(),
+            reorg_handler: None,
+            rewind_lock: RewindLock::new(),
+            event_sink: None,
 
             chain_event_senders: HashMap::new(),
             chain_event_receivers: HashMap::new(),
             managed_node_senders: HashMap::new(),
             managed_node_receivers: HashMap::new(),
+            chain_processor_controls: HashMap::new(),
+            paused_chains: HashSet::new(),
             admin_receiver: None,
 
             cancel_token: CancellationToken::new(),
@@ -92,10 +140,13 @@ impl Service {
         }
 
         self.init_database().await?;
+        self.init_event_sink()?;
         self.init_chain_processor().await?;
         self.init_managed_nodes().await?;
         self.init_l1_watcher()?;
         self.init_cross_safety_checker().await?;
+        self.init_storage_pruner()?;
+        self.init_dependency_set_reloader()?;
 
         // todo: run metric worker only if metrics are enabled
         self.init_rpc_server().await?;
@@ -143,43 +194,70 @@ impl Service {
             .ok_or(anyhow::anyhow!("no chain event sender found for chain {chain_id}"))?
             .clone();
 
-        let managed_node =
-            ManagedNode::<ChainDb, Client>::new(client.clone(), db, provider, chain_event_sender);
+        let managed_node = Arc::new(ManagedNode::<F::ChainDb, Client>::new(
+            client.clone(),
+            db,
+            provider,
+            chain_event_sender,
+        ));
+
+        // add the managed node to the pool of its chain; the first node of a chain becomes its
+        // primary
+        let pool = self.managed_node_pool(chain_id).await?;
+        let member = pool.add_node(config.url.clone(), managed_node)?;
+        info!(target: "supervisor::service",
+             chain_id,
+             node = %config.url,
+             primary = member.is_primary(),
+            "Managed node for chain initialized successfully",
+        );
 
-        if self.managed_nodes.contains_key(&chain_id) {
-            warn!(target: "supervisor::service", %chain_id, "Managed node for chain already exists, skipping initialization");
-            return Ok(());
+        // start managed node actor
+        let cancel_token = self.cancel_token.child_token();
+        self.managed_node_tokens.insert(config.url.clone(), cancel_token.clone());
+        self.join_set.spawn(async move {
+            if let Err(err) = ManagedNodeActor::new(client, member, cancel_token).start().await {
+                Err(anyhow::anyhow!(err))
+            } else {
+                Ok(())
+            }
+        });
+        Ok(())
+    }
+
+    /// Returns the pool of managed nodes of the chain, creating it on first use.
+    async fn managed_node_pool(
+        &mut self,
+        chain_id: ChainId,
+    ) -> Result<Arc<ManagedNodes<F::ChainDb>>> {
+        if let Some(pool) = self.managed_nodes.get(&chain_id) {
+            return Ok(pool.clone());
         }
 
-        let managed_node = Arc::new(managed_node);
-        // add the managed node to the supervisor service
+        let pool = Arc::new(ManagedNodePool::new(chain_id));
+        // add the pool to the supervisor service
         // also checks if the chain ID is supported
-        self.supervisor.add_managed_node(chain_id, managed_node.clone()).await?;
+        self.supervisor.add_managed_node(chain_id, pool.clone()).await?;
 
-        // set the managed node in the log indexer
+        // set the pool in the log indexer
         let log_indexer = self
             .log_indexers
             .get(&chain_id)
             .ok_or(anyhow::anyhow!("no log indexer found for chain {chain_id}"))?
             .clone();
-        log_indexer.set_block_provider(managed_node.clone()).await;
-
-        self.managed_nodes.insert(chain_id, managed_node.clone());
-        info!(target: "supervisor::service",
-             chain_id,
-            "Managed node for chain initialized successfully",
-        );
+        log_indexer.set_block_provider(pool.clone()).await;
 
-        // start managed node actor
+        // start the actor mirroring commands to the nodes of the pool
         let managed_node_receiver = self
             .managed_node_receivers
             .remove(&chain_id)
             .ok_or(anyhow::anyhow!("no managed node receiver found for chain {chain_id}"))?;
 
         let cancel_token = self.cancel_token.clone();
+        let command_pool = pool.clone();
         self.join_set.spawn(async move {
             if let Err(err) =
-                ManagedNodeActor::new(client, managed_node, managed_node_receiver, cancel_token)
+                ManagedNodeCommandActor::new(command_pool, managed_node_receiver, cancel_token)
                     .start()
                     .await
             {
@@ -188,7 +266,40 @@ impl Service {
                 Ok(())
             }
         });
-        Ok(())
+
+        self.managed_nodes.insert(chain_id, pool.clone());
+        Ok(pool)
+    }
+
+    /// Detaches the managed node with the given URL from the supervisor.
+    async fn remove_managed_node(&mut self, url: &str) -> Result<()> {
+        for (chain_id, pool) in &self.managed_nodes {
+            if pool.remove_node(url).await {
+                if let Some(cancel_token) = self.managed_node_tokens.remove(url) {
+                    cancel_token.cancel();
+                }
+                info!(target: "supervisor::service", chain_id, node = %url, primary = ?pool.primary_url(), "Managed node removed");
+                return Ok(());
+            }
+        }
+        Err(anyhow::anyhow!("no managed node found for {url}"))
+    }
+
+    /// Returns the managed nodes of every chain.
+    fn managed_node_infos(&self) -> Vec<ManagedNodeInfo> {
+        self.managed_nodes
+            .iter()
+            .flat_map(|(chain_id, pool)| {
+                pool.nodes().into_iter().map(|node| ManagedNodeInfo {
+                    chain_id: *chain_id,
+                    url: node.url,
+                    primary: node.primary,
+                    subscribed: node.health.subscribed,
+                    unsafe_head: node.health.unsafe_head,
+                    local_safe: node.health.local_safe,
+                })
+            })
+            .collect()
     }
 
     async fn init_managed_nodes(&mut self) -> Result<()> {
@@ -199,6 +310,32 @@ impl Service {
         Ok(())
     }
 
+    fn init_event_sink(&mut self) -> Result<()> {
+        let Some(export_config) = &self.config.event_export else {
+            return Ok(());
+        };
+        info!(target: "supervisor::service", "Initialising event sink...");
+
+        let dir = self.config.datadir.join("events");
+        let event_sink = Arc::new(EventSink::open(&dir, export_config.retention)?);
+        for target in &export_config.targets {
+            info!(target: "supervisor::service", %target, "Exporting safety events");
+            let exporter = EventExporter::new(
+                event_sink.clone(),
+                target.clone(),
+                &dir,
+                self.cancel_token.clone(),
+            );
+            self.join_set.spawn(async move {
+                exporter.run().await;
+                Ok(())
+            });
+        }
+
+        self.event_sink = Some(event_sink);
+        Ok(())
+    }
+
     async fn init_chain_processor(&mut self) -> Result<()> {
         info!(target: "supervisor::service", "Initialising chain processors for all chains...");
 
@@ -225,6 +362,9 @@ impl Service {
 
             // todo: enable metrics only if configured
             processor = processor.with_metrics();
+            if let Some(event_sink) = &self.event_sink {
+                processor = processor.with_event_sink(event_sink.clone());
+            }
 
             // Start the chain processor actor.
             let chain_event_receiver = self
@@ -232,10 +372,14 @@ impl Service {
                 .remove(chain_id)
                 .ok_or(anyhow::anyhow!("no chain event receiver found for chain {chain_id}"))?;
 
+            let (control_tx, control_rx) = mpsc::channel::<ChainProcessorControl>(10);
+            self.chain_processor_controls.insert(*chain_id, control_tx);
+
             let cancel_token = self.cancel_token.clone();
             self.join_set.spawn(async move {
                 if let Err(err) =
                     ChainProcessorActor::new(processor, cancel_token, chain_event_receiver)
+                        .with_control(control_rx)
                         .start()
                         .await
                 {
@@ -257,7 +401,7 @@ impl Service {
         })?;
         let l1_rpc = RpcClient::new_http(l1_rpc_url);
 
-        let chain_dbs_map: HashMap<ChainId, Arc<ChainDb>> = self
+        let chain_dbs_map: HashMap<ChainId, Arc<F::ChainDb>> = self
             .config
             .rollup_config_set
             .rollups
@@ -270,11 +414,17 @@ impl Service {
                         anyhow::anyhow!("failed to get database for chain {chain_id}: {err}")
                 })
             })
-            .collect::<Result<HashMap<ChainId, Arc<ChainDb>>>>()?;
+            .collect::<Result<HashMap<ChainId, Arc<F::ChainDb>>>>()?;
+
+        // Separate handler for operator-triggered rewinds. The L1 watcher is paused through the
+        // rewind lock while they run.
+        self.reorg_handler =
+            Some(Arc::new(ReorgHandler::new(l1_rpc.clone(), chain_dbs_map.clone())));
 
         let database_factory = self.database_factory.clone();
         let cancel_token = self.cancel_token.clone();
         let event_senders = self.chain_event_senders.clone();
+        let rewind_lock = self.rewind_lock.clone();
         self.join_set.spawn(async move {
             let reorg_handler =
                 ReorgHandler::new(l1_rpc.clone(), chain_dbs_map.clone()).with_metrics();
@@ -286,7 +436,8 @@ impl Service {
                 event_senders,
                 cancel_token,
                 reorg_handler,
-            );
+            )
+            .with_rewind_lock(rewind_lock);
 
             l1_watcher.run().await;
             Ok(())
@@ -297,49 +448,114 @@ impl Service {
     async fn init_cross_safety_checker(&mut self) -> Result<()> {
         info!(target: "supervisor::service", "Initialising cross safety checker...");
 
-        for (&chain_id, config) in &self.config.rollup_config_set.rollups {
-            let db = Arc::clone(&self.database_factory);
-            let cancel = self.cancel_token.clone();
+        // Each round covers all chains, so poll at the pace of the fastest one.
+        let interval = self
+            .config
+            .rollup_config_set
+            .rollups
+            .values()
+            .map(|config| config.block_time)
+            .min()
+            .map(Duration::from_secs)
+            .ok_or(anyhow::anyhow!("no rollup configs found"))?;
+
+        let cross_safe_scheduler = CrossSafetyScheduler::new(
+            self.database_factory.clone(),
+            self.config.clone(),
+            self.config.dependency_set.clone(),
+            CrossSafePromoter,
+            self.chain_event_senders.clone(),
+            self.cancel_token.clone(),
+            interval,
+        )
+        .with_rewind_lock(self.rewind_lock.clone());
 
-            let chain_event_sender = self
-                .chain_event_senders
-                .get(&chain_id)
-                .ok_or(anyhow::anyhow!("no chain event sender found for chain {chain_id}"))?
-                .clone();
+        self.join_set.spawn(async move {
+            cross_safe_scheduler.run().await;
+            Ok(())
+        });
 
-            let cross_safe_job = CrossSafetyCheckerJob::new(
-                chain_id,
-                db.clone(),
-                cancel.clone(),
-                Duration::from_secs(config.block_time),
-                CrossSafePromoter,
-                chain_event_sender.clone(),
-                self.config.clone(),
-            );
+        let cross_unsafe_scheduler = CrossSafetyScheduler::new(
+            self.database_factory.clone(),
+            self.config.clone(),
+            self.config.dependency_set.clone(),
+            CrossUnsafePromoter,
+            self.chain_event_senders.clone(),
+            self.cancel_token.clone(),
+            interval,
+        )
+        .with_rewind_lock(self.rewind_lock.clone());
 
-            self.join_set.spawn(async move {
-                cross_safe_job.run().await;
-                Ok(())
-            });
+        self.join_set.spawn(async move {
+            cross_unsafe_scheduler.run().await;
+            Ok(())
+        });
+        Ok(())
+    }
 
-            let cross_unsafe_job = CrossSafetyCheckerJob::new(
+    fn init_storage_pruner(&mut self) -> Result<()> {
+        let Some(safety_margin) = self.config.prune_safety_margin else {
+            return Ok(());
+        };
+        info!(target: "supervisor::service", safety_margin, "Initialising storage pruner...");
+
+        for (&chain_id, config) in &self.config.rollup_config_set.rollups {
+            let db = self.database_factory.get_db(chain_id)?;
+            let pruner = StoragePrunerJob::new(
                 chain_id,
                 db,
-                cancel,
-                Duration::from_secs(config.block_time),
-                CrossUnsafePromoter,
-                chain_event_sender,
-                self.config.clone(),
+                self.database_factory.clone(),
+                self.config.dependency_set.clone(),
+                self.cancel_token.clone(),
+                Duration::from_secs(60),
+                config.block_time,
+                safety_margin,
             );
 
             self.join_set.spawn(async move {
-                cross_unsafe_job.run().await;
+                pruner.run().await;
                 Ok(())
             });
         }
         Ok(())
     }
 
+    /// Reloads the dependency set on `SIGHUP`, if the dependency set path is configured.
+    #[cfg(unix)]
+    fn init_dependency_set_reloader(&mut self) -> Result<()> {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        if self.config.dependency_set_path.is_none() {
+            return Ok(());
+        }
+        info!(target: "supervisor::service", "Reloading dependency set on SIGHUP");
+
+        let mut sighup = signal(SignalKind::hangup())?;
+        let supervisor = self.supervisor.clone();
+        let cancel_token = self.cancel_token.clone();
+        self.join_set.spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = cancel_token.cancelled() => return Ok(()),
+                    received = sighup.recv() => {
+                        if received.is_none() {
+                            return Ok(());
+                        }
+                        if let Err(err) = supervisor.reload_dependency_set().await {
+                            error!(target: "supervisor::service", %err, "Failed to reload dependency set");
+                        }
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    const fn init_dependency_set_reloader(&mut self) -> Result<()> {
+        Ok(())
+    }
+
     async fn init_metric_reporter(&mut self) {
         // Initialize the metric reporter actor.
         let database_factory = self.database_factory.clone();
@@ -362,6 +578,12 @@ impl Service {
 
         let mut rpc_module = supervisor_rpc.into_rpc();
 
+        if let Some(event_sink) = &self.event_sink {
+            rpc_module
+                .merge(EventsRpc::new(event_sink.clone(), self.supervisor.clone()).into_rpc())
+                .map_err(|err| anyhow::anyhow!("failed to merge Events RPC module: {err}"))?;
+        }
+
         if self.config.enable_admin_api {
             info!(target: "supervisor::service", "Enabling Supervisor Admin API");
 
@@ -400,7 +622,146 @@ impl Service {
 
                 let _ = resp.send(result);
             }
+            AdminRequest::RemoveL2Rpc { url, resp } => {
+                let result = self.remove_managed_node(&url).await.map_err(|e| {
+                    tracing::error!(target: "supervisor::service", %e, "admin remove_l2_rpc failed");
+                    AdminError::ServiceError(e.to_string())
+                });
+
+                let _ = resp.send(result);
+            }
+            AdminRequest::ListL2Rpcs { resp } => {
+                let _ = resp.send(Ok(self.managed_node_infos()));
+            }
+            AdminRequest::ReloadDependencySet { resp } => {
+                let result = self.supervisor.reload_dependency_set().await.map_err(|e| {
+                    tracing::error!(target: "supervisor::service", %e, "admin reload_dependency_set failed");
+                    AdminError::ServiceError(e.to_string())
+                });
+
+                let _ = resp.send(result);
+            }
+            AdminRequest::PauseChain { chain_id, resp } => {
+                let result =
+                    self.control_chain_processor(chain_id, ChainProcessorControl::Pause).await;
+                if result.is_ok() {
+                    self.paused_chains.insert(chain_id);
+                }
+                let _ = resp.send(result);
+            }
+            AdminRequest::ResumeChain { chain_id, resp } => {
+                let result =
+                    self.control_chain_processor(chain_id, ChainProcessorControl::Resume).await;
+                if result.is_ok() {
+                    self.paused_chains.remove(&chain_id);
+                }
+                let _ = resp.send(result);
+            }
+            AdminRequest::RewindChain { chain_id, block_id, resp } => {
+                let _ = resp.send(self.rewind_chain(chain_id, block_id).await);
+            }
+            AdminRequest::ResetL2Rpcs { chain_id, resp } => {
+                let _ = resp.send(self.reset_managed_nodes(chain_id).await);
+            }
+            AdminRequest::PendingChainEvents { chain_id, resp } => {
+                let _ = resp.send(self.pending_chain_events(chain_id).await);
+            }
+        }
+    }
+
+    /// Sends a control command to the chain processor of the chain.
+    async fn control_chain_processor(
+        &self,
+        chain_id: ChainId,
+        control: ChainProcessorControl,
+    ) -> Result<(), AdminError> {
+        let control_tx = self
+            .chain_processor_controls
+            .get(&chain_id)
+            .ok_or(AdminError::ChainNotFound(chain_id))?;
+        control_tx.send(control).await.map_err(|_| {
+            error!(target: "supervisor::service", chain_id, "Chain processor control channel closed");
+            AdminError::ServiceError(format!("chain processor of chain {chain_id} is not running"))
+        })
+    }
+
+    /// Returns the events queued for the chain processor of the chain.
+    async fn pending_chain_events(
+        &self,
+        chain_id: ChainId,
+    ) -> Result<Vec<ChainEventInfo>, AdminError> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        self.control_chain_processor(
+            chain_id,
+            ChainProcessorControl::PendingEvents { resp: resp_tx },
+        )
+        .await?;
+        let events = resp_rx.await.map_err(|_| AdminError::SenderDropped)?;
+        Ok(events.iter().map(ChainEventInfo::from).collect())
+    }
+
+    /// Rewinds the storage of the chain to the given block (inclusive), and resets its managed
+    /// nodes to the rewound state.
+    ///
+    /// The chain processor is paused for the duration of the rewind, and the events queued for it
+    /// are dropped, since they describe the chain before the rewind. It is resumed afterwards,
+    /// unless it was paused by an admin request. The cross-safety schedulers and the L1 watcher
+    /// are held off through the [`RewindLock`] until the rewind completes.
+    async fn rewind_chain(&self, chain_id: ChainId, to: BlockNumHash) -> Result<(), AdminError> {
+        let reorg_handler = self
+            .reorg_handler
+            .as_ref()
+            .ok_or_else(|| AdminError::ServiceError("reorg handler not initialised".to_string()))?;
+
+        // Taken before pausing the chain processor, so the jobs holding off the rewind can still
+        // deliver their events.
+        let _rewind = self.rewind_lock.rewind().await;
+        self.control_chain_processor(chain_id, ChainProcessorControl::Pause).await?;
+        let result = self.rewind_paused_chain(reorg_handler, chain_id, to).await;
+        if !self.paused_chains.contains(&chain_id) {
+            self.control_chain_processor(chain_id, ChainProcessorControl::Resume).await?;
         }
+        result
+    }
+
+    /// Rewinds the chain, whose chain processor is paused.
+    async fn rewind_paused_chain(
+        &self,
+        reorg_handler: &ReorgHandler<F::ChainDb>,
+        chain_id: ChainId,
+        to: BlockNumHash,
+    ) -> Result<(), AdminError> {
+        // The response is only sent once the chain processor is idle, so no event is processed
+        // concurrently with the rewind.
+        let (resp_tx, resp_rx) = oneshot::channel();
+        self.control_chain_processor(
+            chain_id,
+            ChainProcessorControl::ClearPending { resp: resp_tx },
+        )
+        .await?;
+        let cleared = resp_rx.await.map_err(|_| AdminError::SenderDropped)?;
+        info!(target: "supervisor::service", chain_id, cleared, "Dropped queued events before rewind");
+
+        reorg_handler.rewind_chain(chain_id, to).await.map_err(|err| {
+            error!(target: "supervisor::service", chain_id, %err, "admin rewind_chain failed");
+            match err {
+                ReorgHandlerError::DatabaseMissing(chain_id) => AdminError::ChainNotFound(chain_id),
+                err => AdminError::ServiceError(err.to_string()),
+            }
+        })?;
+        info!(target: "supervisor::service", chain_id, block = to.number, "Chain rewound by admin request");
+
+        self.reset_managed_nodes(chain_id).await
+    }
+
+    /// Resets the managed nodes of the chain to the supervisor's state.
+    async fn reset_managed_nodes(&self, chain_id: ChainId) -> Result<(), AdminError> {
+        let managed_node_tx =
+            self.managed_node_senders.get(&chain_id).ok_or(AdminError::ChainNotFound(chain_id))?;
+        managed_node_tx.send(ManagedNodeCommand::Reset {}).await.map_err(|_| {
+            error!(target: "supervisor::service", chain_id, "Managed node command channel closed");
+            AdminError::ServiceError(format!("managed nodes of chain {chain_id} are not running"))
+        })
     }
 
     /// Runs the Supervisor service.
@@ -477,6 +838,7 @@ mod tests {
 
     use kona_interop::DependencySet;
     use kona_supervisor_core::config::RollupConfigSet;
+    use kona_supervisor_storage::InMemoryChainDbFactory;
 
     use super::*;
 
@@ -490,8 +852,12 @@ mod tests {
             DependencySet {
                 dependencies: Default::default(),
                 override_message_expiry_window: None,
-            },
+            }
+            .into(),
+            None,
             RollupConfigSet { rollups: HashMap::new() },
+            None,
+            None,
         );
         cfg.enable_admin_api = enable_admin;
         cfg
@@ -506,4 +872,99 @@ mod tests {
         svc.init_rpc_server().await.expect("init_rpc_server failed");
         assert!(svc.admin_receiver.is_some(), "admin_receiver must be set when admin enabled");
     }
+
+    #[tokio::test]
+    async fn test_admin_chain_requests_for_unknown_chain() {
+        let mut svc = Service::new(make_test_config(true));
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+        svc.handle_admin_request(AdminRequest::PauseChain { chain_id: 10, resp: resp_tx }).await;
+        assert!(matches!(resp_rx.await.unwrap(), Err(AdminError::ChainNotFound(10))));
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+        svc.handle_admin_request(AdminRequest::ResetL2Rpcs { chain_id: 10, resp: resp_tx }).await;
+        assert!(matches!(resp_rx.await.unwrap(), Err(AdminError::ChainNotFound(10))));
+    }
+
+    #[tokio::test]
+    async fn test_admin_pause_chain_forwards_control() {
+        let mut svc = Service::new(make_test_config(true));
+        let (control_tx, mut control_rx) = mpsc::channel(1);
+        svc.chain_processor_controls.insert(10, control_tx);
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+        svc.handle_admin_request(AdminRequest::PauseChain { chain_id: 10, resp: resp_tx }).await;
+        assert!(resp_rx.await.unwrap().is_ok());
+        assert!(matches!(control_rx.recv().await, Some(ChainProcessorControl::Pause)));
+    }
+
+    #[tokio::test]
+    async fn test_service_runs_on_in_memory_storage() {
+        let mut svc =
+            Service::with_database_factory(make_test_config(true), InMemoryChainDbFactory::new());
+
+        svc.init_database().await.expect("init_database failed");
+        svc.init_rpc_server().await.expect("init_rpc_server failed");
+        assert!(svc.admin_receiver.is_some(), "admin_receiver must be set when admin enabled");
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+        svc.handle_admin_request(AdminRequest::PauseChain { chain_id: 10, resp: resp_tx }).await;
+        assert!(matches!(resp_rx.await.unwrap(), Err(AdminError::ChainNotFound(10))));
+    }
+
+    #[tokio::test]
+    async fn test_admin_rewind_chain_pauses_and_clears_chain_processor() {
+        let mut svc =
+            Service::with_database_factory(make_test_config(true), InMemoryChainDbFactory::new());
+        let l1_rpc = RpcClient::new_http(Url::parse("http://localhost:8545").unwrap());
+        svc.reorg_handler = Some(Arc::new(ReorgHandler::new(l1_rpc, HashMap::new())));
+
+        let (control_tx, mut control_rx) = mpsc::channel(10);
+        svc.chain_processor_controls.insert(10, control_tx);
+        let controls = tokio::spawn(async move {
+            let mut controls = Vec::new();
+            while let Some(control) = control_rx.recv().await {
+                controls.push(match control {
+                    ChainProcessorControl::ClearPending { resp } => {
+                        let _ = resp.send(2);
+                        "clear"
+                    }
+                    ChainProcessorControl::Pause => "pause",
+                    ChainProcessorControl::Resume => "resume",
+                    ChainProcessorControl::PendingEvents { .. } => "pending",
+                });
+            }
+            controls
+        });
+
+        // The chain has no database, so the rewind itself fails, after the queue is cleared.
+        let block_id = BlockNumHash { number: 5, hash: Default::default() };
+        let (resp_tx, resp_rx) = oneshot::channel();
+        svc.handle_admin_request(AdminRequest::RewindChain {
+            chain_id: 10,
+            block_id,
+            resp: resp_tx,
+        })
+        .await;
+        assert!(matches!(resp_rx.await.unwrap(), Err(AdminError::ChainNotFound(10))));
+
+        // A chain paused by an admin request stays paused.
+        let (resp_tx, resp_rx) = oneshot::channel();
+        svc.handle_admin_request(AdminRequest::PauseChain { chain_id: 10, resp: resp_tx }).await;
+        assert!(resp_rx.await.unwrap().is_ok());
+        let (resp_tx, resp_rx) = oneshot::channel();
+        svc.handle_admin_request(AdminRequest::RewindChain {
+            chain_id: 10,
+            block_id,
+            resp: resp_tx,
+        })
+        .await;
+        assert!(resp_rx.await.unwrap().is_err());
+
+        svc.chain_processor_controls.clear();
+        assert_eq!(
+            controls.await.unwrap(),
+            vec!["pause", "clear", "resume", "pause", "pause", "clear"]
+        );
+    }
 }
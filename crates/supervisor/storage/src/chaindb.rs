@@ -1,12 +1,13 @@
 //! Main database access structure and transaction contexts.
 
 use crate::{
-    Metrics, StorageRewinder,
+    Metrics, StoragePruner, StorageRewinder,
     error::StorageError,
-    providers::{DerivationProvider, LogProvider, SafetyHeadRefProvider},
+    providers::{DerivationProvider, LogProvider, OutputProvider, SafetyHeadRefProvider},
     traits::{
         DerivationStorageReader, DerivationStorageWriter, HeadRefStorageReader,
-        HeadRefStorageWriter, LogStorageReader, LogStorageWriter,
+        HeadRefStorageWriter, LogStorageReader, LogStorageWriter, MessageIndexReader,
+        OutputStorageReader, OutputStorageWriter,
     },
 };
 use alloy_eips::eip1898::BlockNumHash;
@@ -14,16 +15,23 @@ use alloy_primitives::ChainId;
 use kona_interop::DerivedRefPair;
 use kona_protocol::BlockInfo;
 use kona_supervisor_metrics::{MetricsReporter, observe_metrics_for_result};
-use kona_supervisor_types::{Log, SuperHead};
-use metrics::{Label, gauge};
+use kona_supervisor_types::{ChainOutput, ExecutingMessageRef, Log, MessageIdentifier, SuperHead};
+use metrics::{Label, counter, gauge};
 use op_alloy_consensus::interop::SafetyLevel;
 use reth_db::{
     DatabaseEnv,
-    mdbx::{DatabaseArguments, init_db_for},
+    mdbx::{DatabaseArguments, init_db_for, open_db_read_only},
 };
 use reth_db_api::database::Database;
 use std::path::Path;
-use tracing::warn;
+use tracing::{debug, warn};
+
+/// Default maximum number of blocks removed in a single write transaction by [`ChainDb`] when
+/// pruning.
+///
+/// The first prune of a long-running database may have to remove millions of blocks. Splitting it
+/// into batches bounds the size of each write transaction, and lets other writers interleave.
+pub const DEFAULT_PRUNE_BATCH_SIZE: u64 = 10_000;
 
 /// Manages the database environment for a single chain.
 /// Provides transactional access to data via providers.
@@ -31,6 +39,7 @@ use tracing::warn;
 pub struct ChainDb {
     chain_id: ChainId,
     metrics_enabled: Option<bool>,
+    prune_batch_size: u64,
 
     env: DatabaseEnv,
 }
@@ -39,7 +48,31 @@ impl ChainDb {
     /// Creates or opens a database environment at the given path.
     pub fn new(chain_id: ChainId, path: &Path) -> Result<Self, StorageError> {
         let env = init_db_for::<_, crate::models::Tables>(path, DatabaseArguments::default())?;
-        Ok(Self { chain_id, metrics_enabled: None, env })
+        Ok(Self {
+            chain_id,
+            metrics_enabled: None,
+            prune_batch_size: DEFAULT_PRUNE_BATCH_SIZE,
+            env,
+        })
+    }
+
+    /// Opens an existing database environment at the given path in read-only mode.
+    ///
+    /// Every write to the returned database fails, which makes it safe for offline inspection.
+    pub fn open_read_only(chain_id: ChainId, path: &Path) -> Result<Self, StorageError> {
+        let env = open_db_read_only(path, DatabaseArguments::default())?;
+        Ok(Self {
+            chain_id,
+            metrics_enabled: None,
+            prune_batch_size: DEFAULT_PRUNE_BATCH_SIZE,
+            env,
+        })
+    }
+
+    /// Sets the maximum number of blocks removed in a single write transaction when pruning.
+    pub fn with_prune_batch_size(mut self, prune_batch_size: u64) -> Self {
+        self.prune_batch_size = prune_batch_size.max(1);
+        self
     }
 
     /// Enables metrics on the database environment.
@@ -205,6 +238,18 @@ impl LogStorageReader for ChainDb {
     }
 }
 
+impl MessageIndexReader for ChainDb {
+    fn get_executing_messages(
+        &self,
+        initiating: &MessageIdentifier,
+    ) -> Result<Vec<ExecutingMessageRef>, StorageError> {
+        self.observe_call(Metrics::STORAGE_METHOD_GET_EXECUTING_MESSAGES, || {
+            self.env
+                .view(|tx| LogProvider::new(tx, self.chain_id).get_executing_messages(initiating))
+        })?
+    }
+}
+
 impl LogStorageWriter for ChainDb {
     fn initialise_log_storage(&self, block: BlockInfo) -> Result<(), StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_INITIALISE_LOG_STORAGE, || {
@@ -399,6 +444,48 @@ impl HeadRefStorageWriter for ChainDb {
     }
 }
 
+impl OutputStorageReader for ChainDb {
+    fn get_finalized_output(&self, timestamp: u64) -> Result<Option<ChainOutput>, StorageError> {
+        self.observe_call(Metrics::STORAGE_METHOD_GET_FINALIZED_OUTPUT, || {
+            self.env.view(|tx| OutputProvider::new(tx, self.chain_id).get_output(timestamp))
+        })?
+    }
+}
+
+impl OutputStorageWriter for ChainDb {
+    fn save_finalized_output(
+        &self,
+        timestamp: u64,
+        output: ChainOutput,
+    ) -> Result<(), StorageError> {
+        self.observe_call(Metrics::STORAGE_METHOD_SAVE_FINALIZED_OUTPUT, || {
+            self.env.update(|tx| {
+                let lp = LogProvider::new(tx, self.chain_id);
+                let hp = SafetyHeadRefProvider::new(tx, self.chain_id);
+
+                let finalized = hp.get_safety_head_ref(SafetyLevel::Finalized)?;
+                if output.block_number > finalized.number {
+                    return Err(StorageError::FutureData);
+                }
+
+                let stored_block = lp.get_block(output.block_number)?;
+                if stored_block.hash != output.output.block_hash {
+                    warn!(
+                        target: "supervisor::storage",
+                        chain_id = %self.chain_id,
+                        output_block_hash = %output.output.block_hash,
+                        stored_block_hash = %stored_block.hash,
+                        "Hash mismatch while caching finalized output",
+                    );
+                    return Err(StorageError::ConflictError);
+                }
+
+                OutputProvider::new(tx, self.chain_id).save_output(timestamp, output)
+            })?
+        })
+    }
+}
+
 impl StorageRewinder for ChainDb {
     fn rewind_log_storage(&self, to: &BlockNumHash) -> Result<(), StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_REWIND_LOG_STORAGE, || {
@@ -456,6 +543,7 @@ impl StorageRewinder for ChainDb {
 
                 lp.rewind_to(to)?;
                 dp.rewind_to(to)?;
+                OutputProvider::new(tx, self.chain_id).rewind_to(to.number)?;
 
                 // get the current latest block to update the safety head refs
                 match lp.get_latest_block() {
@@ -492,6 +580,7 @@ impl StorageRewinder for ChainDb {
                 let derived_target_block = dp.rewind_to_source(to)?;
                 if let Some(rewind_target) = derived_target_block {
                     lp.rewind_to(&rewind_target.id())?;
+                    OutputProvider::new(tx, self.chain_id).rewind_to(rewind_target.number)?;
                 }
 
                 // get the current latest block to update the safety head refs
@@ -520,6 +609,117 @@ impl StorageRewinder for ChainDb {
     }
 }
 
+impl StoragePruner for ChainDb {
+    fn prune(&self, block_number: u64) -> Result<u64, StorageError> {
+        let metrics_enabled = self.metrics_enabled.unwrap_or(false);
+        let size_before = if metrics_enabled { self.tables_size() } else { None };
+
+        let mut pruned_blocks = 0;
+        loop {
+            let (pruned, done) = self.observe_call(Metrics::STORAGE_METHOD_PRUNE, || {
+                self.env.update(|tx| {
+                    let lp = LogProvider::new(tx, self.chain_id);
+                    let dp = DerivationProvider::new(tx, self.chain_id);
+                    let hp = SafetyHeadRefProvider::new(tx, self.chain_id);
+
+                    // Never prune past the finalized head, as anything above it may still be
+                    // reorged.
+                    let finalized = hp.get_safety_head_ref(SafetyLevel::Finalized)?;
+                    let target = block_number.min(finalized.number);
+                    let batch_target = lp.oldest_prunable_block()?.map_or(target, |oldest| {
+                        target.min(oldest.saturating_add(self.prune_batch_size))
+                    });
+
+                    dp.prune_to(batch_target)?;
+                    OutputProvider::new(tx, self.chain_id).prune_to(batch_target)?;
+                    Ok::<_, StorageError>((lp.prune_to(batch_target)?, batch_target == target))
+                })?
+            })?;
+
+            pruned_blocks += pruned;
+            if done {
+                break;
+            }
+            debug!(
+                target: "supervisor::storage",
+                chain_id = %self.chain_id,
+                pruned_blocks,
+                "Pruned batch of blocks"
+            );
+        }
+
+        if metrics_enabled && pruned_blocks > 0 {
+            let chain_id = self.chain_id.to_string();
+            counter!(Metrics::STORAGE_PRUNED_BLOCKS_TOTAL, "chain_id" => chain_id.clone())
+                .increment(pruned_blocks);
+
+            // MDBX never shrinks the database file: pages freed by the prune go to its free list,
+            // and new entries are allocated from there before the file grows again. The shrinking
+            // of the tables is therefore the space made available for reuse, not released to the
+            // filesystem.
+            if let (Some(before), Some(after)) = (size_before, self.tables_size()) {
+                counter!(Metrics::STORAGE_FREED_BYTES_TOTAL, "chain_id" => chain_id)
+                    .increment(before.saturating_sub(after));
+            }
+        }
+        Ok(pruned_blocks)
+    }
+}
+
+impl ChainDb {
+    /// Moves the cross head of the given level back to the parent of `block`, undoing its
+    /// promotion.
+    ///
+    /// Fails with [`StorageError::ConflictError`] if `block` is not the current cross head.
+    pub fn revert_cross_head(
+        &self,
+        level: SafetyLevel,
+        block: &BlockInfo,
+    ) -> Result<(), StorageError> {
+        if !matches!(level, SafetyLevel::CrossUnsafe | SafetyLevel::CrossSafe) {
+            return Err(StorageError::ConflictError);
+        }
+
+        self.env.update(|tx| {
+            let lp = LogProvider::new(tx, self.chain_id);
+            let hp = SafetyHeadRefProvider::new(tx, self.chain_id);
+
+            let current = hp.get_safety_head_ref(level)?;
+            let parent = match block.number.checked_sub(1) {
+                Some(number) if current == *block => lp.get_block(number)?,
+                _ => return Err(StorageError::ConflictError),
+            };
+            if !parent.is_parent_of(block) {
+                return Err(StorageError::ConflictError);
+            }
+
+            hp.reset_safety_head_ref_if_ahead(level, &parent)
+        })?
+    }
+
+    /// Returns the total size of the tables, in bytes.
+    fn tables_size(&self) -> Option<u64> {
+        self.env
+            .view(|tx| {
+                let mut size = 0;
+                for table in crate::models::Tables::ALL.iter().map(crate::models::Tables::name) {
+                    let table_db = tx.inner.open_db(Some(table))?;
+                    let stats = tx.inner.db_stat(&table_db)?;
+                    let num_pages =
+                        stats.leaf_pages() + stats.branch_pages() + stats.overflow_pages();
+                    size += stats.page_size() as u64 * num_pages as u64;
+                }
+                Ok::<u64, eyre::Report>(size)
+            })
+            .map_err(eyre::Report::from)
+            .and_then(|size| size)
+            .inspect_err(|err| {
+                warn!(target: "supervisor::storage", %err, "Failed to collect database size");
+            })
+            .ok()
+    }
+}
+
 impl MetricsReporter for ChainDb {
     fn report_metrics(&self) {
         let mut metrics = Vec::new();
@@ -612,6 +812,23 @@ mod tests {
         assert!(db.is_ok(), "Should create or open database");
     }
 
+    #[test]
+    fn test_open_read_only_db() {
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let db_path = tmp_dir.path().join("chaindb_read_only");
+        assert!(ChainDb::open_read_only(1, &db_path).is_err(), "Should not create database");
+
+        let block = BlockInfo { number: 0, ..Default::default() };
+        let db = ChainDb::new(1, &db_path).expect("create db");
+        db.initialise_log_storage(block).expect("initialise log storage");
+        drop(db);
+
+        let db = ChainDb::open_read_only(1, &db_path).expect("open db read-only");
+        assert_eq!(db.get_latest_block(), Ok(block));
+        assert!(db.store_block_logs(&BlockInfo { number: 1, ..block }, Vec::new()).is_err());
+        assert_eq!(db.get_latest_block(), Ok(block));
+    }
+
     #[test]
     fn test_log_storage() {
         let tmp_dir = TempDir::new().expect("create temp dir");
@@ -872,6 +1089,40 @@ mod tests {
         assert_eq!(cross_unsafe_block, block2);
     }
 
+    #[test]
+    fn test_revert_cross_head() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = tmp_dir.path().join("chaindb");
+        let db = ChainDb::new(1, &db_path).unwrap();
+
+        let source = BlockInfo { number: 1, ..Default::default() };
+        let block1 = BlockInfo {
+            number: 10,
+            hash: B256::random(),
+            parent_hash: B256::random(),
+            timestamp: 1,
+        };
+        let block2 =
+            BlockInfo { number: 11, hash: B256::random(), parent_hash: block1.hash, timestamp: 1 };
+
+        db.initialise_log_storage(block1).expect("initialise log storage");
+        db.initialise_derivation_storage(DerivedRefPair { source, derived: block1 })
+            .expect("initialise derivation storage");
+        db.store_block_logs(&block2, vec![]).unwrap();
+
+        // block2 is not the cross-unsafe head yet
+        let err = db.revert_cross_head(SafetyLevel::CrossUnsafe, &block2).unwrap_err();
+        assert!(matches!(err, StorageError::ConflictError));
+
+        db.update_current_cross_unsafe(&block2).unwrap();
+        db.revert_cross_head(SafetyLevel::CrossUnsafe, &block2).unwrap();
+        assert_eq!(db.get_safety_head_ref(SafetyLevel::CrossUnsafe).unwrap(), block1);
+
+        // only cross heads can be reverted
+        let err = db.revert_cross_head(SafetyLevel::LocalUnsafe, &block2).unwrap_err();
+        assert!(matches!(err, StorageError::ConflictError));
+    }
+
     #[test]
     fn test_update_current_cross_safe() {
         let tmp_dir = tempfile::TempDir::new().unwrap();
@@ -1620,4 +1871,118 @@ mod tests {
         let latest_pair = db.latest_derivation_state().expect("latest derivation state");
         assert_eq!(latest_pair, anchor);
     }
+
+    /// Creates a database holding the derived blocks `0..6`, with block `0` as the activation
+    /// block.
+    fn prunable_db(path: &Path) -> (ChainDb, Vec<DerivedRefPair>, Log) {
+        let db = ChainDb::new(1, path).expect("create db");
+
+        let mut pairs: Vec<DerivedRefPair> = Vec::new();
+        for i in 0..6u8 {
+            let (source_parent, derived_parent) = pairs
+                .last()
+                .map_or((B256::ZERO, B256::ZERO), |pair| (pair.source.hash, pair.derived.hash));
+            pairs.push(DerivedRefPair {
+                source: BlockInfo {
+                    hash: B256::from([100 + i; 32]),
+                    number: 100 + i as u64,
+                    parent_hash: source_parent,
+                    timestamp: i as u64,
+                },
+                derived: BlockInfo {
+                    hash: B256::from([i + 1; 32]),
+                    number: i as u64,
+                    parent_hash: derived_parent,
+                    timestamp: i as u64,
+                },
+            });
+        }
+        let log = Log { index: 0, hash: B256::from([42u8; 32]), executing_message: None };
+
+        db.initialise_log_storage(pairs[0].derived).expect("initialise log storage");
+        db.initialise_derivation_storage(pairs[0]).expect("initialise derivation storage");
+        for pair in &pairs[1..] {
+            db.store_block_logs(&pair.derived, vec![log.clone()]).expect("store logs");
+            db.save_source_block(pair.source).expect("save source block");
+            db.save_derived_block(*pair).expect("save derived block");
+            db.update_current_cross_safe(&pair.derived).expect("update cross safe");
+        }
+        (db, pairs, log)
+    }
+
+    #[test]
+    fn test_prune_keeps_activation_and_reports_pruned_entries() {
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let (db, pairs, log) = prunable_db(&tmp_dir.path().join("chaindb_prune"));
+
+        // Pruning requires a finalized head.
+        assert!(matches!(db.prune(3), Err(StorageError::FutureData)));
+
+        let finalized = db.update_finalized_using_source(pairs[4].source).expect("finalize");
+        assert_eq!(finalized, pairs[4].derived);
+
+        // The prune target is capped at the finalized head.
+        assert_eq!(db.prune(10), Ok(3));
+        assert_eq!(db.prune(10), Ok(0));
+
+        // The activation entries are kept.
+        assert_eq!(db.get_activation_block(), Ok(pairs[0].derived));
+        assert_eq!(db.get_block(0), Ok(pairs[0].derived));
+        assert_eq!(db.get_source_block(100), Ok(pairs[0].source));
+
+        // Pruned entries are reported as such.
+        for number in 1..4 {
+            assert_eq!(db.get_block(number), Err(StorageError::EntryPruned(number)));
+            assert_eq!(db.get_log(number, 0), Err(StorageError::EntryPruned(number)));
+            assert_eq!(db.get_logs(number), Err(StorageError::EntryPruned(number)));
+            assert_eq!(
+                db.derived_to_source(pairs[number as usize].derived.id()),
+                Err(StorageError::EntryPruned(number))
+            );
+            assert_eq!(
+                db.get_source_block(100 + number),
+                Err(StorageError::EntryPruned(100 + number))
+            );
+        }
+
+        // Entries from the finalized head onward are untouched.
+        assert_eq!(db.get_block(4), Ok(pairs[4].derived));
+        assert_eq!(db.get_log(4, 0), Ok(log.clone()));
+        assert_eq!(db.get_source_block(104), Ok(pairs[4].source));
+        assert_eq!(db.derived_to_source(pairs[5].derived.id()), Ok(pairs[5].source));
+        assert_eq!(db.latest_derived_block_at_source(pairs[5].source.id()), Ok(pairs[5].derived));
+        assert!(matches!(db.get_block(6), Err(StorageError::EntryNotFound(_))));
+
+        // New blocks can still be stored on top.
+        let next = BlockInfo {
+            hash: B256::from([7u8; 32]),
+            number: 6,
+            parent_hash: pairs[5].derived.hash,
+            timestamp: 6,
+        };
+        db.store_block_logs(&next, vec![log]).expect("store logs after prune");
+        assert_eq!(db.get_latest_block(), Ok(next));
+    }
+
+    #[test]
+    fn test_prune_in_batches() {
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let (db, pairs, _) = prunable_db(&tmp_dir.path().join("chaindb_prune_batches"));
+        let db = db.with_prune_batch_size(1);
+        db.update_finalized_using_source(pairs[5].source).expect("finalize");
+
+        // Every block is removed, one write transaction at a time.
+        assert_eq!(db.prune(5), Ok(4));
+        assert_eq!(db.prune(5), Ok(0));
+        for number in 1..5 {
+            assert_eq!(db.get_block(number), Err(StorageError::EntryPruned(number)));
+            assert_eq!(
+                db.get_source_block(100 + number),
+                Err(StorageError::EntryPruned(100 + number))
+            );
+        }
+        assert_eq!(db.get_block(0), Ok(pairs[0].derived));
+        assert_eq!(db.get_block(5), Ok(pairs[5].derived));
+        assert_eq!(db.derived_to_source(pairs[5].derived.id()), Ok(pairs[5].source));
+    }
 }
@@ -6,7 +6,7 @@ use std::{
 
 use crate::{
     CrossChainSafetyProvider, FinalizedL1Storage, HeadRefStorageReader, HeadRefStorageWriter,
-    LogStorageReader, Metrics, chaindb::ChainDb, error::StorageError,
+    LogStorageReader, Metrics, StorageFactory, chaindb::ChainDb, error::StorageError,
 };
 use alloy_primitives::ChainId;
 use kona_interop::DerivedRefPair;
@@ -64,11 +64,15 @@ impl ChainDbFactory {
             f()
         }
     }
+}
+
+impl StorageFactory for ChainDbFactory {
+    type ChainDb = ChainDb;
 
     /// Get or create a [`ChainDb`] for the given chain id.
     ///
     /// If the database does not exist, it will be created at the path `self.db_path/<chain_id>`.
-    pub fn get_or_create_db(&self, chain_id: ChainId) -> Result<Arc<ChainDb>, StorageError> {
+    fn get_or_create_db(&self, chain_id: ChainId) -> Result<Arc<ChainDb>, StorageError> {
         {
             // Try to get it without locking for write
             let dbs = self.dbs.read().map_err(|err| {
@@ -105,7 +109,7 @@ impl ChainDbFactory {
     /// # Returns
     /// * `Ok(Arc<ChainDb>)` if the database exists.
     /// * `Err(StorageError)` if the database does not exist.
-    pub fn get_db(&self, chain_id: ChainId) -> Result<Arc<ChainDb>, StorageError> {
+    fn get_db(&self, chain_id: ChainId) -> Result<Arc<ChainDb>, StorageError> {
         let dbs = self.dbs.read().map_err(|_| StorageError::LockPoisoned)?;
         dbs.get(&chain_id).cloned().ok_or_else(|| StorageError::DatabaseNotInitialised)
     }
@@ -220,6 +224,15 @@ impl CrossChainSafetyProvider for ChainDbFactory {
     ) -> Result<DerivedRefPair, StorageError> {
         self.get_db(chain_id)?.update_current_cross_safe(block)
     }
+
+    fn revert_cross_head(
+        &self,
+        chain_id: ChainId,
+        level: SafetyLevel,
+        block: &BlockInfo,
+    ) -> Result<(), StorageError> {
+        self.get_db(chain_id)?.revert_cross_head(level, block)
+    }
 }
 
 #[cfg(test)]
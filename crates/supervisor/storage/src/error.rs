@@ -23,6 +23,10 @@ pub enum StorageError {
     #[error(transparent)]
     EntryNotFound(#[from] EntryNotFoundError),
 
+    /// The requested entry was pruned, as it is older than the message expiry window.
+    #[error("entry pruned, block number: {0}")]
+    EntryPruned(u64),
+
     /// Represents an error that occurred while getting data that is not yet available.
     #[error("data not yet available")]
     FutureData,
@@ -61,6 +65,7 @@ impl PartialEq for StorageError {
             (Database(a), Database(b)) => a == b,
             (DatabaseInit(a), DatabaseInit(b)) => format!("{a}") == format!("{b}"),
             (EntryNotFound(a), EntryNotFound(b)) => a == b,
+            (EntryPruned(a), EntryPruned(b)) => a == b,
             (DatabaseNotInitialised, DatabaseNotInitialised) | (ConflictError, ConflictError) => {
                 true
             }
@@ -0,0 +1,674 @@
+//! Consistency checks of the supervisor storage.
+//!
+//! A crash in the middle of a write, or a faulty rewind, can leave the log storage, the
+//! derivation storage and the safety head references of a chain out of sync. The
+//! [`IntegrityChecker`] walks the storage of a chain, reports every violation of the storage
+//! invariants, and can roll the storage back to the last consistent block.
+
+use crate::{DbReader, StorageError, StorageRewinder};
+use alloy_eips::eip1898::BlockNumHash;
+use alloy_primitives::B256;
+use kona_protocol::BlockInfo;
+use op_alloy_consensus::interop::SafetyLevel;
+use thiserror::Error;
+use tracing::{info, warn};
+
+/// A violation of the storage invariants.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum IntegrityViolation {
+    /// A log block is missing below the latest log block.
+    #[error("log block {0} is missing")]
+    MissingLogBlock(u64),
+
+    /// A log block does not link to the log block below it.
+    #[error("log block {block} does not link to log block {parent}")]
+    LogBlockNotLinked {
+        /// The stored block.
+        block: BlockInfo,
+        /// The stored block below it.
+        parent: BlockInfo,
+    },
+
+    /// The latest log block is not the local unsafe head.
+    #[error("latest log block {latest_block} is not the local unsafe head {local_unsafe}")]
+    LogBlocksPastUnsafeHead {
+        /// The latest log block.
+        latest_block: BlockInfo,
+        /// The local unsafe head.
+        local_unsafe: BlockInfo,
+    },
+
+    /// A derived block is missing below the latest derived block.
+    #[error("derived block {0} is missing")]
+    MissingDerivedBlock(u64),
+
+    /// A derived block is not in the log storage, or differs from the log block at its height.
+    #[error("derived block {0} does not match the log storage")]
+    DerivedBlockNotInLogs(u64),
+
+    /// The source of a derived block is not a stored source block.
+    #[error("source {source} of derived block {derived} is not stored")]
+    UnknownSourceBlock {
+        /// The derived block.
+        derived: BlockInfo,
+        /// The source of the derived block.
+        source: BlockInfo,
+    },
+
+    /// A source block is missing below the latest source block.
+    #[error("source block {0} is missing")]
+    MissingSourceBlock(u64),
+
+    /// A source block does not link to the source block below it.
+    #[error("source block {block} does not link to source block {parent}")]
+    SourceBlockNotLinked {
+        /// The stored source block.
+        block: BlockInfo,
+        /// The stored source block below it.
+        parent: BlockInfo,
+    },
+
+    /// A safety head reference points to a block which is not stored.
+    #[error("{safety_level} head {head} is not stored")]
+    UnknownHeadRef {
+        /// The safety level of the head.
+        safety_level: SafetyLevel,
+        /// The head reference.
+        head: BlockInfo,
+    },
+
+    /// A safety head reference is ahead of the head of a weaker safety level.
+    #[error("{safety_level} head {head} is ahead of {weaker_level} head {weaker_head}")]
+    HeadRefAhead {
+        /// The safety level of the head.
+        safety_level: SafetyLevel,
+        /// The head reference.
+        head: BlockInfo,
+        /// The weaker safety level.
+        weaker_level: SafetyLevel,
+        /// The head reference of the weaker safety level.
+        weaker_head: BlockInfo,
+    },
+}
+
+/// The result of an integrity check.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// All violations found.
+    pub violations: Vec<IntegrityViolation>,
+    /// The lowest derived block number affected by a violation. All blocks below it are
+    /// consistent.
+    pub first_inconsistent_block: Option<u64>,
+}
+
+impl IntegrityReport {
+    /// Returns `true` if no violations were found.
+    pub const fn is_consistent(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    fn record(&mut self, violation: IntegrityViolation, affected_block: u64) {
+        warn!(target: "supervisor::storage", %violation, affected_block, "Integrity violation");
+        self.violations.push(violation);
+        self.first_inconsistent_block = Some(
+            self.first_inconsistent_block.map_or(affected_block, |block| block.min(affected_block)),
+        );
+    }
+}
+
+/// Heads which must not be ahead of the head of a weaker safety level, paired with the weaker
+/// level.
+const HEAD_ORDER: [(SafetyLevel, SafetyLevel); 4] = [
+    (SafetyLevel::CrossUnsafe, SafetyLevel::LocalUnsafe),
+    (SafetyLevel::LocalSafe, SafetyLevel::LocalUnsafe),
+    (SafetyLevel::CrossSafe, SafetyLevel::LocalSafe),
+    (SafetyLevel::Finalized, SafetyLevel::CrossSafe),
+];
+
+/// Checks the consistency of the storage of a single chain.
+///
+/// The checker verifies that
+/// - log blocks and source blocks form contiguous hash chains,
+/// - every derived block is a stored log block, derived from a stored source block,
+/// - every safety head reference points to a stored block, and is not ahead of the head of a weaker
+///   safety level.
+///
+/// Pruned ranges are skipped.
+#[derive(Debug)]
+pub struct IntegrityChecker<'a, DB> {
+    db: &'a DB,
+}
+
+impl<'a, DB> IntegrityChecker<'a, DB>
+where
+    DB: DbReader,
+{
+    /// Creates a new [`IntegrityChecker`] for the given storage.
+    pub const fn new(db: &'a DB) -> Self {
+        Self { db }
+    }
+
+    /// Checks the storage and reports all violations.
+    pub fn check(&self) -> Result<IntegrityReport, StorageError> {
+        let mut report = IntegrityReport::default();
+
+        let Some(latest_block) = not_initialised_as_none(self.db.get_latest_block())? else {
+            return Ok(report);
+        };
+        let activation = not_initialised_as_none(self.db.get_activation_block())?;
+
+        self.check_log_blocks(latest_block, activation, &mut report)?;
+        if let Some(activation) = activation {
+            self.check_derived_blocks(latest_block, activation, &mut report)?;
+            self.check_source_blocks(activation, &mut report)?;
+        }
+        self.check_head_refs(latest_block, &mut report)?;
+
+        info!(
+            target: "supervisor::storage",
+            violations = report.violations.len(),
+            first_inconsistent_block = ?report.first_inconsistent_block,
+            "Storage integrity check completed"
+        );
+        Ok(report)
+    }
+
+    /// Walks the log blocks from the latest one down to the activation block.
+    fn check_log_blocks(
+        &self,
+        latest_block: BlockInfo,
+        activation: Option<BlockInfo>,
+        report: &mut IntegrityReport,
+    ) -> Result<(), StorageError> {
+        let floor = activation.map_or(0, |block| block.number);
+        let mut child = Some(latest_block);
+        let mut number = latest_block.number;
+        while number > floor {
+            number -= 1;
+            match self.db.get_block(number) {
+                Ok(parent) => {
+                    if let Some(block) = child.filter(|block| !parent.is_parent_of(block)) {
+                        report.record(
+                            IntegrityViolation::LogBlockNotLinked { block, parent },
+                            block.number,
+                        );
+                    }
+                    child = Some(parent);
+                }
+                // Without derivation data, the first missing block is the start of the storage.
+                Err(StorageError::EntryNotFound(_)) if activation.is_none() => break,
+                Err(StorageError::EntryNotFound(_)) => {
+                    report.record(IntegrityViolation::MissingLogBlock(number), number);
+                    child = None;
+                }
+                Err(StorageError::EntryPruned(_)) => break,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+
+    /// Walks the derived blocks from the latest one down to the activation block.
+    fn check_derived_blocks(
+        &self,
+        latest_block: BlockInfo,
+        activation: BlockInfo,
+        report: &mut IntegrityReport,
+    ) -> Result<(), StorageError> {
+        let latest_derived = self.db.latest_derivation_state()?.derived;
+        for number in (activation.number..=latest_derived.number).rev() {
+            let block = match self.db.get_block(number) {
+                Ok(block) => block,
+                Err(StorageError::EntryPruned(_)) => break,
+                // Derived blocks above the latest log block are rewound together with it.
+                Err(StorageError::EntryNotFound(_)) if number > latest_block.number => {
+                    report.record(
+                        IntegrityViolation::DerivedBlockNotInLogs(number),
+                        latest_block.number,
+                    );
+                    continue;
+                }
+                // Missing log blocks are reported by the log block check.
+                Err(StorageError::EntryNotFound(_)) => continue,
+                Err(err) => return Err(err),
+            };
+
+            let source = match self.db.derived_to_source(block.id()) {
+                Ok(source) => source,
+                Err(StorageError::EntryPruned(_)) => break,
+                Err(StorageError::EntryNotFound(_)) => {
+                    report.record(IntegrityViolation::MissingDerivedBlock(number), number);
+                    continue;
+                }
+                Err(StorageError::ConflictError) => {
+                    report.record(IntegrityViolation::DerivedBlockNotInLogs(number), number);
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            match self.db.get_source_block(source.number) {
+                Ok(stored) if stored == source => {}
+                Ok(_) | Err(StorageError::EntryNotFound(_)) => report.record(
+                    IntegrityViolation::UnknownSourceBlock { derived: block, source },
+                    number,
+                ),
+                Err(StorageError::EntryPruned(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+
+    /// Walks the source blocks from the latest one down to the source of the activation block.
+    fn check_source_blocks(
+        &self,
+        activation: BlockInfo,
+        report: &mut IntegrityReport,
+    ) -> Result<(), StorageError> {
+        let floor = match self.db.derived_to_source(activation.id()) {
+            Ok(source) => source.number,
+            Err(StorageError::EntryNotFound(_) | StorageError::ConflictError) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let latest_source = self.db.latest_derivation_state()?.source;
+
+        let mut child = Some(latest_source);
+        // Source blocks above a stored source block, which are missing or do not link to it.
+        let mut invalid_sources = Vec::new();
+        let mut number = latest_source.number;
+        while number > floor {
+            number -= 1;
+            match self.db.get_source_block(number) {
+                Ok(parent) => {
+                    if let Some(block) = child.filter(|block| !parent.is_parent_of(block)) {
+                        invalid_sources
+                            .push(IntegrityViolation::SourceBlockNotLinked { block, parent });
+                    }
+                    if !invalid_sources.is_empty() {
+                        // Blocks derived after this source block are affected.
+                        let affected_block =
+                            match self.db.latest_derived_block_at_source(parent.id()) {
+                                Ok(derived) => derived.number + 1,
+                                Err(StorageError::EntryNotFound(_)) => activation.number + 1,
+                                Err(err) => return Err(err),
+                            };
+                        for violation in invalid_sources.drain(..) {
+                            report.record(violation, affected_block);
+                        }
+                    }
+                    child = Some(parent);
+                }
+                Err(StorageError::EntryNotFound(_)) => {
+                    invalid_sources.push(IntegrityViolation::MissingSourceBlock(number));
+                    child = None;
+                }
+                Err(StorageError::EntryPruned(_)) => break,
+                Err(err) => return Err(err),
+            }
+        }
+
+        for violation in invalid_sources {
+            report.record(violation, activation.number + 1);
+        }
+        Ok(())
+    }
+
+    /// Checks that the safety head references point to stored blocks, in the right order.
+    fn check_head_refs(
+        &self,
+        latest_block: BlockInfo,
+        report: &mut IntegrityReport,
+    ) -> Result<(), StorageError> {
+        // Rewinding to the latest block resets all heads above it.
+        let cap = |number: u64| number.min(latest_block.number);
+
+        if let Some(local_unsafe) =
+            self.head_ref(SafetyLevel::LocalUnsafe)?.filter(|head| *head != latest_block)
+        {
+            report.record(
+                IntegrityViolation::LogBlocksPastUnsafeHead { latest_block, local_unsafe },
+                cap(local_unsafe.number + 1),
+            );
+        }
+
+        for safety_level in [
+            SafetyLevel::LocalUnsafe,
+            SafetyLevel::CrossUnsafe,
+            SafetyLevel::LocalSafe,
+            SafetyLevel::CrossSafe,
+            SafetyLevel::Finalized,
+        ] {
+            let Some(head) = self.head_ref(safety_level)? else { continue };
+            let stored = match safety_level {
+                SafetyLevel::LocalUnsafe | SafetyLevel::CrossUnsafe => {
+                    self.db.get_block(head.number).map(|block| block == head)
+                }
+                _ => self.db.derived_to_source(head.id()).map(|_| true),
+            };
+            match stored {
+                Ok(true) | Err(StorageError::EntryPruned(_)) => {}
+                Ok(false) | Err(StorageError::EntryNotFound(_) | StorageError::ConflictError) => {
+                    report.record(
+                        IntegrityViolation::UnknownHeadRef { safety_level, head },
+                        cap(head.number),
+                    )
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        for (safety_level, weaker_level) in HEAD_ORDER {
+            let (Some(head), Some(weaker_head)) =
+                (self.head_ref(safety_level)?, self.head_ref(weaker_level)?)
+            else {
+                continue;
+            };
+            if head.number > weaker_head.number {
+                report.record(
+                    IntegrityViolation::HeadRefAhead {
+                        safety_level,
+                        head,
+                        weaker_level,
+                        weaker_head,
+                    },
+                    cap(weaker_head.number + 1),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn head_ref(&self, safety_level: SafetyLevel) -> Result<Option<BlockInfo>, StorageError> {
+        match self.db.get_safety_head_ref(safety_level) {
+            Ok(head) => Ok(Some(head)),
+            Err(StorageError::FutureData) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<DB> IntegrityChecker<'_, DB>
+where
+    DB: DbReader + StorageRewinder,
+{
+    /// Checks the storage and rolls it back to the last consistent block, if any violation is
+    /// found.
+    ///
+    /// The storage is rewound with [`StorageRewinder::rewind`] from the first inconsistent block
+    /// onward, or with [`StorageRewinder::rewind_log_storage`] if only blocks above the derived
+    /// blocks are affected.
+    ///
+    /// # Returns
+    /// * `Ok(Some(BlockNumHash))` containing the block the storage was rewound to (inclusive).
+    /// * `Ok(None)` if the storage is consistent, or cannot be repaired by rewinding since the
+    ///   activation block itself is affected.
+    /// * `Err(StorageError)` if there is an issue checking or rewinding the storage.
+    pub fn repair(&self) -> Result<Option<BlockNumHash>, StorageError> {
+        let Some(first_inconsistent) = self.check()?.first_inconsistent_block else {
+            return Ok(None);
+        };
+
+        let derivation = not_initialised_as_none(self.db.latest_derivation_state())?;
+        let activation = not_initialised_as_none(self.db.get_activation_block())?;
+        if activation.is_some_and(|activation| first_inconsistent <= activation.number) {
+            warn!(
+                target: "supervisor::storage",
+                first_inconsistent,
+                "Activation block is inconsistent, storage cannot be repaired"
+            );
+            return Ok(None);
+        }
+
+        // Blocks above the derived blocks are only in the log storage.
+        if derivation.is_none_or(|pair| first_inconsistent > pair.derived.number) {
+            let to = self.log_block_id(first_inconsistent)?;
+            self.db.rewind_log_storage(&to)?;
+            info!(target: "supervisor::storage", ?to, "Rewound log storage to repair integrity");
+            return Ok(Some(to));
+        }
+
+        // The rewind target must be stored identically in the log and derivation storage. If the
+        // first inconsistent block is not, rewind one block further.
+        let mut to = self.log_block_id(first_inconsistent)?;
+        if self.db.derived_to_source(to).is_err() {
+            if activation.is_some_and(|activation| first_inconsistent - 1 <= activation.number) {
+                warn!(
+                    target: "supervisor::storage",
+                    first_inconsistent,
+                    "Activation block is inconsistent, storage cannot be repaired"
+                );
+                return Ok(None);
+            }
+            to = self.db.get_block(first_inconsistent - 1)?.id();
+        }
+
+        self.db.rewind(&to)?;
+        info!(target: "supervisor::storage", ?to, "Rewound storage to repair integrity");
+        Ok(Some(to))
+    }
+
+    /// Returns the id of the log block at the given height. Missing blocks are not hash checked
+    /// by rewinds, so their id has an empty hash.
+    fn log_block_id(&self, number: u64) -> Result<BlockNumHash, StorageError> {
+        match self.db.get_block(number) {
+            Ok(block) => Ok(block.id()),
+            Err(StorageError::EntryNotFound(_)) => Ok(BlockNumHash { number, hash: B256::ZERO }),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+fn not_initialised_as_none<T>(result: Result<T, StorageError>) -> Result<Option<T>, StorageError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(StorageError::DatabaseNotInitialised) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        DerivationStorageReader, DerivationStorageWriter, HeadRefStorageReader, InMemoryChainDb,
+        LogStorageReader, LogStorageWriter,
+    };
+    use kona_interop::DerivedRefPair;
+    use kona_supervisor_types::{Log, SuperHead};
+    use std::collections::HashMap;
+
+    fn block(prefix: u8, number: u64) -> BlockInfo {
+        let hash = |number: u64| {
+            let mut bytes = [prefix; 32];
+            bytes[24..].copy_from_slice(&number.to_be_bytes());
+            B256::from(bytes)
+        };
+        BlockInfo {
+            number,
+            hash: hash(number),
+            parent_hash: hash(number.wrapping_sub(1)),
+            timestamp: number,
+        }
+    }
+
+    /// Storage of L2 blocks `0..=4`, of which `0..=2` are derived from source blocks `10..=11`.
+    /// Stored blocks and head references can be overridden to simulate a corrupted storage.
+    #[derive(Debug)]
+    struct CorruptedDb {
+        db: InMemoryChainDb,
+        blocks: HashMap<u64, BlockInfo>,
+        head_refs: Vec<(SafetyLevel, BlockInfo)>,
+    }
+
+    impl CorruptedDb {
+        fn new() -> Self {
+            let db = InMemoryChainDb::new(1);
+            db.initialise_log_storage(block(2, 0)).unwrap();
+            for number in 1..=4 {
+                db.store_block_logs(&block(2, number), Vec::new()).unwrap();
+            }
+            db.initialise_derivation_storage(DerivedRefPair {
+                source: block(1, 10),
+                derived: block(2, 0),
+            })
+            .unwrap();
+            db.save_source_block(block(1, 11)).unwrap();
+            for number in 1..=2 {
+                db.save_derived_block(DerivedRefPair {
+                    source: block(1, 11),
+                    derived: block(2, number),
+                })
+                .unwrap();
+            }
+            Self { db, blocks: HashMap::new(), head_refs: Vec::new() }
+        }
+    }
+
+    impl DerivationStorageReader for CorruptedDb {
+        fn derived_to_source(
+            &self,
+            derived_block_id: BlockNumHash,
+        ) -> Result<BlockInfo, StorageError> {
+            self.db.derived_to_source(derived_block_id)
+        }
+
+        fn latest_derived_block_at_source(
+            &self,
+            source_block_id: BlockNumHash,
+        ) -> Result<BlockInfo, StorageError> {
+            self.db.latest_derived_block_at_source(source_block_id)
+        }
+
+        fn latest_derivation_state(&self) -> Result<DerivedRefPair, StorageError> {
+            self.db.latest_derivation_state()
+        }
+
+        fn get_source_block(&self, source_block_number: u64) -> Result<BlockInfo, StorageError> {
+            self.db.get_source_block(source_block_number)
+        }
+
+        fn get_activation_block(&self) -> Result<BlockInfo, StorageError> {
+            self.db.get_activation_block()
+        }
+    }
+
+    impl LogStorageReader for CorruptedDb {
+        fn get_latest_block(&self) -> Result<BlockInfo, StorageError> {
+            self.db.get_latest_block()
+        }
+
+        fn get_block(&self, block_number: u64) -> Result<BlockInfo, StorageError> {
+            self.blocks
+                .get(&block_number)
+                .map_or_else(|| self.db.get_block(block_number), |block| Ok(*block))
+        }
+
+        fn get_log(&self, block_number: u64, log_index: u32) -> Result<Log, StorageError> {
+            self.db.get_log(block_number, log_index)
+        }
+
+        fn get_logs(&self, block_number: u64) -> Result<Vec<Log>, StorageError> {
+            self.db.get_logs(block_number)
+        }
+    }
+
+    impl HeadRefStorageReader for CorruptedDb {
+        fn get_safety_head_ref(
+            &self,
+            safety_level: SafetyLevel,
+        ) -> Result<BlockInfo, StorageError> {
+            self.head_refs
+                .iter()
+                .find(|(level, _)| *level == safety_level)
+                .map_or_else(|| self.db.get_safety_head_ref(safety_level), |(_, head)| Ok(*head))
+        }
+
+        fn get_super_head(&self) -> Result<SuperHead, StorageError> {
+            self.db.get_super_head()
+        }
+    }
+
+    impl StorageRewinder for CorruptedDb {
+        fn rewind_log_storage(&self, to: &BlockNumHash) -> Result<(), StorageError> {
+            self.db.rewind_log_storage(to)
+        }
+
+        fn rewind(&self, to: &BlockNumHash) -> Result<(), StorageError> {
+            self.db.rewind(to)
+        }
+
+        fn rewind_to_source(&self, to: &BlockNumHash) -> Result<Option<BlockInfo>, StorageError> {
+            self.db.rewind_to_source(to)
+        }
+    }
+
+    #[test]
+    fn test_consistent_storage() {
+        let db = CorruptedDb::new();
+        let checker = IntegrityChecker::new(&db);
+        assert_eq!(checker.check().unwrap(), IntegrityReport::default());
+        assert_eq!(checker.repair().unwrap(), None);
+
+        let empty = InMemoryChainDb::new(1);
+        assert!(IntegrityChecker::new(&empty).check().unwrap().is_consistent());
+    }
+
+    #[test]
+    fn test_repair_unlinked_log_block() {
+        let mut db = CorruptedDb::new();
+        let fork = BlockInfo { hash: B256::repeat_byte(0xff), ..block(2, 3) };
+        db.blocks.insert(3, fork);
+
+        let report = IntegrityChecker::new(&db).check().unwrap();
+        assert_eq!(
+            report.violations,
+            vec![IntegrityViolation::LogBlockNotLinked { block: block(2, 4), parent: fork }]
+        );
+        assert_eq!(report.first_inconsistent_block, Some(4));
+
+        // Only the log storage is rewound, as all derived blocks are consistent.
+        assert_eq!(IntegrityChecker::new(&db).repair().unwrap(), Some(block(2, 4).id()));
+        assert_eq!(db.db.get_latest_block().unwrap(), block(2, 3));
+        assert_eq!(db.db.latest_derivation_state().unwrap().derived, block(2, 2));
+    }
+
+    #[test]
+    fn test_repair_head_ahead_of_weaker_head() {
+        let mut db = CorruptedDb::new();
+        db.head_refs.push((SafetyLevel::Finalized, block(2, 2)));
+
+        let report = IntegrityChecker::new(&db).check().unwrap();
+        assert_eq!(
+            report.violations,
+            vec![IntegrityViolation::HeadRefAhead {
+                safety_level: SafetyLevel::Finalized,
+                head: block(2, 2),
+                weaker_level: SafetyLevel::CrossSafe,
+                weaker_head: block(2, 0),
+            }]
+        );
+        assert_eq!(report.first_inconsistent_block, Some(1));
+
+        assert_eq!(IntegrityChecker::new(&db).repair().unwrap(), Some(block(2, 1).id()));
+        assert_eq!(db.db.get_latest_block().unwrap(), block(2, 0));
+        assert_eq!(db.db.latest_derivation_state().unwrap().derived, block(2, 0));
+    }
+
+    #[test]
+    fn test_unrepairable_activation_block() {
+        let mut db = CorruptedDb::new();
+        db.head_refs.push((SafetyLevel::CrossSafe, block(3, 0)));
+
+        let report = IntegrityChecker::new(&db).check().unwrap();
+        assert_eq!(
+            report.violations,
+            vec![IntegrityViolation::UnknownHeadRef {
+                safety_level: SafetyLevel::CrossSafe,
+                head: block(3, 0),
+            }]
+        );
+        assert_eq!(report.first_inconsistent_block, Some(0));
+        assert_eq!(IntegrityChecker::new(&db).repair().unwrap(), None);
+    }
+}
@@ -16,8 +16,15 @@
 //!
 //! - Append logs emitted by L2 execution
 //! - Look up logs by block number and index
+//! - Look up executing messages by the initiating message they reference
+//! - Cache the outputs of finalized blocks used to compute super roots
 //! - Rewind logs during reorgs
+//! - Prune logs and derivation data past the message expiry window
 //! - Track sealed blocks and ancestry metadata
+//! - Check the consistency of the storage, and repair it by rewinding
+//!
+//! An in-memory backend, [`InMemoryChainDb`], implements the same storage traits for tests and
+//! ephemeral setups.
 
 pub mod models;
 pub use models::SourceBlockTraversal;
@@ -28,7 +35,7 @@ pub use error::{EntryNotFoundError, StorageError};
 mod providers;
 
 mod chaindb;
-pub use chaindb::ChainDb;
+pub use chaindb::{ChainDb, DEFAULT_PRUNE_BATCH_SIZE};
 
 mod metrics;
 pub(crate) use metrics::Metrics;
@@ -36,9 +43,17 @@ pub(crate) use metrics::Metrics;
 mod chaindb_factory;
 pub use chaindb_factory::ChainDbFactory;
 
+mod memory;
+pub use memory::{InMemoryChainDb, InMemoryChainDbFactory};
+
+mod integrity;
+pub use integrity::{IntegrityChecker, IntegrityReport, IntegrityViolation};
+
 mod traits;
 pub use traits::{
-    CrossChainSafetyProvider, DbReader, DerivationStorage, DerivationStorageReader,
+    ChainStorage, CrossChainSafetyProvider, DbReader, DerivationStorage, DerivationStorageReader,
     DerivationStorageWriter, FinalizedL1Storage, HeadRefStorage, HeadRefStorageReader,
-    HeadRefStorageWriter, LogStorage, LogStorageReader, LogStorageWriter, StorageRewinder,
+    HeadRefStorageWriter, LogStorage, LogStorageReader, LogStorageWriter, MessageIndexReader,
+    OutputStorage, OutputStorageReader, OutputStorageWriter, StorageFactory, StoragePruner,
+    StorageRewinder,
 };
@@ -0,0 +1,891 @@
+//! In-memory storage of a single chain.
+
+use crate::{
+    StoragePruner, StorageRewinder,
+    error::{EntryNotFoundError, StorageError},
+    models::SafetyHeadRefKey,
+    traits::{
+        DerivationStorageReader, DerivationStorageWriter, HeadRefStorageReader,
+        HeadRefStorageWriter, LogStorageReader, LogStorageWriter, MessageIndexReader,
+        OutputStorageReader, OutputStorageWriter,
+    },
+};
+use alloy_eips::eip1898::BlockNumHash;
+use alloy_primitives::{ChainId, map::HashMap};
+use kona_interop::DerivedRefPair;
+use kona_protocol::BlockInfo;
+use kona_supervisor_types::{ChainOutput, ExecutingMessageRef, Log, MessageIdentifier, SuperHead};
+use op_alloy_consensus::interop::SafetyLevel;
+use std::{
+    collections::BTreeMap,
+    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+use tracing::{error, warn};
+
+/// In-memory storage for a single chain.
+///
+/// Drop-in replacement for [`ChainDb`](crate::ChainDb) which keeps all state in memory, e.g. for
+/// tests and ephemeral setups. It upholds the same invariants and returns the same
+/// [`StorageError`]s. Write operations validate the request before touching the state, so a
+/// failed write leaves the storage unchanged, like an aborted database transaction.
+#[derive(Debug)]
+pub struct InMemoryChainDb {
+    chain_id: ChainId,
+    tables: RwLock<Tables>,
+}
+
+impl InMemoryChainDb {
+    /// Creates a new, empty in-memory storage.
+    pub fn new(chain_id: ChainId) -> Self {
+        Self { chain_id, tables: RwLock::new(Tables::default()) }
+    }
+
+    fn read(&self) -> Result<RwLockReadGuard<'_, Tables>, StorageError> {
+        self.tables.read().map_err(|err| {
+            error!(target: "supervisor::storage", chain_id = %self.chain_id, %err, "Failed to acquire read lock on storage");
+            StorageError::LockPoisoned
+        })
+    }
+
+    /// Moves the cross head of the given level back to the parent of `block`, undoing its
+    /// promotion.
+    ///
+    /// Fails with [`StorageError::ConflictError`] if `block` is not the current cross head.
+    pub fn revert_cross_head(
+        &self,
+        level: SafetyLevel,
+        block: &BlockInfo,
+    ) -> Result<(), StorageError> {
+        if !matches!(level, SafetyLevel::CrossUnsafe | SafetyLevel::CrossSafe) {
+            return Err(StorageError::ConflictError);
+        }
+
+        let mut tables = self.write()?;
+        let current = tables.get_safety_head_ref(level)?;
+        let parent = match block.number.checked_sub(1) {
+            Some(number) if current == *block => tables.get_block(number)?,
+            _ => return Err(StorageError::ConflictError),
+        };
+        if !parent.is_parent_of(block) {
+            return Err(StorageError::ConflictError);
+        }
+
+        tables.head_refs.insert(level.into(), parent);
+        Ok(())
+    }
+
+    fn write(&self) -> Result<RwLockWriteGuard<'_, Tables>, StorageError> {
+        self.tables.write().map_err(|err| {
+            error!(target: "supervisor::storage", chain_id = %self.chain_id, %err, "Failed to acquire write lock on storage");
+            StorageError::LockPoisoned
+        })
+    }
+}
+
+/// Traversal of a source block, i.e. the blocks derived from it.
+#[derive(Debug, Clone)]
+struct SourceTraversal {
+    source: BlockInfo,
+    derived_block_numbers: Vec<u64>,
+}
+
+impl SourceTraversal {
+    const fn new(source: BlockInfo) -> Self {
+        Self { source, derived_block_numbers: Vec::new() }
+    }
+}
+
+/// In-memory counterpart of the database tables.
+#[derive(Debug, Default)]
+struct Tables {
+    blocks: BTreeMap<u64, BlockInfo>,
+    logs: BTreeMap<u64, Vec<Log>>,
+    executing_messages: HashMap<MessageIdentifier, Vec<ExecutingMessageRef>>,
+    derived_blocks: BTreeMap<u64, DerivedRefPair>,
+    traversals: BTreeMap<u64, SourceTraversal>,
+    head_refs: HashMap<SafetyHeadRefKey, BlockInfo>,
+    outputs: BTreeMap<u64, ChainOutput>,
+}
+
+/// Returns whether the entry keyed by `key` was pruned from `table`.
+///
+/// Mirrors the database tables, which keep the first (activation) entry when pruning.
+fn is_pruned<V>(table: &BTreeMap<u64, V>, key: u64) -> bool {
+    let mut keys = table.keys();
+    match (keys.next(), keys.next()) {
+        (Some(&first), Some(&next)) => first < key && key < next,
+        _ => false,
+    }
+}
+
+/// Removes the entries keyed within `start..end` from `table`, returning the number of removed
+/// entries.
+fn remove_range<V>(table: &mut BTreeMap<u64, V>, start: u64, end: u64) -> u64 {
+    if start >= end {
+        return 0;
+    }
+    let mut removed = table.split_off(&start);
+    table.append(&mut removed.split_off(&end));
+    removed.len() as u64
+}
+
+// Log storage
+impl Tables {
+    fn get_block(&self, block_number: u64) -> Result<BlockInfo, StorageError> {
+        if let Some(block) = self.blocks.get(&block_number) {
+            return Ok(*block);
+        }
+        if is_pruned(&self.blocks, block_number) {
+            return Err(StorageError::EntryPruned(block_number));
+        }
+        Err(EntryNotFoundError::DerivedBlockNotFound(block_number).into())
+    }
+
+    fn get_latest_block(&self) -> Result<BlockInfo, StorageError> {
+        self.blocks.values().next_back().copied().ok_or(StorageError::DatabaseNotInitialised)
+    }
+
+    fn get_log(&self, block_number: u64, log_index: u32) -> Result<Log, StorageError> {
+        let log = self
+            .logs
+            .get(&block_number)
+            .and_then(|logs| logs.iter().find(|log| log.index == log_index));
+        if let Some(log) = log {
+            return Ok(log.clone());
+        }
+        if is_pruned(&self.blocks, block_number) {
+            return Err(StorageError::EntryPruned(block_number));
+        }
+        Err(EntryNotFoundError::LogNotFound { block_number, log_index }.into())
+    }
+
+    fn get_logs(&self, block_number: u64) -> Result<Vec<Log>, StorageError> {
+        let logs = self.logs.get(&block_number).cloned().unwrap_or_default();
+        if logs.is_empty() && is_pruned(&self.blocks, block_number) {
+            return Err(StorageError::EntryPruned(block_number));
+        }
+        Ok(logs)
+    }
+
+    fn initialise_log_storage(&mut self, activation_block: BlockInfo) -> Result<(), StorageError> {
+        match self.blocks.get(&0) {
+            Some(block) if *block == activation_block => Ok(()),
+            Some(_) => Err(StorageError::ConflictError),
+            None => {
+                self.insert_block_logs(&activation_block, Vec::new());
+                Ok(())
+            }
+        }
+    }
+
+    fn store_block_logs(&mut self, block: &BlockInfo, logs: Vec<Log>) -> Result<(), StorageError> {
+        let latest_block = self.get_latest_block()?;
+
+        if latest_block.number >= block.number {
+            let stored_block = self.get_block(block.number)?;
+            if stored_block == *block {
+                return Ok(());
+            }
+            warn!(
+                target: "supervisor::storage",
+                %stored_block,
+                incoming_block = %block,
+                "Incoming log block is not consistent with the stored log block",
+            );
+            return Err(StorageError::ConflictError);
+        }
+
+        if !latest_block.is_parent_of(block) {
+            return Err(StorageError::BlockOutOfOrder);
+        }
+
+        self.insert_block_logs(block, logs);
+        Ok(())
+    }
+
+    fn insert_block_logs(&mut self, block: &BlockInfo, logs: Vec<Log>) {
+        for log in &logs {
+            if let Some(msg) = &log.executing_message {
+                let entry =
+                    ExecutingMessageRef { block: block.id(), log_index: log.index, hash: msg.hash };
+                let entries = self.executing_messages.entry(msg.into()).or_default();
+                if !entries.contains(&entry) {
+                    entries.push(entry);
+                }
+            }
+        }
+
+        self.blocks.insert(block.number, *block);
+        if !logs.is_empty() {
+            self.logs.insert(block.number, logs);
+        }
+    }
+
+    /// Checks that the log storage can be rewound to the given block.
+    fn check_log_rewind(&self, to: &BlockNumHash) -> Result<(), StorageError> {
+        let latest_block = self.blocks.keys().next_back().copied().unwrap_or(to.number);
+        if to.number > latest_block {
+            return Err(StorageError::FutureData);
+        }
+        if self.blocks.get(&to.number).is_some_and(|block| block.hash != to.hash) {
+            return Err(StorageError::ConflictError);
+        }
+        Ok(())
+    }
+
+    /// Removes all blocks and logs from the given block onward. Executing message index entries
+    /// are kept, like in the database.
+    fn rewind_logs(&mut self, to: &BlockNumHash) {
+        self.blocks.split_off(&to.number);
+        self.logs.split_off(&to.number);
+    }
+
+    /// Prunes all blocks and logs below the given block number, keeping the activation block.
+    fn prune_logs(&mut self, block_number: u64) -> Result<u64, StorageError> {
+        let activation = *self.blocks.keys().next().ok_or(StorageError::DatabaseNotInitialised)?;
+        if block_number <= activation + 1 {
+            return Ok(0);
+        }
+
+        let pruned_blocks = remove_range(&mut self.blocks, activation + 1, block_number);
+        remove_range(&mut self.logs, activation + 1, block_number);
+
+        self.executing_messages.retain(|_, entries| {
+            entries.retain(|entry| {
+                entry.block.number <= activation || entry.block.number >= block_number
+            });
+            !entries.is_empty()
+        });
+        Ok(pruned_blocks)
+    }
+}
+
+// Derivation storage
+impl Tables {
+    fn get_derived_block_pair_by_number(
+        &self,
+        derived_block_number: u64,
+    ) -> Result<DerivedRefPair, StorageError> {
+        if let Some(pair) = self.derived_blocks.get(&derived_block_number) {
+            return Ok(*pair);
+        }
+        if is_pruned(&self.derived_blocks, derived_block_number) {
+            return Err(StorageError::EntryPruned(derived_block_number));
+        }
+        Err(EntryNotFoundError::DerivedBlockNotFound(derived_block_number).into())
+    }
+
+    fn get_derived_block_pair(
+        &self,
+        derived_block_id: BlockNumHash,
+    ) -> Result<DerivedRefPair, StorageError> {
+        let pair = self.get_derived_block_pair_by_number(derived_block_id.number)?;
+        if pair.derived.hash != derived_block_id.hash {
+            return Err(StorageError::ConflictError);
+        }
+        Ok(pair)
+    }
+
+    fn get_block_traversal(
+        &self,
+        source_block_number: u64,
+    ) -> Result<&SourceTraversal, StorageError> {
+        if let Some(traversal) = self.traversals.get(&source_block_number) {
+            return Ok(traversal);
+        }
+        if is_pruned(&self.traversals, source_block_number) {
+            return Err(StorageError::EntryPruned(source_block_number));
+        }
+        Err(EntryNotFoundError::SourceBlockNotFound(source_block_number).into())
+    }
+
+    fn latest_derived_block_at_source(
+        &self,
+        source_block_id: BlockNumHash,
+    ) -> Result<BlockInfo, StorageError> {
+        let traversal = self.get_block_traversal(source_block_id.number)?;
+        if traversal.source.hash != source_block_id.hash {
+            return Err(StorageError::ConflictError);
+        }
+
+        let latest_derived_block_number = self
+            .traversals
+            .range(..=source_block_id.number)
+            .rev()
+            .find_map(|(_, traversal)| traversal.derived_block_numbers.last());
+        match latest_derived_block_number {
+            Some(number) => Ok(self.get_derived_block_pair_by_number(*number)?.derived),
+            None => Err(EntryNotFoundError::MissingDerivedBlocks(source_block_id).into()),
+        }
+    }
+
+    fn latest_source_block(&self) -> Result<BlockInfo, StorageError> {
+        self.traversals
+            .values()
+            .next_back()
+            .map(|traversal| traversal.source)
+            .ok_or(StorageError::DatabaseNotInitialised)
+    }
+
+    fn latest_derivation_state(&self) -> Result<DerivedRefPair, StorageError> {
+        let (_, pair) =
+            self.derived_blocks.last_key_value().ok_or(StorageError::DatabaseNotInitialised)?;
+        Ok(DerivedRefPair { source: self.latest_source_block()?, derived: pair.derived })
+    }
+
+    fn get_activation_block(&self) -> Result<BlockInfo, StorageError> {
+        self.derived_blocks
+            .values()
+            .next()
+            .map(|pair| pair.derived)
+            .ok_or(StorageError::DatabaseNotInitialised)
+    }
+
+    fn initialise_derivation_storage(
+        &mut self,
+        activation_pair: DerivedRefPair,
+    ) -> Result<(), StorageError> {
+        match self.derived_blocks.get(&0) {
+            Some(pair) if *pair == activation_pair => Ok(()),
+            Some(_) => Err(StorageError::ConflictError),
+            None => {
+                // The activation pair must be derived from the latest source block.
+                if self
+                    .traversals
+                    .keys()
+                    .next_back()
+                    .is_some_and(|&n| n > activation_pair.source.number)
+                {
+                    return Err(StorageError::BlockOutOfOrder);
+                }
+                self.traversals.insert(
+                    activation_pair.source.number,
+                    SourceTraversal::new(activation_pair.source),
+                );
+                self.insert_derived_block(activation_pair);
+                Ok(())
+            }
+        }
+    }
+
+    /// Checks whether the given pair can be appended to the derivation storage.
+    ///
+    /// Returns `false` if the pair is already stored.
+    fn check_derived_block(&self, incoming_pair: &DerivedRefPair) -> Result<bool, StorageError> {
+        let latest_derivation_state = self.latest_derivation_state()?;
+
+        if latest_derivation_state.derived.number >= incoming_pair.derived.number {
+            let stored_pair =
+                self.get_derived_block_pair_by_number(incoming_pair.derived.number)?;
+            if stored_pair == *incoming_pair {
+                return Ok(false);
+            }
+            warn!(
+                target: "supervisor::storage",
+                %latest_derivation_state,
+                incoming_derived_block_pair = %incoming_pair,
+                "Incoming derived block is not consistent with the latest stored derived block"
+            );
+            return Err(StorageError::ConflictError);
+        }
+
+        if latest_derivation_state.source != incoming_pair.source ||
+            !latest_derivation_state.derived.is_parent_of(&incoming_pair.derived)
+        {
+            return Err(StorageError::BlockOutOfOrder);
+        }
+        Ok(true)
+    }
+
+    /// Appends a derived block pair derived from the latest source block.
+    fn insert_derived_block(&mut self, incoming_pair: DerivedRefPair) {
+        if let Some(traversal) = self.traversals.get_mut(&incoming_pair.source.number) {
+            traversal.derived_block_numbers.push(incoming_pair.derived.number);
+        }
+        self.derived_blocks.insert(incoming_pair.derived.number, incoming_pair);
+    }
+
+    fn save_source_block(&mut self, incoming_source: BlockInfo) -> Result<(), StorageError> {
+        let latest_source_block = self.latest_source_block()?;
+        if latest_source_block == incoming_source {
+            return Ok(());
+        }
+
+        if latest_source_block.number > incoming_source.number {
+            let source_block = self.get_block_traversal(incoming_source.number)?.source;
+            if source_block == incoming_source {
+                return Ok(());
+            }
+            return Err(StorageError::ConflictError);
+        }
+
+        if !latest_source_block.is_parent_of(&incoming_source) {
+            return Err(StorageError::BlockOutOfOrder);
+        }
+
+        self.traversals.insert(incoming_source.number, SourceTraversal::new(incoming_source));
+        Ok(())
+    }
+
+    /// Checks that the derivation storage can be rewound to the given derived block, returning
+    /// the stored pair of the block.
+    fn check_derivation_rewind(&self, to: &BlockNumHash) -> Result<DerivedRefPair, StorageError> {
+        let pair = self.get_derived_block_pair(*to)?;
+        self.get_block_traversal(pair.source.number)?;
+        Ok(pair)
+    }
+
+    /// Removes all derived blocks from the given pair onward, together with the traversals of
+    /// the source blocks left without derived blocks.
+    fn rewind_derivation(&mut self, pair: &DerivedRefPair) {
+        self.derived_blocks.split_off(&pair.derived.number);
+
+        let mut walk_from = pair.source.number;
+        if let Some(traversal) = self.traversals.get_mut(&pair.source.number) {
+            traversal.derived_block_numbers.retain(|&num| num < pair.derived.number);
+            if !traversal.derived_block_numbers.is_empty() {
+                walk_from += 1;
+            }
+        }
+        self.traversals.split_off(&walk_from);
+    }
+
+    /// Returns the first derived block derived from the given source block or any later one.
+    fn check_source_rewind(&self, to: &BlockNumHash) -> Result<Option<BlockInfo>, StorageError> {
+        if self.traversals.get(&to.number).is_some_and(|traversal| traversal.source.hash != to.hash)
+        {
+            return Err(StorageError::ConflictError);
+        }
+
+        let first_derived = self
+            .traversals
+            .range(to.number..)
+            .find_map(|(_, traversal)| traversal.derived_block_numbers.first());
+        first_derived
+            .map(|number| self.get_derived_block_pair_by_number(*number).map(|pair| pair.derived))
+            .transpose()
+    }
+
+    /// Prunes all derived blocks below the given derived block number, together with the
+    /// traversals of the source blocks they were derived from. The activation pair is kept.
+    fn prune_derivation(&mut self, block_number: u64) -> Result<u64, StorageError> {
+        let boundary = self.get_derived_block_pair_by_number(block_number)?;
+        let activation =
+            *self.derived_blocks.keys().next().ok_or(StorageError::DatabaseNotInitialised)?;
+        let activation_source =
+            *self.traversals.keys().next().ok_or(StorageError::DatabaseNotInitialised)?;
+        if boundary.source.number > activation_source {
+            self.get_block_traversal(boundary.source.number)?;
+        }
+
+        let pruned_blocks = remove_range(&mut self.derived_blocks, activation + 1, block_number);
+        remove_range(&mut self.traversals, activation_source + 1, boundary.source.number);
+        if boundary.source.number > activation_source {
+            if let Some(traversal) = self.traversals.get_mut(&boundary.source.number) {
+                traversal.derived_block_numbers.retain(|&num| num >= block_number);
+            }
+        }
+        Ok(pruned_blocks)
+    }
+}
+
+// Safety head references
+impl Tables {
+    fn get_safety_head_ref(&self, safety_level: SafetyLevel) -> Result<BlockInfo, StorageError> {
+        self.head_refs.get(&safety_level.into()).copied().ok_or(StorageError::FutureData)
+    }
+
+    fn optional_safety_head_ref(
+        &self,
+        safety_level: SafetyLevel,
+    ) -> Result<Option<BlockInfo>, StorageError> {
+        match self.get_safety_head_ref(safety_level) {
+            Ok(block) => Ok(Some(block)),
+            Err(StorageError::FutureData) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Updates the safety head reference, unless the stored head is ahead of the incoming one.
+    fn update_safety_head_ref(&mut self, safety_level: SafetyLevel, incoming_head_ref: &BlockInfo) {
+        let key = safety_level.into();
+        if self.head_refs.get(&key).is_some_and(|current| current.number > incoming_head_ref.number)
+        {
+            return;
+        }
+        self.head_refs.insert(key, *incoming_head_ref);
+    }
+
+    /// Resets the given safety head references to the latest log block, if they are ahead of it,
+    /// or removes them if the log storage was rewound past the activation block.
+    fn reset_safety_head_refs(&mut self, safety_levels: &[SafetyLevel]) {
+        let latest_block = self.blocks.values().next_back().copied();
+        for &safety_level in safety_levels {
+            let key = safety_level.into();
+            match latest_block {
+                Some(latest_block) => {
+                    if self
+                        .head_refs
+                        .get(&key)
+                        .is_some_and(|head| head.number >= latest_block.number)
+                    {
+                        self.head_refs.insert(key, latest_block);
+                    }
+                }
+                None => {
+                    self.head_refs.remove(&key);
+                }
+            }
+        }
+    }
+}
+
+const UNSAFE_LEVELS: [SafetyLevel; 2] = [SafetyLevel::LocalUnsafe, SafetyLevel::CrossUnsafe];
+
+const ALL_LEVELS: [SafetyLevel; 5] = [
+    SafetyLevel::LocalUnsafe,
+    SafetyLevel::CrossUnsafe,
+    SafetyLevel::LocalSafe,
+    SafetyLevel::CrossSafe,
+    SafetyLevel::Finalized,
+];
+
+impl DerivationStorageReader for InMemoryChainDb {
+    fn derived_to_source(&self, derived_block_id: BlockNumHash) -> Result<BlockInfo, StorageError> {
+        Ok(self.read()?.get_derived_block_pair(derived_block_id)?.source)
+    }
+
+    fn latest_derived_block_at_source(
+        &self,
+        source_block_id: BlockNumHash,
+    ) -> Result<BlockInfo, StorageError> {
+        self.read()?.latest_derived_block_at_source(source_block_id)
+    }
+
+    fn latest_derivation_state(&self) -> Result<DerivedRefPair, StorageError> {
+        self.read()?.latest_derivation_state()
+    }
+
+    fn get_source_block(&self, source_block_number: u64) -> Result<BlockInfo, StorageError> {
+        Ok(self.read()?.get_block_traversal(source_block_number)?.source)
+    }
+
+    fn get_activation_block(&self) -> Result<BlockInfo, StorageError> {
+        self.read()?.get_activation_block()
+    }
+}
+
+impl DerivationStorageWriter for InMemoryChainDb {
+    fn initialise_derivation_storage(
+        &self,
+        incoming_pair: DerivedRefPair,
+    ) -> Result<(), StorageError> {
+        let mut tables = self.write()?;
+        tables.initialise_derivation_storage(incoming_pair)?;
+        tables.update_safety_head_ref(SafetyLevel::LocalSafe, &incoming_pair.derived);
+        tables.update_safety_head_ref(SafetyLevel::CrossSafe, &incoming_pair.derived);
+        Ok(())
+    }
+
+    fn save_derived_block(&self, incoming_pair: DerivedRefPair) -> Result<(), StorageError> {
+        let mut tables = self.write()?;
+        let is_new = tables.check_derived_block(&incoming_pair)?;
+
+        // Verify the consistency with log storage.
+        let derived_block = incoming_pair.derived;
+        let block = tables.get_block(derived_block.number).map_err(|err| match err {
+            StorageError::EntryNotFound(_) => StorageError::FutureData,
+            other => other,
+        })?;
+        if block != derived_block {
+            warn!(
+                target: "supervisor::storage",
+                chain_id = %self.chain_id,
+                incoming_block = %derived_block,
+                stored_log_block = %block,
+                "Derived block does not match the stored log block"
+            );
+            return Err(StorageError::ReorgRequired);
+        }
+
+        if is_new {
+            tables.insert_derived_block(incoming_pair);
+        }
+        tables.update_safety_head_ref(SafetyLevel::LocalSafe, &derived_block);
+        Ok(())
+    }
+
+    fn save_source_block(&self, source: BlockInfo) -> Result<(), StorageError> {
+        self.write()?.save_source_block(source)
+    }
+}
+
+impl LogStorageReader for InMemoryChainDb {
+    fn get_latest_block(&self) -> Result<BlockInfo, StorageError> {
+        self.read()?.get_latest_block()
+    }
+
+    fn get_block(&self, block_number: u64) -> Result<BlockInfo, StorageError> {
+        self.read()?.get_block(block_number)
+    }
+
+    fn get_log(&self, block_number: u64, log_index: u32) -> Result<Log, StorageError> {
+        self.read()?.get_log(block_number, log_index)
+    }
+
+    fn get_logs(&self, block_number: u64) -> Result<Vec<Log>, StorageError> {
+        self.read()?.get_logs(block_number)
+    }
+}
+
+impl MessageIndexReader for InMemoryChainDb {
+    fn get_executing_messages(
+        &self,
+        initiating: &MessageIdentifier,
+    ) -> Result<Vec<ExecutingMessageRef>, StorageError> {
+        Ok(self.read()?.executing_messages.get(initiating).cloned().unwrap_or_default())
+    }
+}
+
+impl LogStorageWriter for InMemoryChainDb {
+    fn initialise_log_storage(&self, block: BlockInfo) -> Result<(), StorageError> {
+        let mut tables = self.write()?;
+        tables.initialise_log_storage(block)?;
+        tables.update_safety_head_ref(SafetyLevel::LocalUnsafe, &block);
+        tables.update_safety_head_ref(SafetyLevel::CrossUnsafe, &block);
+        Ok(())
+    }
+
+    fn store_block_logs(&self, block: &BlockInfo, logs: Vec<Log>) -> Result<(), StorageError> {
+        let mut tables = self.write()?;
+        tables.store_block_logs(block, logs)?;
+        tables.update_safety_head_ref(SafetyLevel::LocalUnsafe, block);
+        Ok(())
+    }
+}
+
+impl HeadRefStorageReader for InMemoryChainDb {
+    fn get_safety_head_ref(&self, safety_level: SafetyLevel) -> Result<BlockInfo, StorageError> {
+        self.read()?.get_safety_head_ref(safety_level)
+    }
+
+    fn get_super_head(&self) -> Result<SuperHead, StorageError> {
+        let tables = self.read()?;
+        let local_unsafe = tables
+            .optional_safety_head_ref(SafetyLevel::LocalUnsafe)?
+            .ok_or(StorageError::DatabaseNotInitialised)?;
+
+        let l1_source = match tables.latest_derivation_state() {
+            Ok(pair) => Some(pair.source),
+            Err(StorageError::DatabaseNotInitialised) => None,
+            Err(err) => return Err(err),
+        };
+
+        Ok(SuperHead {
+            l1_source,
+            local_unsafe,
+            cross_unsafe: tables.optional_safety_head_ref(SafetyLevel::CrossUnsafe)?,
+            local_safe: tables.optional_safety_head_ref(SafetyLevel::LocalSafe)?,
+            cross_safe: tables.optional_safety_head_ref(SafetyLevel::CrossSafe)?,
+            finalized: tables.optional_safety_head_ref(SafetyLevel::Finalized)?,
+        })
+    }
+}
+
+impl HeadRefStorageWriter for InMemoryChainDb {
+    fn update_finalized_using_source(
+        &self,
+        finalized_source_block: BlockInfo,
+    ) -> Result<BlockInfo, StorageError> {
+        let mut tables = self.write()?;
+        let safe = tables.get_safety_head_ref(SafetyLevel::CrossSafe)?;
+        let safe_block_pair = tables.get_derived_block_pair(safe.id())?;
+
+        let finalized = if finalized_source_block.number >= safe_block_pair.source.number {
+            safe
+        } else {
+            tables.latest_derived_block_at_source(finalized_source_block.id())?
+        };
+        tables.update_safety_head_ref(SafetyLevel::Finalized, &finalized);
+        Ok(finalized)
+    }
+
+    fn update_current_cross_unsafe(&self, block: &BlockInfo) -> Result<(), StorageError> {
+        let mut tables = self.write()?;
+        let parent = tables.get_safety_head_ref(SafetyLevel::CrossUnsafe)?;
+        if !parent.is_parent_of(block) {
+            return Err(StorageError::ConflictError);
+        }
+
+        // Ensure the block exists in log storage and hasn't been removed due to a re-org.
+        if tables.get_block(block.number)?.hash != block.hash {
+            return Err(StorageError::ConflictError);
+        }
+
+        tables.update_safety_head_ref(SafetyLevel::CrossUnsafe, block);
+        Ok(())
+    }
+
+    fn update_current_cross_safe(&self, block: &BlockInfo) -> Result<DerivedRefPair, StorageError> {
+        let mut tables = self.write()?;
+        let parent = tables.get_safety_head_ref(SafetyLevel::CrossSafe)?;
+        if !parent.is_parent_of(block) {
+            return Err(StorageError::ConflictError);
+        }
+
+        // Ensure the block exists in derivation storage and hasn't been removed due to a re-org.
+        let derived_pair = tables.get_derived_block_pair(block.id())?;
+        tables.update_safety_head_ref(SafetyLevel::CrossSafe, block);
+        Ok(derived_pair)
+    }
+}
+
+impl OutputStorageReader for InMemoryChainDb {
+    fn get_finalized_output(&self, timestamp: u64) -> Result<Option<ChainOutput>, StorageError> {
+        Ok(self.read()?.outputs.get(&timestamp).cloned())
+    }
+}
+
+impl OutputStorageWriter for InMemoryChainDb {
+    fn save_finalized_output(
+        &self,
+        timestamp: u64,
+        output: ChainOutput,
+    ) -> Result<(), StorageError> {
+        let mut tables = self.write()?;
+        let finalized = tables.get_safety_head_ref(SafetyLevel::Finalized)?;
+        if output.block_number > finalized.number {
+            return Err(StorageError::FutureData);
+        }
+        if tables.get_block(output.block_number)?.hash != output.output.block_hash {
+            return Err(StorageError::ConflictError);
+        }
+
+        tables.outputs.insert(timestamp, output);
+        Ok(())
+    }
+}
+
+impl StorageRewinder for InMemoryChainDb {
+    fn rewind_log_storage(&self, to: &BlockNumHash) -> Result<(), StorageError> {
+        let mut tables = self.write()?;
+        if let Some(local_safe) = tables.optional_safety_head_ref(SafetyLevel::LocalSafe)? {
+            if to.number <= local_safe.number {
+                return Err(StorageError::RewindBeyondLocalSafeHead {
+                    to: to.number,
+                    local_safe: local_safe.number,
+                });
+            }
+        }
+
+        tables.check_log_rewind(to)?;
+        tables.rewind_logs(to);
+        tables.reset_safety_head_refs(&UNSAFE_LEVELS);
+        Ok(())
+    }
+
+    fn rewind(&self, to: &BlockNumHash) -> Result<(), StorageError> {
+        let mut tables = self.write()?;
+        tables.check_log_rewind(to)?;
+        let pair = tables.check_derivation_rewind(to)?;
+
+        tables.rewind_logs(to);
+        tables.rewind_derivation(&pair);
+        tables.outputs.retain(|_, output| output.block_number < to.number);
+        tables.reset_safety_head_refs(&ALL_LEVELS);
+        Ok(())
+    }
+
+    fn rewind_to_source(&self, to: &BlockNumHash) -> Result<Option<BlockInfo>, StorageError> {
+        let mut tables = self.write()?;
+        let derived_target_block = tables.check_source_rewind(to)?;
+        if let Some(rewind_target) = derived_target_block {
+            tables.check_log_rewind(&rewind_target.id())?;
+        }
+
+        tables.traversals.split_off(&to.number);
+        if let Some(rewind_target) = derived_target_block {
+            tables.derived_blocks.split_off(&rewind_target.number);
+            tables.rewind_logs(&rewind_target.id());
+            tables.outputs.retain(|_, output| output.block_number < rewind_target.number);
+        }
+        tables.reset_safety_head_refs(&ALL_LEVELS);
+        Ok(derived_target_block)
+    }
+}
+
+impl StoragePruner for InMemoryChainDb {
+    fn prune(&self, block_number: u64) -> Result<u64, StorageError> {
+        let mut tables = self.write()?;
+
+        // Never prune past the finalized head, as anything above it may still be reorged.
+        let finalized = tables.get_safety_head_ref(SafetyLevel::Finalized)?;
+        let target = block_number.min(finalized.number);
+
+        // Validate both storages before pruning either of them.
+        tables.get_derived_block_pair_by_number(target)?;
+        if tables.blocks.is_empty() {
+            return Err(StorageError::DatabaseNotInitialised);
+        }
+
+        tables.prune_derivation(target)?;
+        tables.outputs.retain(|_, output| output.block_number >= target);
+        tables.prune_logs(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use kona_supervisor_types::ExecutingMessage;
+
+    fn block(number: u64) -> BlockInfo {
+        BlockInfo {
+            number,
+            hash: B256::from([number as u8; 32]),
+            parent_hash: B256::from([number.wrapping_sub(1) as u8; 32]),
+            timestamp: number,
+        }
+    }
+
+    #[test]
+    fn test_failed_rewind_leaves_storage_unchanged() {
+        let db = InMemoryChainDb::new(1);
+        db.initialise_log_storage(block(0)).unwrap();
+        db.initialise_derivation_storage(DerivedRefPair { source: block(10), derived: block(0) })
+            .unwrap();
+        db.store_block_logs(&block(1), Vec::new()).unwrap();
+        db.store_block_logs(&block(2), Vec::new()).unwrap();
+
+        // Block 1 is in log storage, but not in derivation storage.
+        let err = db.rewind(&block(1).id()).unwrap_err();
+        assert_eq!(err, StorageError::EntryNotFound(EntryNotFoundError::DerivedBlockNotFound(1)));
+        assert_eq!(db.get_latest_block().unwrap(), block(2));
+    }
+
+    #[test]
+    fn test_rewind_keeps_executing_message_index() {
+        let db = InMemoryChainDb::new(1);
+        db.initialise_log_storage(block(0)).unwrap();
+
+        let message = ExecutingMessage {
+            chain_id: 2,
+            block_number: 5,
+            log_index: 0,
+            timestamp: 5,
+            hash: B256::ZERO,
+        };
+        let log = Log { index: 0, hash: B256::ZERO, executing_message: Some(message) };
+        db.store_block_logs(&block(1), vec![log]).unwrap();
+        db.rewind_log_storage(&block(1).id()).unwrap();
+
+        let executions = db.get_executing_messages(&(&message).into()).unwrap();
+        assert_eq!(
+            executions,
+            vec![ExecutingMessageRef { block: block(1).id(), log_index: 0, hash: B256::ZERO }]
+        );
+        assert_eq!(db.get_latest_block().unwrap(), block(0));
+        assert_eq!(db.get_safety_head_ref(SafetyLevel::LocalUnsafe).unwrap(), block(0));
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    CrossChainSafetyProvider, FinalizedL1Storage, HeadRefStorageReader, HeadRefStorageWriter,
+    LogStorageReader, StorageFactory, error::StorageError, memory::InMemoryChainDb,
+};
+use alloy_primitives::ChainId;
+use kona_interop::DerivedRefPair;
+use kona_protocol::BlockInfo;
+use kona_supervisor_metrics::MetricsReporter;
+use kona_supervisor_types::Log;
+use op_alloy_consensus::interop::SafetyLevel;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+use tracing::error;
+
+/// Factory for managing the in-memory storage of multiple chains.
+///
+/// In-memory counterpart of [`ChainDbFactory`](crate::ChainDbFactory).
+#[derive(Debug, Default)]
+pub struct InMemoryChainDbFactory {
+    dbs: RwLock<HashMap<ChainId, Arc<InMemoryChainDb>>>,
+    finalized_l1: RwLock<Option<BlockInfo>>,
+}
+
+impl InMemoryChainDbFactory {
+    /// Create a new, empty factory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl StorageFactory for InMemoryChainDbFactory {
+    type ChainDb = InMemoryChainDb;
+
+    /// Get or create an [`InMemoryChainDb`] for the given chain id.
+    fn get_or_create_db(&self, chain_id: ChainId) -> Result<Arc<InMemoryChainDb>, StorageError> {
+        let mut dbs = self.dbs.write().map_err(|err| {
+            error!(target: "supervisor::storage", %err, "Failed to acquire write lock on databases");
+            StorageError::LockPoisoned
+        })?;
+        Ok(dbs.entry(chain_id).or_insert_with(|| Arc::new(InMemoryChainDb::new(chain_id))).clone())
+    }
+
+    /// Get an [`InMemoryChainDb`] for the given chain id, returning an error if it doesn't exist.
+    fn get_db(&self, chain_id: ChainId) -> Result<Arc<InMemoryChainDb>, StorageError> {
+        let dbs = self.dbs.read().map_err(|_| StorageError::LockPoisoned)?;
+        dbs.get(&chain_id).cloned().ok_or_else(|| StorageError::DatabaseNotInitialised)
+    }
+}
+
+/// The in-memory storage has no metrics to report.
+impl MetricsReporter for InMemoryChainDbFactory {
+    fn report_metrics(&self) {}
+}
+
+impl FinalizedL1Storage for InMemoryChainDbFactory {
+    fn get_finalized_l1(&self) -> Result<BlockInfo, StorageError> {
+        let guard = self.finalized_l1.read().map_err(|_| StorageError::LockPoisoned)?;
+        guard.as_ref().copied().ok_or(StorageError::FutureData)
+    }
+
+    fn update_finalized_l1(&self, block: BlockInfo) -> Result<(), StorageError> {
+        let mut guard = self.finalized_l1.write().map_err(|_| StorageError::LockPoisoned)?;
+        if guard.as_ref().is_some_and(|current| block.number <= current.number) {
+            return Err(StorageError::BlockOutOfOrder);
+        }
+        *guard = Some(block);
+        Ok(())
+    }
+}
+
+impl CrossChainSafetyProvider for InMemoryChainDbFactory {
+    fn get_block(&self, chain_id: ChainId, block_number: u64) -> Result<BlockInfo, StorageError> {
+        self.get_db(chain_id)?.get_block(block_number)
+    }
+
+    fn get_log(
+        &self,
+        chain_id: ChainId,
+        block_number: u64,
+        log_index: u32,
+    ) -> Result<Log, StorageError> {
+        self.get_db(chain_id)?.get_log(block_number, log_index)
+    }
+
+    fn get_block_logs(
+        &self,
+        chain_id: ChainId,
+        block_number: u64,
+    ) -> Result<Vec<Log>, StorageError> {
+        self.get_db(chain_id)?.get_logs(block_number)
+    }
+
+    fn get_safety_head_ref(
+        &self,
+        chain_id: ChainId,
+        level: SafetyLevel,
+    ) -> Result<BlockInfo, StorageError> {
+        self.get_db(chain_id)?.get_safety_head_ref(level)
+    }
+
+    fn update_current_cross_unsafe(
+        &self,
+        chain_id: ChainId,
+        block: &BlockInfo,
+    ) -> Result<(), StorageError> {
+        self.get_db(chain_id)?.update_current_cross_unsafe(block)
+    }
+
+    fn update_current_cross_safe(
+        &self,
+        chain_id: ChainId,
+        block: &BlockInfo,
+    ) -> Result<DerivedRefPair, StorageError> {
+        self.get_db(chain_id)?.update_current_cross_safe(block)
+    }
+
+    fn revert_cross_head(
+        &self,
+        chain_id: ChainId,
+        level: SafetyLevel,
+        block: &BlockInfo,
+    ) -> Result<(), StorageError> {
+        self.get_db(chain_id)?.revert_cross_head(level, block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_or_create_db_returns_same_instance() {
+        let factory = InMemoryChainDbFactory::new();
+        let db1 = factory.get_or_create_db(42).unwrap();
+        let db2 = factory.get_db(42).unwrap();
+        assert!(Arc::ptr_eq(&db1, &db2));
+
+        let err = factory.get_db(999).unwrap_err();
+        assert!(matches!(err, StorageError::DatabaseNotInitialised));
+    }
+
+    #[test]
+    fn test_update_finalized_l1_with_lower_block_number_errors() {
+        let factory = InMemoryChainDbFactory::new();
+        assert!(matches!(factory.get_finalized_l1(), Err(StorageError::FutureData)));
+
+        let block = BlockInfo { number: 100, ..Default::default() };
+        factory.update_finalized_l1(block).unwrap();
+        assert_eq!(factory.get_finalized_l1().unwrap(), block);
+
+        let err = factory.update_finalized_l1(BlockInfo { number: 100, ..Default::default() });
+        assert!(matches!(err, Err(StorageError::BlockOutOfOrder)));
+    }
+}
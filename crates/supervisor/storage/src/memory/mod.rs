@@ -0,0 +1,11 @@
+//! In-memory storage backend.
+//!
+//! Implements the storage traits without a database, keeping all state in memory. The backend
+//! upholds the same invariants as the MDBX-backed [`ChainDb`](crate::ChainDb), and is meant for
+//! tests and ephemeral setups such as devnets, where nothing needs to survive a restart.
+
+mod chaindb;
+pub use chaindb::InMemoryChainDb;
+
+mod factory;
+pub use factory::InMemoryChainDbFactory;
@@ -12,6 +12,10 @@ impl Metrics {
         "kona_supervisor_storage_error_total";
     pub(crate) const STORAGE_REQUEST_DURATION_SECONDS: &'static str =
         "kona_supervisor_storage_duration_seconds";
+    pub(crate) const STORAGE_PRUNED_BLOCKS_TOTAL: &'static str =
+        "kona_supervisor_storage_pruned_blocks_total";
+    pub(crate) const STORAGE_FREED_BYTES_TOTAL: &'static str =
+        "kona_supervisor_storage_freed_bytes_total";
 
     pub(crate) const STORAGE_METHOD_DERIVED_TO_SOURCE: &'static str = "derived_to_source";
     pub(crate) const STORAGE_METHOD_LATEST_DERIVED_BLOCK_AT_SOURCE: &'static str =
@@ -28,6 +32,7 @@ impl Metrics {
     pub(crate) const STORAGE_METHOD_GET_BLOCK: &'static str = "get_block";
     pub(crate) const STORAGE_METHOD_GET_LOG: &'static str = "get_log";
     pub(crate) const STORAGE_METHOD_GET_LOGS: &'static str = "get_logs";
+    pub(crate) const STORAGE_METHOD_GET_EXECUTING_MESSAGES: &'static str = "get_executing_messages";
     pub(crate) const STORAGE_METHOD_INITIALISE_LOG_STORAGE: &'static str = "initialise_log_storage";
     pub(crate) const STORAGE_METHOD_STORE_BLOCK_LOGS: &'static str = "store_block_logs";
     pub(crate) const STORAGE_METHOD_GET_SAFETY_HEAD_REF: &'static str = "get_safety_head_ref";
@@ -43,6 +48,9 @@ impl Metrics {
     pub(crate) const STORAGE_METHOD_REWIND_LOG_STORAGE: &'static str = "rewind_log_storage";
     pub(crate) const STORAGE_METHOD_REWIND: &'static str = "rewind";
     pub(crate) const STORAGE_METHOD_REWIND_TO_SOURCE: &'static str = "rewind_to_source";
+    pub(crate) const STORAGE_METHOD_PRUNE: &'static str = "prune";
+    pub(crate) const STORAGE_METHOD_GET_FINALIZED_OUTPUT: &'static str = "get_finalized_output";
+    pub(crate) const STORAGE_METHOD_SAVE_FINALIZED_OUTPUT: &'static str = "save_finalized_output";
 
     pub(crate) fn init(chain_id: ChainId) {
         Self::describe();
@@ -65,6 +73,16 @@ impl Metrics {
             metrics::Unit::Seconds,
             "Duration of Kona Supervisor Storage requests"
         );
+        metrics::describe_counter!(
+            Self::STORAGE_PRUNED_BLOCKS_TOTAL,
+            metrics::Unit::Count,
+            "Total number of blocks pruned from Kona Supervisor Storage"
+        );
+        metrics::describe_counter!(
+            Self::STORAGE_FREED_BYTES_TOTAL,
+            metrics::Unit::Bytes,
+            "Total number of bytes of table pages freed for reuse by pruning Kona Supervisor Storage"
+        );
     }
 
     fn zero_storage_methods(chain_id: ChainId, method_name: &'static str) {
@@ -102,6 +120,7 @@ impl Metrics {
         Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_GET_BLOCK);
         Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_GET_LOG);
         Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_GET_LOGS);
+        Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_GET_EXECUTING_MESSAGES);
         Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_INITIALISE_LOG_STORAGE);
         Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_STORE_BLOCK_LOGS);
         Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_GET_SAFETY_HEAD_REF);
@@ -114,5 +133,13 @@ impl Metrics {
         Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_REWIND_LOG_STORAGE);
         Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_REWIND);
         Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_REWIND_TO_SOURCE);
+        Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_PRUNE);
+        Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_GET_FINALIZED_OUTPUT);
+        Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_SAVE_FINALIZED_OUTPUT);
+
+        metrics::counter!(Self::STORAGE_PRUNED_BLOCKS_TOTAL, "chain_id" => chain_id.to_string())
+            .increment(0);
+        metrics::counter!(Self::STORAGE_FREED_BYTES_TOTAL, "chain_id" => chain_id.to_string())
+            .increment(0);
     }
 }
@@ -0,0 +1,225 @@
+//! Models for the reverse message index.
+//!
+//! The reverse index maps an initiating message, identified by the chain, block and log it was
+//! emitted in, to every executing message in the local chain that references it. It is maintained
+//! by the log storage alongside [`crate::models::LogEntries`] and lets the supervisor answer
+//! lifecycle queries without scanning all stored logs.
+
+use alloy_eips::BlockNumHash;
+use alloy_primitives::B256;
+use bytes::{Buf, BufMut};
+use derive_more::{Deref, DerefMut};
+use kona_supervisor_types::{ExecutingMessageRef, MessageIdentifier};
+use reth_codecs::Compact;
+use reth_db::DatabaseError;
+use reth_db_api::table;
+use serde::{Deserialize, Serialize};
+
+/// Key of the [`crate::models::ExecutingMessages`] table.
+///
+/// Identifies an initiating message by its chain ID, block number and log index.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+)]
+pub struct MessageIdentifierKey {
+    /// ID of the chain where the message was initiated.
+    pub chain_id: u64,
+    /// Block number of the initiating log.
+    pub block_number: u64,
+    /// Index of the initiating log within the block.
+    pub log_index: u32,
+}
+
+/// Implementation of [`table::Encode`] for [`MessageIdentifierKey`].
+///
+/// Fields are encoded big-endian in declaration order, so the byte ordering matches the
+/// derived [`Ord`] implementation.
+impl table::Encode for MessageIdentifierKey {
+    type Encoded = [u8; 20];
+
+    fn encode(self) -> Self::Encoded {
+        let mut buf = [0u8; 20];
+        buf[..8].copy_from_slice(&self.chain_id.to_be_bytes());
+        buf[8..16].copy_from_slice(&self.block_number.to_be_bytes());
+        buf[16..].copy_from_slice(&self.log_index.to_be_bytes());
+        buf
+    }
+}
+
+/// Implementation of [`table::Decode`] for [`MessageIdentifierKey`].
+impl table::Decode for MessageIdentifierKey {
+    fn decode(value: &[u8]) -> Result<Self, DatabaseError> {
+        if value.len() != 20 {
+            return Err(DatabaseError::Decode)
+        }
+
+        let mut buf = value;
+        Ok(Self { chain_id: buf.get_u64(), block_number: buf.get_u64(), log_index: buf.get_u32() })
+    }
+}
+
+impl From<MessageIdentifier> for MessageIdentifierKey {
+    fn from(id: MessageIdentifier) -> Self {
+        Self { chain_id: id.chain_id, block_number: id.block_number, log_index: id.log_index }
+    }
+}
+
+/// Reference to an executing message stored in the [`crate::models::ExecutingMessages`]
+/// dup-sorted table.
+///
+/// The block hash is kept so that references left behind by a rewind of the executing chain can
+/// be told apart from canonical ones. The message hash claimed by the executing log is kept so that
+/// executions of a different message at the same position can be told apart from valid ones.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ExecutingMessageRefEntry {
+    /// Number of the block containing the executing log.
+    pub block_number: u64,
+    /// Index of the executing log within the block.
+    pub log_index: u32,
+    /// Hash of the block containing the executing log.
+    pub block_hash: B256,
+    /// Hash of the initiating message, as claimed by the executing log.
+    pub message_hash: B256,
+}
+
+/// Compact encoding for [`ExecutingMessageRefEntry`].
+///
+/// ## Encoding Layout (ordered):
+/// - `block_number: u64` – Subkey for dup sort ordering.
+/// - `log_index: u32`
+/// - `block_hash: B256` – 32-byte block hash.
+/// - `message_hash: B256` – 32-byte message hash.
+impl Compact for ExecutingMessageRefEntry {
+    fn to_compact<B>(&self, buf: &mut B) -> usize
+    where
+        B: BufMut + AsMut<[u8]>,
+    {
+        let start_len = buf.remaining_mut();
+
+        buf.put_u64(self.block_number); // Subkey must be at first
+        buf.put_u32(self.log_index);
+        buf.put_slice(self.block_hash.as_slice());
+        buf.put_slice(self.message_hash.as_slice());
+
+        start_len - buf.remaining_mut()
+    }
+
+    fn from_compact(mut buf: &[u8], _len: usize) -> (Self, &[u8]) {
+        let block_number = buf.get_u64();
+        let log_index = buf.get_u32();
+
+        assert!(buf.len() >= 64, "ExecutingMessageRefEntry::from_compact: buffer too small");
+        let block_hash = B256::from_slice(&buf[..32]);
+        let message_hash = B256::from_slice(&buf[32..64]);
+        buf.advance(64);
+
+        (Self { block_number, log_index, block_hash, message_hash }, buf)
+    }
+}
+
+impl From<ExecutingMessageRefEntry> for ExecutingMessageRef {
+    fn from(entry: ExecutingMessageRefEntry) -> Self {
+        Self {
+            block: BlockNumHash::new(entry.block_number, entry.block_hash),
+            log_index: entry.log_index,
+            hash: entry.message_hash,
+        }
+    }
+}
+
+/// List of the initiating messages referenced by the executing logs of a block, stored in the
+/// [`crate::models::ExecutingMessageKeys`] table.
+///
+/// Lets pruning find the [`crate::models::ExecutingMessages`] entries of a range of blocks without
+/// scanning the whole reverse index.
+#[derive(Deref, DerefMut, Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct MessageIdentifierList(pub Vec<MessageIdentifierKey>);
+
+/// Compact encoding for [`MessageIdentifierList`].
+///
+/// The keys are concatenated in their 20-byte [`table::Encode`] form.
+impl Compact for MessageIdentifierList {
+    fn to_compact<B>(&self, buf: &mut B) -> usize
+    where
+        B: BufMut + AsMut<[u8]>,
+    {
+        for key in &self.0 {
+            buf.put_slice(&table::Encode::encode(*key));
+        }
+        self.0.len() * 20
+    }
+
+    fn from_compact(buf: &[u8], len: usize) -> (Self, &[u8]) {
+        let (keys, rest) = buf.split_at(len);
+        let keys = keys
+            .chunks_exact(20)
+            .map(|key| table::Decode::decode(key).expect("20-byte chunk is a valid key"))
+            .collect();
+        (Self(keys), rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_db_api::table::{Decode, Encode};
+
+    #[test]
+    fn test_message_identifier_key_encode_decode() {
+        let key = MessageIdentifierKey { chain_id: 10, block_number: 1_000, log_index: 7 };
+
+        let encoded = key.encode();
+        assert_eq!(&encoded[..8], &10u64.to_be_bytes());
+        assert_eq!(&encoded[8..16], &1_000u64.to_be_bytes());
+        assert_eq!(&encoded[16..], &7u32.to_be_bytes());
+
+        let decoded = MessageIdentifierKey::decode(&encoded).expect("decoding should succeed");
+        assert_eq!(decoded, key);
+
+        assert!(matches!(MessageIdentifierKey::decode(&encoded[..19]), Err(DatabaseError::Decode)));
+    }
+
+    #[test]
+    fn test_message_identifier_key_encoding_preserves_order() {
+        let lower = MessageIdentifierKey { chain_id: 1, block_number: 256, log_index: 0 };
+        let higher = MessageIdentifierKey { chain_id: 1, block_number: 257, log_index: 0 };
+
+        assert!(lower < higher);
+        assert!(lower.encode() < higher.encode());
+    }
+
+    #[test]
+    fn test_executing_message_ref_entry_compact_roundtrip() {
+        let entry = ExecutingMessageRefEntry {
+            block_number: 42,
+            log_index: 3,
+            block_hash: B256::from([0xab; 32]),
+            message_hash: B256::from([0xcd; 32]),
+        };
+
+        let mut buf = Vec::new();
+        let len = entry.to_compact(&mut buf);
+        assert_eq!(len, 8 + 4 + 32 + 32);
+
+        let (decoded, rest) = ExecutingMessageRefEntry::from_compact(&buf, len);
+        assert_eq!(decoded, entry);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_message_identifier_list_compact_roundtrip() {
+        let list = MessageIdentifierList(vec![
+            MessageIdentifierKey { chain_id: 10, block_number: 1_000, log_index: 7 },
+            MessageIdentifierKey { chain_id: 11, block_number: 3, log_index: 0 },
+        ]);
+
+        let mut buf = Vec::new();
+        let len = list.to_compact(&mut buf);
+        assert_eq!(len, 40);
+        buf.push(0xff);
+
+        let (decoded, rest) = MessageIdentifierList::from_compact(&buf, len);
+        assert_eq!(decoded, list);
+        assert_eq!(rest, &[0xff]);
+    }
+}
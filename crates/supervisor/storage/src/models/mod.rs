@@ -22,6 +22,12 @@ pub use block::BlockRef;
 mod derivation;
 pub use derivation::{SourceBlockTraversal, StoredDerivedBlockPair};
 
+mod message;
+pub use message::{ExecutingMessageRefEntry, MessageIdentifierKey, MessageIdentifierList};
+
+mod output;
+pub use output::{OutputRootEntry, StoredChainOutput};
+
 mod common;
 mod head_ref;
 pub use head_ref::SafetyHeadRefKey;
@@ -65,7 +71,10 @@ impl_compression_for_compact!(
     LogEntry,
     StoredDerivedBlockPair,
     U64List,
-    SourceBlockTraversal
+    SourceBlockTraversal,
+    ExecutingMessageRefEntry,
+    MessageIdentifierList,
+    StoredChainOutput
 );
 
 tables! {
@@ -115,6 +124,37 @@ tables! {
         type Key = SafetyHeadRefKey;
         type Value = BlockRef;
     }
+
+    /// A dup-sorted reverse index from an initiating message to the executing messages in this
+    /// chain that reference it.
+    /// - Key: [`MessageIdentifierKey`] — chain ID, block number and log index of the initiating
+    ///   message
+    /// - Value: [`ExecutingMessageRefEntry`] — block and log index of the executing message, and
+    ///   the message hash it claims
+    /// - SubKey: `u64` — block number of the executing message
+    table ExecutingMessages {
+        type Key = MessageIdentifierKey;
+        type Value = ExecutingMessageRefEntry;
+        type SubKey = u64;
+    }
+
+    /// A table mapping an executing block number to the initiating messages referenced by its
+    /// logs, across every version of the block stored so far. Used to prune
+    /// [`ExecutingMessages`] by block range.
+    /// - Key: `u64` — block number of the executing messages
+    /// - Value: [`MessageIdentifierList`] — keys of the referenced initiating messages
+    table ExecutingMessageKeys {
+        type Key = u64;
+        type Value = MessageIdentifierList;
+    }
+
+    /// A table caching the outputs of finalized blocks, used to compute super roots.
+    /// - Key: `u64` — timestamp the output was requested at
+    /// - Value: [`StoredChainOutput`] — outputs of the latest block at or before the timestamp
+    table ChainOutputs {
+        type Key = u64;
+        type Value = StoredChainOutput;
+    }
 }
 
 #[cfg(test)]
@@ -210,6 +250,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_stored_chain_output_compression_decompression() {
+        let output = OutputRootEntry {
+            state_root: test_b256(9),
+            message_passer_storage_root: test_b256(10),
+            block_hash: test_b256(11),
+        };
+        let original = StoredChainOutput {
+            block_number: 42,
+            source: BlockRef {
+                number: 7,
+                hash: test_b256(12),
+                parent_hash: test_b256(13),
+                timestamp: 84,
+            },
+            output: output.clone(),
+            pending: OutputRootEntry { state_root: test_b256(14), ..output },
+        };
+
+        let mut compressed_buf = Vec::new();
+        original.compress_to_buf(&mut compressed_buf);
+        assert!(!compressed_buf.is_empty());
+        let decompressed = StoredChainOutput::decompress(&compressed_buf).unwrap();
+        assert_eq!(original, decompressed);
+    }
+
     #[test]
     fn test_u64list_compression_decompression_empty() {
         let original_list = U64List(Vec::new());
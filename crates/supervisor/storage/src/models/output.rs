@@ -0,0 +1,73 @@
+//! Models for caching the outputs of finalized blocks.
+//!
+//! Computing a super root requires the output of every chain at the given timestamp, which is
+//! fetched from the managed nodes. Once the blocks are finalized these outputs can no longer
+//! change, so they are stored in the [`crate::models::ChainOutputs`] table keyed by timestamp.
+
+use crate::models::BlockRef;
+use alloy_primitives::B256;
+use kona_supervisor_types::{ChainOutput, OutputV0};
+use reth_codecs::Compact;
+use serde::{Deserialize, Serialize};
+
+/// Storage format of an [`OutputV0`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, Compact)]
+pub struct OutputRootEntry {
+    /// The state root of the block.
+    pub state_root: B256,
+    /// The storage root of the message passer contract.
+    pub message_passer_storage_root: B256,
+    /// The hash of the block.
+    pub block_hash: B256,
+}
+
+impl From<OutputV0> for OutputRootEntry {
+    fn from(output: OutputV0) -> Self {
+        Self {
+            state_root: output.state_root,
+            message_passer_storage_root: output.message_passer_storage_root,
+            block_hash: output.block_hash,
+        }
+    }
+}
+
+impl From<OutputRootEntry> for OutputV0 {
+    fn from(entry: OutputRootEntry) -> Self {
+        Self::new(entry.state_root, entry.message_passer_storage_root, entry.block_hash)
+    }
+}
+
+/// Storage format of a [`ChainOutput`], stored in the [`crate::models::ChainOutputs`] table.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, Compact)]
+pub struct StoredChainOutput {
+    /// The number of the L2 block.
+    pub block_number: u64,
+    /// The L1 block the L2 block was derived from.
+    pub source: BlockRef,
+    /// The output of the canonical block.
+    pub output: OutputRootEntry,
+    /// The output of the block prior to validation of its executing messages.
+    pub pending: OutputRootEntry,
+}
+
+impl From<ChainOutput> for StoredChainOutput {
+    fn from(output: ChainOutput) -> Self {
+        Self {
+            block_number: output.block_number,
+            source: output.source.into(),
+            output: output.output.into(),
+            pending: output.pending.into(),
+        }
+    }
+}
+
+impl From<StoredChainOutput> for ChainOutput {
+    fn from(entry: StoredChainOutput) -> Self {
+        Self {
+            block_number: entry.block_number,
+            source: entry.source.into(),
+            output: entry.output.into(),
+            pending: entry.pending.into(),
+        }
+    }
+}
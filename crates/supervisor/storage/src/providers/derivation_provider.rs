@@ -4,6 +4,7 @@ use crate::{
     models::{
         BlockTraversal, DerivedBlocks, SourceBlockTraversal, StoredDerivedBlockPair, U64List,
     },
+    providers::is_pruned,
 };
 use alloy_eips::eip1898::BlockNumHash;
 use alloy_primitives::ChainId;
@@ -60,15 +61,18 @@ where
                 );
             })?;
 
-        let derived_block_pair = derived_block_pair_opt.ok_or_else(|| {
+        let Some(derived_block_pair) = derived_block_pair_opt else {
+            if is_pruned::<DerivedBlocks, _>(self.tx, derived_block_number)? {
+                return Err(StorageError::EntryPruned(derived_block_number));
+            }
             warn!(
               target: "supervisor::storage",
               chain_id = %self.chain_id,
               derived_block_number,
               "Derived block not found"
             );
-            EntryNotFoundError::DerivedBlockNotFound(derived_block_number)
-        })?;
+            return Err(EntryNotFoundError::DerivedBlockNotFound(derived_block_number).into());
+        };
 
         Ok(derived_block_pair)
     }
@@ -129,15 +133,20 @@ where
                 );
             })?;
 
-        Ok(block_traversal.ok_or_else(|| {
+        let Some(block_traversal) = block_traversal else {
+            if is_pruned::<BlockTraversal, _>(self.tx, source_block_number)? {
+                return Err(StorageError::EntryPruned(source_block_number));
+            }
             warn!(
               target: "supervisor::storage",
               chain_id = %self.chain_id,
               source_block_number,
               "source block not found"
             );
-            EntryNotFoundError::SourceBlockNotFound(source_block_number)
-        })?)
+            return Err(EntryNotFoundError::SourceBlockNotFound(source_block_number).into());
+        };
+
+        Ok(block_traversal)
     }
 
     /// Gets the latest derived [`BlockInfo`] at the given source [`BlockNumHash`].
@@ -594,6 +603,64 @@ where
         self.rewind_block_traversal_to(&block_pair)
     }
 
+    /// Prunes all derived blocks below the given derived block number, together with the
+    /// traversals of the source blocks they were derived from. The activation pair is kept.
+    ///
+    /// Returns the number of pruned derived blocks.
+    pub(crate) fn prune_to(&self, block_number: u64) -> Result<u64, StorageError> {
+        // The boundary must exist, so that pruning never creates a gap at the tip.
+        let boundary = self.get_derived_block_pair_by_number(block_number)?;
+
+        let mut pruned_blocks = 0;
+        {
+            let mut cursor = self.tx.cursor_write::<DerivedBlocks>()?;
+            let (activation, _) = cursor.first()?.ok_or(StorageError::DatabaseNotInitialised)?;
+            if block_number > activation + 1 {
+                let mut walker = cursor.walk_range(activation + 1..block_number)?;
+                while let Some(row) = walker.next() {
+                    row?;
+                    walker.delete_current()?;
+                    pruned_blocks += 1;
+                }
+            }
+        }
+
+        let activation_source = {
+            let mut cursor = self.tx.cursor_write::<BlockTraversal>()?;
+            let (activation_source, _) =
+                cursor.first()?.ok_or(StorageError::DatabaseNotInitialised)?;
+            if boundary.source.number > activation_source + 1 {
+                let mut walker =
+                    cursor.walk_range(activation_source + 1..boundary.source.number)?;
+                while let Some(row) = walker.next() {
+                    row?;
+                    walker.delete_current()?;
+                }
+            }
+            activation_source
+        };
+
+        // The boundary source block may also have derived pruned blocks.
+        if boundary.source.number > activation_source {
+            let mut traversal = self.get_block_traversal(boundary.source.number)?;
+            traversal.derived_block_numbers.retain(|&num| num >= block_number);
+            self.tx.put::<BlockTraversal>(boundary.source.number, traversal).inspect_err(
+                |err| {
+                    error!(target: "supervisor::storage", chain_id = %self.chain_id, %err, "Failed to update block traversal");
+                },
+            )?;
+        }
+
+        info!(
+            target: "supervisor::storage",
+            chain_id = %self.chain_id,
+            block_number,
+            pruned_blocks,
+            "Pruned derivation storage"
+        );
+        Ok(pruned_blocks)
+    }
+
     /// Rewinds the block traversal for a given derived block pair.
     /// - If only part of the derived list needs to be removed, it updates the list in-place.
     /// - If later source blocks exist, they are removed entirely.
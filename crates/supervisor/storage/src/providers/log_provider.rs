@@ -12,15 +12,23 @@
 //!
 //! Logs are stored in [`LogEntries`] under dup-sorted tables, with log index
 //! used as the subkey. Block metadata is stored in [`BlockRefs`].
+//!
+//! Executing messages are additionally indexed in [`ExecutingMessages`], keyed by the
+//! identifier of the initiating message they reference. Rewinds keep these entries so that
+//! executions which were reorged out can still be reported as invalidated.
 
 use crate::{
     error::{EntryNotFoundError, StorageError},
-    models::{BlockRefs, LogEntries},
+    models::{
+        BlockRefs, ExecutingMessageKeys, ExecutingMessageRefEntry, ExecutingMessages, LogEntries,
+        MessageIdentifierKey,
+    },
+    providers::is_pruned,
 };
 use alloy_eips::BlockNumHash;
-use alloy_primitives::ChainId;
+use alloy_primitives::{B256, ChainId};
 use kona_protocol::BlockInfo;
-use kona_supervisor_types::Log;
+use kona_supervisor_types::{ExecutingMessageRef, Log, MessageIdentifier};
 use reth_db_api::{
     cursor::{DbCursorRO, DbDupCursorRO, DbDupCursorRW},
     transaction::{DbTx, DbTxMut},
@@ -142,7 +150,14 @@ where
             );
         })?;
 
+        let mut initiating_keys = Vec::new();
         for log in logs {
+            if let Some(msg) = &log.executing_message {
+                let initiating = MessageIdentifierKey::from(MessageIdentifier::from(msg));
+                self.index_executing_message(block, log.index, initiating, msg.hash)?;
+                initiating_keys.push(initiating);
+            }
+
             cursor.append_dup(block.number, log.into()).inspect_err(|err| {
                 error!(
                     target: "supervisor::storage",
@@ -153,6 +168,53 @@ where
                 );
             })?;
         }
+
+        if !initiating_keys.is_empty() {
+            // Keep the keys indexed by earlier versions of the block, so that pruning also finds
+            // the entries left behind by rewinds.
+            let mut keys = self.tx.get::<ExecutingMessageKeys>(block.number)?.unwrap_or_default();
+            for key in initiating_keys {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+            self.tx.put::<ExecutingMessageKeys>(block.number, keys).inspect_err(|err| {
+                error!(
+                    target: "supervisor::storage",
+                    chain_id = %self.chain_id,
+                    block_number = block.number,
+                    %err,
+                    "Failed to index executing message keys"
+                );
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Records the executing log at `log_index` in `block` in the reverse message index.
+    fn index_executing_message(
+        &self,
+        block: &BlockInfo,
+        log_index: u32,
+        initiating: MessageIdentifierKey,
+        message_hash: B256,
+    ) -> Result<(), StorageError> {
+        let entry = ExecutingMessageRefEntry {
+            block_number: block.number,
+            log_index,
+            block_hash: block.hash,
+            message_hash,
+        };
+        self.tx.put::<ExecutingMessages>(initiating, entry).inspect_err(|err| {
+            error!(
+                target: "supervisor::storage",
+                chain_id = %self.chain_id,
+                block_number = block.number,
+                log_index,
+                %err,
+                "Failed to index executing message"
+            );
+        })?;
         Ok(())
     }
 
@@ -258,6 +320,78 @@ where
 
         Ok(())
     }
+
+    /// Prunes all blocks and logs below the given block number, keeping the activation block.
+    ///
+    /// Returns the number of pruned blocks.
+    pub(crate) fn prune_to(&self, block_number: u64) -> Result<u64, StorageError> {
+        let mut cursor = self.tx.cursor_write::<BlockRefs>()?;
+        let Some((activation, _)) = cursor.first()? else {
+            return Err(StorageError::DatabaseNotInitialised);
+        };
+        if block_number <= activation + 1 {
+            return Ok(0);
+        }
+
+        let mut pruned_blocks = 0;
+        let mut walker = cursor.walk_range(activation + 1..block_number)?;
+        while let Some(row) = walker.next() {
+            let (key, _) = row?;
+
+            // remove the block and its logs
+            walker.delete_current()?;
+            self.tx.delete::<LogEntries>(key, None)?;
+
+            pruned_blocks += 1;
+        }
+
+        // Drop reverse index entries of pruned executions, including the ones left behind by
+        // rewinds.
+        let mut cursor = self.tx.cursor_write::<ExecutingMessageKeys>()?;
+        let mut walker = cursor.walk_range(activation + 1..block_number)?;
+        while let Some(row) = walker.next() {
+            let (executing_block, keys) = row?;
+            for key in keys.0 {
+                self.remove_executions(key, executing_block)?;
+            }
+            walker.delete_current()?;
+        }
+
+        info!(
+            target: "supervisor::storage",
+            chain_id = %self.chain_id,
+            block_number,
+            pruned_blocks,
+            "Pruned log storage"
+        );
+        Ok(pruned_blocks)
+    }
+
+    /// Removes the executions of the initiating message `key` in `executing_block` from the
+    /// reverse message index.
+    fn remove_executions(
+        &self,
+        key: MessageIdentifierKey,
+        executing_block: u64,
+    ) -> Result<(), StorageError> {
+        let entries = {
+            let mut cursor = self.tx.cursor_dup_read::<ExecutingMessages>()?;
+            let mut entries = Vec::new();
+            for row in cursor.walk_dup(Some(key), Some(executing_block))? {
+                let (_, entry) = row?;
+                if entry.block_number != executing_block {
+                    break;
+                }
+                entries.push(entry);
+            }
+            entries
+        };
+
+        for entry in entries {
+            self.tx.delete::<ExecutingMessages>(key, Some(entry))?;
+        }
+        Ok(())
+    }
 }
 
 impl<TX> LogProvider<'_, TX>
@@ -282,18 +416,29 @@ where
             );
         })?;
 
-        let block = block_option.ok_or_else(|| {
+        let Some(block) = block_option else {
+            self.ensure_not_pruned(block_number)?;
             warn!(
                 target: "supervisor::storage",
                 chain_id = %self.chain_id,
                 block_number,
                 "Block not found"
             );
-            EntryNotFoundError::DerivedBlockNotFound(block_number)
-        })?;
+            return Err(EntryNotFoundError::DerivedBlockNotFound(block_number).into());
+        };
         Ok(block.into())
     }
 
+    /// Returns the number of the oldest block above the activation block that has not been
+    /// pruned, or [`None`] if only the activation block is stored.
+    pub(crate) fn oldest_prunable_block(&self) -> Result<Option<u64>, StorageError> {
+        let mut cursor = self.tx.cursor_read::<BlockRefs>()?;
+        if cursor.first()?.is_none() {
+            return Err(StorageError::DatabaseNotInitialised);
+        }
+        Ok(cursor.next()?.map(|(number, _)| number))
+    }
+
     pub(crate) fn get_latest_block(&self) -> Result<BlockInfo, StorageError> {
         debug!(target: "supervisor::storage", chain_id = %self.chain_id, "Fetching latest block");
 
@@ -355,7 +500,8 @@ where
             );
         })?;
 
-        let log_entry = result.ok_or_else(|| {
+        let Some(log_entry) = result else {
+            self.ensure_not_pruned(block_number)?;
             warn!(
                 target: "supervisor::storage",
                 chain_id = %self.chain_id,
@@ -363,8 +509,8 @@ where
                 log_index,
                 "Log not found"
             );
-            EntryNotFoundError::LogNotFound { block_number, log_index }
-        })?;
+            return Err(EntryNotFoundError::LogNotFound { block_number, log_index }.into());
+        };
 
         Ok(Log::from(log_entry))
     }
@@ -407,8 +553,60 @@ where
                 }
             }
         }
+        if logs.is_empty() {
+            self.ensure_not_pruned(block_number)?;
+        }
         Ok(logs)
     }
+
+    /// Returns all executing messages in this chain that reference the given initiating message.
+    ///
+    /// The result may contain executions from blocks that were since rewound. Callers should
+    /// check the returned block against the canonical one.
+    pub(crate) fn get_executing_messages(
+        &self,
+        initiating: &MessageIdentifier,
+    ) -> Result<Vec<ExecutingMessageRef>, StorageError> {
+        debug!(
+            target: "supervisor::storage",
+            chain_id = %self.chain_id,
+            ?initiating,
+            "Fetching executing messages"
+        );
+
+        let mut cursor = self.tx.cursor_dup_read::<ExecutingMessages>().inspect_err(|err| {
+            error!(
+                target: "supervisor::storage",
+                chain_id = %self.chain_id,
+                %err,
+                "Failed to get dup cursor for ExecutingMessages"
+            );
+        })?;
+
+        let key = MessageIdentifierKey::from(*initiating);
+        let walker = cursor.walk_dup(Some(key), None)?;
+
+        let mut executions = Vec::new();
+        for row in walker {
+            let (_, entry) = row?;
+            executions.push(entry.into());
+        }
+        Ok(executions)
+    }
+
+    /// Returns [`StorageError::EntryPruned`] if the given block was pruned from the log storage.
+    fn ensure_not_pruned(&self, block_number: u64) -> Result<(), StorageError> {
+        if is_pruned::<BlockRefs, _>(self.tx, block_number)? {
+            debug!(
+                target: "supervisor::storage",
+                chain_id = %self.chain_id,
+                block_number,
+                "Block was pruned"
+            );
+            return Err(StorageError::EntryPruned(block_number));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -780,4 +978,71 @@ mod tests {
             "Expected conflict error due to hash mismatch"
         );
     }
+
+    #[test]
+    fn test_executing_message_index() {
+        let db = setup_db();
+        let genesis = genesis_block();
+        initialize_db(&db, &genesis).expect("Failed to initialize DB");
+
+        // Logs 0 and 2 of every block reference the same initiating message
+        let mut blocks = vec![genesis];
+        for i in 1..=3 {
+            let block = sample_block_info(i, blocks[i as usize - 1].hash);
+            let logs = (0..3).map(|j| sample_log(j, j % 2 == 0)).collect();
+            insert_block_logs(&db, &block, logs).expect("Failed to insert logs");
+            blocks.push(block);
+        }
+
+        let initiating = MessageIdentifier { chain_id: 10, block_number: 999, log_index: 7 };
+        let unknown = MessageIdentifier { chain_id: 10, block_number: 999, log_index: 8 };
+
+        let tx = db.tx().expect("Could not get RO tx");
+        let provider = LogProvider::new(&tx, CHAIN_ID);
+        let executions = provider.get_executing_messages(&initiating).expect("should read index");
+        assert_eq!(executions.len(), 6);
+        assert!(executions.contains(&ExecutingMessageRef {
+            block: blocks[2].id(),
+            log_index: 2,
+            hash: B256::from([0x44; 32]),
+        }));
+        assert!(provider.get_executing_messages(&unknown).expect("should read index").is_empty());
+        drop(tx);
+
+        // Rewinding keeps the executions of the removed blocks
+        let tx = db.tx_mut().expect("Could not get mutable tx");
+        LogProvider::new(&tx, CHAIN_ID).rewind_to(&blocks[3].id()).expect("Failed to rewind");
+        tx.commit().expect("Failed to commit rewind");
+
+        let tx = db.tx().expect("Could not get RO tx");
+        let provider = LogProvider::new(&tx, CHAIN_ID);
+        assert_eq!(provider.get_executing_messages(&initiating).unwrap().len(), 6);
+        drop(tx);
+
+        // A replacement block is indexed next to the rewound one
+        let mut fork = sample_block_info(3, blocks[2].hash);
+        fork.hash = B256::from([0xAB; 32]);
+        insert_block_logs(&db, &fork, vec![sample_log(0, true)]).expect("Failed to insert fork");
+
+        // Pruning drops the executions of the pruned blocks
+        let tx = db.tx_mut().expect("Could not get mutable tx");
+        assert_eq!(LogProvider::new(&tx, CHAIN_ID).prune_to(2).expect("Failed to prune"), 1);
+        tx.commit().expect("Failed to commit prune");
+
+        let tx = db.tx().expect("Could not get RO tx");
+        let provider = LogProvider::new(&tx, CHAIN_ID);
+        let executions = provider.get_executing_messages(&initiating).unwrap();
+        assert_eq!(executions.len(), 5);
+        assert!(executions.iter().all(|exec| exec.block.number >= 2));
+        drop(tx);
+
+        // Both the rewound and the replacement executions of a pruned block are dropped
+        let tx = db.tx_mut().expect("Could not get mutable tx");
+        assert_eq!(LogProvider::new(&tx, CHAIN_ID).prune_to(4).expect("Failed to prune"), 2);
+        tx.commit().expect("Failed to commit prune");
+
+        let tx = db.tx().expect("Could not get RO tx");
+        let provider = LogProvider::new(&tx, CHAIN_ID);
+        assert!(provider.get_executing_messages(&initiating).unwrap().is_empty());
+    }
 }
@@ -5,6 +5,10 @@
 //! - Logs and block metadata (via [`LogProvider`])
 //! - Derivation pipeline state (via [`DerivationProvider`])
 //! - Chain head tracking and progression
+//! - Cached outputs of finalized blocks (via [`OutputProvider`])
+
+use reth_db_api::{DatabaseError, cursor::DbCursorRO, table::Table, transaction::DbTx};
+
 mod derivation_provider;
 pub(crate) use derivation_provider::DerivationProvider;
 
@@ -13,3 +17,22 @@ pub(crate) use log_provider::LogProvider;
 
 mod head_ref_provider;
 pub(crate) use head_ref_provider::SafetyHeadRefProvider;
+
+mod output_provider;
+pub(crate) use output_provider::OutputProvider;
+
+/// Returns whether the entry keyed by `key` was pruned from table `T`.
+///
+/// Tables are append-only with contiguous keys, and pruning always keeps the first (activation)
+/// entry while removing the range of keys right above it. An entry is therefore pruned iff its key
+/// falls strictly between the first two stored keys.
+pub(crate) fn is_pruned<T, TX>(tx: &TX, key: u64) -> Result<bool, DatabaseError>
+where
+    T: Table<Key = u64>,
+    TX: DbTx,
+{
+    let mut cursor = tx.cursor_read::<T>()?;
+    let Some((first, _)) = cursor.first()? else { return Ok(false) };
+    let Some((next, _)) = cursor.next()? else { return Ok(false) };
+    Ok(first < key && key < next)
+}
@@ -0,0 +1,144 @@
+//! Provider for the cached outputs of finalized blocks.
+//!
+//! Outputs are keyed by the timestamp they were requested at. Since later timestamps resolve to
+//! the same or later blocks, the block numbers of the stored outputs grow with their keys, which
+//! lets rewinds and pruning stop at the first output they must keep.
+use crate::{StorageError, models::ChainOutputs};
+use alloy_primitives::ChainId;
+use derive_more::Constructor;
+use kona_supervisor_types::ChainOutput;
+use reth_db_api::{
+    cursor::{DbCursorRO, DbCursorRW},
+    transaction::{DbTx, DbTxMut},
+};
+use tracing::error;
+
+/// A provider for the cached outputs that wraps a transactional reference.
+#[derive(Debug, Constructor)]
+pub(crate) struct OutputProvider<'tx, TX> {
+    tx: &'tx TX,
+    chain_id: ChainId,
+}
+
+impl<TX> OutputProvider<'_, TX>
+where
+    TX: DbTx,
+{
+    /// Returns the output cached for the given timestamp, if any.
+    pub(crate) fn get_output(&self, timestamp: u64) -> Result<Option<ChainOutput>, StorageError> {
+        let result = self.tx.get::<ChainOutputs>(timestamp).inspect_err(|err| {
+            error!(
+                target: "supervisor::storage",
+                chain_id = %self.chain_id,
+                timestamp,
+                %err,
+                "Failed to get cached output"
+            );
+        })?;
+        Ok(result.map(Into::into))
+    }
+}
+
+impl<TX> OutputProvider<'_, TX>
+where
+    TX: DbTxMut + DbTx,
+{
+    /// Caches the output for the given timestamp.
+    pub(crate) fn save_output(
+        &self,
+        timestamp: u64,
+        output: ChainOutput,
+    ) -> Result<(), StorageError> {
+        self.tx.put::<ChainOutputs>(timestamp, output.into()).inspect_err(|err| {
+            error!(
+                target: "supervisor::storage",
+                chain_id = %self.chain_id,
+                timestamp,
+                %err,
+                "Failed to cache output"
+            );
+        })?;
+        Ok(())
+    }
+
+    /// Removes the outputs of the given block and all later blocks.
+    pub(crate) fn rewind_to(&self, block_number: u64) -> Result<(), StorageError> {
+        let mut cursor = self.tx.cursor_write::<ChainOutputs>()?;
+        let mut walker = cursor.walk_back(None)?;
+        while let Some(row) = walker.next() {
+            let (_, output) = row?;
+            if output.block_number < block_number {
+                break;
+            }
+            walker.delete_current()?;
+        }
+        Ok(())
+    }
+
+    /// Removes the outputs of all blocks below the given block number.
+    pub(crate) fn prune_to(&self, block_number: u64) -> Result<(), StorageError> {
+        let mut cursor = self.tx.cursor_write::<ChainOutputs>()?;
+        let mut walker = cursor.walk(None)?;
+        while let Some(row) = walker.next() {
+            let (_, output) = row?;
+            if output.block_number >= block_number {
+                break;
+            }
+            walker.delete_current()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Tables;
+    use alloy_primitives::B256;
+    use kona_supervisor_types::OutputV0;
+    use reth_db::{
+        DatabaseEnv,
+        mdbx::{DatabaseArguments, init_db_for},
+    };
+    use reth_db_api::Database;
+    use tempfile::TempDir;
+
+    fn setup_db() -> (TempDir, DatabaseEnv) {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let db = init_db_for::<_, Tables>(temp_dir.path(), DatabaseArguments::default())
+            .expect("init database");
+        (temp_dir, db)
+    }
+
+    fn output(block_number: u64) -> ChainOutput {
+        let block_hash = B256::from([block_number as u8; 32]);
+        ChainOutput {
+            block_number,
+            output: OutputV0 { block_hash, ..Default::default() },
+            pending: OutputV0 { block_hash, ..Default::default() },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rewind_and_prune_outputs() {
+        let (_dir, db) = setup_db();
+
+        // Two timestamps per block.
+        let tx = db.tx_mut().expect("open rw tx");
+        let provider = OutputProvider::new(&tx, 1);
+        for timestamp in 0..10 {
+            provider.save_output(timestamp, output(timestamp / 2)).unwrap();
+        }
+        provider.rewind_to(3).unwrap();
+        provider.prune_to(1).unwrap();
+        tx.commit().expect("commit");
+
+        let tx = db.tx().expect("open ro tx");
+        let provider = OutputProvider::new(&tx, 1);
+        assert_eq!(provider.get_output(1).unwrap(), None);
+        assert_eq!(provider.get_output(2).unwrap(), Some(output(1)));
+        assert_eq!(provider.get_output(5).unwrap(), Some(output(2)));
+        assert_eq!(provider.get_output(6).unwrap(), None);
+    }
+}
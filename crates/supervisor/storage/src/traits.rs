@@ -3,9 +3,10 @@ use alloy_eips::eip1898::BlockNumHash;
 use alloy_primitives::ChainId;
 use kona_interop::DerivedRefPair;
 use kona_protocol::BlockInfo;
-use kona_supervisor_types::{Log, SuperHead};
+use kona_supervisor_metrics::MetricsReporter;
+use kona_supervisor_types::{ChainOutput, ExecutingMessageRef, Log, MessageIdentifier, SuperHead};
 use op_alloy_consensus::interop::SafetyLevel;
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 /// Provides an interface for supervisor storage to manage source and derived blocks.
 ///
@@ -219,6 +220,28 @@ pub trait LogStorage: LogStorageReader + LogStorageWriter {}
 
 impl<T: LogStorageReader + LogStorageWriter> LogStorage for T {}
 
+/// Provides an interface for looking up executing messages by the initiating message they
+/// reference.
+///
+/// The reverse index is maintained by the log storage as blocks are stored. Entries of blocks
+/// that were rewound are kept, so that consumers can detect invalidated executions by comparing
+/// the returned block against the canonical block at the same height.
+pub trait MessageIndexReader: Debug {
+    /// Retrieves all executing messages in this chain that reference the given initiating
+    /// message.
+    ///
+    /// # Arguments
+    /// * `initiating` - The [`MessageIdentifier`] of the initiating message.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<ExecutingMessageRef>)` containing the executing messages, empty if none exist.
+    /// * `Err(StorageError)` if there is an issue reading the index.
+    fn get_executing_messages(
+        &self,
+        initiating: &MessageIdentifier,
+    ) -> Result<Vec<ExecutingMessageRef>, StorageError>;
+}
+
 /// Provides an interface for retrieving head references.
 ///
 /// This trait defines methods to manage safety head references for different safety levels.
@@ -299,6 +322,55 @@ pub trait HeadRefStorage: HeadRefStorageReader + HeadRefStorageWriter {}
 
 impl<T: HeadRefStorageReader + HeadRefStorageWriter> HeadRefStorage for T {}
 
+/// Provides an interface for reading the cached outputs of finalized blocks.
+///
+/// Super roots are computed from the outputs of all chains at a given timestamp. Outputs of
+/// finalized blocks can no longer change, so they are cached to avoid fetching them from the
+/// managed nodes again.
+pub trait OutputStorageReader: Debug {
+    /// Retrieves the cached output of the latest block at or before the given timestamp.
+    ///
+    /// # Arguments
+    /// * `timestamp` - The timestamp the output was requested at.
+    ///
+    /// # Returns
+    /// * `Ok(Some(ChainOutput))` if an output is cached for the timestamp.
+    /// * `Ok(None)` if no output is cached for the timestamp.
+    /// * `Err(StorageError)` if there is an issue retrieving the output.
+    fn get_finalized_output(&self, timestamp: u64) -> Result<Option<ChainOutput>, StorageError>;
+}
+
+/// Provides an interface for caching the outputs of finalized blocks.
+pub trait OutputStorageWriter: Debug {
+    /// Caches the output of the latest block at or before the given timestamp.
+    ///
+    /// The block must be stored and at or below the [`Finalized`](SafetyLevel::Finalized) head.
+    /// Cached outputs are dropped when their block is rewound or pruned.
+    ///
+    /// # Arguments
+    /// * `timestamp` - The timestamp the output was requested at.
+    /// * `output` - The [`ChainOutput`] of the block.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the output was successfully cached.
+    /// * `Err(StorageError::FutureData)` if the block is not finalized yet.
+    /// * `Err(StorageError::ConflictError)` if the block conflicts with the stored block.
+    /// * `Err(StorageError)` if there is an issue caching the output.
+    fn save_finalized_output(
+        &self,
+        timestamp: u64,
+        output: ChainOutput,
+    ) -> Result<(), StorageError>;
+}
+
+/// Combines both reading and writing capabilities for the cached outputs.
+///
+/// Any type that implements both [`OutputStorageReader`] and [`OutputStorageWriter`]
+/// automatically implements this trait.
+pub trait OutputStorage: OutputStorageReader + OutputStorageWriter {}
+
+impl<T: OutputStorageReader + OutputStorageWriter> OutputStorage for T {}
+
 /// Provides an interface for managing the finalized L1 block reference in the storage.
 ///
 /// This trait defines methods to update and retrieve the finalized L1 block reference.
@@ -418,6 +490,26 @@ pub trait CrossChainSafetyProvider {
         chain_id: ChainId,
         block: &BlockInfo,
     ) -> Result<DerivedRefPair, StorageError>;
+
+    /// Reverts the promotion of a block to the [`CrossUnsafe`](SafetyLevel::CrossUnsafe) or
+    /// [`CrossSafe`](SafetyLevel::CrossSafe) head, moving the head back to the block's parent.
+    ///
+    /// Used to undo the promotion of a group of blocks that must be promoted together, when the
+    /// promotion of another block of the group fails.
+    /// # Arguments
+    /// * `chain_id` - The [`ChainId`] of the target chain.
+    /// * `level` - The [`SafetyLevel`] of the head, either `CrossUnsafe` or `CrossSafe`.
+    /// * `block` - The [`BlockInfo`] of the current head.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the head was moved back to the parent of the block.
+    /// * `Err(StorageError::ConflictError)` if the block is not the current head.
+    fn revert_cross_head(
+        &self,
+        chain_id: ChainId,
+        level: SafetyLevel,
+        block: &BlockInfo,
+    ) -> Result<(), StorageError>;
 }
 
 /// Trait for rewinding supervisor-related state in the database.
@@ -466,6 +558,29 @@ pub trait StorageRewinder {
     fn rewind_to_source(&self, to: &BlockNumHash) -> Result<Option<BlockInfo>, StorageError>;
 }
 
+/// Trait for pruning supervisor-related state that can no longer be referenced.
+///
+/// Once finalized, blocks older than the message expiry window cannot be referenced by new
+/// executing messages. Pruning removes their logs and derivation records, while keeping the
+/// interop activation entries that anchor the storage. Reads of pruned entries fail with
+/// [`StorageError::EntryPruned`].
+pub trait StoragePruner {
+    /// Prunes log storage, derivation storage and cached outputs below the given derived block
+    /// number.
+    ///
+    /// The target is capped at the [`Finalized`](SafetyLevel::Finalized) head, so that only data
+    /// which can no longer be reorged is ever pruned. Space freed by the pruned entries is handed
+    /// back to the database, which reuses it for new entries.
+    ///
+    /// # Arguments
+    /// * `block_number` - The derived block number to prune up to (exclusive).
+    ///
+    /// # Returns
+    /// * `Ok(u64)` containing the number of pruned blocks.
+    /// * `Err(StorageError)` if there is an issue pruning the storage.
+    fn prune(&self, block_number: u64) -> Result<u64, StorageError>;
+}
+
 /// Combines the reader traits for the database.
 ///
 /// Any type that implements [`DerivationStorageReader`], [`HeadRefStorageReader`], and
@@ -473,3 +588,66 @@ pub trait StorageRewinder {
 pub trait DbReader: DerivationStorageReader + HeadRefStorageReader + LogStorageReader {}
 
 impl<T: DerivationStorageReader + HeadRefStorageReader + LogStorageReader> DbReader for T {}
+
+/// Combines every storage trait of a single chain.
+///
+/// Any type that implements all of the storage traits, and can be shared across tasks,
+/// automatically implements this trait.
+pub trait ChainStorage:
+    DerivationStorage
+    + LogStorage
+    + HeadRefStorage
+    + MessageIndexReader
+    + OutputStorage
+    + StorageRewinder
+    + StoragePruner
+    + Send
+    + Sync
+    + 'static
+{
+}
+
+impl<T> ChainStorage for T where
+    T: DerivationStorage
+        + LogStorage
+        + HeadRefStorage
+        + MessageIndexReader
+        + OutputStorage
+        + StorageRewinder
+        + StoragePruner
+        + Send
+        + Sync
+        + 'static
+{
+}
+
+/// Provides an interface for managing the storage of every supervised chain.
+///
+/// Implemented by the MDBX-backed [`ChainDbFactory`](crate::ChainDbFactory) and by the
+/// [`InMemoryChainDbFactory`](crate::InMemoryChainDbFactory).
+pub trait StorageFactory:
+    FinalizedL1Storage + CrossChainSafetyProvider + MetricsReporter + Debug + Send + Sync + 'static
+{
+    /// The storage of a single chain.
+    type ChainDb: ChainStorage;
+
+    /// Gets the storage of the given chain, creating it if it doesn't exist.
+    ///
+    /// # Arguments
+    /// * `chain_id` - The ID of the chain.
+    ///
+    /// # Returns
+    /// * `Ok(Arc<Self::ChainDb>)` containing the storage of the chain.
+    /// * `Err(StorageError)` if the storage could not be created.
+    fn get_or_create_db(&self, chain_id: ChainId) -> Result<Arc<Self::ChainDb>, StorageError>;
+
+    /// Gets the storage of the given chain.
+    ///
+    /// # Arguments
+    /// * `chain_id` - The ID of the chain.
+    ///
+    /// # Returns
+    /// * `Ok(Arc<Self::ChainDb>)` containing the storage of the chain.
+    /// * `Err(StorageError::DatabaseNotInitialised)` if the storage does not exist.
+    fn get_db(&self, chain_id: ChainId) -> Result<Arc<Self::ChainDb>, StorageError>;
+}
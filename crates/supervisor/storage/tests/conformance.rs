@@ -0,0 +1,544 @@
+//! Conformance tests shared by all storage backends.
+//!
+//! Every scenario is run against both the MDBX-backed [`ChainDb`] and the [`InMemoryChainDb`],
+//! asserting that they uphold the same invariants and return the same errors. Rewinds are also
+//! checked as properties over randomly shaped chains.
+
+use alloy_primitives::B256;
+use kona_interop::DerivedRefPair;
+use kona_protocol::BlockInfo;
+use kona_supervisor_storage::{
+    ChainDb, ChainStorage, EntryNotFoundError, InMemoryChainDb, StorageError,
+};
+use kona_supervisor_types::{
+    ChainOutput, ExecutingMessage, ExecutingMessageRef, Log, OutputV0, SuperHead,
+};
+use op_alloy_consensus::interop::SafetyLevel;
+use proptest::prelude::*;
+
+const CHAIN_ID: u64 = 1;
+
+macro_rules! conformance_tests {
+    ($($name:ident),* $(,)?) => {
+        mod chaindb {
+            use super::*;
+
+            $(
+                #[test]
+                fn $name() {
+                    let tmp_dir = tempfile::TempDir::new().expect("create temp dir");
+                    let db = ChainDb::new(CHAIN_ID, tmp_dir.path()).expect("create db");
+                    super::$name(&db);
+                }
+            )*
+        }
+
+        mod in_memory {
+            use super::*;
+
+            $(
+                #[test]
+                fn $name() {
+                    super::$name(&InMemoryChainDb::new(CHAIN_ID));
+                }
+            )*
+        }
+    };
+}
+
+conformance_tests!(
+    uninitialised_storage,
+    log_storage,
+    derivation_storage,
+    safety_head_refs,
+    rewind,
+    rewind_to_source,
+    prune,
+    executing_message_index,
+    finalized_outputs,
+);
+
+fn hash(prefix: u8, number: u64) -> B256 {
+    let mut bytes = [0u8; 32];
+    bytes[0] = prefix;
+    bytes[24..].copy_from_slice(&number.to_be_bytes());
+    B256::from(bytes)
+}
+
+fn l2(number: u64) -> BlockInfo {
+    BlockInfo {
+        number,
+        hash: hash(2, number),
+        parent_hash: hash(2, number.wrapping_sub(1)),
+        timestamp: number * 2,
+    }
+}
+
+fn l1(number: u64) -> BlockInfo {
+    BlockInfo {
+        number,
+        hash: hash(1, number),
+        parent_hash: hash(1, number.wrapping_sub(1)),
+        timestamp: number * 12,
+    }
+}
+
+/// Returns a block at the height of `block`, on a different fork.
+fn sibling(block: BlockInfo) -> BlockInfo {
+    BlockInfo { hash: hash(0xff, block.number), ..block }
+}
+
+const fn pair(source: BlockInfo, derived: BlockInfo) -> DerivedRefPair {
+    DerivedRefPair { source, derived }
+}
+
+const EXECUTING_MESSAGE: ExecutingMessage = ExecutingMessage {
+    chain_id: 2,
+    block_number: 7,
+    log_index: 3,
+    timestamp: 14,
+    hash: B256::ZERO,
+};
+
+fn logs(block_number: u64) -> Vec<Log> {
+    match block_number {
+        1 => vec![Log { index: 0, hash: hash(3, 1), executing_message: None }],
+        4 => vec![
+            Log { index: 0, hash: hash(3, 4), executing_message: None },
+            Log { index: 1, hash: hash(4, 4), executing_message: Some(EXECUTING_MESSAGE) },
+        ],
+        _ => Vec::new(),
+    }
+}
+
+/// Stores L2 blocks `0..=5` in the log storage and derives blocks `0..=3` as follows:
+/// - source `10`: derived `0` (activation)
+/// - source `11`: derived `1`, `2`
+/// - source `12`: derived `3`
+/// - source `13`: nothing
+fn setup(db: &impl ChainStorage) {
+    db.initialise_log_storage(l2(0)).unwrap();
+    for number in 1..=5 {
+        db.store_block_logs(&l2(number), logs(number)).unwrap();
+    }
+
+    db.initialise_derivation_storage(pair(l1(10), l2(0))).unwrap();
+    db.save_source_block(l1(11)).unwrap();
+    db.save_derived_block(pair(l1(11), l2(1))).unwrap();
+    db.save_derived_block(pair(l1(11), l2(2))).unwrap();
+    db.save_source_block(l1(12)).unwrap();
+    db.save_derived_block(pair(l1(12), l2(3))).unwrap();
+    db.save_source_block(l1(13)).unwrap();
+}
+
+fn uninitialised_storage(db: &impl ChainStorage) {
+    assert_eq!(db.get_latest_block(), Err(StorageError::DatabaseNotInitialised));
+    assert_eq!(db.latest_derivation_state(), Err(StorageError::DatabaseNotInitialised));
+    assert_eq!(db.get_activation_block(), Err(StorageError::DatabaseNotInitialised));
+    assert_eq!(db.get_super_head(), Err(StorageError::DatabaseNotInitialised));
+    assert!(matches!(
+        db.get_safety_head_ref(SafetyLevel::LocalUnsafe),
+        Err(StorageError::FutureData)
+    ));
+    assert_eq!(
+        db.get_block(3),
+        Err(StorageError::EntryNotFound(EntryNotFoundError::DerivedBlockNotFound(3)))
+    );
+    assert_eq!(db.get_logs(3), Ok(Vec::new()));
+
+    assert_eq!(db.store_block_logs(&l2(1), Vec::new()), Err(StorageError::DatabaseNotInitialised));
+    assert_eq!(db.save_source_block(l1(11)), Err(StorageError::DatabaseNotInitialised));
+    assert_eq!(
+        db.save_derived_block(pair(l1(11), l2(1))),
+        Err(StorageError::DatabaseNotInitialised)
+    );
+}
+
+fn log_storage(db: &impl ChainStorage) {
+    setup(db);
+
+    assert_eq!(db.get_latest_block(), Ok(l2(5)));
+    assert_eq!(db.get_block(2), Ok(l2(2)));
+    assert_eq!(db.get_logs(4), Ok(logs(4)));
+    assert_eq!(db.get_logs(2), Ok(Vec::new()));
+    assert_eq!(db.get_log(4, 1), Ok(logs(4)[1].clone()));
+    assert_eq!(
+        db.get_log(1, 5),
+        Err(StorageError::EntryNotFound(EntryNotFoundError::LogNotFound {
+            block_number: 1,
+            log_index: 5
+        }))
+    );
+
+    // Storing known blocks is idempotent, conflicting and detached blocks are rejected.
+    assert_eq!(db.store_block_logs(&l2(3), Vec::new()), Ok(()));
+    assert_eq!(db.store_block_logs(&sibling(l2(3)), Vec::new()), Err(StorageError::ConflictError));
+    assert!(matches!(db.store_block_logs(&l2(7), Vec::new()), Err(StorageError::BlockOutOfOrder)));
+    assert_eq!(db.initialise_log_storage(l2(0)), Ok(()));
+    assert_eq!(db.initialise_log_storage(sibling(l2(0))), Err(StorageError::ConflictError));
+
+    assert_eq!(db.get_safety_head_ref(SafetyLevel::LocalUnsafe), Ok(l2(5)));
+    assert_eq!(db.get_safety_head_ref(SafetyLevel::CrossUnsafe), Ok(l2(0)));
+}
+
+fn derivation_storage(db: &impl ChainStorage) {
+    setup(db);
+
+    assert_eq!(db.get_activation_block(), Ok(l2(0)));
+    assert_eq!(db.latest_derivation_state(), Ok(pair(l1(13), l2(3))));
+    assert_eq!(db.get_source_block(12), Ok(l1(12)));
+    assert_eq!(db.derived_to_source(l2(3).id()), Ok(l1(12)));
+    assert_eq!(db.derived_to_source(sibling(l2(3)).id()), Err(StorageError::ConflictError));
+    assert_eq!(
+        db.derived_to_source(l2(4).id()),
+        Err(StorageError::EntryNotFound(EntryNotFoundError::DerivedBlockNotFound(4)))
+    );
+    assert_eq!(db.latest_derived_block_at_source(l1(11).id()), Ok(l2(2)));
+    assert_eq!(db.latest_derived_block_at_source(l1(13).id()), Ok(l2(3)));
+    assert_eq!(
+        db.latest_derived_block_at_source(l1(14).id()),
+        Err(StorageError::EntryNotFound(EntryNotFoundError::SourceBlockNotFound(14)))
+    );
+
+    // Source blocks
+    assert_eq!(db.save_source_block(l1(12)), Ok(()));
+    assert_eq!(db.save_source_block(sibling(l1(12))), Err(StorageError::ConflictError));
+    assert!(matches!(db.save_source_block(l1(15)), Err(StorageError::BlockOutOfOrder)));
+
+    // Derived blocks
+    assert_eq!(db.save_derived_block(pair(l1(13), l2(4))), Ok(()));
+    assert_eq!(db.save_derived_block(pair(l1(13), l2(4))), Ok(()));
+    assert_eq!(db.get_safety_head_ref(SafetyLevel::LocalSafe), Ok(l2(4)));
+    assert_eq!(db.save_derived_block(pair(l1(12), l2(4))), Err(StorageError::ConflictError));
+    assert!(matches!(
+        db.save_derived_block(pair(l1(12), l2(5))),
+        Err(StorageError::BlockOutOfOrder)
+    ));
+    assert!(matches!(
+        db.save_derived_block(pair(l1(13), l2(6))),
+        Err(StorageError::BlockOutOfOrder)
+    ));
+
+    // Derived blocks must be consistent with the log storage. Failed saves are not persisted.
+    db.save_source_block(l1(14)).unwrap();
+    db.save_derived_block(pair(l1(14), l2(5))).unwrap();
+    assert!(matches!(db.save_derived_block(pair(l1(14), l2(6))), Err(StorageError::FutureData)));
+    db.store_block_logs(&l2(6), Vec::new()).unwrap();
+    assert!(matches!(
+        db.save_derived_block(pair(l1(14), sibling(l2(6)))),
+        Err(StorageError::ReorgRequired)
+    ));
+    assert_eq!(db.latest_derivation_state(), Ok(pair(l1(14), l2(5))));
+    assert_eq!(db.save_derived_block(pair(l1(14), l2(6))), Ok(()));
+}
+
+fn safety_head_refs(db: &impl ChainStorage) {
+    setup(db);
+
+    // Cross-unsafe must progress one block at a time, along the stored chain.
+    assert_eq!(db.update_current_cross_unsafe(&l2(2)), Err(StorageError::ConflictError));
+    assert_eq!(db.update_current_cross_unsafe(&l2(1)), Ok(()));
+    let fork = BlockInfo { parent_hash: l2(1).hash, ..sibling(l2(2)) };
+    assert_eq!(db.update_current_cross_unsafe(&fork), Err(StorageError::ConflictError));
+
+    // Cross-safe must progress one block at a time, along the derived chain.
+    assert_eq!(db.update_current_cross_safe(&l2(2)), Err(StorageError::ConflictError));
+    assert_eq!(db.update_current_cross_safe(&l2(1)), Ok(pair(l1(11), l2(1))));
+
+    // A finalized source block ahead of the cross-safe head finalizes the cross-safe head.
+    assert_eq!(db.update_finalized_using_source(l1(11)), Ok(l2(1)));
+    db.update_current_cross_safe(&l2(2)).unwrap();
+    db.update_current_cross_safe(&l2(3)).unwrap();
+    assert_eq!(db.update_finalized_using_source(l1(11)), Ok(l2(2)));
+
+    assert_eq!(
+        db.get_super_head(),
+        Ok(SuperHead {
+            l1_source: Some(l1(13)),
+            local_unsafe: l2(5),
+            cross_unsafe: Some(l2(1)),
+            local_safe: Some(l2(3)),
+            cross_safe: Some(l2(3)),
+            finalized: Some(l2(2)),
+        })
+    );
+}
+
+fn rewind(db: &impl ChainStorage) {
+    setup(db);
+
+    // Log storage can only be rewound above the local safe head.
+    assert!(matches!(
+        db.rewind_log_storage(&l2(3).id()),
+        Err(StorageError::RewindBeyondLocalSafeHead { to: 3, local_safe: 3 })
+    ));
+    assert_eq!(db.rewind_log_storage(&sibling(l2(4)).id()), Err(StorageError::ConflictError));
+    assert!(matches!(db.rewind_log_storage(&l2(9).id()), Err(StorageError::FutureData)));
+
+    db.rewind_log_storage(&l2(5).id()).unwrap();
+    assert_eq!(db.get_latest_block(), Ok(l2(4)));
+    assert_eq!(db.get_safety_head_ref(SafetyLevel::LocalUnsafe), Ok(l2(4)));
+    assert_eq!(db.get_safety_head_ref(SafetyLevel::CrossUnsafe), Ok(l2(0)));
+
+    // A failed rewind does not touch the log storage.
+    assert_eq!(
+        db.rewind(&l2(4).id()),
+        Err(StorageError::EntryNotFound(EntryNotFoundError::DerivedBlockNotFound(4)))
+    );
+    assert_eq!(db.get_latest_block(), Ok(l2(4)));
+
+    db.rewind(&l2(2).id()).unwrap();
+    assert_eq!(db.get_latest_block(), Ok(l2(1)));
+    assert_eq!(db.latest_derivation_state(), Ok(pair(l1(11), l2(1))));
+    assert_eq!(db.get_safety_head_ref(SafetyLevel::LocalUnsafe), Ok(l2(1)));
+    assert_eq!(db.get_safety_head_ref(SafetyLevel::LocalSafe), Ok(l2(1)));
+    assert_eq!(db.get_safety_head_ref(SafetyLevel::CrossSafe), Ok(l2(0)));
+    assert!(matches!(db.rewind(&l2(5).id()), Err(StorageError::FutureData)));
+
+    // The storage continues from the rewound state.
+    db.store_block_logs(&l2(2), Vec::new()).unwrap();
+    db.save_derived_block(pair(l1(11), l2(2))).unwrap();
+    assert_eq!(db.latest_derivation_state(), Ok(pair(l1(11), l2(2))));
+}
+
+fn rewind_to_source(db: &impl ChainStorage) {
+    setup(db);
+
+    assert_eq!(db.rewind_to_source(&sibling(l1(12)).id()), Err(StorageError::ConflictError));
+
+    // Source blocks without derived blocks do not affect the derived chain.
+    assert_eq!(db.rewind_to_source(&l1(13).id()), Ok(None));
+    assert_eq!(db.latest_derivation_state(), Ok(pair(l1(12), l2(3))));
+    assert_eq!(db.get_latest_block(), Ok(l2(5)));
+
+    assert_eq!(db.rewind_to_source(&l1(12).id()), Ok(Some(l2(3))));
+    assert_eq!(db.latest_derivation_state(), Ok(pair(l1(11), l2(2))));
+    assert_eq!(db.get_latest_block(), Ok(l2(2)));
+    assert_eq!(db.get_safety_head_ref(SafetyLevel::LocalUnsafe), Ok(l2(2)));
+    assert_eq!(db.get_safety_head_ref(SafetyLevel::LocalSafe), Ok(l2(2)));
+}
+
+fn prune(db: &impl ChainStorage) {
+    setup(db);
+
+    // Nothing is pruned before finalization.
+    assert!(matches!(db.prune(10), Err(StorageError::FutureData)));
+
+    for number in 1..=3 {
+        db.update_current_cross_safe(&l2(number)).unwrap();
+    }
+    assert_eq!(db.update_finalized_using_source(l1(13)), Ok(l2(3)));
+
+    // Pruning is capped at the finalized head.
+    assert_eq!(db.prune(10), Ok(2));
+    assert_eq!(db.prune(10), Ok(0));
+
+    assert_eq!(db.get_block(1), Err(StorageError::EntryPruned(1)));
+    assert_eq!(db.get_log(1, 0), Err(StorageError::EntryPruned(1)));
+    assert_eq!(db.get_logs(2), Err(StorageError::EntryPruned(2)));
+    assert_eq!(db.derived_to_source(l2(1).id()), Err(StorageError::EntryPruned(1)));
+    assert_eq!(db.get_source_block(11), Err(StorageError::EntryPruned(11)));
+
+    // The activation entries and the blocks from the finalized head onward are kept.
+    assert_eq!(db.get_block(0), Ok(l2(0)));
+    assert_eq!(db.get_activation_block(), Ok(l2(0)));
+    assert_eq!(db.get_block(3), Ok(l2(3)));
+    assert_eq!(db.derived_to_source(l2(3).id()), Ok(l1(12)));
+    assert_eq!(db.latest_derived_block_at_source(l1(12).id()), Ok(l2(3)));
+    assert_eq!(db.get_executing_messages(&(&EXECUTING_MESSAGE).into()).unwrap().len(), 1);
+}
+
+fn executing_message_index(db: &impl ChainStorage) {
+    setup(db);
+
+    let initiating = (&EXECUTING_MESSAGE).into();
+    let execution =
+        ExecutingMessageRef { block: l2(4).id(), log_index: 1, hash: EXECUTING_MESSAGE.hash };
+    assert_eq!(db.get_executing_messages(&initiating), Ok(vec![execution]));
+
+    // Executions of rewound blocks are kept, next to the ones of the new chain.
+    db.rewind_log_storage(&l2(4).id()).unwrap();
+    let fork = BlockInfo { parent_hash: l2(3).hash, ..sibling(l2(4)) };
+    db.store_block_logs(&fork, logs(4)).unwrap();
+
+    let executions = db.get_executing_messages(&initiating).unwrap();
+    assert_eq!(executions.len(), 2);
+    assert!(executions.contains(&execution));
+    assert!(executions.contains(&ExecutingMessageRef { block: fork.id(), ..execution }));
+}
+
+fn output(block: BlockInfo, source: BlockInfo) -> ChainOutput {
+    ChainOutput {
+        block_number: block.number,
+        source,
+        output: OutputV0 { block_hash: block.hash, ..Default::default() },
+        pending: OutputV0 { block_hash: block.hash, ..Default::default() },
+    }
+}
+
+fn finalized_outputs(db: &impl ChainStorage) {
+    setup(db);
+
+    // Outputs can only be cached once finalized.
+    assert_eq!(db.save_finalized_output(0, output(l2(0), l1(10))), Err(StorageError::FutureData));
+
+    for number in 1..=3 {
+        db.update_current_cross_safe(&l2(number)).unwrap();
+    }
+    assert_eq!(db.update_finalized_using_source(l1(11)), Ok(l2(2)));
+
+    assert_eq!(db.save_finalized_output(6, output(l2(3), l1(12))), Err(StorageError::FutureData));
+    assert_eq!(
+        db.save_finalized_output(4, output(sibling(l2(2)), l1(11))),
+        Err(StorageError::ConflictError)
+    );
+    for number in 0..=2 {
+        let source = if number == 0 { l1(10) } else { l1(11) };
+        db.save_finalized_output(l2(number).timestamp, output(l2(number), source)).unwrap();
+    }
+    assert_eq!(db.get_finalized_output(4), Ok(Some(output(l2(2), l1(11)))));
+    assert_eq!(db.get_finalized_output(5), Ok(None));
+
+    // Outputs of pruned blocks are dropped.
+    assert_eq!(db.prune(10), Ok(1));
+    assert_eq!(db.get_finalized_output(0), Ok(None));
+    assert_eq!(db.get_finalized_output(2), Ok(None));
+    assert_eq!(db.get_finalized_output(4), Ok(Some(output(l2(2), l1(11)))));
+
+    // Outputs of rewound blocks are dropped.
+    db.rewind(&l2(2).id()).unwrap();
+    assert_eq!(db.get_finalized_output(4), Ok(None));
+}
+
+/// Rewind operations exercised by the rewind properties.
+#[derive(Debug, Clone, Copy)]
+enum Rewind {
+    LogStorage(u64),
+    Derived(u64),
+    Source(u64),
+}
+
+impl Rewind {
+    fn apply(self, db: &impl ChainStorage) -> Result<Option<BlockInfo>, StorageError> {
+        match self {
+            Self::LogStorage(number) => db.rewind_log_storage(&l2(number).id()).map(|_| None),
+            Self::Derived(number) => db.rewind(&l2(number).id()).map(|_| None),
+            Self::Source(number) => db.rewind_to_source(&l1(number).id()),
+        }
+    }
+}
+
+/// Observable state of a storage, compared across backends and before and after a rewind.
+#[derive(Debug, PartialEq)]
+struct Snapshot {
+    latest_block: Result<BlockInfo, StorageError>,
+    derivation_state: Result<DerivedRefPair, StorageError>,
+    heads: Vec<Result<BlockInfo, StorageError>>,
+    blocks: Vec<Result<BlockInfo, StorageError>>,
+    logs: Vec<Result<Vec<Log>, StorageError>>,
+    sources: Vec<Result<BlockInfo, StorageError>>,
+}
+
+impl Snapshot {
+    fn take(db: &impl ChainStorage, len: u64) -> Self {
+        let levels = [
+            SafetyLevel::LocalUnsafe,
+            SafetyLevel::CrossUnsafe,
+            SafetyLevel::LocalSafe,
+            SafetyLevel::CrossSafe,
+            SafetyLevel::Finalized,
+        ];
+        Self {
+            latest_block: db.get_latest_block(),
+            derivation_state: db.latest_derivation_state(),
+            heads: levels.into_iter().map(|level| db.get_safety_head_ref(level)).collect(),
+            blocks: (0..=len).map(|number| db.get_block(number)).collect(),
+            logs: (0..=len).map(|number| db.get_logs(number)).collect(),
+            sources: (0..=len).map(|number| db.derived_to_source(l2(number).id())).collect(),
+        }
+    }
+}
+
+/// Stores L2 blocks `0..=logged`, derives blocks `1..=derived` from source blocks of the same
+/// number and promotes blocks `1..=cross` to cross-unsafe and cross-safe.
+fn build_chain(db: &impl ChainStorage, logged: u64, derived: u64, cross: u64) {
+    db.initialise_log_storage(l2(0)).unwrap();
+    for number in 1..=logged {
+        db.store_block_logs(&l2(number), logs(number)).unwrap();
+    }
+
+    db.initialise_derivation_storage(pair(l1(0), l2(0))).unwrap();
+    for number in 1..=derived {
+        db.save_source_block(l1(number)).unwrap();
+        db.save_derived_block(pair(l1(number), l2(number))).unwrap();
+    }
+
+    for number in 1..=cross {
+        db.update_current_cross_unsafe(&l2(number)).unwrap();
+        db.update_current_cross_safe(&l2(number)).unwrap();
+    }
+}
+
+/// Applies the rewind to both backends and checks that they agree, that a failed rewind leaves
+/// the storage untouched and that a successful one removes exactly the blocks from its target
+/// onward while keeping every safety head on the remaining chain.
+fn check_rewind(logged: u64, derived: u64, cross: u64, rewind: Rewind) {
+    let tmp_dir = tempfile::TempDir::new().expect("create temp dir");
+    let chain_db = ChainDb::new(CHAIN_ID, tmp_dir.path()).expect("create db");
+    let in_memory = InMemoryChainDb::new(CHAIN_ID);
+    build_chain(&chain_db, logged, derived, cross);
+    build_chain(&in_memory, logged, derived, cross);
+
+    // Include one block past the tip, which must never exist.
+    let len = logged + 1;
+    let before = Snapshot::take(&in_memory, len);
+
+    let result = rewind.apply(&chain_db);
+    assert_eq!(result, rewind.apply(&in_memory), "{rewind:?}");
+    let after = Snapshot::take(&in_memory, len);
+    assert_eq!(Snapshot::take(&chain_db, len), after, "{rewind:?}");
+
+    let Ok(rewound) = result else {
+        assert_eq!(after, before, "failed {rewind:?} changed the storage");
+        return;
+    };
+    let removed_from = match rewind {
+        Rewind::LogStorage(number) | Rewind::Derived(number) => number,
+        Rewind::Source(_) => rewound.map_or(len, |block| block.number),
+    };
+
+    assert_eq!(after.latest_block, Ok(l2(logged.min(removed_from - 1))), "{rewind:?}");
+    for number in 0..=len {
+        let kept = number < removed_from && number <= logged;
+        assert_eq!(after.blocks[number as usize].is_ok(), kept, "{rewind:?} block {number}");
+        if kept {
+            assert_eq!(after.logs[number as usize], Ok(logs(number)), "{rewind:?} block {number}");
+        }
+        let has_source = number <= derived && number < removed_from;
+        assert_eq!(after.sources[number as usize].is_ok(), has_source, "{rewind:?} block {number}");
+    }
+    for head in after.heads.iter().flatten() {
+        assert!(head.number < removed_from, "{rewind:?} left head {head:?}");
+        assert_eq!(head, &l2(head.number), "{rewind:?} left head {head:?}");
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn rewind_properties(
+        (logged, derived, cross) in (1u64..12)
+            .prop_flat_map(|logged| (Just(logged), 0..=logged))
+            .prop_flat_map(|(logged, derived)| (Just(logged), Just(derived), 0..=derived)),
+        target in 1u64..13,
+        kind in 0u8..3,
+    ) {
+        let rewind = match kind {
+            0 => Rewind::LogStorage(target),
+            1 => Rewind::Derived(target),
+            _ => Rewind::Source(target),
+        };
+        check_rewind(logged, derived, cross, rewind);
+    }
+}
@@ -10,7 +10,7 @@ mod log;
 pub use log::Log;
 
 mod message;
-pub use message::ExecutingMessage;
+pub use message::{ExecutingMessage, ExecutingMessageRef, MessageIdentifier};
 
 mod receipt;
 pub use receipt::Receipts;
@@ -23,4 +23,4 @@ mod types;
 
 pub use hex_string_u64::HexStringU64;
 
-pub use types::{BlockSeal, OutputV0, SubscriptionEvent};
+pub use types::{BlockSeal, ChainOutput, OutputV0, SubscriptionEvent};
@@ -1,8 +1,10 @@
+use alloy_eips::BlockNumHash;
 use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
 
 /// A parsed executing message extracted from a log emitted by the
 /// `CrossL2Inbox` contract on an L2 chain.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExecutingMessage {
     /// The chain ID where the message was observed.
     pub chain_id: u64,
@@ -15,3 +17,51 @@ pub struct ExecutingMessage {
     /// A unique hash identifying the log (based on payload and origin).
     pub hash: B256,
 }
+
+/// Identifies an initiating message by the position of its log on the initiating chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageIdentifier {
+    /// The chain ID where the message was initiated.
+    #[serde(rename = "chainID", with = "alloy_serde::quantity")]
+    pub chain_id: u64,
+    /// The block number that contains the initiating log.
+    #[serde(with = "alloy_serde::quantity")]
+    pub block_number: u64,
+    /// The log index within the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub log_index: u32,
+}
+
+impl From<&ExecutingMessage> for MessageIdentifier {
+    fn from(msg: &ExecutingMessage) -> Self {
+        Self { chain_id: msg.chain_id, block_number: msg.block_number, log_index: msg.log_index }
+    }
+}
+
+/// A reference to an executing message, i.e. a log consuming an initiating message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutingMessageRef {
+    /// The block that contains the executing log.
+    pub block: BlockNumHash,
+    /// The log index within the block.
+    pub log_index: u32,
+    /// The hash of the initiating message, as claimed by the executing log.
+    pub hash: B256,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_identifier_serde() {
+        let identifier = MessageIdentifier { chain_id: 10, block_number: 255, log_index: 1 };
+
+        let json = serde_json::to_string(&identifier).unwrap();
+        assert_eq!(json, r#"{"chainID":"0xa","blockNumber":"0xff","logIndex":"0x1"}"#);
+
+        let decoded: MessageIdentifier = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, identifier);
+    }
+}
@@ -6,6 +6,7 @@
 
 use alloy_primitives::B256;
 use kona_interop::ManagedEvent;
+use kona_protocol::BlockInfo;
 use serde::{Deserialize, Serialize};
 
 // todo:: Determine appropriate locations for these structs and move them accordingly.
@@ -52,6 +53,23 @@ impl OutputV0 {
     }
 }
 
+/// Outputs of a chain's latest block at or before a given timestamp.
+///
+/// Holds the [`OutputV0`] preimages of the canonical and pending output roots, along with the L1
+/// block the L2 block was derived from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainOutput {
+    /// The number of the L2 block.
+    pub block_number: u64,
+    /// The L1 block the L2 block was derived from.
+    pub source: BlockInfo,
+    /// The output of the canonical block.
+    pub output: OutputV0,
+    /// The output of the block prior to validation of its executing messages.
+    pub pending: OutputV0,
+}
+
 /// Represents the events structure sent by the node to the supervisor.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SubscriptionEvent {
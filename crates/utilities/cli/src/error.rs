@@ -1,5 +1,6 @@
 //! Error types for CLI utilities.
 
+use kona_genesis::RollupConfigError;
 use thiserror::Error;
 
 /// Errors that can occur in CLI operations.
@@ -17,10 +18,24 @@ pub enum CliError {
     #[error("No unsafe block signer found for chain ID: {0}")]
     UnsafeBlockSignerNotFound(u64),
 
+    /// Error when the rollup config fails validation.
+    #[error("Invalid rollup config for chain ID {chain_id}: {}", join_errors(errors))]
+    InvalidRollupConfig {
+        /// The L2 chain ID of the rollup config.
+        chain_id: u64,
+        /// The errors found while validating the rollup config.
+        errors: Vec<RollupConfigError>,
+    },
+
     /// Error initializing metrics.
     #[error("Failed to initialize metrics")]
     MetricsInitialization(#[from] metrics_exporter_prometheus::BuildError),
 }
 
+/// Joins the rollup config errors into a single message.
+fn join_errors(errors: &[RollupConfigError]) -> String {
+    errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+}
+
 /// Type alias for CLI results.
 pub type CliResult<T> = Result<T, CliError>;
@@ -11,6 +11,9 @@ pub use error::{CliError, CliResult};
 mod flags;
 pub use flags::{GlobalArgs, LogArgs, MetricsArgs, OverrideArgs};
 
+mod rollup;
+pub use rollup::validate_rollup_config;
+
 mod logs;
 pub use logs::{FileLogConfig, LogConfig, LogRotation, StdoutLogConfig};
 
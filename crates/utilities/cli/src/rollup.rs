@@ -0,0 +1,47 @@
+//! Rollup config validation for the CLI.
+
+use crate::{CliError, CliResult};
+use kona_genesis::RollupConfig;
+
+/// Validates the [`RollupConfig`], logging each error found.
+///
+/// Binaries call this at startup so that a malformed config is rejected before any service is
+/// started.
+pub fn validate_rollup_config(config: &RollupConfig) -> CliResult<()> {
+    let chain_id = config.l2_chain_id.id();
+    config.validate().map_err(|errors| {
+        for error in &errors {
+            tracing::error!(target: "cli", chain_id, "Invalid rollup config: {error}");
+        }
+        CliError::InvalidRollupConfig { chain_id, errors }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_genesis::RollupConfigError;
+    use kona_registry::ROLLUP_CONFIGS;
+
+    #[test]
+    fn test_registry_rollup_configs_are_valid() {
+        for config in ROLLUP_CONFIGS.values() {
+            assert!(validate_rollup_config(config).is_ok(), "chain {}", config.l2_chain_id);
+        }
+    }
+
+    #[test]
+    fn test_validate_rollup_config_invalid() {
+        let config = RollupConfig { block_time: 0, ..ROLLUP_CONFIGS[&10].clone() };
+        let err = validate_rollup_config(&config).unwrap_err();
+        assert!(matches!(
+            err,
+            CliError::InvalidRollupConfig { chain_id: 10, ref errors }
+                if errors == &[RollupConfigError::ZeroBlockTime]
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Invalid rollup config for chain ID 10: Block time cannot be 0"
+        );
+    }
+}